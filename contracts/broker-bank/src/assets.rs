@@ -0,0 +1,238 @@
+use std::collections::BTreeSet;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, Api, BankMsg, Coin, CosmosMsg, QuerierWrapper, StdError,
+    StdResult, Storage, Uint128, WasmMsg,
+};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
+use nibiru_std::{
+    address::{nibiru_bech32_to_eth_address, parse_eth_address},
+    proto::{eth, NibiruStargateMsg},
+};
+
+use crate::{error::ContractError, state::CW20S};
+
+/// Denom: An asset the broker contract can hold, in the style of
+/// `cw20::Denom`.
+#[cw_serde]
+pub enum Denom {
+    /// A bank coin, sent with `BankMsg::Send`.
+    Native(String),
+    /// The address of a CW20 token contract, sent with
+    /// `Cw20ExecuteMsg::Transfer`.
+    Cw20(String),
+    /// The bank denom of a FunToken mapping. The contract holds the bank coin
+    /// and the recipient receives its ERC20 through `MsgConvertCoinToEvm`.
+    FunToken(String),
+}
+
+#[cw_serde]
+pub struct Asset {
+    pub denom: Denom,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub enum Action {
+    /// Adds a CW20 token contract to the "CW20S" that `WithdrawAll` sweeps.
+    AddCw20 { address: String },
+    /// Removes a CW20 token contract from the "CW20S".
+    RemoveCw20 { address: String },
+}
+
+#[cw_serde]
+pub struct Cw20sResponse {
+    pub cw20s: BTreeSet<String>,
+}
+
+impl Denom {
+    /// The denom that spend limits and recipient denom restrictions use for
+    /// the asset. CW20 tokens use "cw20:{address}", while FunTokens use their
+    /// bank denom.
+    pub fn key(&self) -> String {
+        match self {
+            Denom::Native(denom) | Denom::FunToken(denom) => denom.clone(),
+            Denom::Cw20(address) => format!("cw20:{address}"),
+        }
+    }
+
+    /// Validates the address of a CW20 token. Addresses that are not in their
+    /// normalized form are rejected, so each token has exactly one
+    /// [Denom::key] for spend limits and recipient restrictions.
+    pub fn validate(&self, api: &dyn Api) -> StdResult<()> {
+        if let Denom::Cw20(address) = self {
+            if api.addr_validate(address)?.as_str() != address {
+                return Err(StdError::generic_err(format!(
+                    "cw20 address {address} is not normalized"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Queries the balance of `address` in this denom.
+    pub fn query_balance(
+        &self,
+        querier: &QuerierWrapper,
+        address: &str,
+    ) -> StdResult<Uint128> {
+        match self {
+            Denom::Native(denom) | Denom::FunToken(denom) => {
+                Ok(querier.query_balance(address, denom)?.amount)
+            }
+            Denom::Cw20(contract) => {
+                let res: BalanceResponse = querier.query_wasm_smart(
+                    contract,
+                    &Cw20QueryMsg::Balance {
+                        address: address.to_string(),
+                    },
+                )?;
+                Ok(res.balance)
+            }
+        }
+    }
+}
+
+impl Asset {
+    /// The asset as a coin in the denom given by [Denom::key].
+    pub fn keyed_coin(&self) -> Coin {
+        Coin {
+            denom: self.denom.key(),
+            amount: self.amount,
+        }
+    }
+
+    /// Builds the tx msg that sends the asset from the contract to `to`.
+    pub fn send_msg(
+        &self,
+        contract_addr: &str,
+        to: &str,
+    ) -> Result<CosmosMsg, ContractError> {
+        let msg = match &self.denom {
+            Denom::Native(denom) => BankMsg::Send {
+                to_address: to.to_string(),
+                amount: vec![Coin::new(self.amount.u128(), denom)],
+            }
+            .into(),
+            Denom::Cw20(contract) => WasmMsg::Execute {
+                contract_addr: contract.clone(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: to.to_string(),
+                    amount: self.amount,
+                })?,
+                funds: vec![],
+            }
+            .into(),
+            Denom::FunToken(denom) => eth::evm::MsgConvertCoinToEvm {
+                to_eth_addr: eth_address(to)?,
+                sender: contract_addr.to_string(),
+                bank_coin: Some(Coin::new(self.amount.u128(), denom).into()),
+            }
+            .into_stargate_msg(),
+        };
+        Ok(msg)
+    }
+}
+
+/// Returns the hex EVM address of `to`, which is either a hex address already
+/// or a "nibi1..." account address.
+fn eth_address(to: &str) -> Result<String, ContractError> {
+    let res = match to.starts_with("0x") {
+        true => parse_eth_address(to).map(|_| to.to_string()),
+        false => nibiru_bech32_to_eth_address(to),
+    };
+    res.map_err(|err| ContractError::InvalidEvmRecipient {
+        to_addr: to.to_string(),
+        reason: err.to_string(),
+    })
+}
+
+/// Lists the CW20 token contracts registered by the owner.
+pub fn cw20s(storage: &dyn Storage) -> StdResult<BTreeSet<String>> {
+    Ok(CW20S.may_load(storage)?.unwrap_or_default())
+}
+
+/// Applies `action` to the "CW20S" and returns the affected address.
+pub fn edit_cw20s(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    action: Action,
+) -> StdResult<String> {
+    let mut cw20s = cw20s(storage)?;
+    let address = match action {
+        Action::AddCw20 { address } => {
+            let address = api.addr_validate(&address)?.into_string();
+            cw20s.insert(address.clone());
+            address
+        }
+        Action::RemoveCw20 { address } => {
+            cw20s.remove(&address);
+            address
+        }
+    };
+    CW20S.save(storage, &cw20s)?;
+    Ok(address)
+}
+
+/// Queries the contract balance of each denom, skipping the empty ones.
+pub fn query_balances(
+    querier: &QuerierWrapper,
+    contract_addr: &str,
+    denoms: impl IntoIterator<Item = Denom>,
+) -> StdResult<Vec<Asset>> {
+    let mut assets = Vec::new();
+    for denom in denoms {
+        let amount = denom.query_balance(querier, contract_addr)?;
+        if !amount.is_zero() {
+            assets.push(Asset { denom, amount });
+        }
+    }
+    Ok(assets)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use nibiru_std::errors::TestResult;
+
+    use super::*;
+
+    #[test]
+    fn denom_key() {
+        let test_cases = [
+            (Denom::Native("unibi".into()), "unibi"),
+            (Denom::Cw20("cw20_addr".into()), "cw20:cw20_addr"),
+            (Denom::FunToken("erc20/0xabc".into()), "erc20/0xabc"),
+        ];
+        for (denom, want) in test_cases {
+            assert_eq!(denom.key(), want);
+        }
+    }
+
+    #[test]
+    fn fun_token_send_msg() -> TestResult {
+        let contract_addr = mock_env().contract.address.to_string();
+        let asset = Asset {
+            denom: Denom::FunToken("erc20/0xabc".into()),
+            amount: Uint128::new(5),
+        };
+
+        let to = "nibi1gc24lt74ses9swkq6g7cug4e5y72p7e34jqgul";
+        let want_to = nibiru_bech32_to_eth_address(to)?;
+        for to in [to, want_to.as_str()] {
+            let msg = asset.send_msg(&contract_addr, to)?;
+            let want_msg = eth::evm::MsgConvertCoinToEvm {
+                to_eth_addr: want_to.clone(),
+                sender: contract_addr.clone(),
+                bank_coin: Some(Coin::new(5, "erc20/0xabc").into()),
+            }
+            .into_stargate_msg();
+            assert_eq!(msg, want_msg);
+        }
+
+        let err = asset.send_msg(&contract_addr, "to_addr").unwrap_err();
+        assert!(matches!(err, ContractError::InvalidEvmRecipient { .. }));
+        Ok(())
+    }
+}
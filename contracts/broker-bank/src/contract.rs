@@ -2,24 +2,33 @@ use std::collections::BTreeSet;
 
 use cosmwasm_std::{
     self as cw_std, attr, to_json_binary, AllBalanceResponse, BankMsg,
-    BankQuery, Binary, Deps, DepsMut, Env, MessageInfo, QueryRequest, Response,
-    StdResult,
+    BankQuery, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, QueryRequest,
+    Response, StdResult,
 };
 use cw_std::Coin;
 
 use crate::oper_perms::Permissions;
 use crate::{
+    assets::{self, Asset},
+    logs,
     msgs::{PermsStatus, QueryMsg},
-    oper_perms,
-    state::{Log, IS_HALTED, LOGS, OPERATORS},
+    oper_perms, spend_limits,
+    state::{
+        Log, IS_HALTED, LOGS, LOG_RETENTION, OPERATOR_ROLE, SPENDS, SPEND_LIMITS,
+    },
+    to_addrs::{self, Recipient},
 };
 
 use cw2::set_contract_version;
 
 use crate::{
     error::ContractError,
-    events::{event_bank_send, event_toggle_halt, event_withdraw},
-    msgs::{ExecuteMsg, InstantiateMsg},
+    events::{
+        event_add_cw20, event_add_to_addr, event_bank_send, event_prune_logs,
+        event_remove_cw20, event_remove_to_addr, event_replace_to_addrs,
+        event_toggle_halt, event_withdraw,
+    },
+    msgs::{ExecuteMsg, InstantiateMsg, MigrateMsg},
     state::TO_ADDRS,
 };
 
@@ -37,8 +46,26 @@ pub fn instantiate(
     )?;
     nibiru_ownable::initialize_owner(deps.storage, Some(&msg.owner))?;
     TO_ADDRS.save(deps.storage, &msg.to_addrs)?;
-    OPERATORS.save(deps.storage, &msg.opers)?;
+    oper_perms::save_operators(deps.storage, &msg.opers)?;
     IS_HALTED.save(deps.storage, &false)?;
+    if let Some(retention) = msg.log_retention {
+        LOG_RETENTION.save(deps.storage, &retention)?;
+    }
+    Ok(Response::default())
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn migrate(
+    deps: DepsMut,
+    _env: Env,
+    _msg: MigrateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(
+        deps.storage,
+        format!("crates.io:{CONTRACT_NAME}"),
+        CONTRACT_VERSION,
+    )?;
+    oper_perms::migrate_legacy_operators(deps.storage)?;
     Ok(Response::default())
 }
 
@@ -51,20 +78,28 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     let contract_addr = env.contract.address.to_string();
     match msg {
-        ExecuteMsg::BankSend { coins, to } => {
-            bank_send(deps, env, info, coins, to)
+        ExecuteMsg::BankSend { coins, assets, to } => {
+            bank_send(deps, env, info, coins, assets, to)
         }
         ExecuteMsg::ToggleHalt {} => toggle_halt(deps, env, info),
         ExecuteMsg::UpdateOwnership(action) => {
             execute_update_ownership(deps, env, info, action)
         }
         ExecuteMsg::EditOpers(action) => edit_opers(deps, env, info, action),
+        ExecuteMsg::EditSpendLimits(action) => {
+            edit_spend_limits(deps, env, info, action)
+        }
         ExecuteMsg::WithdrawAll { to } => {
             withdraw_all(deps, env, info, to, contract_addr)
         }
-        ExecuteMsg::Withdraw { to, denoms } => {
-            withdraw(deps, env, info, to, denoms, contract_addr)
+        ExecuteMsg::PruneLogs(prune) => prune_logs(deps, env, info, prune),
+        ExecuteMsg::EditToAddrs(action) => {
+            edit_to_addrs(deps, env, info, action)
         }
+        ExecuteMsg::Withdraw { to, denoms, assets } => {
+            withdraw(deps, env, info, to, denoms, assets, contract_addr)
+        }
+        ExecuteMsg::EditCw20s(action) => edit_cw20s(deps, env, info, action),
     }
 }
 
@@ -74,6 +109,7 @@ pub fn withdraw(
     info: MessageInfo,
     to: Option<String>,
     denoms: BTreeSet<String>,
+    assets: Vec<assets::Denom>,
     contract_addr: String,
 ) -> Result<Response, ContractError> {
     nibiru_ownable::assert_owner(deps.storage, info.sender.as_str())?;
@@ -82,28 +118,17 @@ pub fn withdraw(
         None => info.sender.to_string(),
     };
     let balances: Vec<cw_std::Coin> =
-        query_bank_balances(contract_addr, deps.as_ref())?;
+        query_bank_balances(contract_addr.clone(), deps.as_ref())?;
     let balances: Vec<cw_std::Coin> = balances
         .iter()
         .filter(|b_coin| denoms.contains(&b_coin.denom))
         .cloned()
         .collect();
-
-    let tx_msg = BankMsg::Send {
-        to_address: to_addr.to_string(),
-        amount: balances.clone(),
-    };
-    let event =
-        event_withdraw(serde_json::to_string(&balances)?.as_str(), &to_addr);
-    LOGS.push_front(
-        deps.storage,
-        &Log {
-            block_height: env.block.height,
-            sender_addr: info.sender.to_string(),
-            event: event.clone(),
-        },
-    )?;
-    Ok(Response::new().add_message(tx_msg).add_event(event))
+    for denom in &assets {
+        denom.validate(deps.api)?;
+    }
+    let assets = assets::query_balances(&deps.querier, &contract_addr, assets)?;
+    send_withdrawal(deps, env, info, to_addr, balances, assets, contract_addr)
 }
 
 pub fn withdraw_all(
@@ -118,14 +143,43 @@ pub fn withdraw_all(
         Some(given_to_addr) => given_to_addr,
         None => info.sender.to_string(),
     };
-    let balances = query_bank_balances(contract_addr, deps.as_ref())?;
-    let tx_msg = BankMsg::Send {
-        to_address: to_addr.to_string(),
-        amount: balances.clone(),
-    };
-    let event =
+    let balances = query_bank_balances(contract_addr.clone(), deps.as_ref())?;
+    let cw20s = assets::cw20s(deps.storage)?
+        .into_iter()
+        .map(assets::Denom::Cw20);
+    let assets = assets::query_balances(&deps.querier, &contract_addr, cw20s)?;
+    send_withdrawal(deps, env, info, to_addr, balances, assets, contract_addr)
+}
+
+/// Sends the withdrawn coins and assets to `to_addr` and logs the withdrawal.
+fn send_withdrawal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    to_addr: String,
+    balances: Vec<Coin>,
+    assets: Vec<Asset>,
+    contract_addr: String,
+) -> Result<Response, ContractError> {
+    let mut tx_msgs: Vec<CosmosMsg> = Vec::new();
+    if !balances.is_empty() {
+        tx_msgs.push(
+            BankMsg::Send {
+                to_address: to_addr.to_string(),
+                amount: balances.clone(),
+            }
+            .into(),
+        );
+    }
+    for asset in assets.iter() {
+        tx_msgs.push(asset.send_msg(&contract_addr, &to_addr)?);
+    }
+    let mut event =
         event_withdraw(serde_json::to_string(&balances)?.as_str(), &to_addr);
-    LOGS.push_front(
+    if !assets.is_empty() {
+        event = event.add_attribute("assets", serde_json::to_string(&assets)?);
+    }
+    logs::push(
         deps.storage,
         &Log {
             block_height: env.block.height,
@@ -133,7 +187,7 @@ pub fn withdraw_all(
             event: event.clone(),
         },
     )?;
-    Ok(Response::new().add_message(tx_msg).add_event(event))
+    Ok(Response::new().add_messages(tx_msgs).add_event(event))
 }
 
 pub fn edit_opers(
@@ -143,11 +197,9 @@ pub fn edit_opers(
     action: oper_perms::Action,
 ) -> Result<Response, ContractError> {
     nibiru_ownable::assert_owner(deps.storage, info.sender.as_str())?;
-    let mut perms = Permissions::load(deps.storage)?;
     match action {
         oper_perms::Action::AddOper { address } => {
-            perms.operators.insert(address.clone());
-            OPERATORS.save(deps.storage, &perms.operators)?;
+            nibiru_perms::add_member(deps.storage, OPERATOR_ROLE, &address)?;
 
             let res = Response::new().add_attributes(vec![
                 attr("action", "add_operator"),
@@ -157,8 +209,8 @@ pub fn edit_opers(
         }
 
         oper_perms::Action::RemoveOper { address } => {
-            perms.operators.remove(address.as_str());
-            OPERATORS.save(deps.storage, &perms.operators)?;
+            nibiru_perms::remove_member(deps.storage, OPERATOR_ROLE, &address)?;
+            spend_limits::clear_operator(deps.storage, &address)?;
 
             let res = Response::new().add_attributes(vec![
                 attr("action", "remove_operator"),
@@ -169,6 +221,103 @@ pub fn edit_opers(
     }
 }
 
+pub fn edit_spend_limits(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    action: spend_limits::Action,
+) -> Result<Response, ContractError> {
+    nibiru_ownable::assert_owner(deps.storage, info.sender.as_str())?;
+    match action {
+        spend_limits::Action::SetLimit {
+            operator,
+            denom,
+            limit,
+        } => {
+            let operator = deps.api.addr_validate(&operator)?.into_string();
+            limit.validate()?;
+            SPEND_LIMITS.save(deps.storage, (&operator, &denom), &limit)?;
+
+            let res = Response::new().add_attributes(vec![
+                attr("action", "set_spend_limit"),
+                attr("operator", operator),
+                attr("denom", denom),
+                attr("limit", serde_json::to_string(&limit)?),
+            ]);
+            Ok(res)
+        }
+
+        spend_limits::Action::RemoveLimit { operator, denom } => {
+            SPEND_LIMITS.remove(deps.storage, (&operator, &denom));
+            SPENDS.remove(deps.storage, (&operator, &denom));
+
+            let res = Response::new().add_attributes(vec![
+                attr("action", "remove_spend_limit"),
+                attr("operator", operator),
+                attr("denom", denom),
+            ]);
+            Ok(res)
+        }
+    }
+}
+
+pub fn edit_to_addrs(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: to_addrs::Action,
+) -> Result<Response, ContractError> {
+    nibiru_ownable::assert_owner(deps.storage, info.sender.as_str())?;
+    let event = match action {
+        to_addrs::Action::AddToAddr { recipient } => {
+            recipient.validate(deps.api, &env.block)?;
+            recipient.save(deps.storage)?;
+            let recipient_json = serde_json::to_string(&recipient)?;
+            event_add_to_addr(&recipient_json, &recipient.address)
+        }
+        to_addrs::Action::RemoveToAddr { address } => {
+            to_addrs::remove(deps.storage, &address)?;
+            event_remove_to_addr(&address)
+        }
+        to_addrs::Action::ReplaceToAddrs { recipients } => {
+            for recipient in &recipients {
+                recipient.validate(deps.api, &env.block)?;
+            }
+            to_addrs::replace(deps.storage, &recipients)?;
+            event_replace_to_addrs(&serde_json::to_string(&recipients)?)
+        }
+    };
+    Ok(Response::new().add_event(event))
+}
+
+pub fn edit_cw20s(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    action: assets::Action,
+) -> Result<Response, ContractError> {
+    nibiru_ownable::assert_owner(deps.storage, info.sender.as_str())?;
+    let is_add = matches!(action, assets::Action::AddCw20 { .. });
+    let cw20_addr = assets::edit_cw20s(deps.storage, deps.api, action)?;
+    let event = match is_add {
+        true => event_add_cw20(&cw20_addr),
+        false => event_remove_cw20(&cw20_addr),
+    };
+    Ok(Response::new().add_event(event))
+}
+
+pub fn prune_logs(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    prune: logs::Prune,
+) -> Result<Response, ContractError> {
+    nibiru_ownable::assert_owner(deps.storage, info.sender.as_str())?;
+    let pruned = logs::prune(deps.storage, prune)?;
+    let remaining = LOGS.len(deps.storage)?;
+    Ok(Response::new().add_event(event_prune_logs(pruned, remaining)))
+}
+
 pub fn toggle_halt(
     deps: DepsMut,
     _env: Env,
@@ -185,6 +334,7 @@ pub fn bank_send(
     env: Env,
     info: MessageInfo,
     coins: Vec<cw_std::Coin>,
+    assets: Vec<Asset>,
     to: String,
 ) -> Result<Response, ContractError> {
     // assert sender is operator
@@ -193,17 +343,52 @@ pub fn bank_send(
     let is_halted = IS_HALTED.load(deps.storage)?;
     assert_not_halted(is_halted)?;
 
-    // assert: Recipient addr must be in the TO_ADDRS set.
-    if !TO_ADDRS.load(deps.storage)?.contains(&to) {
-        return Err(ContractError::ToAddrNotAllowed {
-            to_addr: to.to_string(),
-        });
+    for asset in &assets {
+        asset.denom.validate(deps.api)?;
+    }
+
+    // Coins and assets together, with the assets in their `Denom::key`.
+    let keyed_coins: Vec<Coin> = coins
+        .iter()
+        .cloned()
+        .chain(assets.iter().map(Asset::keyed_coin))
+        .collect();
+
+    // assert: Recipient addr must be in the TO_ADDRS set, unexpired, and
+    // allowed to receive the denoms.
+    Recipient::load(deps.storage, &to)?
+        .assert_can_receive(&env.block, &keyed_coins)?;
+
+    // assert: Coins must be within the spend limits of the sender.
+    spend_limits::consume(
+        deps.storage,
+        &env.block,
+        info.sender.as_str(),
+        &keyed_coins,
+    )?;
+
+    let mut tx_msgs: Vec<CosmosMsg> = Vec::new();
+    if !coins.is_empty() || assets.is_empty() {
+        tx_msgs.push(
+            BankMsg::Send {
+                to_address: to.clone(),
+                amount: coins.clone(),
+            }
+            .into(),
+        );
+    }
+    let contract_addr = env.contract.address.to_string();
+    for asset in assets.iter() {
+        tx_msgs.push(asset.send_msg(&contract_addr, &to)?);
     }
 
     // Events and tx history logging
     let coins_json = serde_json::to_string(&coins)?;
-    let event = event_bank_send(&coins_json, info.sender.as_str());
-    LOGS.push_front(
+    let mut event = event_bank_send(&coins_json, info.sender.as_str());
+    if !assets.is_empty() {
+        event = event.add_attribute("assets", serde_json::to_string(&assets)?);
+    }
+    logs::push(
         deps.storage,
         &Log {
             block_height: env.block.height,
@@ -212,13 +397,8 @@ pub fn bank_send(
         },
     )?;
 
-    // Reply with TxMsg to send funds
-    Ok(Response::new()
-        .add_message(BankMsg::Send {
-            to_address: to,
-            amount: coins,
-        })
-        .add_event(event))
+    // Reply with TxMsgs to send funds
+    Ok(Response::new().add_messages(tx_msgs).add_event(event))
 }
 
 pub fn execute_update_ownership(
@@ -249,7 +429,7 @@ pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 #[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
 pub fn query(
     deps: Deps,
-    _env: Env,
+    env: Env,
     msg: QueryMsg,
 ) -> Result<Binary, ContractError> {
     match msg {
@@ -257,6 +437,33 @@ pub fn query(
             let perms_status: PermsStatus = query_perms_status(deps)?;
             Ok(to_json_binary(&perms_status)?)
         }
+        QueryMsg::Allowances {
+            operator,
+            start_after,
+            limit,
+        } => Ok(to_json_binary(&spend_limits::query_allowances(
+            deps.storage,
+            &env.block,
+            operator,
+            start_after,
+            limit,
+        )?)?),
+        QueryMsg::Recipients {} => {
+            Ok(to_json_binary(&to_addrs::query_recipients(deps.storage)?)?)
+        }
+        QueryMsg::Cw20s {} => Ok(to_json_binary(&assets::Cw20sResponse {
+            cw20s: assets::cw20s(deps.storage)?,
+        })?),
+        QueryMsg::Logs {
+            start_after,
+            limit,
+            filter,
+        } => Ok(to_json_binary(&logs::query_logs(
+            deps.storage,
+            start_after,
+            limit,
+            filter,
+        )?)?),
         QueryMsg::Ownership {} => Ok(to_json_binary(
             &nibiru_ownable::get_ownership(deps.storage)?,
         )?),
@@ -312,16 +519,25 @@ pub mod tests {
 
     use cosmwasm_std::{self as cw_std};
     use cw_std::{
-        from_json, testing, BankMsg, Coin, CosmosMsg, Response, SubMsg, Uint128,
+        from_json, testing, to_json_binary, BankMsg, Coin, ContractResult,
+        CosmosMsg, Event, Response, SubMsg, SystemError, SystemResult, Uint128,
+        WasmMsg, WasmQuery,
     };
     use nibiru_std::errors::TestResult;
     use serde::Serialize;
 
     use crate::{
-        contract::{execute, query},
-        msgs::{ExecuteMsg, PermsStatus, QueryMsg},
+        assets,
+        contract::{execute, instantiate, migrate, query},
+        error::ContractError,
+        logs::{self, LogsFilter, LogsResponse},
+        msgs::{ExecuteMsg, InstantiateMsg, MigrateMsg, PermsStatus, QueryMsg},
         oper_perms::{self, Permissions},
-        state::{IS_HALTED, OPERATORS},
+        spend_limits::{
+            self, Allowance, AllowancesResponse, SpendLimit, Window, WindowLimit,
+        },
+        state::{Log, IS_HALTED, LEGACY_OPERATORS, TO_ADDRS},
+        to_addrs::{self, Recipient, RecipientsResponse},
         tutil::{
             self, mock_info_for_sender, setup_contract, setup_contract_defaults,
             TEST_OWNER,
@@ -382,6 +598,7 @@ pub mod tests {
                 exec_msg: ExecuteMsg::Withdraw {
                     to: Some(String::from("mm_bybit")),
                     denoms: vec![].into_iter().collect(),
+                    assets: vec![],
                 },
                 err: want_err,
                 contract_funds_start: None,
@@ -435,7 +652,7 @@ pub mod tests {
             ["mm_kucoin", "mm_bybit"].map(|s| s.to_string());
         let opers: [String; 1] = ["valid_oper"].map(|s| s.to_string());
         let test_cases: Vec<TestCaseExec> = vec![
-            // WithdrawAll
+            // WithdrawAll / Empty balance sends nothing
             TestCaseExec {
                 to_addrs: to_addrs.to_vec(),
                 opers: opers.to_vec(),
@@ -445,11 +662,7 @@ pub mod tests {
                 },
                 err: None,
                 contract_funds_start: None,
-                resp_msgs: vec![BankMsg::Send {
-                    to_address: String::from("mm_bybit"),
-                    amount: vec![],
-                }
-                .into()],
+                resp_msgs: vec![],
             },
             // WithdrawAll / Nonzero amount
             TestCaseExec {
@@ -484,6 +697,7 @@ pub mod tests {
                         .iter()
                         .map(|str| String::from(*str))
                         .collect(),
+                    assets: vec![],
                 },
                 err: None,
                 contract_funds_start: Some(vec![
@@ -685,7 +899,8 @@ pub mod tests {
         for member in opers_start.iter() {
             perms.operators.insert(member.clone());
         }
-        let res = OPERATORS.save(deps.as_mut().storage, &perms.operators);
+        let res =
+            oper_perms::save_operators(deps.as_mut().storage, &perms.operators);
         assert!(res.is_ok());
 
         // Remove a member from the whitelist
@@ -756,6 +971,7 @@ pub mod tests {
         // Success case: valid operator sends coins to an allowed address
         let exec_msg = ExecuteMsg::BankSend {
             coins: coins.clone(),
+            assets: vec![],
             to: String::from("mm_bybit"),
         };
         let sender = "valid_oper";
@@ -772,6 +988,7 @@ pub mod tests {
         // Error case: unauthorized sender
         let exec_msg = ExecuteMsg::BankSend {
             coins: coins.clone(),
+            assets: vec![],
             to: String::from("mm_bybit"),
         };
         let sender = "invalid_sender";
@@ -782,6 +999,7 @@ pub mod tests {
         // Error case: sending to an address not in the allowed list
         let exec_msg = ExecuteMsg::BankSend {
             coins,
+            assets: vec![],
             to: String::from("not_allowed_addr"),
         };
         let sender = "valid_oper";
@@ -791,4 +1009,865 @@ pub mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn exec_bank_send_spend_limits() -> TestResult {
+        let (mut deps, mut env, owner_info) = setup_contract_defaults()?;
+        let oper = mock_info_for_sender("oper0");
+        let bank_send = |amount: u128, denom: &str| ExecuteMsg::BankSend {
+            coins: vec![Coin::new(amount, denom)],
+            assets: vec![],
+            to: String::from("to_addr0"),
+        };
+        let set_limit = |limit: SpendLimit| {
+            ExecuteMsg::EditSpendLimits(spend_limits::Action::SetLimit {
+                operator: String::from("oper0"),
+                denom: String::from("unibi"),
+                limit,
+            })
+        };
+
+        // Only the owner can set limits, and they must be valid
+        let limit = SpendLimit {
+            per_tx: Some(Uint128::new(100)),
+            per_window: Some(WindowLimit {
+                amount: Uint128::new(250),
+                window: Window::Seconds(3600),
+            }),
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            oper.clone(),
+            set_limit(limit.clone()),
+        );
+        assert!(res.is_err());
+        for (bad_limit, want_err) in [
+            (
+                SpendLimit {
+                    per_tx: None,
+                    per_window: None,
+                },
+                "set at least one",
+            ),
+            (
+                SpendLimit {
+                    per_tx: None,
+                    per_window: Some(WindowLimit {
+                        amount: Uint128::new(1),
+                        window: Window::Blocks(0),
+                    }),
+                },
+                "window length must be positive",
+            ),
+        ] {
+            let err = execute(
+                deps.as_mut(),
+                env.clone(),
+                owner_info.clone(),
+                set_limit(bad_limit),
+            )
+            .expect_err("limit should be invalid");
+            assert!(err.to_string().contains(want_err), "got {err}");
+        }
+        // A mistyped operator is rejected instead of capping nobody
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            owner_info.clone(),
+            ExecuteMsg::EditSpendLimits(spend_limits::Action::SetLimit {
+                operator: String::from("OPER0"),
+                denom: String::from("unibi"),
+                limit: limit.clone(),
+            }),
+        )
+        .expect_err("operator should be invalid");
+        assert!(err.to_string().contains("not normalized"), "got {err}");
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            owner_info.clone(),
+            set_limit(limit.clone()),
+        )?;
+
+        // Per-transaction limit
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            oper.clone(),
+            bank_send(101, "unibi"),
+        )
+        .expect_err("send should exceed the per-tx limit");
+        assert_eq!(
+            err,
+            ContractError::TxLimitExceeded {
+                operator: String::from("oper0"),
+                denom: String::from("unibi"),
+                amount: Uint128::new(101),
+                limit: Uint128::new(100),
+            }
+        );
+
+        // Window limit: 100 + 100 fits, then only 50 is left
+        for _ in 0..2 {
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                oper.clone(),
+                bank_send(100, "unibi"),
+            )?;
+            env.block.time = env.block.time.plus_seconds(60);
+        }
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            oper.clone(),
+            bank_send(51, "unibi"),
+        )
+        .expect_err("send should exceed the window limit");
+        assert_eq!(
+            err,
+            ContractError::WindowLimitExceeded {
+                operator: String::from("oper0"),
+                denom: String::from("unibi"),
+                amount: Uint128::new(51),
+                remaining: Uint128::new(50),
+            }
+        );
+
+        // A multi-coin send over the limit records nothing, and denoms
+        // without a limit are uncapped.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            oper.clone(),
+            ExecuteMsg::BankSend {
+                coins: vec![
+                    Coin::new(1_000_000, "uusd"),
+                    Coin::new(60, "unibi"),
+                ],
+                assets: vec![],
+                to: String::from("to_addr0"),
+            },
+        );
+        assert!(res.is_err());
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            oper.clone(),
+            bank_send(1_000_000, "uusd"),
+        )?;
+
+        let query_allowances = |deps: cw_std::Deps,
+                                env: cw_std::Env|
+         -> anyhow::Result<AllowancesResponse> {
+            Ok(from_json(query(
+                deps,
+                env,
+                QueryMsg::Allowances {
+                    operator: None,
+                    start_after: None,
+                    limit: None,
+                },
+            )?)?)
+        };
+        assert_eq!(
+            query_allowances(deps.as_ref(), env.clone())?.allowances,
+            vec![Allowance {
+                operator: String::from("oper0"),
+                denom: String::from("unibi"),
+                limit: limit.clone(),
+                spent_in_window: Uint128::new(200),
+                remaining: Some(Uint128::new(50)),
+            }]
+        );
+
+        // The first send leaves the rolling window after an hour
+        env.block.time = env.block.time.plus_seconds(3600 - 120);
+        let resp = query_allowances(deps.as_ref(), env.clone())?;
+        assert_eq!(resp.allowances[0].remaining, Some(Uint128::new(100)));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            oper.clone(),
+            bank_send(100, "unibi"),
+        )?;
+
+        // Window in blocks
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            owner_info.clone(),
+            set_limit(SpendLimit {
+                per_tx: None,
+                per_window: Some(WindowLimit {
+                    amount: Uint128::new(200),
+                    window: Window::Blocks(10),
+                }),
+            }),
+        )?;
+        assert!(execute(
+            deps.as_mut(),
+            env.clone(),
+            oper.clone(),
+            bank_send(1, "unibi")
+        )
+        .is_err());
+        env.block.height += 10;
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            oper.clone(),
+            bank_send(200, "unibi"),
+        )?;
+
+        // Other operators and the query for one operator
+        let resp: AllowancesResponse = from_json(query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Allowances {
+                operator: Some(String::from("oper1")),
+                start_after: None,
+                limit: None,
+            },
+        )?)?;
+        assert_eq!(resp.allowances, vec![]);
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info_for_sender("oper1"),
+            bank_send(1_000, "unibi"),
+        )?;
+
+        // Removing the operator clears its limits
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            owner_info,
+            ExecuteMsg::EditOpers(oper_perms::Action::RemoveOper {
+                address: String::from("oper0"),
+            }),
+        )?;
+        assert_eq!(query_allowances(deps.as_ref(), env)?.allowances, vec![]);
+        Ok(())
+    }
+
+    #[test]
+    fn query_allowances_paginates() -> TestResult {
+        let (mut deps, env, owner_info) = setup_contract_defaults()?;
+        let limit = SpendLimit {
+            per_tx: Some(Uint128::new(100)),
+            per_window: None,
+        };
+        for (operator, denom) in
+            [("oper0", "unibi"), ("oper0", "uusd"), ("oper1", "unibi")]
+        {
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                owner_info.clone(),
+                ExecuteMsg::EditSpendLimits(spend_limits::Action::SetLimit {
+                    operator: String::from(operator),
+                    denom: String::from(denom),
+                    limit: limit.clone(),
+                }),
+            )?;
+        }
+        let query_page = |operator: Option<&str>,
+                          start_after: Option<(String, String)>|
+         -> anyhow::Result<AllowancesResponse> {
+            Ok(from_json(query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::Allowances {
+                    operator: operator.map(String::from),
+                    start_after,
+                    limit: Some(2),
+                },
+            )?)?)
+        };
+        let keys = |resp: &AllowancesResponse| -> Vec<(String, String)> {
+            resp.allowances
+                .iter()
+                .map(|a| (a.operator.clone(), a.denom.clone()))
+                .collect()
+        };
+        let key = |operator: &str, denom: &str| {
+            (String::from(operator), String::from(denom))
+        };
+
+        // All operators
+        let resp = query_page(None, None)?;
+        assert_eq!(
+            keys(&resp),
+            vec![key("oper0", "unibi"), key("oper0", "uusd")]
+        );
+        assert_eq!(resp.next_start_after, Some(key("oper0", "uusd")));
+        let resp = query_page(None, resp.next_start_after)?;
+        assert_eq!(keys(&resp), vec![key("oper1", "unibi")]);
+        assert_eq!(resp.next_start_after, None);
+
+        // One operator
+        let resp = query_page(Some("oper0"), Some(key("oper0", "unibi")))?;
+        assert_eq!(keys(&resp), vec![key("oper0", "uusd")]);
+        assert_eq!(resp.next_start_after, None);
+        Ok(())
+    }
+
+    /// Sends one coin from each of `senders`, one block apart, starting at
+    /// block 1.
+    fn send_from_each(
+        mut deps: cw_std::DepsMut,
+        senders: &[&str],
+    ) -> TestResult {
+        for (idx, sender) in senders.iter().enumerate() {
+            let env = tutil::mock_env_height(idx as u64 + 1);
+            let exec_msg = ExecuteMsg::BankSend {
+                coins: vec![Coin::new(1, "unibi")],
+                assets: vec![],
+                to: String::from("to_addr0"),
+            };
+            execute(deps.branch(), env, mock_info_for_sender(sender), exec_msg)?;
+        }
+        Ok(())
+    }
+
+    fn query_logs(
+        deps: cw_std::Deps,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        filter: Option<LogsFilter>,
+    ) -> anyhow::Result<LogsResponse> {
+        let query_msg = QueryMsg::Logs {
+            start_after,
+            limit,
+            filter,
+        };
+        Ok(from_json(query(deps, testing::mock_env(), query_msg)?)?)
+    }
+
+    #[test]
+    fn query_logs_pages() -> TestResult {
+        let (mut deps, _env, _info) = setup_contract_defaults()?;
+        let senders = ["oper0", "oper1", "oper0", "oper0", "oper1"];
+        send_from_each(deps.as_mut(), &senders)?;
+
+        // Newest first, two per page
+        let mut seqs: Vec<u64> = vec![];
+        let mut start_after = None;
+        loop {
+            let resp = query_logs(deps.as_ref(), start_after, Some(2), None)?;
+            seqs.extend(resp.logs.iter().map(|entry| entry.seq));
+            start_after = resp.next_start_after;
+            if start_after.is_none() {
+                break;
+            }
+        }
+        assert_eq!(seqs, vec![4, 3, 2, 1, 0]);
+
+        let test_cases: Vec<(LogsFilter, Vec<u64>)> = vec![
+            (
+                LogsFilter {
+                    sender: Some(String::from("oper1")),
+                    ..Default::default()
+                },
+                vec![4, 1],
+            ),
+            (
+                LogsFilter {
+                    min_height: Some(2),
+                    max_height: Some(4),
+                    ..Default::default()
+                },
+                vec![3, 2, 1],
+            ),
+            (
+                LogsFilter {
+                    sender: Some(String::from("oper0")),
+                    event_type: Some(String::from("broker_bank/send")),
+                    max_height: Some(3),
+                    ..Default::default()
+                },
+                vec![2, 0],
+            ),
+            (
+                LogsFilter {
+                    event_type: Some(String::from("broker_bank/withdraw")),
+                    ..Default::default()
+                },
+                vec![],
+            ),
+        ];
+        for (filter, want_seqs) in test_cases {
+            let resp =
+                query_logs(deps.as_ref(), None, None, Some(filter.clone()))?;
+            let got_seqs: Vec<u64> =
+                resp.logs.iter().map(|entry| entry.seq).collect();
+            assert_eq!(got_seqs, want_seqs, "filter: {filter:?}");
+            for entry in resp.logs {
+                assert_eq!(entry.log.block_height, entry.seq + 1);
+                assert_eq!(entry.log.sender_addr, senders[entry.seq as usize]);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn exec_prune_logs() -> TestResult {
+        let (mut deps, env, owner_info) = setup_contract_defaults()?;
+        send_from_each(deps.as_mut(), &["oper0"; 6])?;
+
+        let prune = |prune: logs::Prune| ExecuteMsg::PruneLogs(prune);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info_for_sender("oper0"),
+            prune(logs::Prune::KeepLast(0)),
+        );
+        assert!(res.is_err(), "only the owner can prune");
+
+        // Logs at heights 1 and 2 go, and seqs stay the same
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            owner_info.clone(),
+            prune(logs::Prune::BeforeHeight(3)),
+        )?;
+        assert_eq!(res.events[0].attributes[0].value, "2");
+        assert_eq!(res.events[0].attributes[1].value, "4");
+        let resp = query_logs(deps.as_ref(), Some(4), None, None)?;
+        let seqs: Vec<u64> = resp.logs.iter().map(|entry| entry.seq).collect();
+        assert_eq!(seqs, vec![3, 2]);
+        assert_eq!(resp.next_start_after, None);
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            owner_info,
+            prune(logs::Prune::KeepLast(1)),
+        )?;
+        let resp = query_logs(deps.as_ref(), None, None, None)?;
+        let seqs: Vec<u64> = resp.logs.iter().map(|entry| entry.seq).collect();
+        assert_eq!(seqs, vec![5]);
+
+        // A cursor that was pruned past returns nothing
+        let resp = query_logs(deps.as_ref(), Some(3), None, None)?;
+        assert_eq!(resp.logs, vec![]);
+        Ok(())
+    }
+
+    #[test]
+    fn prune_logs_is_capped() -> TestResult {
+        let (mut deps, env, owner_info) = setup_contract_defaults()?;
+        let num_logs = logs::MAX_PRUNE as u64 + 5;
+        for height in 1..=num_logs {
+            let log = Log {
+                block_height: height,
+                sender_addr: String::from("oper0"),
+                event: Event::new("broker_bank/test"),
+            };
+            logs::push(deps.as_mut().storage, &log)?;
+        }
+
+        // The first call stops at the cap and reports what is left
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            owner_info.clone(),
+            ExecuteMsg::PruneLogs(logs::Prune::KeepLast(0)),
+        )?;
+        let pruned = logs::MAX_PRUNE.to_string();
+        assert_eq!(res.events[0].attributes[0].value, pruned);
+        assert_eq!(res.events[0].attributes[1].value, "5");
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            owner_info,
+            ExecuteMsg::PruneLogs(logs::Prune::KeepLast(0)),
+        )?;
+        assert_eq!(res.events[0].attributes[0].value, "5");
+        assert_eq!(res.events[0].attributes[1].value, "0");
+        Ok(())
+    }
+
+    #[test]
+    fn log_retention() -> TestResult {
+        let mut deps = testing::mock_dependencies();
+        let msg = InstantiateMsg {
+            owner: String::from(TEST_OWNER),
+            to_addrs: [String::from("to_addr0")].into(),
+            opers: [String::from("oper0")].into(),
+            log_retention: Some(3),
+        };
+        instantiate(
+            deps.as_mut(),
+            testing::mock_env(),
+            mock_info_for_sender(TEST_OWNER),
+            msg,
+        )?;
+        send_from_each(deps.as_mut(), &["oper0"; 5])?;
+
+        let resp = query_logs(deps.as_ref(), None, None, None)?;
+        let seqs: Vec<u64> = resp.logs.iter().map(|entry| entry.seq).collect();
+        assert_eq!(seqs, vec![4, 3, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn exec_edit_to_addrs() -> TestResult {
+        let (mut deps, env, owner_info) = setup_contract_defaults()?;
+        let query_recipients =
+            |deps: cw_std::Deps| -> anyhow::Result<Vec<Recipient>> {
+                let resp: RecipientsResponse = from_json(query(
+                    deps,
+                    testing::mock_env(),
+                    QueryMsg::Recipients {},
+                )?)?;
+                Ok(resp.recipients)
+            };
+        let bank_send = |to: &str, denom: &str| ExecuteMsg::BankSend {
+            coins: vec![Coin::new(1, denom)],
+            assets: vec![],
+            to: String::from(to),
+        };
+        let oper = mock_info_for_sender("oper0");
+
+        // Addresses from instantiation have no restrictions
+        assert_eq!(
+            query_recipients(deps.as_ref())?,
+            vec![
+                Recipient::unrestricted("to_addr0"),
+                Recipient::unrestricted("to_addr1"),
+            ]
+        );
+
+        let expires_at = env.block.time.plus_seconds(100);
+        let mm_kucoin = Recipient {
+            address: String::from("mm_kucoin"),
+            label: String::from("Kucoin market maker"),
+            expires_at: Some(expires_at),
+            denoms: Some(BTreeSet::from([String::from("unibi")])),
+        };
+        let add = ExecuteMsg::EditToAddrs(to_addrs::Action::AddToAddr {
+            recipient: mm_kucoin.clone(),
+        });
+        let res = execute(deps.as_mut(), env.clone(), oper.clone(), add.clone());
+        assert!(res.is_err(), "only the owner can edit the recipients");
+        let res = execute(deps.as_mut(), env.clone(), owner_info.clone(), add)?;
+        assert_eq!(res.events[0].ty, "broker_bank/add_to_addr");
+
+        // Denom restrictions and expiry
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            oper.clone(),
+            bank_send("mm_kucoin", "unibi"),
+        )?;
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            oper.clone(),
+            bank_send("mm_kucoin", "uusd"),
+        )
+        .expect_err("mm_kucoin cannot receive uusd");
+        assert_eq!(
+            err,
+            ContractError::DenomNotAllowedForToAddr {
+                to_addr: String::from("mm_kucoin"),
+                denom: String::from("uusd"),
+            }
+        );
+        let mut env_expired = env.clone();
+        env_expired.block.time = expires_at;
+        let err = execute(
+            deps.as_mut(),
+            env_expired,
+            oper.clone(),
+            bank_send("mm_kucoin", "unibi"),
+        )
+        .expect_err("mm_kucoin has expired");
+        assert_eq!(
+            err,
+            ContractError::ToAddrExpired {
+                to_addr: String::from("mm_kucoin"),
+                expires_at,
+            }
+        );
+
+        // Invalid recipients
+        for (recipient, want_err) in [
+            (
+                Recipient {
+                    expires_at: Some(env.block.time),
+                    ..mm_kucoin.clone()
+                },
+                "expiry must be in the future",
+            ),
+            (
+                Recipient {
+                    denoms: Some(BTreeSet::new()),
+                    ..mm_kucoin.clone()
+                },
+                "denoms must not be empty",
+            ),
+            (
+                Recipient {
+                    address: String::from("MM_KUCOIN"),
+                    ..mm_kucoin.clone()
+                },
+                "address not normalized",
+            ),
+        ] {
+            for action in [
+                to_addrs::Action::AddToAddr {
+                    recipient: recipient.clone(),
+                },
+                to_addrs::Action::ReplaceToAddrs {
+                    recipients: vec![recipient],
+                },
+            ] {
+                let err = execute(
+                    deps.as_mut(),
+                    env.clone(),
+                    owner_info.clone(),
+                    ExecuteMsg::EditToAddrs(action),
+                )
+                .expect_err("recipient should be invalid");
+                assert!(err.to_string().contains(want_err), "got {err}");
+            }
+        }
+
+        // Remove
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            owner_info.clone(),
+            ExecuteMsg::EditToAddrs(to_addrs::Action::RemoveToAddr {
+                address: String::from("to_addr0"),
+            }),
+        )?;
+        assert_eq!(res.events[0].ty, "broker_bank/remove_to_addr");
+        assert!(execute(
+            deps.as_mut(),
+            env.clone(),
+            oper.clone(),
+            bank_send("to_addr0", "unibi"),
+        )
+        .is_err());
+        assert_eq!(
+            query_recipients(deps.as_ref())?,
+            vec![mm_kucoin, Recipient::unrestricted("to_addr1")]
+        );
+
+        // Replace
+        let mm_bybit = Recipient {
+            label: String::from("Bybit market maker"),
+            ..Recipient::unrestricted("mm_bybit")
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            owner_info,
+            ExecuteMsg::EditToAddrs(to_addrs::Action::ReplaceToAddrs {
+                recipients: vec![mm_bybit.clone()],
+            }),
+        )?;
+        assert_eq!(res.events[0].ty, "broker_bank/replace_to_addrs");
+        assert_eq!(query_recipients(deps.as_ref())?, vec![mm_bybit]);
+        execute(deps.as_mut(), env, oper, bank_send("mm_bybit", "uusd"))?;
+        Ok(())
+    }
+
+    #[test]
+    fn exec_cw20_assets() -> TestResult {
+        let (mut deps, env, owner_info) = setup_contract_defaults()?;
+        let contract_addr = env.contract.address.to_string();
+        deps.querier = testing::MockQuerier::new(&[(
+            contract_addr.as_str(),
+            &[Coin::new(420, "unibi")],
+        )]);
+        deps.querier.update_wasm(|query| match query {
+            WasmQuery::Smart { contract_addr, .. }
+                if contract_addr == "token" =>
+            {
+                let balance = Uint128::new(69);
+                let res = to_json_binary(&cw20::BalanceResponse { balance });
+                SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+            }
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: String::from("wasm"),
+            }),
+        });
+        let token = assets::Denom::Cw20(String::from("token"));
+        let transfer = |amount: u128, recipient: &str| -> CosmosMsg {
+            WasmMsg::Execute {
+                contract_addr: String::from("token"),
+                msg: to_json_binary(&cw20::Cw20ExecuteMsg::Transfer {
+                    recipient: String::from(recipient),
+                    amount: Uint128::new(amount),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()
+        };
+
+        // BankSend: Spend limits apply to the asset's denom key
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            owner_info.clone(),
+            ExecuteMsg::EditSpendLimits(spend_limits::Action::SetLimit {
+                operator: String::from("oper0"),
+                denom: token.key(),
+                limit: SpendLimit {
+                    per_tx: Some(Uint128::new(10)),
+                    per_window: None,
+                },
+            }),
+        )?;
+        let bank_send = |amount: u128| ExecuteMsg::BankSend {
+            coins: vec![],
+            assets: vec![assets::Asset {
+                denom: token.clone(),
+                amount: Uint128::new(amount),
+            }],
+            to: String::from("to_addr0"),
+        };
+        let oper = mock_info_for_sender("oper0");
+        let res =
+            execute(deps.as_mut(), env.clone(), oper.clone(), bank_send(10))?;
+        assert_eq!(res.messages, vec![SubMsg::new(transfer(10, "to_addr0"))]);
+        let err = execute(deps.as_mut(), env.clone(), oper, bank_send(11))
+            .expect_err("exceeds the per-tx limit");
+        assert!(matches!(err, ContractError::TxLimitExceeded { .. }));
+
+        // EditCw20s and WithdrawAll
+        let add_cw20 = ExecuteMsg::EditCw20s(assets::Action::AddCw20 {
+            address: String::from("token"),
+        });
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info_for_sender("oper0"),
+            add_cw20.clone(),
+        );
+        assert!(res.is_err(), "only the owner can edit the CW20s");
+        let res =
+            execute(deps.as_mut(), env.clone(), owner_info.clone(), add_cw20)?;
+        assert_eq!(res.events[0].ty, "broker_bank/add_cw20");
+        let resp: assets::Cw20sResponse =
+            from_json(query(deps.as_ref(), env.clone(), QueryMsg::Cw20s {})?)?;
+        assert_eq!(resp.cw20s, BTreeSet::from([String::from("token")]));
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            owner_info.clone(),
+            ExecuteMsg::WithdrawAll { to: None },
+        )?;
+        let bank_msg: CosmosMsg = BankMsg::Send {
+            to_address: String::from(TEST_OWNER),
+            amount: vec![Coin::new(420, "unibi")],
+        }
+        .into();
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(bank_msg), SubMsg::new(transfer(69, TEST_OWNER))]
+        );
+
+        // Withdraw: Only the requested assets
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            owner_info.clone(),
+            ExecuteMsg::Withdraw {
+                to: Some(String::from("to_addr1")),
+                denoms: BTreeSet::new(),
+                assets: vec![token],
+            },
+        )?;
+        assert_eq!(res.messages, vec![SubMsg::new(transfer(69, "to_addr1"))]);
+
+        // WithdrawAll: No bank message without bank balances
+        deps.querier.update_balance(&contract_addr, vec![]);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            owner_info.clone(),
+            ExecuteMsg::WithdrawAll { to: None },
+        )?;
+        assert_eq!(res.messages, vec![SubMsg::new(transfer(69, TEST_OWNER))]);
+
+        // CW20 addresses that are not normalized are rejected
+        let unnormalized = assets::Denom::Cw20(String::from("TOKEN"));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            owner_info,
+            ExecuteMsg::Withdraw {
+                to: None,
+                denoms: BTreeSet::new(),
+                assets: vec![unnormalized.clone()],
+            },
+        )
+        .expect_err("TOKEN is not normalized");
+        assert!(err.to_string().contains("not normalized"), "got {err}");
+        let err = execute(
+            deps.as_mut(),
+            env,
+            mock_info_for_sender("oper0"),
+            ExecuteMsg::BankSend {
+                coins: vec![],
+                assets: vec![assets::Asset {
+                    denom: unnormalized,
+                    amount: Uint128::new(1),
+                }],
+                to: String::from("to_addr0"),
+            },
+        )
+        .expect_err("TOKEN is not normalized");
+        assert!(err.to_string().contains("not normalized"), "got {err}");
+        Ok(())
+    }
+
+    #[test]
+    fn migrate_legacy_operators() -> TestResult {
+        // Storage from before the operators moved to nibiru-perms
+        let mut deps = testing::mock_dependencies();
+        let env = testing::mock_env();
+        let storage = deps.as_mut().storage;
+        nibiru_ownable::initialize_owner(storage, Some(TEST_OWNER))?;
+        TO_ADDRS.save(storage, &BTreeSet::from([String::from("to_addr0")]))?;
+        IS_HALTED.save(storage, &false)?;
+        let opers = BTreeSet::from([String::from("oper0")]);
+        LEGACY_OPERATORS.save(storage, &opers)?;
+
+        migrate(deps.as_mut(), env.clone(), MigrateMsg {})?;
+        assert_eq!(LEGACY_OPERATORS.may_load(&deps.storage)?, None);
+        let perms = Permissions::load(&deps.storage)?;
+        assert_eq!(perms.operators, opers);
+        assert_eq!(perms.owner, Some(String::from(TEST_OWNER)));
+
+        // Permissions are unchanged
+        let bank_send = ExecuteMsg::BankSend {
+            coins: vec![Coin::new(1, "unibi")],
+            assets: vec![],
+            to: String::from("to_addr0"),
+        };
+        for (sender, ok) in
+            [("oper0", true), (TEST_OWNER, true), ("oper1", false)]
+        {
+            let info = mock_info_for_sender(sender);
+            let res =
+                execute(deps.as_mut(), env.clone(), info, bank_send.clone());
+            assert_eq!(res.is_ok(), ok, "sender: {sender}");
+        }
+
+        // Migrating again is a no-op
+        migrate(deps.as_mut(), env, MigrateMsg {})?;
+        assert_eq!(Permissions::load(&deps.storage)?.operators, opers);
+        Ok(())
+    }
 }
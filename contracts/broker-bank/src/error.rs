@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Timestamp, Uint128};
 use std::collections::BTreeSet;
 
 use thiserror::Error;
@@ -25,12 +25,46 @@ pub enum ContractError {
     #[error("recipient address is not whitelisted (to_addr: {to_addr:?}). Query permissions for more info.")]
     ToAddrNotAllowed { to_addr: String },
 
+    #[error("recipient {to_addr:?} expired at {expires_at}")]
+    ToAddrExpired {
+        to_addr: String,
+        expires_at: Timestamp,
+    },
+
+    #[error("recipient {to_addr:?} cannot receive denom {denom:?}")]
+    DenomNotAllowedForToAddr { to_addr: String, denom: String },
+
+    #[error("invalid recipient {address:?}: {reason}")]
+    InvalidRecipient { address: String, reason: String },
+
+    #[error("recipient {to_addr:?} has no EVM address: {reason}")]
+    InvalidEvmRecipient { to_addr: String, reason: String },
+
     #[error("unknown request")]
     UnknownRequest,
 
     #[error("insufficient permissions: address is not a contract operator ({addr:?})")]
     NoOperatorPerms { addr: String },
 
+    #[error("operator {operator} cannot send {amount}{denom} in one transaction: the limit is {limit}{denom}")]
+    TxLimitExceeded {
+        operator: String,
+        denom: String,
+        amount: Uint128,
+        limit: Uint128,
+    },
+
+    #[error("operator {operator} cannot send {amount}{denom}: only {remaining}{denom} is left in the current window")]
+    WindowLimitExceeded {
+        operator: String,
+        denom: String,
+        amount: Uint128,
+        remaining: Uint128,
+    },
+
+    #[error("invalid spend limit: {0}")]
+    InvalidSpendLimit(String),
+
     #[error("no need to add denom {denom} to set {denom_set:?}")]
     AddExistentDenom {
         denom: String,
@@ -19,6 +19,35 @@ pub fn event_withdraw(coins_json: &str, to_addr: &str) -> Event {
         .add_attribute("to_addr", to_addr)
 }
 
+pub fn event_add_cw20(cw20_addr: &str) -> Event {
+    Event::new("broker_bank/add_cw20").add_attribute("cw20_addr", cw20_addr)
+}
+
+pub fn event_remove_cw20(cw20_addr: &str) -> Event {
+    Event::new("broker_bank/remove_cw20").add_attribute("cw20_addr", cw20_addr)
+}
+
+pub fn event_prune_logs(pruned: u64, remaining: u32) -> Event {
+    Event::new("broker_bank/prune_logs")
+        .add_attribute("pruned", pruned.to_string())
+        .add_attribute("remaining", remaining.to_string())
+}
+
+pub fn event_add_to_addr(recipient_json: &str, to_addr: &str) -> Event {
+    Event::new("broker_bank/add_to_addr")
+        .add_attribute("to_addr", to_addr)
+        .add_attribute("recipient", recipient_json)
+}
+
+pub fn event_remove_to_addr(to_addr: &str) -> Event {
+    Event::new("broker_bank/remove_to_addr").add_attribute("to_addr", to_addr)
+}
+
+pub fn event_replace_to_addrs(recipients_json: &str) -> Event {
+    Event::new("broker_bank/replace_to_addrs")
+        .add_attribute("recipients", recipients_json)
+}
+
 pub fn denom_set_json(
     denom_set: BTreeSet<String>,
 ) -> serde_json::Result<String> {
@@ -1,5 +1,9 @@
+pub mod assets;
+pub mod logs;
 pub mod msgs;
 pub mod oper_perms;
+pub mod spend_limits;
+pub mod to_addrs;
 pub mod tutil;
 
 // #[cfg(not(feature = "library"))]
@@ -0,0 +1,145 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{StdResult, Storage};
+
+use crate::state::{Log, LOGS, LOGS_PRUNED, LOG_RETENTION};
+
+pub const DEFAULT_LIMIT: u32 = 10;
+pub const MAX_LIMIT: u32 = 100;
+
+/// Most logs a single `QueryMsg::Logs` reads, matching or not. Keeps queries
+/// with rare filter matches within gas limits. Continue from
+/// `LogsResponse::next_start_after` to scan further.
+pub const MAX_SCAN: u32 = 1_000;
+
+/// Most logs a single `prune` removes. Keeps `ExecuteMsg::PruneLogs` within
+/// gas limits when many logs match. Prune again to remove the rest.
+pub const MAX_PRUNE: u32 = 1_000;
+
+#[cw_serde]
+pub enum Prune {
+    /// Removes the logs from blocks before the given height.
+    BeforeHeight(u64),
+    /// Removes all but the given number of most recent logs.
+    KeepLast(u32),
+}
+
+/// LogsFilter: Conditions that a log must meet to be returned. All fields are
+/// optional, and the height range is inclusive.
+#[cw_serde]
+#[derive(Default)]
+pub struct LogsFilter {
+    pub sender: Option<String>,
+    /// Type of the log's event, Ex.: "broker_bank/send".
+    pub event_type: Option<String>,
+    pub min_height: Option<u64>,
+    pub max_height: Option<u64>,
+}
+
+impl LogsFilter {
+    pub fn matches(&self, log: &Log) -> bool {
+        self.sender.as_ref().is_none_or(|s| *s == log.sender_addr)
+            && self.event_type.as_ref().is_none_or(|t| *t == log.event.ty)
+            && self.min_height.is_none_or(|h| log.block_height >= h)
+            && self.max_height.is_none_or(|h| log.block_height <= h)
+    }
+}
+
+/// LogEntry: A log with its sequence number, which counts every log ever
+/// written, starting from 0. Sequence numbers do not change when older logs
+/// are pruned.
+#[cw_serde]
+pub struct LogEntry {
+    pub seq: u64,
+    pub log: Log,
+}
+
+#[cw_serde]
+pub struct LogsResponse {
+    /// Matching logs, newest first.
+    pub logs: Vec<LogEntry>,
+    /// Cursor for the next page, or `None` if there are no older logs.
+    pub next_start_after: Option<u64>,
+}
+
+/// Appends a log as the newest entry, then drops the oldest entries beyond
+/// the retention cap, if the contract has one.
+pub fn push(storage: &mut dyn Storage, log: &Log) -> StdResult<()> {
+    LOGS.push_front(storage, log)?;
+    if let Some(retention) = LOG_RETENTION.may_load(storage)? {
+        prune(storage, Prune::KeepLast(retention))?;
+    }
+    Ok(())
+}
+
+/// Removes the oldest logs that `prune` selects, at most `MAX_PRUNE`, and
+/// returns how many were removed.
+pub fn prune(storage: &mut dyn Storage, prune: Prune) -> StdResult<u64> {
+    let mut pruned: u64 = 0;
+    while pruned < MAX_PRUNE as u64 {
+        let is_prunable = match prune {
+            Prune::BeforeHeight(height) => LOGS
+                .back(storage)?
+                .is_some_and(|log| log.block_height < height),
+            Prune::KeepLast(count) => LOGS.len(storage)? > count,
+        };
+        if !is_prunable {
+            break;
+        }
+        LOGS.pop_back(storage)?;
+        pruned += 1;
+    }
+    if pruned > 0 {
+        let total = LOGS_PRUNED.may_load(storage)?.unwrap_or_default();
+        LOGS_PRUNED.save(storage, &(total + pruned))?;
+    }
+    Ok(pruned)
+}
+
+/// Pages through the logs from newest to oldest. `start_after` is the `seq`
+/// of the last log of the previous page.
+pub fn query_logs(
+    storage: &dyn Storage,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    filter: Option<LogsFilter>,
+) -> StdResult<LogsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let filter = filter.unwrap_or_default();
+    let len = LOGS.len(storage)? as u64;
+    let pruned = LOGS_PRUNED.may_load(storage)?.unwrap_or_default();
+
+    // The log at position `pos` from the front has seq `pruned + len - 1 - pos`.
+    let next_seq = pruned + len;
+    let start_seq = start_after.map_or(next_seq, |seq| seq.min(next_seq));
+    let mut pos = next_seq - start_seq;
+
+    let mut logs: Vec<LogEntry> = vec![];
+    let mut scanned: u32 = 0;
+    while pos < len && logs.len() < limit && scanned < MAX_SCAN {
+        let Some(log) = LOGS.get(storage, pos as u32)? else {
+            break;
+        };
+        // Heights only decrease from here on.
+        if filter.min_height.is_some_and(|h| log.block_height < h) {
+            pos = len;
+            break;
+        }
+        if filter.matches(&log) {
+            logs.push(LogEntry {
+                seq: next_seq - 1 - pos,
+                log,
+            });
+        }
+        pos += 1;
+        scanned += 1;
+    }
+
+    let next_start_after = match pos < len {
+        true => Some(next_seq - pos),
+        false => None,
+    };
+    Ok(LogsResponse {
+        logs,
+        next_start_after,
+    })
+}
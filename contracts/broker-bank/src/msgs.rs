@@ -3,33 +3,64 @@ use std::collections::BTreeSet;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std as cw;
 
-use crate::oper_perms;
+use crate::{assets, logs, oper_perms, spend_limits, to_addrs};
 
 #[nibiru_ownable::ownable_execute]
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Send coins to an account the set of "TO_ADDRS", appending transaction
-    /// info to the "LOGS". This tx msg emits a "broker/bank/send" event
-    BankSend { coins: Vec<cw::Coin>, to: String },
+    /// info to the "LOGS". This tx msg emits a "broker/bank/send" event.
+    /// Fails if the coins exceed a spend limit of the sender, or if the
+    /// recipient has expired or cannot receive one of the denoms.
+    ///
+    /// The `assets` can also include CW20 tokens and FunTokens, which spend
+    /// limits and recipient restrictions match by `assets::Denom::key`.
+    BankSend {
+        coins: Vec<cw::Coin>,
+        #[serde(default)]
+        assets: Vec<assets::Asset>,
+        to: String,
+    },
 
     /// ToggleHalt: Toggles on or off the ability of the operators to use the
     /// smart contract. Only callable by the contract owner.
     ToggleHalt {},
 
-    /// Withdraw coins from the broker smart contract balance. Only callable by
-    /// the contract owner.
+    /// Withdraw coins from the broker smart contract balance, along with the
+    /// whole balance of each of the `assets`. Only callable by the contract
+    /// owner.
     Withdraw {
         to: Option<String>,
         denoms: BTreeSet<String>,
+        #[serde(default)]
+        assets: Vec<assets::Denom>,
     },
 
-    /// Withdraw all coins from the broker smart contract balance. Only callable
-    /// by the contract owner.
+    /// Withdraw all coins and the balances of the "CW20S" from the broker
+    /// smart contract. Only callable by the contract owner.
     WithdrawAll { to: Option<String> },
 
     /// TODO: owner
     EditOpers(oper_perms::Action),
-    // TODO: feat(broker-bank): Clear logs tx
+
+    /// EditSpendLimits: Sets or removes the per-transaction and rolling
+    /// window caps of an operator for a denom. Only callable by the contract
+    /// owner.
+    EditSpendLimits(spend_limits::Action),
+
+    /// PruneLogs: Removes the oldest entries of the "LOGS", either those from
+    /// before a block height or all but the most recent ones. Removes at most
+    /// `logs::MAX_PRUNE` entries per call. Only callable by the contract owner.
+    PruneLogs(logs::Prune),
+
+    /// EditToAddrs: Adds, removes, or replaces the recipients in the
+    /// "TO_ADDRS", with their labels, expiry, and denom restrictions. Only
+    /// callable by the contract owner.
+    EditToAddrs(to_addrs::Action),
+
+    /// EditCw20s: Adds or removes a CW20 token contract in the "CW20S" that
+    /// `WithdrawAll` sweeps. Only callable by the contract owner.
+    EditCw20s(assets::Action),
 }
 
 #[nibiru_ownable::ownable_query]
@@ -40,6 +71,33 @@ pub enum QueryMsg {
     /// operator set is "halted".
     #[returns(PermsStatus)]
     Perms {},
+
+    /// Allowances: Query the spend limits of an operator, or of all operators
+    /// if none is given, and how much each can still send. Pass the
+    /// `next_start_after` of a response as `start_after` to get the next page.
+    #[returns(spend_limits::AllowancesResponse)]
+    Allowances {
+        operator: Option<String>,
+        start_after: Option<(String, String)>,
+        limit: Option<u32>,
+    },
+
+    /// Logs: Query the "LOGS", newest first. Pass the `next_start_after` of a
+    /// response as `start_after` to get the next page.
+    #[returns(logs::LogsResponse)]
+    Logs {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        filter: Option<logs::LogsFilter>,
+    },
+
+    /// Recipients: Query the "TO_ADDRS" along with their metadata.
+    #[returns(to_addrs::RecipientsResponse)]
+    Recipients {},
+
+    /// Cw20s: Query the CW20 token contracts in the "CW20S".
+    #[returns(assets::Cw20sResponse)]
+    Cw20s {},
 }
 
 #[cw_serde]
@@ -54,4 +112,10 @@ pub struct InstantiateMsg {
     pub owner: String,
     pub to_addrs: BTreeSet<String>,
     pub opers: BTreeSet<String>,
+    /// Maximum number of "LOGS" to keep. The oldest logs beyond it are
+    /// dropped automatically. Unbounded if not given.
+    pub log_retention: Option<u32>,
 }
+
+#[cw_serde]
+pub struct MigrateMsg {}
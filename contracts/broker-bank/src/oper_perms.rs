@@ -1,9 +1,13 @@
 use std::collections::BTreeSet;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Storage;
+use cosmwasm_std::{StdResult, Storage};
+use nibiru_perms::{Actions, Role};
 
-use crate::{error::ContractError, state::OPERATORS};
+use crate::{
+    error::ContractError,
+    state::{LEGACY_OPERATORS, OPERATOR_ROLE},
+};
 
 #[cw_serde]
 pub enum Action {
@@ -46,7 +50,7 @@ impl Permissions {
 
     pub fn load(storage: &dyn Storage) -> Result<Self, ContractError> {
         let owner = nibiru_ownable::get_ownership(storage)?.owner;
-        let opers = OPERATORS.load(storage)?;
+        let opers = nibiru_perms::members(storage, OPERATOR_ROLE)?;
         Ok(Permissions {
             owner: owner.map(|addr| addr.to_string()),
             operators: opers,
@@ -64,3 +68,27 @@ impl Permissions {
         }
     }
 }
+
+/// Replaces the set of operators.
+pub fn save_operators(
+    storage: &mut dyn Storage,
+    operators: &BTreeSet<String>,
+) -> StdResult<()> {
+    let role = Role {
+        members: operators.clone(),
+        actions: Actions::All,
+    };
+    nibiru_perms::save_role(storage, OPERATOR_ROLE, &role)
+}
+
+/// Moves the `LEGACY_OPERATORS` into the `OPERATOR_ROLE`. Does nothing if the
+/// contract has already been migrated.
+pub fn migrate_legacy_operators(storage: &mut dyn Storage) -> StdResult<()> {
+    if let Some(legacy_opers) = LEGACY_OPERATORS.may_load(storage)? {
+        let mut opers = nibiru_perms::members(storage, OPERATOR_ROLE)?;
+        opers.extend(legacy_opers);
+        save_operators(storage, &opers)?;
+        LEGACY_OPERATORS.remove(storage);
+    }
+    Ok(())
+}
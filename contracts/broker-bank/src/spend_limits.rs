@@ -0,0 +1,287 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    BlockInfo, Coin, Order, StdError, StdResult, Storage, Uint128,
+};
+use cw_storage_plus::Bound;
+
+use crate::{
+    error::ContractError,
+    state::{SPENDS, SPEND_LIMITS},
+};
+
+pub const DEFAULT_LIMIT: u32 = 10;
+pub const MAX_LIMIT: u32 = 100;
+
+#[cw_serde]
+pub enum Action {
+    /// Sets the limit of an operator for one denom, replacing any previous
+    /// limit. Spends already in the window still count against the new one.
+    SetLimit {
+        operator: String,
+        denom: String,
+        limit: SpendLimit,
+    },
+    /// Removes the limit of an operator for one denom, along with the record
+    /// of its spends. Sends of that denom become uncapped.
+    RemoveLimit { operator: String, denom: String },
+}
+
+/// SpendLimit: Caps on how much of one denom an operator can send with
+/// `ExecuteMsg::BankSend`. Denoms without a limit are uncapped.
+#[cw_serde]
+pub struct SpendLimit {
+    /// Maximum amount in a single transaction.
+    pub per_tx: Option<Uint128>,
+    /// Maximum amount over any rolling window.
+    pub per_window: Option<WindowLimit>,
+}
+
+#[cw_serde]
+pub struct WindowLimit {
+    pub amount: Uint128,
+    pub window: Window,
+}
+
+/// Window: Length of a rolling window, either in seconds of block time or in
+/// blocks.
+#[cw_serde]
+#[derive(Copy)]
+pub enum Window {
+    Seconds(u64),
+    Blocks(u64),
+}
+
+impl Window {
+    /// Whether `spend` is still inside the window that ends at `block`.
+    pub fn contains(&self, spend: &Spend, block: &BlockInfo) -> bool {
+        match *self {
+            Window::Seconds(secs) => {
+                block.time.seconds().saturating_sub(spend.time) < secs
+            }
+            Window::Blocks(blocks) => {
+                block.height.saturating_sub(spend.height) < blocks
+            }
+        }
+    }
+}
+
+/// Spend: A send of one denom by an operator, recorded to enforce
+/// `WindowLimit`s.
+#[cw_serde]
+pub struct Spend {
+    pub height: u64,
+    /// Block time in seconds.
+    pub time: u64,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct AllowancesResponse {
+    pub allowances: Vec<Allowance>,
+    /// The (operator, denom) of the last allowance if more may follow. Pass it
+    /// as `start_after` to get the next page.
+    pub next_start_after: Option<(String, String)>,
+}
+
+/// Allowance: The limit of an operator for one denom and how much of it is
+/// left at the current block.
+#[cw_serde]
+pub struct Allowance {
+    pub operator: String,
+    pub denom: String,
+    pub limit: SpendLimit,
+    /// Amount sent within the current window.
+    pub spent_in_window: Uint128,
+    /// The most the operator can send right now, or `None` if uncapped.
+    pub remaining: Option<Uint128>,
+}
+
+impl SpendLimit {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if self.per_tx.is_none() && self.per_window.is_none() {
+            return Err(ContractError::InvalidSpendLimit(
+                "set at least one of per_tx and per_window".to_string(),
+            ));
+        }
+        if let Some(WindowLimit {
+            window: Window::Seconds(0) | Window::Blocks(0),
+            ..
+        }) = self.per_window
+        {
+            return Err(ContractError::InvalidSpendLimit(
+                "window length must be positive".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Spends of `denom` by `operator` that are still inside the window of
+/// `limit`. Without a `WindowLimit`, nothing needs to be kept.
+fn spends_in_window(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    operator: &str,
+    denom: &str,
+    limit: &SpendLimit,
+) -> StdResult<Vec<Spend>> {
+    let Some(per_window) = &limit.per_window else {
+        return Ok(vec![]);
+    };
+    let spends = SPENDS.may_load(storage, (operator, denom))?;
+    Ok(spends
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|spend| per_window.window.contains(spend, block))
+        .collect())
+}
+
+/// Checks the coins of a `BankSend` from `operator` against its limits and
+/// records the spends. Fails without recording anything if any denom is over
+/// its limit.
+pub fn consume(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    operator: &str,
+    coins: &[Coin],
+) -> Result<(), ContractError> {
+    let mut amounts: BTreeMap<&str, Uint128> = BTreeMap::new();
+    for coin in coins {
+        let total = amounts.entry(coin.denom.as_str()).or_default();
+        *total = total.checked_add(coin.amount).map_err(StdError::from)?;
+    }
+
+    let mut updates: Vec<(&str, Vec<Spend>)> = vec![];
+    for (denom, amount) in amounts {
+        let Some(limit) = SPEND_LIMITS.may_load(storage, (operator, denom))?
+        else {
+            continue;
+        };
+        if let Some(per_tx) = limit.per_tx {
+            if amount > per_tx {
+                return Err(ContractError::TxLimitExceeded {
+                    operator: operator.to_string(),
+                    denom: denom.to_string(),
+                    amount,
+                    limit: per_tx,
+                });
+            }
+        }
+        let Some(per_window) = &limit.per_window else {
+            continue;
+        };
+        let mut spends =
+            spends_in_window(storage, block, operator, denom, &limit)?;
+        let spent: Uint128 = spends.iter().map(|spend| spend.amount).sum();
+        let remaining = per_window.amount.saturating_sub(spent);
+        if amount > remaining {
+            return Err(ContractError::WindowLimitExceeded {
+                operator: operator.to_string(),
+                denom: denom.to_string(),
+                amount,
+                remaining,
+            });
+        }
+        spends.push(Spend {
+            height: block.height,
+            time: block.time.seconds(),
+            amount,
+        });
+        updates.push((denom, spends));
+    }
+
+    for (denom, spends) in updates {
+        SPENDS.save(storage, (operator, denom), &spends)?;
+    }
+    Ok(())
+}
+
+/// A page of the allowances of one operator, or of every operator with a
+/// limit, ordered by (operator, denom) and starting after `start_after`.
+pub fn query_allowances(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    operator: Option<String>,
+    start_after: Option<(String, String)>,
+    limit: Option<u32>,
+) -> StdResult<AllowancesResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    // One more than the page to know if another page follows
+    let mut limits: Vec<((String, String), SpendLimit)> = match &operator {
+        Some(operator) => {
+            let min = start_after
+                .as_ref()
+                .filter(|(after_operator, _)| after_operator == operator)
+                .map(|(_, denom)| Bound::exclusive(denom.as_str()));
+            SPEND_LIMITS
+                .prefix(operator.as_str())
+                .range(storage, min, None, Order::Ascending)
+                .take(limit + 1)
+                .map(|item| {
+                    item.map(|(denom, limit)| ((operator.clone(), denom), limit))
+                })
+                .collect::<StdResult<_>>()?
+        }
+        None => {
+            let min = start_after.as_ref().map(|(operator, denom)| {
+                Bound::exclusive((operator.as_str(), denom.as_str()))
+            });
+            SPEND_LIMITS
+                .range(storage, min, None, Order::Ascending)
+                .take(limit + 1)
+                .collect::<StdResult<_>>()?
+        }
+    };
+    let next_start_after = match limits.len() > limit {
+        true => {
+            limits.truncate(limit);
+            limits.last().map(|(key, _)| key.clone())
+        }
+        false => None,
+    };
+
+    let mut allowances: Vec<Allowance> = vec![];
+    for ((operator, denom), limit) in limits {
+        let spends =
+            spends_in_window(storage, block, &operator, &denom, &limit)?;
+        let spent_in_window: Uint128 =
+            spends.iter().map(|spend| spend.amount).sum();
+        let window_remaining = limit
+            .per_window
+            .as_ref()
+            .map(|per_window| per_window.amount.saturating_sub(spent_in_window));
+        let remaining = match (limit.per_tx, window_remaining) {
+            (Some(per_tx), Some(window)) => Some(per_tx.min(window)),
+            (per_tx, window) => per_tx.or(window),
+        };
+        allowances.push(Allowance {
+            operator,
+            denom,
+            limit,
+            spent_in_window,
+            remaining,
+        });
+    }
+    Ok(AllowancesResponse {
+        allowances,
+        next_start_after,
+    })
+}
+
+/// Removes every limit and spend record of `operator`.
+pub fn clear_operator(
+    storage: &mut dyn Storage,
+    operator: &str,
+) -> StdResult<()> {
+    let denoms: Vec<String> = SPEND_LIMITS
+        .prefix(operator)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for denom in denoms {
+        SPEND_LIMITS.remove(storage, (operator, &denom));
+        SPENDS.remove(storage, (operator, &denom));
+    }
+    Ok(())
+}
@@ -1,24 +1,59 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Event;
-use cw_storage_plus::{Deque, Item};
+use cw_storage_plus::{Deque, Item, Map};
 use std::collections::BTreeSet;
 
+use crate::{
+    spend_limits::{Spend, SpendLimit},
+    to_addrs::Recipient,
+};
+
 /// TO_ADDRS: Defines the set of addresses that can receive transfers from the
 /// contract.
 pub const TO_ADDRS: Item<BTreeSet<String>> = Item::new("to_addrs");
 
-/// OPERATORS: The set of accounts that can operate the broker smart contract.
-/// Operators cannot add or remove other operators or withdraw funds.
-pub const OPERATORS: Item<BTreeSet<String>> = Item::new("operators");
+/// RECIPIENTS: Label, expiry, and denom restrictions of the "TO_ADDRS", keyed
+/// by address. Addresses in "TO_ADDRS" without an entry are unrestricted.
+pub const RECIPIENTS: Map<&str, Recipient> = Map::new("recipients");
+
+/// CW20S: CW20 token contracts registered by the owner. `WithdrawAll` sweeps
+/// their balances along with the bank coins of the contract.
+pub const CW20S: Item<BTreeSet<String>> = Item::new("cw20s");
+
+/// OPERATOR_ROLE: The `nibiru_perms` role of the accounts that can operate the
+/// broker smart contract. Operators cannot add or remove other operators or
+/// withdraw funds.
+pub const OPERATOR_ROLE: &str = "operator";
+
+/// LEGACY_OPERATORS: The set of operators from before they moved to the
+/// `OPERATOR_ROLE`. Only read by the `migrate` entry point.
+pub const LEGACY_OPERATORS: Item<BTreeSet<String>> = Item::new("operators");
 
 /// LOGS: Stateful `cw_storage_plus::Deque` holding transaction and event logs.
 /// The "Deque" increments every time the smart contract is invoked to send
-/// funds, withdraw, or change operator permissions.
+/// funds, withdraw, or change operator permissions. The newest log is at the
+/// front. Write to it with `logs::push`, which applies `LOG_RETENTION`.
 pub const LOGS: Deque<Log> = Deque::new("logs");
 
+/// LOGS_PRUNED: Number of logs removed from the back of `LOGS` so far. Used to
+/// give each log a stable sequence number.
+pub const LOGS_PRUNED: Item<u64> = Item::new("logs_pruned");
+
+/// LOG_RETENTION: Optional cap on the number of `LOGS`, set at instantiation.
+/// Writing a log beyond the cap drops the oldest one.
+pub const LOG_RETENTION: Item<u32> = Item::new("log_retention");
+
 /// IS_HALTED: An on and off switch the owner can toggle for the operators.
 pub const IS_HALTED: Item<bool> = Item::new("is_halted");
 
+/// SPEND_LIMITS: Caps on `BankSend`, keyed by (operator, denom). Operators can
+/// send denoms without an entry freely.
+pub const SPEND_LIMITS: Map<(&str, &str), SpendLimit> = Map::new("spend_limits");
+
+/// SPENDS: Sends by (operator, denom) that are still inside the rolling window
+/// of the matching `SPEND_LIMITS` entry. Older spends are pruned on each send.
+pub const SPENDS: Map<(&str, &str), Vec<Spend>> = Map::new("spends");
+
 /// Log: An entry in the "logs" state of the contract. Each `Log` records a
 /// successful execute transaction on the broker contract.
 #[cw_serde]
@@ -0,0 +1,178 @@
+use std::collections::BTreeSet;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Api, BlockInfo, Coin, Order, StdResult, Storage, Timestamp};
+
+use crate::{
+    error::ContractError,
+    state::{RECIPIENTS, TO_ADDRS},
+};
+
+#[cw_serde]
+pub enum Action {
+    /// Adds a recipient to the "TO_ADDRS", or updates the metadata of one
+    /// that is already there.
+    AddToAddr { recipient: Recipient },
+    /// Removes a recipient from the "TO_ADDRS".
+    RemoveToAddr { address: String },
+    /// Replaces the whole "TO_ADDRS" set with the given recipients.
+    ReplaceToAddrs { recipients: Vec<Recipient> },
+}
+
+/// Recipient: An address in the "TO_ADDRS" and the restrictions on what
+/// `ExecuteMsg::BankSend` can send it.
+#[cw_serde]
+pub struct Recipient {
+    pub address: String,
+    /// Human-readable name of the recipient, Ex.: "mm_kucoin".
+    pub label: String,
+    /// Block time after which the recipient can no longer receive funds.
+    pub expires_at: Option<Timestamp>,
+    /// Denoms that the recipient can receive. Any denom if not given.
+    pub denoms: Option<BTreeSet<String>>,
+}
+
+#[cw_serde]
+pub struct RecipientsResponse {
+    pub recipients: Vec<Recipient>,
+}
+
+impl Recipient {
+    /// Recipient without restrictions, used for the "TO_ADDRS" from
+    /// instantiation.
+    pub fn unrestricted(address: impl Into<String>) -> Self {
+        Recipient {
+            address: address.into(),
+            label: String::new(),
+            expires_at: None,
+            denoms: None,
+        }
+    }
+
+    pub fn validate(
+        &self,
+        api: &dyn Api,
+        block: &BlockInfo,
+    ) -> Result<(), ContractError> {
+        let invalid = |reason: &str| {
+            Err(ContractError::InvalidRecipient {
+                address: self.address.clone(),
+                reason: reason.to_string(),
+            })
+        };
+        if self.address.trim().is_empty() {
+            return invalid("address must not be empty");
+        }
+        api.addr_validate(&self.address)?;
+        if self.expires_at.is_some_and(|expiry| expiry <= block.time) {
+            return invalid("expiry must be in the future");
+        }
+        if self.denoms.as_ref().is_some_and(BTreeSet::is_empty) {
+            return invalid("denoms must not be empty if given");
+        }
+        Ok(())
+    }
+
+    pub fn is_expired(&self, block: &BlockInfo) -> bool {
+        self.expires_at.is_some_and(|expiry| block.time >= expiry)
+    }
+
+    /// Saves the recipient and adds it to the "TO_ADDRS".
+    pub fn save(&self, storage: &mut dyn Storage) -> StdResult<()> {
+        let mut to_addrs = TO_ADDRS.load(storage)?;
+        to_addrs.insert(self.address.clone());
+        TO_ADDRS.save(storage, &to_addrs)?;
+        RECIPIENTS.save(storage, &self.address, self)
+    }
+
+    /// Loads a recipient in the "TO_ADDRS". Addresses without metadata have
+    /// no restrictions.
+    pub fn load(
+        storage: &dyn Storage,
+        address: &str,
+    ) -> Result<Self, ContractError> {
+        if !TO_ADDRS.load(storage)?.contains(address) {
+            return Err(ContractError::ToAddrNotAllowed {
+                to_addr: address.to_string(),
+            });
+        }
+        Ok(RECIPIENTS
+            .may_load(storage, address)?
+            .unwrap_or_else(|| Recipient::unrestricted(address)))
+    }
+
+    /// Asserts that the recipient is allowed to receive `coins` at `block`.
+    pub fn assert_can_receive(
+        &self,
+        block: &BlockInfo,
+        coins: &[Coin],
+    ) -> Result<(), ContractError> {
+        if let Some(expires_at) = self.expires_at {
+            if self.is_expired(block) {
+                return Err(ContractError::ToAddrExpired {
+                    to_addr: self.address.clone(),
+                    expires_at,
+                });
+            }
+        }
+        if let Some(denoms) = &self.denoms {
+            if let Some(coin) =
+                coins.iter().find(|coin| !denoms.contains(&coin.denom))
+            {
+                return Err(ContractError::DenomNotAllowedForToAddr {
+                    to_addr: self.address.clone(),
+                    denom: coin.denom.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Removes a recipient from the "TO_ADDRS" along with its metadata.
+pub fn remove(
+    storage: &mut dyn Storage,
+    address: &str,
+) -> Result<(), ContractError> {
+    let mut to_addrs = TO_ADDRS.load(storage)?;
+    if !to_addrs.remove(address) {
+        return Err(ContractError::ToAddrNotAllowed {
+            to_addr: address.to_string(),
+        });
+    }
+    TO_ADDRS.save(storage, &to_addrs)?;
+    RECIPIENTS.remove(storage, address);
+    Ok(())
+}
+
+/// Replaces every recipient in the "TO_ADDRS" with `recipients`.
+pub fn replace(
+    storage: &mut dyn Storage,
+    recipients: &[Recipient],
+) -> StdResult<()> {
+    let old_addrs: Vec<String> = RECIPIENTS
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for address in old_addrs {
+        RECIPIENTS.remove(storage, &address);
+    }
+    TO_ADDRS.save(storage, &BTreeSet::new())?;
+    for recipient in recipients {
+        recipient.save(storage)?;
+    }
+    Ok(())
+}
+
+/// Lists every recipient in the "TO_ADDRS", sorted by address.
+pub fn query_recipients(storage: &dyn Storage) -> StdResult<RecipientsResponse> {
+    let recipients = TO_ADDRS
+        .load(storage)?
+        .into_iter()
+        .map(|address| {
+            Ok(RECIPIENTS
+                .may_load(storage, &address)?
+                .unwrap_or_else(|| Recipient::unrestricted(address)))
+        })
+        .collect::<StdResult<_>>()?;
+    Ok(RecipientsResponse { recipients })
+}
@@ -2,8 +2,9 @@ use broker_bank::contract::{
     assert_not_halted, edit_opers, execute_update_ownership, query_perms_status,
     toggle_halt, withdraw, withdraw_all,
 };
-use broker_bank::oper_perms::Permissions;
-use broker_bank::state::{IS_HALTED, OPERATORS, TO_ADDRS};
+use broker_bank::oper_perms::{self, Permissions};
+use broker_bank::state::{IS_HALTED, LOG_RETENTION, TO_ADDRS};
+use broker_bank::{assets, logs, spend_limits, to_addrs};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
@@ -14,7 +15,8 @@ use cosmwasm_std::{
 use crate::msg::{ExecuteMsg, StakeMsg, UnstakeMsg};
 use broker_bank::error::ContractError;
 use broker_bank::msgs::{
-    InstantiateMsg as BrokerBankInstantiateMsg, PermsStatus, QueryMsg,
+    InstantiateMsg as BrokerBankInstantiateMsg, MigrateMsg, PermsStatus,
+    QueryMsg,
 };
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -27,12 +29,25 @@ pub fn instantiate(
     // Managers validation
     nibiru_ownable::initialize_owner(deps.storage, Some(&msg.owner))?;
     TO_ADDRS.save(deps.storage, &msg.to_addrs)?;
-    OPERATORS.save(deps.storage, &msg.opers)?;
+    oper_perms::save_operators(deps.storage, &msg.opers)?;
     IS_HALTED.save(deps.storage, &false)?;
+    if let Some(retention) = msg.log_retention {
+        LOG_RETENTION.save(deps.storage, &retention)?;
+    }
 
     Ok(Response::new())
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    deps: DepsMut,
+    _env: Env,
+    _msg: MigrateMsg,
+) -> StdResult<Response> {
+    oper_perms::migrate_legacy_operators(deps.storage)?;
+    Ok(Response::new())
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
@@ -43,7 +58,7 @@ pub fn execute(
     let contract_addr = env.contract.address.to_string();
     match msg {
         ExecuteMsg::Withdraw { to, denoms } => {
-            withdraw(deps, env, info, to, denoms, contract_addr)
+            withdraw(deps, env, info, to, denoms, vec![], contract_addr)
         }
         ExecuteMsg::Stake { stake_msgs, amount } => {
             stake(deps, env, info, stake_msgs, amount)
@@ -189,7 +204,7 @@ fn build_unstakes_messages(
 #[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
 pub fn query(
     deps: Deps,
-    _env: Env,
+    env: Env,
     msg: QueryMsg,
 ) -> Result<Binary, ContractError> {
     match msg {
@@ -197,6 +212,35 @@ pub fn query(
             let perms_status: PermsStatus = query_perms_status(deps)?;
             Ok(to_json_binary(&perms_status)?)
         }
+        // Staking has no spend limits, so the list is always empty.
+        QueryMsg::Allowances {
+            operator,
+            start_after,
+            limit,
+        } => Ok(to_json_binary(&spend_limits::query_allowances(
+            deps.storage,
+            &env.block,
+            operator,
+            start_after,
+            limit,
+        )?)?),
+        QueryMsg::Recipients {} => {
+            Ok(to_json_binary(&to_addrs::query_recipients(deps.storage)?)?)
+        }
+        // Staking has no way to register CW20s, so the set is always empty.
+        QueryMsg::Cw20s {} => Ok(to_json_binary(&assets::Cw20sResponse {
+            cw20s: assets::cw20s(deps.storage)?,
+        })?),
+        QueryMsg::Logs {
+            start_after,
+            limit,
+            filter,
+        } => Ok(to_json_binary(&logs::query_logs(
+            deps.storage,
+            start_after,
+            limit,
+            filter,
+        )?)?),
         QueryMsg::Ownership {} => Ok(to_json_binary(
             &nibiru_ownable::get_ownership(deps.storage)?,
         )?),
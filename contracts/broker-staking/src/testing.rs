@@ -1,6 +1,6 @@
 use std::collections::BTreeSet;
 
-use crate::contract::{execute, query};
+use crate::contract::{execute, migrate, query};
 use crate::msg::{ExecuteMsg, StakeMsg, UnstakeMsg};
 use cosmwasm_std::{self as cw_std};
 use cw_std::{
@@ -11,9 +11,9 @@ use nibiru_std::errors::TestResult;
 use serde::Serialize;
 
 use broker_bank::{
-    msgs::{PermsStatus, QueryMsg},
+    msgs::{MigrateMsg, PermsStatus, QueryMsg},
     oper_perms::{self, Permissions},
-    state::{IS_HALTED, OPERATORS},
+    state::{IS_HALTED, LEGACY_OPERATORS},
     tutil::{
         self, mock_info_for_sender, setup_contract, setup_contract_defaults,
         TEST_OWNER,
@@ -125,7 +125,7 @@ fn exec_withdraw() -> TestResult {
     let to_addrs: [String; 2] = ["mm_kucoin", "mm_bybit"].map(|s| s.to_string());
     let opers: [String; 1] = ["valid_oper"].map(|s| s.to_string());
     let test_cases: Vec<TestCaseExec> = vec![
-        // WithdrawAll
+        // WithdrawAll / Empty balance sends nothing
         TestCaseExec {
             to_addrs: to_addrs.to_vec(),
             opers: opers.to_vec(),
@@ -135,11 +135,7 @@ fn exec_withdraw() -> TestResult {
             },
             err: None,
             contract_funds_start: None,
-            resp_msgs: vec![BankMsg::Send {
-                to_address: String::from("mm_bybit"),
-                amount: vec![],
-            }
-            .into()],
+            resp_msgs: vec![],
         },
         // WithdrawAll / Nonzero amount
         TestCaseExec {
@@ -366,7 +362,8 @@ fn exec_edit_opers_remove() -> TestResult {
     for member in opers_start.iter() {
         perms.operators.insert(member.clone());
     }
-    let res = OPERATORS.save(deps.as_mut().storage, &perms.operators);
+    let res =
+        oper_perms::save_operators(deps.as_mut().storage, &perms.operators);
     assert!(res.is_ok());
 
     // Remove a member from the whitelist
@@ -829,3 +826,25 @@ fn test_withdraw_rewards() -> TestResult {
     }
     Ok(())
 }
+
+#[test]
+fn migrate_legacy_operators() -> TestResult {
+    // Storage from before the operators moved to nibiru-perms
+    let mut deps = testing::mock_dependencies();
+    let storage = deps.as_mut().storage;
+    nibiru_ownable::initialize_owner(storage, Some(TEST_OWNER))?;
+    IS_HALTED.save(storage, &false)?;
+    let opers = BTreeSet::from([String::from("oper0"), String::from("oper1")]);
+    LEGACY_OPERATORS.save(storage, &opers)?;
+
+    migrate(deps.as_mut(), testing::mock_env(), MigrateMsg {})?;
+    assert_eq!(LEGACY_OPERATORS.may_load(&deps.storage)?, None);
+    assert_eq!(Permissions::load(&deps.storage)?.operators, opers);
+
+    // Permissions are unchanged
+    for (sender, ok) in [("oper0", true), (TEST_OWNER, true), ("oper2", false)] {
+        let res = Permissions::assert_operator(&deps.storage, sender.into());
+        assert_eq!(res.is_ok(), ok, "sender: {sender}");
+    }
+    Ok(())
+}
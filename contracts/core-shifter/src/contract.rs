@@ -12,8 +12,10 @@ use nibiru_std::{
 
 use crate::{
     error::ContractError,
-    msgs::{operator_perms, ExecuteMsg, InitMsg, QueryMsg},
-    state::{instantiate_perms, Permissions, OPERATORS},
+    msgs::{operator_perms, ExecuteMsg, InitMsg, MigrateMsg, QueryMsg},
+    state::{
+        instantiate_perms, migrate_legacy_operators, Permissions, OPERATOR_ROLE,
+    },
 };
 
 pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
@@ -35,6 +37,21 @@ pub fn instantiate(
     Ok(Response::default())
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    deps: DepsMut,
+    _env: Env,
+    _msg: MigrateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(
+        deps.storage,
+        format!("crates.io:{CONTRACT_NAME}"),
+        CONTRACT_VERSION,
+    )?;
+    migrate_legacy_operators(deps.storage)?;
+    Ok(Response::default())
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
@@ -44,7 +61,6 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     let deps_for_check = &deps;
     let check = CanExecute::new(deps_for_check.as_ref(), info.sender.as_ref())?;
-    let mut perms = check.perms.clone();
 
     let contract_addr = env.contract.address.to_string();
     match msg {
@@ -85,8 +101,11 @@ pub fn execute(
             match action {
                 operator_perms::Action::AddOper { address } => {
                     let addr = api.addr_validate(address.as_str())?;
-                    perms.operators.insert(addr.into_string());
-                    OPERATORS.save(deps.storage, &perms.operators)?;
+                    nibiru_perms::add_member(
+                        deps.storage,
+                        OPERATOR_ROLE,
+                        addr.as_str(),
+                    )?;
 
                     let res = Response::new().add_attributes(vec![
                         attr("action", "add_operator"),
@@ -96,8 +115,11 @@ pub fn execute(
                 }
 
                 operator_perms::Action::RemoveOper { address } => {
-                    perms.operators.remove(address.as_str());
-                    OPERATORS.save(deps.storage, &perms.operators)?;
+                    nibiru_perms::remove_member(
+                        deps.storage,
+                        OPERATOR_ROLE,
+                        &address,
+                    )?;
 
                     let res = Response::new().add_attributes(vec![
                         attr("action", "remove_operator"),
@@ -133,7 +155,6 @@ struct CanExecute {
     is_owner: bool,
     is_operator: bool,
     sender: String,
-    perms: Permissions,
 }
 
 impl CanExecute {
@@ -143,7 +164,6 @@ impl CanExecute {
             is_owner: perms.is_owner(sender),
             is_operator: perms.is_operator(sender),
             sender: sender.into(),
-            perms,
         })
     }
 
@@ -188,7 +208,7 @@ pub mod tests {
     use super::*;
     use crate::{
         msgs::{ExecuteMsg, InitMsg},
-        state::OPERATORS,
+        state::{save_operators, LEGACY_OPERATORS},
         testing::{self as t, TestResult},
     };
 
@@ -314,7 +334,7 @@ pub mod tests {
         for member in opers_start.iter() {
             perms.operators.insert(member.clone());
         }
-        let res = OPERATORS.save(deps.as_mut().storage, &perms.operators);
+        let res = save_operators(deps.as_mut().storage, &perms.operators);
         assert!(res.is_ok());
 
         // Remove a member from the whitelist
@@ -366,4 +386,35 @@ pub mod tests {
     fn test_exec_change_admin() -> TestResult {
         Ok(())
     }
+
+    #[test]
+    fn test_migrate_legacy_operators() -> TestResult {
+        // Storage from before the operators moved to nibiru-perms
+        let mut deps = testing::mock_dependencies();
+        let storage = deps.as_mut().storage;
+        nibiru_ownable::initialize_owner(storage, Some(t::TEST_OWNER))?;
+        let opers = BTreeSet::from([String::from("oper0")]);
+        LEGACY_OPERATORS.save(storage, &opers)?;
+
+        migrate(deps.as_mut(), testing::mock_env(), MigrateMsg {})?;
+        assert_eq!(LEGACY_OPERATORS.may_load(&deps.storage)?, None);
+        assert_eq!(Permissions::load(&deps.storage)?.operators, opers);
+
+        // Permissions are unchanged
+        let execute_msg =
+            ExecuteMsg::EditOpers(operator_perms::Action::AddOper {
+                address: addr!("new_member").to_string(),
+            });
+        for (sender, ok) in [("unauthorized", false), ("oper0", true)] {
+            let info = testing::mock_info(sender, &[]);
+            let res = execute(
+                deps.as_mut(),
+                testing::mock_env(),
+                info,
+                execute_msg.clone(),
+            );
+            assert_eq!(res.is_ok(), ok, "sender: {sender}");
+        }
+        Ok(())
+    }
 }
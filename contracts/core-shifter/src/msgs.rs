@@ -7,6 +7,10 @@ pub struct InitMsg {
     pub owner: String,
 }
 
+/// MigrateMsg specifies the args for the migrate entry point of the contract.
+#[cw_serde]
+pub struct MigrateMsg {}
+
 /// ExecuteMsg specifies the args for the execute entry point of the contract.
 #[nibiru_ownable::ownable_execute]
 #[cw_serde]
@@ -1,12 +1,18 @@
 use std::collections::BTreeSet;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Api, Storage};
+use cosmwasm_std::{Api, StdResult, Storage};
 use cw_storage_plus::Item;
+use nibiru_perms::{Actions, Role};
 
 use crate::error::ContractError;
 
-pub const OPERATORS: Item<BTreeSet<String>> = Item::new("operators");
+/// The `nibiru_perms` role of the operators of the contract.
+pub const OPERATOR_ROLE: &str = "operator";
+
+/// The set of operators from before they moved to the [OPERATOR_ROLE]. Only
+/// read by the `migrate` entry point.
+pub const LEGACY_OPERATORS: Item<BTreeSet<String>> = Item::new("operators");
 
 #[cw_serde]
 pub struct Permissions {
@@ -36,7 +42,7 @@ impl Permissions {
 
     pub fn load(storage: &dyn Storage) -> Result<Self, ContractError> {
         let owner = nibiru_ownable::get_ownership(storage)?.owner;
-        let opers = OPERATORS.load(storage)?;
+        let opers = nibiru_perms::members(storage, OPERATOR_ROLE)?;
         Ok(Permissions {
             owner: owner.map(|addr| addr.to_string()),
             operators: opers,
@@ -45,7 +51,7 @@ impl Permissions {
 }
 
 /// Set the given address as the contract owner and initialize the
-/// 'OPERATORS' and 'OWNERSHIP' state. This function is only intended to be used only
+/// 'OPERATOR_ROLE' and 'OWNERSHIP' state. This function is only intended to be used only
 /// during contract instantiation.
 pub fn instantiate_perms(
     owner: Option<&str>,
@@ -53,7 +59,31 @@ pub fn instantiate_perms(
     _api: &dyn Api,
 ) -> Result<(), ContractError> {
     nibiru_ownable::initialize_owner(storage, owner)?;
-    Ok(OPERATORS.save(storage, &BTreeSet::default())?)
+    Ok(save_operators(storage, &BTreeSet::default())?)
+}
+
+/// Replaces the set of operators.
+pub fn save_operators(
+    storage: &mut dyn Storage,
+    operators: &BTreeSet<String>,
+) -> StdResult<()> {
+    let role = Role {
+        members: operators.clone(),
+        actions: Actions::All,
+    };
+    nibiru_perms::save_role(storage, OPERATOR_ROLE, &role)
+}
+
+/// Moves the [LEGACY_OPERATORS] into the [OPERATOR_ROLE]. Does nothing if the
+/// contract has already been migrated.
+pub fn migrate_legacy_operators(storage: &mut dyn Storage) -> StdResult<()> {
+    if let Some(legacy_opers) = LEGACY_OPERATORS.may_load(storage)? {
+        let mut opers = nibiru_perms::members(storage, OPERATOR_ROLE)?;
+        opers.extend(legacy_opers);
+        save_operators(storage, &opers)?;
+        LEGACY_OPERATORS.remove(storage);
+    }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -112,17 +142,17 @@ pub mod tests {
         let mut store = MockStorage::new();
 
         // Store should start out empty
-        assert!(OPERATORS.load(&store).is_err());
-        assert_eq!(OPERATORS.may_load(&store)?, None);
+        assert_eq!(nibiru_perms::get_role(&store, OPERATOR_ROLE)?, None);
+        assert!(nibiru_perms::members(&store, OPERATOR_ROLE)?.is_empty());
 
         // save to store
         let perms = init_mock_perms();
         let opers = perms.operators;
-        let res = OPERATORS.save(&mut store, &opers);
+        let res = save_operators(&mut store, &opers);
         assert!(res.is_ok());
 
         // load from store
-        assert_eq!(opers, OPERATORS.load(&store)?);
+        assert_eq!(opers, nibiru_perms::members(&store, OPERATOR_ROLE)?);
         Ok(())
     }
 }
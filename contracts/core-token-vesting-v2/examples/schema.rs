@@ -2,7 +2,7 @@ use std::env::current_dir;
 use std::fs::create_dir_all;
 
 use core_token_vesting_v2::msg::{
-    ExecuteMsg, InstantiateMsg, QueryMsg, VestingAccountResponse,
+    ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, VestingAccountResponse,
 };
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
@@ -14,6 +14,7 @@ fn main() {
 
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
     export_schema(&schema_for!(ExecuteMsg), &out_dir);
+    export_schema(&schema_for!(MigrateMsg), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
     export_schema(&schema_for!(VestingAccountResponse), &out_dir);
 }
@@ -12,12 +12,12 @@ use serde_json::to_string;
 use crate::errors::ContractError;
 use crate::msg::{
     from_vesting_to_query_output, DeregisterUserResponse, ExecuteMsg,
-    InstantiateMsg, QueryMsg, RewardUserRequest, RewardUserResponse,
+    InstantiateMsg, MigrateMsg, QueryMsg, RewardUserRequest, RewardUserResponse,
     VestingAccountResponse, VestingData, VestingSchedule,
 };
 use crate::state::{
-    VestingAccount, Whitelist, DENOM, UNALLOCATED_AMOUNT, VESTING_ACCOUNTS,
-    WHITELIST,
+    migrate_legacy_whitelist, VestingAccount, Whitelist, DENOM,
+    UNALLOCATED_AMOUNT, VESTING_ACCOUNTS,
 };
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -51,14 +51,22 @@ pub fn instantiate(
 
     UNALLOCATED_AMOUNT.save(deps.storage, &unallocated_amount)?;
     DENOM.save(deps.storage, denom)?;
-    WHITELIST.save(
-        deps.storage,
-        &Whitelist {
-            members: msg.managers.into_iter().collect(),
-            admin: msg.admin,
-        },
-    )?;
+    Whitelist {
+        members: msg.managers.into_iter().collect(),
+        admin: msg.admin,
+    }
+    .save(deps.storage)?;
+
+    Ok(Response::new())
+}
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    deps: DepsMut,
+    _env: Env,
+    _msg: MigrateMsg,
+) -> StdResult<Response> {
+    migrate_legacy_whitelist(deps.storage)?;
     Ok(Response::new())
 }
 
@@ -91,7 +99,7 @@ pub fn withdraw(
     info: MessageInfo,
     amount: Uint128,
 ) -> Result<Response, ContractError> {
-    let whitelist = WHITELIST.load(deps.storage)?;
+    let whitelist = Whitelist::load(deps.storage)?;
     let mut unallocated_amount = UNALLOCATED_AMOUNT.load(deps.storage)?;
     let denom = DENOM.load(deps.storage)?;
 
@@ -125,7 +133,7 @@ fn reward_users(
 ) -> Result<Response, ContractError> {
     let mut res = vec![];
 
-    let whitelist = WHITELIST.load(deps.storage)?;
+    let whitelist = Whitelist::load(deps.storage)?;
     if !(whitelist.is_member(&info.sender) || whitelist.is_admin(&info.sender)) {
         return Err(StdError::generic_err(format!(
             "Sender {} is unauthorized to reward users.",
@@ -233,7 +241,7 @@ fn deregister_vesting_accounts(
     info: MessageInfo,
     addresses: Vec<String>,
 ) -> Result<Response, ContractError> {
-    let whitelist = WHITELIST.load(deps.storage)?;
+    let whitelist = Whitelist::load(deps.storage)?;
     if !(whitelist.is_member(&info.sender) || whitelist.is_admin(&info.sender)) {
         return Err(StdError::generic_err(format!(
             "Sender {} is not authorized to deregister vesting accounts.",
@@ -446,7 +454,7 @@ fn vesting_account(
     address: String,
 ) -> StdResult<VestingAccountResponse> {
     let account = VESTING_ACCOUNTS.may_load(deps.storage, address.as_str())?;
-    let whitelist = WHITELIST.load(deps.storage)?;
+    let whitelist = Whitelist::load(deps.storage)?;
     let denom = DENOM.load(deps.storage)?;
 
     match account {
@@ -11,6 +11,10 @@ pub struct InstantiateMsg {
     pub managers: Vec<String>,
 }
 
+/// Structure for the message that migrates the smart contract.
+#[cw_serde]
+pub struct MigrateMsg {}
+
 /// Enum respresenting message types for the execute entry point.
 /// These express the different ways in which one can invoke the contract
 /// and broadcast tx messages against it.
@@ -3,15 +3,24 @@ use std::collections::HashSet;
 use cosmwasm_schema::cw_serde;
 
 use crate::msg::VestingSchedule;
-use cosmwasm_std::{StdResult, Timestamp, Uint128};
+use cosmwasm_std::{StdError, StdResult, Storage, Timestamp, Uint128};
 use cw_storage_plus::{Item, Map};
+use nibiru_perms::{Actions, Role};
 
 pub const VESTING_ACCOUNTS: Map<&str, VestingAccount> =
     Map::new("vesting_accounts");
 pub const UNALLOCATED_AMOUNT: Item<Uint128> = Item::new("unallocated_amount");
 pub const DENOM: Item<String> = Item::new("denom");
-pub const WHITELIST: Item<Whitelist> = Item::new("whitelist");
+/// The `nibiru_perms` role of the managers of the contract.
+pub const MANAGER_ROLE: &str = "manager";
 
+/// The whitelist from before it moved to `nibiru_ownable` and
+/// `nibiru_perms`. Only read by the `migrate` entry point.
+pub const LEGACY_WHITELIST: Item<Whitelist> = Item::new("whitelist");
+
+/// Whitelist: The admin and managers of the contract. The admin is the
+/// `nibiru_ownable` owner and the managers are the members of the
+/// [MANAGER_ROLE].
 #[cw_serde]
 pub struct Whitelist {
     pub members: HashSet<String>,
@@ -19,6 +28,26 @@ pub struct Whitelist {
 }
 
 impl Whitelist {
+    pub fn load(storage: &dyn Storage) -> StdResult<Self> {
+        let admin = nibiru_ownable::get_ownership(storage)?
+            .owner
+            .ok_or_else(|| StdError::generic_err("contract has no admin"))?;
+        let members = nibiru_perms::members(storage, MANAGER_ROLE)?;
+        Ok(Whitelist {
+            members: members.into_iter().collect(),
+            admin,
+        })
+    }
+
+    pub fn save(&self, storage: &mut dyn Storage) -> StdResult<()> {
+        nibiru_ownable::initialize_owner(storage, Some(&self.admin))?;
+        let role = Role {
+            members: self.members.iter().cloned().collect(),
+            actions: Actions::All,
+        };
+        nibiru_perms::save_role(storage, MANAGER_ROLE, &role)
+    }
+
     pub fn is_admin(&self, addr: impl AsRef<str>) -> bool {
         let addr = addr.as_ref();
         self.admin == addr
@@ -30,6 +59,16 @@ impl Whitelist {
     }
 }
 
+/// Moves the [LEGACY_WHITELIST] into `nibiru_ownable` and `nibiru_perms`
+/// state. Does nothing if the contract has already been migrated.
+pub fn migrate_legacy_whitelist(storage: &mut dyn Storage) -> StdResult<()> {
+    if let Some(whitelist) = LEGACY_WHITELIST.may_load(storage)? {
+        whitelist.save(storage)?;
+        LEGACY_WHITELIST.remove(storage);
+    }
+    Ok(())
+}
+
 #[cw_serde]
 pub struct VestingAccount {
     pub address: String,
@@ -1,10 +1,13 @@
-use crate::contract::{execute, instantiate, query};
+use std::collections::HashSet;
+
+use crate::contract::{execute, instantiate, migrate, query};
 use crate::errors::{ContractError, VestingError};
 use crate::msg::{
-    DeregisterUserResponse, ExecuteMsg, InstantiateMsg, QueryMsg,
+    DeregisterUserResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
     RewardUserRequest, VestingAccountResponse, VestingData, VestingSchedule,
     VestingScheduleQueryOutput,
 };
+use crate::state::{Whitelist, DENOM, LEGACY_WHITELIST, UNALLOCATED_AMOUNT};
 
 use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
 use cosmwasm_std::{coin, testing, Empty, MessageInfo};
@@ -552,6 +555,38 @@ fn test_withdraw() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn migrate_legacy_whitelist() -> TestResult {
+    // Storage from before the whitelist moved to nibiru-perms
+    let mut deps = mock_dependencies();
+    let legacy = Whitelist {
+        members: HashSet::from(["manager-sender".to_string()]),
+        admin: "addr0000".to_string(),
+    };
+    let storage = deps.as_mut().storage;
+    UNALLOCATED_AMOUNT.save(storage, &Uint128::new(1000))?;
+    DENOM.save(storage, &"uusd".to_string())?;
+    LEGACY_WHITELIST.save(storage, &legacy)?;
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {})?;
+    assert_eq!(LEGACY_WHITELIST.may_load(&deps.storage)?, None);
+    assert_eq!(Whitelist::load(&deps.storage)?, legacy);
+
+    // Permissions are unchanged
+    let msg = ExecuteMsg::Withdraw {
+        amount: Uint128::new(10),
+    };
+    require_error(
+        &mut deps,
+        &mock_env(),
+        mock_info("manager-sender", &[]),
+        msg.clone(),
+        StdError::generic_err("Unauthorized").into(),
+    );
+    execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg)?;
+    Ok(())
+}
+
 fn require_error(
     deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
     env: &Env,
@@ -2,11 +2,10 @@ use crate::error::ContractError;
 use crate::msgs::{ExecuteMsg, InstantiateMsg};
 use crate::state::TOKEN_SUPPLY;
 use cosmwasm_std::{
-    entry_point, CosmosMsg, DepsMut, Env, MessageInfo, Response, StdError,
-    Uint256,
+    entry_point, CosmosMsg, DepsMut, Env, MessageInfo, Response, Uint256,
 };
-use nibiru_std::proto::cosmos::{self, base};
-use nibiru_std::proto::{nibiru, NibiruStargateMsg};
+use nibiru_std::proto::NibiruStargateMsg;
+use nibiru_std::tokenfactory::{self, TfDenom};
 
 #[entry_point]
 pub fn instantiate(
@@ -28,11 +27,9 @@ pub fn execute(
     let contract_addr: String = env.contract.address.into();
     match msg {
         ExecuteMsg::CreateDenom { subdenom } => {
-            let cosmos_msg: CosmosMsg = nibiru::tokenfactory::MsgCreateDenom {
-                sender: contract_addr,
-                subdenom,
-            }
-            .into_stargate_msg();
+            let denom = TfDenom::new(contract_addr, subdenom)?;
+            let cosmos_msg: CosmosMsg =
+                tokenfactory::create_denom(&denom).into_stargate_msg();
 
             Ok(Response::new()
                 // .add_event()
@@ -40,28 +37,12 @@ pub fn execute(
         }
 
         ExecuteMsg::Mint { coin, mint_to } => {
-            let cosmos_msg: CosmosMsg = nibiru::tokenfactory::MsgMint {
-                sender: contract_addr,
-                // TODO feat: cosmwasm-std Coin should implement into()
-                // base::v1beta1::Coin.
-                coin: Some(cosmos::base::v1beta1::Coin {
-                    denom: coin.denom.clone(),
-                    amount: coin.amount.into(),
-                }),
-                mint_to,
-            }
-            .into_stargate_msg();
-
-            let denom_parts: Vec<&str> = coin.denom.split('/').collect();
-            if denom_parts.len() != 3 {
-                return Err(StdError::generic_err(
-                    "invalid denom input".to_string(),
-                )
-                .into());
-            }
+            let denom: TfDenom = coin.denom.parse()?;
+            let cosmos_msg: CosmosMsg =
+                tokenfactory::mint(contract_addr, coin.clone(), mint_to)?
+                    .into_stargate_msg();
 
-            let subdenom = denom_parts[2];
-            let supply_key = subdenom;
+            let supply_key = denom.subdenom();
             let token_supply =
                 TOKEN_SUPPLY.may_load(_deps.storage, supply_key)?;
             match token_supply {
@@ -82,29 +63,19 @@ pub fn execute(
         }
 
         ExecuteMsg::Burn { coin, burn_from } => {
-            let cosmos_msg: CosmosMsg = nibiru::tokenfactory::MsgBurn {
-                sender: contract_addr,
-                // TODO cosmwasm-std Coin should implement into()
-                // base::v1beta1::Coin.
-                coin: Some(base::v1beta1::Coin {
-                    denom: coin.denom.clone(),
-                    amount: coin.amount.into(),
-                }),
-                burn_from,
-            }
-            .into_stargate_msg();
+            let cosmos_msg: CosmosMsg =
+                tokenfactory::burn(contract_addr, coin, burn_from)?
+                    .into_stargate_msg();
             Ok(Response::new()
                 // .add_event()
                 .add_message(cosmos_msg))
         }
 
         ExecuteMsg::ChangeAdmin { denom, new_admin } => {
-            let cosmos_msg: CosmosMsg = nibiru::tokenfactory::MsgChangeAdmin {
-                sender: contract_addr,
-                denom: denom.to_string(),
-                new_admin: new_admin.to_string(),
-            }
-            .into_stargate_msg();
+            let denom: TfDenom = denom.parse()?;
+            let cosmos_msg: CosmosMsg =
+                tokenfactory::change_admin(contract_addr, &denom, new_admin)
+                    .into_stargate_msg();
             Ok(Response::new()
                 // .add_event()
                 .add_message(cosmos_msg))
@@ -122,10 +93,13 @@ pub fn execute(
 #[cfg(test)]
 mod tests {
 
-    use crate::contract::instantiate;
+    use crate::contract::{execute, instantiate};
     use crate::error::ContractError;
-    use crate::msgs::InstantiateMsg;
+    use crate::msgs::{ExecuteMsg, InstantiateMsg};
+    use crate::state::TOKEN_SUPPLY;
     use cosmwasm_std::testing::{mock_env, mock_info};
+    use nibiru_std::address::contract_address_classic;
+    use nibiru_std::errors::NibiruError;
 
     use cosmwasm_std as cw;
     use cosmwasm_std::DepsMut;
@@ -142,4 +116,54 @@ mod tests {
         let _ = init(deps.as_mut())?;
         Ok(())
     }
+
+    #[test]
+    fn mint_tracks_supply_of_tf_denoms() -> Result<(), ContractError> {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let contract_addr = contract_address_classic(1, 1);
+        env.contract.address = cw::Addr::unchecked(&contract_addr);
+        let info = mock_info("none", &[]);
+        let denom = format!("tf/{contract_addr}/ustable");
+
+        for amount in [100u128, 20] {
+            let msg = ExecuteMsg::Mint {
+                coin: cw::coin(amount, &denom),
+                mint_to: String::new(),
+            };
+            let res = execute(deps.as_mut(), env.clone(), info.clone(), msg)?;
+            assert_eq!(res.messages.len(), 1);
+        }
+        let supply = TOKEN_SUPPLY.load(&deps.storage, "ustable")?;
+        assert_eq!(supply, cw::Uint256::from(120u128));
+
+        let mint = |coin: cw::Coin| ExecuteMsg::Mint {
+            coin,
+            mint_to: String::new(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            mint(cw::coin(1, "unibi")),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                ContractError::Nibiru(NibiruError::InvalidTfDenom { .. })
+            ),
+            "got {err:?}"
+        );
+        let err = execute(deps.as_mut(), env, info, mint(cw::coin(0, &denom)))
+            .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                ContractError::Nibiru(NibiruError::InvalidCoinAmount { .. })
+            ),
+            "got {err:?}"
+        );
+        Ok(())
+    }
 }
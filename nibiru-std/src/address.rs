@@ -1,14 +1,458 @@
 //! Address conversion utilities for Nibiru
+//!
+//! Nibiru accounts live in two encodings at once: bech32 strings such as
+//! "nibi1..." on the Cosmos side and EIP-55 checksummed hex on the EVM side.
+//! This module converts between them for any human-readable part (HRP) and
+//! keeps track of address length so that 32-byte contract addresses are never
+//! silently truncated to 20 bytes.
+
+use std::fmt;
 
 use bech32::{self, FromBase32, ToBase32};
+use cosmwasm_std as cw;
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
 
 use crate::errors::{NibiruError, NibiruResult};
 
+pub use bech32::Variant as Bech32Variant;
+
+/// HRP of Nibiru account addresses.
+pub const HRP_ACCOUNT: &str = "nibi";
+/// HRP of Nibiru account public keys.
+pub const HRP_ACCOUNT_PUB: &str = "nibipub";
+/// HRP of validator operator addresses.
+pub const HRP_VALOPER: &str = "nibivaloper";
+/// HRP of validator operator public keys.
+pub const HRP_VALOPER_PUB: &str = "nibivaloperpub";
+/// HRP of validator consensus addresses.
+pub const HRP_VALCONS: &str = "nibivalcons";
+/// HRP of validator consensus public keys.
+pub const HRP_VALCONS_PUB: &str = "nibivalconspub";
+
+/// Length in bytes of account and module addresses, which is also the length
+/// of an Ethereum address.
+pub const ADDR_LEN_ACCOUNT: usize = 20;
+/// Length in bytes of Wasm contract addresses.
+pub const ADDR_LEN_CONTRACT: usize = 32;
+
+/// Decodes a bech32 or bech32m string with any HRP.
+///
+/// ```
+/// use nibiru_std::address::{bech32_decode, Bech32Variant};
+///
+/// let (hrp, bytes, variant) =
+///     bech32_decode("nibi1gc24lt74ses9swkq6g7cug4e5y72p7e34jqgul").unwrap();
+/// assert_eq!(hrp, "nibi");
+/// assert_eq!(bytes.len(), 20);
+/// assert_eq!(variant, Bech32Variant::Bech32);
+/// ```
+pub fn bech32_decode(
+    addr: &str,
+) -> NibiruResult<(String, Vec<u8>, Bech32Variant)> {
+    let (hrp, data, variant) = bech32::decode(addr)?;
+    let bytes = Vec::<u8>::from_base32(&data)?;
+    Ok((hrp, bytes, variant))
+}
+
+/// Decodes a bech32 or bech32m string and checks that its HRP is
+/// `expected_hrp`.
+pub fn bech32_decode_with_hrp(
+    addr: &str,
+    expected_hrp: &str,
+) -> NibiruResult<(Vec<u8>, Bech32Variant)> {
+    let (hrp, bytes, variant) = bech32_decode(addr)?;
+    if hrp != expected_hrp {
+        return Err(NibiruError::InvalidBech32Prefix {
+            expected: expected_hrp.to_string(),
+            actual: hrp,
+        });
+    }
+    Ok((bytes, variant))
+}
+
+/// Encodes `bytes` as a bech32 or bech32m string with the given HRP.
+pub fn bech32_encode(
+    hrp: &str,
+    bytes: &[u8],
+    variant: Bech32Variant,
+) -> NibiruResult<String> {
+    Ok(bech32::encode(hrp, bytes.to_base32(), variant)?)
+}
+
+/// Re-encodes a bech32 string under a different HRP, keeping its bytes and
+/// variant. Useful for moving between "nibi" and "nibivaloper".
+///
+/// ```
+/// use nibiru_std::address::{bech32_convert_hrp, HRP_ACCOUNT, HRP_VALOPER};
+///
+/// let acc = "nibi1gc24lt74ses9swkq6g7cug4e5y72p7e34jqgul";
+/// let valoper = bech32_convert_hrp(acc, HRP_VALOPER).unwrap();
+/// assert!(valoper.starts_with("nibivaloper1"));
+/// assert_eq!(bech32_convert_hrp(&valoper, HRP_ACCOUNT).unwrap(), acc);
+/// ```
+pub fn bech32_convert_hrp(addr: &str, new_hrp: &str) -> NibiruResult<String> {
+    let (_hrp, bytes, variant) = bech32_decode(addr)?;
+    bech32_encode(new_hrp, &bytes, variant)
+}
+
+/// Formats a 20-byte address as EIP-55 checksummed hex with a "0x" prefix.
+///
+/// ```
+/// use nibiru_std::address::eip55_checksum_address;
+///
+/// let bytes = hex::decode("46155fafd58660583ac0d23d8e22b9a13ca0fb31").unwrap();
+/// assert_eq!(
+///     eip55_checksum_address(&bytes.try_into().unwrap()),
+///     "0x46155fAfd58660583ac0d23d8E22B9A13Ca0fb31"
+/// );
+/// ```
+pub fn eip55_checksum_address(bytes: &[u8; ADDR_LEN_ACCOUNT]) -> String {
+    let lower_hex = hex::encode(bytes);
+    let hash = Keccak256::digest(lower_hex.as_bytes());
+    let checksummed: String = lower_hex
+        .chars()
+        .enumerate()
+        .map(|(idx, c)| {
+            let nibble = (hash[idx / 2] >> (4 * (1 - idx % 2))) & 0x0f;
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+    format!("0x{checksummed}")
+}
+
+/// Parses a hex Ethereum address, with or without the "0x" prefix.
+///
+/// All-lowercase and all-uppercase addresses carry no checksum and are
+/// accepted as is. Mixed-case addresses must be valid EIP-55 checksums.
+pub fn parse_eth_address(
+    eth_addr: &str,
+) -> NibiruResult<[u8; ADDR_LEN_ACCOUNT]> {
+    let hex_str = eth_addr.strip_prefix("0x").unwrap_or(eth_addr);
+
+    // Validate hex string length (20 bytes = 40 hex chars)
+    if hex_str.len() != 2 * ADDR_LEN_ACCOUNT {
+        return Err(NibiruError::InvalidEthAddress(format!(
+            "Ethereum address must be 20 bytes (40 hex chars), got {} chars",
+            hex_str.len()
+        )));
+    }
+
+    let bytes: [u8; ADDR_LEN_ACCOUNT] =
+        hex::decode(hex_str)?.try_into().map_err(|bytes: Vec<u8>| {
+            NibiruError::InvalidEthAddress(format!(
+                "Invalid Ethereum address length: expected 20 bytes, got {}",
+                bytes.len()
+            ))
+        })?;
+
+    let is_mixed_case = hex_str.chars().any(|c| c.is_ascii_lowercase())
+        && hex_str.chars().any(|c| c.is_ascii_uppercase());
+    if is_mixed_case {
+        let checksummed = eip55_checksum_address(&bytes);
+        if checksummed[2..] != *hex_str {
+            return Err(NibiruError::InvalidEthAddress(format!(
+                "invalid EIP-55 checksum: got {eth_addr}, expected \
+                 {checksummed}"
+            )));
+        }
+    }
+    Ok(bytes)
+}
+
+/// Address of a module account, which is the first 20 bytes of the SHA-256
+/// hash of the module name. Equivalent to `authtypes.NewModuleAddress` in the
+/// Cosmos-SDK.
+pub fn module_address_bytes(module_name: &str) -> [u8; ADDR_LEN_ACCOUNT] {
+    let hash = Sha256::digest(module_name.as_bytes());
+    let mut bytes = [0u8; ADDR_LEN_ACCOUNT];
+    bytes.copy_from_slice(&hash[..ADDR_LEN_ACCOUNT]);
+    bytes
+}
+
+/// Nibiru bech32 address of a module account.
+///
+/// ```
+/// use nibiru_std::address::module_address;
+///
+/// assert_eq!(
+///     module_address("fee_collector"),
+///     "nibi17xpfvakm2amg962yls6f84z3kell8c5l8u8ezw"
+/// );
+/// ```
+pub fn module_address(module_name: &str) -> String {
+    NibiruAddress::module(module_name).to_nibi_bech32()
+}
+
+/// Typed hash used by the Cosmos-SDK `address` package:
+/// `sha256(sha256(typ) || key)`. Equivalent to `address.Hash`.
+fn address_hash(typ: &[u8], key: &[u8]) -> [u8; 32] {
+    let type_hash = Sha256::digest(typ);
+    let mut hasher = Sha256::new();
+    hasher.update(type_hash);
+    hasher.update(key);
+    hasher.finalize().into()
+}
+
+/// Equivalent to `address.Module(moduleName, derivationKeys...)` in the
+/// Cosmos-SDK.
+///
+/// Without derivation keys, this is the 20-byte [module_address_bytes].
+/// Otherwise, the result is a 32-byte address derived from
+/// `hash("module", moduleName || 0x00 || key_0)` and then re-derived for each
+/// subsequent key with `address.Derive`.
+pub fn derived_module_address_bytes(
+    module_name: &str,
+    derivation_keys: &[&[u8]],
+) -> Vec<u8> {
+    let Some((first_key, rest)) = derivation_keys.split_first() else {
+        return module_address_bytes(module_name).to_vec();
+    };
+
+    // The zero byte avoids clashes between the module name and the first
+    // derivation key.
+    let mut key = Vec::with_capacity(module_name.len() + 1 + first_key.len());
+    key.extend_from_slice(module_name.as_bytes());
+    key.push(0);
+    key.extend_from_slice(first_key);
+
+    let mut addr = address_hash(b"module", &key);
+    for derivation_key in rest {
+        addr = address_hash(&addr, derivation_key);
+    }
+    addr.to_vec()
+}
+
+/// Nibiru bech32 address of a derived module account. See
+/// [derived_module_address_bytes].
+pub fn derived_module_address(
+    module_name: &str,
+    derivation_keys: &[&[u8]],
+) -> String {
+    let bytes = derived_module_address_bytes(module_name, derivation_keys);
+    bech32_encode(HRP_ACCOUNT, &bytes, Bech32Variant::Bech32)
+        .expect("\"nibi\" is a valid bech32 HRP")
+}
+
+/// Name of the wasmd module, used as the module name when deriving contract
+/// addresses.
+const WASM_MODULE_NAME: &str = "wasm";
+
+/// Appends `data` prefixed by its length as a big-endian `u64`, like wasmd's
+/// `UInt64LengthPrefix`.
+fn extend_length_prefixed(buf: &mut Vec<u8>, data: &[u8]) {
+    buf.extend_from_slice(&(data.len() as u64).to_be_bytes());
+    buf.extend_from_slice(data);
+}
+
+/// Address of a contract created with `MsgInstantiateContract2`. Equivalent
+/// to wasmd's `BuildContractAddressPredictable`.
+///
+/// `checksum` is the SHA-256 hash of the Wasm byte code, `creator` the
+/// canonical address of the sender, and `init_msg` the raw instantiate
+/// message. `init_msg` only matters when the instantiation sets
+/// `fix_msg = true`; otherwise pass an empty slice.
+pub fn instantiate2_address_bytes(
+    checksum: &[u8],
+    creator: &[u8],
+    salt: &[u8],
+    init_msg: &[u8],
+) -> NibiruResult<[u8; ADDR_LEN_CONTRACT]> {
+    if checksum.len() != 32 {
+        return Err(cw::Instantiate2AddressError::InvalidChecksumLength.into());
+    }
+    if salt.is_empty() || salt.len() > 64 {
+        return Err(cw::Instantiate2AddressError::InvalidSaltLength.into());
+    }
+
+    let mut contract_id = Vec::with_capacity(
+        32 + checksum.len() + creator.len() + salt.len() + init_msg.len(),
+    );
+    extend_length_prefixed(&mut contract_id, checksum);
+    extend_length_prefixed(&mut contract_id, creator);
+    extend_length_prefixed(&mut contract_id, salt);
+    extend_length_prefixed(&mut contract_id, init_msg);
+
+    let addr = derived_module_address_bytes(WASM_MODULE_NAME, &[&contract_id]);
+    let mut bytes = [0u8; ADDR_LEN_CONTRACT];
+    bytes.copy_from_slice(&addr[..ADDR_LEN_CONTRACT]);
+    Ok(bytes)
+}
+
+/// Nibiru bech32 address of a contract created with
+/// `MsgInstantiateContract2` by the bech32 address `creator`. See
+/// [instantiate2_address_bytes].
+///
+/// ```
+/// use nibiru_std::address::instantiate2_address;
+///
+/// let checksum = hex::decode(
+///     "13a1fc994cc6d1c81b746ee0c0ff6f90043875e0bf1d9be6b7d779fc978dc2a5",
+/// )
+/// .unwrap();
+/// let addr = instantiate2_address(
+///     &checksum,
+///     "nibi1gc24lt74ses9swkq6g7cug4e5y72p7e34jqgul",
+///     b"salt",
+///     b"",
+/// )
+/// .unwrap();
+/// assert!(addr.starts_with("nibi1"));
+/// assert_eq!(addr.len(), "nibi1".len() + 58);
+/// ```
+pub fn instantiate2_address(
+    checksum: &[u8],
+    creator: &str,
+    salt: &[u8],
+    init_msg: &[u8],
+) -> NibiruResult<String> {
+    let (_hrp, creator_bytes, _variant) = bech32_decode(creator)?;
+    let bytes =
+        instantiate2_address_bytes(checksum, &creator_bytes, salt, init_msg)?;
+    bech32_encode(HRP_ACCOUNT, &bytes, Bech32Variant::Bech32)
+}
+
+/// Nibiru bech32 address of a contract created with `MsgInstantiateContract`,
+/// where `instance_id` is the global contract sequence number at the time of
+/// instantiation. Equivalent to wasmd's `BuildContractAddressClassic`.
+pub fn contract_address_classic(code_id: u64, instance_id: u64) -> String {
+    let mut contract_id = Vec::with_capacity(16);
+    contract_id.extend_from_slice(&code_id.to_be_bytes());
+    contract_id.extend_from_slice(&instance_id.to_be_bytes());
+    let addr = derived_module_address_bytes(WASM_MODULE_NAME, &[&contract_id]);
+    bech32_encode(
+        HRP_ACCOUNT,
+        &addr[..ADDR_LEN_CONTRACT],
+        Bech32Variant::Bech32,
+    )
+    .expect("\"nibi\" is a valid bech32 HRP")
+}
+
+/// NibiruAddress: An address on Nibiru, typed by what it can be converted to.
+///
+/// Only 20-byte addresses have an Ethereum equivalent. A 32-byte contract
+/// address refuses to convert to hex instead of being truncated.
+///
+/// ```
+/// use nibiru_std::address::{NibiruAddress, HRP_VALOPER};
+///
+/// let addr = NibiruAddress::from_bech32(
+///     "nibi1gc24lt74ses9swkq6g7cug4e5y72p7e34jqgul",
+/// )
+/// .unwrap();
+/// assert_eq!(
+///     addr.to_eth_address().unwrap(),
+///     "0x46155fAfd58660583ac0d23d8E22B9A13Ca0fb31"
+/// );
+/// assert!(addr.to_bech32(HRP_VALOPER).unwrap().starts_with("nibivaloper1"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NibiruAddress {
+    /// 20-byte account address, shared by the Cosmos and EVM sides.
+    Account20([u8; ADDR_LEN_ACCOUNT]),
+    /// 32-byte address of a Wasm contract.
+    Contract32([u8; ADDR_LEN_CONTRACT]),
+    /// Module account, addressed by module name. See [module_address_bytes].
+    Module(String),
+}
+
+impl NibiruAddress {
+    /// Parses a bech32 address with any HRP. 20-byte addresses become
+    /// `Account20` (module accounts can't be told apart from their bytes) and
+    /// 32-byte addresses become `Contract32`.
+    pub fn from_bech32(addr: &str) -> NibiruResult<Self> {
+        let (_hrp, bytes, _variant) = bech32_decode(addr)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Parses a bech32 address whose HRP must be `expected_hrp`.
+    pub fn from_bech32_with_hrp(
+        addr: &str,
+        expected_hrp: &str,
+    ) -> NibiruResult<Self> {
+        let (bytes, _variant) = bech32_decode_with_hrp(addr, expected_hrp)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Parses a hex Ethereum address, verifying its EIP-55 checksum when it
+    /// has one. See [parse_eth_address].
+    pub fn from_eth_address(eth_addr: &str) -> NibiruResult<Self> {
+        Ok(NibiruAddress::Account20(parse_eth_address(eth_addr)?))
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> NibiruResult<Self> {
+        match bytes.len() {
+            ADDR_LEN_ACCOUNT => {
+                Ok(NibiruAddress::Account20(bytes.try_into().unwrap()))
+            }
+            ADDR_LEN_CONTRACT => {
+                Ok(NibiruAddress::Contract32(bytes.try_into().unwrap()))
+            }
+            len => Err(NibiruError::UnsupportedAddressLength { len }),
+        }
+    }
+
+    pub fn module(module_name: impl Into<String>) -> Self {
+        NibiruAddress::Module(module_name.into())
+    }
+
+    /// Raw address bytes.
+    pub fn bytes(&self) -> Vec<u8> {
+        match self {
+            NibiruAddress::Account20(bytes) => bytes.to_vec(),
+            NibiruAddress::Contract32(bytes) => bytes.to_vec(),
+            NibiruAddress::Module(name) => module_address_bytes(name).to_vec(),
+        }
+    }
+
+    pub fn to_bech32(&self, hrp: &str) -> NibiruResult<String> {
+        bech32_encode(hrp, &self.bytes(), Bech32Variant::Bech32)
+    }
+
+    /// Bech32 address with the "nibi" HRP.
+    pub fn to_nibi_bech32(&self) -> String {
+        self.to_bech32(HRP_ACCOUNT)
+            .expect("\"nibi\" is a valid bech32 HRP")
+    }
+
+    /// EIP-55 checksummed hex address. Errors for 32-byte addresses, which
+    /// have no lossless Ethereum equivalent.
+    pub fn to_eth_address(&self) -> NibiruResult<String> {
+        match self {
+            NibiruAddress::Account20(bytes) => Ok(eip55_checksum_address(bytes)),
+            NibiruAddress::Module(name) => {
+                Ok(eip55_checksum_address(&module_address_bytes(name)))
+            }
+            NibiruAddress::Contract32(_) => {
+                Err(NibiruError::LossyAddressConversion {
+                    len: ADDR_LEN_CONTRACT,
+                })
+            }
+        }
+    }
+
+    pub fn to_canonical(&self) -> cw::CanonicalAddr {
+        cw::CanonicalAddr::from(self.bytes())
+    }
+}
+
+impl fmt::Display for NibiruAddress {
+    /// Formats the address as "nibi" bech32.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_nibi_bech32())
+    }
+}
+
 /// Converts a Nibiru bech32 address to an Ethereum hex address.
 ///
 /// This function decodes a bech32-encoded Nibiru address (with "nibi" prefix)
-/// and converts it to an Ethereum-compatible hex address by taking the first
-/// 20 bytes of the decoded data.
+/// and converts it to a lowercase Ethereum-compatible hex address. Only
+/// 20-byte addresses are accepted. Use [NibiruAddress::to_eth_address] for
+/// EIP-55 checksummed output.
 ///
 /// # Arguments
 ///
@@ -17,7 +461,9 @@ use crate::errors::{NibiruError, NibiruResult};
 /// # Returns
 ///
 /// * `Ok(String)` - The Ethereum hex address prefixed with "0x"
-/// * `Err(NibiruError)` - If the address is invalid, has wrong prefix, or is too short
+/// * `Err(NibiruError)` - If the address is invalid, has wrong prefix, or is
+///   not 20 bytes long. 32-byte contract addresses are rejected rather than
+///   truncated.
 ///
 /// # Example
 ///
@@ -29,27 +475,17 @@ use crate::errors::{NibiruError, NibiruResult};
 /// assert_eq!(eth_addr, "0x46155fafd58660583ac0d23d8e22b9a13ca0fb31");
 /// ```
 pub fn nibiru_bech32_to_eth_address(bech32_addr: &str) -> NibiruResult<String> {
-    // Decode the bech32 address
-    let (hrp, data, _variant) = bech32::decode(bech32_addr)?;
-
-    // Verify the human-readable part is "nibi"
-    if hrp != "nibi" {
-        return Err(NibiruError::InvalidBech32Prefix {
-            expected: "nibi".to_string(),
-            actual: hrp,
-        });
-    }
-
-    // Convert from base32 to bytes
-    let bytes = Vec::<u8>::from_base32(&data)?;
+    let (bytes, _variant) = bech32_decode_with_hrp(bech32_addr, HRP_ACCOUNT)?;
 
     // Ethereum addresses are 20 bytes
-    if bytes.len() < 20 {
+    if bytes.len() < ADDR_LEN_ACCOUNT {
         return Err(NibiruError::InvalidAddressLength);
     }
+    if bytes.len() > ADDR_LEN_ACCOUNT {
+        return Err(NibiruError::LossyAddressConversion { len: bytes.len() });
+    }
 
-    // Take the first 20 bytes and format as hex with 0x prefix
-    let eth_addr = format!("0x{}", hex::encode(&bytes[..20]));
+    let eth_addr = format!("0x{}", hex::encode(bytes));
     Ok(eth_addr)
 }
 
@@ -57,6 +493,7 @@ pub fn nibiru_bech32_to_eth_address(bech32_addr: &str) -> NibiruResult<String> {
 ///
 /// This function takes an Ethereum address in hex format (with or without "0x" prefix)
 /// and converts it to a bech32-encoded Nibiru address with "nibi" prefix.
+/// Mixed-case input must carry a valid EIP-55 checksum.
 ///
 /// # Arguments
 ///
@@ -65,7 +502,8 @@ pub fn nibiru_bech32_to_eth_address(bech32_addr: &str) -> NibiruResult<String> {
 /// # Returns
 ///
 /// * `Ok(String)` - The Nibiru bech32 address
-/// * `Err(NibiruError)` - If the address is invalid or not exactly 20 bytes
+/// * `Err(NibiruError)` - If the address is invalid, not exactly 20 bytes, or
+///   has a bad checksum
 ///
 /// # Example
 ///
@@ -77,32 +515,8 @@ pub fn nibiru_bech32_to_eth_address(bech32_addr: &str) -> NibiruResult<String> {
 /// assert_eq!(bech32_addr, "nibi1gc24lt74ses9swkq6g7cug4e5y72p7e34jqgul");
 /// ```
 pub fn eth_address_to_nibiru_bech32(eth_addr: &str) -> NibiruResult<String> {
-    // Remove "0x" prefix if present
-    let hex_str = eth_addr.strip_prefix("0x").unwrap_or(eth_addr);
-
-    // Validate hex string length (20 bytes = 40 hex chars)
-    if hex_str.len() != 40 {
-        return Err(NibiruError::InvalidEthAddress(format!(
-            "Ethereum address must be 20 bytes (40 hex chars), got {} chars",
-            hex_str.len()
-        )));
-    }
-
-    // Decode hex to bytes
-    let bytes = hex::decode(hex_str)?;
-
-    // Sanity check: should be exactly 20 bytes
-    if bytes.len() != 20 {
-        return Err(NibiruError::InvalidEthAddress(format!(
-            "Invalid Ethereum address length: expected 20 bytes, got {}",
-            bytes.len()
-        )));
-    }
-
-    // Encode as bech32 with "nibi" prefix
-    let bech32_addr =
-        bech32::encode("nibi", bytes.to_base32(), bech32::Variant::Bech32)?;
-    Ok(bech32_addr)
+    let bytes = parse_eth_address(eth_addr)?;
+    bech32_encode(HRP_ACCOUNT, &bytes, Bech32Variant::Bech32)
 }
 
 #[cfg(test)]
@@ -293,4 +707,307 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_eip55_checksum() {
+        // Test vectors from the EIP-55 specification
+        let cases = [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+            "0x52908400098527886E0F7030069857D2E4169EE7",
+            "0xde709f2102306220921060314715629080e2fb77",
+        ];
+        for case in cases {
+            let bytes = parse_eth_address(case).unwrap();
+            assert_eq!(eip55_checksum_address(&bytes), case);
+            // Unchecksummed forms are accepted
+            assert_eq!(parse_eth_address(&case.to_lowercase()).unwrap(), bytes);
+            assert_eq!(
+                parse_eth_address(&case[2..].to_uppercase()).unwrap(),
+                bytes
+            );
+        }
+
+        // One flipped letter breaks the checksum
+        let bad = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD";
+        match eth_address_to_nibiru_bech32(bad) {
+            Err(NibiruError::InvalidEthAddress(msg)) => {
+                assert!(msg.contains("EIP-55"), "{msg}");
+            }
+            result => {
+                panic!("Expected InvalidEthAddress error, got: {result:?}")
+            }
+        }
+    }
+
+    #[test]
+    fn test_bech32_any_hrp_and_variant() {
+        let bytes = [7u8; 20];
+        for hrp in [HRP_ACCOUNT, HRP_VALOPER, HRP_VALCONS] {
+            for variant in [Bech32Variant::Bech32, Bech32Variant::Bech32m] {
+                let addr = bech32_encode(hrp, &bytes, variant).unwrap();
+                let (got_bytes, got_variant) =
+                    bech32_decode_with_hrp(&addr, hrp).unwrap();
+                assert_eq!(got_bytes, bytes);
+                assert_eq!(got_variant, variant);
+
+                // Converting the HRP keeps the variant
+                let converted = bech32_convert_hrp(&addr, "cosmos").unwrap();
+                let (hrp_out, bytes_out, variant_out) =
+                    bech32_decode(&converted).unwrap();
+                assert_eq!(
+                    (hrp_out.as_str(), bytes_out, variant_out),
+                    ("cosmos", bytes.to_vec(), variant)
+                );
+            }
+        }
+
+        let valoper =
+            bech32_encode(HRP_VALOPER, &bytes, Bech32Variant::Bech32).unwrap();
+        assert!(matches!(
+            bech32_decode_with_hrp(&valoper, HRP_ACCOUNT),
+            Err(NibiruError::InvalidBech32Prefix { .. })
+        ));
+    }
+
+    #[test]
+    fn test_contract_addresses_are_not_truncated() {
+        let contract = bech32_encode(
+            HRP_ACCOUNT,
+            &[9u8; ADDR_LEN_CONTRACT],
+            Bech32Variant::Bech32,
+        )
+        .unwrap();
+
+        assert_eq!(
+            nibiru_bech32_to_eth_address(&contract),
+            Err(NibiruError::LossyAddressConversion { len: 32 })
+        );
+
+        let addr = NibiruAddress::from_bech32(&contract).unwrap();
+        assert_eq!(addr, NibiruAddress::Contract32([9u8; 32]));
+        assert_eq!(
+            addr.to_eth_address(),
+            Err(NibiruError::LossyAddressConversion { len: 32 })
+        );
+        assert_eq!(addr.to_string(), contract);
+        assert_eq!(addr.to_canonical().len(), 32);
+    }
+
+    #[test]
+    fn test_nibiru_address_variants() {
+        let acc = "nibi1gc24lt74ses9swkq6g7cug4e5y72p7e34jqgul";
+        let eth = "0x46155fAfd58660583ac0d23d8E22B9A13Ca0fb31";
+
+        let from_bech32 = NibiruAddress::from_bech32(acc).unwrap();
+        let from_eth = NibiruAddress::from_eth_address(eth).unwrap();
+        assert_eq!(from_bech32, from_eth);
+        assert!(matches!(from_eth, NibiruAddress::Account20(_)));
+        assert_eq!(from_eth.to_eth_address().unwrap(), eth);
+        assert_eq!(from_eth.to_string(), acc);
+
+        let valoper = from_eth.to_bech32(HRP_VALOPER).unwrap();
+        assert_eq!(
+            NibiruAddress::from_bech32_with_hrp(&valoper, HRP_VALOPER).unwrap(),
+            from_eth
+        );
+
+        // Module accounts resolve to sha256(name)[:20], which is the address
+        // of e.g. the "gov" module on every Cosmos-SDK chain.
+        let gov = NibiruAddress::module("gov");
+        assert_eq!(
+            gov.to_bech32("cosmos").unwrap(),
+            "cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zn9kn"
+        );
+        let gov_bytes = NibiruAddress::from_bytes(&gov.bytes()).unwrap();
+        assert_eq!(gov_bytes.to_string(), gov.to_string());
+
+        assert_eq!(
+            NibiruAddress::from_bytes(&[0u8; 10]),
+            Err(NibiruError::UnsupportedAddressLength { len: 10 })
+        );
+    }
+
+    #[test]
+    fn test_module_addresses() {
+        // Module account addresses are identical across Cosmos-SDK chains, so
+        // these are checked against the well known "cosmos" addresses.
+        let cases = [
+            (
+                "fee_collector",
+                "cosmos17xpfvakm2amg962yls6f84z3kell8c5lserqta",
+            ),
+            (
+                "distribution",
+                "cosmos1jv65s3grqf6v6jl3dp4t6c9t9rk99cd88lyufl",
+            ),
+            (
+                "bonded_tokens_pool",
+                "cosmos1fl48vsnmsdzcv85q5d2q4z5ajdha8yu34mf0eh",
+            ),
+            (
+                "not_bonded_tokens_pool",
+                "cosmos1tygms3xhhs3yv487phx3dw4a95jn7t7lpm470r",
+            ),
+            ("gov", "cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zn9kn"),
+            ("mint", "cosmos1m3h30wlvsf8llruxtpukdvsy0km2kum8g38c8q"),
+        ];
+        for (module_name, cosmos_addr) in cases {
+            let nibi_addr = module_address(module_name);
+            assert_eq!(
+                bech32_convert_hrp(cosmos_addr, HRP_ACCOUNT).unwrap(),
+                nibi_addr,
+                "module {module_name}"
+            );
+            assert_eq!(derived_module_address(module_name, &[]), nibi_addr);
+        }
+    }
+
+    #[test]
+    fn test_derived_module_addresses() {
+        // Without keys, these are the module accounts on Nibiru
+        assert_eq!(
+            derived_module_address("transfer", &[]),
+            "nibi1yl6hdjhmkf37639730gffanpzndzdpmhe6js7s"
+        );
+        assert_eq!(
+            derived_module_address("evm", &[]),
+            "nibi1vqu8rska6swzdmnhf90zuv0xmelej4lq6hpdam"
+        );
+
+        // With one key: the wasmd address of the first contract of code 1,
+        // "wasm14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9s0phg4d".
+        let mut contract_id = 1u64.to_be_bytes().to_vec();
+        contract_id.extend_from_slice(&1u64.to_be_bytes());
+        assert_eq!(
+            derived_module_address("wasm", &[&contract_id]),
+            "nibi14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9ssa9gcs"
+        );
+
+        // address.Module(name, k0, k1) == address.Derive(address.Module(name, k0), k1)
+        let first = derived_module_address_bytes("myModule", &[&[1, 2]]);
+        let second = derived_module_address_bytes("myModule", &[&[1, 2], b"k1"]);
+        assert_eq!(first.len(), 32);
+        assert_eq!(second.to_vec(), address_hash(&first, b"k1").to_vec());
+
+        // The zero byte separator keeps the name and key from running together
+        assert_ne!(
+            derived_module_address_bytes("myModule", &[b"2"]),
+            derived_module_address_bytes("myModule2", &[b""]),
+        );
+    }
+
+    #[test]
+    fn test_classic_contract_address() {
+        // First contract instantiated on any wasmd chain
+        assert_eq!(
+            bech32_convert_hrp(&contract_address_classic(1, 1), "wasm").unwrap(),
+            "wasm14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9s0phg4d"
+        );
+    }
+
+    #[test]
+    fn test_instantiate2_address() {
+        // Test vectors from wasmd, as published in cosmjs and cosmwasm-std
+        // (testdata/instantiate2_addresses.json).
+        struct Case {
+            checksum: &'static str,
+            creator: &'static str,
+            salt: &'static str,
+            msg: &'static str,
+            address_data: &'static str,
+            address: &'static str,
+        }
+        let cases = [
+            Case {
+                checksum: "13a1fc994cc6d1c81b746ee0c0ff6f90043875e0bf1d9be6b7d779fc978dc2a5",
+                creator: "purple1nxvenxve42424242hwamhwamenxvenxvhxf2py",
+                salt: "61",
+                msg: "",
+                address_data: "5e865d3e45ad3e961f77fd77d46543417ced44d924dc3e079b5415ff6775f847",
+                address: "purple1t6r960j945lfv8mhl4mage2rg97w63xeynwrupum2s2l7em4lprs9ce5hk",
+            },
+            Case {
+                checksum: "13a1fc994cc6d1c81b746ee0c0ff6f90043875e0bf1d9be6b7d779fc978dc2a5",
+                creator: "purple1nxvenxve42424242hwamhwamenxvenxvhxf2py",
+                salt: "61",
+                msg: "{}",
+                address_data: "0995499608947a5281e2c7ebd71bdb26a1ad981946dad57f6c4d3ee35de77835",
+                address: "",
+            },
+            Case {
+                checksum: "13a1fc994cc6d1c81b746ee0c0ff6f90043875e0bf1d9be6b7d779fc978dc2a5",
+                creator: "purple1nxvenxve42424242hwamhwamenxvenxvhxf2py",
+                salt: "aabbccddeeffffeeddbbccddaa66551155aaaabbcc787878789900aabbccddeeffffeeddbbccddaa66551155aaaabbcc787878789900aabbbbcc221100acadae",
+                msg: "",
+                address_data: "9384c6248c0bb171e306fd7da0993ec1e20eba006452a3a9e078883eb3594564",
+                address: "",
+            },
+            Case {
+                checksum: "1da6c16de2cbaf7ad8cbb66f0925ba33f5c278cb2491762d04658c1480ea229b",
+                creator: "purple1nxvenxve42424242hwamhwamenxvenxvmhwamhwaamhwamhwlllsatsy6m",
+                salt: "aabbccddeeffffeeddbbccddaa66551155aaaabbcc787878789900aabbccddeeffffeeddbbccddaa66551155aaaabbcc787878789900aabbbbcc221100acadae",
+                msg: r#"{"some":123,"structure":{"nested":["ok",true]}}"#,
+                address_data: "ccdf9dea141a6c2475870529ab38fae9dec30df28e005894fe6578b66133ab4a",
+                address: "purple1en0em6s5rfkzgav8q556kw86a80vxr0j3cq93987v4utvcfn4d9q0tql4w",
+            },
+        ];
+
+        for case in cases {
+            let checksum = hex::decode(case.checksum).unwrap();
+            let salt = hex::decode(case.salt).unwrap();
+            let (_hrp, creator, _variant) = bech32_decode(case.creator).unwrap();
+
+            let bytes = instantiate2_address_bytes(
+                &checksum,
+                &creator,
+                &salt,
+                case.msg.as_bytes(),
+            )
+            .unwrap();
+            assert_eq!(hex::encode(bytes), case.address_data);
+
+            // Agrees with cosmwasm-std whenever the message is not fixed
+            if case.msg.is_empty() {
+                let cw_addr = cw::instantiate2_address(
+                    &checksum,
+                    &cw::CanonicalAddr::from(creator.clone()),
+                    &salt,
+                )
+                .unwrap();
+                assert_eq!(cw_addr.as_slice(), bytes);
+            }
+
+            let nibi_addr = instantiate2_address(
+                &checksum,
+                case.creator,
+                &salt,
+                case.msg.as_bytes(),
+            )
+            .unwrap();
+            assert!(nibi_addr.starts_with("nibi1"));
+            if !case.address.is_empty() {
+                assert_eq!(
+                    bech32_convert_hrp(case.address, HRP_ACCOUNT).unwrap(),
+                    nibi_addr
+                );
+            }
+        }
+
+        let checksum = [0u8; 32];
+        assert_eq!(
+            instantiate2_address_bytes(&checksum, &[1; 20], b"", b""),
+            Err(cw::Instantiate2AddressError::InvalidSaltLength.into())
+        );
+        assert_eq!(
+            instantiate2_address_bytes(&checksum, &[1; 20], &[0; 65], b""),
+            Err(cw::Instantiate2AddressError::InvalidSaltLength.into())
+        );
+        assert_eq!(
+            instantiate2_address_bytes(&checksum[..31], &[1; 20], b"a", b""),
+            Err(cw::Instantiate2AddressError::InvalidChecksumLength.into())
+        );
+    }
 }
@@ -0,0 +1,455 @@
+//! authz.rs: Builders for `cosmos.authz.v1beta1` messages, which let a
+//! grantee, such as a contract, execute messages on behalf of a granter.
+//!
+//! - [AuthzExec]: `MsgExec` wrapping any [NibiruStargateMsg] as an `Any`.
+//! - [Authorization]: What a `MsgGrant` permits, encoded to and decoded from
+//!   `prost_types::Any`.
+//! - [AuthzGrant]: A grant returned by the authz grant queries. See
+//!   [crate::querier::NibiruQuerier::authz_grants].
+
+use cosmwasm_std as cw;
+use prost::Message;
+
+use crate::{
+    errors::{NibiruError, NibiruResult},
+    proto::{
+        cosmos::{authz::v1beta1 as authz, bank::v1beta1 as bank},
+        cw_coins_from_proto, cw_timestamp_from_proto, proto_coins_from_cw,
+        proto_timestamp_from_cw, NibiruStargateMsg,
+    },
+};
+
+/// AuthzExec: Builder for a `MsgExec`, which the `grantee` sends to execute
+/// `msgs` with the authority of the accounts that granted it permission.
+/// Each inner message must be signed by, i.e. name as its sender, one of
+/// those granters.
+///
+/// ```
+/// use cosmwasm_std::{coin, CosmosMsg};
+/// use nibiru_std::{
+///     authz::AuthzExec,
+///     proto::{cosmos::bank::v1beta1::MsgSend, NibiruStargateMsg},
+/// };
+///
+/// let send = MsgSend {
+///     from_address: "nibi1granter".to_string(),
+///     to_address: "nibi1recipient".to_string(),
+///     amount: vec![coin(5, "unibi").into()],
+/// };
+/// let exec = AuthzExec::new("nibi1contract").msg(&send).into_msg();
+/// assert_eq!(exec.msgs[0].type_url, "/cosmos.bank.v1beta1.MsgSend");
+///
+/// let cosmos_msg: CosmosMsg = exec.into_stargate_msg();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AuthzExec {
+    pub grantee: String,
+    pub msgs: Vec<prost_types::Any>,
+}
+
+impl AuthzExec {
+    pub fn new(grantee: impl Into<String>) -> Self {
+        AuthzExec {
+            grantee: grantee.into(),
+            msgs: vec![],
+        }
+    }
+
+    /// Appends a message to execute on behalf of its signer.
+    pub fn msg(mut self, msg: &impl NibiruStargateMsg) -> Self {
+        self.msgs.push(msg.to_any());
+        self
+    }
+
+    /// Appends a message that is already wrapped as an `Any`, such as one
+    /// without a `prost::Name` implementation.
+    pub fn any(mut self, any: prost_types::Any) -> Self {
+        self.msgs.push(any);
+        self
+    }
+
+    pub fn into_msg(self) -> authz::MsgExec {
+        authz::MsgExec {
+            grantee: self.grantee,
+            msgs: self.msgs,
+        }
+    }
+}
+
+/// Authorization: What an authz grant permits its grantee to do.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Authorization {
+    /// `GenericAuthorization`: Permits any message with the type URL `msg`,
+    /// without limits.
+    Generic { msg: String },
+    /// `SendAuthorization`: Permits `MsgSend` for up to `spend_limit` in
+    /// total. If `allow_list` is not empty, only its addresses may receive
+    /// the funds.
+    Send {
+        spend_limit: Vec<cw::Coin>,
+        allow_list: Vec<String>,
+    },
+    /// Any other authorization, such as a staking `StakeAuthorization`,
+    /// left encoded.
+    Other(prost_types::Any),
+}
+
+impl Authorization {
+    /// Generic authorization for the message type `M`.
+    ///
+    /// ```
+    /// use nibiru_std::{
+    ///     authz::Authorization,
+    ///     proto::nibiru::tokenfactory::MsgMint,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     Authorization::generic::<MsgMint>(),
+    ///     Authorization::Generic {
+    ///         msg: "/nibiru.tokenfactory.v1.MsgMint".to_string()
+    ///     },
+    /// );
+    /// ```
+    pub fn generic<M: prost::Name>() -> Self {
+        Authorization::Generic {
+            msg: <M as prost::Name>::type_url(),
+        }
+    }
+
+    /// Send authorization with no recipient allow list.
+    pub fn send(spend_limit: Vec<cw::Coin>) -> Self {
+        Authorization::Send {
+            spend_limit,
+            allow_list: vec![],
+        }
+    }
+
+    /// Type URL of the message this authorization applies to. This is the
+    /// `msg_type_url` used to revoke the grant or to query it. `None` for
+    /// authorizations this crate cannot decode.
+    pub fn msg_type_url(&self) -> Option<String> {
+        match self {
+            Authorization::Generic { msg } => Some(msg.clone()),
+            Authorization::Send { .. } => {
+                Some(<bank::MsgSend as prost::Name>::type_url())
+            }
+            Authorization::Other(_) => None,
+        }
+    }
+
+    pub fn to_any(&self) -> prost_types::Any {
+        match self {
+            Authorization::Generic { msg } => {
+                authz::GenericAuthorization { msg: msg.clone() }.to_any()
+            }
+            Authorization::Send {
+                spend_limit,
+                allow_list,
+            } => bank::SendAuthorization {
+                spend_limit: proto_coins_from_cw(spend_limit.clone()),
+                allow_list: allow_list.clone(),
+            }
+            .to_any(),
+            Authorization::Other(any) => any.clone(),
+        }
+    }
+
+    /// Decodes an authorization from its `Any` form. Unknown authorization
+    /// types become [Authorization::Other].
+    pub fn from_any(any: &prost_types::Any) -> NibiruResult<Self> {
+        let type_url = any.type_url.as_str();
+        if type_url == <authz::GenericAuthorization as prost::Name>::type_url() {
+            let generic = authz::GenericAuthorization::decode(&*any.value)?;
+            Ok(Authorization::Generic { msg: generic.msg })
+        } else if type_url
+            == <bank::SendAuthorization as prost::Name>::type_url()
+        {
+            let send = bank::SendAuthorization::decode(&*any.value)?;
+            Ok(Authorization::Send {
+                spend_limit: cw_coins_from_proto(send.spend_limit)?,
+                allow_list: send.allow_list,
+            })
+        } else {
+            Ok(Authorization::Other(any.clone()))
+        }
+    }
+}
+
+/// Grants `grantee` the `authorization` over the account of `granter`, who
+/// must sign the message. A grant without an `expiration` never expires.
+///
+/// ```
+/// use cosmwasm_std::{coin, Timestamp};
+/// use nibiru_std::authz::{grant, Authorization};
+///
+/// let msg = grant(
+///     "nibi1granter",
+///     "nibi1contract",
+///     &Authorization::send(vec![coin(1_000, "unibi")]),
+///     Some(Timestamp::from_seconds(1_800_000_000)),
+/// );
+/// let authorization = msg.grant.unwrap().authorization.unwrap();
+/// assert_eq!(authorization.type_url, "/cosmos.bank.v1beta1.SendAuthorization");
+/// ```
+pub fn grant(
+    granter: impl Into<String>,
+    grantee: impl Into<String>,
+    authorization: &Authorization,
+    expiration: Option<cw::Timestamp>,
+) -> authz::MsgGrant {
+    authz::MsgGrant {
+        granter: granter.into(),
+        grantee: grantee.into(),
+        grant: Some(authz::Grant {
+            authorization: Some(authorization.to_any()),
+            expiration: expiration.map(proto_timestamp_from_cw),
+        }),
+    }
+}
+
+/// Revokes the grant from `granter` to `grantee` for the message type
+/// `msg_type_url`.
+pub fn revoke(
+    granter: impl Into<String>,
+    grantee: impl Into<String>,
+    msg_type_url: impl Into<String>,
+) -> authz::MsgRevoke {
+    authz::MsgRevoke {
+        granter: granter.into(),
+        grantee: grantee.into(),
+        msg_type_url: msg_type_url.into(),
+    }
+}
+
+/// AuthzGrant: A decoded authz grant.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuthzGrant {
+    /// Empty when decoded from an `authz::Grant`, which does not record the
+    /// accounts. [crate::querier::NibiruQuerier::authz_grants] fills them
+    /// in.
+    pub granter: String,
+    pub grantee: String,
+    pub authorization: Authorization,
+    pub expiration: Option<cw::Timestamp>,
+}
+
+impl AuthzGrant {
+    pub fn is_expired(&self, now: cw::Timestamp) -> bool {
+        self.expiration.is_some_and(|expiration| expiration <= now)
+    }
+
+    /// Whether the grant lets its grantee execute messages of type
+    /// `msg_type_url` at time `now`. Spend limits of a send authorization
+    /// are not checked.
+    pub fn permits(&self, msg_type_url: &str, now: cw::Timestamp) -> bool {
+        !self.is_expired(now)
+            && self.authorization.msg_type_url().as_deref() == Some(msg_type_url)
+    }
+
+    fn decode(
+        granter: String,
+        grantee: String,
+        authorization: Option<prost_types::Any>,
+        expiration: Option<prost_types::Timestamp>,
+    ) -> NibiruResult<Self> {
+        let authorization = authorization.ok_or_else(|| {
+            NibiruError::CwStd(cw::StdError::generic_err(
+                "authz grant is missing \"authorization\"",
+            ))
+        })?;
+        Ok(AuthzGrant {
+            granter,
+            grantee,
+            authorization: Authorization::from_any(&authorization)?,
+            expiration: expiration
+                .as_ref()
+                .map(cw_timestamp_from_proto)
+                .transpose()?,
+        })
+    }
+}
+
+impl TryFrom<authz::Grant> for AuthzGrant {
+    type Error = NibiruError;
+
+    fn try_from(grant: authz::Grant) -> Result<Self, Self::Error> {
+        AuthzGrant::decode(
+            String::new(),
+            String::new(),
+            grant.authorization,
+            grant.expiration,
+        )
+    }
+}
+
+impl TryFrom<authz::GrantAuthorization> for AuthzGrant {
+    type Error = NibiruError;
+
+    fn try_from(grant: authz::GrantAuthorization) -> Result<Self, Self::Error> {
+        AuthzGrant::decode(
+            grant.granter,
+            grant.grantee,
+            grant.authorization,
+            grant.expiration,
+        )
+    }
+}
+
+#[cfg(all(test, feature = "all_protos"))]
+mod tests {
+    use cosmwasm_std::{coin, Timestamp};
+    use prost::Message;
+
+    use super::*;
+    use crate::{
+        errors::TestResult,
+        proto::{cosmos, nibiru, NibiruProstMsg},
+    };
+
+    #[test]
+    fn exec_wraps_msgs_as_any() -> TestResult {
+        let send = bank::MsgSend {
+            from_address: "nibi1granter".to_string(),
+            to_address: "nibi1recipient".to_string(),
+            amount: vec![coin(5, "unibi").into()],
+        };
+        let mint = nibiru::tokenfactory::MsgMint {
+            sender: "nibi1granter".to_string(),
+            coin: Some(coin(7, "tf/nibi1granter/foo").into()),
+            mint_to: String::new(),
+        };
+        let exec = AuthzExec::new("nibi1contract")
+            .msg(&send)
+            .msg(&mint)
+            .into_msg();
+
+        assert_eq!(exec.grantee, "nibi1contract");
+        let type_urls: Vec<&str> =
+            exec.msgs.iter().map(|any| any.type_url.as_str()).collect();
+        assert_eq!(
+            type_urls,
+            [
+                "/cosmos.bank.v1beta1.MsgSend",
+                "/nibiru.tokenfactory.v1.MsgMint"
+            ]
+        );
+        assert_eq!(bank::MsgSend::from_any(&exec.msgs[0])?, send);
+        assert_eq!(
+            nibiru::tokenfactory::MsgMint::from_any(&exec.msgs[1])?,
+            mint
+        );
+
+        // The wrapped message survives a full encoding round trip.
+        let decoded = authz::MsgExec::decode(exec.to_bytes().as_slice())?;
+        assert_eq!(decoded, exec);
+        Ok(())
+    }
+
+    #[test]
+    fn authorization_any_round_trip() -> TestResult {
+        let stake_auth = prost_types::Any {
+            type_url: "/cosmos.staking.v1beta1.StakeAuthorization".to_string(),
+            value: vec![],
+        };
+        let cases = [
+            (
+                Authorization::generic::<cosmos::gov::v1::MsgVote>(),
+                "/cosmos.authz.v1beta1.GenericAuthorization",
+                Some("/cosmos.gov.v1.MsgVote"),
+            ),
+            (
+                Authorization::Send {
+                    spend_limit: vec![coin(10, "unibi"), coin(3, "uusd")],
+                    allow_list: vec!["nibi1recipient".to_string()],
+                },
+                "/cosmos.bank.v1beta1.SendAuthorization",
+                Some("/cosmos.bank.v1beta1.MsgSend"),
+            ),
+            (
+                Authorization::Other(stake_auth),
+                "/cosmos.staking.v1beta1.StakeAuthorization",
+                None,
+            ),
+        ];
+        for (authorization, want_type_url, want_msg_type_url) in cases {
+            let any = authorization.to_any();
+            assert_eq!(any.type_url, want_type_url);
+            assert_eq!(Authorization::from_any(&any)?, authorization);
+            assert_eq!(
+                authorization.msg_type_url().as_deref(),
+                want_msg_type_url
+            );
+        }
+
+        let corrupt = prost_types::Any {
+            type_url: "/cosmos.authz.v1beta1.GenericAuthorization".to_string(),
+            value: vec![0xff],
+        };
+        assert!(Authorization::from_any(&corrupt).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn grant_and_revoke() -> TestResult {
+        let authorization = Authorization::send(vec![coin(1_000, "unibi")]);
+        let expiration = Timestamp::from_seconds(1_800_000_000);
+        let msg = grant(
+            "nibi1granter",
+            "nibi1contract",
+            &authorization,
+            Some(expiration),
+        );
+        assert_eq!(msg.granter, "nibi1granter");
+        assert_eq!(msg.grantee, "nibi1contract");
+
+        let decoded = AuthzGrant::try_from(msg.grant.unwrap())?;
+        assert_eq!(
+            decoded,
+            AuthzGrant {
+                granter: String::new(),
+                grantee: String::new(),
+                authorization,
+                expiration: Some(expiration),
+            }
+        );
+
+        let msg = revoke(
+            "nibi1granter",
+            "nibi1contract",
+            decoded.authorization.msg_type_url().unwrap(),
+        );
+        assert_eq!(msg.msg_type_url, "/cosmos.bank.v1beta1.MsgSend");
+        Ok(())
+    }
+
+    #[test]
+    fn grant_permits() -> TestResult {
+        let grant = AuthzGrant::try_from(authz::GrantAuthorization {
+            granter: "nibi1granter".to_string(),
+            grantee: "nibi1contract".to_string(),
+            authorization: Some(
+                Authorization::generic::<bank::MsgSend>().to_any(),
+            ),
+            expiration: Some(prost_types::Timestamp {
+                seconds: 100,
+                nanos: 0,
+            }),
+        })?;
+        assert_eq!(grant.granter, "nibi1granter");
+
+        let send_url = "/cosmos.bank.v1beta1.MsgSend";
+        assert!(grant.permits(send_url, Timestamp::from_seconds(99)));
+        assert!(!grant.permits(send_url, Timestamp::from_seconds(100)));
+        assert!(!grant.permits(
+            "/cosmos.bank.v1beta1.MsgMultiSend",
+            Timestamp::from_seconds(99)
+        ));
+
+        let no_authorization = authz::Grant {
+            authorization: None,
+            expiration: None,
+        };
+        assert!(AuthzGrant::try_from(no_authorization).is_err());
+        Ok(())
+    }
+}
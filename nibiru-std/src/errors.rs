@@ -35,11 +35,70 @@ pub enum NibiruError {
     )]
     InvalidAddressLength,
 
+    #[error(
+        "address of {len} bytes has no Ethereum equivalent; converting it would truncate it to 20 bytes"
+    )]
+    LossyAddressConversion { len: usize },
+
+    #[error("EVM ABI error: {0}")]
+    AbiError(String),
+
+    #[error("cannot derive instantiate2 address: {0}")]
+    Instantiate2AddressError(#[from] cw::Instantiate2AddressError),
+
+    #[error("unsupported address length: expected 20 or 32 bytes, got {len}")]
+    UnsupportedAddressLength { len: usize },
+
     #[error("Invalid Ethereum address format: {0}")]
     InvalidEthAddress(String),
 
     #[error("Failed to parse hex: {0}")]
     HexError(#[from] hex::FromHexError),
+
+    #[error(
+        "invalid amount \"{amount}\" for coin with denom \"{denom}\": {reason}"
+    )]
+    InvalidCoinAmount {
+        denom: String,
+        amount: String,
+        reason: String,
+    },
+
+    #[error("{0}")]
+    CoinsError(#[from] cw::CoinsError),
+
+    #[error("failed to decode protobuf message: {0}")]
+    ProstDecodeError(#[from] prost::DecodeError),
+
+    #[error("invalid public key: {0}")]
+    InvalidPubKey(String),
+
+    #[error("public key controls {pubkey_addr}, not the signer {signer}")]
+    SignerMismatch { signer: String, pubkey_addr: String },
+
+    #[error("signature verification failed")]
+    InvalidSignature,
+
+    #[error("{0}")]
+    VerificationError(#[from] cw::VerificationError),
+
+    #[error("submessage failed: {0}")]
+    SubMsgFailed(String),
+
+    #[error("reply has no message response with type URL {type_url}")]
+    MsgResponseNotFound { type_url: String },
+
+    #[error("invalid weighted vote: {0}")]
+    InvalidVoteWeights(String),
+
+    #[error("invalid token factory denom \"{denom}\": {reason}")]
+    InvalidTfDenom { denom: String, reason: String },
+
+    #[error("invalid denom metadata: {0}")]
+    InvalidDenomMetadata(String),
+
+    #[error("invalid IBC transfer: {0}")]
+    InvalidIbcTransfer(String),
 }
 
 #[derive(Error, Debug, PartialEq)]
@@ -52,6 +111,66 @@ pub enum MathError {
 
     #[error("could not parse to cosmosdk.io/math.LegacyDec: {0}")]
     SdkDecError(String),
+
+    #[error("could not parse to cosmosdk.io/math.Int: {0}")]
+    SdkIntError(String),
+
+    #[error("overflow: {lhs} {operation} {rhs}")]
+    Overflow {
+        operation: String,
+        lhs: String,
+        rhs: String,
+    },
+
+    #[error("{value} is out of range for {target}")]
+    OutOfRange { value: String, target: String },
+}
+
+/// Errors from reading oracle prices with [crate::oracle::OracleReader].
+#[derive(Error, Debug, PartialEq)]
+pub enum OracleError {
+    #[error("failed to query oracle price of {pair}: {reason}")]
+    QueryFailed { pair: String, reason: String },
+
+    #[error("invalid oracle price for {pair}: {value}")]
+    InvalidPrice { pair: String, value: String },
+
+    #[error("invalid oracle pair \"{0}\": expected \"base:quote\"")]
+    InvalidPair(String),
+
+    #[error("oracle price of {pair} is {age_blocks} blocks old, above the max of {max_age_blocks}")]
+    StaleBlocks {
+        pair: String,
+        age_blocks: u64,
+        max_age_blocks: u64,
+    },
+
+    #[error("oracle price of {pair} is {age_secs}s old, above the max of {max_age_secs}s")]
+    StaleSeconds {
+        pair: String,
+        age_secs: u64,
+        max_age_secs: u64,
+    },
+
+    #[error("spot price {spot} of {pair} deviates from its TWAP {twap} by more than {max_deviation}")]
+    TwapDeviation {
+        pair: String,
+        spot: cw::Decimal,
+        twap: cw::Decimal,
+        max_deviation: cw::Decimal,
+    },
+
+    #[error("pairs {base_pair} and {quote_pair} do not share a quote asset")]
+    QuoteMismatch {
+        base_pair: String,
+        quote_pair: String,
+    },
+}
+
+impl From<OracleError> for cw::StdError {
+    fn from(err: OracleError) -> cw::StdError {
+        cw::StdError::generic_err(err.to_string())
+    }
 }
 
 impl From<NibiruError> for cw::StdError {
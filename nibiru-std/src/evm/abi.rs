@@ -0,0 +1,1029 @@
+//! evm/abi.rs: Solidity ABI encoding and decoding.
+//!
+//! Implements the "Contract ABI Specification" for the elementary types,
+//! fixed and dynamic arrays, and tuples, which covers the calldata, return
+//! data, and event logs of typical EVM contracts.
+
+use std::{fmt, str::FromStr};
+
+use cosmwasm_std::{Int256, Uint256};
+use sha3::{Digest, Keccak256};
+
+use crate::{
+    address::{
+        bech32_decode, eip55_checksum_address, parse_eth_address,
+        ADDR_LEN_ACCOUNT,
+    },
+    errors::{NibiruError, NibiruResult},
+};
+
+/// Size in bytes of a single ABI word.
+pub const WORD_LEN: usize = 32;
+
+/// Keccak-256 hash.
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+/// First four bytes of the Keccak-256 hash of a canonical function signature,
+/// such as "transfer(address,uint256)".
+pub fn function_selector(signature: &str) -> [u8; 4] {
+    let hash = keccak256(signature.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Topic of an event, which is the Keccak-256 hash of its canonical
+/// signature, such as "Transfer(address,address,uint256)".
+pub fn event_topic(signature: &str) -> [u8; 32] {
+    keccak256(signature.as_bytes())
+}
+
+/// AbiType: A Solidity ABI type.
+///
+/// Parses from and formats to the canonical type names used in function
+/// signatures.
+///
+/// ```
+/// use nibiru_std::evm::abi::AbiType;
+///
+/// let ty: AbiType = "(address,uint256[])[2]".parse().unwrap();
+/// assert!(ty.is_dynamic());
+/// assert_eq!(ty.to_string(), "(address,uint256[])[2]");
+/// assert_eq!("uint".parse::<AbiType>().unwrap(), AbiType::Uint(256));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AbiType {
+    Address,
+    /// `uint<M>` with `M` the size in bits.
+    Uint(usize),
+    /// `int<M>` with `M` the size in bits.
+    Int(usize),
+    Bool,
+    /// `bytes<M>` with `M` the size in bytes.
+    FixedBytes(usize),
+    Bytes,
+    String,
+    /// `T[k]`
+    FixedArray(Box<AbiType>, usize),
+    /// `T[]`
+    Array(Box<AbiType>),
+    /// `(T1,T2,...)`
+    Tuple(Vec<AbiType>),
+}
+
+impl AbiType {
+    /// Whether values of this type are encoded in the tail of their enclosing
+    /// tuple.
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            AbiType::Bytes | AbiType::String | AbiType::Array(_) => true,
+            AbiType::FixedArray(elem, _) => elem.is_dynamic(),
+            AbiType::Tuple(types) => types.iter().any(AbiType::is_dynamic),
+            _ => false,
+        }
+    }
+
+    /// Errors on sizes that parsing rejects but a hand-built type can have:
+    /// `uint<M>` and `int<M>` need `M` in 8 to 256 and a multiple of 8, and
+    /// `bytes<M>` needs `M` in 1 to 32.
+    pub fn validate(&self) -> NibiruResult<()> {
+        match self {
+            AbiType::Uint(bits) | AbiType::Int(bits) if !valid_bits(*bits) => {
+                Err(abi_err(format!("invalid bit size in type {self}")))
+            }
+            AbiType::FixedBytes(len) if !(1..=WORD_LEN).contains(len) => {
+                Err(abi_err(format!("invalid type {self}")))
+            }
+            AbiType::FixedArray(elem, _) | AbiType::Array(elem) => {
+                elem.validate()
+            }
+            AbiType::Tuple(types) => {
+                types.iter().try_for_each(AbiType::validate)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Number of bytes a value of this type takes in the head of its
+    /// enclosing tuple. Errors if it does not fit in a `usize`.
+    fn head_len(&self) -> NibiruResult<usize> {
+        if self.is_dynamic() {
+            return Ok(WORD_LEN);
+        }
+        match self {
+            AbiType::FixedArray(elem, len) => {
+                elem.head_len()?.checked_mul(*len).ok_or_else(|| {
+                    abi_err(format!("head of {self} overflows usize"))
+                })
+            }
+            AbiType::Tuple(types) => types
+                .iter()
+                .try_fold(0, |sum, ty| checked_offset(sum, ty.head_len()?)),
+            _ => Ok(WORD_LEN),
+        }
+    }
+}
+
+impl fmt::Display for AbiType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AbiType::Address => write!(f, "address"),
+            AbiType::Uint(bits) => write!(f, "uint{bits}"),
+            AbiType::Int(bits) => write!(f, "int{bits}"),
+            AbiType::Bool => write!(f, "bool"),
+            AbiType::FixedBytes(len) => write!(f, "bytes{len}"),
+            AbiType::Bytes => write!(f, "bytes"),
+            AbiType::String => write!(f, "string"),
+            AbiType::FixedArray(elem, len) => write!(f, "{elem}[{len}]"),
+            AbiType::Array(elem) => write!(f, "{elem}[]"),
+            AbiType::Tuple(types) => {
+                write!(f, "({})", join_types(types))
+            }
+        }
+    }
+}
+
+fn valid_bits(bits: usize) -> bool {
+    (8..=256).contains(&bits) && bits.is_multiple_of(8)
+}
+
+fn join_types(types: &[AbiType]) -> String {
+    types
+        .iter()
+        .map(AbiType::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+impl FromStr for AbiType {
+    type Err = NibiruError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        // Array suffixes bind to everything before them, so peel them off
+        // from the right.
+        if let Some(stripped) = s.strip_suffix(']') {
+            let open = stripped
+                .rfind('[')
+                .ok_or_else(|| abi_err(format!("unbalanced \"]\" in {s}")))?;
+            let elem: AbiType = stripped[..open].parse()?;
+            let size = &stripped[open + 1..];
+            return if size.is_empty() {
+                Ok(AbiType::Array(Box::new(elem)))
+            } else {
+                let len = size.parse().map_err(|_| {
+                    abi_err(format!("invalid array length in {s}"))
+                })?;
+                Ok(AbiType::FixedArray(Box::new(elem), len))
+            };
+        }
+        if let Some(inner) =
+            s.strip_prefix('(').and_then(|rest| rest.strip_suffix(')'))
+        {
+            return Ok(AbiType::Tuple(parse_type_list(inner)?));
+        }
+
+        let bits = |digits: &str, default: usize| -> NibiruResult<usize> {
+            if digits.is_empty() {
+                return Ok(default);
+            }
+            match digits.parse::<usize>() {
+                Ok(bits) if valid_bits(bits) => Ok(bits),
+                _ => Err(abi_err(format!("invalid bit size in type {s}"))),
+            }
+        };
+        match s {
+            "address" => Ok(AbiType::Address),
+            "bool" => Ok(AbiType::Bool),
+            "string" => Ok(AbiType::String),
+            "bytes" => Ok(AbiType::Bytes),
+            _ => {
+                if let Some(digits) = s.strip_prefix("uint") {
+                    Ok(AbiType::Uint(bits(digits, 256)?))
+                } else if let Some(digits) = s.strip_prefix("int") {
+                    Ok(AbiType::Int(bits(digits, 256)?))
+                } else if let Some(digits) = s.strip_prefix("bytes") {
+                    match digits.parse::<usize>() {
+                        Ok(len) if (1..=WORD_LEN).contains(&len) => {
+                            Ok(AbiType::FixedBytes(len))
+                        }
+                        _ => Err(abi_err(format!("invalid type {s}"))),
+                    }
+                } else {
+                    Err(abi_err(format!("unknown type \"{s}\"")))
+                }
+            }
+        }
+    }
+}
+
+/// Parses a comma separated list of types, such as the parameter list of a
+/// function signature. Commas nested in tuples are not separators.
+pub fn parse_type_list(list: &str) -> NibiruResult<Vec<AbiType>> {
+    if list.trim().is_empty() {
+        return Ok(vec![]);
+    }
+    let mut types = vec![];
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (idx, c) in list.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                types.push(list[start..idx].parse()?);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+        if depth < 0 {
+            return Err(abi_err(format!("unbalanced \")\" in {list}")));
+        }
+    }
+    if depth != 0 {
+        return Err(abi_err(format!("unbalanced \"(\" in {list}")));
+    }
+    types.push(list[start..].parse()?);
+    Ok(types)
+}
+
+/// AbiToken: A value of a Solidity ABI type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AbiToken {
+    Address([u8; ADDR_LEN_ACCOUNT]),
+    Uint(Uint256),
+    Int(Int256),
+    Bool(bool),
+    /// Value of a `bytes<M>` type. Must hold at most 32 bytes.
+    FixedBytes(Vec<u8>),
+    Bytes(Vec<u8>),
+    String(String),
+    FixedArray(Vec<AbiToken>),
+    Array(Vec<AbiToken>),
+    Tuple(Vec<AbiToken>),
+}
+
+impl AbiToken {
+    /// Address token from either a hex Ethereum address or a 20-byte bech32
+    /// address.
+    pub fn address(addr: &str) -> NibiruResult<Self> {
+        Ok(AbiToken::Address(eth_address_bytes(addr)?))
+    }
+
+    pub fn uint(value: impl Into<Uint256>) -> Self {
+        AbiToken::Uint(value.into())
+    }
+
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            AbiToken::Bytes(_) | AbiToken::String(_) | AbiToken::Array(_) => {
+                true
+            }
+            AbiToken::FixedArray(tokens) | AbiToken::Tuple(tokens) => {
+                tokens.iter().any(AbiToken::is_dynamic)
+            }
+            _ => false,
+        }
+    }
+
+    fn head_len(&self) -> usize {
+        if self.is_dynamic() {
+            return WORD_LEN;
+        }
+        match self {
+            AbiToken::FixedArray(tokens) | AbiToken::Tuple(tokens) => {
+                tokens.iter().map(AbiToken::head_len).sum()
+            }
+            _ => WORD_LEN,
+        }
+    }
+
+    /// Whether this token is a valid value of `ty`.
+    pub fn type_check(&self, ty: &AbiType) -> bool {
+        match (self, ty) {
+            (AbiToken::Address(_), AbiType::Address)
+            | (AbiToken::Bool(_), AbiType::Bool)
+            | (AbiToken::Bytes(_), AbiType::Bytes)
+            | (AbiToken::String(_), AbiType::String) => true,
+            // Invalid sizes, which only hand-built types can have, match no
+            // value.
+            (AbiToken::Uint(value), AbiType::Uint(bits)) => {
+                valid_bits(*bits)
+                    && (*bits == 256
+                        || *value < Uint256::one() << (*bits as u32))
+            }
+            (AbiToken::Int(value), AbiType::Int(bits)) => {
+                valid_bits(*bits)
+                    && (*bits == 256 || {
+                        let bound = Int256::one() << (*bits as u32 - 1);
+                        *value < bound && *value >= -bound
+                    })
+            }
+            (AbiToken::FixedBytes(bytes), AbiType::FixedBytes(len)) => {
+                (1..=WORD_LEN).contains(len) && bytes.len() == *len
+            }
+            (AbiToken::FixedArray(tokens), AbiType::FixedArray(elem, len)) => {
+                tokens.len() == *len && tokens.iter().all(|t| t.type_check(elem))
+            }
+            (AbiToken::Array(tokens), AbiType::Array(elem)) => {
+                tokens.iter().all(|t| t.type_check(elem))
+            }
+            (AbiToken::Tuple(tokens), AbiType::Tuple(types)) => {
+                tokens.len() == types.len()
+                    && tokens.iter().zip(types).all(|(t, ty)| t.type_check(ty))
+            }
+            _ => false,
+        }
+    }
+
+    pub fn into_address(self) -> Option<[u8; ADDR_LEN_ACCOUNT]> {
+        match self {
+            AbiToken::Address(addr) => Some(addr),
+            _ => None,
+        }
+    }
+
+    pub fn into_uint(self) -> Option<Uint256> {
+        match self {
+            AbiToken::Uint(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn into_bool(self) -> Option<bool> {
+        match self {
+            AbiToken::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn into_string(self) -> Option<String> {
+        match self {
+            AbiToken::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for AbiToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AbiToken::Address(addr) => {
+                write!(f, "{}", eip55_checksum_address(addr))
+            }
+            AbiToken::Uint(value) => write!(f, "{value}"),
+            AbiToken::Int(value) => write!(f, "{value}"),
+            AbiToken::Bool(value) => write!(f, "{value}"),
+            AbiToken::FixedBytes(bytes) | AbiToken::Bytes(bytes) => {
+                write!(f, "0x{}", hex::encode(bytes))
+            }
+            AbiToken::String(value) => write!(f, "{value:?}"),
+            AbiToken::FixedArray(tokens) | AbiToken::Array(tokens) => {
+                write!(f, "[{}]", join_tokens(tokens))
+            }
+            AbiToken::Tuple(tokens) => write!(f, "({})", join_tokens(tokens)),
+        }
+    }
+}
+
+fn join_tokens(tokens: &[AbiToken]) -> String {
+    tokens
+        .iter()
+        .map(AbiToken::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Parses a hex Ethereum address or a 20-byte bech32 address with any HRP.
+pub fn eth_address_bytes(addr: &str) -> NibiruResult<[u8; ADDR_LEN_ACCOUNT]> {
+    if addr.starts_with("0x") || addr.len() == 2 * ADDR_LEN_ACCOUNT {
+        return parse_eth_address(addr);
+    }
+    let (_hrp, bytes, _variant) = bech32_decode(addr)?;
+    if bytes.len() != ADDR_LEN_ACCOUNT {
+        return Err(NibiruError::LossyAddressConversion { len: bytes.len() });
+    }
+    Ok(bytes.try_into().expect("length checked above"))
+}
+
+fn abi_err(msg: impl Into<String>) -> NibiruError {
+    NibiruError::AbiError(msg.into())
+}
+
+// ---------------------------------------------------------------------------
+// Encoding
+// ---------------------------------------------------------------------------
+
+/// ABI encodes `tokens` as the elements of a tuple, which is the layout of
+/// function arguments and return values.
+///
+/// ```
+/// use nibiru_std::evm::abi::{abi_encode, AbiToken};
+///
+/// let encoded =
+///     abi_encode(&[AbiToken::uint(69u128), AbiToken::Bool(true)]).unwrap();
+/// assert_eq!(encoded.len(), 64);
+/// assert_eq!(encoded[31], 69);
+/// assert_eq!(encoded[63], 1);
+/// ```
+pub fn abi_encode(tokens: &[AbiToken]) -> NibiruResult<Vec<u8>> {
+    let head_len: usize = tokens.iter().map(AbiToken::head_len).sum();
+    let mut head = Vec::with_capacity(head_len);
+    let mut tail = vec![];
+    for token in tokens {
+        if token.is_dynamic() {
+            head.extend_from_slice(&usize_word(head_len + tail.len()));
+            tail.extend(encode_token(token)?);
+        } else {
+            head.extend(encode_token(token)?);
+        }
+    }
+    head.extend(tail);
+    Ok(head)
+}
+
+/// ABI encodes a function call: the 4-byte selector of `signature` followed
+/// by the encoded arguments.
+pub fn encode_function_call(
+    signature: &str,
+    args: &[AbiToken],
+) -> NibiruResult<Vec<u8>> {
+    let mut calldata = function_selector(signature).to_vec();
+    calldata.extend(abi_encode(args)?);
+    Ok(calldata)
+}
+
+fn usize_word(value: usize) -> [u8; WORD_LEN] {
+    Uint256::from(value as u128).to_be_bytes()
+}
+
+/// Right pads `bytes` with zeros to a multiple of the word length.
+fn padded(bytes: &[u8]) -> Vec<u8> {
+    let mut out = bytes.to_vec();
+    let rem = bytes.len() % WORD_LEN;
+    if rem != 0 {
+        out.resize(bytes.len() + WORD_LEN - rem, 0);
+    }
+    out
+}
+
+fn encode_token(token: &AbiToken) -> NibiruResult<Vec<u8>> {
+    Ok(match token {
+        AbiToken::Address(addr) => {
+            let mut word = vec![0u8; WORD_LEN - ADDR_LEN_ACCOUNT];
+            word.extend_from_slice(addr);
+            word
+        }
+        AbiToken::Uint(value) => value.to_be_bytes().to_vec(),
+        AbiToken::Int(value) => value.to_be_bytes().to_vec(),
+        AbiToken::Bool(value) => usize_word(*value as usize).to_vec(),
+        AbiToken::FixedBytes(bytes) => {
+            if bytes.is_empty() || bytes.len() > WORD_LEN {
+                return Err(abi_err(format!(
+                    "bytes<M> must hold 1 to 32 bytes, got {}",
+                    bytes.len()
+                )));
+            }
+            padded(bytes)
+        }
+        AbiToken::Bytes(bytes) => {
+            let mut out = usize_word(bytes.len()).to_vec();
+            out.extend(padded(bytes));
+            out
+        }
+        AbiToken::String(value) => {
+            let mut out = usize_word(value.len()).to_vec();
+            out.extend(padded(value.as_bytes()));
+            out
+        }
+        AbiToken::Array(tokens) => {
+            let mut out = usize_word(tokens.len()).to_vec();
+            out.extend(abi_encode(tokens)?);
+            out
+        }
+        AbiToken::FixedArray(tokens) | AbiToken::Tuple(tokens) => {
+            abi_encode(tokens)?
+        }
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Decoding
+// ---------------------------------------------------------------------------
+
+/// Decodes ABI encoded `data` as a tuple of `types`, such as the return data
+/// of a call.
+///
+/// ```
+/// use nibiru_std::evm::abi::{abi_decode, abi_encode, AbiToken, AbiType};
+///
+/// let tokens = vec![
+///     AbiToken::String("Nibiru".to_string()),
+///     AbiToken::Array(vec![AbiToken::uint(1u128), AbiToken::uint(2u128)]),
+/// ];
+/// let types = vec![
+///     AbiType::String,
+///     AbiType::Array(Box::new(AbiType::Uint(256))),
+/// ];
+/// let encoded = abi_encode(&tokens).unwrap();
+/// assert_eq!(abi_decode(&types, &encoded).unwrap(), tokens);
+/// ```
+pub fn abi_decode(
+    types: &[AbiType],
+    data: &[u8],
+) -> NibiruResult<Vec<AbiToken>> {
+    let mut pos = 0;
+    let mut tokens = Vec::with_capacity(types.len());
+    for ty in types {
+        let token = if ty.is_dynamic() {
+            let offset = read_usize(data, pos)?;
+            decode_at(ty, slice_from(data, offset)?)?
+        } else {
+            decode_at(ty, slice_from(data, pos)?)?
+        };
+        tokens.push(token);
+        pos = checked_offset(pos, ty.head_len()?)?;
+    }
+    Ok(tokens)
+}
+
+/// Adds two offsets into ABI data, which may come from untrusted lengths.
+fn checked_offset(pos: usize, len: usize) -> NibiruResult<usize> {
+    pos.checked_add(len)
+        .ok_or_else(|| abi_err("offset overflows usize"))
+}
+
+fn slice_from(data: &[u8], start: usize) -> NibiruResult<&[u8]> {
+    data.get(start..)
+        .ok_or_else(|| abi_err(format!("offset {start} is out of bounds")))
+}
+
+fn read_word(data: &[u8], pos: usize) -> NibiruResult<[u8; WORD_LEN]> {
+    data.get(pos..checked_offset(pos, WORD_LEN)?)
+        .map(|word| word.try_into().expect("slice has word length"))
+        .ok_or_else(|| abi_err("unexpected end of ABI data"))
+}
+
+fn read_usize(data: &[u8], pos: usize) -> NibiruResult<usize> {
+    let word = read_word(data, pos)?;
+    if word[..WORD_LEN - 8].iter().any(|b| *b != 0) {
+        return Err(abi_err("length or offset does not fit in 64 bits"));
+    }
+    let value = u64::from_be_bytes(word[WORD_LEN - 8..].try_into().unwrap());
+    usize::try_from(value)
+        .map_err(|_| abi_err("length or offset does not fit in usize"))
+}
+
+/// Reads `len` bytes that follow a length word.
+fn read_len_prefixed(data: &[u8]) -> NibiruResult<&[u8]> {
+    let len = read_usize(data, 0)?;
+    data.get(WORD_LEN..checked_offset(WORD_LEN, len)?)
+        .ok_or_else(|| abi_err("unexpected end of ABI data"))
+}
+
+/// Decodes the value of type `ty` whose encoding starts at `data[0]`.
+fn decode_at(ty: &AbiType, data: &[u8]) -> NibiruResult<AbiToken> {
+    Ok(match ty {
+        AbiType::Address => {
+            let word = read_word(data, 0)?;
+            let (padding, addr) = word.split_at(WORD_LEN - ADDR_LEN_ACCOUNT);
+            if padding.iter().any(|b| *b != 0) {
+                return Err(abi_err("address has non-zero padding"));
+            }
+            AbiToken::Address(addr.try_into().unwrap())
+        }
+        AbiType::Uint(_) => {
+            ty.validate()?;
+            let value = Uint256::from_be_bytes(read_word(data, 0)?);
+            check_decoded(AbiToken::Uint(value), ty)?
+        }
+        AbiType::Int(_) => {
+            ty.validate()?;
+            let value = Int256::from_be_bytes(read_word(data, 0)?);
+            check_decoded(AbiToken::Int(value), ty)?
+        }
+        AbiType::Bool => match read_usize(data, 0) {
+            Ok(0) => AbiToken::Bool(false),
+            Ok(1) => AbiToken::Bool(true),
+            _ => return Err(abi_err("bool must be encoded as 0 or 1")),
+        },
+        AbiType::FixedBytes(len) => {
+            ty.validate()?;
+            AbiToken::FixedBytes(read_word(data, 0)?[..*len].to_vec())
+        }
+        AbiType::Bytes => AbiToken::Bytes(read_len_prefixed(data)?.to_vec()),
+        AbiType::String => {
+            let bytes = read_len_prefixed(data)?;
+            let value = String::from_utf8(bytes.to_vec()).map_err(|err| {
+                abi_err(format!("invalid UTF-8 string: {err}"))
+            })?;
+            AbiToken::String(value)
+        }
+        AbiType::Array(elem) => {
+            let len = read_usize(data, 0)?;
+            let elems = slice_from(data, WORD_LEN)?;
+            // Every element takes at least one word in the head, which
+            // bounds the allocation below by the size of the input.
+            if len.saturating_mul(WORD_LEN) > elems.len() {
+                return Err(abi_err(format!(
+                    "array length {len} exceeds the ABI data"
+                )));
+            }
+            let types = vec![(**elem).clone(); len];
+            AbiToken::Array(abi_decode(&types, elems)?)
+        }
+        AbiType::FixedArray(elem, len) => {
+            // As with `Array`, bound the allocation by the size of the input.
+            if len.saturating_mul(WORD_LEN) > data.len() {
+                return Err(abi_err(format!(
+                    "array length {len} exceeds the ABI data"
+                )));
+            }
+            let types = vec![(**elem).clone(); *len];
+            AbiToken::FixedArray(abi_decode(&types, data)?)
+        }
+        AbiType::Tuple(types) => AbiToken::Tuple(abi_decode(types, data)?),
+    })
+}
+
+fn check_decoded(token: AbiToken, ty: &AbiType) -> NibiruResult<AbiToken> {
+    if !token.type_check(ty) {
+        return Err(abi_err(format!("{token} is out of range for {ty}")));
+    }
+    Ok(token)
+}
+
+/// AbiFunction: The signature of a Solidity function, used to encode calls to
+/// it and decode what it returns.
+///
+/// ```
+/// use nibiru_std::evm::abi::{AbiFunction, AbiToken};
+///
+/// let func =
+///     AbiFunction::parse("balanceOf(address)", "uint256").unwrap();
+/// assert_eq!(hex::encode(func.selector()), "70a08231");
+/// let calldata = func
+///     .encode_input(&[AbiToken::address(
+///         "0x46155fAfd58660583ac0d23d8E22B9A13Ca0fb31",
+///     )
+///     .unwrap()])
+///     .unwrap();
+/// assert_eq!(calldata.len(), 4 + 32);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AbiFunction {
+    pub name: String,
+    pub inputs: Vec<AbiType>,
+    pub outputs: Vec<AbiType>,
+}
+
+impl AbiFunction {
+    /// Parses a signature such as "transfer(address,uint256)" and a comma
+    /// separated list of output types such as "bool".
+    pub fn parse(signature: &str, outputs: &str) -> NibiruResult<Self> {
+        let (name, params) = signature
+            .trim()
+            .strip_suffix(')')
+            .and_then(|sig| sig.split_once('('))
+            .ok_or_else(|| {
+                abi_err(format!("invalid function signature \"{signature}\""))
+            })?;
+        Ok(AbiFunction {
+            name: name.to_string(),
+            inputs: parse_type_list(params)?,
+            outputs: parse_type_list(outputs)?,
+        })
+    }
+
+    /// Canonical signature, e.g. "transfer(address,uint256)".
+    pub fn signature(&self) -> String {
+        format!("{}({})", self.name, join_types(&self.inputs))
+    }
+
+    pub fn selector(&self) -> [u8; 4] {
+        function_selector(&self.signature())
+    }
+
+    /// Calldata for a call with `args`, which must match the input types.
+    pub fn encode_input(&self, args: &[AbiToken]) -> NibiruResult<Vec<u8>> {
+        let matches = args.len() == self.inputs.len()
+            && args
+                .iter()
+                .zip(&self.inputs)
+                .all(|(arg, ty)| arg.type_check(ty));
+        if !matches {
+            return Err(abi_err(format!(
+                "arguments ({}) do not match {}",
+                join_tokens(args),
+                self.signature()
+            )));
+        }
+        let mut calldata = self.selector().to_vec();
+        calldata.extend(abi_encode(args)?);
+        Ok(calldata)
+    }
+
+    pub fn decode_output(&self, data: &[u8]) -> NibiruResult<Vec<AbiToken>> {
+        abi_decode(&self.outputs, data)
+    }
+}
+
+/// Selector of the `Error(string)` revert payload emitted by `require` and
+/// `revert` with a reason.
+const REVERT_ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Decodes the reason string of a revert, if the return data is an
+/// `Error(string)` payload.
+pub fn decode_revert_reason(ret: &[u8]) -> Option<String> {
+    let payload = ret.strip_prefix(&REVERT_ERROR_SELECTOR)?;
+    abi_decode(&[AbiType::String], payload)
+        .ok()?
+        .pop()?
+        .into_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::TestResult;
+
+    #[test]
+    fn selectors_and_topics() {
+        let cases = [
+            ("transfer(address,uint256)", "a9059cbb"),
+            ("approve(address,uint256)", "095ea7b3"),
+            ("balanceOf(address)", "70a08231"),
+            ("transferFrom(address,address,uint256)", "23b872dd"),
+            ("Error(string)", "08c379a0"),
+        ];
+        for (signature, selector) in cases {
+            assert_eq!(hex::encode(function_selector(signature)), selector);
+        }
+        assert_eq!(
+            hex::encode(event_topic("Transfer(address,address,uint256)")),
+            "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+        );
+    }
+
+    #[test]
+    fn parse_types() -> TestResult {
+        let cases = [
+            ("uint", "uint256"),
+            ("int8", "int8"),
+            ("bytes32", "bytes32"),
+            ("address[]", "address[]"),
+            ("uint256[2][]", "uint256[2][]"),
+            ("(bool,(string,bytes))", "(bool,(string,bytes))"),
+            ("(uint,address)[3]", "(uint256,address)[3]"),
+        ];
+        for (input, canonical) in cases {
+            assert_eq!(input.parse::<AbiType>()?.to_string(), canonical);
+        }
+
+        assert_eq!(
+            "uint256[2][]".parse::<AbiType>()?,
+            AbiType::Array(Box::new(AbiType::FixedArray(
+                Box::new(AbiType::Uint(256)),
+                2
+            )))
+        );
+
+        for bad in [
+            "uint7", "uint264", "bytes0", "bytes33", "foo", "(uint", "uint]",
+        ] {
+            assert!(bad.parse::<AbiType>().is_err(), "{bad}");
+        }
+        Ok(())
+    }
+
+    /// Example from the Solidity ABI specification:
+    /// `f(uint256,uint32[],bytes10,bytes)` called with
+    /// `(0x123, [0x456, 0x789], "1234567890", "Hello, world!")`.
+    #[test]
+    fn spec_example_dynamic_types() -> TestResult {
+        let func = AbiFunction::parse("f(uint256,uint32[],bytes10,bytes)", "")?;
+        let args = vec![
+            AbiToken::uint(0x123u128),
+            AbiToken::Array(vec![
+                AbiToken::uint(0x456u128),
+                AbiToken::uint(0x789u128),
+            ]),
+            AbiToken::FixedBytes(b"1234567890".to_vec()),
+            AbiToken::Bytes(b"Hello, world!".to_vec()),
+        ];
+        let calldata = func.encode_input(&args)?;
+        let want = [
+            "8be65246",
+            "0000000000000000000000000000000000000000000000000000000000000123",
+            "0000000000000000000000000000000000000000000000000000000000000080",
+            "3132333435363738393000000000000000000000000000000000000000000000",
+            "00000000000000000000000000000000000000000000000000000000000000e0",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            "0000000000000000000000000000000000000000000000000000000000000456",
+            "0000000000000000000000000000000000000000000000000000000000000789",
+            "000000000000000000000000000000000000000000000000000000000000000d",
+            "48656c6c6f2c20776f726c642100000000000000000000000000000000000000",
+        ]
+        .concat();
+        assert_eq!(hex::encode(&calldata), want);
+
+        let decoded = abi_decode(&func.inputs, &calldata[4..])?;
+        assert_eq!(decoded, args);
+        Ok(())
+    }
+
+    /// Example from the Solidity ABI specification:
+    /// `g(uint256[][],string[])` called with
+    /// `([[1, 2], [3]], ["one", "two", "three"])`.
+    #[test]
+    fn spec_example_nested_dynamic_arrays() -> TestResult {
+        let func = AbiFunction::parse("g(uint256[][],string[])", "")?;
+        let args = vec![
+            AbiToken::Array(vec![
+                AbiToken::Array(vec![
+                    AbiToken::uint(1u128),
+                    AbiToken::uint(2u128),
+                ]),
+                AbiToken::Array(vec![AbiToken::uint(3u128)]),
+            ]),
+            AbiToken::Array(
+                ["one", "two", "three"]
+                    .into_iter()
+                    .map(|s| AbiToken::String(s.to_string()))
+                    .collect(),
+            ),
+        ];
+        let calldata = func.encode_input(&args)?;
+        let want = [
+            "2289b18c",
+            "0000000000000000000000000000000000000000000000000000000000000040",
+            "0000000000000000000000000000000000000000000000000000000000000140",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            "0000000000000000000000000000000000000000000000000000000000000040",
+            "00000000000000000000000000000000000000000000000000000000000000a0",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "0000000000000000000000000000000000000000000000000000000000000060",
+            "00000000000000000000000000000000000000000000000000000000000000a0",
+            "00000000000000000000000000000000000000000000000000000000000000e0",
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "6f6e650000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "74776f0000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000005",
+            "7468726565000000000000000000000000000000000000000000000000000000",
+        ]
+        .concat();
+        assert_eq!(hex::encode(&calldata), want);
+        assert_eq!(abi_decode(&func.inputs, &calldata[4..])?, args);
+        Ok(())
+    }
+
+    #[test]
+    fn static_tuples_and_signed_ints() -> TestResult {
+        let types: Vec<AbiType> =
+            parse_type_list("int8,(address,bool)[2],bytes4")?;
+        let addr =
+            AbiToken::address("0x46155fAfd58660583ac0d23d8E22B9A13Ca0fb31")?;
+        let tokens = vec![
+            AbiToken::Int(Int256::from(-1i128)),
+            AbiToken::FixedArray(vec![
+                AbiToken::Tuple(vec![addr.clone(), AbiToken::Bool(true)]),
+                AbiToken::Tuple(vec![addr, AbiToken::Bool(false)]),
+            ]),
+            AbiToken::FixedBytes(vec![0xde, 0xad, 0xbe, 0xef]),
+        ];
+        let encoded = abi_encode(&tokens)?;
+        // Everything is static, so it is all in the head.
+        assert_eq!(encoded.len(), 6 * WORD_LEN);
+        assert_eq!(encoded[..WORD_LEN], [0xff; WORD_LEN]);
+        assert_eq!(abi_decode(&types, &encoded)?, tokens);
+
+        // -129 does not fit in an int8
+        let bad = abi_encode(&[AbiToken::Int(Int256::from(-129i128))])?;
+        assert!(abi_decode(&[AbiType::Int(8)], &bad).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn decode_rejects_malformed_data() {
+        let cases: Vec<(&str, Vec<AbiType>, Vec<u8>)> = vec![
+            ("too short", vec![AbiType::Uint(256)], vec![0u8; 31]),
+            ("bool out of range", vec![AbiType::Bool], {
+                let mut word = vec![0u8; 32];
+                word[31] = 2;
+                word
+            }),
+            ("address padding", vec![AbiType::Address], vec![0xff; 32]),
+            ("offset out of bounds", vec![AbiType::Bytes], {
+                let mut word = vec![0u8; 32];
+                word[31] = 0x40;
+                word
+            }),
+            (
+                "huge array length",
+                vec![AbiType::Array(Box::new(AbiType::Uint(256)))],
+                {
+                    let mut data = vec![0u8; 64];
+                    data[31] = 0x20;
+                    data[56..].copy_from_slice(&[0xff; 8]);
+                    data
+                },
+            ),
+            ("huge string length", vec![AbiType::String], {
+                let mut data = vec![0u8; 64];
+                data[31] = 0x20;
+                data[56..].copy_from_slice(&[0xff; 8]);
+                data
+            }),
+            (
+                "fixed array head overflows",
+                vec![AbiType::FixedArray(
+                    Box::new(AbiType::FixedArray(
+                        Box::new(AbiType::Uint(256)),
+                        usize::MAX,
+                    )),
+                    2,
+                )],
+                vec![0u8; 64],
+            ),
+            (
+                "huge fixed array length",
+                vec![AbiType::FixedArray(
+                    Box::new(AbiType::Uint(256)),
+                    usize::MAX,
+                )],
+                vec![0u8; 64],
+            ),
+        ];
+        for (name, types, data) in cases {
+            assert!(abi_decode(&types, &data).is_err(), "{name}");
+        }
+    }
+
+    #[test]
+    fn function_arg_type_check() -> TestResult {
+        let func = AbiFunction::parse("transfer(address,uint256)", "bool")?;
+        assert!(func.encode_input(&[AbiToken::uint(1u128)]).is_err());
+        assert!(func
+            .encode_input(&[AbiToken::Bool(true), AbiToken::uint(1u128)])
+            .is_err());
+
+        let uint8 = AbiFunction::parse("f(uint8)", "")?;
+        assert!(uint8.encode_input(&[AbiToken::uint(255u128)]).is_ok());
+        assert!(uint8.encode_input(&[AbiToken::uint(256u128)]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn hand_built_types_with_invalid_sizes() {
+        let word = [0u8; WORD_LEN];
+        let cases = [
+            (AbiType::Uint(0), AbiToken::uint(0u128)),
+            (AbiType::Uint(7), AbiToken::uint(0u128)),
+            (AbiType::Uint(512), AbiToken::uint(0u128)),
+            (AbiType::Int(0), AbiToken::Int(Int256::zero())),
+            (AbiType::Int(264), AbiToken::Int(Int256::zero())),
+            (AbiType::FixedBytes(0), AbiToken::FixedBytes(vec![])),
+            (AbiType::FixedBytes(33), AbiToken::FixedBytes(vec![0; 33])),
+        ];
+        for (ty, token) in cases {
+            assert!(ty.validate().is_err(), "{ty}");
+            assert!(!token.type_check(&ty), "{ty}");
+            assert!(
+                abi_decode(std::slice::from_ref(&ty), &word).is_err(),
+                "{ty}"
+            );
+            let nested = AbiType::Tuple(vec![AbiType::Array(Box::new(ty))]);
+            assert!(nested.validate().is_err(), "{nested}");
+        }
+        assert!(AbiType::Int(8).validate().is_ok());
+        assert!(AbiType::FixedBytes(32).validate().is_ok());
+    }
+
+    #[test]
+    fn revert_reason() -> TestResult {
+        let mut ret = REVERT_ERROR_SELECTOR.to_vec();
+        ret.extend(abi_encode(&[AbiToken::String(
+            "ERC20: transfer amount exceeds balance".to_string(),
+        )])?);
+        assert_eq!(
+            decode_revert_reason(&ret).as_deref(),
+            Some("ERC20: transfer amount exceeds balance")
+        );
+        assert_eq!(decode_revert_reason(&[0xde, 0xad]), None);
+
+        // A length word near usize::MAX must not overflow
+        let mut ret = REVERT_ERROR_SELECTOR.to_vec();
+        ret.extend([0u8; 31]);
+        ret.push(0x20);
+        ret.extend([0u8; 24]);
+        ret.extend([0xff; 8]);
+        assert_eq!(decode_revert_reason(&ret), None);
+        Ok(())
+    }
+}
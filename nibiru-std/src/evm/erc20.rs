@@ -0,0 +1,304 @@
+//! evm/erc20.rs: Calldata builders and return data decoders for the ERC20
+//! interface, plus decoding of its `Transfer` and `Approval` events.
+
+use cosmwasm_std::Uint256;
+
+use crate::{
+    errors::{NibiruError, NibiruResult},
+    evm::{
+        abi::{
+            abi_decode, encode_function_call, event_topic, AbiToken, AbiType,
+        },
+        EvmLog,
+    },
+};
+
+pub const SIG_TRANSFER: &str = "transfer(address,uint256)";
+pub const SIG_TRANSFER_FROM: &str = "transferFrom(address,address,uint256)";
+pub const SIG_APPROVE: &str = "approve(address,uint256)";
+pub const SIG_BALANCE_OF: &str = "balanceOf(address)";
+pub const SIG_ALLOWANCE: &str = "allowance(address,address)";
+pub const SIG_TOTAL_SUPPLY: &str = "totalSupply()";
+pub const SIG_DECIMALS: &str = "decimals()";
+pub const SIG_NAME: &str = "name()";
+pub const SIG_SYMBOL: &str = "symbol()";
+
+pub const EVENT_TRANSFER: &str = "Transfer(address,address,uint256)";
+pub const EVENT_APPROVAL: &str = "Approval(address,address,uint256)";
+
+/// Calldata for `transfer(to, amount)`. Addresses may be hex or 20-byte
+/// bech32.
+///
+/// ```
+/// use cosmwasm_std::Uint256;
+/// use nibiru_std::evm::erc20;
+///
+/// let calldata = erc20::transfer(
+///     "nibi1gc24lt74ses9swkq6g7cug4e5y72p7e34jqgul",
+///     Uint256::from(420u128),
+/// )
+/// .unwrap();
+/// assert_eq!(hex::encode(&calldata[..4]), "a9059cbb");
+/// assert_eq!(calldata.len(), 4 + 2 * 32);
+/// ```
+pub fn transfer(to: &str, amount: Uint256) -> NibiruResult<Vec<u8>> {
+    encode_function_call(
+        SIG_TRANSFER,
+        &[AbiToken::address(to)?, AbiToken::Uint(amount)],
+    )
+}
+
+/// Calldata for `transferFrom(from, to, amount)`.
+pub fn transfer_from(
+    from: &str,
+    to: &str,
+    amount: Uint256,
+) -> NibiruResult<Vec<u8>> {
+    encode_function_call(
+        SIG_TRANSFER_FROM,
+        &[
+            AbiToken::address(from)?,
+            AbiToken::address(to)?,
+            AbiToken::Uint(amount),
+        ],
+    )
+}
+
+/// Calldata for `approve(spender, amount)`.
+pub fn approve(spender: &str, amount: Uint256) -> NibiruResult<Vec<u8>> {
+    encode_function_call(
+        SIG_APPROVE,
+        &[AbiToken::address(spender)?, AbiToken::Uint(amount)],
+    )
+}
+
+/// Calldata for `balanceOf(account)`. Decode the result with
+/// [decode_uint256].
+pub fn balance_of(account: &str) -> NibiruResult<Vec<u8>> {
+    encode_function_call(SIG_BALANCE_OF, &[AbiToken::address(account)?])
+}
+
+/// Calldata for `allowance(owner, spender)`. Decode the result with
+/// [decode_uint256].
+pub fn allowance(owner: &str, spender: &str) -> NibiruResult<Vec<u8>> {
+    encode_function_call(
+        SIG_ALLOWANCE,
+        &[AbiToken::address(owner)?, AbiToken::address(spender)?],
+    )
+}
+
+/// Calldata for `totalSupply()`.
+pub fn total_supply() -> Vec<u8> {
+    encode_function_call(SIG_TOTAL_SUPPLY, &[]).expect("no arguments to encode")
+}
+
+/// Decodes the return data of `balanceOf`, `allowance`, or `totalSupply`.
+pub fn decode_uint256(ret: &[u8]) -> NibiruResult<Uint256> {
+    decode_single(AbiType::Uint(256), ret)?
+        .into_uint()
+        .ok_or_else(|| unexpected_token("uint256"))
+}
+
+/// Decodes the return data of `transfer`, `transferFrom`, or `approve`.
+/// Tokens that return nothing, like USDT on Ethereum, are treated as
+/// successful.
+pub fn decode_bool(ret: &[u8]) -> NibiruResult<bool> {
+    if ret.is_empty() {
+        return Ok(true);
+    }
+    decode_single(AbiType::Bool, ret)?
+        .into_bool()
+        .ok_or_else(|| unexpected_token("bool"))
+}
+
+/// Decodes the return data of `decimals()`.
+pub fn decode_decimals(ret: &[u8]) -> NibiruResult<u8> {
+    let value = decode_single(AbiType::Uint(8), ret)?
+        .into_uint()
+        .ok_or_else(|| unexpected_token("uint8"))?;
+    Ok(value.to_be_bytes()[31])
+}
+
+/// Decodes the return data of `name()` or `symbol()`.
+pub fn decode_string(ret: &[u8]) -> NibiruResult<String> {
+    decode_single(AbiType::String, ret)?
+        .into_string()
+        .ok_or_else(|| unexpected_token("string"))
+}
+
+fn decode_single(ty: AbiType, ret: &[u8]) -> NibiruResult<AbiToken> {
+    abi_decode(&[ty], ret)?
+        .pop()
+        .ok_or_else(|| unexpected_token("a value"))
+}
+
+fn unexpected_token(want: &str) -> NibiruError {
+    NibiruError::AbiError(format!("expected the ERC20 call to return {want}"))
+}
+
+/// Erc20Event: A decoded ERC20 `Transfer` or `Approval` event.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Erc20Event {
+    Transfer {
+        /// Address of the ERC20 contract that emitted the event.
+        token: [u8; 20],
+        from: [u8; 20],
+        to: [u8; 20],
+        value: Uint256,
+    },
+    Approval {
+        token: [u8; 20],
+        owner: [u8; 20],
+        spender: [u8; 20],
+        value: Uint256,
+    },
+}
+
+impl Erc20Event {
+    /// Decodes `log` if it is an ERC20 `Transfer` or `Approval` event.
+    /// Returns `Ok(None)` for any other event.
+    pub fn from_log(log: &EvmLog) -> NibiruResult<Option<Self>> {
+        let Some(topic0) = log.topics.first() else {
+            return Ok(None);
+        };
+        let is_transfer = *topic0 == event_topic(EVENT_TRANSFER);
+        let is_approval = *topic0 == event_topic(EVENT_APPROVAL);
+        // ERC721 shares the Transfer and Approval signatures but indexes
+        // the token ID, giving it four topics.
+        if !(is_transfer || is_approval) || log.topics.len() != 3 {
+            return Ok(None);
+        }
+
+        let indexed_addr = |topic: &[u8; 32]| -> NibiruResult<[u8; 20]> {
+            abi_decode(&[AbiType::Address], topic)?
+                .pop()
+                .and_then(AbiToken::into_address)
+                .ok_or_else(|| unexpected_token("an address"))
+        };
+        let first = indexed_addr(&log.topics[1])?;
+        let second = indexed_addr(&log.topics[2])?;
+        let value = decode_uint256(&log.data)?;
+
+        Ok(Some(if is_transfer {
+            Erc20Event::Transfer {
+                token: log.address,
+                from: first,
+                to: second,
+                value,
+            }
+        } else {
+            Erc20Event::Approval {
+                token: log.address,
+                owner: first,
+                spender: second,
+                value,
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{errors::TestResult, evm::abi::abi_encode};
+
+    const ALICE: &str = "0x46155fAfd58660583ac0d23d8E22B9A13Ca0fb31";
+    const BOB: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+    #[test]
+    fn calldata() -> TestResult {
+        let amount = Uint256::from(1_000_000u128);
+        let cases: Vec<(Vec<u8>, &str, usize)> = vec![
+            (transfer(BOB, amount)?, "a9059cbb", 2),
+            (transfer_from(ALICE, BOB, amount)?, "23b872dd", 3),
+            (approve(BOB, amount)?, "095ea7b3", 2),
+            (balance_of(ALICE)?, "70a08231", 1),
+            (allowance(ALICE, BOB)?, "dd62ed3e", 2),
+            (total_supply(), "18160ddd", 0),
+        ];
+        for (calldata, selector, num_args) in cases {
+            assert_eq!(hex::encode(&calldata[..4]), selector);
+            assert_eq!(calldata.len(), 4 + 32 * num_args);
+        }
+
+        // Bech32 and hex forms of the same account give the same calldata
+        assert_eq!(
+            balance_of("nibi1gc24lt74ses9swkq6g7cug4e5y72p7e34jqgul")?,
+            balance_of(ALICE)?
+        );
+        // 32-byte contract addresses have no EVM form
+        let contract = crate::address::contract_address_classic(1, 1);
+        assert!(balance_of(&contract).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn return_data() -> TestResult {
+        let ret = abi_encode(&[AbiToken::uint(42u128)])?;
+        assert_eq!(decode_uint256(&ret)?, Uint256::from(42u128));
+        assert_eq!(decode_decimals(&ret)?, 42);
+        assert!(decode_bool(&abi_encode(&[AbiToken::Bool(true)])?)?);
+        assert!(decode_bool(&[])?);
+        assert_eq!(
+            decode_string(&abi_encode(&[AbiToken::String("NIBI".into())])?)?,
+            "NIBI"
+        );
+        assert!(decode_uint256(&[]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn events() -> TestResult {
+        let token = crate::evm::abi::eth_address_bytes(BOB)?;
+        let topic_of = |addr: &str| -> [u8; 32] {
+            abi_encode(&[AbiToken::address(addr).unwrap()])
+                .unwrap()
+                .try_into()
+                .unwrap()
+        };
+        let log = EvmLog {
+            address: token,
+            topics: vec![
+                event_topic(EVENT_TRANSFER),
+                topic_of(ALICE),
+                topic_of(BOB),
+            ],
+            data: abi_encode(&[AbiToken::uint(7u128)])?,
+        };
+        assert_eq!(
+            Erc20Event::from_log(&log)?,
+            Some(Erc20Event::Transfer {
+                token,
+                from: crate::evm::abi::eth_address_bytes(ALICE)?,
+                to: token,
+                value: Uint256::from(7u128),
+            })
+        );
+
+        let approval = EvmLog {
+            topics: vec![
+                event_topic(EVENT_APPROVAL),
+                topic_of(ALICE),
+                topic_of(BOB),
+            ],
+            ..log.clone()
+        };
+        assert!(matches!(
+            Erc20Event::from_log(&approval)?,
+            Some(Erc20Event::Approval { .. })
+        ));
+
+        // ERC721 transfer: token ID is indexed as a fourth topic
+        let mut erc721 = log.clone();
+        erc721.topics.push([0u8; 32]);
+        erc721.data = vec![];
+        assert_eq!(Erc20Event::from_log(&erc721)?, None);
+
+        let other = EvmLog {
+            topics: vec![event_topic("Foo()")],
+            ..log
+        };
+        assert_eq!(Erc20Event::from_log(&other)?, None);
+        Ok(())
+    }
+}
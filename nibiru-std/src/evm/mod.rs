@@ -0,0 +1,422 @@
+//! evm/mod.rs: Interop with the Nibiru EVM (`eth.evm.v1`).
+//!
+//! - [abi]: Solidity ABI encoding and decoding.
+//! - [erc20]: Calldata, return data, and events of the ERC20 interface.
+//! - Typed builders for `MsgCreateFunToken`, `MsgConvertCoinToEvm`, and
+//!   `MsgEthereumTx`, and decoding of `MsgEthereumTxResponse`.
+
+pub mod abi;
+pub mod erc20;
+
+use cosmwasm_std as cw;
+use cosmwasm_std::Uint256;
+use prost::Message;
+
+use crate::{
+    address::{eip55_checksum_address, ADDR_LEN_ACCOUNT},
+    errors::{NibiruError, NibiruResult},
+    evm::abi::{decode_revert_reason, eth_address_bytes, AbiFunction, AbiToken},
+    proto::{eth::evm, NibiruStargateMsg},
+};
+
+/// Creates a FunToken mapping from an existing bank coin. The EVM module
+/// deploys a new ERC20 contract for it.
+pub fn create_funtoken_from_bank_denom(
+    sender: impl Into<String>,
+    bank_denom: impl Into<String>,
+) -> evm::MsgCreateFunToken {
+    evm::MsgCreateFunToken {
+        from_erc20: String::new(),
+        from_bank_denom: bank_denom.into(),
+        sender: sender.into(),
+    }
+}
+
+/// Creates a FunToken mapping from an existing ERC20 contract. The bank denom
+/// is created by the EVM module.
+pub fn create_funtoken_from_erc20(
+    sender: impl Into<String>,
+    erc20_addr: &str,
+) -> NibiruResult<evm::MsgCreateFunToken> {
+    Ok(evm::MsgCreateFunToken {
+        from_erc20: eip55_checksum_address(&eth_address_bytes(erc20_addr)?),
+        from_bank_denom: String::new(),
+        sender: sender.into(),
+    })
+}
+
+/// Converts a bank coin that has a FunToken mapping into its ERC20
+/// representation, credited to `to_eth_addr`.
+///
+/// ```
+/// use cosmwasm_std::coin;
+/// use nibiru_std::evm::convert_coin_to_evm;
+///
+/// let msg = convert_coin_to_evm(
+///     "nibi1sender",
+///     "nibi1gc24lt74ses9swkq6g7cug4e5y72p7e34jqgul",
+///     coin(5, "unibi"),
+/// )
+/// .unwrap();
+/// assert_eq!(msg.to_eth_addr, "0x46155fAfd58660583ac0d23d8E22B9A13Ca0fb31");
+/// ```
+pub fn convert_coin_to_evm(
+    sender: impl Into<String>,
+    to_eth_addr: &str,
+    coin: cw::Coin,
+) -> NibiruResult<evm::MsgConvertCoinToEvm> {
+    Ok(evm::MsgConvertCoinToEvm {
+        to_eth_addr: eip55_checksum_address(&eth_address_bytes(to_eth_addr)?),
+        sender: sender.into(),
+        bank_coin: Some(coin.into()),
+    })
+}
+
+/// EvmTx: Builder for an EIP-1559 `MsgEthereumTx`.
+///
+/// The resulting message is unsigned: `v`, `r`, and `s` must be filled in by
+/// an Ethereum signer, and the chain rejects transactions whose signature
+/// does not recover to `from`. Contracts should prefer the FunToken messages,
+/// which need no Ethereum signature.
+///
+/// ```
+/// use cosmwasm_std::Uint256;
+/// use nibiru_std::evm::{erc20, EvmTx};
+///
+/// let to = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+/// let data =
+///     erc20::transfer(to, Uint256::from(1u128)).unwrap();
+/// let msg = EvmTx::call(to, data)
+///     .unwrap()
+///     .chain_id(6900)
+///     .nonce(3)
+///     .gas_limit(100_000)
+///     .into_msg("0x46155fAfd58660583ac0d23d8E22B9A13Ca0fb31")
+///     .unwrap();
+/// assert_eq!(msg.data.unwrap().type_url, "/eth.evm.v1.DynamicFeeTx");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EvmTx {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub gas_limit: u64,
+    /// Max priority fee per gas, in wei.
+    pub gas_tip_cap: Uint256,
+    /// Max fee per gas, in wei.
+    pub gas_fee_cap: Uint256,
+    /// Recipient. `None` deploys a contract with `data` as init code.
+    pub to: Option<[u8; ADDR_LEN_ACCOUNT]>,
+    /// Amount of the EVM gas token to send, in wei.
+    pub value: Uint256,
+    pub data: Vec<u8>,
+}
+
+impl EvmTx {
+    /// Transaction that calls the contract at `to` with `data`.
+    pub fn call(to: &str, data: Vec<u8>) -> NibiruResult<Self> {
+        Ok(EvmTx {
+            to: Some(eth_address_bytes(to)?),
+            data,
+            ..Default::default()
+        })
+    }
+
+    /// Transaction that deploys a contract with the given init code.
+    pub fn deploy(init_code: Vec<u8>) -> Self {
+        EvmTx {
+            data: init_code,
+            ..Default::default()
+        }
+    }
+
+    pub fn chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    pub fn nonce(mut self, nonce: u64) -> Self {
+        self.nonce = nonce;
+        self
+    }
+
+    pub fn gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    pub fn fees(mut self, gas_tip_cap: Uint256, gas_fee_cap: Uint256) -> Self {
+        self.gas_tip_cap = gas_tip_cap;
+        self.gas_fee_cap = gas_fee_cap;
+        self
+    }
+
+    pub fn value(mut self, value_wei: Uint256) -> Self {
+        self.value = value_wei;
+        self
+    }
+
+    pub fn into_dynamic_fee_tx(self) -> evm::DynamicFeeTx {
+        evm::DynamicFeeTx {
+            chain_id: self.chain_id.to_string(),
+            nonce: self.nonce,
+            gas_tip_cap: self.gas_tip_cap.to_string(),
+            gas_fee_cap: self.gas_fee_cap.to_string(),
+            gas: self.gas_limit,
+            to: self
+                .to
+                .map(|to| eip55_checksum_address(&to))
+                .unwrap_or_default(),
+            value: self.value.to_string(),
+            data: self.data.into(),
+            accesses: vec![],
+            v: Default::default(),
+            r: Default::default(),
+            s: Default::default(),
+        }
+    }
+
+    /// Unsigned `MsgEthereumTx` sent by the Ethereum address `from`.
+    pub fn into_msg(self, from: &str) -> NibiruResult<evm::MsgEthereumTx> {
+        let from = eip55_checksum_address(&eth_address_bytes(from)?);
+        let tx = self.into_dynamic_fee_tx();
+        Ok(evm::MsgEthereumTx {
+            data: Some(prost_types::Any {
+                type_url: tx.type_url(),
+                value: tx.encode_to_vec(),
+            }),
+            size: 0.0,
+            hash: String::new(),
+            from,
+        })
+    }
+}
+
+/// EvmLog: An EVM event log with its hex fields parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EvmLog {
+    /// Address of the contract that emitted the log.
+    pub address: [u8; ADDR_LEN_ACCOUNT],
+    pub topics: Vec<[u8; 32]>,
+    pub data: Vec<u8>,
+}
+
+impl TryFrom<&evm::Log> for EvmLog {
+    type Error = NibiruError;
+
+    fn try_from(log: &evm::Log) -> Result<Self, Self::Error> {
+        let topics = log
+            .topics
+            .iter()
+            .map(|topic| {
+                let hex_str = topic.strip_prefix("0x").unwrap_or(topic);
+                hex::decode(hex_str)?.try_into().map_err(|_| {
+                    NibiruError::AbiError(format!(
+                        "log topic {topic} is not 32 bytes"
+                    ))
+                })
+            })
+            .collect::<NibiruResult<_>>()?;
+        Ok(EvmLog {
+            address: eth_address_bytes(&log.address)?,
+            topics,
+            data: log.data.to_vec(),
+        })
+    }
+}
+
+/// EvmTxResult: Outcome of a `MsgEthereumTx`, decoded from its
+/// `MsgEthereumTxResponse`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EvmTxResult {
+    /// Ethereum transaction hash in hex.
+    pub hash: String,
+    pub logs: Vec<EvmLog>,
+    /// Return data of the call, or the revert data if the call failed.
+    pub ret: Vec<u8>,
+    /// Error returned by the EVM. `None` if the call succeeded.
+    pub vm_error: Option<String>,
+    pub gas_used: u64,
+}
+
+impl EvmTxResult {
+    /// Decodes a protobuf encoded `MsgEthereumTxResponse`, such as the data
+    /// of a submessage reply.
+    pub fn from_bytes(bytes: &[u8]) -> NibiruResult<Self> {
+        let resp = evm::MsgEthereumTxResponse::decode(bytes).map_err(|err| {
+            NibiruError::AbiError(format!(
+                "decoding MsgEthereumTxResponse: {err}"
+            ))
+        })?;
+        Self::try_from(resp)
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.vm_error.is_none()
+    }
+
+    /// Decodes the return data with the outputs of `func`. Errors if the
+    /// call reverted, including the revert reason when there is one.
+    pub fn decode_ret(&self, func: &AbiFunction) -> NibiruResult<Vec<AbiToken>> {
+        if let Some(vm_error) = &self.vm_error {
+            let reason = self
+                .revert_reason()
+                .map(|reason| format!(": {reason}"))
+                .unwrap_or_default();
+            return Err(NibiruError::AbiError(format!(
+                "{} failed with \"{vm_error}\"{reason}",
+                func.signature()
+            )));
+        }
+        func.decode_output(&self.ret)
+    }
+
+    /// Reason string of a failed call that reverted with `Error(string)`.
+    pub fn revert_reason(&self) -> Option<String> {
+        self.vm_error.as_ref()?;
+        decode_revert_reason(&self.ret)
+    }
+}
+
+impl TryFrom<evm::MsgEthereumTxResponse> for EvmTxResult {
+    type Error = NibiruError;
+
+    fn try_from(resp: evm::MsgEthereumTxResponse) -> Result<Self, Self::Error> {
+        Ok(EvmTxResult {
+            hash: resp.hash,
+            logs: resp
+                .logs
+                .iter()
+                .map(EvmLog::try_from)
+                .collect::<NibiruResult<_>>()?,
+            ret: resp.ret.to_vec(),
+            vm_error: Some(resp.vm_error).filter(|err| !err.is_empty()),
+            gas_used: resp.gas_used,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        errors::TestResult,
+        evm::{
+            abi::{abi_encode, event_topic, function_selector},
+            erc20::{self, Erc20Event},
+        },
+        proto::NibiruProstMsg,
+    };
+
+    const ALICE: &str = "0x46155fAfd58660583ac0d23d8E22B9A13Ca0fb31";
+    const TOKEN: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+    #[test]
+    fn funtoken_msgs() -> TestResult {
+        let msg = create_funtoken_from_bank_denom("nibi1sender", "unibi");
+        assert_eq!(msg.from_bank_denom, "unibi");
+        assert!(msg.from_erc20.is_empty());
+
+        let msg =
+            create_funtoken_from_erc20("nibi1sender", &TOKEN.to_lowercase())?;
+        assert_eq!(msg.from_erc20, TOKEN);
+        assert!(create_funtoken_from_erc20("nibi1sender", "0xabc").is_err());
+
+        let msg = convert_coin_to_evm(
+            "nibi1sender",
+            ALICE,
+            cosmwasm_std::coin(5, "unibi"),
+        )?;
+        assert_eq!(msg.bank_coin.unwrap().amount, "5");
+        assert_eq!(
+            NibiruStargateMsg::type_url(&create_funtoken_from_bank_denom(
+                "", ""
+            )),
+            "/eth.evm.v1.MsgCreateFunToken"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ethereum_tx_msg() -> TestResult {
+        let data = erc20::transfer(ALICE, Uint256::from(9u128))?;
+        let msg = EvmTx::call(TOKEN, data.clone())?
+            .chain_id(6900)
+            .nonce(1)
+            .gas_limit(21_000)
+            .fees(Uint256::from(1u128), Uint256::from(10u128))
+            .into_msg(&ALICE.to_lowercase())?;
+        assert_eq!(msg.from, ALICE);
+
+        let any = msg.data.unwrap();
+        let tx = evm::DynamicFeeTx::decode(any.value.as_slice())?;
+        assert_eq!(tx.to, TOKEN);
+        assert_eq!(tx.chain_id, "6900");
+        assert_eq!(tx.gas_fee_cap, "10");
+        assert_eq!(tx.value, "0");
+        assert_eq!(tx.data.to_vec(), data);
+
+        let deploy = EvmTx::deploy(vec![0x60, 0x80]).into_dynamic_fee_tx();
+        assert!(deploy.to.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn tx_response() -> TestResult {
+        let balance_of = AbiFunction::parse(erc20::SIG_BALANCE_OF, "uint256")?;
+        let alice_topic = hex::encode(abi_encode(&[AbiToken::address(ALICE)?])?);
+        let resp = evm::MsgEthereumTxResponse {
+            hash: "0xabc".to_string(),
+            logs: vec![evm::Log {
+                address: TOKEN.to_string(),
+                topics: vec![
+                    format!(
+                        "0x{}",
+                        hex::encode(event_topic(erc20::EVENT_TRANSFER))
+                    ),
+                    format!("0x{alice_topic}"),
+                    alice_topic,
+                ],
+                data: abi_encode(&[AbiToken::uint(3u128)])?.into(),
+                ..Default::default()
+            }],
+            ret: abi_encode(&[AbiToken::uint(100u128)])?.into(),
+            vm_error: String::new(),
+            gas_used: 30_000,
+        };
+
+        let result = EvmTxResult::from_bytes(&resp.to_bytes())?;
+        assert!(result.is_success());
+        assert_eq!(
+            result.decode_ret(&balance_of)?,
+            vec![AbiToken::uint(100u128)]
+        );
+        assert!(matches!(
+            Erc20Event::from_log(&result.logs[0])?,
+            Some(Erc20Event::Transfer { .. })
+        ));
+
+        let mut revert_data = function_selector("Error(string)").to_vec();
+        revert_data
+            .extend(abi_encode(&[AbiToken::String("insufficient".into())])?);
+        let reverted = EvmTxResult::try_from(evm::MsgEthereumTxResponse {
+            ret: revert_data.into(),
+            vm_error: "execution reverted".to_string(),
+            ..resp
+        })?;
+        assert!(!reverted.is_success());
+        assert_eq!(reverted.revert_reason().as_deref(), Some("insufficient"));
+        let err = reverted.decode_ret(&balance_of).unwrap_err();
+        assert!(err.to_string().contains("insufficient"), "{err}");
+
+        let bad_log = evm::MsgEthereumTxResponse {
+            logs: vec![evm::Log {
+                address: TOKEN.to_string(),
+                topics: vec!["0x1234".to_string()],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        assert!(EvmTxResult::try_from(bad_log).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,425 @@
+//! feegrant.rs: Builders for `cosmos.feegrant.v1beta1` messages, which let
+//! a grantee pay transaction fees from the account of a granter.
+//!
+//! - [FeeAllowance]: The basic, periodic, and message-filtered allowances,
+//!   encoded to and decoded from `prost_types::Any`.
+//! - [grant_allowance] and [revoke_allowance]: The feegrant messages.
+//! - [FeeGrant]: A grant returned by the feegrant allowance queries. See
+//!   [crate::querier::NibiruQuerier::fee_allowance].
+
+use cosmwasm_std as cw;
+use prost::Message;
+
+use crate::{
+    errors::{NibiruError, NibiruResult},
+    proto::{
+        cosmos::feegrant::v1beta1 as feegrant, cw_coins_from_proto,
+        cw_timestamp_from_proto, proto_coins_from_cw, proto_duration_from_secs,
+        proto_timestamp_from_cw, secs_from_proto_duration, NibiruStargateMsg,
+    },
+};
+
+/// FeeAllowance: How much of the granter's funds a grantee may spend on
+/// fees.
+///
+/// ```
+/// use cosmwasm_std::{coin, Timestamp};
+/// use nibiru_std::feegrant::FeeAllowance;
+///
+/// // Up to 1 NIBI a day, for a year, and only to execute contracts.
+/// let allowance = FeeAllowance::periodic(86_400, vec![coin(1_000_000, "unibi")])
+///     .with_expiration(Timestamp::from_seconds(1_800_000_000))
+///     .only_for_msgs(vec![
+///         "/cosmwasm.wasm.v1.MsgExecuteContract".to_string(),
+///     ]);
+/// let any = allowance.to_any().unwrap();
+/// assert_eq!(any.type_url, "/cosmos.feegrant.v1beta1.AllowedMsgAllowance");
+/// assert_eq!(FeeAllowance::from_any(&any).unwrap(), allowance);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum FeeAllowance {
+    /// `BasicAllowance`: Up to `spend_limit` in total, or without limit if
+    /// it is empty.
+    Basic {
+        spend_limit: Vec<cw::Coin>,
+        expiration: Option<cw::Timestamp>,
+    },
+    /// `PeriodicAllowance`: Up to `period_spend_limit` every `period_secs`,
+    /// and up to `spend_limit` in total, or without a total limit if it is
+    /// empty.
+    Periodic {
+        spend_limit: Vec<cw::Coin>,
+        expiration: Option<cw::Timestamp>,
+        period_secs: u64,
+        period_spend_limit: Vec<cw::Coin>,
+        /// Amount left to spend in the current period. Set by the chain.
+        period_can_spend: Vec<cw::Coin>,
+        /// When the current period ends. Set by the chain.
+        period_reset: Option<cw::Timestamp>,
+    },
+    /// `AllowedMsgAllowance`: `allowance`, usable only to pay for
+    /// transactions whose messages all have a type URL in
+    /// `allowed_messages`.
+    AllowedMsgs {
+        allowance: Box<FeeAllowance>,
+        allowed_messages: Vec<String>,
+    },
+    /// An allowance type this crate cannot decode, left encoded.
+    Other(prost_types::Any),
+}
+
+impl FeeAllowance {
+    /// Allowance of up to `spend_limit` in total that never expires. An
+    /// empty `spend_limit` places no limit on spending.
+    pub fn basic(spend_limit: Vec<cw::Coin>) -> Self {
+        FeeAllowance::Basic {
+            spend_limit,
+            expiration: None,
+        }
+    }
+
+    /// Allowance of up to `period_spend_limit` every `period_secs`, with no
+    /// total limit, that never expires.
+    pub fn periodic(
+        period_secs: u64,
+        period_spend_limit: Vec<cw::Coin>,
+    ) -> Self {
+        FeeAllowance::Periodic {
+            spend_limit: vec![],
+            expiration: None,
+            period_secs,
+            period_can_spend: period_spend_limit.clone(),
+            period_spend_limit,
+            period_reset: None,
+        }
+    }
+
+    /// Sets when the allowance expires. For [FeeAllowance::AllowedMsgs],
+    /// sets the expiration of the inner allowance.
+    pub fn with_expiration(mut self, expiration: cw::Timestamp) -> Self {
+        match &mut self {
+            FeeAllowance::Basic {
+                expiration: exp, ..
+            }
+            | FeeAllowance::Periodic {
+                expiration: exp, ..
+            } => *exp = Some(expiration),
+            FeeAllowance::AllowedMsgs { allowance, .. } => {
+                **allowance =
+                    allowance.as_ref().clone().with_expiration(expiration)
+            }
+            FeeAllowance::Other(_) => {}
+        }
+        self
+    }
+
+    /// Sets the total spend limit. For [FeeAllowance::AllowedMsgs], sets the
+    /// limit of the inner allowance.
+    pub fn with_spend_limit(mut self, limit: Vec<cw::Coin>) -> Self {
+        match &mut self {
+            FeeAllowance::Basic { spend_limit, .. }
+            | FeeAllowance::Periodic { spend_limit, .. } => *spend_limit = limit,
+            FeeAllowance::AllowedMsgs { allowance, .. } => {
+                **allowance = allowance.as_ref().clone().with_spend_limit(limit)
+            }
+            FeeAllowance::Other(_) => {}
+        }
+        self
+    }
+
+    /// Restricts the allowance to transactions made only of messages with
+    /// these type URLs.
+    pub fn only_for_msgs(self, allowed_messages: Vec<String>) -> Self {
+        FeeAllowance::AllowedMsgs {
+            allowance: Box::new(self),
+            allowed_messages,
+        }
+    }
+
+    /// When the allowance expires, if ever. `None` for undecoded allowances.
+    pub fn expiration(&self) -> Option<cw::Timestamp> {
+        match self {
+            FeeAllowance::Basic { expiration, .. }
+            | FeeAllowance::Periodic { expiration, .. } => *expiration,
+            FeeAllowance::AllowedMsgs { allowance, .. } => {
+                allowance.expiration()
+            }
+            FeeAllowance::Other(_) => None,
+        }
+    }
+
+    pub fn is_expired(&self, now: cw::Timestamp) -> bool {
+        self.expiration()
+            .is_some_and(|expiration| expiration <= now)
+    }
+
+    /// Encodes the allowance as an `Any`. Errors if a period does not fit in
+    /// a protobuf `Duration`.
+    pub fn to_any(&self) -> NibiruResult<prost_types::Any> {
+        Ok(match self {
+            FeeAllowance::Basic {
+                spend_limit,
+                expiration,
+            } => basic_allowance(spend_limit, expiration).to_any(),
+            FeeAllowance::Periodic {
+                spend_limit,
+                expiration,
+                period_secs,
+                period_spend_limit,
+                period_can_spend,
+                period_reset,
+            } => feegrant::PeriodicAllowance {
+                basic: Some(basic_allowance(spend_limit, expiration)),
+                period: Some(proto_duration_from_secs(*period_secs)?),
+                period_spend_limit: proto_coins_from_cw(
+                    period_spend_limit.clone(),
+                ),
+                period_can_spend: proto_coins_from_cw(period_can_spend.clone()),
+                period_reset: period_reset.map(proto_timestamp_from_cw),
+            }
+            .to_any(),
+            FeeAllowance::AllowedMsgs {
+                allowance,
+                allowed_messages,
+            } => feegrant::AllowedMsgAllowance {
+                allowance: Some(allowance.to_any()?),
+                allowed_messages: allowed_messages.clone(),
+            }
+            .to_any(),
+            FeeAllowance::Other(any) => any.clone(),
+        })
+    }
+
+    /// Decodes an allowance from its `Any` form. Unknown allowance types
+    /// become [FeeAllowance::Other].
+    pub fn from_any(any: &prost_types::Any) -> NibiruResult<Self> {
+        let type_url = any.type_url.as_str();
+        if type_url == <feegrant::BasicAllowance as prost::Name>::type_url() {
+            let basic = feegrant::BasicAllowance::decode(&*any.value)?;
+            Ok(FeeAllowance::Basic {
+                spend_limit: cw_coins_from_proto(basic.spend_limit)?,
+                expiration: opt_timestamp(basic.expiration)?,
+            })
+        } else if type_url
+            == <feegrant::PeriodicAllowance as prost::Name>::type_url()
+        {
+            let periodic = feegrant::PeriodicAllowance::decode(&*any.value)?;
+            let basic = periodic.basic.unwrap_or_default();
+            Ok(FeeAllowance::Periodic {
+                spend_limit: cw_coins_from_proto(basic.spend_limit)?,
+                expiration: opt_timestamp(basic.expiration)?,
+                period_secs: periodic
+                    .period
+                    .as_ref()
+                    .map(secs_from_proto_duration)
+                    .transpose()?
+                    .unwrap_or_default(),
+                period_spend_limit: cw_coins_from_proto(
+                    periodic.period_spend_limit,
+                )?,
+                period_can_spend: cw_coins_from_proto(
+                    periodic.period_can_spend,
+                )?,
+                period_reset: opt_timestamp(periodic.period_reset)?,
+            })
+        } else if type_url
+            == <feegrant::AllowedMsgAllowance as prost::Name>::type_url()
+        {
+            let allowed = feegrant::AllowedMsgAllowance::decode(&*any.value)?;
+            let inner = allowed.allowance.ok_or_else(|| {
+                missing_field("AllowedMsgAllowance", "allowance")
+            })?;
+            Ok(FeeAllowance::AllowedMsgs {
+                allowance: Box::new(FeeAllowance::from_any(&inner)?),
+                allowed_messages: allowed.allowed_messages,
+            })
+        } else {
+            Ok(FeeAllowance::Other(any.clone()))
+        }
+    }
+}
+
+fn basic_allowance(
+    spend_limit: &[cw::Coin],
+    expiration: &Option<cw::Timestamp>,
+) -> feegrant::BasicAllowance {
+    feegrant::BasicAllowance {
+        spend_limit: proto_coins_from_cw(spend_limit.to_vec()),
+        expiration: expiration.map(proto_timestamp_from_cw),
+    }
+}
+
+fn opt_timestamp(
+    pb_time: Option<prost_types::Timestamp>,
+) -> NibiruResult<Option<cw::Timestamp>> {
+    pb_time.as_ref().map(cw_timestamp_from_proto).transpose()
+}
+
+fn missing_field(type_name: &str, field: &str) -> NibiruError {
+    NibiruError::CwStd(cw::StdError::generic_err(format!(
+        "{type_name} is missing \"{field}\""
+    )))
+}
+
+/// Grants `grantee` the fee `allowance` over the account of `granter`, who
+/// must sign the message. Errors if the allowance cannot be encoded, see
+/// [FeeAllowance::to_any].
+///
+/// ```
+/// use cosmwasm_std::coin;
+/// use nibiru_std::feegrant::{grant_allowance, FeeAllowance};
+///
+/// let msg = grant_allowance(
+///     "nibi1granter",
+///     "nibi1grantee",
+///     &FeeAllowance::basic(vec![coin(500_000, "unibi")]),
+/// )
+/// .unwrap();
+/// let allowance = msg.allowance.unwrap();
+/// assert_eq!(allowance.type_url, "/cosmos.feegrant.v1beta1.BasicAllowance");
+/// ```
+pub fn grant_allowance(
+    granter: impl Into<String>,
+    grantee: impl Into<String>,
+    allowance: &FeeAllowance,
+) -> NibiruResult<feegrant::MsgGrantAllowance> {
+    Ok(feegrant::MsgGrantAllowance {
+        granter: granter.into(),
+        grantee: grantee.into(),
+        allowance: Some(allowance.to_any()?),
+    })
+}
+
+/// Revokes the fee allowance from `granter` to `grantee`.
+pub fn revoke_allowance(
+    granter: impl Into<String>,
+    grantee: impl Into<String>,
+) -> feegrant::MsgRevokeAllowance {
+    feegrant::MsgRevokeAllowance {
+        granter: granter.into(),
+        grantee: grantee.into(),
+    }
+}
+
+/// FeeGrant: A decoded feegrant grant.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeeGrant {
+    pub granter: String,
+    pub grantee: String,
+    pub allowance: FeeAllowance,
+}
+
+impl TryFrom<feegrant::Grant> for FeeGrant {
+    type Error = NibiruError;
+
+    fn try_from(grant: feegrant::Grant) -> Result<Self, Self::Error> {
+        let allowance = grant
+            .allowance
+            .ok_or_else(|| missing_field("feegrant Grant", "allowance"))?;
+        Ok(FeeGrant {
+            granter: grant.granter,
+            grantee: grant.grantee,
+            allowance: FeeAllowance::from_any(&allowance)?,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "all_protos"))]
+mod tests {
+    use cosmwasm_std::{coin, Timestamp};
+
+    use super::*;
+    use crate::errors::TestResult;
+
+    #[test]
+    fn allowance_any_round_trip() -> TestResult {
+        let expiration = Timestamp::from_seconds(1_800_000_000);
+        let cases = [
+            (
+                FeeAllowance::basic(vec![coin(10, "unibi")]),
+                "/cosmos.feegrant.v1beta1.BasicAllowance",
+            ),
+            (
+                FeeAllowance::basic(vec![]).with_expiration(expiration),
+                "/cosmos.feegrant.v1beta1.BasicAllowance",
+            ),
+            (
+                FeeAllowance::periodic(3_600, vec![coin(5, "unibi")])
+                    .with_spend_limit(vec![coin(50, "unibi")]),
+                "/cosmos.feegrant.v1beta1.PeriodicAllowance",
+            ),
+            (
+                FeeAllowance::basic(vec![coin(10, "unibi")])
+                    .only_for_msgs(vec!["/cosmos.gov.v1.MsgVote".to_string()])
+                    .with_expiration(expiration),
+                "/cosmos.feegrant.v1beta1.AllowedMsgAllowance",
+            ),
+            (
+                FeeAllowance::Other(prost_types::Any {
+                    type_url: "/custom.v1.Allowance".to_string(),
+                    value: vec![1, 2, 3],
+                }),
+                "/custom.v1.Allowance",
+            ),
+        ];
+        for (allowance, want_type_url) in cases {
+            let any = allowance.to_any()?;
+            assert_eq!(any.type_url, want_type_url);
+            assert_eq!(FeeAllowance::from_any(&any)?, allowance);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn periodic_allowance_period_overflow() {
+        let allowance = FeeAllowance::periodic(u64::MAX, vec![coin(1, "unibi")]);
+        assert!(allowance.to_any().is_err());
+        assert!(
+            grant_allowance("nibi1granter", "nibi1grantee", &allowance).is_err()
+        );
+    }
+
+    #[test]
+    fn allowance_expiration() {
+        let expiration = Timestamp::from_seconds(100);
+        let allowance = FeeAllowance::periodic(60, vec![coin(1, "unibi")])
+            .only_for_msgs(vec![])
+            .with_expiration(expiration);
+        assert_eq!(allowance.expiration(), Some(expiration));
+        assert!(!allowance.is_expired(Timestamp::from_seconds(99)));
+        assert!(allowance.is_expired(Timestamp::from_seconds(100)));
+        assert!(!FeeAllowance::basic(vec![])
+            .is_expired(Timestamp::from_seconds(u64::MAX / 1_000_000_000)));
+    }
+
+    #[test]
+    fn grant_revoke_and_decode() -> TestResult {
+        let allowance = FeeAllowance::basic(vec![coin(500, "unibi")]);
+        let msg = grant_allowance("nibi1granter", "nibi1grantee", &allowance)?;
+        let grant = FeeGrant::try_from(feegrant::Grant {
+            granter: msg.granter,
+            grantee: msg.grantee,
+            allowance: msg.allowance,
+        })?;
+        assert_eq!(
+            grant,
+            FeeGrant {
+                granter: "nibi1granter".to_string(),
+                grantee: "nibi1grantee".to_string(),
+                allowance,
+            }
+        );
+
+        let revoke = revoke_allowance("nibi1granter", "nibi1grantee");
+        assert_eq!(revoke.granter, "nibi1granter");
+        assert_eq!(revoke.grantee, "nibi1grantee");
+
+        let no_allowance = feegrant::Grant {
+            granter: "nibi1granter".to_string(),
+            grantee: "nibi1grantee".to_string(),
+            allowance: None,
+        };
+        assert!(FeeGrant::try_from(no_allowance).is_err());
+        Ok(())
+    }
+}
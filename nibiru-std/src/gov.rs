@@ -0,0 +1,468 @@
+//! gov.rs: Builders for `cosmos.gov.v1` messages, so that contracts like
+//! cw3 multisigs can submit, vote on, and fund chain governance proposals.
+//!
+//! - [GovProposal]: `MsgSubmitProposal` wrapping any [NibiruStargateMsg] as
+//!   an `Any`.
+//! - [vote], [vote_weighted], and [deposit]: The other gov messages.
+//! - [ProposalInfo]: A proposal decoded from `QueryProposalResponse`.
+
+use std::str::FromStr;
+
+use cosmwasm_std::{self as cw, Decimal, Uint128};
+
+pub use crate::proto::cosmos::gov::v1::{ProposalStatus, VoteOption};
+use crate::{
+    address::module_address,
+    errors::{MathError, NibiruError, NibiruResult},
+    math::SdkInt,
+    proto::{
+        cosmos::gov::v1 as gov, cw_coins_from_proto, cw_timestamp_from_proto,
+        proto_coins_from_cw, NibiruStargateMsg,
+    },
+};
+
+/// Address of the gov module account, which signs the messages of a passed
+/// proposal. Messages that need the gov authority, like
+/// `MsgEditOracleParams`, must name it as their sender.
+pub fn gov_authority() -> String {
+    module_address("gov")
+}
+
+/// GovProposal: Builder for a `MsgSubmitProposal`. The messages run, signed
+/// by [gov_authority], if the proposal passes.
+///
+/// ```
+/// use cosmwasm_std::coin;
+/// use nibiru_std::{
+///     gov::{gov_authority, GovProposal},
+///     proto::nibiru::sudo::MsgEditSudoers,
+/// };
+///
+/// let msg = GovProposal::new(
+///     "nibi1multisig",
+///     "Add sudo contract",
+///     "Lets the multisig manage the oracle.",
+/// )
+/// .msg(&MsgEditSudoers {
+///     action: "add_contracts".to_string(),
+///     contracts: vec!["nibi1multisig".to_string()],
+///     sender: gov_authority(),
+/// })
+/// .deposit(vec![coin(10_000_000, "unibi")])
+/// .into_msg();
+/// assert_eq!(msg.messages[0].type_url, "/nibiru.sudo.v1.MsgEditSudoers");
+/// assert_eq!(msg.title, "Add sudo contract");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GovProposal {
+    pub proposer: String,
+    pub title: String,
+    pub summary: String,
+    /// Free-form metadata, conventionally a link to an off-chain JSON
+    /// document.
+    pub metadata: String,
+    pub messages: Vec<prost_types::Any>,
+    pub initial_deposit: Vec<cw::Coin>,
+}
+
+impl GovProposal {
+    pub fn new(
+        proposer: impl Into<String>,
+        title: impl Into<String>,
+        summary: impl Into<String>,
+    ) -> Self {
+        GovProposal {
+            proposer: proposer.into(),
+            title: title.into(),
+            summary: summary.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Appends a message to run if the proposal passes.
+    pub fn msg(mut self, msg: &impl NibiruStargateMsg) -> Self {
+        self.messages.push(msg.to_any());
+        self
+    }
+
+    /// Appends a message that is already wrapped as an `Any`.
+    pub fn any(mut self, any: prost_types::Any) -> Self {
+        self.messages.push(any);
+        self
+    }
+
+    pub fn metadata(mut self, metadata: impl Into<String>) -> Self {
+        self.metadata = metadata.into();
+        self
+    }
+
+    /// Sets the deposit taken from the proposer on submission.
+    pub fn deposit(mut self, initial_deposit: Vec<cw::Coin>) -> Self {
+        self.initial_deposit = initial_deposit;
+        self
+    }
+
+    pub fn into_msg(self) -> gov::MsgSubmitProposal {
+        gov::MsgSubmitProposal {
+            messages: self.messages,
+            initial_deposit: proto_coins_from_cw(self.initial_deposit),
+            proposer: self.proposer,
+            metadata: self.metadata,
+            title: self.title,
+            summary: self.summary,
+        }
+    }
+}
+
+/// Casts the whole voting power of `voter` for `option`.
+pub fn vote(
+    proposal_id: u64,
+    voter: impl Into<String>,
+    option: VoteOption,
+) -> gov::MsgVote {
+    gov::MsgVote {
+        proposal_id,
+        voter: voter.into(),
+        option: option.into(),
+        metadata: String::new(),
+    }
+}
+
+/// Splits the voting power of `voter` across `options`. The weights must be
+/// positive, name each option at most once, and add up to 1.
+///
+/// ```
+/// use cosmwasm_std::Decimal;
+/// use nibiru_std::gov::{vote_weighted, VoteOption};
+///
+/// let msg = vote_weighted(
+///     7,
+///     "nibi1voter",
+///     &[
+///         (VoteOption::Yes, Decimal::percent(70)),
+///         (VoteOption::Abstain, Decimal::percent(30)),
+///     ],
+/// )
+/// .unwrap();
+/// assert_eq!(msg.options[0].weight, "0.7");
+///
+/// // Weights must add up to 1
+/// let half_yes = [(VoteOption::Yes, Decimal::percent(50))];
+/// assert!(vote_weighted(7, "nibi1voter", &half_yes).is_err());
+/// ```
+pub fn vote_weighted(
+    proposal_id: u64,
+    voter: impl Into<String>,
+    options: &[(VoteOption, Decimal)],
+) -> NibiruResult<gov::MsgVoteWeighted> {
+    let invalid = |reason: String| NibiruError::InvalidVoteWeights(reason);
+    let mut total = Decimal::zero();
+    for (idx, (option, weight)) in options.iter().enumerate() {
+        if weight.is_zero() {
+            return Err(invalid(format!(
+                "weight of {} must be positive",
+                option.as_str_name()
+            )));
+        }
+        if options[..idx].iter().any(|(seen, _)| seen == option) {
+            return Err(invalid(format!(
+                "{} is listed more than once",
+                option.as_str_name()
+            )));
+        }
+        total += weight;
+    }
+    if total != Decimal::one() {
+        return Err(invalid(format!("weights add up to {total}, not 1")));
+    }
+
+    Ok(gov::MsgVoteWeighted {
+        proposal_id,
+        voter: voter.into(),
+        options: options
+            .iter()
+            .map(|(option, weight)| gov::WeightedVoteOption {
+                option: (*option).into(),
+                weight: weight.to_string(),
+            })
+            .collect(),
+        metadata: String::new(),
+    })
+}
+
+/// Adds `amount` to the deposit of a proposal in its deposit period.
+pub fn deposit(
+    proposal_id: u64,
+    depositor: impl Into<String>,
+    amount: Vec<cw::Coin>,
+) -> gov::MsgDeposit {
+    gov::MsgDeposit {
+        proposal_id,
+        depositor: depositor.into(),
+        amount: proto_coins_from_cw(amount),
+    }
+}
+
+/// ProposalInfo: A gov proposal with its amounts and times parsed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProposalInfo {
+    pub id: u64,
+    pub title: String,
+    pub summary: String,
+    pub metadata: String,
+    pub proposer: String,
+    pub status: ProposalStatus,
+    /// Messages that run if the proposal passes.
+    pub messages: Vec<prost_types::Any>,
+    pub total_deposit: Vec<cw::Coin>,
+    /// Tally of the votes, which is only final once voting has ended.
+    pub final_tally_result: Option<TallyResult>,
+    pub submit_time: Option<cw::Timestamp>,
+    pub deposit_end_time: Option<cw::Timestamp>,
+    pub voting_start_time: Option<cw::Timestamp>,
+    pub voting_end_time: Option<cw::Timestamp>,
+}
+
+/// TallyResult: Voting power cast for each option of a proposal.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TallyResult {
+    pub yes: Uint128,
+    pub abstain: Uint128,
+    pub no: Uint128,
+    pub no_with_veto: Uint128,
+}
+
+impl TryFrom<gov::TallyResult> for TallyResult {
+    type Error = NibiruError;
+
+    fn try_from(pb_tally: gov::TallyResult) -> Result<Self, Self::Error> {
+        let parse = |count: &str| -> NibiruResult<Uint128> {
+            // Empty counts are zero in tallies that are not yet final.
+            if count.is_empty() {
+                return Ok(Uint128::zero());
+            }
+            Ok(Uint128::try_from(SdkInt::from_str(count)?)?)
+        };
+        Ok(TallyResult {
+            yes: parse(&pb_tally.yes_count)?,
+            abstain: parse(&pb_tally.abstain_count)?,
+            no: parse(&pb_tally.no_count)?,
+            no_with_veto: parse(&pb_tally.no_with_veto_count)?,
+        })
+    }
+}
+
+impl TryFrom<gov::Proposal> for ProposalInfo {
+    type Error = NibiruError;
+
+    fn try_from(pb_proposal: gov::Proposal) -> Result<Self, Self::Error> {
+        let time = |pb_time: Option<prost_types::Timestamp>| {
+            pb_time.as_ref().map(cw_timestamp_from_proto).transpose()
+        };
+        let status =
+            ProposalStatus::try_from(pb_proposal.status).map_err(|_| {
+                MathError::OutOfRange {
+                    value: pb_proposal.status.to_string(),
+                    target: "ProposalStatus".to_string(),
+                }
+            })?;
+        Ok(ProposalInfo {
+            id: pb_proposal.id,
+            title: pb_proposal.title,
+            summary: pb_proposal.summary,
+            metadata: pb_proposal.metadata,
+            proposer: pb_proposal.proposer,
+            status,
+            messages: pb_proposal.messages,
+            total_deposit: cw_coins_from_proto(pb_proposal.total_deposit)?,
+            final_tally_result: pb_proposal
+                .final_tally_result
+                .map(TallyResult::try_from)
+                .transpose()?,
+            submit_time: time(pb_proposal.submit_time)?,
+            deposit_end_time: time(pb_proposal.deposit_end_time)?,
+            voting_start_time: time(pb_proposal.voting_start_time)?,
+            voting_end_time: time(pb_proposal.voting_end_time)?,
+        })
+    }
+}
+
+impl TryFrom<gov::QueryProposalResponse> for ProposalInfo {
+    type Error = NibiruError;
+
+    fn try_from(resp: gov::QueryProposalResponse) -> Result<Self, Self::Error> {
+        let proposal = resp.proposal.ok_or_else(|| {
+            NibiruError::CwStd(cw::StdError::generic_err(
+                "query response is missing \"proposal\"",
+            ))
+        })?;
+        ProposalInfo::try_from(proposal)
+    }
+}
+
+#[cfg(all(test, feature = "all_protos"))]
+mod tests {
+    use cosmwasm_std::{coin, Timestamp};
+
+    use super::*;
+    use crate::{
+        errors::TestResult,
+        proto::{nibiru, NibiruProstMsg},
+    };
+
+    #[test]
+    fn proposal_wraps_msgs_as_any() -> TestResult {
+        let authority = gov_authority();
+        let edit_oracle = nibiru::oracle::MsgEditOracleParams {
+            sender: authority.clone(),
+            params: Some(nibiru::oracle::OracleParamsMsg {
+                vote_period: 30,
+                ..Default::default()
+            }),
+        };
+        let edit_inflation = nibiru::inflation::MsgEditInflationParams {
+            sender: authority.clone(),
+            inflation_enabled: true,
+            ..Default::default()
+        };
+        let edit_sudoers = nibiru::sudo::MsgEditSudoers {
+            action: "add_contracts".to_string(),
+            contracts: vec!["nibi1multisig".to_string()],
+            sender: authority,
+        };
+        let msg = GovProposal::new("nibi1multisig", "Params", "Edit params")
+            .msg(&edit_oracle)
+            .msg(&edit_inflation)
+            .msg(&edit_sudoers)
+            .metadata("ipfs://proposal.json")
+            .deposit(vec![coin(10, "unibi")])
+            .into_msg();
+
+        let type_urls: Vec<&str> = msg
+            .messages
+            .iter()
+            .map(|any| any.type_url.as_str())
+            .collect();
+        assert_eq!(
+            type_urls,
+            [
+                "/nibiru.oracle.v1.MsgEditOracleParams",
+                "/nibiru.inflation.v1.MsgEditInflationParams",
+                "/nibiru.sudo.v1.MsgEditSudoers",
+            ]
+        );
+        assert_eq!(
+            nibiru::oracle::MsgEditOracleParams::from_any(&msg.messages[0])?,
+            edit_oracle
+        );
+        assert_eq!(msg.proposer, "nibi1multisig");
+        assert_eq!(msg.metadata, "ipfs://proposal.json");
+        assert_eq!(msg.summary, "Edit params");
+        assert_eq!(msg.initial_deposit, vec![coin(10, "unibi").into()]);
+        assert_eq!(
+            gov_authority(),
+            "nibi10d07y265gmmuvt4z0w9aw880jnsr700jd8hulq"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn vote_and_deposit_msgs() -> TestResult {
+        let msg = vote(3, "nibi1voter", VoteOption::NoWithVeto);
+        assert_eq!(msg.option, VoteOption::NoWithVeto as i32);
+        assert_eq!(msg.proposal_id, 3);
+
+        let msg = deposit(3, "nibi1depositor", vec![coin(5, "unibi")]);
+        assert_eq!(msg.amount, vec![coin(5, "unibi").into()]);
+
+        let yes = (VoteOption::Yes, Decimal::percent(60));
+        let no = (VoteOption::No, Decimal::percent(40));
+        let msg = vote_weighted(3, "nibi1voter", &[yes, no])?;
+        assert_eq!(
+            msg.options,
+            vec![
+                gov::WeightedVoteOption {
+                    option: VoteOption::Yes as i32,
+                    weight: "0.6".to_string(),
+                },
+                gov::WeightedVoteOption {
+                    option: VoteOption::No as i32,
+                    weight: "0.4".to_string(),
+                },
+            ]
+        );
+
+        let invalid_cases: Vec<Vec<(VoteOption, Decimal)>> = vec![
+            vec![],
+            vec![yes],
+            vec![yes, (VoteOption::Yes, Decimal::percent(40))],
+            vec![
+                (VoteOption::Yes, Decimal::one()),
+                (VoteOption::No, Decimal::zero()),
+            ],
+        ];
+        for options in invalid_cases {
+            let err = vote_weighted(3, "nibi1voter", &options).unwrap_err();
+            assert!(
+                matches!(err, NibiruError::InvalidVoteWeights(_)),
+                "{options:?}: {err}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn decode_proposal() -> TestResult {
+        let submit_msg = GovProposal::new("nibi1proposer", "Title", "Summary")
+            .msg(&nibiru::sudo::MsgEditSudoers::default())
+            .into_msg();
+        let resp = gov::QueryProposalResponse {
+            proposal: Some(gov::Proposal {
+                id: 12,
+                messages: submit_msg.messages.clone(),
+                status: ProposalStatus::VotingPeriod.into(),
+                final_tally_result: Some(gov::TallyResult {
+                    yes_count: "1000".to_string(),
+                    abstain_count: "0".to_string(),
+                    no_count: "25".to_string(),
+                    no_with_veto_count: String::new(),
+                }),
+                submit_time: Some(prost_types::Timestamp {
+                    seconds: 1_700_000_000,
+                    nanos: 0,
+                }),
+                deposit_end_time: None,
+                total_deposit: vec![coin(10, "unibi").into()],
+                voting_start_time: None,
+                voting_end_time: None,
+                metadata: String::new(),
+                title: "Title".to_string(),
+                summary: "Summary".to_string(),
+                proposer: "nibi1proposer".to_string(),
+            }),
+        };
+
+        let info = ProposalInfo::try_from(resp)?;
+        assert_eq!(info.id, 12);
+        assert_eq!(info.status, ProposalStatus::VotingPeriod);
+        assert_eq!(info.messages, submit_msg.messages);
+        assert_eq!(info.total_deposit, vec![coin(10, "unibi")]);
+        assert_eq!(
+            info.final_tally_result,
+            Some(TallyResult {
+                yes: Uint128::new(1000),
+                no: Uint128::new(25),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            info.submit_time,
+            Some(Timestamp::from_seconds(1_700_000_000))
+        );
+        assert_eq!(info.title, "Title");
+
+        let empty = gov::QueryProposalResponse { proposal: None };
+        assert!(ProposalInfo::try_from(empty).is_err());
+        Ok(())
+    }
+}
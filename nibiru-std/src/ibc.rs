@@ -0,0 +1,567 @@
+//! ibc.rs: Builders for ICS-20 token transfers, so that contracts can send
+//! tokens to other chains through Stargate and chain further actions onto
+//! the transfer with a memo.
+//!
+//! - [IbcTransfer]: Builds and validates a `MsgTransfer`.
+//! - [IbcMemo]: Structured memo for the middleware of the receiving chain:
+//!   [ForwardMemo] for packet-forward-middleware and [WasmHookMemo] for
+//!   ibc-hooks, with an optional `ibc_callback`.
+//! - [revision_number]: The revision of a chain ID, for timeout heights.
+//!
+//! The sequence of the sent packet is in the `MsgTransferResponse` of the
+//! reply, which [crate::wasm::msg_response_from_reply] decodes.
+
+use cosmwasm_schema::schemars::JsonSchema;
+use cosmwasm_std as cw;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::{NibiruError, NibiruResult},
+    proto::ibc::{
+        applications::transfer::v1::MsgTransfer, core::client::v1::Height,
+    },
+};
+
+/// Port of the ICS-20 transfer module.
+pub const TRANSFER_PORT: &str = "transfer";
+
+/// Maximum length in bytes of a transfer memo, from ibc-go.
+pub const MAX_MEMO_LEN: usize = 32_768;
+
+/// Maximum length in bytes of a transfer receiver, from ibc-go.
+pub const MAX_RECEIVER_LEN: usize = 2_048;
+
+impl From<cw::IbcTimeoutBlock> for Height {
+    fn from(block: cw::IbcTimeoutBlock) -> Self {
+        Height {
+            revision_number: block.revision,
+            revision_height: block.height,
+        }
+    }
+}
+
+impl From<Height> for cw::IbcTimeoutBlock {
+    fn from(height: Height) -> Self {
+        cw::IbcTimeoutBlock {
+            revision: height.revision_number,
+            height: height.revision_height,
+        }
+    }
+}
+
+/// IbcTransfer: Builder for an ICS-20 `MsgTransfer` sent from the
+/// "transfer" port. A timeout is required; [IbcTransfer::into_msg] checks
+/// the message with the same rules as ibc-go.
+///
+/// ```
+/// use cosmwasm_std::{coin, testing::mock_env};
+/// use nibiru_std::{
+///     ibc::{IbcMemo, IbcTransfer},
+///     proto::NibiruStargateMsg,
+/// };
+///
+/// let env = mock_env();
+/// let msg = IbcTransfer::new(
+///     "nibi1contract",
+///     "channel-0",
+///     "osmo1receiver",
+///     coin(1_000, "unibi"),
+/// )
+/// .timeout_after(&env.block, 600)
+/// .ibc_memo(&IbcMemo::default().callback("nibi1contract"))
+/// .into_msg()
+/// .unwrap();
+/// assert_eq!(msg.memo, r#"{"ibc_callback":"nibi1contract"}"#);
+/// assert_eq!(msg.type_url(), "/ibc.applications.transfer.v1.MsgTransfer");
+///
+/// // A transfer needs a timeout
+/// let no_timeout = IbcTransfer::new(
+///     "nibi1contract",
+///     "channel-0",
+///     "osmo1receiver",
+///     coin(1_000, "unibi"),
+/// );
+/// assert!(no_timeout.into_msg().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct IbcTransfer {
+    pub source_port: String,
+    pub source_channel: String,
+    pub token: cw::Coin,
+    pub sender: String,
+    /// Address on the destination chain.
+    pub receiver: String,
+    pub timeout: Option<cw::IbcTimeout>,
+    pub memo: String,
+}
+
+impl IbcTransfer {
+    pub fn new(
+        sender: impl Into<String>,
+        source_channel: impl Into<String>,
+        receiver: impl Into<String>,
+        token: cw::Coin,
+    ) -> Self {
+        IbcTransfer {
+            source_port: TRANSFER_PORT.to_string(),
+            source_channel: source_channel.into(),
+            token,
+            sender: sender.into(),
+            receiver: receiver.into(),
+            timeout: None,
+            memo: String::new(),
+        }
+    }
+
+    pub fn port(mut self, source_port: impl Into<String>) -> Self {
+        self.source_port = source_port.into();
+        self
+    }
+
+    /// Sets the timeout, which accepts a `Timestamp`, an `IbcTimeoutBlock`
+    /// on the destination chain, or an `IbcTimeout` with both.
+    pub fn timeout(mut self, timeout: impl Into<cw::IbcTimeout>) -> Self {
+        self.timeout = Some(timeout.into());
+        self
+    }
+
+    /// Times the transfer out `seconds` after the time of `block`.
+    pub fn timeout_after(self, block: &cw::BlockInfo, seconds: u64) -> Self {
+        self.timeout(block.time.plus_seconds(seconds))
+    }
+
+    pub fn memo(mut self, memo: impl Into<String>) -> Self {
+        self.memo = memo.into();
+        self
+    }
+
+    /// Sets the memo to the JSON of a structured [IbcMemo].
+    pub fn ibc_memo(self, memo: &IbcMemo) -> Self {
+        self.memo(memo.to_json())
+    }
+
+    pub fn into_msg(self) -> NibiruResult<MsgTransfer> {
+        let timeout =
+            self.validate().map_err(NibiruError::InvalidIbcTransfer)?;
+        if self.token.amount.is_zero() {
+            return Err(NibiruError::InvalidCoinAmount {
+                denom: self.token.denom,
+                amount: self.token.amount.to_string(),
+                reason: "amount must be positive".to_string(),
+            });
+        }
+        Ok(MsgTransfer {
+            source_port: self.source_port,
+            source_channel: self.source_channel,
+            token: Some(self.token.into()),
+            sender: self.sender,
+            receiver: self.receiver,
+            timeout_height: timeout.block().map(Height::from),
+            timeout_timestamp: timeout
+                .timestamp()
+                .map_or(0, |timestamp| timestamp.nanos()),
+            memo: self.memo,
+        })
+    }
+
+    /// Checks everything but the amount and returns the timeout.
+    fn validate(&self) -> Result<cw::IbcTimeout, String> {
+        validate_identifier("port", &self.source_port, 2, 128)?;
+        validate_identifier("channel", &self.source_channel, 8, 64)?;
+        if self.sender.is_empty() {
+            return Err("sender must not be empty".to_string());
+        }
+        if self.receiver.trim().is_empty() {
+            return Err("receiver must not be empty".to_string());
+        }
+        if self.receiver.len() > MAX_RECEIVER_LEN {
+            return Err(format!(
+                "receiver is longer than {MAX_RECEIVER_LEN} bytes"
+            ));
+        }
+        if self.memo.len() > MAX_MEMO_LEN {
+            return Err(format!("memo is longer than {MAX_MEMO_LEN} bytes"));
+        }
+        let Some(timeout) = &self.timeout else {
+            return Err("a timeout height or timestamp is required".to_string());
+        };
+        let is_zero_block =
+            timeout.block().is_none_or(|block| block.height == 0);
+        let is_zero_time = timeout
+            .timestamp()
+            .is_none_or(|timestamp| timestamp.nanos() == 0);
+        if is_zero_block && is_zero_time {
+            return Err("timeout height and timestamp cannot both be 0".into());
+        }
+
+        // ibc-hooks only runs a memo that matches the transfer
+        if let Ok(memo) = serde_json::from_str::<IbcMemo>(&self.memo) {
+            if let Some(wasm) = &memo.wasm {
+                if wasm.contract != self.receiver {
+                    return Err(format!(
+                        "wasm hook contract {} is not the receiver {}",
+                        wasm.contract, self.receiver
+                    ));
+                }
+            }
+            if let Some(callback) = &memo.ibc_callback {
+                if *callback != self.sender {
+                    return Err(format!(
+                        "ibc_callback {callback} is not the sender {}",
+                        self.sender
+                    ));
+                }
+            }
+        }
+        Ok(timeout.clone())
+    }
+}
+
+/// Checks a port or channel ID with the rules of ibc-go's `host` package:
+/// `min..=max` characters out of `[a-zA-Z0-9._+-#[]<>]`.
+fn validate_identifier(
+    kind: &str,
+    id: &str,
+    min: usize,
+    max: usize,
+) -> Result<(), String> {
+    if !(min..=max).contains(&id.len()) {
+        return Err(format!(
+            "{kind} \"{id}\" must be {min} to {max} characters long"
+        ));
+    }
+    let is_valid_char =
+        |c: char| c.is_ascii_alphanumeric() || "._+-#[]<>".contains(c);
+    if !id.chars().all(is_valid_char) {
+        return Err(format!("{kind} \"{id}\" has an invalid character"));
+    }
+    Ok(())
+}
+
+/// IbcMemo: Structured memo of a `MsgTransfer` for the middleware of the
+/// receiving chain. Fields that are `None` are left out of the JSON.
+///
+/// ```
+/// use nibiru_std::ibc::{ForwardMemo, IbcMemo};
+///
+/// // Forward through Osmosis to Cosmos Hub
+/// let memo = IbcMemo::forward(
+///     ForwardMemo::new("cosmos1receiver", "channel-0")
+///         .timeout_secs(600)
+///         .retries(2),
+/// );
+/// assert_eq!(
+///     memo.to_json(),
+///     r#"{"forward":{"receiver":"cosmos1receiver","port":"transfer","channel":"channel-0","timeout":"600s","retries":2}}"#
+/// );
+/// ```
+#[derive(
+    Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema,
+)]
+#[schemars(crate = "::cosmwasm_schema::schemars")]
+pub struct IbcMemo {
+    /// packet-forward-middleware: Sends the received tokens on to another
+    /// chain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forward: Option<ForwardMemo>,
+    /// ibc-hooks: Executes a contract on the receiving chain with the
+    /// received tokens as funds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wasm: Option<WasmHookMemo>,
+    /// ibc-hooks: Contract on the sending chain that gets an
+    /// `ibc_lifecycle_complete` sudo message when the packet is acknowledged
+    /// or times out. It must be the sender of the transfer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ibc_callback: Option<String>,
+}
+
+impl IbcMemo {
+    pub fn forward(forward: ForwardMemo) -> Self {
+        IbcMemo {
+            forward: Some(forward),
+            ..Default::default()
+        }
+    }
+
+    /// Memo that executes `msg` on `contract`, which must also be the
+    /// receiver of the transfer.
+    pub fn wasm(
+        contract: impl Into<String>,
+        msg: &impl Serialize,
+    ) -> NibiruResult<Self> {
+        Ok(IbcMemo {
+            wasm: Some(WasmHookMemo::new(contract, msg)?),
+            ..Default::default()
+        })
+    }
+
+    /// Asks ibc-hooks to report the outcome of the transfer to `contract`.
+    pub fn callback(mut self, contract: impl Into<String>) -> Self {
+        self.ibc_callback = Some(contract.into());
+        self
+    }
+
+    pub fn to_json(&self) -> String {
+        // Only strings and JSON values, which always serialize
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+/// ForwardMemo: packet-forward-middleware metadata that relays the tokens
+/// from the receiving chain to `receiver` over `channel`. The receiver of
+/// the transfer itself is ignored by the forwarding chain.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "::cosmwasm_schema::schemars")]
+pub struct ForwardMemo {
+    pub receiver: String,
+    pub port: String,
+    pub channel: String,
+    /// Go duration of the forwarded packet's timeout, Ex.: "10m".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u8>,
+    /// Memo of the forwarded transfer, for a further hop or a hook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<Box<IbcMemo>>,
+}
+
+impl ForwardMemo {
+    /// Forwards over `channel` of the "transfer" port.
+    pub fn new(receiver: impl Into<String>, channel: impl Into<String>) -> Self {
+        ForwardMemo {
+            receiver: receiver.into(),
+            port: TRANSFER_PORT.to_string(),
+            channel: channel.into(),
+            timeout: None,
+            retries: None,
+            next: None,
+        }
+    }
+
+    pub fn timeout_secs(mut self, seconds: u64) -> Self {
+        self.timeout = Some(format!("{seconds}s"));
+        self
+    }
+
+    pub fn retries(mut self, retries: u8) -> Self {
+        self.retries = Some(retries);
+        self
+    }
+
+    pub fn next(mut self, memo: IbcMemo) -> Self {
+        self.next = Some(Box::new(memo));
+        self
+    }
+}
+
+/// WasmHookMemo: ibc-hooks metadata that executes `msg` on `contract`.
+/// The message is embedded as a JSON object, not as base64.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "::cosmwasm_schema::schemars")]
+pub struct WasmHookMemo {
+    pub contract: String,
+    pub msg: serde_json::Value,
+}
+
+impl WasmHookMemo {
+    pub fn new(
+        contract: impl Into<String>,
+        msg: &impl Serialize,
+    ) -> NibiruResult<Self> {
+        let msg = serde_json::to_value(msg)
+            .map_err(|err| NibiruError::InvalidIbcTransfer(err.to_string()))?;
+        if !msg.is_object() {
+            return Err(NibiruError::InvalidIbcTransfer(
+                "wasm hook msg must be a JSON object".to_string(),
+            ));
+        }
+        Ok(WasmHookMemo {
+            contract: contract.into(),
+            msg,
+        })
+    }
+}
+
+/// Revision number of a chain ID in the "{name}-{revision}" format of
+/// ibc-go, Ex.: 1 for "cataclysm-1". Chain IDs in any other format are at
+/// revision 0.
+pub fn revision_number(chain_id: &str) -> u64 {
+    let Some((name, revision)) = chain_id.rsplit_once('-') else {
+        return 0;
+    };
+    let is_revision_format = !name.is_empty()
+        && !name.ends_with('-')
+        && revision.starts_with(|c: char| ('1'..='9').contains(&c))
+        && revision.chars().all(|c| c.is_ascii_digit());
+    match is_revision_format {
+        true => revision.parse().unwrap_or_default(),
+        false => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{coin, testing::mock_env, IbcTimeoutBlock, Timestamp};
+    use serde_json::json;
+
+    use super::*;
+    use crate::{errors::TestResult, proto::NibiruStargateMsg};
+
+    fn transfer() -> IbcTransfer {
+        IbcTransfer::new(
+            "nibi1sender",
+            "channel-3",
+            "osmo1receiver",
+            coin(25, "unibi"),
+        )
+        .timeout(Timestamp::from_seconds(100))
+    }
+
+    #[test]
+    fn transfer_msg() -> TestResult {
+        let block = IbcTimeoutBlock {
+            revision: 1,
+            height: 500,
+        };
+        let msg = transfer()
+            .timeout(cw::IbcTimeout::with_both(block, Timestamp::from_nanos(7)))
+            .memo("hello")
+            .into_msg()?;
+        assert_eq!(
+            msg,
+            MsgTransfer {
+                source_port: "transfer".to_string(),
+                source_channel: "channel-3".to_string(),
+                token: Some(coin(25, "unibi").into()),
+                sender: "nibi1sender".to_string(),
+                receiver: "osmo1receiver".to_string(),
+                timeout_height: Some(Height {
+                    revision_number: 1,
+                    revision_height: 500,
+                }),
+                timeout_timestamp: 7,
+                memo: "hello".to_string(),
+            }
+        );
+        assert_eq!(IbcTimeoutBlock::from(msg.timeout_height.unwrap()), block);
+
+        let env = mock_env();
+        let msg = transfer().timeout_after(&env.block, 60).into_msg()?;
+        assert_eq!(msg.timeout_height, None);
+        assert_eq!(
+            msg.timeout_timestamp,
+            env.block.time.plus_seconds(60).nanos()
+        );
+
+        let any = msg.to_any();
+        assert_eq!(any.type_url, "/ibc.applications.transfer.v1.MsgTransfer");
+        Ok(())
+    }
+
+    #[test]
+    fn transfer_validation() {
+        let wasm_memo = IbcMemo::wasm("osmo1other", &json!({"swap": {}}))
+            .unwrap()
+            .to_json();
+        let test_cases: Vec<(&str, IbcTransfer)> = vec![
+            ("amount must be positive", {
+                let mut msg = transfer();
+                msg.token = coin(0, "unibi");
+                msg
+            }),
+            ("timeout height or timestamp is required", {
+                let mut msg = transfer();
+                msg.timeout = None;
+                msg
+            }),
+            (
+                "cannot both be 0",
+                transfer().timeout(Timestamp::from_nanos(0)),
+            ),
+            ("channel \"ch-1\" must be 8 to 64", {
+                let mut msg = transfer();
+                msg.source_channel = "ch-1".to_string();
+                msg
+            }),
+            ("has an invalid character", transfer().port("trans fer")),
+            ("receiver must not be empty", {
+                let mut msg = transfer();
+                msg.receiver = " ".to_string();
+                msg
+            }),
+            (
+                "memo is longer than",
+                transfer().memo("x".repeat(MAX_MEMO_LEN + 1)),
+            ),
+            (
+                "wasm hook contract osmo1other is not the receiver",
+                transfer().memo(wasm_memo),
+            ),
+            (
+                "ibc_callback nibi1other is not the sender",
+                transfer().ibc_memo(&IbcMemo::default().callback("nibi1other")),
+            ),
+        ];
+        for (want_err, msg) in test_cases {
+            let err = msg.into_msg().unwrap_err().to_string();
+            assert!(err.contains(want_err), "want {want_err:?}, got {err:?}");
+        }
+
+        // A memo for other middleware is passed through as is
+        let memo = r#"{"autopilot":{"receiver":"stride1abc"}}"#;
+        assert_eq!(transfer().memo(memo).into_msg().unwrap().memo, memo);
+    }
+
+    #[test]
+    fn memo_json() -> TestResult {
+        // Forward through one chain, then call a contract on the next
+        let hook = IbcMemo::wasm("osmo1swap", &json!({"swap": {"min": "1"}}))?;
+        let memo = IbcMemo::forward(
+            ForwardMemo::new("osmo1swap", "channel-9")
+                .timeout_secs(600)
+                .next(hook),
+        );
+        let want = json!({
+            "forward": {
+                "receiver": "osmo1swap",
+                "port": "transfer",
+                "channel": "channel-9",
+                "timeout": "600s",
+                "next": {
+                    "wasm": {
+                        "contract": "osmo1swap",
+                        "msg": {"swap": {"min": "1"}}
+                    }
+                }
+            }
+        });
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&memo.to_json())?,
+            want
+        );
+        assert_eq!(serde_json::from_value::<IbcMemo>(want)?, memo);
+
+        assert_eq!(IbcMemo::default().to_json(), "{}");
+        assert!(IbcMemo::wasm("osmo1swap", &"not an object").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn revision_numbers() {
+        for (chain_id, want) in [
+            ("cataclysm-1", 1),
+            ("nibiru-testnet-2", 2),
+            ("osmosis-1", 1),
+            ("evmos_9001-2", 2),
+            ("localnet", 0),
+            ("chain-0", 0),
+            ("chain-01", 0),
+            ("chain--1", 0),
+            ("-1", 0),
+            ("chain-1a", 0),
+        ] {
+            assert_eq!(revision_number(chain_id), want, "chain ID: {chain_id}");
+        }
+    }
+}
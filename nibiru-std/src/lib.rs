@@ -1,8 +1,43 @@
+// Exactly one CosmWasm major version is selected with the "cosmwasm_1"
+// (default) or "cosmwasm_2" feature. Under "cosmwasm_2", cosmwasm-std 2.x is
+// aliased to `cosmwasm_std` so the rest of the crate is version agnostic.
+#[cfg(all(feature = "cosmwasm_1", feature = "cosmwasm_2"))]
+compile_error!(
+    "features \"cosmwasm_1\" and \"cosmwasm_2\" are mutually exclusive; \
+     depend on nibiru-std with `default-features = false` to use \"cosmwasm_2\""
+);
+#[cfg(not(any(feature = "cosmwasm_1", feature = "cosmwasm_2")))]
+compile_error!(
+    "one of the features \"cosmwasm_1\" or \"cosmwasm_2\" is required"
+);
+#[cfg(all(feature = "cosmwasm_2", feature = "multitest"))]
+compile_error!("feature \"multitest\" requires \"cosmwasm_1\"");
+
+#[cfg(all(feature = "cosmwasm_2", not(feature = "cosmwasm_1")))]
+extern crate cosmwasm_std_2 as cosmwasm_std;
+
 pub mod address;
+#[cfg(feature = "authz")]
+pub mod authz;
 pub mod bindings;
 pub mod errors;
+#[cfg(feature = "evm")]
+pub mod evm;
+#[cfg(feature = "feegrant")]
+pub mod feegrant;
+#[cfg(feature = "gov")]
+pub mod gov;
+#[cfg(feature = "ibc")]
+pub mod ibc;
 pub mod math;
+#[cfg(feature = "oracle")]
+pub mod oracle;
 pub mod proto;
+pub mod querier;
+#[cfg(feature = "tx")]
+pub mod signing;
+#[cfg(feature = "tokenfactory")]
+pub mod tokenfactory;
 pub mod tutil;
 pub mod wasm;
 
@@ -1,29 +1,41 @@
 use crate::errors::MathError;
-use std::{
-    fmt,
-    ops::{Add, Div, Mul, Sub},
-    str::FromStr,
-};
+use std::{cmp::Ordering, fmt, str::FromStr};
 
+use cosmwasm_schema::schemars::{
+    gen::SchemaGenerator, schema::Schema, JsonSchema,
+};
 use cosmwasm_std as cw;
-
-// cosmwasm dec from sdk dec
-// TODO: cosmwasm dec from sdk int  -> What's the max value
-// Decimal
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Sign: The sign of a number. "Positive" and "Negative" mean strictly
-/// postive and negative (excluding 0), respectively.
+/// postive and negative (excluding 0), respectively. Variants are ordered
+/// from least to greatest.
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Ord, PartialOrd)]
 pub enum Sign {
-    Positive,
     Negative,
     #[default]
     Zero,
+    Positive,
+}
+
+/// RoundingMode: Strategy for dropping decimal places from a [DecimalExt].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+    /// Round to the nearest value, with ties going to the even neighbor
+    /// (banker's rounding).
+    HalfEven,
 }
 
 /// DecimalExt: Implements a signed version of `cosmwasm_std::Decimal`
 /// with extentions for generating protobuf type strings.
-#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Debug)]
+///
+/// Serializes to JSON as a decimal string like "-420.69", so it can be used
+/// directly in `cw_serde` messages and contract storage.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
 pub struct DecimalExt {
     sign: Sign,
     dec: cw::Decimal,
@@ -34,6 +46,30 @@ impl DecimalExt {
         DecimalExt::default()
     }
 
+    /// Constructs a `DecimalExt` from its sign and absolute value. Any zero
+    /// value has `Sign::Zero`.
+    pub fn new(is_negative: bool, abs_value: cw::Decimal) -> Self {
+        let sign = match (abs_value.is_zero(), is_negative) {
+            (true, _) => Sign::Zero,
+            (false, true) => Sign::Negative,
+            (false, false) => Sign::Positive,
+        };
+        DecimalExt {
+            sign,
+            dec: abs_value,
+        }
+    }
+
+    /// The largest representable value.
+    pub fn max() -> Self {
+        DecimalExt::new(false, cw::Decimal::MAX)
+    }
+
+    /// The smallest representable value.
+    pub fn min() -> Self {
+        DecimalExt::new(true, cw::Decimal::MAX)
+    }
+
     /// Getter for `Sign`, which can be +, -, 0.
     pub fn sign(&self) -> Sign {
         self.sign
@@ -44,26 +80,18 @@ impl DecimalExt {
         self.dec
     }
 
-    pub fn add(&self, other: Self) -> Self {
-        if self.sign == other.sign {
-            return DecimalExt {
-                sign: self.sign,
-                dec: self.dec.add(other.dec),
-            };
-        } else if other.dec.is_zero() {
-            return *self;
-        }
+    pub fn is_zero(&self) -> bool {
+        self.sign == Sign::Zero
+    }
 
-        let self_dec_gt: bool = self.dec.ge(&other.dec);
-        let sign = if self_dec_gt { self.sign } else { other.sign };
-        let dec = if self_dec_gt {
-            self.dec.sub(other.dec) // if abs(self.dec) > abs(other.dec)
-        } else {
-            other.dec.sub(self.dec) // if abs(self.dec) < abs(other.dec)
-        };
-        let sign = if dec.is_zero() { Sign::Zero } else { sign };
+    pub fn is_negative(&self) -> bool {
+        self.sign == Sign::Negative
+    }
 
-        DecimalExt { sign, dec }
+    /// Adds two decimals. Panics on overflow. See [DecimalExt::checked_add].
+    pub fn add(&self, other: Self) -> Self {
+        self.checked_add(other)
+            .unwrap_or_else(|err| panic!("{err}"))
     }
 
     pub fn neg(&self) -> Self {
@@ -80,45 +108,261 @@ impl DecimalExt {
         }
     }
 
+    /// Subtracts `other`. Panics on overflow. See [DecimalExt::checked_sub].
     pub fn sub(&self, other: Self) -> Self {
         self.add(other.neg())
     }
 
+    /// Multiplies two decimals. Panics on overflow. See
+    /// [DecimalExt::checked_mul].
     pub fn mul(&self, other: Self) -> Self {
-        let dec = self.dec.mul(other.dec);
-        let sign = match (self.sign, other.sign) {
-            (Sign::Zero, _) | (_, Sign::Zero) => Sign::Zero,
-            (Sign::Positive, Sign::Positive)
-            | (Sign::Negative, Sign::Negative) => Sign::Positive,
-            (Sign::Positive, Sign::Negative)
-            | (Sign::Negative, Sign::Positive) => Sign::Negative,
-        };
-        DecimalExt { sign, dec }
+        self.checked_mul(other)
+            .unwrap_or_else(|err| panic!("{err}"))
     }
 
     pub fn quo(&self, other: Self) -> Result<Self, MathError> {
-        let sign = match (self.sign, other.sign) {
-            (Sign::Zero, _) => Sign::Zero,
-            (_, Sign::Zero) => return Err(MathError::DivisionByZero),
-            (Sign::Positive, Sign::Positive)
-            | (Sign::Negative, Sign::Negative) => Sign::Positive,
-            (Sign::Positive, Sign::Negative)
-            | (Sign::Negative, Sign::Positive) => Sign::Negative,
+        self.checked_quo(other)
+    }
+
+    /// Sign of the product or quotient of two non-zero values.
+    fn product_is_negative(&self, other: &Self) -> bool {
+        self.is_negative() != other.is_negative()
+    }
+
+    fn overflow_err(
+        &self,
+        operation: &str,
+        other: impl fmt::Display,
+    ) -> MathError {
+        MathError::Overflow {
+            operation: operation.to_string(),
+            lhs: self.to_string(),
+            rhs: other.to_string(),
+        }
+    }
+
+    pub fn checked_add(&self, other: Self) -> Result<Self, MathError> {
+        if other.is_zero() {
+            return Ok(*self);
+        } else if self.is_zero() {
+            return Ok(other);
+        } else if self.sign == other.sign {
+            let dec = self
+                .dec
+                .checked_add(other.dec)
+                .map_err(|_| self.overflow_err("+", other))?;
+            return Ok(DecimalExt::new(self.is_negative(), dec));
+        }
+
+        // Opposite signs: the result takes the sign of the larger magnitude.
+        Ok(match self.dec.cmp(&other.dec) {
+            Ordering::Less => {
+                DecimalExt::new(other.is_negative(), other.dec - self.dec)
+            }
+            _ => DecimalExt::new(self.is_negative(), self.dec - other.dec),
+        })
+    }
+
+    pub fn checked_sub(&self, other: Self) -> Result<Self, MathError> {
+        self.checked_add(other.neg())
+            .map_err(|_| self.overflow_err("-", other))
+    }
+
+    pub fn checked_mul(&self, other: Self) -> Result<Self, MathError> {
+        let dec = self
+            .dec
+            .checked_mul(other.dec)
+            .map_err(|_| self.overflow_err("*", other))?;
+        Ok(DecimalExt::new(self.product_is_negative(&other), dec))
+    }
+
+    /// Divides by `other`. Errors on division by zero or overflow.
+    pub fn checked_quo(&self, other: Self) -> Result<Self, MathError> {
+        if other.is_zero() {
+            return Err(MathError::DivisionByZero);
+        }
+        let dec = self
+            .dec
+            .checked_div(other.dec)
+            .map_err(|_| self.overflow_err("/", other))?;
+        Ok(DecimalExt::new(self.product_is_negative(&other), dec))
+    }
+
+    /// Adds two decimals, clamping to [DecimalExt::max] or
+    /// [DecimalExt::min] instead of overflowing.
+    pub fn saturating_add(&self, other: Self) -> Self {
+        self.checked_add(other).unwrap_or_else(|_| {
+            DecimalExt::new(self.is_negative(), cw::Decimal::MAX)
+        })
+    }
+
+    /// Subtracts `other`, clamping to [DecimalExt::max] or
+    /// [DecimalExt::min] instead of overflowing.
+    pub fn saturating_sub(&self, other: Self) -> Self {
+        self.saturating_add(other.neg())
+    }
+
+    /// Multiplies two decimals, clamping to [DecimalExt::max] or
+    /// [DecimalExt::min] instead of overflowing.
+    pub fn saturating_mul(&self, other: Self) -> Self {
+        self.checked_mul(other).unwrap_or_else(|_| {
+            DecimalExt::new(self.product_is_negative(&other), cw::Decimal::MAX)
+        })
+    }
+
+    /// Rounds to an integer value with the given [RoundingMode].
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use nibiru_std::math::{DecimalExt, RoundingMode};
+    ///
+    /// let dec = DecimalExt::from_str("-2.5").unwrap();
+    /// assert_eq!(dec.round(RoundingMode::Floor).unwrap().to_string(), "-3");
+    /// assert_eq!(dec.round(RoundingMode::Ceil).unwrap().to_string(), "-2");
+    /// assert_eq!(dec.round(RoundingMode::HalfEven).unwrap().to_string(), "-2");
+    /// ```
+    pub fn round(&self, mode: RoundingMode) -> Result<Self, MathError> {
+        self.round_dp(0, mode)
+    }
+
+    /// Rounds to at most `decimal_places` fractional digits with the given
+    /// [RoundingMode]. Errors if rounding away from zero overflows.
+    pub fn round_dp(
+        &self,
+        decimal_places: u32,
+        mode: RoundingMode,
+    ) -> Result<Self, MathError> {
+        let precision = cw::Decimal::DECIMAL_PLACES;
+        if decimal_places >= precision {
+            return Ok(*self);
+        }
+        let unit: u128 = 10u128.pow(precision - decimal_places);
+        let atomics: u128 = self.dec.atomics().u128();
+        let (quotient, remainder) = (atomics / unit, atomics % unit);
+
+        let round_away_from_zero = match (mode, self.sign) {
+            (RoundingMode::Floor, Sign::Negative)
+            | (RoundingMode::Ceil, Sign::Positive) => remainder > 0,
+            (RoundingMode::Floor, _) | (RoundingMode::Ceil, _) => false,
+            (RoundingMode::HalfEven, _) => match (remainder * 2).cmp(&unit) {
+                Ordering::Greater => true,
+                Ordering::Less => false,
+                Ordering::Equal => quotient % 2 == 1,
+            },
         };
-        let dec = self.dec.div(other.dec);
-        Ok(DecimalExt { sign, dec })
+        let quotient = if round_away_from_zero {
+            quotient + 1
+        } else {
+            quotient
+        };
+        let atomics = quotient
+            .checked_mul(unit)
+            .ok_or_else(|| self.overflow_err("round", decimal_places))?;
+        Ok(DecimalExt::new(
+            self.is_negative(),
+            cw::Decimal::raw(atomics),
+        ))
+    }
+}
+
+impl Ord for DecimalExt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.sign.cmp(&other.sign) {
+            Ordering::Equal => match self.sign {
+                Sign::Positive => self.dec.cmp(&other.dec),
+                Sign::Negative => other.dec.cmp(&self.dec),
+                Sign::Zero => Ordering::Equal,
+            },
+            ord => ord,
+        }
+    }
+}
+
+impl PartialOrd for DecimalExt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
 impl From<cw::Decimal> for DecimalExt {
     fn from(cw_dec: cw::Decimal) -> Self {
-        if cw_dec.is_zero() {
-            return DecimalExt::zero();
-        }
-        DecimalExt {
-            sign: Sign::Positive,
-            dec: cw_dec,
-        }
+        DecimalExt::new(false, cw_dec)
+    }
+}
+
+impl From<cw::SignedDecimal> for DecimalExt {
+    fn from(cw_dec: cw::SignedDecimal) -> Self {
+        let abs_value = cw_dec.abs_diff(cw::SignedDecimal::zero());
+        DecimalExt::new(cw_dec.is_negative(), abs_value)
+    }
+}
+
+impl TryFrom<cw::SignedDecimal256> for DecimalExt {
+    type Error = MathError;
+
+    fn try_from(cw_dec: cw::SignedDecimal256) -> Result<Self, Self::Error> {
+        let abs_value =
+            cw::Decimal::try_from(cw_dec.abs_diff(cw::SignedDecimal256::zero()))
+                .map_err(|_| MathError::OutOfRange {
+                    value: cw_dec.to_string(),
+                    target: "DecimalExt".to_string(),
+                })?;
+        Ok(DecimalExt::new(cw_dec.is_negative(), abs_value))
+    }
+}
+
+impl TryFrom<DecimalExt> for cw::SignedDecimal {
+    type Error = MathError;
+
+    fn try_from(dec: DecimalExt) -> Result<Self, Self::Error> {
+        let atomics: u128 = dec.dec.atomics().u128();
+        let signed_atomics: Option<i128> = if dec.is_negative() {
+            0i128.checked_sub_unsigned(atomics)
+        } else {
+            i128::try_from(atomics).ok()
+        };
+        signed_atomics.map(cw::SignedDecimal::raw).ok_or_else(|| {
+            MathError::OutOfRange {
+                value: dec.to_string(),
+                target: "SignedDecimal".to_string(),
+            }
+        })
+    }
+}
+
+impl From<DecimalExt> for cw::SignedDecimal256 {
+    fn from(dec: DecimalExt) -> Self {
+        let atomics = cw::Int256::from(dec.dec.atomics().u128());
+        let atomics = if dec.is_negative() { -atomics } else { atomics };
+        cw::SignedDecimal256::new(atomics)
+    }
+}
+
+impl Serialize for DecimalExt {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DecimalExt {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let dec_str = String::deserialize(deserializer)?;
+        DecimalExt::from_str(&dec_str).map_err(de::Error::custom)
+    }
+}
+
+impl JsonSchema for DecimalExt {
+    fn schema_name() -> String {
+        "DecimalExt".to_string()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        String::json_schema(gen)
     }
 }
 
@@ -219,6 +463,257 @@ impl fmt::Display for SdkDec {
     }
 }
 
+/// Maximum bit length of the absolute value of a `"cosmossdk.io/math".Int`.
+pub const SDK_INT_MAX_BIT_LEN: u32 = 256;
+
+/// SdkInt: Integer string representing the protobuf string for
+/// `"cosmossdk.io/math".Int`, which holds values with an absolute value below
+/// 2^256. See https://pkg.go.dev/cosmossdk.io/math@v1.2.0#Int.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use cosmwasm_std::{Int256, Uint128};
+/// use nibiru_std::math::SdkInt;
+///
+/// let sdk_int = SdkInt::from_str("-420").unwrap();
+/// assert_eq!(sdk_int.pb_repr(), "-420");
+/// assert_eq!(Int256::try_from(sdk_int).unwrap(), Int256::from(-420i128));
+/// assert!(Uint128::try_from(sdk_int).is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SdkInt {
+    value: cw::Int512,
+}
+
+impl SdkInt {
+    /// Validates that `value` fits in the 256-bit bound of the SDK Int.
+    pub fn new(value: cw::Int512) -> Result<Self, MathError> {
+        let max = cw::Int512::from(cw::Uint256::MAX);
+        if value > max || value < -max {
+            return Err(MathError::OutOfRange {
+                value: value.to_string(),
+                target: "SdkInt".to_string(),
+            });
+        }
+        Ok(Self { value })
+    }
+
+    /// Returns the protobuf representation.
+    pub fn pb_repr(&self) -> String {
+        self.value.to_string()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.value.is_negative()
+    }
+}
+
+impl FromStr for SdkInt {
+    type Err = MathError;
+
+    /// Parses an integer string such as "-69" or "0012". Signs other than a
+    /// leading "-", decimal points, and empty strings are rejected.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix('-').unwrap_or(s);
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(MathError::SdkIntError(format!(
+                "Invalid integer format: {s}"
+            )));
+        }
+        let value = cw::Int512::from_str(s)
+            .map_err(|err| MathError::SdkIntError(err.to_string()))?;
+        Self::new(value)
+    }
+}
+
+impl fmt::Display for SdkInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl From<cw::Uint128> for SdkInt {
+    fn from(value: cw::Uint128) -> Self {
+        Self {
+            value: value.into(),
+        }
+    }
+}
+
+impl From<cw::Uint256> for SdkInt {
+    fn from(value: cw::Uint256) -> Self {
+        Self {
+            value: value.into(),
+        }
+    }
+}
+
+impl From<cw::Int256> for SdkInt {
+    fn from(value: cw::Int256) -> Self {
+        Self {
+            value: value.into(),
+        }
+    }
+}
+
+impl TryFrom<cw::Int512> for SdkInt {
+    type Error = MathError;
+
+    fn try_from(value: cw::Int512) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<SdkInt> for cw::Int512 {
+    fn from(sdk_int: SdkInt) -> Self {
+        sdk_int.value
+    }
+}
+
+/// Implements `TryFrom<SdkInt>` for a narrower cosmwasm integer type.
+macro_rules! impl_try_from_sdk_int {
+    ($($target:ty => $name:literal),* $(,)?) => {$(
+        impl TryFrom<SdkInt> for $target {
+            type Error = MathError;
+
+            fn try_from(sdk_int: SdkInt) -> Result<Self, Self::Error> {
+                <$target>::try_from(sdk_int.value).map_err(|_| {
+                    MathError::OutOfRange {
+                        value: sdk_int.pb_repr(),
+                        target: $name.to_string(),
+                    }
+                })
+            }
+        }
+    )*};
+}
+
+impl_try_from_sdk_int!(
+    cw::Uint128 => "Uint128",
+    cw::Uint256 => "Uint256",
+    cw::Int256 => "Int256",
+);
+
+/// SdkDec256: 256-bit decimal representing the protobuf string for
+/// `"cosmossdk.io/math".LegacyDec`. Use it over [SdkDec] for values that
+/// overflow `cosmwasm_std::Decimal`. Values have at most 18 decimal places
+/// and fit in a `cosmwasm_std::SignedDecimal256`.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use nibiru_std::math::SdkDec256;
+///
+/// // Too large for cosmwasm_std::Decimal
+/// let dec = SdkDec256::from_str("-1000000000000000000000000.5").unwrap();
+/// assert_eq!(dec.pb_repr(), "-1000000000000000000000000500000000000000000");
+/// assert_eq!(SdkDec256::from_pb_repr(&dec.pb_repr()).unwrap(), dec);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SdkDec256 {
+    dec: cw::SignedDecimal256,
+}
+
+impl SdkDec256 {
+    pub fn new(dec: cw::SignedDecimal256) -> Self {
+        Self { dec }
+    }
+
+    /// Returns the protobuf representation: the decimal scaled by 10^18 as an
+    /// integer string.
+    pub fn pb_repr(&self) -> String {
+        self.dec.atomics().to_string()
+    }
+
+    /// Parses the protobuf representation of a `LegacyDec`.
+    pub fn from_pb_repr(pb_repr: &str) -> Result<Self, MathError> {
+        if pb_repr.is_empty() {
+            return Ok(Self::default());
+        }
+        let digits = pb_repr.strip_prefix('-').unwrap_or(pb_repr);
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(MathError::SdkDecError(format!(
+                "Invalid decimal format: {pb_repr}"
+            )));
+        }
+        let atomics = cw::Int256::from_str(pb_repr).map_err(|_| {
+            MathError::OutOfRange {
+                value: pb_repr.to_string(),
+                target: "SdkDec256".to_string(),
+            }
+        })?;
+        Ok(Self::new(cw::SignedDecimal256::new(atomics)))
+    }
+
+    /// Getter for the underlying `cosmwasm_std::SignedDecimal256`.
+    pub fn dec(&self) -> cw::SignedDecimal256 {
+        self.dec
+    }
+
+    pub fn from_cw_dec256(cw_dec: cw::Decimal256) -> Result<Self, MathError> {
+        cw::SignedDecimal256::try_from(cw_dec)
+            .map(Self::new)
+            .map_err(|_| MathError::OutOfRange {
+                value: cw_dec.to_string(),
+                target: "SdkDec256".to_string(),
+            })
+    }
+}
+
+impl FromStr for SdkDec256 {
+    type Err = MathError;
+
+    /// Converts a human readable decimal string, like "-420.69", to an
+    /// `SdkDec256`. Errors for more than 18 decimal places or values outside
+    /// of the 256-bit range.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        cw::SignedDecimal256::from_str(s)
+            .map(Self::new)
+            .map_err(|err| MathError::CwDecParseError {
+                dec_str: s.to_string(),
+                err,
+            })
+    }
+}
+
+impl fmt::Display for SdkDec256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.dec)
+    }
+}
+
+impl From<cw::SignedDecimal256> for SdkDec256 {
+    fn from(dec: cw::SignedDecimal256) -> Self {
+        Self::new(dec)
+    }
+}
+
+impl From<DecimalExt> for SdkDec256 {
+    fn from(dec: DecimalExt) -> Self {
+        Self::new(dec.into())
+    }
+}
+
+impl TryFrom<SdkDec256> for DecimalExt {
+    type Error = MathError;
+
+    fn try_from(sdk_dec: SdkDec256) -> Result<Self, Self::Error> {
+        DecimalExt::try_from(sdk_dec.dec)
+    }
+}
+
+impl TryFrom<SdkDec256> for cw::Decimal256 {
+    type Error = MathError;
+
+    fn try_from(sdk_dec: SdkDec256) -> Result<Self, Self::Error> {
+        cw::Decimal256::try_from(sdk_dec.dec).map_err(|_| {
+            MathError::OutOfRange {
+                value: sdk_dec.to_string(),
+                target: "Decimal256".to_string(),
+            }
+        })
+    }
+}
+
 impl DecimalExt {
     pub fn precision_digits() -> usize {
         18
@@ -346,8 +841,8 @@ mod test_sign_dec {
     use std::str::FromStr;
 
     use crate::{
-        errors::TestResult,
-        math::{DecimalExt, Sign},
+        errors::{MathError, TestResult},
+        math::{DecimalExt, RoundingMode, Sign},
     };
 
     #[test]
@@ -392,7 +887,6 @@ mod test_sign_dec {
         Ok(())
     }
 
-    // TODO: How will you handle overflow?
     #[test]
     fn add() -> TestResult {
         let test_cases: &[(&str, &str, &str)] = &[
@@ -515,4 +1009,286 @@ mod test_sign_dec {
         }
         Ok(())
     }
+
+    #[test]
+    fn ordering() -> TestResult {
+        let ascending: Vec<DecimalExt> =
+            ["-420", "-69.5", "-0.1", "0", "0.1", "69", "420"]
+                .iter()
+                .map(|s| DecimalExt::from_str(s))
+                .collect::<Result<_, _>>()?;
+        let mut shuffled = ascending.clone();
+        shuffled.reverse();
+        shuffled.swap(1, 4);
+        shuffled.sort();
+        assert_eq!(ascending, shuffled);
+
+        assert!(DecimalExt::from_str("-2")? < DecimalExt::from_str("-1")?);
+        assert!(DecimalExt::from_str("-1")? < DecimalExt::zero());
+        assert!(DecimalExt::zero() < DecimalExt::from_str("0.000001")?);
+        assert!(DecimalExt::min() < DecimalExt::max());
+        assert_eq!(DecimalExt::from_str("-0")?, DecimalExt::zero());
+        Ok(())
+    }
+
+    #[test]
+    fn checked_and_saturating() -> TestResult {
+        let max = DecimalExt::max();
+        let min = DecimalExt::min();
+        let one = DecimalExt::from_str("1")?;
+        let two = DecimalExt::from_str("2")?;
+
+        assert!(matches!(
+            max.checked_add(one),
+            Err(MathError::Overflow { .. })
+        ));
+        assert!(matches!(
+            min.checked_sub(one),
+            Err(MathError::Overflow { .. })
+        ));
+        assert!(matches!(
+            min.checked_mul(two),
+            Err(MathError::Overflow { .. })
+        ));
+        assert_eq!(
+            one.checked_quo(DecimalExt::zero()),
+            Err(MathError::DivisionByZero)
+        );
+        assert_eq!(max.checked_sub(one)?, max.sub(one));
+        assert_eq!(min.checked_add(max)?, DecimalExt::zero());
+
+        assert_eq!(max.saturating_add(one), max);
+        assert_eq!(min.saturating_sub(one), min);
+        assert_eq!(max.saturating_mul(two.neg()), min);
+        assert_eq!(min.saturating_mul(two.neg()), max);
+        assert_eq!(one.saturating_sub(two), one.neg());
+
+        // Products below the smallest unit are zero, not "negative zero".
+        let tiny = DecimalExt::from_str("-0.0000000001")?;
+        assert_eq!(tiny.checked_mul(tiny.neg())?.sign(), Sign::Zero);
+        Ok(())
+    }
+
+    #[test]
+    fn rounding() -> TestResult {
+        // (input, decimal places, floor, ceil, half even)
+        let test_cases: &[(&str, u32, &str, &str, &str)] = &[
+            ("2.5", 0, "2", "3", "2"),
+            ("3.5", 0, "3", "4", "4"),
+            ("-2.5", 0, "-3", "-2", "-2"),
+            ("-3.5", 0, "-4", "-3", "-4"),
+            ("2.51", 0, "2", "3", "3"),
+            ("-2.49", 0, "-3", "-2", "-2"),
+            ("7", 0, "7", "7", "7"),
+            ("0", 0, "0", "0", "0"),
+            ("-0.4", 0, "-1", "0", "0"),
+            ("1.23456", 2, "1.23", "1.24", "1.23"),
+            ("-1.235", 2, "-1.24", "-1.23", "-1.24"),
+            ("1.225", 2, "1.22", "1.23", "1.22"),
+            ("1.225", 18, "1.225", "1.225", "1.225"),
+        ];
+        for &(input, dp, floor, ceil, half_even) in test_cases {
+            let dec = DecimalExt::from_str(input)?;
+            for (mode, want) in [
+                (RoundingMode::Floor, floor),
+                (RoundingMode::Ceil, ceil),
+                (RoundingMode::HalfEven, half_even),
+            ] {
+                let got = dec.round_dp(dp, mode)?;
+                assert_eq!(
+                    got,
+                    DecimalExt::from_str(want)?,
+                    "input: {input}, dp: {dp}, mode: {mode:?}"
+                );
+            }
+        }
+
+        assert!(matches!(
+            DecimalExt::max().round(RoundingMode::Ceil),
+            Err(MathError::Overflow { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn signed_decimal_conversions() -> TestResult {
+        for dec_str in ["-420.69", "0", "1.5", "0.000000000000000001"] {
+            let dec = DecimalExt::from_str(dec_str)?;
+            let cw_dec = cw::SignedDecimal::try_from(dec)?;
+            assert_eq!(cw_dec, cw::SignedDecimal::from_str(dec_str)?);
+            assert_eq!(DecimalExt::from(cw_dec), dec);
+
+            let cw_dec256 = cw::SignedDecimal256::from(dec);
+            assert_eq!(cw_dec256, cw::SignedDecimal256::from_str(dec_str)?);
+            assert_eq!(DecimalExt::try_from(cw_dec256)?, dec);
+        }
+
+        // Magnitudes above i128::MAX atomics don't fit in SignedDecimal.
+        assert!(matches!(
+            cw::SignedDecimal::try_from(DecimalExt::max()),
+            Err(MathError::OutOfRange { .. })
+        ));
+        let min_dec = DecimalExt::from(cw::SignedDecimal::MIN);
+        assert_eq!(
+            cw::SignedDecimal::try_from(min_dec)?,
+            cw::SignedDecimal::MIN
+        );
+
+        // SignedDecimal256 holds values beyond the range of DecimalExt.
+        assert!(matches!(
+            DecimalExt::try_from(cw::SignedDecimal256::MIN),
+            Err(MathError::OutOfRange { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn serde_json() -> TestResult {
+        #[cosmwasm_schema::cw_serde]
+        struct Msg {
+            price: DecimalExt,
+        }
+
+        let msg = Msg {
+            price: DecimalExt::from_str("-420.69")?,
+        };
+        let json = cw::to_json_string(&msg)?;
+        assert_eq!(json, r#"{"price":"-420.69"}"#);
+        assert_eq!(cw::from_json::<Msg>(&json)?, msg);
+
+        assert!(cw::from_json::<Msg>(r#"{"price":"-"}"#).is_err());
+        assert!(cw::from_json::<Msg>(r#"{"price":-1}"#).is_err());
+
+        let schema = cosmwasm_schema::schema_for!(Msg);
+        let schema_json = serde_json::to_string(&schema)?;
+        assert!(
+            schema_json.contains(r#""DecimalExt":{"type":"string"}"#),
+            "{schema_json}"
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_sdk_int {
+    use cosmwasm_std as cw;
+    use std::str::FromStr;
+
+    use crate::{
+        errors::{MathError, TestResult},
+        math::{DecimalExt, SdkDec, SdkDec256, SdkInt},
+    };
+
+    #[test]
+    fn sdk_int_pb_repr() -> TestResult {
+        let max = cw::Uint256::MAX.to_string();
+        let test_cases: &[(&str, &str)] = &[
+            ("0", "0"),
+            ("-0", "0"),
+            ("0012", "12"),
+            ("-420", "-420"),
+            (&max, &max),
+        ];
+        for &(arg, want) in test_cases {
+            let got = SdkInt::from_str(arg)?;
+            assert_eq!(got.pb_repr(), want, "arg: {arg}");
+            assert_eq!(SdkInt::from_str(&got.pb_repr())?, got);
+        }
+
+        let too_big =
+            (cw::Int512::from(cw::Uint256::MAX) + cw::Int512::one()).to_string();
+        for bad in ["", "-", "+1", "1.5", "1e6", " 1", &too_big] {
+            assert!(SdkInt::from_str(bad).is_err(), "input: {bad:?}");
+        }
+        assert!(matches!(
+            SdkInt::from_str(&format!("-{too_big}")),
+            Err(MathError::OutOfRange { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn sdk_int_conversions() -> TestResult {
+        let small = SdkInt::from(cw::Uint128::new(123));
+        assert_eq!(cw::Uint128::try_from(small)?, cw::Uint128::new(123));
+        assert_eq!(cw::Uint256::try_from(small)?, cw::Uint256::from(123u128));
+        assert_eq!(cw::Int256::try_from(small)?, cw::Int256::from(123i128));
+        assert_eq!(cw::Int512::from(small), cw::Int512::from(123i128));
+
+        let big = SdkInt::from(cw::Uint256::MAX);
+        assert_eq!(cw::Uint256::try_from(big)?, cw::Uint256::MAX);
+        assert!(matches!(
+            cw::Uint128::try_from(big),
+            Err(MathError::OutOfRange { .. })
+        ));
+        assert!(cw::Int256::try_from(big).is_err());
+
+        let neg = SdkInt::from(cw::Int256::MIN);
+        assert!(neg.is_negative());
+        assert_eq!(cw::Int256::try_from(neg)?, cw::Int256::MIN);
+        assert!(cw::Uint256::try_from(neg).is_err());
+
+        assert!(SdkInt::try_from(cw::Int512::MAX).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn sdk_dec256_pb_repr() -> TestResult {
+        let test_cases: &[(&str, &str)] = &[
+            ("0", "0"),
+            ("1.5", "1500000000000000000"),
+            ("-123.456", "-123456000000000000000"),
+            ("0.000000000000000001", "1"),
+            (
+                "340282366920938463463.374607431768211456",
+                "340282366920938463463374607431768211456",
+            ),
+        ];
+        for &(dec_str, want_pb) in test_cases {
+            let dec = SdkDec256::from_str(dec_str)?;
+            assert_eq!(dec.pb_repr(), want_pb, "dec: {dec_str}");
+            assert_eq!(SdkDec256::from_pb_repr(want_pb)?, dec);
+            assert_eq!(dec.to_string(), dec_str);
+        }
+
+        // The SdkDec format, including its zero-padded forms, parses to the
+        // same value.
+        for dec_str in ["0", "0.5", "-13.5", "1574.00005"] {
+            let sdk_dec = SdkDec::from_str(dec_str)?;
+            assert_eq!(
+                SdkDec256::from_pb_repr(&sdk_dec.pb_repr())?,
+                SdkDec256::from_str(dec_str)?
+            );
+        }
+
+        assert!(SdkDec256::from_str("0.0000000000000000001").is_err());
+        for bad in ["1.5", "--1", "abc", "-"] {
+            assert!(SdkDec256::from_pb_repr(bad).is_err(), "input: {bad}");
+        }
+        let too_big = format!("{}0", cw::Int256::MAX);
+        assert!(matches!(
+            SdkDec256::from_pb_repr(&too_big),
+            Err(MathError::OutOfRange { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn sdk_dec256_conversions() -> TestResult {
+        let dec = DecimalExt::from_str("-420.69")?;
+        let sdk_dec = SdkDec256::from(dec);
+        assert_eq!(DecimalExt::try_from(sdk_dec)?, dec);
+        assert!(cw::Decimal256::try_from(sdk_dec).is_err());
+        assert_eq!(
+            cw::Decimal256::try_from(SdkDec256::from(dec.neg()))?,
+            cw::Decimal256::from_str("420.69")?
+        );
+
+        let huge = SdkDec256::from_cw_dec256(cw::Decimal256::from_str(
+            "1000000000000000000000000",
+        )?)?;
+        assert!(DecimalExt::try_from(huge).is_err());
+        assert!(SdkDec256::from_cw_dec256(cw::Decimal256::MAX).is_err());
+        Ok(())
+    }
 }
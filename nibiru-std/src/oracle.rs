@@ -0,0 +1,496 @@
+//! oracle.rs: Reads `nibiru.oracle.v1` exchange rates with the checks a
+//! contract needs before it trusts them.
+//!
+//! - [OraclePrice]: A price with the block it was set at.
+//! - [PriceChecks]: Maximum age in blocks or seconds and maximum deviation of
+//!   the spot price from the oracle TWAP.
+//! - [OracleReader]: Queries prices and applies the [PriceChecks], including
+//!   synthetic pairs priced through a shared quote asset.
+//!
+//! Failed checks come back as an [OracleError](crate::errors::OracleError).
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{BlockInfo, CustomQuery, Decimal, QuerierWrapper, Timestamp};
+
+use crate::{
+    errors::OracleError,
+    proto::{
+        nibiru::oracle::{QueryExchangeRateRequest, QueryExchangeRateResponse},
+        query_grpc_proto, query_proto_at_path,
+    },
+    querier::price_from_sdk_dec,
+};
+
+/// gRPC path of the oracle TWAP query. It shares `QueryExchangeRateRequest`
+/// with the spot price query, so it has no [crate::proto::NibiruGrpcQuery]
+/// impl.
+pub const EXCHANGE_RATE_TWAP_PATH: &str =
+    "/nibiru.oracle.v1.Query/ExchangeRateTwap";
+
+/// OraclePrice: Exchange rate of an oracle pair and the block at which the
+/// oracle came to consensus on it.
+#[cw_serde]
+pub struct OraclePrice {
+    /// Ex.: "ubtc:unusd"
+    pub pair: String,
+    pub price: Decimal,
+    pub block_height: u64,
+    pub block_time: Timestamp,
+}
+
+impl OraclePrice {
+    /// Number of blocks since the price was set.
+    pub fn age_blocks(&self, block: &BlockInfo) -> u64 {
+        block.height.saturating_sub(self.block_height)
+    }
+
+    /// Number of seconds since the price was set.
+    pub fn age_secs(&self, block: &BlockInfo) -> u64 {
+        block
+            .time
+            .seconds()
+            .saturating_sub(self.block_time.seconds())
+    }
+
+    fn from_response(
+        pair: &str,
+        resp: &QueryExchangeRateResponse,
+    ) -> Result<Self, OracleError> {
+        let invalid = |value: String| OracleError::InvalidPrice {
+            pair: pair.to_string(),
+            value,
+        };
+        let price = price_from_sdk_dec(&resp.exchange_rate)
+            .map_err(|_| invalid(resp.exchange_rate.clone()))?;
+        if price.is_zero() {
+            return Err(invalid(resp.exchange_rate.clone()));
+        }
+        let block_time_ms = u64::try_from(resp.block_timestamp_ms)
+            .map_err(|_| invalid(format!("{}ms", resp.block_timestamp_ms)))?;
+        Ok(OraclePrice {
+            pair: pair.to_string(),
+            price,
+            block_height: resp.block_height,
+            block_time: Timestamp::from_nanos(
+                block_time_ms.saturating_mul(1_000_000),
+            ),
+        })
+    }
+}
+
+/// PriceChecks: Conditions an [OraclePrice] must meet. Each check is skipped
+/// when its field is `None`. Contracts can keep it in their config.
+///
+/// ```
+/// use cosmwasm_std::Decimal;
+/// use nibiru_std::oracle::PriceChecks;
+///
+/// let checks = PriceChecks {
+///     max_age_blocks: Some(100),
+///     max_age_secs: Some(600),
+///     max_twap_deviation: Some(Decimal::percent(5)),
+/// };
+/// ```
+#[cw_serde]
+#[derive(Default)]
+pub struct PriceChecks {
+    /// Maximum number of blocks since the price was set.
+    pub max_age_blocks: Option<u64>,
+    /// Maximum number of seconds since the price was set.
+    pub max_age_secs: Option<u64>,
+    /// Maximum relative difference between the spot price and the oracle
+    /// TWAP, Ex.: 0.05 for 5%.
+    pub max_twap_deviation: Option<Decimal>,
+}
+
+impl PriceChecks {
+    /// Errors if `price` is older than the configured maximum age at `block`.
+    pub fn check_age(
+        &self,
+        price: &OraclePrice,
+        block: &BlockInfo,
+    ) -> Result<(), OracleError> {
+        if let Some(max_age_blocks) = self.max_age_blocks {
+            let age_blocks = price.age_blocks(block);
+            if age_blocks > max_age_blocks {
+                return Err(OracleError::StaleBlocks {
+                    pair: price.pair.clone(),
+                    age_blocks,
+                    max_age_blocks,
+                });
+            }
+        }
+        if let Some(max_age_secs) = self.max_age_secs {
+            let age_secs = price.age_secs(block);
+            if age_secs > max_age_secs {
+                return Err(OracleError::StaleSeconds {
+                    pair: price.pair.clone(),
+                    age_secs,
+                    max_age_secs,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Errors if `spot` differs from `twap` by more than the configured
+    /// fraction of `twap`.
+    pub fn check_deviation(
+        &self,
+        pair: &str,
+        spot: Decimal,
+        twap: Decimal,
+    ) -> Result<(), OracleError> {
+        let Some(max_deviation) = self.max_twap_deviation else {
+            return Ok(());
+        };
+        let deviation = spot.abs_diff(twap).checked_div(twap).map_err(|_| {
+            OracleError::InvalidPrice {
+                pair: pair.to_string(),
+                value: format!("TWAP {twap}"),
+            }
+        })?;
+        if deviation > max_deviation {
+            return Err(OracleError::TwapDeviation {
+                pair: pair.to_string(),
+                spot,
+                twap,
+                max_deviation,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// OracleReader: Reads oracle prices and applies [PriceChecks] to them.
+///
+/// ```
+/// use cosmwasm_std::{testing::mock_env, Decimal, QuerierWrapper};
+/// use nibiru_std::{
+///     oracle::{OracleReader, PriceChecks},
+///     proto::nibiru::oracle::{
+///         QueryExchangeRateRequest, QueryExchangeRateResponse,
+///     },
+///     tutil::StargateMockQuerier,
+/// };
+///
+/// let env = mock_env();
+/// let querier: StargateMockQuerier = StargateMockQuerier::new(&[])
+///     .with_handler(move |_req: QueryExchangeRateRequest| {
+///         Ok(QueryExchangeRateResponse {
+///             // LegacyDec protobuf repr of 2.5
+///             exchange_rate: "2500000000000000000".to_string(),
+///             block_height: env.block.height - 10,
+///             block_timestamp_ms: 1_571_797_000_000,
+///         })
+///     });
+/// let wrapper = QuerierWrapper::<cosmwasm_std::Empty>::new(&querier);
+///
+/// let checks = PriceChecks {
+///     max_age_blocks: Some(20),
+///     ..Default::default()
+/// };
+/// let reader = OracleReader::new(&wrapper, checks);
+/// let price = reader.price(&mock_env().block, "ubtc:unusd").unwrap();
+/// assert_eq!(price.price, Decimal::percent(250));
+/// ```
+pub struct OracleReader<'a, C: CustomQuery> {
+    querier: QuerierWrapper<'a, C>,
+    pub checks: PriceChecks,
+}
+
+impl<'a, C: CustomQuery> OracleReader<'a, C> {
+    pub fn new(querier: &QuerierWrapper<'a, C>, checks: PriceChecks) -> Self {
+        OracleReader {
+            querier: *querier,
+            checks,
+        }
+    }
+
+    /// Spot price of `pair` without any checks.
+    pub fn spot_price(&self, pair: &str) -> Result<OraclePrice, OracleError> {
+        let req = QueryExchangeRateRequest {
+            pair: pair.to_string(),
+        };
+        let resp = query_grpc_proto(&self.querier, &req)
+            .map_err(|err| query_failed(pair, err))?;
+        OraclePrice::from_response(pair, &resp)
+    }
+
+    /// Time-weighted average price of `pair` over the oracle's
+    /// `twap_lookback_window`.
+    pub fn twap(&self, pair: &str) -> Result<Decimal, OracleError> {
+        let req = QueryExchangeRateRequest {
+            pair: pair.to_string(),
+        };
+        let resp: QueryExchangeRateResponse =
+            query_proto_at_path(&self.querier, EXCHANGE_RATE_TWAP_PATH, &req)
+                .map_err(|err| query_failed(pair, err))?;
+        let invalid = || OracleError::InvalidPrice {
+            pair: pair.to_string(),
+            value: format!("TWAP {}", resp.exchange_rate),
+        };
+        let twap =
+            price_from_sdk_dec(&resp.exchange_rate).map_err(|_| invalid())?;
+        if twap.is_zero() {
+            return Err(invalid());
+        }
+        Ok(twap)
+    }
+
+    /// Price of `pair` that passed the age checks at `block` and, if
+    /// configured, the TWAP deviation check.
+    pub fn price(
+        &self,
+        block: &BlockInfo,
+        pair: &str,
+    ) -> Result<OraclePrice, OracleError> {
+        let price = self.spot_price(pair)?;
+        self.checks.check_age(&price, block)?;
+        if self.checks.max_twap_deviation.is_some() {
+            let twap = self.twap(pair)?;
+            self.checks.check_deviation(pair, price.price, twap)?;
+        }
+        Ok(price)
+    }
+
+    /// Price of a pair without its own oracle feed, computed from two checked
+    /// prices with the same quote asset. For example, "ubtc:unusd" and
+    /// "ueth:unusd" give the price of "ubtc:ueth". The result carries the
+    /// block of the older of the two prices.
+    pub fn synthetic_price(
+        &self,
+        block: &BlockInfo,
+        base_pair: &str,
+        quote_pair: &str,
+    ) -> Result<OraclePrice, OracleError> {
+        let (base, base_quote) = split_pair(base_pair)?;
+        let (quote, quote_quote) = split_pair(quote_pair)?;
+        if base_quote != quote_quote {
+            return Err(OracleError::QuoteMismatch {
+                base_pair: base_pair.to_string(),
+                quote_pair: quote_pair.to_string(),
+            });
+        }
+
+        let base_price = self.price(block, base_pair)?;
+        let quote_price = self.price(block, quote_pair)?;
+        let pair = format!("{base}:{quote}");
+        let price =
+            base_price
+                .price
+                .checked_div(quote_price.price)
+                .map_err(|_| OracleError::InvalidPrice {
+                    pair: pair.clone(),
+                    value: format!(
+                        "{} / {}",
+                        base_price.price, quote_price.price
+                    ),
+                })?;
+        Ok(OraclePrice {
+            pair,
+            price,
+            block_height: base_price.block_height.min(quote_price.block_height),
+            block_time: base_price.block_time.min(quote_price.block_time),
+        })
+    }
+}
+
+/// Splits an oracle pair, Ex.: "ubtc:unusd", into its base and quote assets.
+fn split_pair(pair: &str) -> Result<(&str, &str), OracleError> {
+    match pair.split_once(':') {
+        Some((base, quote)) if !base.is_empty() && !quote.is_empty() => {
+            Ok((base, quote))
+        }
+        _ => Err(OracleError::InvalidPair(pair.to_string())),
+    }
+}
+
+fn query_failed(pair: &str, err: impl ToString) -> OracleError {
+    OracleError::QueryFailed {
+        pair: pair.to_string(),
+        reason: err.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use cosmwasm_std::{testing::mock_env, Empty};
+
+    use super::*;
+    use crate::{
+        errors::TestResult,
+        tutil::{stargate_query_err, StargateMockQuerier},
+    };
+
+    /// LegacyDec protobuf repr of a whole number
+    fn sdk_dec(whole: u64) -> String {
+        format!("{whole}000000000000000000")
+    }
+
+    /// Mock oracle with (spot, TWAP, block height) for each pair. Prices are
+    /// set at the height's block time in `mock_env`, 5 seconds per block.
+    fn mock_oracle(
+        prices: &[(&str, u64, u64, u64)],
+    ) -> StargateMockQuerier<Empty> {
+        let block = mock_env().block;
+        let to_resp = move |rate: u64, height: u64| {
+            let blocks_ago = block.height - height;
+            let time = block.time.minus_seconds(blocks_ago * 5);
+            QueryExchangeRateResponse {
+                exchange_rate: sdk_dec(rate),
+                block_timestamp_ms: (time.nanos() / 1_000_000) as i64,
+                block_height: height,
+            }
+        };
+        let spot: BTreeMap<String, QueryExchangeRateResponse> = prices
+            .iter()
+            .map(|(pair, spot, _, height)| {
+                (pair.to_string(), to_resp(*spot, *height))
+            })
+            .collect();
+        let twap: BTreeMap<String, QueryExchangeRateResponse> = prices
+            .iter()
+            .map(|(pair, _, twap, height)| {
+                (pair.to_string(), to_resp(*twap, *height))
+            })
+            .collect();
+        StargateMockQuerier::new(&[])
+            .with_handler(move |req: QueryExchangeRateRequest| {
+                match spot.get(&req.pair) {
+                    Some(resp) => Ok(resp.clone()),
+                    None => stargate_query_err("pair not found"),
+                }
+            })
+            .with_path_handler(
+                EXCHANGE_RATE_TWAP_PATH,
+                move |req: QueryExchangeRateRequest| match twap.get(&req.pair) {
+                    Some(resp) => Ok(resp.clone()),
+                    None => stargate_query_err("pair not found"),
+                },
+            )
+    }
+
+    #[test]
+    fn price_checks() -> TestResult {
+        let block = mock_env().block;
+        let querier = mock_oracle(&[
+            ("ubtc:unusd", 60_000, 59_000, block.height - 2),
+            ("ueth:unusd", 3_000, 2_000, block.height - 50),
+        ]);
+        let wrapper = QuerierWrapper::<Empty>::new(&querier);
+
+        let no_checks = OracleReader::new(&wrapper, PriceChecks::default());
+        let eth = no_checks.price(&block, "ueth:unusd")?;
+        assert_eq!(eth.price, Decimal::from_ratio(3_000u64, 1u64));
+        assert_eq!(eth.age_blocks(&block), 50);
+        assert_eq!(eth.age_secs(&block), 250);
+
+        let test_cases: Vec<(PriceChecks, &str, Option<OracleError>)> = vec![
+            (
+                PriceChecks {
+                    max_age_blocks: Some(10),
+                    ..Default::default()
+                },
+                "ueth:unusd",
+                Some(OracleError::StaleBlocks {
+                    pair: "ueth:unusd".to_string(),
+                    age_blocks: 50,
+                    max_age_blocks: 10,
+                }),
+            ),
+            (
+                PriceChecks {
+                    max_age_secs: Some(60),
+                    ..Default::default()
+                },
+                "ueth:unusd",
+                Some(OracleError::StaleSeconds {
+                    pair: "ueth:unusd".to_string(),
+                    age_secs: 250,
+                    max_age_secs: 60,
+                }),
+            ),
+            (
+                PriceChecks {
+                    max_age_blocks: Some(10),
+                    max_age_secs: Some(60),
+                    max_twap_deviation: Some(Decimal::percent(5)),
+                },
+                "ubtc:unusd",
+                None,
+            ),
+            (
+                PriceChecks {
+                    max_twap_deviation: Some(Decimal::percent(5)),
+                    ..Default::default()
+                },
+                "ueth:unusd",
+                Some(OracleError::TwapDeviation {
+                    pair: "ueth:unusd".to_string(),
+                    spot: Decimal::from_ratio(3_000u64, 1u64),
+                    twap: Decimal::from_ratio(2_000u64, 1u64),
+                    max_deviation: Decimal::percent(5),
+                }),
+            ),
+        ];
+        for (checks, pair, want_err) in test_cases {
+            let reader = OracleReader::new(&wrapper, checks.clone());
+            let result = reader.price(&block, pair);
+            match want_err {
+                Some(err) => assert_eq!(result, Err(err), "{checks:?}"),
+                None => assert!(result.is_ok(), "{checks:?}: {result:?}"),
+            }
+        }
+
+        let err = no_checks.price(&block, "uatom:unusd").unwrap_err();
+        assert!(matches!(err, OracleError::QueryFailed { .. }), "{err}");
+        Ok(())
+    }
+
+    #[test]
+    fn synthetic_prices() -> TestResult {
+        let block = mock_env().block;
+        let querier = mock_oracle(&[
+            ("ubtc:unusd", 60_000, 60_000, block.height - 2),
+            ("ueth:unusd", 3_000, 3_000, block.height - 8),
+            ("ueth:uusdc", 3_000, 3_000, block.height),
+        ]);
+        let wrapper = QuerierWrapper::<Empty>::new(&querier);
+        let reader = OracleReader::new(&wrapper, PriceChecks::default());
+
+        let btc_eth =
+            reader.synthetic_price(&block, "ubtc:unusd", "ueth:unusd")?;
+        assert_eq!(btc_eth.pair, "ubtc:ueth");
+        assert_eq!(btc_eth.price, Decimal::from_ratio(20u64, 1u64));
+        assert_eq!(btc_eth.block_height, block.height - 8);
+
+        // Each leg gets the same checks as a direct price.
+        let strict = OracleReader::new(
+            &wrapper,
+            PriceChecks {
+                max_age_blocks: Some(5),
+                ..Default::default()
+            },
+        );
+        let err = strict
+            .synthetic_price(&block, "ubtc:unusd", "ueth:unusd")
+            .unwrap_err();
+        assert!(matches!(err, OracleError::StaleBlocks { .. }), "{err}");
+
+        assert_eq!(
+            reader.synthetic_price(&block, "ubtc:unusd", "ueth:uusdc"),
+            Err(OracleError::QuoteMismatch {
+                base_pair: "ubtc:unusd".to_string(),
+                quote_pair: "ueth:uusdc".to_string(),
+            })
+        );
+        assert_eq!(
+            reader.synthetic_price(&block, "ubtc", "ueth:unusd"),
+            Err(OracleError::InvalidPair("ubtc".to_string()))
+        );
+        Ok(())
+    }
+}
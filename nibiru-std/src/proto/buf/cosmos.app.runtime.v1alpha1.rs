@@ -2,6 +2,7 @@
 /// Module is the config object for the runtime module.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Module {
     /// app_name is the name of the app.
     #[prost(string, tag="1")]
@@ -35,6 +36,7 @@ pub struct Module {
 /// is the module name.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct StoreKeyConfig {
     /// name of the module to override the store key of
     #[prost(string, tag="1")]
@@ -2,6 +2,7 @@
 /// ModuleDescriptor describes an app module.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ModuleDescriptor {
     /// go_import names the package that should be imported by an app to load the
     /// module in the runtime module registry. It is required to make debugging
@@ -27,6 +28,7 @@ pub struct ModuleDescriptor {
 /// PackageReference is a reference to a protobuf package used by a module.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct PackageReference {
     /// name is the fully-qualified name of the package.
     #[prost(string, tag="1")]
@@ -73,6 +75,7 @@ pub struct PackageReference {
 /// can migrate from.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MigrateFromInfo {
     /// module is the fully-qualified protobuf name of the module config object
     /// for the previous module version, ex: "cosmos.group.module.v1.Module".
@@ -88,6 +91,7 @@ pub struct MigrateFromInfo {
 /// their state machine with a config object alone.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Config {
     /// modules are the module configurations for the app.
     #[prost(message, repeated, tag="1")]
@@ -101,6 +105,7 @@ pub struct Config {
 /// ModuleConfig is a module configuration for an app.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ModuleConfig {
     /// name is the unique name of the module within the app. It should be a name
     /// that persists between different versions of a module so that modules
@@ -117,6 +122,7 @@ pub struct ModuleConfig {
     /// config is the config object for the module. Module config messages should
     /// define a ModuleDescriptor using the cosmos.app.v1alpha1.is_module extension.
     #[prost(message, optional, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub config: ::core::option::Option<::prost_types::Any>,
     /// golang_bindings specifies explicit interface to implementation type bindings which
     /// depinject uses to resolve interface inputs to provider functions.  The scope of this
@@ -127,6 +133,7 @@ pub struct ModuleConfig {
 /// GolangBinding is an explicit interface type to implementing type binding for dependency injection.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GolangBinding {
     /// interface_type is the interface type which will be bound to a specific implementation type
     #[prost(string, tag="1")]
@@ -138,11 +145,13 @@ pub struct GolangBinding {
 /// QueryConfigRequest is the Query/Config request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryConfigRequest {
 }
 /// QueryConfigRequest is the Query/Config response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryConfigResponse {
     /// config is the current app config.
     #[prost(message, optional, tag="1")]
@@ -2,6 +2,7 @@
 /// Module is the config object for the auth module.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Module {
     /// bech32_prefix is the bech32 account prefix for the app.
     #[prost(string, tag="1")]
@@ -16,6 +17,7 @@ pub struct Module {
 /// ModuleAccountPermission represents permissions for a module account.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ModuleAccountPermission {
     /// account is the name of the module.
     #[prost(string, tag="1")]
@@ -4,19 +4,24 @@
 /// type for additional functionality (e.g. vesting).
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct BaseAccount {
     #[prost(string, tag="1")]
     pub address: ::prost::alloc::string::String,
     #[prost(message, optional, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub pub_key: ::core::option::Option<::prost_types::Any>,
     #[prost(uint64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub account_number: u64,
     #[prost(uint64, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub sequence: u64,
 }
 /// ModuleAccount defines an account for modules that holds coins on a pool.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ModuleAccount {
     #[prost(message, optional, tag="1")]
     pub base_account: ::core::option::Option<BaseAccount>,
@@ -30,6 +35,7 @@ pub struct ModuleAccount {
 /// Since: cosmos-sdk 0.47
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ModuleCredential {
     /// module_name is the name of the module used for address derivation (passed into address.Module).
     #[prost(string, tag="1")]
@@ -37,32 +43,41 @@ pub struct ModuleCredential {
     /// derivation_keys is for deriving a module account address (passed into address.Module)
     /// adding more keys creates sub-account addresses (passed into address.Derive)
     #[prost(bytes="bytes", repeated, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<String>"))]
     pub derivation_keys: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
 }
 /// Params defines the parameters for the auth module.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Params {
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub max_memo_characters: u64,
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub tx_sig_limit: u64,
     #[prost(uint64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub tx_size_cost_per_byte: u64,
     #[prost(uint64, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub sig_verify_cost_ed25519: u64,
     #[prost(uint64, tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub sig_verify_cost_secp256k1: u64,
 }
 /// GenesisState defines the auth module's genesis state.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GenesisState {
     /// params defines all the parameters of the module.
     #[prost(message, optional, tag="1")]
     pub params: ::core::option::Option<Params>,
     /// accounts are the accounts present at genesis.
     #[prost(message, repeated, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<::serde_json::Value>"))]
     pub accounts: ::prost::alloc::vec::Vec<::prost_types::Any>,
 }
 /// QueryAccountsRequest is the request type for the Query/Accounts RPC method.
@@ -70,6 +85,7 @@ pub struct GenesisState {
 /// Since: cosmos-sdk 0.43
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryAccountsRequest {
     /// pagination defines an optional pagination for the request.
     #[prost(message, optional, tag="1")]
@@ -80,9 +96,11 @@ pub struct QueryAccountsRequest {
 /// Since: cosmos-sdk 0.43
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryAccountsResponse {
     /// accounts are the existing accounts
     #[prost(message, repeated, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<::serde_json::Value>"))]
     pub accounts: ::prost::alloc::vec::Vec<::prost_types::Any>,
     /// pagination defines the pagination in the response.
     #[prost(message, optional, tag="2")]
@@ -91,6 +109,7 @@ pub struct QueryAccountsResponse {
 /// QueryAccountRequest is the request type for the Query/Account RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryAccountRequest {
     /// address defines the address to query for.
     #[prost(string, tag="1")]
@@ -99,19 +118,23 @@ pub struct QueryAccountRequest {
 /// QueryAccountResponse is the response type for the Query/Account RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryAccountResponse {
     /// account defines the account of the corresponding address.
     #[prost(message, optional, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub account: ::core::option::Option<::prost_types::Any>,
 }
 /// QueryParamsRequest is the request type for the Query/Params RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryParamsRequest {
 }
 /// QueryParamsResponse is the response type for the Query/Params RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryParamsResponse {
     /// params defines the parameters of the module.
     #[prost(message, optional, tag="1")]
@@ -122,6 +145,7 @@ pub struct QueryParamsResponse {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryModuleAccountsRequest {
 }
 /// QueryModuleAccountsResponse is the response type for the Query/ModuleAccounts RPC method.
@@ -129,13 +153,16 @@ pub struct QueryModuleAccountsRequest {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryModuleAccountsResponse {
     #[prost(message, repeated, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<::serde_json::Value>"))]
     pub accounts: ::prost::alloc::vec::Vec<::prost_types::Any>,
 }
 /// QueryModuleAccountByNameRequest is the request type for the Query/ModuleAccountByName RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryModuleAccountByNameRequest {
     #[prost(string, tag="1")]
     pub name: ::prost::alloc::string::String,
@@ -143,8 +170,10 @@ pub struct QueryModuleAccountByNameRequest {
 /// QueryModuleAccountByNameResponse is the response type for the Query/ModuleAccountByName RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryModuleAccountByNameResponse {
     #[prost(message, optional, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub account: ::core::option::Option<::prost_types::Any>,
 }
 /// Bech32PrefixRequest is the request type for Bech32Prefix rpc method.
@@ -152,6 +181,7 @@ pub struct QueryModuleAccountByNameResponse {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Bech32PrefixRequest {
 }
 /// Bech32PrefixResponse is the response type for Bech32Prefix rpc method.
@@ -159,6 +189,7 @@ pub struct Bech32PrefixRequest {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Bech32PrefixResponse {
     #[prost(string, tag="1")]
     pub bech32_prefix: ::prost::alloc::string::String,
@@ -168,8 +199,10 @@ pub struct Bech32PrefixResponse {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct AddressBytesToStringRequest {
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub address_bytes: ::prost::bytes::Bytes,
 }
 /// AddressBytesToStringResponse is the response type for AddressString rpc method.
@@ -177,6 +210,7 @@ pub struct AddressBytesToStringRequest {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct AddressBytesToStringResponse {
     #[prost(string, tag="1")]
     pub address_string: ::prost::alloc::string::String,
@@ -186,6 +220,7 @@ pub struct AddressBytesToStringResponse {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct AddressStringToBytesRequest {
     #[prost(string, tag="1")]
     pub address_string: ::prost::alloc::string::String,
@@ -195,8 +230,10 @@ pub struct AddressStringToBytesRequest {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct AddressStringToBytesResponse {
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub address_bytes: ::prost::bytes::Bytes,
 }
 /// QueryAccountAddressByIDRequest is the request type for AccountAddressByID rpc method
@@ -204,6 +241,7 @@ pub struct AddressStringToBytesResponse {
 /// Since: cosmos-sdk 0.46.2
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryAccountAddressByIdRequest {
     /// Deprecated, use account_id instead
     ///
@@ -212,11 +250,13 @@ pub struct QueryAccountAddressByIdRequest {
     /// updated to uint64 in a future version of the auth query.
     #[deprecated]
     #[prost(int64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub id: i64,
     /// account_id is the account number of the address to be queried.
     ///
     /// Since: cosmos-sdk 0.47
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub account_id: u64,
 }
 /// QueryAccountAddressByIDResponse is the response type for AccountAddressByID rpc method
@@ -224,6 +264,7 @@ pub struct QueryAccountAddressByIdRequest {
 /// Since: cosmos-sdk 0.46.2
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryAccountAddressByIdResponse {
     #[prost(string, tag="1")]
     pub account_address: ::prost::alloc::string::String,
@@ -233,6 +274,7 @@ pub struct QueryAccountAddressByIdResponse {
 /// Since: cosmos-sdk 0.47
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryAccountInfoRequest {
     /// address is the account address string.
     #[prost(string, tag="1")]
@@ -243,6 +285,7 @@ pub struct QueryAccountInfoRequest {
 /// Since: cosmos-sdk 0.47
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryAccountInfoResponse {
     /// info is the account info which is represented by BaseAccount.
     #[prost(message, optional, tag="1")]
@@ -253,6 +296,7 @@ pub struct QueryAccountInfoResponse {
 /// Since: cosmos-sdk 0.47
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateParams {
     /// authority is the address that controls the module (defaults to x/gov unless overwritten).
     #[prost(string, tag="1")]
@@ -269,6 +313,7 @@ pub struct MsgUpdateParams {
 /// Since: cosmos-sdk 0.47
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateParamsResponse {
 }
 // @@protoc_insertion_point(module)
@@ -2,6 +2,7 @@
 /// Module is the config object of the authz module.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Module {
 }
 // @@protoc_insertion_point(module)
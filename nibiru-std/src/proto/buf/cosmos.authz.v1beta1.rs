@@ -3,6 +3,7 @@
 /// the provided method on behalf of the granter's account.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GenericAuthorization {
     /// Msg, identified by it's type URL, to grant unrestricted permissions to execute
     #[prost(string, tag="1")]
@@ -12,32 +13,39 @@ pub struct GenericAuthorization {
 /// the provide method with expiration time.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Grant {
     #[prost(message, optional, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub authorization: ::core::option::Option<::prost_types::Any>,
     /// time when the grant will expire and will be pruned. If null, then the grant
     /// doesn't have a time expiration (other conditions  in `authorization`
     /// may apply to invalidate the grant)
     #[prost(message, optional, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub expiration: ::core::option::Option<::prost_types::Timestamp>,
 }
 /// GrantAuthorization extends a grant with both the addresses of the grantee and granter.
 /// It is used in genesis.proto and query.proto
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GrantAuthorization {
     #[prost(string, tag="1")]
     pub granter: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
     pub grantee: ::prost::alloc::string::String,
     #[prost(message, optional, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub authorization: ::core::option::Option<::prost_types::Any>,
     #[prost(message, optional, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub expiration: ::core::option::Option<::prost_types::Timestamp>,
 }
 /// GrantQueueItem contains the list of TypeURL of a sdk.Msg.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GrantQueueItem {
     /// msg_type_urls contains the list of TypeURL of a sdk.Msg.
     #[prost(string, repeated, tag="1")]
@@ -46,6 +54,7 @@ pub struct GrantQueueItem {
 /// EventGrant is emitted on Msg/Grant
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventGrant {
     /// Msg type URL for which an autorization is granted
     #[prost(string, tag="2")]
@@ -60,6 +69,7 @@ pub struct EventGrant {
 /// EventRevoke is emitted on Msg/Revoke
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventRevoke {
     /// Msg type URL for which an autorization is revoked
     #[prost(string, tag="2")]
@@ -74,6 +84,7 @@ pub struct EventRevoke {
 /// GenesisState defines the authz module's genesis state.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GenesisState {
     #[prost(message, repeated, tag="1")]
     pub authorization: ::prost::alloc::vec::Vec<GrantAuthorization>,
@@ -81,6 +92,7 @@ pub struct GenesisState {
 /// QueryGrantsRequest is the request type for the Query/Grants RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGrantsRequest {
     #[prost(string, tag="1")]
     pub granter: ::prost::alloc::string::String,
@@ -96,6 +108,7 @@ pub struct QueryGrantsRequest {
 /// QueryGrantsResponse is the response type for the Query/Authorizations RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGrantsResponse {
     /// authorizations is a list of grants granted for grantee by granter.
     #[prost(message, repeated, tag="1")]
@@ -107,6 +120,7 @@ pub struct QueryGrantsResponse {
 /// QueryGranterGrantsRequest is the request type for the Query/GranterGrants RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGranterGrantsRequest {
     #[prost(string, tag="1")]
     pub granter: ::prost::alloc::string::String,
@@ -117,6 +131,7 @@ pub struct QueryGranterGrantsRequest {
 /// QueryGranterGrantsResponse is the response type for the Query/GranterGrants RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGranterGrantsResponse {
     /// grants is a list of grants granted by the granter.
     #[prost(message, repeated, tag="1")]
@@ -128,6 +143,7 @@ pub struct QueryGranterGrantsResponse {
 /// QueryGranteeGrantsRequest is the request type for the Query/IssuedGrants RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGranteeGrantsRequest {
     #[prost(string, tag="1")]
     pub grantee: ::prost::alloc::string::String,
@@ -138,6 +154,7 @@ pub struct QueryGranteeGrantsRequest {
 /// QueryGranteeGrantsResponse is the response type for the Query/GranteeGrants RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGranteeGrantsResponse {
     /// grants is a list of grants granted to the grantee.
     #[prost(message, repeated, tag="1")]
@@ -150,6 +167,7 @@ pub struct QueryGranteeGrantsResponse {
 /// on behalf of the granter with the provided expiration time.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgGrant {
     #[prost(string, tag="1")]
     pub granter: ::prost::alloc::string::String,
@@ -161,8 +179,10 @@ pub struct MsgGrant {
 /// MsgExecResponse defines the Msg/MsgExecResponse response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgExecResponse {
     #[prost(bytes="bytes", repeated, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<String>"))]
     pub results: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
 }
 /// MsgExec attempts to execute the provided messages using
@@ -170,6 +190,7 @@ pub struct MsgExecResponse {
 /// one signer corresponding to the granter of the authorization.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgExec {
     #[prost(string, tag="1")]
     pub grantee: ::prost::alloc::string::String,
@@ -177,17 +198,20 @@ pub struct MsgExec {
     /// The x/authz will try to find a grant matching (msg.signers\[0\], grantee, MsgTypeURL(msg))
     /// triple and validate it.
     #[prost(message, repeated, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<::serde_json::Value>"))]
     pub msgs: ::prost::alloc::vec::Vec<::prost_types::Any>,
 }
 /// MsgGrantResponse defines the Msg/MsgGrant response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgGrantResponse {
 }
 /// MsgRevoke revokes any authorization with the provided sdk.Msg type on the
 /// granter's account with that has been granted to the grantee.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgRevoke {
     #[prost(string, tag="1")]
     pub granter: ::prost::alloc::string::String,
@@ -199,6 +223,7 @@ pub struct MsgRevoke {
 /// MsgRevokeResponse defines the Msg/MsgRevokeResponse response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgRevokeResponse {
 }
 // @@protoc_insertion_point(module)
@@ -2,6 +2,7 @@
 /// ModuleOptions describes the CLI options for a Cosmos SDK module.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ModuleOptions {
     /// tx describes the tx command for the module.
     #[prost(message, optional, tag="1")]
@@ -13,6 +14,7 @@ pub struct ModuleOptions {
 /// ServiceCommandDescriptor describes a CLI command based on a protobuf service.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ServiceCommandDescriptor {
     /// service is the fully qualified name of the protobuf service to build
     /// the command from. It can be left empty if sub_commands are used instead
@@ -34,6 +36,7 @@ pub struct ServiceCommandDescriptor {
 /// rpc methods.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct RpcCommandOptions {
     /// rpc_method is short name of the protobuf rpc method that this command is
     /// generated from.
@@ -89,6 +92,7 @@ pub struct RpcCommandOptions {
 /// instead by using RpcCommandOptions.positional_args.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct FlagOptions {
     /// name is an alternate name to use for the field flag.
     #[prost(string, tag="1")]
@@ -118,6 +122,7 @@ pub struct FlagOptions {
 /// PositionalArgDescriptor describes a positional argument.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct PositionalArgDescriptor {
     /// proto_field specifies the proto field to use as the positional arg. Any
     /// fields used as positional args will not have a flag generated.
@@ -132,11 +137,13 @@ pub struct PositionalArgDescriptor {
 /// AppOptionsRequest is the RemoteInfoService/AppOptions request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct AppOptionsRequest {
 }
 /// AppOptionsResponse is the RemoteInfoService/AppOptions response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct AppOptionsResponse {
     /// module_options is a map of module name to autocli module options.
     #[prost(map="string, message", tag="1")]
@@ -2,6 +2,7 @@
 /// Module is the config object of the bank module.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Module {
     /// blocked_module_accounts configures exceptional module accounts which should be blocked from receiving funds.
     /// If left empty it defaults to the list of account names supplied in the auth module configuration as
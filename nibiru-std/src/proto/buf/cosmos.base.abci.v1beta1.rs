@@ -3,9 +3,11 @@
 /// tags are stringified and the log is JSON decoded.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TxResponse {
     /// The block height
     #[prost(int64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(skip_serializing, with = "String"))]
     pub height: i64,
     /// The transaction hash.
     #[prost(string, tag="2")]
@@ -31,12 +33,15 @@ pub struct TxResponse {
     pub info: ::prost::alloc::string::String,
     /// Amount of gas requested for transaction.
     #[prost(int64, tag="9")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(skip_serializing, with = "String"))]
     pub gas_wanted: i64,
     /// Amount of gas consumed by transaction.
     #[prost(int64, tag="10")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(skip_serializing, with = "String"))]
     pub gas_used: i64,
     /// The request transaction bytes.
     #[prost(message, optional, tag="11")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(skip_serializing, with = "Option<::serde_json::Value>"))]
     pub tx: ::core::option::Option<::prost_types::Any>,
     /// Time of the previous block. For heights > 1, it's the weighted median of
     /// the timestamps of the valid votes in the block.LastCommit. For height == 1,
@@ -55,6 +60,7 @@ pub struct TxResponse {
 /// ABCIMessageLog defines a structure containing an indexed tx ABCI message log.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct AbciMessageLog {
     #[prost(uint32, tag="1")]
     pub msg_index: u32,
@@ -69,6 +75,7 @@ pub struct AbciMessageLog {
 /// contain key/value pairs that are strings instead of raw bytes.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct StringEvent {
     #[prost(string, tag="1")]
     pub r#type: ::prost::alloc::string::String,
@@ -79,6 +86,7 @@ pub struct StringEvent {
 /// strings instead of raw bytes.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Attribute {
     #[prost(string, tag="1")]
     pub key: ::prost::alloc::string::String,
@@ -88,17 +96,21 @@ pub struct Attribute {
 /// GasInfo defines tx execution gas context.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GasInfo {
     /// GasWanted is the maximum units of work we allow this tx to perform.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(skip_serializing, with = "String"))]
     pub gas_wanted: u64,
     /// GasUsed is the amount of gas actually consumed.
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(skip_serializing, with = "String"))]
     pub gas_used: u64,
 }
 /// Result is the union of ResponseFormat and ResponseCheckTx.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Result {
     /// Data is any data returned from message or handler execution. It MUST be
     /// length prefixed in order to separate data from multiple message executions.
@@ -106,6 +118,7 @@ pub struct Result {
     /// because it also contains the Msg response typeURL.
     #[deprecated]
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(skip_serializing, with = "String"))]
     pub data: ::prost::bytes::Bytes,
     /// Log contains the log information from message or handler execution.
     #[prost(string, tag="2")]
@@ -118,12 +131,14 @@ pub struct Result {
     ///
     /// Since: cosmos-sdk 0.46
     #[prost(message, repeated, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(skip_serializing, with = "Vec<::serde_json::Value>"))]
     pub msg_responses: ::prost::alloc::vec::Vec<::prost_types::Any>,
 }
 /// SimulationResponse defines the response generated when a transaction is
 /// successfully simulated.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SimulationResponse {
     #[prost(message, optional, tag="1")]
     pub gas_info: ::core::option::Option<GasInfo>,
@@ -134,16 +149,19 @@ pub struct SimulationResponse {
 /// execution.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgData {
     #[prost(string, tag="1")]
     pub msg_type: ::prost::alloc::string::String,
     #[prost(bytes="bytes", tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(skip_serializing, with = "String"))]
     pub data: ::prost::bytes::Bytes,
 }
 /// TxMsgData defines a list of MsgData. A transaction will have a MsgData object
 /// for each message.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TxMsgData {
     /// data field is deprecated and not populated.
     #[deprecated]
@@ -153,26 +171,33 @@ pub struct TxMsgData {
     ///
     /// Since: cosmos-sdk 0.46
     #[prost(message, repeated, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(skip_serializing, with = "Vec<::serde_json::Value>"))]
     pub msg_responses: ::prost::alloc::vec::Vec<::prost_types::Any>,
 }
 /// SearchTxsResult defines a structure for querying txs pageable
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SearchTxsResult {
     /// Count of all txs
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(skip_serializing, with = "String"))]
     pub total_count: u64,
     /// Count of txs in current page
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(skip_serializing, with = "String"))]
     pub count: u64,
     /// Index of current page, start from 1
     #[prost(uint64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(skip_serializing, with = "String"))]
     pub page_number: u64,
     /// Count of total pages
     #[prost(uint64, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(skip_serializing, with = "String"))]
     pub page_total: u64,
     /// Max count txs per page
     #[prost(uint64, tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(skip_serializing, with = "String"))]
     pub limit: u64,
     /// List of txs in current page
     #[prost(message, repeated, tag="6")]
@@ -2,6 +2,7 @@
 /// Pairs defines a repeated slice of Pair objects.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Pairs {
     #[prost(message, repeated, tag="1")]
     pub pairs: ::prost::alloc::vec::Vec<Pair>,
@@ -9,10 +10,13 @@ pub struct Pairs {
 /// Pair defines a key/value bytes tuple.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Pair {
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub key: ::prost::bytes::Bytes,
     #[prost(bytes="bytes", tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub value: ::prost::bytes::Bytes,
 }
 // @@protoc_insertion_point(module)
@@ -8,20 +8,24 @@
 ///   }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct PageRequest {
     /// key is a value returned in PageResponse.next_key to begin
     /// querying the next page most efficiently. Only one of offset or key
     /// should be set.
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub key: ::prost::bytes::Bytes,
     /// offset is a numeric offset that can be used when key is unavailable.
     /// It is less efficient than using key. Only one of offset or key should
     /// be set.
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub offset: u64,
     /// limit is the total number of results to be returned in the result page.
     /// If left empty it will default to a value to be set by each app.
     #[prost(uint64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub limit: u64,
     /// count_total is set to true  to indicate that the result set should include
     /// a count of the total number of items available for pagination in UIs.
@@ -44,15 +48,18 @@ pub struct PageRequest {
 ///   }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct PageResponse {
     /// next_key is the key to be passed to PageRequest.key to
     /// query the next page most efficiently. It will be empty if
     /// there are no more results.
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub next_key: ::prost::bytes::Bytes,
     /// total is total number of results available if PageRequest.count_total
     /// was set, its value is undefined otherwise
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub total: u64,
 }
 // @@protoc_insertion_point(module)
@@ -2,6 +2,7 @@
 /// AppDescriptor describes a cosmos-sdk based application
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct AppDescriptor {
     /// AuthnDescriptor provides information on how to authenticate transactions on the application
     /// NOTE: experimental and subject to change in future releases.
@@ -26,6 +27,7 @@ pub struct AppDescriptor {
 /// TxDescriptor describes the accepted transaction type
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TxDescriptor {
     /// fullname is the protobuf fullname of the raw transaction type (for instance the tx.Tx type)
     /// it is not meant to support polymorphism of transaction types, it is supposed to be used by
@@ -40,6 +42,7 @@ pub struct TxDescriptor {
 /// on the online RPCs GetTxMetadata and CombineUnsignedTxAndSignatures
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct AuthnDescriptor {
     /// sign_modes defines the supported signature algorithm
     #[prost(message, repeated, tag="1")]
@@ -51,6 +54,7 @@ pub struct AuthnDescriptor {
 /// this another time
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SigningModeDescriptor {
     /// name defines the unique name of the signing mode
     #[prost(string, tag="1")]
@@ -66,6 +70,7 @@ pub struct SigningModeDescriptor {
 /// ChainDescriptor describes chain information of the application
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ChainDescriptor {
     /// id is the chain id
     #[prost(string, tag="1")]
@@ -74,6 +79,7 @@ pub struct ChainDescriptor {
 /// CodecDescriptor describes the registered interfaces and provides metadata information on the types
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct CodecDescriptor {
     /// interfaces is a list of the registerted interfaces descriptors
     #[prost(message, repeated, tag="1")]
@@ -82,6 +88,7 @@ pub struct CodecDescriptor {
 /// InterfaceDescriptor describes the implementation of an interface
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct InterfaceDescriptor {
     /// fullname is the name of the interface
     #[prost(string, tag="1")]
@@ -97,6 +104,7 @@ pub struct InterfaceDescriptor {
 /// InterfaceImplementerDescriptor describes an interface implementer
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct InterfaceImplementerDescriptor {
     /// fullname is the protobuf queryable name of the interface implementer
     #[prost(string, tag="1")]
@@ -112,6 +120,7 @@ pub struct InterfaceImplementerDescriptor {
 /// an interface represented as a google.protobuf.Any
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct InterfaceAcceptingMessageDescriptor {
     /// fullname is the protobuf fullname of the type containing the interface
     #[prost(string, tag="1")]
@@ -125,6 +134,7 @@ pub struct InterfaceAcceptingMessageDescriptor {
 /// ConfigurationDescriptor contains metadata information on the sdk.Config
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ConfigurationDescriptor {
     /// bech32_account_address_prefix is the account address prefix
     #[prost(string, tag="1")]
@@ -133,6 +143,7 @@ pub struct ConfigurationDescriptor {
 /// MsgDescriptor describes a cosmos-sdk message that can be delivered with a transaction
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgDescriptor {
     /// msg_type_url contains the TypeURL of a sdk.Msg.
     #[prost(string, tag="1")]
@@ -141,11 +152,13 @@ pub struct MsgDescriptor {
 /// GetAuthnDescriptorRequest is the request used for the GetAuthnDescriptor RPC
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetAuthnDescriptorRequest {
 }
 /// GetAuthnDescriptorResponse is the response returned by the GetAuthnDescriptor RPC
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetAuthnDescriptorResponse {
     /// authn describes how to authenticate to the application when sending transactions
     #[prost(message, optional, tag="1")]
@@ -154,11 +167,13 @@ pub struct GetAuthnDescriptorResponse {
 /// GetChainDescriptorRequest is the request used for the GetChainDescriptor RPC
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetChainDescriptorRequest {
 }
 /// GetChainDescriptorResponse is the response returned by the GetChainDescriptor RPC
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetChainDescriptorResponse {
     /// chain describes application chain information
     #[prost(message, optional, tag="1")]
@@ -167,11 +182,13 @@ pub struct GetChainDescriptorResponse {
 /// GetCodecDescriptorRequest is the request used for the GetCodecDescriptor RPC
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetCodecDescriptorRequest {
 }
 /// GetCodecDescriptorResponse is the response returned by the GetCodecDescriptor RPC
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetCodecDescriptorResponse {
     /// codec describes the application codec such as registered interfaces and implementations
     #[prost(message, optional, tag="1")]
@@ -180,11 +197,13 @@ pub struct GetCodecDescriptorResponse {
 /// GetConfigurationDescriptorRequest is the request used for the GetConfigurationDescriptor RPC
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetConfigurationDescriptorRequest {
 }
 /// GetConfigurationDescriptorResponse is the response returned by the GetConfigurationDescriptor RPC
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetConfigurationDescriptorResponse {
     /// config describes the application's sdk.Config
     #[prost(message, optional, tag="1")]
@@ -193,11 +212,13 @@ pub struct GetConfigurationDescriptorResponse {
 /// GetQueryServicesDescriptorRequest is the request used for the GetQueryServicesDescriptor RPC
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetQueryServicesDescriptorRequest {
 }
 /// GetQueryServicesDescriptorResponse is the response returned by the GetQueryServicesDescriptor RPC
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetQueryServicesDescriptorResponse {
     /// queries provides information on the available queryable services
     #[prost(message, optional, tag="1")]
@@ -206,11 +227,13 @@ pub struct GetQueryServicesDescriptorResponse {
 /// GetTxDescriptorRequest is the request used for the GetTxDescriptor RPC
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetTxDescriptorRequest {
 }
 /// GetTxDescriptorResponse is the response returned by the GetTxDescriptor RPC
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetTxDescriptorResponse {
     /// tx provides information on msgs that can be forwarded to the application
     /// alongside the accepted transaction protobuf type
@@ -220,6 +243,7 @@ pub struct GetTxDescriptorResponse {
 /// QueryServicesDescriptor contains the list of cosmos-sdk queriable services
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryServicesDescriptor {
     /// query_services is a list of cosmos-sdk QueryServiceDescriptor
     #[prost(message, repeated, tag="1")]
@@ -228,6 +252,7 @@ pub struct QueryServicesDescriptor {
 /// QueryServiceDescriptor describes a cosmos-sdk queryable service
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryServiceDescriptor {
     /// fullname is the protobuf fullname of the service descriptor
     #[prost(string, tag="1")]
@@ -244,6 +269,7 @@ pub struct QueryServiceDescriptor {
 /// because it would be redundant with the grpc reflection service
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryMethodDescriptor {
     /// name is the protobuf name (not fullname) of the method
     #[prost(string, tag="1")]
@@ -2,14 +2,17 @@
 /// Snapshot contains Tendermint state sync snapshot info.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Snapshot {
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub height: u64,
     #[prost(uint32, tag="2")]
     pub format: u32,
     #[prost(uint32, tag="3")]
     pub chunks: u32,
     #[prost(bytes="bytes", tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub hash: ::prost::bytes::Bytes,
     #[prost(message, optional, tag="5")]
     pub metadata: ::core::option::Option<Metadata>,
@@ -17,9 +20,11 @@ pub struct Snapshot {
 /// Metadata contains SDK-specific snapshot metadata.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Metadata {
     /// SHA-256 chunk hashes
     #[prost(bytes="bytes", repeated, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<String>"))]
     pub chunk_hashes: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
 }
 /// SnapshotItem is an item contained in a rootmulti.Store snapshot.
@@ -27,9 +32,11 @@ pub struct Metadata {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SnapshotItem {
     /// item is the specific type of snapshot item.
     #[prost(oneof="snapshot_item::Item", tags="1, 2, 3, 4, 5, 6")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub item: ::core::option::Option<snapshot_item::Item>,
 }
 /// Nested message and enum types in `SnapshotItem`.
@@ -37,6 +44,7 @@ pub mod snapshot_item {
     /// item is the specific type of snapshot item.
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Oneof)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase"), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub enum Item {
         #[prost(message, tag="1")]
         Store(super::SnapshotStoreItem),
@@ -57,6 +65,7 @@ pub mod snapshot_item {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SnapshotStoreItem {
     #[prost(string, tag="1")]
     pub name: ::prost::alloc::string::String,
@@ -66,13 +75,17 @@ pub struct SnapshotStoreItem {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SnapshotIavlItem {
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub key: ::prost::bytes::Bytes,
     #[prost(bytes="bytes", tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub value: ::prost::bytes::Bytes,
     /// version is block height
     #[prost(int64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub version: i64,
     /// height is depth of the tree.
     #[prost(int32, tag="4")]
@@ -83,6 +96,7 @@ pub struct SnapshotIavlItem {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SnapshotExtensionMeta {
     #[prost(string, tag="1")]
     pub name: ::prost::alloc::string::String,
@@ -94,8 +108,10 @@ pub struct SnapshotExtensionMeta {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SnapshotExtensionPayload {
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub payload: ::prost::bytes::Bytes,
 }
 /// SnapshotKVItem is an exported Key/Value Pair
@@ -104,10 +120,13 @@ pub struct SnapshotExtensionPayload {
 /// Deprecated: This message was part of store/v2alpha1 which has been deleted from v0.47.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SnapshotKvItem {
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub key: ::prost::bytes::Bytes,
     #[prost(bytes="bytes", tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub value: ::prost::bytes::Bytes,
 }
 /// SnapshotSchema is an exported schema of smt store
@@ -116,8 +135,10 @@ pub struct SnapshotKvItem {
 /// Deprecated: This message was part of store/v2alpha1 which has been deleted from v0.47.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SnapshotSchema {
     #[prost(bytes="bytes", repeated, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<String>"))]
     pub keys: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
 }
 // @@protoc_insertion_point(module)
@@ -3,18 +3,22 @@
 /// a version/height.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct CommitInfo {
     #[prost(int64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub version: i64,
     #[prost(message, repeated, tag="2")]
     pub store_infos: ::prost::alloc::vec::Vec<StoreInfo>,
     #[prost(message, optional, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
 }
 /// StoreInfo defines store-specific commit information. It contains a reference
 /// between a store name and the commit ID.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct StoreInfo {
     #[prost(string, tag="1")]
     pub name: ::prost::alloc::string::String,
@@ -25,10 +29,13 @@ pub struct StoreInfo {
 /// committed.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct CommitId {
     #[prost(int64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub version: i64,
     #[prost(bytes="bytes", tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub hash: ::prost::bytes::Bytes,
 }
 /// StoreKVPair is a KVStore KVPair used for listening to state changes (Sets and Deletes)
@@ -38,6 +45,7 @@ pub struct CommitId {
 /// Since: cosmos-sdk 0.43
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct StoreKvPair {
     /// the store key for the KVStore this pair originates from
     #[prost(string, tag="1")]
@@ -46,14 +54,17 @@ pub struct StoreKvPair {
     #[prost(bool, tag="2")]
     pub delete: bool,
     #[prost(bytes="bytes", tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub key: ::prost::bytes::Bytes,
     #[prost(bytes="bytes", tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub value: ::prost::bytes::Bytes,
 }
 /// BlockMetadata contains all the abci event data of a block
 /// the file streamer dump them into files together with the state changes.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct BlockMetadata {
     #[prost(message, optional, tag="1")]
     pub request_begin_block: ::core::option::Option<crate::proto::tendermint::abci::RequestBeginBlock>,
@@ -73,6 +84,7 @@ pub mod block_metadata {
     /// DeliverTx encapulate deliver tx request and response.
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub struct DeliverTx {
         #[prost(message, optional, tag="1")]
         pub request: ::core::option::Option<crate::proto::tendermint::abci::RequestDeliverTx>,
@@ -3,6 +3,7 @@
 /// field converted to bech32 string.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Block {
     #[prost(message, optional, tag="1")]
     pub header: ::core::option::Option<Header>,
@@ -16,6 +17,7 @@ pub struct Block {
 /// Header defines the structure of a Tendermint block header.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Header {
     /// basic block info
     #[prost(message, optional, tag="1")]
@@ -23,8 +25,10 @@ pub struct Header {
     #[prost(string, tag="2")]
     pub chain_id: ::prost::alloc::string::String,
     #[prost(int64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub height: i64,
     #[prost(message, optional, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
     /// prev block info
     #[prost(message, optional, tag="5")]
@@ -33,31 +37,39 @@ pub struct Header {
     ///
     /// commit from validators from the last block
     #[prost(bytes="bytes", tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub last_commit_hash: ::prost::bytes::Bytes,
     /// transactions
     #[prost(bytes="bytes", tag="7")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub data_hash: ::prost::bytes::Bytes,
     /// hashes from the app output from the prev block
     ///
     /// validators for the current block
     #[prost(bytes="bytes", tag="8")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub validators_hash: ::prost::bytes::Bytes,
     /// validators for the next block
     #[prost(bytes="bytes", tag="9")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub next_validators_hash: ::prost::bytes::Bytes,
     /// consensus params for current block
     #[prost(bytes="bytes", tag="10")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub consensus_hash: ::prost::bytes::Bytes,
     /// state after txs from the previous block
     #[prost(bytes="bytes", tag="11")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub app_hash: ::prost::bytes::Bytes,
     /// root hash of all results from the txs from the previous block
     #[prost(bytes="bytes", tag="12")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub last_results_hash: ::prost::bytes::Bytes,
     /// consensus info
     ///
     /// evidence included in the block
     #[prost(bytes="bytes", tag="13")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub evidence_hash: ::prost::bytes::Bytes,
     /// proposer_address is the original block proposer address, formatted as a Bech32 string.
     /// In Tendermint, this type is `bytes`, but in the SDK, we convert it to a Bech32 string
@@ -70,8 +82,10 @@ pub struct Header {
 /// GetValidatorSetByHeightRequest is the request type for the Query/GetValidatorSetByHeight RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetValidatorSetByHeightRequest {
     #[prost(int64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub height: i64,
     /// pagination defines an pagination for the request.
     #[prost(message, optional, tag="2")]
@@ -80,8 +94,10 @@ pub struct GetValidatorSetByHeightRequest {
 /// GetValidatorSetByHeightResponse is the response type for the Query/GetValidatorSetByHeight RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetValidatorSetByHeightResponse {
     #[prost(int64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub block_height: i64,
     #[prost(message, repeated, tag="2")]
     pub validators: ::prost::alloc::vec::Vec<Validator>,
@@ -92,6 +108,7 @@ pub struct GetValidatorSetByHeightResponse {
 /// GetLatestValidatorSetRequest is the request type for the Query/GetValidatorSetByHeight RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetLatestValidatorSetRequest {
     /// pagination defines an pagination for the request.
     #[prost(message, optional, tag="1")]
@@ -100,8 +117,10 @@ pub struct GetLatestValidatorSetRequest {
 /// GetLatestValidatorSetResponse is the response type for the Query/GetValidatorSetByHeight RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetLatestValidatorSetResponse {
     #[prost(int64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub block_height: i64,
     #[prost(message, repeated, tag="2")]
     pub validators: ::prost::alloc::vec::Vec<Validator>,
@@ -112,26 +131,33 @@ pub struct GetLatestValidatorSetResponse {
 /// Validator is the type for the validator-set.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Validator {
     #[prost(string, tag="1")]
     pub address: ::prost::alloc::string::String,
     #[prost(message, optional, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub pub_key: ::core::option::Option<::prost_types::Any>,
     #[prost(int64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub voting_power: i64,
     #[prost(int64, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposer_priority: i64,
 }
 /// GetBlockByHeightRequest is the request type for the Query/GetBlockByHeight RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetBlockByHeightRequest {
     #[prost(int64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub height: i64,
 }
 /// GetBlockByHeightResponse is the response type for the Query/GetBlockByHeight RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetBlockByHeightResponse {
     #[prost(message, optional, tag="1")]
     pub block_id: ::core::option::Option<crate::proto::tendermint::types::BlockId>,
@@ -145,11 +171,13 @@ pub struct GetBlockByHeightResponse {
 /// GetLatestBlockRequest is the request type for the Query/GetLatestBlock RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetLatestBlockRequest {
 }
 /// GetLatestBlockResponse is the response type for the Query/GetLatestBlock RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetLatestBlockResponse {
     #[prost(message, optional, tag="1")]
     pub block_id: ::core::option::Option<crate::proto::tendermint::types::BlockId>,
@@ -163,11 +191,13 @@ pub struct GetLatestBlockResponse {
 /// GetSyncingRequest is the request type for the Query/GetSyncing RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetSyncingRequest {
 }
 /// GetSyncingResponse is the response type for the Query/GetSyncing RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetSyncingResponse {
     #[prost(bool, tag="1")]
     pub syncing: bool,
@@ -175,11 +205,13 @@ pub struct GetSyncingResponse {
 /// GetNodeInfoRequest is the request type for the Query/GetNodeInfo RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetNodeInfoRequest {
 }
 /// GetNodeInfoResponse is the response type for the Query/GetNodeInfo RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetNodeInfoResponse {
     #[prost(message, optional, tag="1")]
     pub default_node_info: ::core::option::Option<crate::proto::tendermint::p2p::DefaultNodeInfo>,
@@ -189,6 +221,7 @@ pub struct GetNodeInfoResponse {
 /// VersionInfo is the type for the GetNodeInfoResponse message.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct VersionInfo {
     #[prost(string, tag="1")]
     pub name: ::prost::alloc::string::String,
@@ -211,6 +244,7 @@ pub struct VersionInfo {
 /// Module is the type for VersionInfo
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Module {
     /// module path
     #[prost(string, tag="1")]
@@ -225,12 +259,15 @@ pub struct Module {
 /// ABCIQueryRequest defines the request structure for the ABCIQuery gRPC query.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct AbciQueryRequest {
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub data: ::prost::bytes::Bytes,
     #[prost(string, tag="2")]
     pub path: ::prost::alloc::string::String,
     #[prost(int64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub height: i64,
     #[prost(bool, tag="4")]
     pub prove: bool,
@@ -241,6 +278,7 @@ pub struct AbciQueryRequest {
 /// Tendermint.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct AbciQueryResponse {
     #[prost(uint32, tag="1")]
     pub code: u32,
@@ -251,14 +289,18 @@ pub struct AbciQueryResponse {
     #[prost(string, tag="4")]
     pub info: ::prost::alloc::string::String,
     #[prost(int64, tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub index: i64,
     #[prost(bytes="bytes", tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub key: ::prost::bytes::Bytes,
     #[prost(bytes="bytes", tag="7")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub value: ::prost::bytes::Bytes,
     #[prost(message, optional, tag="8")]
     pub proof_ops: ::core::option::Option<ProofOps>,
     #[prost(int64, tag="9")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub height: i64,
     #[prost(string, tag="10")]
     pub codespace: ::prost::alloc::string::String,
@@ -270,12 +312,15 @@ pub struct AbciQueryResponse {
 /// Note: This type is a duplicate of the ProofOp proto type defined in Tendermint.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ProofOp {
     #[prost(string, tag="1")]
     pub r#type: ::prost::alloc::string::String,
     #[prost(bytes="bytes", tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub key: ::prost::bytes::Bytes,
     #[prost(bytes="bytes", tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub data: ::prost::bytes::Bytes,
 }
 /// ProofOps is Merkle proof defined by the list of ProofOps.
@@ -283,6 +328,7 @@ pub struct ProofOp {
 /// Note: This type is a duplicate of the ProofOps proto type defined in Tendermint.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ProofOps {
     #[prost(message, repeated, tag="1")]
     pub ops: ::prost::alloc::vec::Vec<ProofOp>,
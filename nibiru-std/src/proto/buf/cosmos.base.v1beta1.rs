@@ -5,6 +5,7 @@
 /// signatures required by gogoproto.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Coin {
     #[prost(string, tag="1")]
     pub denom: ::prost::alloc::string::String,
@@ -17,6 +18,7 @@ pub struct Coin {
 /// signatures required by gogoproto.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct DecCoin {
     #[prost(string, tag="1")]
     pub denom: ::prost::alloc::string::String,
@@ -26,6 +28,7 @@ pub struct DecCoin {
 /// IntProto defines a Protobuf wrapper around an Int object.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct IntProto {
     #[prost(string, tag="1")]
     pub int: ::prost::alloc::string::String,
@@ -33,6 +36,7 @@ pub struct IntProto {
 /// DecProto defines a Protobuf wrapper around a Dec object.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct DecProto {
     #[prost(string, tag="1")]
     pub dec: ::prost::alloc::string::String,
@@ -2,6 +2,7 @@
 /// Module is the config object of the capability module.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Module {
     /// seal_keeper defines if keeper.Seal() will run on BeginBlock() to prevent further modules from creating a scoped
     /// keeper. For more details check x/capability/keeper.go.
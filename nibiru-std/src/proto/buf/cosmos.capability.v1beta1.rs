@@ -3,14 +3,17 @@
 /// provided to a Capability must be globally unique.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Capability {
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub index: u64,
 }
 /// Owner defines a single capability owner. An owner is defined by the name of
 /// capability and the module name.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Owner {
     #[prost(string, tag="1")]
     pub module: ::prost::alloc::string::String,
@@ -21,6 +24,7 @@ pub struct Owner {
 /// owners must be unique.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct CapabilityOwners {
     #[prost(message, repeated, tag="1")]
     pub owners: ::prost::alloc::vec::Vec<Owner>,
@@ -28,9 +32,11 @@ pub struct CapabilityOwners {
 /// GenesisOwners defines the capability owners with their corresponding index.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GenesisOwners {
     /// index is the index of the capability owner.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub index: u64,
     /// index_owners are the owners at the given index.
     #[prost(message, optional, tag="2")]
@@ -39,9 +45,11 @@ pub struct GenesisOwners {
 /// GenesisState defines the capability module's genesis state.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GenesisState {
     /// index is the capability global index.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub index: u64,
     /// owners represents a map from index to owners of the capability index
     /// index key is string to allow amino marshalling.
@@ -2,6 +2,7 @@
 /// BIP44Params is used as path field in ledger item in Record.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Bip44Params {
     /// purpose is a constant set to 44' (or 0x8000002C) following the BIP43 recommendation
     #[prost(uint32, tag="1")]
@@ -2,15 +2,18 @@
 /// Record is used for representing a key in the keyring.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Record {
     /// name represents a name of Record
     #[prost(string, tag="1")]
     pub name: ::prost::alloc::string::String,
     /// pub_key represents a public key in any format
     #[prost(message, optional, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub pub_key: ::core::option::Option<::prost_types::Any>,
     /// Record contains one of the following items
     #[prost(oneof="record::Item", tags="3, 4, 5, 6")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub item: ::core::option::Option<record::Item>,
 }
 /// Nested message and enum types in `Record`.
@@ -19,13 +22,16 @@ pub mod record {
     /// Local item
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub struct Local {
         #[prost(message, optional, tag="1")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
         pub priv_key: ::core::option::Option<::prost_types::Any>,
     }
     /// Ledger item
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub struct Ledger {
         #[prost(message, optional, tag="1")]
         pub path: ::core::option::Option<crate::proto::cosmos::crypto::hd::v1::Bip44Params>,
@@ -33,16 +39,19 @@ pub mod record {
     /// Multi item
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub struct Multi {
     }
     /// Offline item
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub struct Offline {
     }
     /// Record contains one of the following items
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Oneof)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase"), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub enum Item {
         /// local stores the private key locally.
         #[prost(message, tag="3")]
@@ -4,10 +4,12 @@
 /// it uses legacy amino address rules.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct LegacyAminoPubKey {
     #[prost(uint32, tag="1")]
     pub threshold: u32,
     #[prost(message, repeated, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<::serde_json::Value>"))]
     pub public_keys: ::prost::alloc::vec::Vec<::prost_types::Any>,
 }
 // @@protoc_insertion_point(module)
@@ -4,8 +4,10 @@
 /// signed and with which modes.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MultiSignature {
     #[prost(bytes="bytes", repeated, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<String>"))]
     pub signatures: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
 }
 /// CompactBitArray is an implementation of a space efficient bit array.
@@ -14,10 +16,12 @@ pub struct MultiSignature {
 /// This is not thread safe, and is not intended for concurrent usage.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct CompactBitArray {
     #[prost(uint32, tag="1")]
     pub extra_bits_stored: u32,
     #[prost(bytes="bytes", tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub elems: ::prost::bytes::Bytes,
 }
 // @@protoc_insertion_point(module)
@@ -2,9 +2,11 @@
 /// GenesisState defines the raw genesis transaction in JSON.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GenesisState {
     /// gen_txs defines the genesis transactions.
     #[prost(bytes="bytes", repeated, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<String>"))]
     pub gen_txs: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
 }
 // @@protoc_insertion_point(module)
@@ -2,10 +2,12 @@
 /// Module is the config object of the gov module.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Module {
     /// max_metadata_len defines the maximum proposal metadata length. 
     /// Defaults to 255 if not explicitly set.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub max_metadata_len: u64,
     /// authority defines the custom module authority. If not set, defaults to the governance module.
     #[prost(string, tag="2")]
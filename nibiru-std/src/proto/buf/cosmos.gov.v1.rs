@@ -2,9 +2,11 @@
 /// WeightedVoteOption defines a unit of vote for vote split.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct WeightedVoteOption {
     /// option defines the valid vote options, it must not contain duplicate vote options.
     #[prost(enumeration="VoteOption", tag="1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<VoteOption, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<VoteOption, _>"), schemars(with = "VoteOption"))]
     pub option: i32,
     /// weight is the vote weight associated with the vote option.
     #[prost(string, tag="2")]
@@ -14,9 +16,11 @@ pub struct WeightedVoteOption {
 /// proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Deposit {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// depositor defines the deposit addresses from the proposals.
     #[prost(string, tag="2")]
@@ -28,15 +32,19 @@ pub struct Deposit {
 /// Proposal defines the core field members of a governance proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Proposal {
     /// id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub id: u64,
     /// messages are the arbitrary messages to be executed if the proposal passes.
     #[prost(message, repeated, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<::serde_json::Value>"))]
     pub messages: ::prost::alloc::vec::Vec<::prost_types::Any>,
     /// status defines the proposal status.
     #[prost(enumeration="ProposalStatus", tag="3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<ProposalStatus, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<ProposalStatus, _>"), schemars(with = "ProposalStatus"))]
     pub status: i32,
     /// final_tally_result is the final tally result of the proposal. When
     /// querying a proposal via gRPC, this field is not populated until the
@@ -45,18 +53,22 @@ pub struct Proposal {
     pub final_tally_result: ::core::option::Option<TallyResult>,
     /// submit_time is the time of proposal submission.
     #[prost(message, optional, tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub submit_time: ::core::option::Option<::prost_types::Timestamp>,
     /// deposit_end_time is the end time for deposition.
     #[prost(message, optional, tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub deposit_end_time: ::core::option::Option<::prost_types::Timestamp>,
     /// total_deposit is the total deposit on the proposal.
     #[prost(message, repeated, tag="7")]
     pub total_deposit: ::prost::alloc::vec::Vec<crate::proto::cosmos::base::v1beta1::Coin>,
     /// voting_start_time is the starting time to vote on a proposal.
     #[prost(message, optional, tag="8")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub voting_start_time: ::core::option::Option<::prost_types::Timestamp>,
     /// voting_end_time is the end time of voting on a proposal.
     #[prost(message, optional, tag="9")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub voting_end_time: ::core::option::Option<::prost_types::Timestamp>,
     /// metadata is any arbitrary metadata attached to the proposal.
     #[prost(string, tag="10")]
@@ -80,6 +92,7 @@ pub struct Proposal {
 /// TallyResult defines a standard tally for a governance proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TallyResult {
     /// yes_count is the number of yes votes on a proposal.
     #[prost(string, tag="1")]
@@ -98,9 +111,11 @@ pub struct TallyResult {
 /// A Vote consists of a proposal ID, the voter, and the vote option.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Vote {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// voter is the voter address of the proposal.
     #[prost(string, tag="2")]
@@ -115,6 +130,7 @@ pub struct Vote {
 /// DepositParams defines the params for deposits on governance proposals.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct DepositParams {
     /// Minimum deposit for a proposal to enter voting period.
     #[prost(message, repeated, tag="1")]
@@ -122,19 +138,23 @@ pub struct DepositParams {
     /// Maximum period for Atom holders to deposit on a proposal. Initial value: 2
     /// months.
     #[prost(message, optional, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub max_deposit_period: ::core::option::Option<::prost_types::Duration>,
 }
 /// VotingParams defines the params for voting on governance proposals.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct VotingParams {
     /// Duration of the voting period.
     #[prost(message, optional, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub voting_period: ::core::option::Option<::prost_types::Duration>,
 }
 /// TallyParams defines the params for tallying votes on governance proposals.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TallyParams {
     /// Minimum percentage of total stake needed to vote for a result to be
     /// considered valid.
@@ -153,6 +173,7 @@ pub struct TallyParams {
 /// Since: cosmos-sdk 0.47
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Params {
     /// Minimum deposit for a proposal to enter voting period.
     #[prost(message, repeated, tag="1")]
@@ -160,9 +181,11 @@ pub struct Params {
     /// Maximum period for Atom holders to deposit on a proposal. Initial value: 2
     /// months.
     #[prost(message, optional, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub max_deposit_period: ::core::option::Option<::prost_types::Duration>,
     /// Duration of the voting period.
     #[prost(message, optional, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub voting_period: ::core::option::Option<::prost_types::Duration>,
     ///   Minimum percentage of total stake needed to vote for a result to be
     ///   considered valid.
@@ -190,17 +213,23 @@ pub struct Params {
 }
 /// VoteOption enumerates the valid vote options for a given governance proposal.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
 #[repr(i32)]
 pub enum VoteOption {
     /// VOTE_OPTION_UNSPECIFIED defines a no-op vote option.
+    #[cfg_attr(feature = "serde", serde(rename = "VOTE_OPTION_UNSPECIFIED"))]
     Unspecified = 0,
     /// VOTE_OPTION_YES defines a yes vote option.
+    #[cfg_attr(feature = "serde", serde(rename = "VOTE_OPTION_YES"))]
     Yes = 1,
     /// VOTE_OPTION_ABSTAIN defines an abstain vote option.
+    #[cfg_attr(feature = "serde", serde(rename = "VOTE_OPTION_ABSTAIN"))]
     Abstain = 2,
     /// VOTE_OPTION_NO defines a no vote option.
+    #[cfg_attr(feature = "serde", serde(rename = "VOTE_OPTION_NO"))]
     No = 3,
     /// VOTE_OPTION_NO_WITH_VETO defines a no with veto vote option.
+    #[cfg_attr(feature = "serde", serde(rename = "VOTE_OPTION_NO_WITH_VETO"))]
     NoWithVeto = 4,
 }
 impl VoteOption {
@@ -231,24 +260,31 @@ impl VoteOption {
 }
 /// ProposalStatus enumerates the valid statuses of a proposal.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
 #[repr(i32)]
 pub enum ProposalStatus {
     /// PROPOSAL_STATUS_UNSPECIFIED defines the default proposal status.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_STATUS_UNSPECIFIED"))]
     Unspecified = 0,
     /// PROPOSAL_STATUS_DEPOSIT_PERIOD defines a proposal status during the deposit
     /// period.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_STATUS_DEPOSIT_PERIOD"))]
     DepositPeriod = 1,
     /// PROPOSAL_STATUS_VOTING_PERIOD defines a proposal status during the voting
     /// period.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_STATUS_VOTING_PERIOD"))]
     VotingPeriod = 2,
     /// PROPOSAL_STATUS_PASSED defines a proposal status of a proposal that has
     /// passed.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_STATUS_PASSED"))]
     Passed = 3,
     /// PROPOSAL_STATUS_REJECTED defines a proposal status of a proposal that has
     /// been rejected.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_STATUS_REJECTED"))]
     Rejected = 4,
     /// PROPOSAL_STATUS_FAILED defines a proposal status of a proposal that has
     /// failed.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_STATUS_FAILED"))]
     Failed = 5,
 }
 impl ProposalStatus {
@@ -282,9 +318,11 @@ impl ProposalStatus {
 /// GenesisState defines the gov module's genesis state.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GenesisState {
     /// starting_proposal_id is the ID of the starting proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub starting_proposal_id: u64,
     /// deposits defines all the deposits present at genesis.
     #[prost(message, repeated, tag="2")]
@@ -319,14 +357,17 @@ pub struct GenesisState {
 /// QueryProposalRequest is the request type for the Query/Proposal RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryProposalRequest {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
 }
 /// QueryProposalResponse is the response type for the Query/Proposal RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryProposalResponse {
     /// proposal is the requested governance proposal.
     #[prost(message, optional, tag="1")]
@@ -335,9 +376,11 @@ pub struct QueryProposalResponse {
 /// QueryProposalsRequest is the request type for the Query/Proposals RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryProposalsRequest {
     /// proposal_status defines the status of the proposals.
     #[prost(enumeration="ProposalStatus", tag="1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<ProposalStatus, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<ProposalStatus, _>"), schemars(with = "ProposalStatus"))]
     pub proposal_status: i32,
     /// voter defines the voter address for the proposals.
     #[prost(string, tag="2")]
@@ -353,6 +396,7 @@ pub struct QueryProposalsRequest {
 /// method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryProposalsResponse {
     /// proposals defines all the requested governance proposals.
     #[prost(message, repeated, tag="1")]
@@ -364,9 +408,11 @@ pub struct QueryProposalsResponse {
 /// QueryVoteRequest is the request type for the Query/Vote RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryVoteRequest {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// voter defines the voter address for the proposals.
     #[prost(string, tag="2")]
@@ -375,6 +421,7 @@ pub struct QueryVoteRequest {
 /// QueryVoteResponse is the response type for the Query/Vote RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryVoteResponse {
     /// vote defines the queried vote.
     #[prost(message, optional, tag="1")]
@@ -383,9 +430,11 @@ pub struct QueryVoteResponse {
 /// QueryVotesRequest is the request type for the Query/Votes RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryVotesRequest {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// pagination defines an optional pagination for the request.
     #[prost(message, optional, tag="2")]
@@ -394,6 +443,7 @@ pub struct QueryVotesRequest {
 /// QueryVotesResponse is the response type for the Query/Votes RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryVotesResponse {
     /// votes defines the queried votes.
     #[prost(message, repeated, tag="1")]
@@ -405,6 +455,7 @@ pub struct QueryVotesResponse {
 /// QueryParamsRequest is the request type for the Query/Params RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryParamsRequest {
     /// params_type defines which parameters to query for, can be one of "voting",
     /// "tallying" or "deposit".
@@ -414,6 +465,7 @@ pub struct QueryParamsRequest {
 /// QueryParamsResponse is the response type for the Query/Params RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryParamsResponse {
     /// Deprecated: Prefer to use `params` instead.
     /// voting_params defines the parameters related to voting.
@@ -439,9 +491,11 @@ pub struct QueryParamsResponse {
 /// QueryDepositRequest is the request type for the Query/Deposit RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryDepositRequest {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// depositor defines the deposit addresses from the proposals.
     #[prost(string, tag="2")]
@@ -450,6 +504,7 @@ pub struct QueryDepositRequest {
 /// QueryDepositResponse is the response type for the Query/Deposit RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryDepositResponse {
     /// deposit defines the requested deposit.
     #[prost(message, optional, tag="1")]
@@ -458,9 +513,11 @@ pub struct QueryDepositResponse {
 /// QueryDepositsRequest is the request type for the Query/Deposits RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryDepositsRequest {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// pagination defines an optional pagination for the request.
     #[prost(message, optional, tag="2")]
@@ -469,6 +526,7 @@ pub struct QueryDepositsRequest {
 /// QueryDepositsResponse is the response type for the Query/Deposits RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryDepositsResponse {
     /// deposits defines the requested deposits.
     #[prost(message, repeated, tag="1")]
@@ -480,14 +538,17 @@ pub struct QueryDepositsResponse {
 /// QueryTallyResultRequest is the request type for the Query/Tally RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryTallyResultRequest {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
 }
 /// QueryTallyResultResponse is the response type for the Query/Tally RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryTallyResultResponse {
     /// tally defines the requested tally.
     #[prost(message, optional, tag="1")]
@@ -497,9 +558,11 @@ pub struct QueryTallyResultResponse {
 /// proposal Content.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgSubmitProposal {
     /// messages are the arbitrary messages to be executed if proposal passes.
     #[prost(message, repeated, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<::serde_json::Value>"))]
     pub messages: ::prost::alloc::vec::Vec<::prost_types::Any>,
     /// initial_deposit is the deposit value that must be paid at proposal submission.
     #[prost(message, repeated, tag="2")]
@@ -524,18 +587,22 @@ pub struct MsgSubmitProposal {
 /// MsgSubmitProposalResponse defines the Msg/SubmitProposal response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgSubmitProposalResponse {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
 }
 /// MsgExecLegacyContent is used to wrap the legacy content field into a message.
 /// This ensures backwards compatibility with v1beta1.MsgSubmitProposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgExecLegacyContent {
     /// content is the proposal's content.
     #[prost(message, optional, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub content: ::core::option::Option<::prost_types::Any>,
     /// authority must be the gov module address.
     #[prost(string, tag="2")]
@@ -544,20 +611,24 @@ pub struct MsgExecLegacyContent {
 /// MsgExecLegacyContentResponse defines the Msg/ExecLegacyContent response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgExecLegacyContentResponse {
 }
 /// MsgVote defines a message to cast a vote.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgVote {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// voter is the voter address for the proposal.
     #[prost(string, tag="2")]
     pub voter: ::prost::alloc::string::String,
     /// option defines the vote option.
     #[prost(enumeration="VoteOption", tag="3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<VoteOption, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<VoteOption, _>"), schemars(with = "VoteOption"))]
     pub option: i32,
     /// metadata is any arbitrary metadata attached to the Vote.
     #[prost(string, tag="4")]
@@ -566,14 +637,17 @@ pub struct MsgVote {
 /// MsgVoteResponse defines the Msg/Vote response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgVoteResponse {
 }
 /// MsgVoteWeighted defines a message to cast a vote.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgVoteWeighted {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// voter is the voter address for the proposal.
     #[prost(string, tag="2")]
@@ -588,14 +662,17 @@ pub struct MsgVoteWeighted {
 /// MsgVoteWeightedResponse defines the Msg/VoteWeighted response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgVoteWeightedResponse {
 }
 /// MsgDeposit defines a message to submit a deposit to an existing proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgDeposit {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// depositor defines the deposit addresses from the proposals.
     #[prost(string, tag="2")]
@@ -607,6 +684,7 @@ pub struct MsgDeposit {
 /// MsgDepositResponse defines the Msg/Deposit response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgDepositResponse {
 }
 /// MsgUpdateParams is the Msg/UpdateParams request type.
@@ -614,6 +692,7 @@ pub struct MsgDepositResponse {
 /// Since: cosmos-sdk 0.47
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateParams {
     /// authority is the address that controls the module (defaults to x/gov unless overwritten).
     #[prost(string, tag="1")]
@@ -630,6 +709,7 @@ pub struct MsgUpdateParams {
 /// Since: cosmos-sdk 0.47
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateParamsResponse {
 }
 // @@protoc_insertion_point(module)
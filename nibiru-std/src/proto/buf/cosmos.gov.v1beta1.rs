@@ -4,9 +4,11 @@
 /// Since: cosmos-sdk 0.43
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct WeightedVoteOption {
     /// option defines the valid vote options, it must not contain duplicate vote options.
     #[prost(enumeration="VoteOption", tag="1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<VoteOption, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<VoteOption, _>"), schemars(with = "VoteOption"))]
     pub option: i32,
     /// weight is the vote weight associated with the vote option. 
     #[prost(string, tag="2")]
@@ -16,6 +18,7 @@ pub struct WeightedVoteOption {
 /// manually updated in case of approval.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TextProposal {
     /// title of the proposal.
     #[prost(string, tag="1")]
@@ -28,9 +31,11 @@ pub struct TextProposal {
 /// proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Deposit {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// depositor defines the deposit addresses from the proposals.
     #[prost(string, tag="2")]
@@ -42,15 +47,19 @@ pub struct Deposit {
 /// Proposal defines the core field members of a governance proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Proposal {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// content is the proposal's content.
     #[prost(message, optional, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub content: ::core::option::Option<::prost_types::Any>,
     /// status defines the proposal status.
     #[prost(enumeration="ProposalStatus", tag="3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<ProposalStatus, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<ProposalStatus, _>"), schemars(with = "ProposalStatus"))]
     pub status: i32,
     /// final_tally_result is the final tally result of the proposal. When
     /// querying a proposal via gRPC, this field is not populated until the
@@ -59,23 +68,28 @@ pub struct Proposal {
     pub final_tally_result: ::core::option::Option<TallyResult>,
     /// submit_time is the time of proposal submission.
     #[prost(message, optional, tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub submit_time: ::core::option::Option<::prost_types::Timestamp>,
     /// deposit_end_time is the end time for deposition.
     #[prost(message, optional, tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub deposit_end_time: ::core::option::Option<::prost_types::Timestamp>,
     /// total_deposit is the total deposit on the proposal.
     #[prost(message, repeated, tag="7")]
     pub total_deposit: ::prost::alloc::vec::Vec<crate::proto::cosmos::base::v1beta1::Coin>,
     /// voting_start_time is the starting time to vote on a proposal.
     #[prost(message, optional, tag="8")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub voting_start_time: ::core::option::Option<::prost_types::Timestamp>,
     /// voting_end_time is the end time of voting on a proposal.
     #[prost(message, optional, tag="9")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub voting_end_time: ::core::option::Option<::prost_types::Timestamp>,
 }
 /// TallyResult defines a standard tally for a governance proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TallyResult {
     /// yes is the number of yes votes on a proposal.
     #[prost(string, tag="1")]
@@ -94,9 +108,11 @@ pub struct TallyResult {
 /// A Vote consists of a proposal ID, the voter, and the vote option.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Vote {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// voter is the voter address of the proposal.
     #[prost(string, tag="2")]
@@ -106,6 +122,7 @@ pub struct Vote {
     /// other cases, this field will default to VOTE_OPTION_UNSPECIFIED.
     #[deprecated]
     #[prost(enumeration="VoteOption", tag="3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<VoteOption, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<VoteOption, _>"), schemars(with = "VoteOption"))]
     pub option: i32,
     /// options is the weighted vote options.
     ///
@@ -116,6 +133,7 @@ pub struct Vote {
 /// DepositParams defines the params for deposits on governance proposals.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct DepositParams {
     /// Minimum deposit for a proposal to enter voting period.
     #[prost(message, repeated, tag="1")]
@@ -123,45 +141,58 @@ pub struct DepositParams {
     /// Maximum period for Atom holders to deposit on a proposal. Initial value: 2
     /// months.
     #[prost(message, optional, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub max_deposit_period: ::core::option::Option<::prost_types::Duration>,
 }
 /// VotingParams defines the params for voting on governance proposals.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct VotingParams {
     /// Duration of the voting period.
     #[prost(message, optional, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub voting_period: ::core::option::Option<::prost_types::Duration>,
 }
 /// TallyParams defines the params for tallying votes on governance proposals.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TallyParams {
     /// Minimum percentage of total stake needed to vote for a result to be
     /// considered valid.
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub quorum: ::prost::bytes::Bytes,
     /// Minimum proportion of Yes votes for proposal to pass. Default value: 0.5.
     #[prost(bytes="bytes", tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub threshold: ::prost::bytes::Bytes,
     /// Minimum value of Veto votes to Total votes ratio for proposal to be
     /// vetoed. Default value: 1/3.
     #[prost(bytes="bytes", tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub veto_threshold: ::prost::bytes::Bytes,
 }
 /// VoteOption enumerates the valid vote options for a given governance proposal.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
 #[repr(i32)]
 pub enum VoteOption {
     /// VOTE_OPTION_UNSPECIFIED defines a no-op vote option.
+    #[cfg_attr(feature = "serde", serde(rename = "VOTE_OPTION_UNSPECIFIED"))]
     Unspecified = 0,
     /// VOTE_OPTION_YES defines a yes vote option.
+    #[cfg_attr(feature = "serde", serde(rename = "VOTE_OPTION_YES"))]
     Yes = 1,
     /// VOTE_OPTION_ABSTAIN defines an abstain vote option.
+    #[cfg_attr(feature = "serde", serde(rename = "VOTE_OPTION_ABSTAIN"))]
     Abstain = 2,
     /// VOTE_OPTION_NO defines a no vote option.
+    #[cfg_attr(feature = "serde", serde(rename = "VOTE_OPTION_NO"))]
     No = 3,
     /// VOTE_OPTION_NO_WITH_VETO defines a no with veto vote option.
+    #[cfg_attr(feature = "serde", serde(rename = "VOTE_OPTION_NO_WITH_VETO"))]
     NoWithVeto = 4,
 }
 impl VoteOption {
@@ -192,24 +223,31 @@ impl VoteOption {
 }
 /// ProposalStatus enumerates the valid statuses of a proposal.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
 #[repr(i32)]
 pub enum ProposalStatus {
     /// PROPOSAL_STATUS_UNSPECIFIED defines the default proposal status.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_STATUS_UNSPECIFIED"))]
     Unspecified = 0,
     /// PROPOSAL_STATUS_DEPOSIT_PERIOD defines a proposal status during the deposit
     /// period.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_STATUS_DEPOSIT_PERIOD"))]
     DepositPeriod = 1,
     /// PROPOSAL_STATUS_VOTING_PERIOD defines a proposal status during the voting
     /// period.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_STATUS_VOTING_PERIOD"))]
     VotingPeriod = 2,
     /// PROPOSAL_STATUS_PASSED defines a proposal status of a proposal that has
     /// passed.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_STATUS_PASSED"))]
     Passed = 3,
     /// PROPOSAL_STATUS_REJECTED defines a proposal status of a proposal that has
     /// been rejected.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_STATUS_REJECTED"))]
     Rejected = 4,
     /// PROPOSAL_STATUS_FAILED defines a proposal status of a proposal that has
     /// failed.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_STATUS_FAILED"))]
     Failed = 5,
 }
 impl ProposalStatus {
@@ -243,9 +281,11 @@ impl ProposalStatus {
 /// GenesisState defines the gov module's genesis state.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GenesisState {
     /// starting_proposal_id is the ID of the starting proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub starting_proposal_id: u64,
     /// deposits defines all the deposits present at genesis.
     #[prost(message, repeated, tag="2")]
@@ -269,14 +309,17 @@ pub struct GenesisState {
 /// QueryProposalRequest is the request type for the Query/Proposal RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryProposalRequest {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
 }
 /// QueryProposalResponse is the response type for the Query/Proposal RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryProposalResponse {
     #[prost(message, optional, tag="1")]
     pub proposal: ::core::option::Option<Proposal>,
@@ -284,9 +327,11 @@ pub struct QueryProposalResponse {
 /// QueryProposalsRequest is the request type for the Query/Proposals RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryProposalsRequest {
     /// proposal_status defines the status of the proposals.
     #[prost(enumeration="ProposalStatus", tag="1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<ProposalStatus, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<ProposalStatus, _>"), schemars(with = "ProposalStatus"))]
     pub proposal_status: i32,
     /// voter defines the voter address for the proposals.
     #[prost(string, tag="2")]
@@ -302,6 +347,7 @@ pub struct QueryProposalsRequest {
 /// method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryProposalsResponse {
     /// proposals defines all the requested governance proposals.
     #[prost(message, repeated, tag="1")]
@@ -313,9 +359,11 @@ pub struct QueryProposalsResponse {
 /// QueryVoteRequest is the request type for the Query/Vote RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryVoteRequest {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// voter defines the voter address for the proposals.
     #[prost(string, tag="2")]
@@ -324,6 +372,7 @@ pub struct QueryVoteRequest {
 /// QueryVoteResponse is the response type for the Query/Vote RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryVoteResponse {
     /// vote defines the queried vote.
     #[prost(message, optional, tag="1")]
@@ -332,9 +381,11 @@ pub struct QueryVoteResponse {
 /// QueryVotesRequest is the request type for the Query/Votes RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryVotesRequest {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// pagination defines an optional pagination for the request.
     #[prost(message, optional, tag="2")]
@@ -343,6 +394,7 @@ pub struct QueryVotesRequest {
 /// QueryVotesResponse is the response type for the Query/Votes RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryVotesResponse {
     /// votes defines the queried votes.
     #[prost(message, repeated, tag="1")]
@@ -354,6 +406,7 @@ pub struct QueryVotesResponse {
 /// QueryParamsRequest is the request type for the Query/Params RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryParamsRequest {
     /// params_type defines which parameters to query for, can be one of "voting",
     /// "tallying" or "deposit".
@@ -363,6 +416,7 @@ pub struct QueryParamsRequest {
 /// QueryParamsResponse is the response type for the Query/Params RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryParamsResponse {
     /// voting_params defines the parameters related to voting.
     #[prost(message, optional, tag="1")]
@@ -377,9 +431,11 @@ pub struct QueryParamsResponse {
 /// QueryDepositRequest is the request type for the Query/Deposit RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryDepositRequest {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// depositor defines the deposit addresses from the proposals.
     #[prost(string, tag="2")]
@@ -388,6 +444,7 @@ pub struct QueryDepositRequest {
 /// QueryDepositResponse is the response type for the Query/Deposit RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryDepositResponse {
     /// deposit defines the requested deposit.
     #[prost(message, optional, tag="1")]
@@ -396,9 +453,11 @@ pub struct QueryDepositResponse {
 /// QueryDepositsRequest is the request type for the Query/Deposits RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryDepositsRequest {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// pagination defines an optional pagination for the request.
     #[prost(message, optional, tag="2")]
@@ -407,6 +466,7 @@ pub struct QueryDepositsRequest {
 /// QueryDepositsResponse is the response type for the Query/Deposits RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryDepositsResponse {
     /// deposits defines the requested deposits.
     #[prost(message, repeated, tag="1")]
@@ -418,14 +478,17 @@ pub struct QueryDepositsResponse {
 /// QueryTallyResultRequest is the request type for the Query/Tally RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryTallyResultRequest {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
 }
 /// QueryTallyResultResponse is the response type for the Query/Tally RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryTallyResultResponse {
     /// tally defines the requested tally.
     #[prost(message, optional, tag="1")]
@@ -435,9 +498,11 @@ pub struct QueryTallyResultResponse {
 /// proposal Content.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgSubmitProposal {
     /// content is the proposal's content.
     #[prost(message, optional, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub content: ::core::option::Option<::prost_types::Any>,
     /// initial_deposit is the deposit value that must be paid at proposal submission.
     #[prost(message, repeated, tag="2")]
@@ -449,28 +514,34 @@ pub struct MsgSubmitProposal {
 /// MsgSubmitProposalResponse defines the Msg/SubmitProposal response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgSubmitProposalResponse {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
 }
 /// MsgVote defines a message to cast a vote.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgVote {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// voter is the voter address for the proposal.
     #[prost(string, tag="2")]
     pub voter: ::prost::alloc::string::String,
     /// option defines the vote option.
     #[prost(enumeration="VoteOption", tag="3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<VoteOption, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<VoteOption, _>"), schemars(with = "VoteOption"))]
     pub option: i32,
 }
 /// MsgVoteResponse defines the Msg/Vote response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgVoteResponse {
 }
 /// MsgVoteWeighted defines a message to cast a vote.
@@ -478,9 +549,11 @@ pub struct MsgVoteResponse {
 /// Since: cosmos-sdk 0.43
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgVoteWeighted {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// voter is the voter address for the proposal.
     #[prost(string, tag="2")]
@@ -494,14 +567,17 @@ pub struct MsgVoteWeighted {
 /// Since: cosmos-sdk 0.43
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgVoteWeightedResponse {
 }
 /// MsgDeposit defines a message to submit a deposit to an existing proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgDeposit {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// depositor defines the deposit addresses from the proposals.
     #[prost(string, tag="2")]
@@ -513,6 +589,7 @@ pub struct MsgDeposit {
 /// MsgDepositResponse defines the Msg/Deposit response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgDepositResponse {
 }
 // @@protoc_insertion_point(module)
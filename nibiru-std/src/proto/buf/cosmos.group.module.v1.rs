@@ -2,14 +2,17 @@
 /// Module is the config object of the group module.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Module {
     /// max_execution_period defines the max duration after a proposal's voting period ends that members can send a MsgExec
     /// to execute the proposal.
     #[prost(message, optional, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub max_execution_period: ::core::option::Option<::prost_types::Duration>,
     /// max_metadata_len defines the max length of the metadata bytes field for various entities within the group module.
     /// Defaults to 255 if not explicitly set.
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub max_metadata_len: u64,
 }
 // @@protoc_insertion_point(module)
@@ -3,6 +3,7 @@
 /// non-zero weight, metadata and added_at timestamp.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Member {
     /// address is the member's account address.
     #[prost(string, tag="1")]
@@ -15,6 +16,7 @@ pub struct Member {
     pub metadata: ::prost::alloc::string::String,
     /// added_at is a timestamp specifying when a member was added.
     #[prost(message, optional, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub added_at: ::core::option::Option<::prost_types::Timestamp>,
 }
 /// MemberRequest represents a group member to be used in Msg server requests.
@@ -22,6 +24,7 @@ pub struct Member {
 /// since this field cannot be set as part of requests.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MemberRequest {
     /// address is the member's account address.
     #[prost(string, tag="1")]
@@ -41,6 +44,7 @@ pub struct MemberRequest {
 ///     given by `windows`.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ThresholdDecisionPolicy {
     /// threshold is the minimum weighted sum of `YES` votes that must be met or
     /// exceeded for a proposal to succeed.
@@ -58,6 +62,7 @@ pub struct ThresholdDecisionPolicy {
 ///     given by `windows`.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct PercentageDecisionPolicy {
     /// percentage is the minimum percentage of the weighted sum of `YES` votes must
     /// meet for a proposal to succeed.
@@ -70,15 +75,17 @@ pub struct PercentageDecisionPolicy {
 /// DecisionPolicyWindows defines the different windows for voting and execution.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct DecisionPolicyWindows {
     /// voting_period is the duration from submission of a proposal to the end of voting period
     /// Within this times votes can be submitted with MsgVote.
     #[prost(message, optional, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub voting_period: ::core::option::Option<::prost_types::Duration>,
     /// min_execution_period is the minimum duration after the proposal submission
     /// where members can start sending MsgExec. This means that the window for
     /// sending a MsgExec transaction is:
-    /// `[ submission + min_execution_period ; submission + voting_period + max_execution_period]`
+    /// `\[ submission + min_execution_period ; submission + voting_period + max_execution_period\]`
     /// where max_execution_period is a app-specific config, defined in the keeper.
     /// If not set, min_execution_period will default to 0.
     ///
@@ -87,6 +94,7 @@ pub struct DecisionPolicyWindows {
     /// is empty, meaning that all proposals created with this decision policy
     /// won't be able to be executed.
     #[prost(message, optional, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub min_execution_period: ::core::option::Option<::prost_types::Duration>,
 }
 //
@@ -96,9 +104,11 @@ pub struct DecisionPolicyWindows {
 /// GroupInfo represents the high-level on-chain information for a group.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GroupInfo {
     /// id is the unique ID of the group.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub id: u64,
     /// admin is the account address of the group's admin.
     #[prost(string, tag="2")]
@@ -111,20 +121,24 @@ pub struct GroupInfo {
     /// or any member is added or removed this version is incremented and will
     /// cause proposals based on older versions of this group to fail
     #[prost(uint64, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub version: u64,
     /// total_weight is the sum of the group members' weights.
     #[prost(string, tag="5")]
     pub total_weight: ::prost::alloc::string::String,
     /// created_at is a timestamp specifying when a group was created.
     #[prost(message, optional, tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub created_at: ::core::option::Option<::prost_types::Timestamp>,
 }
 /// GroupMember represents the relationship between a group and a member.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GroupMember {
     /// group_id is the unique ID of the group.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_id: u64,
     /// member is the member data.
     #[prost(message, optional, tag="2")]
@@ -133,12 +147,14 @@ pub struct GroupMember {
 /// GroupPolicyInfo represents the high-level on-chain information for a group policy.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GroupPolicyInfo {
     /// address is the account address of group policy.
     #[prost(string, tag="1")]
     pub address: ::prost::alloc::string::String,
     /// group_id is the unique ID of the group.
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_id: u64,
     /// admin is the account address of the group admin.
     #[prost(string, tag="3")]
@@ -151,12 +167,15 @@ pub struct GroupPolicyInfo {
     /// version is used to track changes to a group's GroupPolicyInfo structure that
     /// would create a different result on a running proposal.
     #[prost(uint64, tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub version: u64,
     /// decision_policy specifies the group policy's decision policy.
     #[prost(message, optional, tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub decision_policy: ::core::option::Option<::prost_types::Any>,
     /// created_at is a timestamp specifying when a group policy was created.
     #[prost(message, optional, tag="7")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub created_at: ::core::option::Option<::prost_types::Timestamp>,
 }
 /// Proposal defines a group proposal. Any member of a group can submit a proposal
@@ -165,9 +184,11 @@ pub struct GroupPolicyInfo {
 /// passes as well as some optional metadata associated with the proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Proposal {
     /// id is the unique id of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub id: u64,
     /// group_policy_address is the account address of group policy.
     #[prost(string, tag="2")]
@@ -182,19 +203,23 @@ pub struct Proposal {
     pub proposers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     /// submit_time is a timestamp specifying when a proposal was submitted.
     #[prost(message, optional, tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub submit_time: ::core::option::Option<::prost_types::Timestamp>,
     /// group_version tracks the version of the group at proposal submission.
     /// This field is here for informational purposes only.
     #[prost(uint64, tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_version: u64,
     /// group_policy_version tracks the version of the group policy at proposal submission.
     /// When a decision policy is changed, existing proposals from previous policy
     /// versions will become invalid with the `ABORTED` status.
     /// This field is here for informational purposes only.
     #[prost(uint64, tag="7")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_policy_version: u64,
     /// status represents the high level position in the life cycle of the proposal. Initial value is Submitted.
     #[prost(enumeration="ProposalStatus", tag="8")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<ProposalStatus, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<ProposalStatus, _>"), schemars(with = "ProposalStatus"))]
     pub status: i32,
     /// final_tally_result contains the sums of all weighted votes for this
     /// proposal for each vote option. It is empty at submission, and only
@@ -208,12 +233,15 @@ pub struct Proposal {
     /// at this point, and the `final_tally_result`and `status` fields will be
     /// accordingly updated.
     #[prost(message, optional, tag="10")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub voting_period_end: ::core::option::Option<::prost_types::Timestamp>,
     /// executor_result is the final result of the proposal execution. Initial value is NotRun.
     #[prost(enumeration="ProposalExecutorResult", tag="11")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<ProposalExecutorResult, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<ProposalExecutorResult, _>"), schemars(with = "ProposalExecutorResult"))]
     pub executor_result: i32,
     /// messages is a list of `sdk.Msg`s that will be executed if the proposal passes.
     #[prost(message, repeated, tag="12")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<::serde_json::Value>"))]
     pub messages: ::prost::alloc::vec::Vec<::prost_types::Any>,
     /// title is the title of the proposal
     ///
@@ -229,6 +257,7 @@ pub struct Proposal {
 /// TallyResult represents the sum of weighted votes for each vote option.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TallyResult {
     /// yes_count is the weighted sum of yes votes.
     #[prost(string, tag="1")]
@@ -246,37 +275,47 @@ pub struct TallyResult {
 /// Vote represents a vote for a proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Vote {
     /// proposal is the unique ID of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// voter is the account address of the voter.
     #[prost(string, tag="2")]
     pub voter: ::prost::alloc::string::String,
     /// option is the voter's choice on the proposal.
     #[prost(enumeration="VoteOption", tag="3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<VoteOption, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<VoteOption, _>"), schemars(with = "VoteOption"))]
     pub option: i32,
     /// metadata is any arbitrary metadata attached to the vote.
     #[prost(string, tag="4")]
     pub metadata: ::prost::alloc::string::String,
     /// submit_time is the timestamp when the vote was submitted.
     #[prost(message, optional, tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub submit_time: ::core::option::Option<::prost_types::Timestamp>,
 }
 /// VoteOption enumerates the valid vote options for a given proposal.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
 #[repr(i32)]
 pub enum VoteOption {
     /// VOTE_OPTION_UNSPECIFIED defines an unspecified vote option which will
     /// return an error.
+    #[cfg_attr(feature = "serde", serde(rename = "VOTE_OPTION_UNSPECIFIED"))]
     Unspecified = 0,
     /// VOTE_OPTION_YES defines a yes vote option.
+    #[cfg_attr(feature = "serde", serde(rename = "VOTE_OPTION_YES"))]
     Yes = 1,
     /// VOTE_OPTION_ABSTAIN defines an abstain vote option.
+    #[cfg_attr(feature = "serde", serde(rename = "VOTE_OPTION_ABSTAIN"))]
     Abstain = 2,
     /// VOTE_OPTION_NO defines a no vote option.
+    #[cfg_attr(feature = "serde", serde(rename = "VOTE_OPTION_NO"))]
     No = 3,
     /// VOTE_OPTION_NO_WITH_VETO defines a no with veto vote option.
+    #[cfg_attr(feature = "serde", serde(rename = "VOTE_OPTION_NO_WITH_VETO"))]
     NoWithVeto = 4,
 }
 impl VoteOption {
@@ -307,23 +346,30 @@ impl VoteOption {
 }
 /// ProposalStatus defines proposal statuses.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
 #[repr(i32)]
 pub enum ProposalStatus {
     /// An empty value is invalid and not allowed.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_STATUS_UNSPECIFIED"))]
     Unspecified = 0,
     /// Initial status of a proposal when submitted.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_STATUS_SUBMITTED"))]
     Submitted = 1,
     /// Final status of a proposal when the final tally is done and the outcome
     /// passes the group policy's decision policy.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_STATUS_ACCEPTED"))]
     Accepted = 2,
     /// Final status of a proposal when the final tally is done and the outcome
     /// is rejected by the group policy's decision policy.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_STATUS_REJECTED"))]
     Rejected = 3,
     /// Final status of a proposal when the group policy is modified before the
     /// final tally.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_STATUS_ABORTED"))]
     Aborted = 4,
     /// A proposal can be withdrawn before the voting start time by the owner.
     /// When this happens the final status is Withdrawn.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_STATUS_WITHDRAWN"))]
     Withdrawn = 5,
 }
 impl ProposalStatus {
@@ -356,15 +402,20 @@ impl ProposalStatus {
 }
 /// ProposalExecutorResult defines types of proposal executor results.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
 #[repr(i32)]
 pub enum ProposalExecutorResult {
     /// An empty value is not allowed.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_EXECUTOR_RESULT_UNSPECIFIED"))]
     Unspecified = 0,
     /// We have not yet run the executor.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_EXECUTOR_RESULT_NOT_RUN"))]
     NotRun = 1,
     /// The executor was successful and proposed action updated state.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_EXECUTOR_RESULT_SUCCESS"))]
     Success = 2,
     /// The executor returned an error and proposed action didn't update state.
+    #[cfg_attr(feature = "serde", serde(rename = "PROPOSAL_EXECUTOR_RESULT_FAILURE"))]
     Failure = 3,
 }
 impl ProposalExecutorResult {
@@ -394,22 +445,27 @@ impl ProposalExecutorResult {
 /// EventCreateGroup is an event emitted when a group is created.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventCreateGroup {
     /// group_id is the unique ID of the group.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_id: u64,
 }
 /// EventUpdateGroup is an event emitted when a group is updated.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventUpdateGroup {
     /// group_id is the unique ID of the group.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_id: u64,
 }
 /// EventCreateGroupPolicy is an event emitted when a group policy is created.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventCreateGroupPolicy {
     /// address is the account address of the group policy.
     #[prost(string, tag="1")]
@@ -418,6 +474,7 @@ pub struct EventCreateGroupPolicy {
 /// EventUpdateGroupPolicy is an event emitted when a group policy is updated.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventUpdateGroupPolicy {
     /// address is the account address of the group policy.
     #[prost(string, tag="1")]
@@ -426,36 +483,45 @@ pub struct EventUpdateGroupPolicy {
 /// EventSubmitProposal is an event emitted when a proposal is created.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventSubmitProposal {
     /// proposal_id is the unique ID of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
 }
 /// EventWithdrawProposal is an event emitted when a proposal is withdrawn.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventWithdrawProposal {
     /// proposal_id is the unique ID of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
 }
 /// EventVote is an event emitted when a voter votes on a proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventVote {
     /// proposal_id is the unique ID of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
 }
 /// EventExec is an event emitted when a proposal is executed.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventExec {
     /// proposal_id is the unique ID of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// result is the proposal execution result.
     #[prost(enumeration="ProposalExecutorResult", tag="2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<ProposalExecutorResult, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<ProposalExecutorResult, _>"), schemars(with = "ProposalExecutorResult"))]
     pub result: i32,
     /// logs contains error logs in case the execution result is FAILURE.
     #[prost(string, tag="3")]
@@ -464,9 +530,11 @@ pub struct EventExec {
 /// EventLeaveGroup is an event emitted when group member leaves the group.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventLeaveGroup {
     /// group_id is the unique ID of the group.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_id: u64,
     /// address is the account address of the group member.
     #[prost(string, tag="2")]
@@ -475,12 +543,15 @@ pub struct EventLeaveGroup {
 /// EventProposalPruned is an event emitted when a proposal is pruned.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventProposalPruned {
     /// proposal_id is the unique ID of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// status is the proposal status (UNSPECIFIED, SUBMITTED, ACCEPTED, REJECTED, ABORTED, WITHDRAWN).
     #[prost(enumeration="ProposalStatus", tag="2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<ProposalStatus, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<ProposalStatus, _>"), schemars(with = "ProposalStatus"))]
     pub status: i32,
     /// tally_result is the proposal tally result (when applicable).
     #[prost(message, optional, tag="3")]
@@ -489,10 +560,12 @@ pub struct EventProposalPruned {
 /// GenesisState defines the group module's genesis state.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GenesisState {
     /// group_seq is the group table orm.Sequence,
     /// it is used to get the next group ID.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_seq: u64,
     /// groups is the list of groups info.
     #[prost(message, repeated, tag="2")]
@@ -503,6 +576,7 @@ pub struct GenesisState {
     /// group_policy_seq is the group policy table orm.Sequence,
     /// it is used to generate the next group policy account address.
     #[prost(uint64, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_policy_seq: u64,
     /// group_policies is the list of group policies info.
     #[prost(message, repeated, tag="5")]
@@ -510,6 +584,7 @@ pub struct GenesisState {
     /// proposal_seq is the proposal table orm.Sequence,
     /// it is used to get the next proposal ID.
     #[prost(uint64, tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_seq: u64,
     /// proposals is the list of proposals.
     #[prost(message, repeated, tag="7")]
@@ -521,14 +596,17 @@ pub struct GenesisState {
 /// QueryGroupInfoRequest is the Query/GroupInfo request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGroupInfoRequest {
     /// group_id is the unique ID of the group.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_id: u64,
 }
 /// QueryGroupInfoResponse is the Query/GroupInfo response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGroupInfoResponse {
     /// info is the GroupInfo of the group.
     #[prost(message, optional, tag="1")]
@@ -537,6 +615,7 @@ pub struct QueryGroupInfoResponse {
 /// QueryGroupPolicyInfoRequest is the Query/GroupPolicyInfo request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGroupPolicyInfoRequest {
     /// address is the account address of the group policy.
     #[prost(string, tag="1")]
@@ -545,6 +624,7 @@ pub struct QueryGroupPolicyInfoRequest {
 /// QueryGroupPolicyInfoResponse is the Query/GroupPolicyInfo response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGroupPolicyInfoResponse {
     /// info is the GroupPolicyInfo of the group policy.
     #[prost(message, optional, tag="1")]
@@ -553,9 +633,11 @@ pub struct QueryGroupPolicyInfoResponse {
 /// QueryGroupMembersRequest is the Query/GroupMembers request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGroupMembersRequest {
     /// group_id is the unique ID of the group.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_id: u64,
     /// pagination defines an optional pagination for the request.
     #[prost(message, optional, tag="2")]
@@ -564,6 +646,7 @@ pub struct QueryGroupMembersRequest {
 /// QueryGroupMembersResponse is the Query/GroupMembersResponse response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGroupMembersResponse {
     /// members are the members of the group with given group_id.
     #[prost(message, repeated, tag="1")]
@@ -575,6 +658,7 @@ pub struct QueryGroupMembersResponse {
 /// QueryGroupsByAdminRequest is the Query/GroupsByAdmin request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGroupsByAdminRequest {
     /// admin is the account address of a group's admin.
     #[prost(string, tag="1")]
@@ -586,6 +670,7 @@ pub struct QueryGroupsByAdminRequest {
 /// QueryGroupsByAdminResponse is the Query/GroupsByAdminResponse response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGroupsByAdminResponse {
     /// groups are the groups info with the provided admin.
     #[prost(message, repeated, tag="1")]
@@ -597,9 +682,11 @@ pub struct QueryGroupsByAdminResponse {
 /// QueryGroupPoliciesByGroupRequest is the Query/GroupPoliciesByGroup request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGroupPoliciesByGroupRequest {
     /// group_id is the unique ID of the group policy's group.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_id: u64,
     /// pagination defines an optional pagination for the request.
     #[prost(message, optional, tag="2")]
@@ -608,6 +695,7 @@ pub struct QueryGroupPoliciesByGroupRequest {
 /// QueryGroupPoliciesByGroupResponse is the Query/GroupPoliciesByGroup response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGroupPoliciesByGroupResponse {
     /// group_policies are the group policies info associated with the provided group.
     #[prost(message, repeated, tag="1")]
@@ -619,6 +707,7 @@ pub struct QueryGroupPoliciesByGroupResponse {
 /// QueryGroupPoliciesByAdminRequest is the Query/GroupPoliciesByAdmin request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGroupPoliciesByAdminRequest {
     /// admin is the admin address of the group policy.
     #[prost(string, tag="1")]
@@ -630,6 +719,7 @@ pub struct QueryGroupPoliciesByAdminRequest {
 /// QueryGroupPoliciesByAdminResponse is the Query/GroupPoliciesByAdmin response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGroupPoliciesByAdminResponse {
     /// group_policies are the group policies info with provided admin.
     #[prost(message, repeated, tag="1")]
@@ -641,14 +731,17 @@ pub struct QueryGroupPoliciesByAdminResponse {
 /// QueryProposalRequest is the Query/Proposal request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryProposalRequest {
     /// proposal_id is the unique ID of a proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
 }
 /// QueryProposalResponse is the Query/Proposal response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryProposalResponse {
     /// proposal is the proposal info.
     #[prost(message, optional, tag="1")]
@@ -657,6 +750,7 @@ pub struct QueryProposalResponse {
 /// QueryProposalsByGroupPolicyRequest is the Query/ProposalByGroupPolicy request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryProposalsByGroupPolicyRequest {
     /// address is the account address of the group policy related to proposals.
     #[prost(string, tag="1")]
@@ -668,6 +762,7 @@ pub struct QueryProposalsByGroupPolicyRequest {
 /// QueryProposalsByGroupPolicyResponse is the Query/ProposalByGroupPolicy response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryProposalsByGroupPolicyResponse {
     /// proposals are the proposals with given group policy.
     #[prost(message, repeated, tag="1")]
@@ -679,9 +774,11 @@ pub struct QueryProposalsByGroupPolicyResponse {
 /// QueryVoteByProposalVoterRequest is the Query/VoteByProposalVoter request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryVoteByProposalVoterRequest {
     /// proposal_id is the unique ID of a proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// voter is a proposal voter account address.
     #[prost(string, tag="2")]
@@ -690,6 +787,7 @@ pub struct QueryVoteByProposalVoterRequest {
 /// QueryVoteByProposalVoterResponse is the Query/VoteByProposalVoter response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryVoteByProposalVoterResponse {
     /// vote is the vote with given proposal_id and voter.
     #[prost(message, optional, tag="1")]
@@ -698,9 +796,11 @@ pub struct QueryVoteByProposalVoterResponse {
 /// QueryVotesByProposalRequest is the Query/VotesByProposal request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryVotesByProposalRequest {
     /// proposal_id is the unique ID of a proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// pagination defines an optional pagination for the request.
     #[prost(message, optional, tag="2")]
@@ -709,6 +809,7 @@ pub struct QueryVotesByProposalRequest {
 /// QueryVotesByProposalResponse is the Query/VotesByProposal response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryVotesByProposalResponse {
     /// votes are the list of votes for given proposal_id.
     #[prost(message, repeated, tag="1")]
@@ -720,6 +821,7 @@ pub struct QueryVotesByProposalResponse {
 /// QueryVotesByVoterRequest is the Query/VotesByVoter request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryVotesByVoterRequest {
     /// voter is a proposal voter account address.
     #[prost(string, tag="1")]
@@ -731,6 +833,7 @@ pub struct QueryVotesByVoterRequest {
 /// QueryVotesByVoterResponse is the Query/VotesByVoter response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryVotesByVoterResponse {
     /// votes are the list of votes by given voter.
     #[prost(message, repeated, tag="1")]
@@ -742,6 +845,7 @@ pub struct QueryVotesByVoterResponse {
 /// QueryGroupsByMemberRequest is the Query/GroupsByMember request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGroupsByMemberRequest {
     /// address is the group member address.
     #[prost(string, tag="1")]
@@ -753,6 +857,7 @@ pub struct QueryGroupsByMemberRequest {
 /// QueryGroupsByMemberResponse is the Query/GroupsByMember response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGroupsByMemberResponse {
     /// groups are the groups info with the provided group member.
     #[prost(message, repeated, tag="1")]
@@ -764,14 +869,17 @@ pub struct QueryGroupsByMemberResponse {
 /// QueryTallyResultRequest is the Query/TallyResult request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryTallyResultRequest {
     /// proposal_id is the unique id of a proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
 }
 /// QueryTallyResultResponse is the Query/TallyResult response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryTallyResultResponse {
     /// tally defines the requested tally.
     #[prost(message, optional, tag="1")]
@@ -782,6 +890,7 @@ pub struct QueryTallyResultResponse {
 /// Since: cosmos-sdk 0.47.1
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGroupsRequest {
     /// pagination defines an optional pagination for the request.
     #[prost(message, optional, tag="2")]
@@ -792,6 +901,7 @@ pub struct QueryGroupsRequest {
 /// Since: cosmos-sdk 0.47.1
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryGroupsResponse {
     /// `groups` is all the groups present in state.
     #[prost(message, repeated, tag="1")]
@@ -807,6 +917,7 @@ pub struct QueryGroupsResponse {
 /// MsgCreateGroup is the Msg/CreateGroup request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgCreateGroup {
     /// admin is the account address of the group admin.
     #[prost(string, tag="1")]
@@ -821,20 +932,24 @@ pub struct MsgCreateGroup {
 /// MsgCreateGroupResponse is the Msg/CreateGroup response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgCreateGroupResponse {
     /// group_id is the unique ID of the newly created group.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_id: u64,
 }
 /// MsgUpdateGroupMembers is the Msg/UpdateGroupMembers request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateGroupMembers {
     /// admin is the account address of the group admin.
     #[prost(string, tag="1")]
     pub admin: ::prost::alloc::string::String,
     /// group_id is the unique ID of the group.
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_id: u64,
     /// member_updates is the list of members to update,
     /// set weight to 0 to remove a member.
@@ -844,17 +959,20 @@ pub struct MsgUpdateGroupMembers {
 /// MsgUpdateGroupMembersResponse is the Msg/UpdateGroupMembers response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateGroupMembersResponse {
 }
 /// MsgUpdateGroupAdmin is the Msg/UpdateGroupAdmin request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateGroupAdmin {
     /// admin is the current account address of the group admin.
     #[prost(string, tag="1")]
     pub admin: ::prost::alloc::string::String,
     /// group_id is the unique ID of the group.
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_id: u64,
     /// new_admin is the group new admin account address.
     #[prost(string, tag="3")]
@@ -863,17 +981,20 @@ pub struct MsgUpdateGroupAdmin {
 /// MsgUpdateGroupAdminResponse is the Msg/UpdateGroupAdmin response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateGroupAdminResponse {
 }
 /// MsgUpdateGroupMetadata is the Msg/UpdateGroupMetadata request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateGroupMetadata {
     /// admin is the account address of the group admin.
     #[prost(string, tag="1")]
     pub admin: ::prost::alloc::string::String,
     /// group_id is the unique ID of the group.
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_id: u64,
     /// metadata is the updated group's metadata.
     #[prost(string, tag="3")]
@@ -882,6 +1003,7 @@ pub struct MsgUpdateGroupMetadata {
 /// MsgUpdateGroupMetadataResponse is the Msg/UpdateGroupMetadata response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateGroupMetadataResponse {
 }
 //
@@ -891,23 +1013,27 @@ pub struct MsgUpdateGroupMetadataResponse {
 /// MsgCreateGroupPolicy is the Msg/CreateGroupPolicy request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgCreateGroupPolicy {
     /// admin is the account address of the group admin.
     #[prost(string, tag="1")]
     pub admin: ::prost::alloc::string::String,
     /// group_id is the unique ID of the group.
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_id: u64,
     /// metadata is any arbitrary metadata attached to the group policy.
     #[prost(string, tag="3")]
     pub metadata: ::prost::alloc::string::String,
     /// decision_policy specifies the group policy's decision policy.
     #[prost(message, optional, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub decision_policy: ::core::option::Option<::prost_types::Any>,
 }
 /// MsgCreateGroupPolicyResponse is the Msg/CreateGroupPolicy response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgCreateGroupPolicyResponse {
     /// address is the account address of the newly created group policy.
     #[prost(string, tag="1")]
@@ -916,6 +1042,7 @@ pub struct MsgCreateGroupPolicyResponse {
 /// MsgUpdateGroupPolicyAdmin is the Msg/UpdateGroupPolicyAdmin request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateGroupPolicyAdmin {
     /// admin is the account address of the group admin.
     #[prost(string, tag="1")]
@@ -930,11 +1057,13 @@ pub struct MsgUpdateGroupPolicyAdmin {
 /// MsgUpdateGroupPolicyAdminResponse is the Msg/UpdateGroupPolicyAdmin response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateGroupPolicyAdminResponse {
 }
 /// MsgCreateGroupWithPolicy is the Msg/CreateGroupWithPolicy request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgCreateGroupWithPolicy {
     /// admin is the account address of the group and group policy admin.
     #[prost(string, tag="1")]
@@ -954,14 +1083,17 @@ pub struct MsgCreateGroupWithPolicy {
     pub group_policy_as_admin: bool,
     /// decision_policy specifies the group policy's decision policy.
     #[prost(message, optional, tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub decision_policy: ::core::option::Option<::prost_types::Any>,
 }
 /// MsgCreateGroupWithPolicyResponse is the Msg/CreateGroupWithPolicy response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgCreateGroupWithPolicyResponse {
     /// group_id is the unique ID of the newly created group with policy.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_id: u64,
     /// group_policy_address is the account address of the newly created group policy.
     #[prost(string, tag="2")]
@@ -970,6 +1102,7 @@ pub struct MsgCreateGroupWithPolicyResponse {
 /// MsgUpdateGroupPolicyDecisionPolicy is the Msg/UpdateGroupPolicyDecisionPolicy request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateGroupPolicyDecisionPolicy {
     /// admin is the account address of the group admin.
     #[prost(string, tag="1")]
@@ -979,16 +1112,19 @@ pub struct MsgUpdateGroupPolicyDecisionPolicy {
     pub group_policy_address: ::prost::alloc::string::String,
     /// decision_policy is the updated group policy's decision policy.
     #[prost(message, optional, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub decision_policy: ::core::option::Option<::prost_types::Any>,
 }
 /// MsgUpdateGroupPolicyDecisionPolicyResponse is the Msg/UpdateGroupPolicyDecisionPolicy response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateGroupPolicyDecisionPolicyResponse {
 }
 /// MsgUpdateGroupPolicyMetadata is the Msg/UpdateGroupPolicyMetadata request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateGroupPolicyMetadata {
     /// admin is the account address of the group admin.
     #[prost(string, tag="1")]
@@ -1003,11 +1139,13 @@ pub struct MsgUpdateGroupPolicyMetadata {
 /// MsgUpdateGroupPolicyMetadataResponse is the Msg/UpdateGroupPolicyMetadata response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateGroupPolicyMetadataResponse {
 }
 /// MsgSubmitProposal is the Msg/SubmitProposal request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgSubmitProposal {
     /// group_policy_address is the account address of group policy.
     #[prost(string, tag="1")]
@@ -1021,11 +1159,13 @@ pub struct MsgSubmitProposal {
     pub metadata: ::prost::alloc::string::String,
     /// messages is a list of `sdk.Msg`s that will be executed if the proposal passes.
     #[prost(message, repeated, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<::serde_json::Value>"))]
     pub messages: ::prost::alloc::vec::Vec<::prost_types::Any>,
     /// exec defines the mode of execution of the proposal,
     /// whether it should be executed immediately on creation or not.
     /// If so, proposers signatures are considered as Yes votes.
     #[prost(enumeration="Exec", tag="5")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<Exec, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<Exec, _>"), schemars(with = "Exec"))]
     pub exec: i32,
     /// title is the title of the proposal.
     ///
@@ -1041,17 +1181,21 @@ pub struct MsgSubmitProposal {
 /// MsgSubmitProposalResponse is the Msg/SubmitProposal response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgSubmitProposalResponse {
     /// proposal is the unique ID of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
 }
 /// MsgWithdrawProposal is the Msg/WithdrawProposal request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgWithdrawProposal {
     /// proposal is the unique ID of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// address is the admin of the group policy or one of the proposer of the proposal.
     #[prost(string, tag="2")]
@@ -1060,20 +1204,24 @@ pub struct MsgWithdrawProposal {
 /// MsgWithdrawProposalResponse is the Msg/WithdrawProposal response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgWithdrawProposalResponse {
 }
 /// MsgVote is the Msg/Vote request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgVote {
     /// proposal is the unique ID of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// voter is the voter account address.
     #[prost(string, tag="2")]
     pub voter: ::prost::alloc::string::String,
     /// option is the voter's choice on the proposal.
     #[prost(enumeration="VoteOption", tag="3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<VoteOption, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<VoteOption, _>"), schemars(with = "VoteOption"))]
     pub option: i32,
     /// metadata is any arbitrary metadata attached to the vote.
     #[prost(string, tag="4")]
@@ -1081,19 +1229,23 @@ pub struct MsgVote {
     /// exec defines whether the proposal should be executed
     /// immediately after voting or not.
     #[prost(enumeration="Exec", tag="5")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<Exec, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<Exec, _>"), schemars(with = "Exec"))]
     pub exec: i32,
 }
 /// MsgVoteResponse is the Msg/Vote response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgVoteResponse {
 }
 /// MsgExec is the Msg/Exec request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgExec {
     /// proposal is the unique ID of the proposal.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposal_id: u64,
     /// executor is the account address used to execute the proposal.
     #[prost(string, tag="2")]
@@ -1102,25 +1254,30 @@ pub struct MsgExec {
 /// MsgExecResponse is the Msg/Exec request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgExecResponse {
     /// result is the final result of the proposal execution.
     #[prost(enumeration="ProposalExecutorResult", tag="2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<ProposalExecutorResult, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<ProposalExecutorResult, _>"), schemars(with = "ProposalExecutorResult"))]
     pub result: i32,
 }
 /// MsgLeaveGroup is the Msg/LeaveGroup request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgLeaveGroup {
     /// address is the account address of the group member.
     #[prost(string, tag="1")]
     pub address: ::prost::alloc::string::String,
     /// group_id is the unique ID of the group.
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub group_id: u64,
 }
 /// MsgLeaveGroupResponse is the Msg/LeaveGroup response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgLeaveGroupResponse {
 }
 //
@@ -1129,15 +1286,18 @@ pub struct MsgLeaveGroupResponse {
 
 /// Exec defines modes of execution of a proposal on creation or on new vote.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
 #[repr(i32)]
 pub enum Exec {
     /// An empty value means that there should be a separate
     /// MsgExec request for the proposal to execute.
+    #[cfg_attr(feature = "serde", serde(rename = "EXEC_UNSPECIFIED"))]
     Unspecified = 0,
     /// Try to execute the proposal immediately.
     /// If the proposal is not allowed per the DecisionPolicy,
     /// the proposal will still be open and could
     /// be executed at a later point.
+    #[cfg_attr(feature = "serde", serde(rename = "EXEC_TRY"))]
     Try = 1,
 }
 impl Exec {
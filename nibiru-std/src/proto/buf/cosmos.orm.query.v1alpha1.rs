@@ -2,6 +2,7 @@
 /// GetRequest is the Query/Get request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetRequest {
     /// message_name is the fully-qualified message name of the ORM table being queried.
     #[prost(string, tag="1")]
@@ -20,15 +21,18 @@ pub struct GetRequest {
 /// GetResponse is the Query/Get response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetResponse {
     /// result is the result of the get query. If no value is found, the gRPC
     /// status code NOT_FOUND will be returned.
     #[prost(message, optional, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub result: ::core::option::Option<::prost_types::Any>,
 }
 /// ListRequest is the Query/List request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ListRequest {
     /// message_name is the fully-qualified message name of the ORM table being queried.
     #[prost(string, tag="1")]
@@ -44,6 +48,7 @@ pub struct ListRequest {
     /// neither prefix nor range is specified, the query will list all the fields
     /// in the index.
     #[prost(oneof="list_request::Query", tags="3, 4")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub query: ::core::option::Option<list_request::Query>,
 }
 /// Nested message and enum types in `ListRequest`.
@@ -51,6 +56,7 @@ pub mod list_request {
     /// Prefix specifies the arguments to a prefix query.
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub struct Prefix {
         /// values specifies the index values for the prefix query.
         /// It is valid to special a partial prefix with fewer values than
@@ -61,6 +67,7 @@ pub mod list_request {
     /// Range specifies the arguments to a range query.
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub struct Range {
         /// start specifies the starting index values for the range query.
         /// It is valid to provide fewer values than the number of fields in the
@@ -78,6 +85,7 @@ pub mod list_request {
     /// in the index.
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Oneof)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase"), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub enum Query {
         /// prefix defines a prefix query.
         #[prost(message, tag="3")]
@@ -90,9 +98,11 @@ pub mod list_request {
 /// ListResponse is the Query/List response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ListResponse {
     /// results are the results of the query.
     #[prost(message, repeated, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<::serde_json::Value>"))]
     pub results: ::prost::alloc::vec::Vec<::prost_types::Any>,
     /// pagination is the pagination response.
     #[prost(message, optional, tag="5")]
@@ -101,9 +111,11 @@ pub struct ListResponse {
 /// IndexValue represents the value of a field in an ORM index expression.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct IndexValue {
     /// value specifies the index value
     #[prost(oneof="index_value::Value", tags="1, 2, 3, 4, 5, 6, 7, 8")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub value: ::core::option::Option<index_value::Value>,
 }
 /// Nested message and enum types in `IndexValue`.
@@ -111,20 +123,24 @@ pub mod index_value {
     /// value specifies the index value
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Oneof)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase"), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub enum Value {
         /// uint specifies a value for an uint32, fixed32, uint64, or fixed64
         /// index field.
         #[prost(uint64, tag="1")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
         Uint(u64),
         /// int64 specifies a value for an int32, sfixed32, int64, or sfixed64
         /// index field.
         #[prost(int64, tag="2")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
         Int(i64),
         /// str specifies a value for a string index field.
         #[prost(string, tag="3")]
         Str(::prost::alloc::string::String),
         /// bytes specifies a value for a bytes index field.
         #[prost(bytes, tag="4")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
         Bytes(::prost::bytes::Bytes),
         /// enum specifies a value for an enum index field.
         #[prost(string, tag="5")]
@@ -134,9 +150,11 @@ pub mod index_value {
         Bool(bool),
         /// timestamp specifies a value for a timestamp index field.
         #[prost(message, tag="7")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
         Timestamp(::prost_types::Timestamp),
         /// duration specifies a value for a duration index field.
         #[prost(message, tag="8")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
         Duration(::prost_types::Duration),
     }
 }
@@ -2,6 +2,7 @@
 /// TableDescriptor describes an ORM table.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TableDescriptor {
     /// primary_key defines the primary key for the table.
     #[prost(message, optional, tag="1")]
@@ -18,6 +19,7 @@ pub struct TableDescriptor {
 /// PrimaryKeyDescriptor describes a table primary key.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct PrimaryKeyDescriptor {
     /// fields is a comma-separated list of fields in the primary key. Spaces are
     /// not allowed. Supported field types, their encodings, and any applicable constraints
@@ -60,6 +62,7 @@ pub struct PrimaryKeyDescriptor {
 /// PrimaryKeyDescriptor describes a table secondary index.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SecondaryIndexDescriptor {
     /// fields is a comma-separated list of fields in the index. The supported
     /// field types are the same as those for PrimaryKeyDescriptor.fields.
@@ -84,6 +87,7 @@ pub struct SecondaryIndexDescriptor {
 /// TableDescriptor describes an ORM singleton table which has at most one instance.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SingletonDescriptor {
     /// id is a non-zero integer ID that must be unique within the
     /// tables and singletons in this file. It may be deprecated in the future when this
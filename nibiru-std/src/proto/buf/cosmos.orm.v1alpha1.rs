@@ -2,12 +2,14 @@
 /// ModuleSchemaDescriptor describe's a module's ORM schema.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ModuleSchemaDescriptor {
     #[prost(message, repeated, tag="1")]
     pub schema_file: ::prost::alloc::vec::Vec<module_schema_descriptor::FileEntry>,
     /// prefix is an optional prefix that precedes all keys in this module's
     /// store.
     #[prost(bytes="bytes", tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub prefix: ::prost::bytes::Bytes,
 }
 /// Nested message and enum types in `ModuleSchemaDescriptor`.
@@ -15,6 +17,7 @@ pub mod module_schema_descriptor {
     /// FileEntry describes an ORM file used in a module.
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub struct FileEntry {
         /// id is a prefix that will be varint encoded and prepended to all the
         /// table keys specified in the file's tables.
@@ -29,11 +32,13 @@ pub mod module_schema_descriptor {
         /// tables should used. If it is left unspecified, the default KV-storage
         /// of the app will be used.
         #[prost(enumeration="super::StorageType", tag="3")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<super::StorageType, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<super::StorageType, _>"), schemars(with = "super::StorageType"))]
         pub storage_type: i32,
     }
 }
 /// StorageType
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
 #[repr(i32)]
 pub enum StorageType {
     /// STORAGE_TYPE_DEFAULT_UNSPECIFIED indicates the persistent
@@ -41,20 +46,24 @@ pub enum StorageType {
     /// backed commitment storage and indexes and seqs are stored in
     /// fast index storage. Note that the Cosmos SDK before store/v2alpha1
     /// does not support this.
+    #[cfg_attr(feature = "serde", serde(rename = "STORAGE_TYPE_DEFAULT_UNSPECIFIED"))]
     DefaultUnspecified = 0,
     /// STORAGE_TYPE_MEMORY indicates in-memory storage that will be
     /// reloaded every time an app restarts. Tables with this type of storage
     /// will by default be ignored when importing and exporting a module's
     /// state from JSON.
+    #[cfg_attr(feature = "serde", serde(rename = "STORAGE_TYPE_MEMORY"))]
     Memory = 1,
     /// STORAGE_TYPE_TRANSIENT indicates transient storage that is reset
     /// at the end of every block. Tables with this type of storage
     /// will by default be ignored when importing and exporting a module's
     /// state from JSON.
+    #[cfg_attr(feature = "serde", serde(rename = "STORAGE_TYPE_TRANSIENT"))]
     Transient = 2,
     /// STORAGE_TYPE_INDEX indicates persistent storage which is not backed
     /// by a merkle-tree and won't affect the app hash. Note that the Cosmos SDK
     /// before store/v2alpha1 does not support this.
+    #[cfg_attr(feature = "serde", serde(rename = "STORAGE_TYPE_INDEX"))]
     Index = 3,
     /// STORAGE_TYPE_INDEX indicates persistent storage which is backed by
     /// a merkle-tree. With this type of storage, both primary and index keys
@@ -64,6 +73,7 @@ pub enum StorageType {
     /// Cosmos SDK before store/v2alpha1 must specify STORAGE_TYPE_COMMITMENT
     /// instead of STORAGE_TYPE_DEFAULT_UNSPECIFIED or STORAGE_TYPE_INDEX
     /// because this is the only type of persistent storage available.
+    #[cfg_attr(feature = "serde", serde(rename = "STORAGE_TYPE_COMMITMENT"))]
     Commitment = 4,
 }
 impl StorageType {
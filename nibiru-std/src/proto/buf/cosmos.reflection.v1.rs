@@ -2,14 +2,17 @@
 /// FileDescriptorsRequest is the Query/FileDescriptors request type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct FileDescriptorsRequest {
 }
 /// FileDescriptorsResponse is the Query/FileDescriptors response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct FileDescriptorsResponse {
     /// files is the file descriptors.
     #[prost(message, repeated, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<String>"))]
     pub files: ::prost::alloc::vec::Vec<::prost_types::FileDescriptorProto>,
 }
 // @@protoc_insertion_point(module)
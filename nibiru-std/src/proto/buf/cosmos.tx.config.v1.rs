@@ -2,6 +2,7 @@
 /// Config is the config object of the x/auth/tx package.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Config {
     /// skip_ante_handler defines whether the ante handler registration should be skipped in case an app wants to override
     /// this functionality.
@@ -2,6 +2,7 @@
 /// SignatureDescriptors wraps multiple SignatureDescriptor's.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SignatureDescriptors {
     /// signatures are the signature descriptors
     #[prost(message, repeated, tag="1")]
@@ -13,9 +14,11 @@ pub struct SignatureDescriptors {
 /// clients.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SignatureDescriptor {
     /// public_key is the public key of the signer
     #[prost(message, optional, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub public_key: ::core::option::Option<::prost_types::Any>,
     #[prost(message, optional, tag="2")]
     pub data: ::core::option::Option<signature_descriptor::Data>,
@@ -23,6 +26,7 @@ pub struct SignatureDescriptor {
     /// number of committed transactions signed by a given address. It is used to prevent
     /// replay attacks.
     #[prost(uint64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub sequence: u64,
 }
 /// Nested message and enum types in `SignatureDescriptor`.
@@ -30,9 +34,11 @@ pub mod signature_descriptor {
     /// Data represents signature data
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub struct Data {
         /// sum is the oneof that specifies whether this represents single or multi-signature data
         #[prost(oneof="data::Sum", tags="1, 2")]
+        #[cfg_attr(feature = "serde", serde(flatten))]
         pub sum: ::core::option::Option<data::Sum>,
     }
     /// Nested message and enum types in `Data`.
@@ -40,17 +46,21 @@ pub mod signature_descriptor {
         /// Single is the signature data for a single signer
         #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
         pub struct Single {
             /// mode is the signing mode of the single signer
             #[prost(enumeration="super::super::SignMode", tag="1")]
+            #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<super::super::SignMode, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<super::super::SignMode, _>"), schemars(with = "super::super::SignMode"))]
             pub mode: i32,
             /// signature is the raw signature bytes
             #[prost(bytes="bytes", tag="2")]
+            #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
             pub signature: ::prost::bytes::Bytes,
         }
         /// Multi is the signature data for a multisig public key
         #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
         pub struct Multi {
             /// bitarray specifies which keys within the multisig are signing
             #[prost(message, optional, tag="1")]
@@ -62,6 +72,7 @@ pub mod signature_descriptor {
         /// sum is the oneof that specifies whether this represents single or multi-signature data
         #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Oneof)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase"), schemars(crate = "::cosmwasm_schema::schemars"))]
         pub enum Sum {
             /// single represents a single signer
             #[prost(message, tag="1")]
@@ -81,17 +92,21 @@ pub mod signature_descriptor {
 /// to this SignMode enum describing their sign mode so that different
 /// apps have a consistent version of this enum.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
 #[repr(i32)]
 pub enum SignMode {
     /// SIGN_MODE_UNSPECIFIED specifies an unknown signing mode and will be
     /// rejected.
+    #[cfg_attr(feature = "serde", serde(rename = "SIGN_MODE_UNSPECIFIED"))]
     Unspecified = 0,
     /// SIGN_MODE_DIRECT specifies a signing mode which uses SignDoc and is
     /// verified with raw bytes from Tx.
+    #[cfg_attr(feature = "serde", serde(rename = "SIGN_MODE_DIRECT"))]
     Direct = 1,
     /// SIGN_MODE_TEXTUAL is a future signing mode that will verify some
     /// human-readable textual representation on top of the binary representation
     /// from SIGN_MODE_DIRECT. It is currently not supported.
+    #[cfg_attr(feature = "serde", serde(rename = "SIGN_MODE_TEXTUAL"))]
     Textual = 2,
     /// SIGN_MODE_DIRECT_AUX specifies a signing mode which uses
     /// SignDocDirectAux. As opposed to SIGN_MODE_DIRECT, this sign mode does not
@@ -99,9 +114,11 @@ pub enum SignMode {
     /// for adding Tips in transactions.
     ///
     /// Since: cosmos-sdk 0.46
+    #[cfg_attr(feature = "serde", serde(rename = "SIGN_MODE_DIRECT_AUX"))]
     DirectAux = 3,
     /// SIGN_MODE_LEGACY_AMINO_JSON is a backwards compatibility mode which uses
     /// Amino JSON and will be removed in the future.
+    #[cfg_attr(feature = "serde", serde(rename = "SIGN_MODE_LEGACY_AMINO_JSON"))]
     LegacyAminoJson = 127,
     /// SIGN_MODE_EIP_191 specifies the sign mode for EIP 191 signing on the Cosmos
     /// SDK. Ref: <https://eips.ethereum.org/EIPS/eip-191>
@@ -113,6 +130,7 @@ pub enum SignMode {
     /// EIP-191 in the future.
     ///
     /// Since: cosmos-sdk 0.45.2
+    #[cfg_attr(feature = "serde", serde(rename = "SIGN_MODE_EIP_191"))]
     Eip191 = 191,
 }
 impl SignMode {
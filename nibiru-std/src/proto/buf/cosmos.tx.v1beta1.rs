@@ -2,6 +2,7 @@
 /// Tx is the standard type used for broadcasting transactions.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Tx {
     /// body is the processable content of the transaction
     #[prost(message, optional, tag="1")]
@@ -14,6 +15,7 @@ pub struct Tx {
     /// AuthInfo's signer_infos to allow connecting signature meta information like
     /// public key and signing mode by position.
     #[prost(bytes="bytes", repeated, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<String>"))]
     pub signatures: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
 }
 /// TxRaw is a variant of Tx that pins the signer's exact binary representation
@@ -23,32 +25,39 @@ pub struct Tx {
 /// as the transaction ID.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TxRaw {
     /// body_bytes is a protobuf serialization of a TxBody that matches the
     /// representation in SignDoc.
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub body_bytes: ::prost::bytes::Bytes,
     /// auth_info_bytes is a protobuf serialization of an AuthInfo that matches the
     /// representation in SignDoc.
     #[prost(bytes="bytes", tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub auth_info_bytes: ::prost::bytes::Bytes,
     /// signatures is a list of signatures that matches the length and order of
     /// AuthInfo's signer_infos to allow connecting signature meta information like
     /// public key and signing mode by position.
     #[prost(bytes="bytes", repeated, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<String>"))]
     pub signatures: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
 }
 /// SignDoc is the type used for generating sign bytes for SIGN_MODE_DIRECT.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SignDoc {
     /// body_bytes is protobuf serialization of a TxBody that matches the
     /// representation in TxRaw.
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub body_bytes: ::prost::bytes::Bytes,
     /// auth_info_bytes is a protobuf serialization of an AuthInfo that matches the
     /// representation in TxRaw.
     #[prost(bytes="bytes", tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub auth_info_bytes: ::prost::bytes::Bytes,
     /// chain_id is the unique identifier of the chain this transaction targets.
     /// It prevents signed transactions from being used on another chain by an
@@ -57,6 +66,7 @@ pub struct SignDoc {
     pub chain_id: ::prost::alloc::string::String,
     /// account_number is the account number of the account in state
     #[prost(uint64, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub account_number: u64,
 }
 /// SignDocDirectAux is the type used for generating sign bytes for
@@ -65,13 +75,16 @@ pub struct SignDoc {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SignDocDirectAux {
     /// body_bytes is protobuf serialization of a TxBody that matches the
     /// representation in TxRaw.
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub body_bytes: ::prost::bytes::Bytes,
     /// public_key is the public key of the signing account.
     #[prost(message, optional, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub public_key: ::core::option::Option<::prost_types::Any>,
     /// chain_id is the identifier of the chain this transaction targets.
     /// It prevents signed transactions from being used on another chain by an
@@ -80,9 +93,11 @@ pub struct SignDocDirectAux {
     pub chain_id: ::prost::alloc::string::String,
     /// account_number is the account number of the account in state.
     #[prost(uint64, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub account_number: u64,
     /// sequence is the sequence number of the signing account.
     #[prost(uint64, tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub sequence: u64,
     /// Tip is the optional tip used for transactions fees paid in another denom.
     /// It should be left empty if the signer is not the tipper for this
@@ -96,6 +111,7 @@ pub struct SignDocDirectAux {
 /// TxBody is the body of a transaction that all signers sign over.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TxBody {
     /// messages is a list of messages to be executed. The required signers of
     /// those messages define the number and order of elements in AuthInfo's
@@ -105,6 +121,7 @@ pub struct TxBody {
     /// is referred to as the primary signer and pays the fee for the whole
     /// transaction.
     #[prost(message, repeated, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<::serde_json::Value>"))]
     pub messages: ::prost::alloc::vec::Vec<::prost_types::Any>,
     /// memo is any arbitrary note/comment to be added to the transaction.
     /// WARNING: in clients, any publicly exposed text should not be called memo,
@@ -114,22 +131,26 @@ pub struct TxBody {
     /// timeout is the block height after which this transaction will not
     /// be processed by the chain
     #[prost(uint64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub timeout_height: u64,
     /// extension_options are arbitrary options that can be added by chains
     /// when the default options are not sufficient. If any of these are present
     /// and can't be handled, the transaction will be rejected
     #[prost(message, repeated, tag="1023")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<::serde_json::Value>"))]
     pub extension_options: ::prost::alloc::vec::Vec<::prost_types::Any>,
     /// extension_options are arbitrary options that can be added by chains
     /// when the default options are not sufficient. If any of these are present
     /// and can't be handled, they will be ignored
     #[prost(message, repeated, tag="2047")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<::serde_json::Value>"))]
     pub non_critical_extension_options: ::prost::alloc::vec::Vec<::prost_types::Any>,
 }
 /// AuthInfo describes the fee and signer modes that are used to sign a
 /// transaction.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct AuthInfo {
     /// signer_infos defines the signing modes for the required signers. The number
     /// and order of elements must match the required signers from TxBody's
@@ -156,11 +177,13 @@ pub struct AuthInfo {
 /// signer.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SignerInfo {
     /// public_key is the public key of the signer. It is optional for accounts
     /// that already exist in state. If unset, the verifier can use the required \
     /// signer address for this position and lookup the public key.
     #[prost(message, optional, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<::serde_json::Value>"))]
     pub public_key: ::core::option::Option<::prost_types::Any>,
     /// mode_info describes the signing mode of the signer and is a nested
     /// structure to support nested multisig pubkey's
@@ -170,15 +193,18 @@ pub struct SignerInfo {
     /// number of committed transactions signed by a given address. It is used to
     /// prevent replay attacks.
     #[prost(uint64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub sequence: u64,
 }
 /// ModeInfo describes the signing mode of a single or nested multisig signer.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ModeInfo {
     /// sum is the oneof that specifies whether this represents a single or nested
     /// multisig signer
     #[prost(oneof="mode_info::Sum", tags="1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub sum: ::core::option::Option<mode_info::Sum>,
 }
 /// Nested message and enum types in `ModeInfo`.
@@ -188,14 +214,17 @@ pub mod mode_info {
     /// future
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub struct Single {
         /// mode is the signing mode of the single signer
         #[prost(enumeration="crate::proto::cosmos::tx::signing::v1beta1::SignMode", tag="1")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<crate::proto::cosmos::tx::signing::v1beta1::SignMode, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<crate::proto::cosmos::tx::signing::v1beta1::SignMode, _>"), schemars(with = "crate::proto::cosmos::tx::signing::v1beta1::SignMode"))]
         pub mode: i32,
     }
     /// Multi is the mode info for a multisig public key
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub struct Multi {
         /// bitarray specifies which keys within the multisig are signing
         #[prost(message, optional, tag="1")]
@@ -209,6 +238,7 @@ pub mod mode_info {
     /// multisig signer
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Oneof)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase"), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub enum Sum {
         /// single represents a single signer
         #[prost(message, tag="1")]
@@ -223,6 +253,7 @@ pub mod mode_info {
 /// which must be above some miminum to be accepted into the mempool.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Fee {
     /// amount is the amount of coins to be paid as a fee
     #[prost(message, repeated, tag="1")]
@@ -230,6 +261,7 @@ pub struct Fee {
     /// gas_limit is the maximum gas that can be used in transaction processing
     /// before an out of gas error occurs
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub gas_limit: u64,
     /// if unset, the first signer is responsible for paying the fees. If set, the specified account must pay the fees.
     /// the payer must be a tx signer (and thus have signed this field in AuthInfo).
@@ -247,6 +279,7 @@ pub struct Fee {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Tip {
     /// amount is the amount of the tip
     #[prost(message, repeated, tag="1")]
@@ -263,6 +296,7 @@ pub struct Tip {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct AuxSignerData {
     /// address is the bech32-encoded address of the auxiliary signer. If using
     /// AuxSignerData across different chains, the bech32 prefix of the target
@@ -276,15 +310,18 @@ pub struct AuxSignerData {
     pub sign_doc: ::core::option::Option<SignDocDirectAux>,
     /// mode is the signing mode of the single signer.
     #[prost(enumeration="super::signing::v1beta1::SignMode", tag="3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<super::signing::v1beta1::SignMode, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<super::signing::v1beta1::SignMode, _>"), schemars(with = "super::signing::v1beta1::SignMode"))]
     pub mode: i32,
     /// sig is the signature of the sign doc.
     #[prost(bytes="bytes", tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub sig: ::prost::bytes::Bytes,
 }
 /// GetTxsEventRequest is the request type for the Service.TxsByEvents
 /// RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetTxsEventRequest {
     /// events is the list of transaction event type.
     #[prost(string, repeated, tag="1")]
@@ -295,19 +332,23 @@ pub struct GetTxsEventRequest {
     #[prost(message, optional, tag="2")]
     pub pagination: ::core::option::Option<crate::proto::cosmos::base::query::v1beta1::PageRequest>,
     #[prost(enumeration="OrderBy", tag="3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<OrderBy, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<OrderBy, _>"), schemars(with = "OrderBy"))]
     pub order_by: i32,
     /// page is the page number to query, starts at 1. If not provided, will default to first page.
     #[prost(uint64, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub page: u64,
     /// limit is the total number of results to be returned in the result page.
     /// If left empty it will default to a value to be set by each app.
     #[prost(uint64, tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub limit: u64,
 }
 /// GetTxsEventResponse is the response type for the Service.TxsByEvents
 /// RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetTxsEventResponse {
     /// txs is the list of queried transactions.
     #[prost(message, repeated, tag="1")]
@@ -322,23 +363,28 @@ pub struct GetTxsEventResponse {
     pub pagination: ::core::option::Option<crate::proto::cosmos::base::query::v1beta1::PageResponse>,
     /// total is total number of results available
     #[prost(uint64, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub total: u64,
 }
 /// BroadcastTxRequest is the request type for the Service.BroadcastTxRequest
 /// RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct BroadcastTxRequest {
     /// tx_bytes is the raw transaction.
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub tx_bytes: ::prost::bytes::Bytes,
     #[prost(enumeration="BroadcastMode", tag="2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<BroadcastMode, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<BroadcastMode, _>"), schemars(with = "BroadcastMode"))]
     pub mode: i32,
 }
 /// BroadcastTxResponse is the response type for the
 /// Service.BroadcastTx method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct BroadcastTxResponse {
     /// tx_response is the queried TxResponses.
     #[prost(message, optional, tag="1")]
@@ -348,6 +394,7 @@ pub struct BroadcastTxResponse {
 /// RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SimulateRequest {
     /// tx is the transaction to simulate.
     /// Deprecated. Send raw tx bytes instead.
@@ -358,12 +405,14 @@ pub struct SimulateRequest {
     ///
     /// Since: cosmos-sdk 0.43
     #[prost(bytes="bytes", tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub tx_bytes: ::prost::bytes::Bytes,
 }
 /// SimulateResponse is the response type for the
 /// Service.SimulateRPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct SimulateResponse {
     /// gas_info is the information about gas used in the simulation.
     #[prost(message, optional, tag="1")]
@@ -376,6 +425,7 @@ pub struct SimulateResponse {
 /// RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetTxRequest {
     /// hash is the tx hash to query, encoded as a hex string.
     #[prost(string, tag="1")]
@@ -384,6 +434,7 @@ pub struct GetTxRequest {
 /// GetTxResponse is the response type for the Service.GetTx method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetTxResponse {
     /// tx is the queried transaction.
     #[prost(message, optional, tag="1")]
@@ -398,9 +449,11 @@ pub struct GetTxResponse {
 /// Since: cosmos-sdk 0.45.2
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetBlockWithTxsRequest {
     /// height is the height of the block to query.
     #[prost(int64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub height: i64,
     /// pagination defines a pagination for the request.
     #[prost(message, optional, tag="2")]
@@ -411,6 +464,7 @@ pub struct GetBlockWithTxsRequest {
 /// Since: cosmos-sdk 0.45.2
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GetBlockWithTxsResponse {
     /// txs are the transactions in the block.
     #[prost(message, repeated, tag="1")]
@@ -429,9 +483,11 @@ pub struct GetBlockWithTxsResponse {
 /// Since: cosmos-sdk 0.47
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TxDecodeRequest {
     /// tx_bytes is the raw transaction.
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub tx_bytes: ::prost::bytes::Bytes,
 }
 /// TxDecodeResponse is the response type for the
@@ -440,6 +496,7 @@ pub struct TxDecodeRequest {
 /// Since: cosmos-sdk 0.47
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TxDecodeResponse {
     /// tx is the decoded transaction.
     #[prost(message, optional, tag="1")]
@@ -451,6 +508,7 @@ pub struct TxDecodeResponse {
 /// Since: cosmos-sdk 0.47
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TxEncodeRequest {
     /// tx is the transaction to encode.
     #[prost(message, optional, tag="1")]
@@ -462,9 +520,11 @@ pub struct TxEncodeRequest {
 /// Since: cosmos-sdk 0.47
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TxEncodeResponse {
     /// tx_bytes is the encoded transaction bytes.
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub tx_bytes: ::prost::bytes::Bytes,
 }
 /// TxEncodeAminoRequest is the request type for the Service.TxEncodeAmino
@@ -473,6 +533,7 @@ pub struct TxEncodeResponse {
 /// Since: cosmos-sdk 0.47
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TxEncodeAminoRequest {
     #[prost(string, tag="1")]
     pub amino_json: ::prost::alloc::string::String,
@@ -483,8 +544,10 @@ pub struct TxEncodeAminoRequest {
 /// Since: cosmos-sdk 0.47
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TxEncodeAminoResponse {
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub amino_binary: ::prost::bytes::Bytes,
 }
 /// TxDecodeAminoRequest is the request type for the Service.TxDecodeAmino
@@ -493,8 +556,10 @@ pub struct TxEncodeAminoResponse {
 /// Since: cosmos-sdk 0.47
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TxDecodeAminoRequest {
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub amino_binary: ::prost::bytes::Bytes,
 }
 /// TxDecodeAminoResponse is the response type for the Service.TxDecodeAmino
@@ -503,19 +568,24 @@ pub struct TxDecodeAminoRequest {
 /// Since: cosmos-sdk 0.47
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TxDecodeAminoResponse {
     #[prost(string, tag="1")]
     pub amino_json: ::prost::alloc::string::String,
 }
 /// OrderBy defines the sorting order
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
 #[repr(i32)]
 pub enum OrderBy {
     /// ORDER_BY_UNSPECIFIED specifies an unknown sorting order. OrderBy defaults to ASC in this case.
+    #[cfg_attr(feature = "serde", serde(rename = "ORDER_BY_UNSPECIFIED"))]
     Unspecified = 0,
     /// ORDER_BY_ASC defines ascending order
+    #[cfg_attr(feature = "serde", serde(rename = "ORDER_BY_ASC"))]
     Asc = 1,
     /// ORDER_BY_DESC defines descending order
+    #[cfg_attr(feature = "serde", serde(rename = "ORDER_BY_DESC"))]
     Desc = 2,
 }
 impl OrderBy {
@@ -542,18 +612,23 @@ impl OrderBy {
 }
 /// BroadcastMode specifies the broadcast mode for the TxService.Broadcast RPC method.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
 #[repr(i32)]
 pub enum BroadcastMode {
     /// zero-value for mode ordering
+    #[cfg_attr(feature = "serde", serde(rename = "BROADCAST_MODE_UNSPECIFIED"))]
     Unspecified = 0,
     /// DEPRECATED: use BROADCAST_MODE_SYNC instead,
     /// BROADCAST_MODE_BLOCK is not supported by the SDK from v0.47.x onwards.
+    #[cfg_attr(feature = "serde", serde(rename = "BROADCAST_MODE_BLOCK"))]
     Block = 1,
     /// BROADCAST_MODE_SYNC defines a tx broadcasting mode where the client waits for
     /// a CheckTx execution response only.
+    #[cfg_attr(feature = "serde", serde(rename = "BROADCAST_MODE_SYNC"))]
     Sync = 2,
     /// BROADCAST_MODE_ASYNC defines a tx broadcasting mode where the client returns
     /// immediately.
+    #[cfg_attr(feature = "serde", serde(rename = "BROADCAST_MODE_ASYNC"))]
     Async = 3,
 }
 impl BroadcastMode {
@@ -3,6 +3,7 @@
 /// the necessary fields needed for any vesting account implementation.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct BaseVestingAccount {
     #[prost(message, optional, tag="1")]
     pub base_account: ::core::option::Option<crate::proto::cosmos::auth::v1beta1::BaseAccount>,
@@ -14,17 +15,20 @@ pub struct BaseVestingAccount {
     pub delegated_vesting: ::prost::alloc::vec::Vec<crate::proto::cosmos::base::v1beta1::Coin>,
     /// Vesting end time, as unix timestamp (in seconds).
     #[prost(int64, tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub end_time: i64,
 }
 /// ContinuousVestingAccount implements the VestingAccount interface. It
 /// continuously vests by unlocking coins linearly with respect to time.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ContinuousVestingAccount {
     #[prost(message, optional, tag="1")]
     pub base_vesting_account: ::core::option::Option<BaseVestingAccount>,
     /// Vesting start time, as unix timestamp (in seconds).
     #[prost(int64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub start_time: i64,
 }
 /// DelayedVestingAccount implements the VestingAccount interface. It vests all
@@ -32,6 +36,7 @@ pub struct ContinuousVestingAccount {
 /// locked until a specified time.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct DelayedVestingAccount {
     #[prost(message, optional, tag="1")]
     pub base_vesting_account: ::core::option::Option<BaseVestingAccount>,
@@ -39,9 +44,11 @@ pub struct DelayedVestingAccount {
 /// Period defines a length of time and amount of coins that will vest.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Period {
     /// Period duration in seconds.
     #[prost(int64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub length: i64,
     #[prost(message, repeated, tag="2")]
     pub amount: ::prost::alloc::vec::Vec<crate::proto::cosmos::base::v1beta1::Coin>,
@@ -50,10 +57,12 @@ pub struct Period {
 /// periodically vests by unlocking coins during each specified period.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct PeriodicVestingAccount {
     #[prost(message, optional, tag="1")]
     pub base_vesting_account: ::core::option::Option<BaseVestingAccount>,
     #[prost(int64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub start_time: i64,
     #[prost(message, repeated, tag="3")]
     pub vesting_periods: ::prost::alloc::vec::Vec<Period>,
@@ -65,6 +74,7 @@ pub struct PeriodicVestingAccount {
 /// Since: cosmos-sdk 0.43
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct PermanentLockedAccount {
     #[prost(message, optional, tag="1")]
     pub base_vesting_account: ::core::option::Option<BaseVestingAccount>,
@@ -73,6 +83,7 @@ pub struct PermanentLockedAccount {
 /// account.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgCreateVestingAccount {
     #[prost(string, tag="1")]
     pub from_address: ::prost::alloc::string::String,
@@ -82,6 +93,7 @@ pub struct MsgCreateVestingAccount {
     pub amount: ::prost::alloc::vec::Vec<crate::proto::cosmos::base::v1beta1::Coin>,
     /// end of vesting as unix time (in seconds).
     #[prost(int64, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub end_time: i64,
     #[prost(bool, tag="5")]
     pub delayed: bool,
@@ -89,6 +101,7 @@ pub struct MsgCreateVestingAccount {
 /// MsgCreateVestingAccountResponse defines the Msg/CreateVestingAccount response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgCreateVestingAccountResponse {
 }
 /// MsgCreatePermanentLockedAccount defines a message that enables creating a permanent
@@ -97,6 +110,7 @@ pub struct MsgCreateVestingAccountResponse {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgCreatePermanentLockedAccount {
     #[prost(string, tag="1")]
     pub from_address: ::prost::alloc::string::String,
@@ -110,6 +124,7 @@ pub struct MsgCreatePermanentLockedAccount {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgCreatePermanentLockedAccountResponse {
 }
 /// MsgCreateVestingAccount defines a message that enables creating a vesting
@@ -118,6 +133,7 @@ pub struct MsgCreatePermanentLockedAccountResponse {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgCreatePeriodicVestingAccount {
     #[prost(string, tag="1")]
     pub from_address: ::prost::alloc::string::String,
@@ -125,6 +141,7 @@ pub struct MsgCreatePeriodicVestingAccount {
     pub to_address: ::prost::alloc::string::String,
     /// start of vesting as unix time (in seconds).
     #[prost(int64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub start_time: i64,
     #[prost(message, repeated, tag="4")]
     pub vesting_periods: ::prost::alloc::vec::Vec<Period>,
@@ -135,6 +152,7 @@ pub struct MsgCreatePeriodicVestingAccount {
 /// Since: cosmos-sdk 0.46
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgCreatePeriodicVestingAccountResponse {
 }
 // @@protoc_insertion_point(module)
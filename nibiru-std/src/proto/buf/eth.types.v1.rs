@@ -3,6 +3,7 @@
 /// authtypes.BaseAccount type. It is compatible with the auth AccountKeeper.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EthAccount {
     /// base_account is an authtypes.BaseAccount
     #[prost(message, optional, tag="1")]
@@ -14,9 +15,11 @@ pub struct EthAccount {
 /// TxResult is the value stored in eth tx indexer
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TxResult {
     /// height of the blockchain
     #[prost(int64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub height: i64,
     /// tx_index is the index of the block transaction. It is not the index of an
     /// "internal transaction"
@@ -36,10 +39,12 @@ pub struct TxResult {
     /// gas_used by the transaction. If it exceeds the block gas limit,
     /// it's set to gas limit, which is what's actually deducted by ante handler.
     #[prost(uint64, tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub gas_used: u64,
     /// cumulative_gas_used specifies the cumulated amount of gas used for all
     /// processed messages within the current batch transaction.
     #[prost(uint64, tag="7")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub cumulative_gas_used: u64,
 }
 // @@protoc_insertion_point(module)
@@ -3,6 +3,7 @@
 /// the owner of a given smart contract
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct FeeShare {
     /// contract_address is the bech32 address of a registered contract in string
     /// form
@@ -21,6 +22,7 @@ pub struct FeeShare {
 /// sharing payouts, specifying the deployer, contract, and withdrawer addresses.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventRegisterDevGas {
     /// deployer is the addess of the account that registered the smart contract to
     /// receive dev gas royalties.
@@ -40,6 +42,7 @@ pub struct EventRegisterDevGas {
 /// specifying the deployer and contract addresses.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventCancelDevGas {
     /// deployer is the addess of the account that registered the smart contract to
     /// receive dev gas royalties.
@@ -54,6 +57,7 @@ pub struct EventCancelDevGas {
 /// contract, specifying updated deployer, contract, and/or withdrawer addresses.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventUpdateDevGas {
     /// deployer is the addess of the account that registered the smart contract to
     /// receive dev gas royalties.
@@ -73,6 +77,7 @@ pub struct EventUpdateDevGas {
 /// the payouts in JSON format.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventPayoutDevGas {
     #[prost(string, tag="1")]
     pub payouts: ::prost::alloc::string::String,
@@ -80,6 +85,7 @@ pub struct EventPayoutDevGas {
 /// GenesisState defines the module's genesis state.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GenesisState {
     /// params are the feeshare module parameters
     #[prost(message, optional, tag="1")]
@@ -91,6 +97,7 @@ pub struct GenesisState {
 /// ModuleParams defines the params for the devgas module
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ModuleParams {
     /// enable_feeshare defines a parameter to enable the feeshare module
     #[prost(bool, tag="1")]
@@ -109,6 +116,7 @@ pub struct ModuleParams {
 /// QueryFeeSharesRequest is the request type for the Query/FeeShares RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryFeeSharesRequest {
     /// TODO feat(devgas): re-implement the paginated version
     /// TODO feat(colletions): add automatic pagination generation
@@ -130,6 +138,7 @@ pub struct QueryFeeSharesRequest {
 /// method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryFeeSharesResponse {
     /// FeeShare is the slice of all stored Reveneue for the deployer
     #[prost(message, repeated, tag="1")]
@@ -138,6 +147,7 @@ pub struct QueryFeeSharesResponse {
 /// QueryFeeShareRequest is the request type for the Query/FeeShare RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryFeeShareRequest {
     /// contract_address of a registered contract in bech32 format
     #[prost(string, tag="1")]
@@ -146,6 +156,7 @@ pub struct QueryFeeShareRequest {
 /// QueryFeeShareResponse is the response type for the Query/FeeShare RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryFeeShareResponse {
     /// FeeShare is a stored Reveneue for the queried contract
     #[prost(message, optional, tag="1")]
@@ -154,11 +165,13 @@ pub struct QueryFeeShareResponse {
 /// QueryParamsRequest is the request type for the Query/Params RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryParamsRequest {
 }
 /// QueryParamsResponse is the response type for the Query/Params RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryParamsResponse {
     /// params is the returned FeeShare parameter
     #[prost(message, optional, tag="1")]
@@ -168,6 +181,7 @@ pub struct QueryParamsResponse {
 /// Query/FeeSharesByWithdrawer RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryFeeSharesByWithdrawerRequest {
     /// withdrawer_address in bech32 format
     #[prost(string, tag="1")]
@@ -177,6 +191,7 @@ pub struct QueryFeeSharesByWithdrawerRequest {
 /// Query/FeeSharesByWithdrawer RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryFeeSharesByWithdrawerResponse {
     #[prost(message, repeated, tag="1")]
     pub feeshare: ::prost::alloc::vec::Vec<FeeShare>,
@@ -184,6 +199,7 @@ pub struct QueryFeeSharesByWithdrawerResponse {
 /// MsgRegisterFeeShare defines a message that registers a FeeShare
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgRegisterFeeShare {
     /// contract_address in bech32 format
     #[prost(string, tag="1")]
@@ -200,12 +216,14 @@ pub struct MsgRegisterFeeShare {
 /// MsgRegisterFeeShareResponse defines the MsgRegisterFeeShare response type
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgRegisterFeeShareResponse {
 }
 /// MsgUpdateFeeShare defines a message that updates the withdrawer address for a
 /// registered FeeShare
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateFeeShare {
     /// contract_address in bech32 format
     #[prost(string, tag="1")]
@@ -222,11 +240,13 @@ pub struct MsgUpdateFeeShare {
 /// MsgUpdateFeeShareResponse defines the MsgUpdateFeeShare response type
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateFeeShareResponse {
 }
 /// MsgCancelFeeShare defines a message that cancels a registered FeeShare
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgCancelFeeShare {
     /// contract_address in bech32 format
     #[prost(string, tag="1")]
@@ -239,6 +259,7 @@ pub struct MsgCancelFeeShare {
 /// MsgCancelFeeShareResponse defines the MsgCancelFeeShare response type
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgCancelFeeShareResponse {
 }
 /// MsgUpdateParams is the Msg/UpdateParams request type.
@@ -246,6 +267,7 @@ pub struct MsgCancelFeeShareResponse {
 /// Since: cosmos-sdk 0.47
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateParams {
     /// authority is the address that controls the module (defaults to x/gov unless
     /// overwritten).
@@ -263,6 +285,7 @@ pub struct MsgUpdateParams {
 /// Since: cosmos-sdk 0.47
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateParamsResponse {
 }
 // @@protoc_insertion_point(module)
@@ -2,6 +2,7 @@
 /// Module is the config object for the epochs module.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Module {
     /// hooks_order specifies the order of staking hooks and should be a list
     /// of module names which provide a staking hooks instance. If no order is
@@ -15,6 +16,7 @@ pub struct Module {
 /// ModuleAccountPermission represents permissions for a module account.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ModuleAccountPermission {
     /// account is the name of the module.
     #[prost(string, tag="1")]
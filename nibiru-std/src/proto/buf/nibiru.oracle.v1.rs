@@ -2,9 +2,11 @@
 /// Params defines the module parameters for the x/oracle module.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Params {
     /// VotePeriod defines the number of blocks during which voting takes place.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub vote_period: u64,
     /// VoteThreshold specifies the minimum proportion of votes that must be
     /// received for a ballot to pass.
@@ -34,21 +36,25 @@ pub struct Params {
     /// than the penalty threshold are slashed. Missing the penalty threshold is
     /// synonymous with submitting fewer valid votes than `MinValidPerWindow`.
     #[prost(uint64, tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub slash_window: u64,
     #[prost(string, tag="7")]
     pub min_valid_per_window: ::prost::alloc::string::String,
     /// Amount of time to look back for TWAP calculations.
     /// Ex: "900.000000069s" corresponds to 900 seconds and 69 nanoseconds in JSON.
     #[prost(message, optional, tag="8")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub twap_lookback_window: ::core::option::Option<::prost_types::Duration>,
     /// The minimum number of voters (i.e. oracle validators) per pair for it to be
     /// considered a passing ballot. Recommended at least 4.
     #[prost(uint64, tag="9")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub min_voters: u64,
     /// The validator fee ratio that is given to validators every epoch.
     #[prost(string, tag="10")]
     pub validator_fee_ratio: ::prost::alloc::string::String,
     #[prost(uint64, tag="11")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub expiration_blocks: u64,
 }
 /// Struct for aggregate prevoting on the ExchangeRateVote.
@@ -57,18 +63,21 @@ pub struct Params {
 /// SHA256("{salt}:({pair},{exchange_rate})|...|({pair},{exchange_rate}):{voter}")
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct AggregateExchangeRatePrevote {
     #[prost(string, tag="1")]
     pub hash: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
     pub voter: ::prost::alloc::string::String,
     #[prost(uint64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub submit_block: u64,
 }
 /// MsgAggregateExchangeRateVote - struct for voting on
 /// the exchange rates different assets.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct AggregateExchangeRateVote {
     #[prost(message, repeated, tag="1")]
     pub exchange_rate_tuples: ::prost::alloc::vec::Vec<ExchangeRateTuple>,
@@ -78,6 +87,7 @@ pub struct AggregateExchangeRateVote {
 /// ExchangeRateTuple - struct to store interpreted exchange rates data to store
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ExchangeRateTuple {
     #[prost(string, tag="1")]
     pub pair: ::prost::alloc::string::String,
@@ -86,28 +96,34 @@ pub struct ExchangeRateTuple {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ExchangeRateAtBlock {
     #[prost(string, tag="1")]
     pub exchange_rate: ::prost::alloc::string::String,
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub created_block: u64,
     /// Block timestamp for the block where the oracle came to consensus for this
     /// price. This timestamp is a conventional Unix millisecond time, i.e. the
     /// number of milliseconds elapsed since January 1, 1970 UTC. 
     #[prost(int64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub block_timestamp_ms: i64,
 }
 /// Rewards defines a credit object towards validators
 /// which provide prices faithfully for different pairs.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Rewards {
     /// id uniquely identifies the rewards instance of the pair
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub id: u64,
     /// vote_periods defines the vote periods left in which rewards will be
     /// distributed.
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub vote_periods: u64,
     /// Coins defines the amount of coins to distribute in a single vote period.
     #[prost(message, repeated, tag="3")]
@@ -116,17 +132,20 @@ pub struct Rewards {
 /// Emitted when a price is posted
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventPriceUpdate {
     #[prost(string, tag="1")]
     pub pair: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
     pub price: ::prost::alloc::string::String,
     #[prost(int64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub timestamp_ms: i64,
 }
 /// Emitted when a valoper delegates oracle voting rights to a feeder address.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventDelegateFeederConsent {
     /// Validator is the Bech32 address that is delegating voting rights.
     #[prost(string, tag="1")]
@@ -139,6 +158,7 @@ pub struct EventDelegateFeederConsent {
 /// Emitted by MsgAggregateExchangeVote when an aggregate vote is added to state
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventAggregateVote {
     /// Validator is the Bech32 address to which the vote will be credited.
     #[prost(string, tag="1")]
@@ -154,6 +174,7 @@ pub struct EventAggregateVote {
 /// to state
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventAggregatePrevote {
     /// Validator is the Bech32 address to which the vote will be credited.
     #[prost(string, tag="1")]
@@ -165,30 +186,37 @@ pub struct EventAggregatePrevote {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventValidatorPerformance {
     /// Validator is the Bech32 address to which the vote will be credited.
     #[prost(string, tag="1")]
     pub validator: ::prost::alloc::string::String,
     /// Tendermint consensus voting power
     #[prost(int64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub voting_power: i64,
     /// RewardWeight: Weight of rewards the validator should receive in units of
     /// consensus power.
     #[prost(int64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub reward_weight: i64,
     /// Number of valid votes for which the validator will be rewarded
     #[prost(int64, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub win_count: i64,
     /// Number of abstained votes for which there will be no reward or punishment
     #[prost(int64, tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub abstain_count: i64,
     /// Number of invalid/punishable votes
     #[prost(int64, tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub miss_count: i64,
 }
 /// GenesisState defines the oracle module's genesis state.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GenesisState {
     #[prost(message, optional, tag="1")]
     pub params: ::core::option::Option<Params>,
@@ -212,6 +240,7 @@ pub struct GenesisState {
 /// default feeder addresses.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct FeederDelegation {
     #[prost(string, tag="1")]
     pub feeder_address: ::prost::alloc::string::String,
@@ -222,16 +251,19 @@ pub struct FeederDelegation {
 /// oracle module's genesis state
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MissCounter {
     #[prost(string, tag="1")]
     pub validator_address: ::prost::alloc::string::String,
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub miss_counter: u64,
 }
 /// QueryExchangeRateRequest is the request type for the Query/ExchangeRate RPC
 /// method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryExchangeRateRequest {
     /// pair defines the pair to query for.
     #[prost(string, tag="1")]
@@ -241,6 +273,7 @@ pub struct QueryExchangeRateRequest {
 /// Query/ExchangeRate RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryExchangeRateResponse {
     /// exchange_rate defines the exchange rate of assets voted by validators
     #[prost(string, tag="1")]
@@ -249,21 +282,25 @@ pub struct QueryExchangeRateResponse {
     /// price. This timestamp is a conventional Unix millisecond time, i.e. the
     /// number of milliseconds elapsed since January 1, 1970 UTC. 
     #[prost(int64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub block_timestamp_ms: i64,
     /// Block height when the oracle came to consensus for this price.
     #[prost(uint64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub block_height: u64,
 }
 /// QueryExchangeRatesRequest is the request type for the Query/ExchangeRates RPC
 /// method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryExchangeRatesRequest {
 }
 /// QueryExchangeRatesResponse is response type for the
 /// Query/ExchangeRates RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryExchangeRatesResponse {
     /// exchange_rates defines a list of the exchange rate for all whitelisted
     /// pairs.
@@ -273,12 +310,14 @@ pub struct QueryExchangeRatesResponse {
 /// QueryActivesRequest is the request type for the Query/Actives RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryActivesRequest {
 }
 /// QueryActivesResponse is response type for the
 /// Query/Actives RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryActivesResponse {
     /// actives defines a list of the pair which oracle prices agreed upon.
     #[prost(string, repeated, tag="1")]
@@ -288,12 +327,14 @@ pub struct QueryActivesResponse {
 /// method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryVoteTargetsRequest {
 }
 /// QueryVoteTargetsResponse is response type for the
 /// Query/VoteTargets RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryVoteTargetsResponse {
     /// vote_targets defines a list of the pairs in which everyone
     /// should vote in the current vote period.
@@ -304,6 +345,7 @@ pub struct QueryVoteTargetsResponse {
 /// Query/FeederDelegation RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryFeederDelegationRequest {
     /// validator defines the validator address to query for.
     #[prost(string, tag="1")]
@@ -313,6 +355,7 @@ pub struct QueryFeederDelegationRequest {
 /// Query/FeederDelegation RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryFeederDelegationResponse {
     /// feeder_addr defines the feeder delegation of a validator
     #[prost(string, tag="1")]
@@ -322,6 +365,7 @@ pub struct QueryFeederDelegationResponse {
 /// method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryMissCounterRequest {
     /// validator defines the validator address to query for.
     #[prost(string, tag="1")]
@@ -331,15 +375,18 @@ pub struct QueryMissCounterRequest {
 /// Query/MissCounter RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryMissCounterResponse {
     /// miss_counter defines the oracle miss counter of a validator
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub miss_counter: u64,
 }
 /// QueryAggregatePrevoteRequest is the request type for the
 /// Query/AggregatePrevote RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryAggregatePrevoteRequest {
     /// validator defines the validator address to query for.
     #[prost(string, tag="1")]
@@ -349,6 +396,7 @@ pub struct QueryAggregatePrevoteRequest {
 /// Query/AggregatePrevote RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryAggregatePrevoteResponse {
     /// aggregate_prevote defines oracle aggregate prevote submitted by a validator
     /// in the current vote period
@@ -359,12 +407,14 @@ pub struct QueryAggregatePrevoteResponse {
 /// Query/AggregatePrevotes RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryAggregatePrevotesRequest {
 }
 /// QueryAggregatePrevotesResponse is response type for the
 /// Query/AggregatePrevotes RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryAggregatePrevotesResponse {
     /// aggregate_prevotes defines all oracle aggregate prevotes submitted in the
     /// current vote period
@@ -375,6 +425,7 @@ pub struct QueryAggregatePrevotesResponse {
 /// method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryAggregateVoteRequest {
     /// validator defines the validator address to query for.
     #[prost(string, tag="1")]
@@ -384,6 +435,7 @@ pub struct QueryAggregateVoteRequest {
 /// Query/AggregateVote RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryAggregateVoteResponse {
     /// aggregate_vote defines oracle aggregate vote submitted by a validator in
     /// the current vote period
@@ -394,12 +446,14 @@ pub struct QueryAggregateVoteResponse {
 /// RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryAggregateVotesRequest {
 }
 /// QueryAggregateVotesResponse is response type for the
 /// Query/AggregateVotes RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryAggregateVotesResponse {
     /// aggregate_votes defines all oracle aggregate votes submitted in the current
     /// vote period
@@ -409,11 +463,13 @@ pub struct QueryAggregateVotesResponse {
 /// QueryParamsRequest is the request type for the Query/Params RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryParamsRequest {
 }
 /// QueryParamsResponse is the response type for the Query/Params RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryParamsResponse {
     /// params defines the parameters of the module.
     #[prost(message, optional, tag="1")]
@@ -422,6 +478,7 @@ pub struct QueryParamsResponse {
 /// a snapshot of the prices at a given point in time
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct PriceSnapshot {
     #[prost(string, tag="1")]
     pub pair: ::prost::alloc::string::String,
@@ -429,12 +486,14 @@ pub struct PriceSnapshot {
     pub price: ::prost::alloc::string::String,
     /// milliseconds since unix epoch
     #[prost(int64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub timestamp_ms: i64,
 }
 /// MsgAggregateExchangeRatePrevote represents a message to submit
 /// aggregate exchange rate prevote.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgAggregateExchangeRatePrevote {
     #[prost(string, tag="1")]
     pub hash: ::prost::alloc::string::String,
@@ -451,12 +510,14 @@ pub struct MsgAggregateExchangeRatePrevote {
 /// Msg/AggregateExchangeRatePrevote response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgAggregateExchangeRatePrevoteResponse {
 }
 /// MsgAggregateExchangeRateVote represents a message to submit
 /// aggregate exchange rate vote.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgAggregateExchangeRateVote {
     #[prost(string, tag="1")]
     pub salt: ::prost::alloc::string::String,
@@ -475,12 +536,14 @@ pub struct MsgAggregateExchangeRateVote {
 /// Msg/AggregateExchangeRateVote response type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgAggregateExchangeRateVoteResponse {
 }
 /// MsgDelegateFeedConsent represents a message to delegate oracle voting rights
 /// to another address.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgDelegateFeedConsent {
     #[prost(string, tag="1")]
     pub operator: ::prost::alloc::string::String,
@@ -491,10 +554,12 @@ pub struct MsgDelegateFeedConsent {
 /// type.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgDelegateFeedConsentResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgEditOracleParams {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -503,13 +568,16 @@ pub struct MsgEditOracleParams {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgEditOracleParamsResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct OracleParamsMsg {
     /// VotePeriod defines the number of blocks during which voting takes place.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub vote_period: u64,
     /// VoteThreshold specifies the minimum proportion of votes that must be
     /// received for a ballot to pass.
@@ -539,20 +607,24 @@ pub struct OracleParamsMsg {
     /// than the penalty threshold are slashed. Missing the penalty threshold is
     /// synonymous with submitting fewer valid votes than `MinValidPerWindow`.
     #[prost(uint64, tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub slash_window: u64,
     #[prost(string, tag="7")]
     pub min_valid_per_window: ::prost::alloc::string::String,
     /// Amount of time to look back for TWAP calculations
     #[prost(message, optional, tag="8")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub twap_lookback_window: ::core::option::Option<::prost_types::Duration>,
     /// The minimum number of voters (i.e. oracle validators) per pair for it to be
     /// considered a passing ballot. Recommended at least 4.
     #[prost(uint64, tag="9")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub min_voters: u64,
     /// The validator fee ratio that is given to validators every epoch.
     #[prost(string, tag="10")]
     pub validator_fee_ratio: ::prost::alloc::string::String,
     #[prost(uint64, tag="11")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub expiration_blocks: u64,
 }
 // @@protoc_insertion_point(module)
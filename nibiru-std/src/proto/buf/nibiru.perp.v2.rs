@@ -1,6 +1,7 @@
 // @generated
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Market {
     /// the trading pair represented by this market
     /// always BASE:QUOTE, e.g. BTC:NUSD or ETH:NUSD
@@ -12,6 +13,7 @@ pub struct Market {
     /// the version of the Market, only one market can exist per pair, when one is closed it cannot be reactivated,
     /// so a new market must be created, this is the version of the market
     #[prost(uint64, tag="14")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub version: u64,
     /// the minimum margin ratio which a user must maintain on this market
     #[prost(string, tag="3")]
@@ -46,6 +48,7 @@ pub struct Market {
     pub funding_rate_epoch_id: ::prost::alloc::string::String,
     /// amount of time to look back for TWAP calculations
     #[prost(message, optional, tag="11")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub twap_lookback_window: ::core::option::Option<::prost_types::Duration>,
     /// the amount of collateral already credited from the ecosystem fund
     #[prost(message, optional, tag="12")]
@@ -63,13 +66,16 @@ pub struct Market {
 /// MarketLastVersion is used to store the last version of the market
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MarketLastVersion {
     /// version of the market
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub version: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Amm {
     /// identifies the market this AMM belongs to
     #[prost(string, tag="1")]
@@ -77,6 +83,7 @@ pub struct Amm {
     /// the version of the AMM, only one AMM can exist per pair, when one is closed it cannot be reactivated,
     /// so a new AMM must be created, this is the version of the AMM
     #[prost(uint64, tag="8")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub version: u64,
     /// the amount of base reserves this AMM has
     #[prost(string, tag="2")]
@@ -102,6 +109,7 @@ pub struct Amm {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Position {
     /// address identifies the address owner of this position
     #[prost(string, tag="1")]
@@ -124,24 +132,29 @@ pub struct Position {
     pub latest_cumulative_premium_fraction: ::prost::alloc::string::String,
     /// last block number this position was updated
     #[prost(int64, tag="7")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub last_updated_block_number: i64,
 }
 /// a snapshot of the perp.amm's reserves at a given point in time
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ReserveSnapshot {
     #[prost(message, optional, tag="1")]
     pub amm: ::core::option::Option<Amm>,
     /// milliseconds since unix epoch
     #[prost(int64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub timestamp_ms: i64,
 }
 /// DNRAllocation represents a rebates allocation for a given epoch.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct DnrAllocation {
     /// epoch defines the reference epoch for the allocation.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub epoch: u64,
     /// amount of DNR allocated for the epoch.
     #[prost(message, repeated, tag="2")]
@@ -151,10 +164,14 @@ pub struct DnrAllocation {
 /// LONG means the user is going long the base asset (e.g. buy BTC)
 /// SHORT means the user is shorting the base asset (e.g. sell BTC)
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
 #[repr(i32)]
 pub enum Direction {
+    #[cfg_attr(feature = "serde", serde(rename = "DIRECTION_UNSPECIFIED"))]
     Unspecified = 0,
+    #[cfg_attr(feature = "serde", serde(rename = "LONG"))]
     Long = 1,
+    #[cfg_attr(feature = "serde", serde(rename = "SHORT"))]
     Short = 2,
 }
 impl Direction {
@@ -181,14 +198,19 @@ impl Direction {
 }
 /// Enumerates different options of calculating twap.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
 #[repr(i32)]
 pub enum TwapCalcOption {
+    #[cfg_attr(feature = "serde", serde(rename = "TWAP_CALC_OPTION_UNSPECIFIED"))]
     Unspecified = 0,
     /// Spot price from quote asset reserve / base asset reserve
+    #[cfg_attr(feature = "serde", serde(rename = "SPOT"))]
     Spot = 1,
     /// Swapping with quote assets, output denominated in base assets
+    #[cfg_attr(feature = "serde", serde(rename = "QUOTE_ASSET_SWAP"))]
     QuoteAssetSwap = 2,
     /// Swapping with base assets, output denominated in quote assets
+    #[cfg_attr(feature = "serde", serde(rename = "BASE_ASSET_SWAP"))]
     BaseAssetSwap = 3,
 }
 impl TwapCalcOption {
@@ -218,6 +240,7 @@ impl TwapCalcOption {
 /// Emitted when a position changes.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct PositionChangedEvent {
     #[prost(message, optional, tag="1")]
     pub final_position: ::core::option::Option<Position>,
@@ -244,6 +267,7 @@ pub struct PositionChangedEvent {
     pub funding_payment: ::prost::alloc::string::String,
     /// The block number at which this position was changed.
     #[prost(int64, tag="7")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub block_height: i64,
     /// margin_to_user is the amount of collateral received by the trader during
     /// the position change. A positve value indicates that the trader received
@@ -277,6 +301,7 @@ pub struct PositionChangedEvent {
 /// liquidation causes position changes.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct PositionLiquidatedEvent {
     #[prost(message, optional, tag="1")]
     pub position_changed_event: ::core::option::Option<PositionChangedEvent>,
@@ -293,6 +318,7 @@ pub struct PositionLiquidatedEvent {
 /// Emitted when a position is settled.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct PositionSettledEvent {
     /// Identifier for the virtual pool of the position.
     #[prost(string, tag="1")]
@@ -307,6 +333,7 @@ pub struct PositionSettledEvent {
 /// Emitted when the funding rate changes for a market.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct FundingRateChangedEvent {
     /// The pair for which the funding rate was calculated.
     #[prost(string, tag="1")]
@@ -330,6 +357,7 @@ pub struct FundingRateChangedEvent {
 /// Emitted when liquidation fails.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct LiquidationFailedEvent {
     /// The pair for which we are trying to liquidate.
     #[prost(string, tag="1")]
@@ -342,19 +370,25 @@ pub struct LiquidationFailedEvent {
     pub liquidator: ::prost::alloc::string::String,
     /// Reason for the liquidation failure.
     #[prost(enumeration="liquidation_failed_event::LiquidationFailedReason", tag="4")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<liquidation_failed_event::LiquidationFailedReason, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<liquidation_failed_event::LiquidationFailedReason, _>"), schemars(with = "liquidation_failed_event::LiquidationFailedReason"))]
     pub reason: i32,
 }
 /// Nested message and enum types in `LiquidationFailedEvent`.
 pub mod liquidation_failed_event {
     #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
     #[repr(i32)]
     pub enum LiquidationFailedReason {
+        #[cfg_attr(feature = "serde", serde(rename = "UNSPECIFIED"))]
         Unspecified = 0,
         /// the position is healthy and does not need to be liquidated.
+        #[cfg_attr(feature = "serde", serde(rename = "POSITION_HEALTHY"))]
         PositionHealthy = 1,
         /// the pair does not exist.
+        #[cfg_attr(feature = "serde", serde(rename = "NONEXISTENT_PAIR"))]
         NonexistentPair = 2,
         /// the position does not exist.
+        #[cfg_attr(feature = "serde", serde(rename = "NONEXISTENT_POSITION"))]
         NonexistentPosition = 3,
     }
     impl LiquidationFailedReason {
@@ -390,6 +424,7 @@ pub mod liquidation_failed_event {
 /// - edit depth
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct AmmUpdatedEvent {
     /// the final state of the AMM
     #[prost(message, optional, tag="1")]
@@ -411,6 +446,7 @@ pub struct AmmUpdatedEvent {
 /// - bad debt is prepaid by the ecosystem fund
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MarketUpdatedEvent {
     /// the final state of the market
     #[prost(message, optional, tag="1")]
@@ -419,6 +455,7 @@ pub struct MarketUpdatedEvent {
 /// EventShiftPegMultiplier: ABCI event emitted from MsgShiftPegMultiplier
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventShiftPegMultiplier {
     #[prost(string, tag="1")]
     pub old_peg_multiplier: ::prost::alloc::string::String,
@@ -430,6 +467,7 @@ pub struct EventShiftPegMultiplier {
 /// EventShiftSwapInvariant: ABCI event emitted from MsgShiftSwapInvariant
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventShiftSwapInvariant {
     #[prost(string, tag="1")]
     pub old_swap_invariant: ::prost::alloc::string::String,
@@ -443,6 +481,7 @@ pub struct EventShiftSwapInvariant {
 /// exporting and importing state during network upgrades.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GenesisState {
     #[prost(message, repeated, tag="2")]
     pub markets: ::prost::alloc::vec::Vec<Market>,
@@ -453,6 +492,7 @@ pub struct GenesisState {
     #[prost(message, repeated, tag="5")]
     pub reserve_snapshots: ::prost::alloc::vec::Vec<ReserveSnapshot>,
     #[prost(uint64, tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub dnr_epoch: u64,
     /// For testing purposes, we allow the collateral to be set at genesis
     #[prost(string, tag="11")]
@@ -476,16 +516,19 @@ pub struct GenesisState {
 pub mod genesis_state {
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub struct TraderVolume {
         #[prost(string, tag="1")]
         pub trader: ::prost::alloc::string::String,
         #[prost(uint64, tag="2")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
         pub epoch: u64,
         #[prost(string, tag="3")]
         pub volume: ::prost::alloc::string::String,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub struct Discount {
         #[prost(string, tag="1")]
         pub fee: ::prost::alloc::string::String,
@@ -494,6 +537,7 @@ pub mod genesis_state {
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub struct CustomDiscount {
         #[prost(string, tag="1")]
         pub trader: ::prost::alloc::string::String,
@@ -502,8 +546,10 @@ pub mod genesis_state {
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub struct GlobalVolume {
         #[prost(uint64, tag="1")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
         pub epoch: u64,
         #[prost(string, tag="2")]
         pub volume: ::prost::alloc::string::String,
@@ -513,18 +559,22 @@ pub mod genesis_state {
 /// genesis
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GenesisMarketLastVersion {
     #[prost(string, tag="1")]
     pub pair: ::prost::alloc::string::String,
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub version: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GenesisPosition {
     #[prost(string, tag="1")]
     pub pair: ::prost::alloc::string::String,
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub version: u64,
     #[prost(message, optional, tag="3")]
     pub position: ::core::option::Option<Position>,
@@ -535,6 +585,7 @@ pub struct GenesisPosition {
 /// "nibiru.perp.v2.Query/Positions" gRPC service method
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryPositionsRequest {
     #[prost(string, tag="1")]
     pub trader: ::prost::alloc::string::String,
@@ -543,6 +594,7 @@ pub struct QueryPositionsRequest {
 /// "nibiru.perp.v2.Query/Positions" gRPC service method
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryPositionsResponse {
     #[prost(message, repeated, tag="1")]
     pub positions: ::prost::alloc::vec::Vec<QueryPositionResponse>,
@@ -551,6 +603,7 @@ pub struct QueryPositionsResponse {
 /// "nibiru.perp.v2.Query/PositionStore" gRPC service method
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryPositionStoreRequest {
     /// pagination defines a paginated request
     #[prost(message, optional, tag="1")]
@@ -560,6 +613,7 @@ pub struct QueryPositionStoreRequest {
 /// "nibiru.perp.v2.Query/PositionStore" gRPC service method
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryPositionStoreResponse {
     /// Position responses: collection of all stored positions (with pagination)
     #[prost(message, repeated, tag="1")]
@@ -574,6 +628,7 @@ pub struct QueryPositionStoreResponse {
 /// "nibiru.perp.v2.Query/Position" gRPC service method
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryPositionRequest {
     #[prost(string, tag="1")]
     pub pair: ::prost::alloc::string::String,
@@ -584,6 +639,7 @@ pub struct QueryPositionRequest {
 /// "nibiru.perp.v2.Query/Position" gRPC service method
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryPositionResponse {
     /// The position as it exists in the blockchain state
     #[prost(message, optional, tag="1")]
@@ -605,18 +661,21 @@ pub struct QueryPositionResponse {
 /// "nibiru.perp.v2.Query/ModuleAccounts" gRPC service method
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryModuleAccountsRequest {
 }
 /// QueryModuleAccountsResponse: Response type for the
 /// "nibiru.perp.v2.Query/ModuleAccounts" gRPC service method
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryModuleAccountsResponse {
     #[prost(message, repeated, tag="1")]
     pub accounts: ::prost::alloc::vec::Vec<AccountWithBalance>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct AccountWithBalance {
     #[prost(string, tag="1")]
     pub name: ::prost::alloc::string::String,
@@ -627,6 +686,7 @@ pub struct AccountWithBalance {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct AmmMarket {
     #[prost(message, optional, tag="1")]
     pub market: ::core::option::Option<Market>,
@@ -635,12 +695,14 @@ pub struct AmmMarket {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryMarketsRequest {
     #[prost(bool, tag="1")]
     pub versioned: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryMarketsResponse {
     #[prost(message, repeated, tag="1")]
     pub amm_markets: ::prost::alloc::vec::Vec<AmmMarket>,
@@ -651,12 +713,14 @@ pub struct QueryMarketsResponse {
 /// "nibiru.perp.v2.Query/Collateral" gRPC service method
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryCollateralRequest {
 }
 /// QueryCollateralRequest: Response type for the
 /// "nibiru.perp.v2.Query/Collateral" gRPC service method
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryCollateralResponse {
     #[prost(string, tag="1")]
     pub collateral_denom: ::prost::alloc::string::String,
@@ -666,12 +730,14 @@ pub struct QueryCollateralResponse {
 /// MsgSettlePosition: Msg to remove margin. 
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgSettlePosition {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
     pub pair: ::prost::alloc::string::String,
     #[prost(uint64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub version: u64,
 }
 // -------------------------- RemoveMargin --------------------------
@@ -679,6 +745,7 @@ pub struct MsgSettlePosition {
 /// MsgRemoveMargin: Msg to remove margin. 
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgRemoveMargin {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -689,6 +756,7 @@ pub struct MsgRemoveMargin {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgRemoveMarginResponse {
     /// tokens transferred back to the trader
     #[prost(message, optional, tag="1")]
@@ -705,6 +773,7 @@ pub struct MsgRemoveMarginResponse {
 /// MsgAddMargin: Msg to remove margin. 
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgAddMargin {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -715,6 +784,7 @@ pub struct MsgAddMargin {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgAddMarginResponse {
     #[prost(string, tag="1")]
     pub funding_payment: ::prost::alloc::string::String,
@@ -725,6 +795,7 @@ pub struct MsgAddMarginResponse {
 
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgMultiLiquidate {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -735,6 +806,7 @@ pub struct MsgMultiLiquidate {
 pub mod msg_multi_liquidate {
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub struct Liquidation {
         #[prost(string, tag="1")]
         pub pair: ::prost::alloc::string::String,
@@ -744,6 +816,7 @@ pub mod msg_multi_liquidate {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgMultiLiquidateResponse {
     #[prost(message, repeated, tag="1")]
     pub liquidations: ::prost::alloc::vec::Vec<msg_multi_liquidate_response::LiquidationResponse>,
@@ -752,6 +825,7 @@ pub struct MsgMultiLiquidateResponse {
 pub mod msg_multi_liquidate_response {
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub struct LiquidationResponse {
         #[prost(bool, tag="1")]
         pub success: bool,
@@ -775,12 +849,14 @@ pub mod msg_multi_liquidate_response {
 
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgMarketOrder {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
     pub pair: ::prost::alloc::string::String,
     #[prost(enumeration="Direction", tag="3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<Direction, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<Direction, _>"), schemars(with = "Direction"))]
     pub side: i32,
     #[prost(string, tag="4")]
     pub quote_asset_amount: ::prost::alloc::string::String,
@@ -791,6 +867,7 @@ pub struct MsgMarketOrder {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgMarketOrderResponse {
     #[prost(message, optional, tag="1")]
     pub position: ::core::option::Option<Position>,
@@ -825,6 +902,7 @@ pub struct MsgMarketOrderResponse {
 
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgClosePosition {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -833,6 +911,7 @@ pub struct MsgClosePosition {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgClosePositionResponse {
     /// The amount of quote assets exchanged.
     #[prost(string, tag="1")]
@@ -855,6 +934,7 @@ pub struct MsgClosePositionResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgPartialClose {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -865,6 +945,7 @@ pub struct MsgPartialClose {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgPartialCloseResponse {
     /// The amount of quote assets exchanged.
     #[prost(string, tag="1")]
@@ -889,6 +970,7 @@ pub struct MsgPartialCloseResponse {
 
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgDonateToEcosystemFund {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -898,6 +980,7 @@ pub struct MsgDonateToEcosystemFund {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgDonateToEcosystemFundResponse {
 }
 // -----------------------  MsgChangeCollateralDenom -----------------------
@@ -906,6 +989,7 @@ pub struct MsgDonateToEcosystemFundResponse {
 /// \[SUDO\] Only callable by sudoers.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgChangeCollateralDenom {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -914,11 +998,13 @@ pub struct MsgChangeCollateralDenom {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgChangeCollateralDenomResponse {
 }
 /// -------------------------- AllocateEpochRebates --------------------------
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgAllocateEpochRebates {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -928,6 +1014,7 @@ pub struct MsgAllocateEpochRebates {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgAllocateEpochRebatesResponse {
     #[prost(message, repeated, tag="1")]
     pub total_epoch_rebates: ::prost::alloc::vec::Vec<crate::proto::cosmos::base::v1beta1::Coin>,
@@ -935,14 +1022,17 @@ pub struct MsgAllocateEpochRebatesResponse {
 /// -------------------------- WithdrawEpochRebates --------------------------
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgWithdrawEpochRebates {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
     #[prost(uint64, repeated, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<String>"))]
     pub epochs: ::prost::alloc::vec::Vec<u64>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgWithdrawEpochRebatesResponse {
     #[prost(message, repeated, tag="1")]
     pub withdrawn_rebates: ::prost::alloc::vec::Vec<crate::proto::cosmos::base::v1beta1::Coin>,
@@ -953,6 +1043,7 @@ pub struct MsgWithdrawEpochRebatesResponse {
 /// \[SUDO\] Only callable sudoers.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgShiftPegMultiplier {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -963,6 +1054,7 @@ pub struct MsgShiftPegMultiplier {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgShiftPegMultiplierResponse {
 }
 // -------------------------- ShiftSwapInvariant --------------------------
@@ -971,6 +1063,7 @@ pub struct MsgShiftPegMultiplierResponse {
 /// \[SUDO\] Only callable sudoers.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgShiftSwapInvariant {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -981,6 +1074,7 @@ pub struct MsgShiftSwapInvariant {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgShiftSwapInvariantResponse {
 }
 // -------------------------- WithdrawFromPerpFund --------------------------
@@ -989,6 +1083,7 @@ pub struct MsgShiftSwapInvariantResponse {
 /// \[SUDO\] Only callable sudoers.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgWithdrawFromPerpFund {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -1002,6 +1097,7 @@ pub struct MsgWithdrawFromPerpFund {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgWithdrawFromPerpFundResponse {
 }
 // -------------------------- CloseMarket --------------------------
@@ -1010,6 +1106,7 @@ pub struct MsgWithdrawFromPerpFundResponse {
 /// Admin-only.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgCloseMarket {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -1018,6 +1115,7 @@ pub struct MsgCloseMarket {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgCloseMarketResponse {
 }
 // @@protoc_insertion_point(module)
@@ -1,6 +1,7 @@
 // @generated
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Sudoers {
     /// Root: The "root" user.
     #[prost(string, tag="1")]
@@ -12,6 +13,7 @@ pub struct Sudoers {
 /// GenesisState: State for migrations and genesis for the x/sudo module.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GenesisState {
     #[prost(message, optional, tag="1")]
     pub sudoers: ::core::option::Option<Sudoers>,
@@ -19,6 +21,7 @@ pub struct GenesisState {
 /// EventUpdateSudoers: ABCI event emitted upon execution of "MsgEditSudoers".
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventUpdateSudoers {
     #[prost(message, optional, tag="1")]
     pub sudoers: ::core::option::Option<Sudoers>,
@@ -28,11 +31,13 @@ pub struct EventUpdateSudoers {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QuerySudoersRequest {
 }
 /// QuerySudoersResponse indicates the successful execution of MsgEditSudeors.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QuerySudoersResponse {
     #[prost(message, optional, tag="1")]
     pub sudoers: ::core::option::Option<Sudoers>,
@@ -42,6 +47,7 @@ pub struct QuerySudoersResponse {
 /// MsgEditSudoers: Msg to update the "Sudoers" state. 
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgEditSudoers {
     /// Action: identifier for the type of edit that will take place. Using this
     ///    action field prevents us from needing to create several similar message
@@ -58,6 +64,7 @@ pub struct MsgEditSudoers {
 /// MsgEditSudoersResponse indicates the successful execution of MsgEditSudeors.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgEditSudoersResponse {
 }
 // -------------------------- ChangeRoot --------------------------
@@ -65,6 +72,7 @@ pub struct MsgEditSudoersResponse {
 /// MsgChangeRoot: Msg to update the "Sudoers" state. 
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgChangeRoot {
     /// Sender: Address for the signer of the transaction.
     #[prost(string, tag="1")]
@@ -76,6 +84,7 @@ pub struct MsgChangeRoot {
 /// MsgChangeRootResponse indicates the successful execution of MsgChangeRoot.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgChangeRootResponse {
 }
 // @@protoc_insertion_point(module)
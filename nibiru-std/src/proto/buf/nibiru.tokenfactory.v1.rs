@@ -1,6 +1,7 @@
 // @generated
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventCreateDenom {
     #[prost(string, tag="1")]
     pub denom: ::prost::alloc::string::String,
@@ -9,6 +10,7 @@ pub struct EventCreateDenom {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventChangeAdmin {
     #[prost(string, tag="1")]
     pub denom: ::prost::alloc::string::String,
@@ -19,6 +21,7 @@ pub struct EventChangeAdmin {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventMint {
     #[prost(message, optional, tag="1")]
     pub coin: ::core::option::Option<crate::proto::cosmos::base::v1beta1::Coin>,
@@ -29,6 +32,7 @@ pub struct EventMint {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventBurn {
     #[prost(message, optional, tag="1")]
     pub coin: ::core::option::Option<crate::proto::cosmos::base::v1beta1::Coin>,
@@ -39,6 +43,7 @@ pub struct EventBurn {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventSetDenomMetadata {
     #[prost(string, tag="1")]
     pub denom: ::prost::alloc::string::String,
@@ -54,6 +59,7 @@ pub struct EventSetDenomMetadata {
 /// permission, but is planned to be extended to the future.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct DenomAuthorityMetadata {
     /// Admin: Bech32 address of the admin for the tokefactory denom. Can be empty
     /// for no admin.
@@ -88,16 +94,19 @@ pub struct DenomAuthorityMetadata {
 /// discussion of the issue with @ethanfrey and @valardragon.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ModuleParams {
     /// Adds gas consumption to the execution of `MsgCreateDenom` as a method of
     /// spam prevention. Defaults to 10 NIBI.
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub denom_creation_gas_consume: u64,
 }
 /// TFDenom is a token factory (TF) denom. The canonical representation is
 /// "tf/{creator}/{subdenom}", its unique denomination in the x/bank module.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TfDenom {
     /// Creator: Bech32 address of the creator of the denom.
     #[prost(string, tag="1")]
@@ -114,6 +123,7 @@ pub struct TfDenom {
 /// GenesisState for the Token Factory module.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GenesisState {
     #[prost(message, optional, tag="1")]
     pub params: ::core::option::Option<ModuleParams>,
@@ -123,6 +133,7 @@ pub struct GenesisState {
 /// GenesisDenom defines a tokenfactory denoms in the genesis state.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct GenesisDenom {
     #[prost(string, tag="1")]
     pub denom: ::prost::alloc::string::String,
@@ -132,11 +143,13 @@ pub struct GenesisDenom {
 /// QueryParamsRequest is the request type for the Query/Params RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryParamsRequest {
 }
 /// QueryParamsResponse is the response type for the Query/Params RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryParamsResponse {
     /// Module parameters stored in state
     #[prost(message, optional, tag="1")]
@@ -145,6 +158,7 @@ pub struct QueryParamsResponse {
 /// QueryDenomsRequest: gRPC query for all denoms registered for a creator
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryDenomsRequest {
     #[prost(string, tag="1")]
     pub creator: ::prost::alloc::string::String,
@@ -152,6 +166,7 @@ pub struct QueryDenomsRequest {
 /// QueryDenomsResponse: All registered denoms for a creator
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryDenomsResponse {
     #[prost(string, repeated, tag="1")]
     pub denoms: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
@@ -159,6 +174,7 @@ pub struct QueryDenomsResponse {
 /// QueryDenomInfoRequest: gRPC query for the denom admin and x/bank metadata
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryDenomInfoRequest {
     #[prost(string, tag="1")]
     pub denom: ::prost::alloc::string::String,
@@ -166,6 +182,7 @@ pub struct QueryDenomInfoRequest {
 /// QueryDenomInfoResponse: All registered denoms for a creator
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct QueryDenomInfoResponse {
     /// Admin of the token factory denom
     #[prost(string, tag="1")]
@@ -183,6 +200,7 @@ pub struct QueryDenomInfoResponse {
 ///     admin can be changed later.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgCreateDenom {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -193,6 +211,7 @@ pub struct MsgCreateDenom {
 /// MsgCreateDenomResponse is the return value of MsgCreateDenom
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgCreateDenomResponse {
     /// NewTokenDenom: identifier for the newly created token factory denom.
     #[prost(string, tag="1")]
@@ -202,6 +221,7 @@ pub struct MsgCreateDenomResponse {
 /// admin of a denom to a new account
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgChangeAdmin {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -213,11 +233,13 @@ pub struct MsgChangeAdmin {
 /// MsgChangeAdminResponse is the gRPC response for the MsgChangeAdmin TxMsg.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgChangeAdminResponse {
 }
 /// MsgUpdateModuleParams: sdk.Msg for updating the x/tokenfactory module params
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateModuleParams {
     /// Authority: Address of the governance module account.
     #[prost(string, tag="1")]
@@ -229,11 +251,13 @@ pub struct MsgUpdateModuleParams {
 /// MsgUpdateModuleParams TxMsg.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgUpdateModuleParamsResponse {
 }
 /// MsgMint: sdk.Msg (TxMsg) where an denom admin mints more of the token.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgMint {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -247,6 +271,7 @@ pub struct MsgMint {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgMintResponse {
     #[prost(string, tag="1")]
     pub mint_to: ::prost::alloc::string::String,
@@ -258,6 +283,7 @@ pub struct MsgMintResponse {
 /// contract logic.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgBurn {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -270,12 +296,14 @@ pub struct MsgBurn {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgBurnResponse {
 }
 /// MsgSetDenomMetadata: sdk.Msg (TxMsg) enabling the denom admin to change its
 /// bank metadata.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgSetDenomMetadata {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -286,6 +314,7 @@ pub struct MsgSetDenomMetadata {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgSetDenomMetadataResponse {
 }
 /// MsgSudoSetDenomMetadata: sdk.Msg (TxMsg) enabling Nibiru's "sudoers" to change
@@ -298,11 +327,12 @@ pub struct MsgSetDenomMetadataResponse {
 ///    - To set metadata for Bank Coins created via the Token Factory
 ///      module in case the admin forgets to do so. This is important because of
 ///      the relationship Token Factory assets can have with ERC20s with the
-///      [FunToken Mechanism].
+///      \[FunToken Mechanism\].
 ///
-/// [FunToken Mechanism]: <https://nibiru.fi/docs/evm/funtoken.html>
+/// \[FunToken Mechanism\]: <https://nibiru.fi/docs/evm/funtoken.html>
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgSudoSetDenomMetadata {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -313,11 +343,13 @@ pub struct MsgSudoSetDenomMetadata {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgSudoSetDenomMetadataResponse {
 }
 /// Burn a native token such as unibi
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgBurnNative {
     #[prost(string, tag="1")]
     pub sender: ::prost::alloc::string::String,
@@ -326,6 +358,7 @@ pub struct MsgBurnNative {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct MsgBurnNativeResponse {
 }
 // @@protoc_insertion_point(module)
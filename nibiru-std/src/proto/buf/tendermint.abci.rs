@@ -8,14 +8,17 @@
 
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Request {
     #[prost(oneof="request::Value", tags="1, 2, 3, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub value: ::core::option::Option<request::Value>,
 }
 /// Nested message and enum types in `Request`.
 pub mod request {
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Oneof)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase"), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub enum Value {
         #[prost(message, tag="1")]
         Echo(super::RequestEcho),
@@ -53,30 +56,37 @@ pub mod request {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct RequestEcho {
     #[prost(string, tag="1")]
     pub message: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct RequestFlush {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct RequestInfo {
     #[prost(string, tag="1")]
     pub version: ::prost::alloc::string::String,
     #[prost(uint64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub block_version: u64,
     #[prost(uint64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub p2p_version: u64,
     #[prost(string, tag="4")]
     pub abci_version: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct RequestInitChain {
     #[prost(message, optional, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(string, tag="2")]
     pub chain_id: ::prost::alloc::string::String,
@@ -85,26 +95,33 @@ pub struct RequestInitChain {
     #[prost(message, repeated, tag="4")]
     pub validators: ::prost::alloc::vec::Vec<ValidatorUpdate>,
     #[prost(bytes="bytes", tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub app_state_bytes: ::prost::bytes::Bytes,
     #[prost(int64, tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub initial_height: i64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct RequestQuery {
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub data: ::prost::bytes::Bytes,
     #[prost(string, tag="2")]
     pub path: ::prost::alloc::string::String,
     #[prost(int64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub height: i64,
     #[prost(bool, tag="4")]
     pub prove: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct RequestBeginBlock {
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub hash: ::prost::bytes::Bytes,
     #[prost(message, optional, tag="2")]
     pub header: ::core::option::Option<super::types::Header>,
@@ -115,49 +132,62 @@ pub struct RequestBeginBlock {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct RequestCheckTx {
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub tx: ::prost::bytes::Bytes,
     #[prost(enumeration="CheckTxType", tag="2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<CheckTxType, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<CheckTxType, _>"), schemars(with = "CheckTxType"))]
     pub r#type: i32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct RequestDeliverTx {
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub tx: ::prost::bytes::Bytes,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct RequestEndBlock {
     #[prost(int64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub height: i64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct RequestCommit {
 }
 /// lists available snapshots
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct RequestListSnapshots {
 }
 /// offers a snapshot to the application
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct RequestOfferSnapshot {
     /// snapshot offered by peers
     #[prost(message, optional, tag="1")]
     pub snapshot: ::core::option::Option<Snapshot>,
     /// light client-verified app hash for snapshot height
     #[prost(bytes="bytes", tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub app_hash: ::prost::bytes::Bytes,
 }
 /// loads a snapshot chunk
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct RequestLoadSnapshotChunk {
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub height: u64,
     #[prost(uint32, tag="2")]
     pub format: u32,
@@ -167,42 +197,53 @@ pub struct RequestLoadSnapshotChunk {
 /// Applies a snapshot chunk
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct RequestApplySnapshotChunk {
     #[prost(uint32, tag="1")]
     pub index: u32,
     #[prost(bytes="bytes", tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub chunk: ::prost::bytes::Bytes,
     #[prost(string, tag="3")]
     pub sender: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct RequestPrepareProposal {
     /// the modified transactions cannot exceed this size.
     #[prost(int64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub max_tx_bytes: i64,
     /// txs is an array of transactions that will be included in a block,
     /// sent to the app for possible modifications.
     #[prost(bytes="bytes", repeated, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<String>"))]
     pub txs: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
     #[prost(message, optional, tag="3")]
     pub local_last_commit: ::core::option::Option<ExtendedCommitInfo>,
     #[prost(message, repeated, tag="4")]
     pub misbehavior: ::prost::alloc::vec::Vec<Misbehavior>,
     #[prost(int64, tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub height: i64,
     #[prost(message, optional, tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(bytes="bytes", tag="7")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub next_validators_hash: ::prost::bytes::Bytes,
     /// address of the public key of the validator proposing the block.
     #[prost(bytes="bytes", tag="8")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposer_address: ::prost::bytes::Bytes,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct RequestProcessProposal {
     #[prost(bytes="bytes", repeated, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<String>"))]
     pub txs: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
     #[prost(message, optional, tag="2")]
     pub proposed_last_commit: ::core::option::Option<CommitInfo>,
@@ -210,15 +251,20 @@ pub struct RequestProcessProposal {
     pub misbehavior: ::prost::alloc::vec::Vec<Misbehavior>,
     /// hash is the merkle root hash of the fields of the proposed block.
     #[prost(bytes="bytes", tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub hash: ::prost::bytes::Bytes,
     #[prost(int64, tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub height: i64,
     #[prost(message, optional, tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(bytes="bytes", tag="7")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub next_validators_hash: ::prost::bytes::Bytes,
     /// address of the public key of the original proposer of the block.
     #[prost(bytes="bytes", tag="8")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub proposer_address: ::prost::bytes::Bytes,
 }
 // ----------------------------------------
@@ -226,14 +272,17 @@ pub struct RequestProcessProposal {
 
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Response {
     #[prost(oneof="response::Value", tags="1, 2, 3, 4, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub value: ::core::option::Option<response::Value>,
 }
 /// Nested message and enum types in `Response`.
 pub mod response {
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Oneof)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase"), schemars(crate = "::cosmwasm_schema::schemars"))]
     pub enum Value {
         #[prost(message, tag="1")]
         Exception(super::ResponseException),
@@ -274,46 +323,56 @@ pub mod response {
 /// nondeterministic
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ResponseException {
     #[prost(string, tag="1")]
     pub error: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ResponseEcho {
     #[prost(string, tag="1")]
     pub message: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ResponseFlush {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ResponseInfo {
     #[prost(string, tag="1")]
     pub data: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
     pub version: ::prost::alloc::string::String,
     #[prost(uint64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub app_version: u64,
     #[prost(int64, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub last_block_height: i64,
     #[prost(bytes="bytes", tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub last_block_app_hash: ::prost::bytes::Bytes,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ResponseInitChain {
     #[prost(message, optional, tag="1")]
     pub consensus_params: ::core::option::Option<super::types::ConsensusParams>,
     #[prost(message, repeated, tag="2")]
     pub validators: ::prost::alloc::vec::Vec<ValidatorUpdate>,
     #[prost(bytes="bytes", tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub app_hash: ::prost::bytes::Bytes,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ResponseQuery {
     #[prost(uint32, tag="1")]
     pub code: u32,
@@ -326,30 +385,37 @@ pub struct ResponseQuery {
     #[prost(string, tag="4")]
     pub info: ::prost::alloc::string::String,
     #[prost(int64, tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub index: i64,
     #[prost(bytes="bytes", tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub key: ::prost::bytes::Bytes,
     #[prost(bytes="bytes", tag="7")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub value: ::prost::bytes::Bytes,
     #[prost(message, optional, tag="8")]
     pub proof_ops: ::core::option::Option<super::crypto::ProofOps>,
     #[prost(int64, tag="9")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub height: i64,
     #[prost(string, tag="10")]
     pub codespace: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ResponseBeginBlock {
     #[prost(message, repeated, tag="1")]
     pub events: ::prost::alloc::vec::Vec<Event>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ResponseCheckTx {
     #[prost(uint32, tag="1")]
     pub code: u32,
     #[prost(bytes="bytes", tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub data: ::prost::bytes::Bytes,
     /// nondeterministic
     #[prost(string, tag="3")]
@@ -358,8 +424,10 @@ pub struct ResponseCheckTx {
     #[prost(string, tag="4")]
     pub info: ::prost::alloc::string::String,
     #[prost(int64, tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub gas_wanted: i64,
     #[prost(int64, tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub gas_used: i64,
     #[prost(message, repeated, tag="7")]
     pub events: ::prost::alloc::vec::Vec<Event>,
@@ -368,6 +436,7 @@ pub struct ResponseCheckTx {
     #[prost(string, tag="9")]
     pub sender: ::prost::alloc::string::String,
     #[prost(int64, tag="10")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub priority: i64,
     /// mempool_error is set by CometBFT.
     /// ABCI applictions creating a ResponseCheckTX should not set mempool_error.
@@ -376,10 +445,12 @@ pub struct ResponseCheckTx {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ResponseDeliverTx {
     #[prost(uint32, tag="1")]
     pub code: u32,
     #[prost(bytes="bytes", tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub data: ::prost::bytes::Bytes,
     /// nondeterministic
     #[prost(string, tag="3")]
@@ -388,8 +459,10 @@ pub struct ResponseDeliverTx {
     #[prost(string, tag="4")]
     pub info: ::prost::alloc::string::String,
     #[prost(int64, tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub gas_wanted: i64,
     #[prost(int64, tag="6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub gas_used: i64,
     /// nondeterministic
     #[prost(message, repeated, tag="7")]
@@ -399,6 +472,7 @@ pub struct ResponseDeliverTx {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ResponseEndBlock {
     #[prost(message, repeated, tag="1")]
     pub validator_updates: ::prost::alloc::vec::Vec<ValidatorUpdate>,
@@ -409,41 +483,54 @@ pub struct ResponseEndBlock {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ResponseCommit {
     /// reserve 1
     #[prost(bytes="bytes", tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub data: ::prost::bytes::Bytes,
     #[prost(int64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub retain_height: i64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ResponseListSnapshots {
     #[prost(message, repeated, tag="1")]
     pub snapshots: ::prost::alloc::vec::Vec<Snapshot>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ResponseOfferSnapshot {
     #[prost(enumeration="response_offer_snapshot::Result", tag="1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<response_offer_snapshot::Result, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<response_offer_snapshot::Result, _>"), schemars(with = "response_offer_snapshot::Result"))]
     pub result: i32,
 }
 /// Nested message and enum types in `ResponseOfferSnapshot`.
 pub mod response_offer_snapshot {
     #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
     #[repr(i32)]
     pub enum Result {
         /// Unknown result, abort all snapshot restoration
+        #[cfg_attr(feature = "serde", serde(rename = "UNKNOWN"))]
         Unknown = 0,
         /// Snapshot accepted, apply chunks
+        #[cfg_attr(feature = "serde", serde(rename = "ACCEPT"))]
         Accept = 1,
         /// Abort all snapshot restoration
+        #[cfg_attr(feature = "serde", serde(rename = "ABORT"))]
         Abort = 2,
         /// Reject this specific snapshot, try others
+        #[cfg_attr(feature = "serde", serde(rename = "REJECT"))]
         Reject = 3,
         /// Reject all snapshots of this format, try others
+        #[cfg_attr(feature = "serde", serde(rename = "REJECT_FORMAT"))]
         RejectFormat = 4,
         /// Reject all snapshots from the sender(s), try others
+        #[cfg_attr(feature = "serde", serde(rename = "REJECT_SENDER"))]
         RejectSender = 5,
     }
     impl Result {
@@ -477,14 +564,18 @@ pub mod response_offer_snapshot {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ResponseLoadSnapshotChunk {
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub chunk: ::prost::bytes::Bytes,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ResponseApplySnapshotChunk {
     #[prost(enumeration="response_apply_snapshot_chunk::Result", tag="1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<response_apply_snapshot_chunk::Result, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<response_apply_snapshot_chunk::Result, _>"), schemars(with = "response_apply_snapshot_chunk::Result"))]
     pub result: i32,
     /// Chunks to refetch and reapply
     #[prost(uint32, repeated, tag="2")]
@@ -496,19 +587,26 @@ pub struct ResponseApplySnapshotChunk {
 /// Nested message and enum types in `ResponseApplySnapshotChunk`.
 pub mod response_apply_snapshot_chunk {
     #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
     #[repr(i32)]
     pub enum Result {
         /// Unknown result, abort all snapshot restoration
+        #[cfg_attr(feature = "serde", serde(rename = "UNKNOWN"))]
         Unknown = 0,
         /// Chunk successfully accepted
+        #[cfg_attr(feature = "serde", serde(rename = "ACCEPT"))]
         Accept = 1,
         /// Abort all snapshot restoration
+        #[cfg_attr(feature = "serde", serde(rename = "ABORT"))]
         Abort = 2,
         /// Retry chunk (combine with refetch and reject)
+        #[cfg_attr(feature = "serde", serde(rename = "RETRY"))]
         Retry = 3,
         /// Retry snapshot (combine with refetch and reject)
+        #[cfg_attr(feature = "serde", serde(rename = "RETRY_SNAPSHOT"))]
         RetrySnapshot = 4,
         /// Reject this snapshot, try others
+        #[cfg_attr(feature = "serde", serde(rename = "REJECT_SNAPSHOT"))]
         RejectSnapshot = 5,
     }
     impl Result {
@@ -542,23 +640,31 @@ pub mod response_apply_snapshot_chunk {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ResponsePrepareProposal {
     #[prost(bytes="bytes", repeated, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::vec"), schemars(with = "Vec<String>"))]
     pub txs: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ResponseProcessProposal {
     #[prost(enumeration="response_process_proposal::ProposalStatus", tag="1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<response_process_proposal::ProposalStatus, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<response_process_proposal::ProposalStatus, _>"), schemars(with = "response_process_proposal::ProposalStatus"))]
     pub status: i32,
 }
 /// Nested message and enum types in `ResponseProcessProposal`.
 pub mod response_process_proposal {
     #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
     #[repr(i32)]
     pub enum ProposalStatus {
+        #[cfg_attr(feature = "serde", serde(rename = "UNKNOWN"))]
         Unknown = 0,
+        #[cfg_attr(feature = "serde", serde(rename = "ACCEPT"))]
         Accept = 1,
+        #[cfg_attr(feature = "serde", serde(rename = "REJECT"))]
         Reject = 2,
     }
     impl ProposalStatus {
@@ -589,6 +695,7 @@ pub mod response_process_proposal {
 
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct CommitInfo {
     #[prost(int32, tag="1")]
     pub round: i32,
@@ -597,6 +704,7 @@ pub struct CommitInfo {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ExtendedCommitInfo {
     /// The round at which the block proposer decided in the previous height.
     #[prost(int32, tag="1")]
@@ -611,6 +719,7 @@ pub struct ExtendedCommitInfo {
 /// Later, transactions may be queried using these events.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Event {
     #[prost(string, tag="1")]
     pub r#type: ::prost::alloc::string::String,
@@ -620,6 +729,7 @@ pub struct Event {
 /// EventAttribute is a single key-value pair, associated with an event.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct EventAttribute {
     #[prost(string, tag="1")]
     pub key: ::prost::alloc::string::String,
@@ -634,12 +744,15 @@ pub struct EventAttribute {
 /// One usage is indexing transaction results.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct TxResult {
     #[prost(int64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub height: i64,
     #[prost(uint32, tag="2")]
     pub index: u32,
     #[prost(bytes="bytes", tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub tx: ::prost::bytes::Bytes,
     #[prost(message, optional, tag="4")]
     pub result: ::core::option::Option<ResponseDeliverTx>,
@@ -650,28 +763,34 @@ pub struct TxResult {
 /// Validator
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Validator {
     /// The first 20 bytes of SHA256(public key)
     #[prost(bytes="bytes", tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub address: ::prost::bytes::Bytes,
     /// PubKey pub_key = 2 \[(gogoproto.nullable)=false\];
     ///
     /// The voting power
     #[prost(int64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub power: i64,
 }
 /// ValidatorUpdate
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ValidatorUpdate {
     #[prost(message, optional, tag="1")]
     pub pub_key: ::core::option::Option<super::crypto::PublicKey>,
     #[prost(int64, tag="2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub power: i64,
 }
 /// VoteInfo
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct VoteInfo {
     #[prost(message, optional, tag="1")]
     pub validator: ::core::option::Option<Validator>,
@@ -680,6 +799,7 @@ pub struct VoteInfo {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct ExtendedVoteInfo {
     #[prost(message, optional, tag="1")]
     pub validator: ::core::option::Option<Validator>,
@@ -687,26 +807,32 @@ pub struct ExtendedVoteInfo {
     pub signed_last_block: bool,
     /// Reserved for future use
     #[prost(bytes="bytes", tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub vote_extension: ::prost::bytes::Bytes,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Misbehavior {
     #[prost(enumeration="MisbehaviorType", tag="1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<MisbehaviorType, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<MisbehaviorType, _>"), schemars(with = "MisbehaviorType"))]
     pub r#type: i32,
     /// The offending validator
     #[prost(message, optional, tag="2")]
     pub validator: ::core::option::Option<Validator>,
     /// The height when the offense occurred
     #[prost(int64, tag="3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub height: i64,
     /// The corresponding time where the offense occurred
     #[prost(message, optional, tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::option"), schemars(with = "Option<String>"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
     /// Total voting power of the validator set in case the ABCI application does
     /// not store historical validators.
     /// <https://github.com/tendermint/tendermint/issues/4581>
     #[prost(int64, tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub total_voting_power: i64,
 }
 // ----------------------------------------
@@ -714,9 +840,11 @@ pub struct Misbehavior {
 
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
 pub struct Snapshot {
     /// The height at which the snapshot was taken
     #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub height: u64,
     /// The application-specific snapshot format
     #[prost(uint32, tag="2")]
@@ -726,15 +854,20 @@ pub struct Snapshot {
     pub chunks: u32,
     /// Arbitrary snapshot hash, equal only if identical
     #[prost(bytes="bytes", tag="4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub hash: ::prost::bytes::Bytes,
     /// Arbitrary application metadata
     #[prost(bytes="bytes", tag="5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
     pub metadata: ::prost::bytes::Bytes,
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
 #[repr(i32)]
 pub enum CheckTxType {
+    #[cfg_attr(feature = "serde", serde(rename = "NEW"))]
     New = 0,
+    #[cfg_attr(feature = "serde", serde(rename = "RECHECK"))]
     Recheck = 1,
 }
 impl CheckTxType {
@@ -758,10 +891,14 @@ impl CheckTxType {
     }
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
 #[repr(i32)]
 pub enum MisbehaviorType {
+    #[cfg_attr(feature = "serde", serde(rename = "UNKNOWN"))]
     Unknown = 0,
+    #[cfg_attr(feature = "serde", serde(rename = "DUPLICATE_VOTE"))]
     DuplicateVote = 1,
+    #[cfg_attr(feature = "serde", serde(rename = "LIGHT_CLIENT_ATTACK"))]
     LightClientAttack = 2,
 }
 impl MisbehaviorType {
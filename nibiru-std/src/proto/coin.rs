@@ -0,0 +1,323 @@
+//! nibiru-std::proto - coin.rs : Conversions between the protobuf `Coin`,
+//! `DecCoin`, and bank `Metadata` types and their `cosmwasm_std` counterparts.
+
+use std::str::FromStr;
+
+use cosmwasm_std as cw;
+
+#[cfg(feature = "bank")]
+use crate::proto::cosmos::bank::v1beta1::{DenomUnit, Metadata};
+use crate::{
+    errors::{NibiruError, NibiruResult},
+    math::SdkDec256,
+    proto::cosmos::base::v1beta1::{Coin, DecCoin},
+};
+
+impl From<cw::Coin> for Coin {
+    fn from(cw_coin: cw::Coin) -> Self {
+        Coin {
+            denom: cw_coin.denom,
+            amount: cw_coin.amount.to_string(),
+        }
+    }
+}
+
+impl TryFrom<&Coin> for cw::Coin {
+    type Error = NibiruError;
+
+    /// Parses the `amount` of a protobuf `Coin`, which is the string form of
+    /// an unsigned integer.
+    fn try_from(pb_coin: &Coin) -> Result<Self, Self::Error> {
+        let amount = cw::Uint128::from_str(&pb_coin.amount).map_err(|err| {
+            NibiruError::InvalidCoinAmount {
+                denom: pb_coin.denom.clone(),
+                amount: pb_coin.amount.clone(),
+                reason: err.to_string(),
+            }
+        })?;
+        Ok(cw::Coin::new(amount.u128(), pb_coin.denom.clone()))
+    }
+}
+
+impl TryFrom<Coin> for cw::Coin {
+    type Error = NibiruError;
+
+    fn try_from(pb_coin: Coin) -> Result<Self, Self::Error> {
+        cw::Coin::try_from(&pb_coin)
+    }
+}
+
+impl From<cw::DecCoin> for DecCoin {
+    fn from(cw_coin: cw::DecCoin) -> Self {
+        DecCoin {
+            denom: cw_coin.denom,
+            amount: cw_coin.amount.atomics().to_string(),
+        }
+    }
+}
+
+impl TryFrom<&DecCoin> for cw::DecCoin {
+    type Error = NibiruError;
+
+    /// Parses the `amount` of a protobuf `DecCoin`, which is the protobuf
+    /// representation of a `"cosmossdk.io/math".LegacyDec`.
+    fn try_from(pb_coin: &DecCoin) -> Result<Self, Self::Error> {
+        let invalid_amount = |reason: String| NibiruError::InvalidCoinAmount {
+            denom: pb_coin.denom.clone(),
+            amount: pb_coin.amount.clone(),
+            reason,
+        };
+        let sdk_dec = SdkDec256::from_pb_repr(&pb_coin.amount)
+            .map_err(|err| invalid_amount(err.to_string()))?;
+        let amount = cw::Decimal256::try_from(sdk_dec)
+            .map_err(|err| invalid_amount(err.to_string()))?;
+        Ok(cw::DecCoin::new(amount, pb_coin.denom.clone()))
+    }
+}
+
+impl TryFrom<DecCoin> for cw::DecCoin {
+    type Error = NibiruError;
+
+    fn try_from(pb_coin: DecCoin) -> Result<Self, Self::Error> {
+        cw::DecCoin::try_from(&pb_coin)
+    }
+}
+
+#[cfg(feature = "bank")]
+impl From<Metadata> for cw::DenomMetadata {
+    fn from(pb: Metadata) -> Self {
+        cw::DenomMetadata {
+            description: pb.description,
+            denom_units: pb
+                .denom_units
+                .into_iter()
+                .map(|unit| cw::DenomUnit {
+                    denom: unit.denom,
+                    exponent: unit.exponent,
+                    aliases: unit.aliases,
+                })
+                .collect(),
+            base: pb.base,
+            display: pb.display,
+            name: pb.name,
+            symbol: pb.symbol,
+            uri: pb.uri,
+            uri_hash: pb.uri_hash,
+        }
+    }
+}
+
+#[cfg(feature = "bank")]
+impl From<cw::DenomMetadata> for Metadata {
+    fn from(cw_metadata: cw::DenomMetadata) -> Self {
+        Metadata {
+            description: cw_metadata.description,
+            denom_units: cw_metadata
+                .denom_units
+                .into_iter()
+                .map(|unit| DenomUnit {
+                    denom: unit.denom,
+                    exponent: unit.exponent,
+                    aliases: unit.aliases,
+                })
+                .collect(),
+            base: cw_metadata.base,
+            display: cw_metadata.display,
+            name: cw_metadata.name,
+            symbol: cw_metadata.symbol,
+            uri: cw_metadata.uri,
+            uri_hash: cw_metadata.uri_hash,
+        }
+    }
+}
+
+/// Converts protobuf coins, such as the `balances` of a bank query response,
+/// to `cosmwasm_std::Coin`s, keeping their order.
+///
+/// ```rust
+/// use cosmwasm_std as cw;
+/// use nibiru_std::proto::{cosmos, cw_coins_from_proto};
+///
+/// let resp = cosmos::bank::v1beta1::QueryAllBalancesResponse {
+///     balances: vec![cosmos::base::v1beta1::Coin {
+///         denom: "unibi".to_string(),
+///         amount: "420".to_string(),
+///     }],
+///     pagination: None,
+/// };
+/// let balances = cw_coins_from_proto(resp.balances).unwrap();
+/// assert_eq!(balances, vec![cw::coin(420, "unibi")]);
+/// ```
+pub fn cw_coins_from_proto<I>(pb_coins: I) -> NibiruResult<Vec<cw::Coin>>
+where
+    I: IntoIterator,
+    I::Item: TryInto<cw::Coin, Error = NibiruError>,
+{
+    pb_coins.into_iter().map(TryInto::try_into).collect()
+}
+
+/// Converts protobuf coins to a `cosmwasm_std::Coins` set. Errors on
+/// duplicate denoms, in addition to invalid amounts. Zero amounts are
+/// dropped.
+pub fn cw_coins_set_from_proto<I>(pb_coins: I) -> NibiruResult<cw::Coins>
+where
+    I: IntoIterator,
+    I::Item: TryInto<cw::Coin, Error = NibiruError>,
+{
+    Ok(cw::Coins::try_from(cw_coins_from_proto(pb_coins)?)?)
+}
+
+/// Converts protobuf dec coins, such as distribution rewards, to
+/// `cosmwasm_std::DecCoin`s, keeping their order.
+pub fn cw_dec_coins_from_proto<I>(pb_coins: I) -> NibiruResult<Vec<cw::DecCoin>>
+where
+    I: IntoIterator,
+    I::Item: TryInto<cw::DecCoin, Error = NibiruError>,
+{
+    pb_coins.into_iter().map(TryInto::try_into).collect()
+}
+
+/// Converts `cosmwasm_std::Coin`s, or a `cosmwasm_std::Coins` set, to
+/// protobuf coins for use in a message.
+pub fn proto_coins_from_cw(
+    cw_coins: impl IntoIterator<Item = cw::Coin>,
+) -> Vec<Coin> {
+    cw_coins.into_iter().map(Coin::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std as cw;
+
+    use super::*;
+    use crate::errors::TestResult;
+
+    fn pb_coin(amount: &str, denom: &str) -> Coin {
+        Coin {
+            denom: denom.to_string(),
+            amount: amount.to_string(),
+        }
+    }
+
+    fn pb_dec_coin(amount: &str, denom: &str) -> DecCoin {
+        DecCoin {
+            denom: denom.to_string(),
+            amount: amount.to_string(),
+        }
+    }
+
+    #[test]
+    fn coin_round_trip() -> TestResult {
+        let cw_coin = cw::coin(123, "unibi");
+        let pb = Coin::from(cw_coin.clone());
+        assert_eq!(pb, pb_coin("123", "unibi"));
+        assert_eq!(cw::Coin::try_from(&pb)?, cw_coin);
+
+        let max = cw::Uint128::MAX.to_string();
+        assert_eq!(
+            cw::Coin::try_from(pb_coin(&max, "unibi"))?.amount,
+            cw::Uint128::MAX
+        );
+
+        for bad_amount in ["", "-1", "1.5", "abc", &format!("{max}0")] {
+            let err = cw::Coin::try_from(pb_coin(bad_amount, "unibi"))
+                .expect_err(bad_amount);
+            assert!(
+                matches!(err, NibiruError::InvalidCoinAmount { ref amount, .. } if amount == bad_amount),
+                "{err}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn dec_coin_round_trip() -> TestResult {
+        let test_cases: &[(&str, &str)] = &[
+            ("1500000000000000000", "1.5"),
+            ("000000000000000000", "0"),
+            ("1", "0.000000000000000001"),
+            ("1000000000000000000000000000000", "1000000000000"),
+        ];
+        for &(pb_amount, want) in test_cases {
+            let cw_coin =
+                cw::DecCoin::try_from(pb_dec_coin(pb_amount, "unibi"))?;
+            assert_eq!(cw_coin.amount, cw::Decimal256::from_str(want)?);
+            assert_eq!(cw_coin.denom, "unibi");
+
+            let pb = DecCoin::from(cw_coin.clone());
+            assert_eq!(cw::DecCoin::try_from(&pb)?, cw_coin);
+        }
+
+        for bad_amount in ["-1500000000000000000", "1.5", "abc"] {
+            let err = cw::DecCoin::try_from(pb_dec_coin(bad_amount, "unibi"))
+                .expect_err(bad_amount);
+            assert!(
+                matches!(err, NibiruError::InvalidCoinAmount { .. }),
+                "{err}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "bank")]
+    fn metadata_round_trip() {
+        let cw_metadata = cw::DenomMetadata {
+            description: "Nibiru".to_string(),
+            denom_units: vec![
+                cw::DenomUnit {
+                    denom: "unibi".to_string(),
+                    exponent: 0,
+                    aliases: vec!["micronibi".to_string()],
+                },
+                cw::DenomUnit {
+                    denom: "nibi".to_string(),
+                    exponent: 6,
+                    aliases: vec![],
+                },
+            ],
+            base: "unibi".to_string(),
+            display: "nibi".to_string(),
+            name: "Nibiru".to_string(),
+            symbol: "NIBI".to_string(),
+            uri: "".to_string(),
+            uri_hash: "".to_string(),
+        };
+        let pb = Metadata::from(cw_metadata.clone());
+        assert_eq!(pb.denom_units[0].aliases, vec!["micronibi"]);
+        assert_eq!(cw::DenomMetadata::from(pb), cw_metadata);
+    }
+
+    #[test]
+    fn coin_collections() -> TestResult {
+        let pb_coins = vec![pb_coin("5", "uusd"), pb_coin("7", "unibi")];
+        let want = vec![cw::coin(5, "uusd"), cw::coin(7, "unibi")];
+        assert_eq!(cw_coins_from_proto(&pb_coins)?, want);
+        assert_eq!(cw_coins_from_proto(pb_coins.clone())?, want);
+
+        let coins_set = cw_coins_set_from_proto(&pb_coins)?;
+        assert_eq!(coins_set.amount_of("unibi").u128(), 7);
+        assert_eq!(proto_coins_from_cw(coins_set), {
+            let mut sorted = pb_coins.clone();
+            sorted.reverse();
+            sorted
+        });
+        assert_eq!(proto_coins_from_cw(want), pb_coins);
+
+        let dup = vec![pb_coin("5", "unibi"), pb_coin("7", "unibi")];
+        assert!(matches!(
+            cw_coins_set_from_proto(dup),
+            Err(NibiruError::CoinsError(_))
+        ));
+        assert!(cw_coins_from_proto(vec![pb_coin("x", "unibi")]).is_err());
+
+        let dec_coins = vec![pb_dec_coin("2500000000000000000", "unibi")];
+        assert_eq!(
+            cw_dec_coins_from_proto(&dec_coins)?,
+            vec![cw::DecCoin::new(cw::Decimal256::from_str("2.5")?, "unibi")]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,795 @@
+//! nibiru-std::proto - json.rs : Serde support for the generated protobuf
+//! types under the "serde" feature, following the protobuf JSON mapping.
+//!
+//! The attributes in `proto/buf` point fields that JSON does not encode
+//! natively to the modules below:
+//! - 64-bit integers are strings, since JSON numbers lose precision past 2^53.
+//! - bytes are base64 strings.
+//! - `Timestamp` is an RFC 3339 string and `Duration` is a string like "1.5s".
+//! - `Any` is the JSON of the packed message with an extra "@type" field.
+//! - enums are the names of their values, Ex.: "VOTE_OPTION_YES".
+
+use std::fmt;
+
+use cosmwasm_std as cw;
+use prost::Message;
+use serde::{
+    de::{self, DeserializeOwned},
+    ser, Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::proto::{type_url_cosmos, type_url_ibc, type_url_nibiru};
+
+/// A field type with a protobuf JSON representation that differs from the
+/// serde default.
+pub trait ProtoJson: Sized {
+    fn serialize_json<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>;
+
+    fn deserialize_json<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error>;
+}
+
+/// Serializes a `T: ProtoJson` by reference.
+struct Json<'a, T>(&'a T);
+
+impl<T: ProtoJson> Serialize for Json<'_, T> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_json(s)
+    }
+}
+
+/// Deserializes an owned `T: ProtoJson`.
+struct Owned<T>(T);
+
+impl<'de, T: ProtoJson> Deserialize<'de> for Owned<T> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        T::deserialize_json(d).map(Owned)
+    }
+}
+
+/// `serde(with)` module for a singular field.
+pub mod value {
+    use super::*;
+
+    pub fn serialize<T: ProtoJson, S: Serializer>(
+        value: &T,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize_json(s)
+    }
+
+    pub fn deserialize<'de, T: ProtoJson, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<T, D::Error> {
+        T::deserialize_json(d)
+    }
+}
+
+/// `serde(with)` module for an optional field, such as a message field. Only
+/// the packages with optional well-known types use it.
+#[cfg(any(
+    feature = "auth",
+    feature = "authz",
+    feature = "crypto",
+    feature = "feegrant",
+    feature = "gov",
+    feature = "group",
+    feature = "nft",
+    feature = "slashing",
+    feature = "staking",
+    feature = "tx",
+    feature = "upgrade",
+    feature = "vesting",
+    feature = "abci",
+    feature = "tendermint",
+    feature = "epochs",
+    feature = "oracle",
+    feature = "perp",
+    feature = "evm",
+    feature = "ibc"
+))]
+pub mod option {
+    use super::*;
+
+    pub fn serialize<T: ProtoJson, S: Serializer>(
+        value: &Option<T>,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.as_ref().map(Json).serialize(s)
+    }
+
+    pub fn deserialize<'de, T: ProtoJson, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Option<T>, D::Error> {
+        let value: Option<Owned<T>> = Deserialize::deserialize(d)?;
+        Ok(value.map(|Owned(inner)| inner))
+    }
+}
+
+/// `serde(with)` module for a repeated field.
+pub mod vec {
+    use super::*;
+
+    pub fn serialize<T: ProtoJson, S: Serializer>(
+        value: &[T],
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        s.collect_seq(value.iter().map(Json))
+    }
+
+    pub fn deserialize<'de, T: ProtoJson, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Vec<T>, D::Error> {
+        let value: Vec<Owned<T>> = Deserialize::deserialize(d)?;
+        Ok(value.into_iter().map(|Owned(inner)| inner).collect())
+    }
+}
+
+/// `serialize_with` and `deserialize_with` functions for enum fields, which
+/// prost stores as `i32`. Values outside of the enum stay numbers. Only the
+/// packages with enums use them.
+#[cfg(any(
+    feature = "gov",
+    feature = "group",
+    feature = "staking",
+    feature = "tx",
+    feature = "abci",
+    feature = "tendermint",
+    feature = "perp",
+    feature = "spot",
+    feature = "ibc"
+))]
+pub mod enumeration {
+    use super::*;
+
+    pub fn serialize<E, S>(value: &i32, s: S) -> Result<S::Ok, S::Error>
+    where
+        E: TryFrom<i32> + Serialize,
+        S: Serializer,
+    {
+        match E::try_from(*value) {
+            Ok(known) => known.serialize(s),
+            Err(_) => s.serialize_i32(*value),
+        }
+    }
+
+    /// Accepts either the name of an enum value or its number.
+    pub fn deserialize<'de, E, D>(d: D) -> Result<i32, D::Error>
+    where
+        E: DeserializeOwned + Into<i32>,
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(EnumVisitor::<E>(std::marker::PhantomData))
+    }
+
+    struct EnumVisitor<E>(std::marker::PhantomData<E>);
+
+    impl<E: DeserializeOwned + Into<i32>> de::Visitor<'_> for EnumVisitor<E> {
+        type Value = i32;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("an enum value name or number")
+        }
+
+        fn visit_str<Err: de::Error>(self, v: &str) -> Result<i32, Err> {
+            let de = de::value::StrDeserializer::<Err>::new(v);
+            E::deserialize(de).map(Into::into)
+        }
+
+        fn visit_i64<Err: de::Error>(self, v: i64) -> Result<i32, Err> {
+            i32::try_from(v).map_err(Err::custom)
+        }
+
+        fn visit_u64<Err: de::Error>(self, v: u64) -> Result<i32, Err> {
+            i32::try_from(v).map_err(Err::custom)
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// 64-bit integers
+// ---------------------------------------------------------------------------
+
+/// Accepts a 64-bit integer as either a string or a JSON number.
+struct IntVisitor<T>(std::marker::PhantomData<T>);
+
+impl<T> de::Visitor<'_> for IntVisitor<T>
+where
+    T: std::str::FromStr + TryFrom<i64> + TryFrom<u64>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a 64-bit integer or a string holding one")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        v.parse()
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        T::try_from(v)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        T::try_from(v)
+            .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+}
+
+macro_rules! impl_proto_json_int {
+    ($($int:ty),*) => {$(
+        impl ProtoJson for $int {
+            fn serialize_json<S: Serializer>(
+                &self,
+                s: S,
+            ) -> Result<S::Ok, S::Error> {
+                s.collect_str(self)
+            }
+
+            fn deserialize_json<'de, D: Deserializer<'de>>(
+                d: D,
+            ) -> Result<Self, D::Error> {
+                d.deserialize_any(IntVisitor(std::marker::PhantomData))
+            }
+        }
+    )*};
+}
+
+impl_proto_json_int!(i64, u64);
+
+// ---------------------------------------------------------------------------
+// bytes
+// ---------------------------------------------------------------------------
+
+fn serialize_base64<S: Serializer>(bz: &[u8], s: S) -> Result<S::Ok, S::Error> {
+    cw::Binary::from(bz).serialize(s)
+}
+
+fn deserialize_base64<'de, D: Deserializer<'de>>(
+    d: D,
+) -> Result<Vec<u8>, D::Error> {
+    Ok(cw::Binary::deserialize(d)?.to_vec())
+}
+
+impl ProtoJson for Vec<u8> {
+    fn serialize_json<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serialize_base64(self, s)
+    }
+
+    fn deserialize_json<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Self, D::Error> {
+        deserialize_base64(d)
+    }
+}
+
+impl ProtoJson for prost::bytes::Bytes {
+    fn serialize_json<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serialize_base64(self, s)
+    }
+
+    fn deserialize_json<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Self, D::Error> {
+        deserialize_base64(d).map(Into::into)
+    }
+}
+
+/// `FileDescriptorProto` has no serde support in `prost_types`, so it is
+/// kept as base64 encoded protobuf bytes.
+impl ProtoJson for prost_types::FileDescriptorProto {
+    fn serialize_json<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serialize_base64(&self.encode_to_vec(), s)
+    }
+
+    fn deserialize_json<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Self, D::Error> {
+        let bz = deserialize_base64(d)?;
+        Self::decode(bz.as_slice()).map_err(de::Error::custom)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Timestamp and Duration
+// ---------------------------------------------------------------------------
+
+const NANOS_PER_SEC: i64 = 1_000_000_000;
+const SECS_PER_DAY: i64 = 86_400;
+
+/// Formats nanoseconds as a fraction with 0, 3, 6, or 9 digits, the way the
+/// protobuf JSON mapping prints them.
+fn fmt_nanos(nanos: i64) -> String {
+    if nanos == 0 {
+        String::new()
+    } else if nanos % 1_000_000 == 0 {
+        format!(".{:03}", nanos / 1_000_000)
+    } else if nanos % 1_000 == 0 {
+        format!(".{:06}", nanos / 1_000)
+    } else {
+        format!(".{nanos:09}")
+    }
+}
+
+/// Parses the digits after a decimal point as nanoseconds.
+fn parse_nanos(frac: &str) -> Option<i64> {
+    let is_digits = frac.bytes().all(|b| b.is_ascii_digit());
+    if frac.is_empty() || frac.len() > 9 || !is_digits {
+        return None;
+    }
+    let digits: i64 = frac.parse().ok()?;
+    Some(digits * 10_i64.pow(9 - frac.len() as u32))
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_idx = (month + 9) % 12;
+    let day_of_year = (153 * month_idx + 2) / 5 + day - 1;
+    let day_of_era =
+        year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian (year, month, day) of a count of days since
+/// 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524
+        - day_of_era / 146_096)
+        / 365;
+    let day_of_year =
+        day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_idx = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_idx + 2) / 5 + 1;
+    let month = if month_idx < 10 {
+        month_idx + 3
+    } else {
+        month_idx - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Formats a timestamp as RFC 3339 in UTC, Ex.: "2023-11-14T22:13:20.5Z".
+fn fmt_timestamp(ts: &prost_types::Timestamp) -> Option<String> {
+    let nanos = i64::from(ts.nanos);
+    if !(0..NANOS_PER_SEC).contains(&nanos) {
+        return None;
+    }
+    let (year, month, day) =
+        civil_from_days(ts.seconds.div_euclid(SECS_PER_DAY));
+    if !(1..=9999).contains(&year) {
+        return None;
+    }
+    let secs_of_day = ts.seconds.rem_euclid(SECS_PER_DAY);
+    Some(format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}{}Z",
+        secs_of_day / 3600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60,
+        fmt_nanos(nanos)
+    ))
+}
+
+/// Parses an RFC 3339 timestamp with a "Z" or "±hh:mm" offset.
+fn parse_timestamp(s: &str) -> Option<prost_types::Timestamp> {
+    let num = |range: std::ops::Range<usize>| -> Option<i64> {
+        let digits = s.get(range)?;
+        digits
+            .bytes()
+            .all(|b| b.is_ascii_digit())
+            .then(|| digits.parse().ok())?
+    };
+    let sep_ok = s.get(4..5) == Some("-")
+        && s.get(7..8) == Some("-")
+        && matches!(s.get(10..11), Some("T" | "t"))
+        && s.get(13..14) == Some(":")
+        && s.get(16..17) == Some(":");
+    if !sep_ok {
+        return None;
+    }
+    let (year, month, day) = (num(0..4)?, num(5..7)?, num(8..10)?);
+    let (hour, minute, second) = (num(11..13)?, num(14..16)?, num(17..19)?);
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return None;
+    }
+
+    let rest = s.get(19..)?;
+    let (nanos, offset) = match rest.strip_prefix('.') {
+        Some(frac_and_offset) => {
+            let frac_len = frac_and_offset
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(frac_and_offset.len());
+            let (frac, offset) = frac_and_offset.split_at(frac_len);
+            (parse_nanos(frac)?, offset)
+        }
+        None => (0, rest),
+    };
+    let offset_secs = match offset {
+        "Z" | "z" => 0,
+        _ => {
+            let sign = match offset.get(0..1)? {
+                "+" => 1,
+                "-" => -1,
+                _ => return None,
+            };
+            let (hh, mm) = offset.get(1..)?.split_once(':')?;
+            if hh.len() != 2 || mm.len() != 2 {
+                return None;
+            }
+            sign * (hh.parse::<i64>().ok()? * 3600
+                + mm.parse::<i64>().ok()? * 60)
+        }
+    };
+    let seconds = days_from_civil(year, month, day) * SECS_PER_DAY
+        + hour * 3600
+        + minute * 60
+        + second
+        - offset_secs;
+    Some(prost_types::Timestamp {
+        seconds,
+        nanos: nanos as i32,
+    })
+}
+
+impl ProtoJson for prost_types::Timestamp {
+    fn serialize_json<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let formatted = fmt_timestamp(self).ok_or_else(|| {
+            ser::Error::custom(format!("timestamp out of range: {self}"))
+        })?;
+        s.serialize_str(&formatted)
+    }
+
+    fn deserialize_json<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Self, D::Error> {
+        let s = String::deserialize(d)?;
+        parse_timestamp(&s).ok_or_else(|| {
+            de::Error::invalid_value(
+                de::Unexpected::Str(&s),
+                &"an RFC 3339 time",
+            )
+        })
+    }
+}
+
+/// Formats a duration as seconds with an "s" suffix, Ex.: "-1.5s".
+fn fmt_duration(dur: &prost_types::Duration) -> String {
+    let sign = if dur.seconds < 0 || dur.nanos < 0 {
+        "-"
+    } else {
+        ""
+    };
+    format!(
+        "{sign}{}{}s",
+        dur.seconds.unsigned_abs(),
+        fmt_nanos(i64::from(dur.nanos).abs())
+    )
+}
+
+fn parse_duration(s: &str) -> Option<prost_types::Duration> {
+    let s = s.strip_suffix('s')?;
+    let (is_negative, s) = match s.strip_prefix('-') {
+        Some(abs) => (true, abs),
+        None => (false, s),
+    };
+    let (secs, nanos) = match s.split_once('.') {
+        Some((secs, frac)) => (secs, parse_nanos(frac)?),
+        None => (s, 0),
+    };
+    if secs.is_empty() || !secs.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let secs: i64 = secs.parse().ok()?;
+    let sign = if is_negative { -1 } else { 1 };
+    Some(prost_types::Duration {
+        seconds: sign * secs,
+        nanos: (sign * nanos) as i32,
+    })
+}
+
+impl ProtoJson for prost_types::Duration {
+    fn serialize_json<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&fmt_duration(self))
+    }
+
+    fn deserialize_json<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Self, D::Error> {
+        let s = String::deserialize(d)?;
+        parse_duration(&s).ok_or_else(|| {
+            de::Error::invalid_value(
+                de::Unexpected::Str(&s),
+                &"a duration like \"1.5s\"",
+            )
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Any
+// ---------------------------------------------------------------------------
+
+/// Converts the protobuf bytes of one message type to and from JSON. The
+/// generated `any_json` functions map each type URL to one of these.
+pub(crate) struct AnyJson {
+    to_json: fn(&[u8]) -> Result<serde_json::Value, String>,
+    from_json: fn(serde_json::Value) -> Result<Vec<u8>, String>,
+}
+
+impl AnyJson {
+    pub(crate) fn of<M>() -> Self
+    where
+        M: Message + Default + Serialize + DeserializeOwned,
+    {
+        AnyJson {
+            to_json: |bz| {
+                let msg = M::decode(bz).map_err(|err| err.to_string())?;
+                serde_json::to_value(msg).map_err(|err| err.to_string())
+            },
+            from_json: |value| {
+                let msg: M = serde_json::from_value(value)
+                    .map_err(|err| err.to_string())?;
+                Ok(msg.encode_to_vec())
+            },
+        }
+    }
+}
+
+fn any_json(type_url: &str) -> Option<AnyJson> {
+    type_url_cosmos::any_json(type_url)
+        .or_else(|| type_url_nibiru::any_json(type_url))
+        .or_else(|| type_url_ibc::any_json(type_url))
+}
+
+const ANY_TYPE_KEY: &str = "@type";
+/// Field that holds the base64 encoded bytes of an `Any` whose type URL is
+/// unknown to nibiru-std.
+const ANY_VALUE_KEY: &str = "value";
+
+impl ProtoJson for prost_types::Any {
+    fn serialize_json<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut fields = match any_json(&self.type_url) {
+            Some(codec) => match (codec.to_json)(&self.value) {
+                Ok(serde_json::Value::Object(fields)) => fields,
+                Ok(_) => {
+                    return Err(ser::Error::custom("expected a JSON object"))
+                }
+                Err(err) => {
+                    return Err(ser::Error::custom(format!(
+                        "failed to decode {}: {err}",
+                        self.type_url
+                    )))
+                }
+            },
+            None => {
+                let mut fields = serde_json::Map::new();
+                let value = cw::Binary::from(self.value.as_slice());
+                fields.insert(ANY_VALUE_KEY.into(), value.to_base64().into());
+                fields
+            }
+        };
+        fields.insert(ANY_TYPE_KEY.into(), self.type_url.clone().into());
+        fields.serialize(s)
+    }
+
+    fn deserialize_json<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Self, D::Error> {
+        let mut fields = serde_json::Map::deserialize(d)?;
+        let type_url = match fields.remove(ANY_TYPE_KEY) {
+            Some(serde_json::Value::String(type_url)) => type_url,
+            _ => return Err(de::Error::missing_field(ANY_TYPE_KEY)),
+        };
+        let value = match any_json(&type_url) {
+            Some(codec) => (codec.from_json)(fields.into()).map_err(|err| {
+                de::Error::custom(format!("{type_url}: {err}"))
+            })?,
+            None => {
+                let value = fields
+                    .remove(ANY_VALUE_KEY)
+                    .ok_or_else(|| de::Error::missing_field(ANY_VALUE_KEY))?;
+                serde_json::from_value::<cw::Binary>(value)
+                    .map_err(de::Error::custom)?
+                    .to_vec()
+            }
+        };
+        Ok(prost_types::Any { type_url, value })
+    }
+}
+
+#[cfg(all(test, feature = "all_protos"))]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        errors::TestResult,
+        proto::{
+            cosmos::{self, base::v1beta1::Coin},
+            NibiruStargateMsg,
+        },
+    };
+
+    #[test]
+    fn timestamps_use_rfc3339() -> TestResult {
+        let test_cases = [
+            (0, 0, "1970-01-01T00:00:00Z"),
+            (1_700_000_000, 500_000_000, "2023-11-14T22:13:20.500Z"),
+            (1_700_000_000, 123_456_789, "2023-11-14T22:13:20.123456789Z"),
+            (951_782_400, 1_000, "2000-02-29T00:00:00.000001Z"),
+            (-86_400, 0, "1969-12-31T00:00:00Z"),
+        ];
+        for (seconds, nanos, want) in test_cases {
+            let ts = prost_types::Timestamp { seconds, nanos };
+            assert_eq!(fmt_timestamp(&ts).as_deref(), Some(want));
+            assert_eq!(parse_timestamp(want), Some(ts));
+        }
+
+        let with_offset = parse_timestamp("2023-11-15T00:13:20+02:00");
+        assert_eq!(with_offset.map(|ts| ts.seconds), Some(1_700_000_000));
+        for bad in ["2023-11-14", "2023-13-01T00:00:00Z", "2023-11-14T22:13:20"]
+        {
+            assert_eq!(parse_timestamp(bad), None, "{bad}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn durations_use_seconds() -> TestResult {
+        let test_cases = [
+            (1, 0, "1s"),
+            (1, 500_000_000, "1.500s"),
+            (0, -1_000, "-0.000001s"),
+            (-3, -1, "-3.000000001s"),
+        ];
+        for (seconds, nanos, want) in test_cases {
+            let dur = prost_types::Duration { seconds, nanos };
+            assert_eq!(fmt_duration(&dur), want);
+            assert_eq!(parse_duration(want), Some(dur));
+        }
+        for bad in ["1", "1.s", "abc s", "1.0000000001s"] {
+            assert_eq!(parse_duration(bad), None, "{bad}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn messages_use_proto_json_mapping() -> TestResult {
+        use cosmos::gov::v1 as gov;
+
+        let vote = gov::MsgVote {
+            proposal_id: 7,
+            voter: "nibi1voter".to_string(),
+            option: gov::VoteOption::NoWithVeto.into(),
+            metadata: String::new(),
+        };
+        let vote_json = serde_json::to_value(&vote)?;
+        assert_eq!(
+            vote_json,
+            json!({
+                "proposalId": "7",
+                "voter": "nibi1voter",
+                "option": "VOTE_OPTION_NO_WITH_VETO",
+                "metadata": "",
+            })
+        );
+        assert_eq!(serde_json::from_value::<gov::MsgVote>(vote_json)?, vote);
+
+        // Numbers are accepted for 64-bit integers and enums, and missing
+        // fields take their default values.
+        let from_numbers: gov::MsgVote = serde_json::from_value(
+            json!({ "proposalId": 7, "voter": "nibi1voter", "option": 4 }),
+        )?;
+        assert_eq!(from_numbers, vote);
+
+        let pubkey = cosmos::crypto::secp256k1::PubKey {
+            key: vec![2; 33].into(),
+        };
+        let pubkey_json = serde_json::to_value(&pubkey)?;
+        assert_eq!(pubkey_json, json!({ "key": "AgIC".repeat(11) }));
+        Ok(())
+    }
+
+    #[test]
+    fn oneofs_flatten_into_the_parent() -> TestResult {
+        use crate::proto::tendermint::crypto::{public_key::Sum, PublicKey};
+
+        let pubkey = PublicKey {
+            sum: Some(Sum::Ed25519(vec![1, 2, 3].into())),
+        };
+        let pubkey_json = serde_json::to_value(&pubkey)?;
+        assert_eq!(pubkey_json, json!({ "ed25519": "AQID" }));
+        assert_eq!(serde_json::from_value::<PublicKey>(pubkey_json)?, pubkey);
+        assert_eq!(
+            serde_json::from_value::<PublicKey>(json!({}))?,
+            PublicKey::default()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn embeds_in_cw_serde_msgs() -> TestResult {
+        use cosmos::bank::v1beta1::MsgSend;
+
+        #[cosmwasm_schema::cw_serde]
+        struct ExecuteMsg {
+            send: MsgSend,
+        }
+
+        let msg: ExecuteMsg = cw::from_json(
+            r#"{"send":{"fromAddress":"nibi1a","toAddress":"nibi1b"}}"#,
+        )?;
+        assert_eq!(msg.send.to_address, "nibi1b");
+        let schema = cosmwasm_schema::schema_for!(ExecuteMsg);
+        let schema_json = serde_json::to_string(&schema)?;
+        assert!(schema_json.contains("\"fromAddress\""), "{schema_json}");
+        Ok(())
+    }
+
+    #[test]
+    fn any_has_type_field() -> TestResult {
+        let send = cosmos::bank::v1beta1::MsgSend {
+            from_address: "nibi1from".to_string(),
+            to_address: "nibi1to".to_string(),
+            amount: vec![Coin {
+                denom: "unibi".to_string(),
+                amount: "5".to_string(),
+            }],
+        };
+        let exec = cosmos::authz::v1beta1::MsgExec {
+            grantee: "nibi1grantee".to_string(),
+            msgs: vec![send.to_any()],
+        };
+        let exec_json = serde_json::to_value(&exec)?;
+        assert_eq!(
+            exec_json,
+            json!({
+                "grantee": "nibi1grantee",
+                "msgs": [{
+                    "@type": "/cosmos.bank.v1beta1.MsgSend",
+                    "fromAddress": "nibi1from",
+                    "toAddress": "nibi1to",
+                    "amount": [{ "denom": "unibi", "amount": "5" }],
+                }],
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<cosmos::authz::v1beta1::MsgExec>(
+                exec_json
+            )?,
+            exec
+        );
+
+        // Unknown type URLs keep their bytes as base64.
+        let unknown = prost_types::Any {
+            type_url: "/unknown.v1.Msg".to_string(),
+            value: vec![1, 2, 3],
+        };
+        let unknown_json = serde_json::to_value(Json(&unknown))?;
+        assert_eq!(
+            unknown_json,
+            json!({ "@type": "/unknown.v1.Msg", "value": "AQID" })
+        );
+        let Owned(round_trip) = serde_json::from_value(unknown_json)?;
+        assert_eq!(unknown, round_trip);
+        Ok(())
+    }
+}
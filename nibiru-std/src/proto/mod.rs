@@ -1,12 +1,35 @@
 //! proto/mod.rs: Protobuf types defined in NibiruChain/nibiru/proto.
 
+mod coin;
+#[cfg(feature = "serde")]
+mod json;
+mod time;
 mod traits;
 mod type_url_cosmos;
+mod type_url_ibc;
 mod type_url_nibiru;
+#[cfg(all(test, feature = "all_protos"))]
+mod type_url_tests;
 
+pub use coin::*;
+pub use time::*;
 pub use traits::*;
 
+// The modules below `include!` prost generated code, which is not expected to
+// follow every clippy lint.
+//
+// Each proto package is gated by the cargo feature named in its
+// `#[cfg(feature = ...)]`. The type_url_gen script reads these attributes to
+// gate the matching `prost::Name` impls, so a new package needs its feature
+// in Cargo.toml and in "all_protos".
+#[allow(
+    clippy::doc_lazy_continuation,
+    clippy::doc_overindented_list_items,
+    clippy::large_enum_variant,
+    clippy::tabs_in_doc_comments
+)]
 pub mod cosmos {
+    #[cfg(feature = "auth")]
     /// Authentication of accounts and transactions.
     pub mod auth {
         pub mod v1beta1 {
@@ -14,12 +37,14 @@ pub mod cosmos {
         }
     }
 
+    #[cfg(feature = "authz")]
     pub mod authz {
         pub mod v1beta1 {
             include!("buf/cosmos.authz.v1beta1.rs");
         }
     }
 
+    #[cfg(feature = "bank")]
     pub mod bank {
         pub mod v1beta1 {
             include!("buf/cosmos.bank.v1beta1.rs");
@@ -28,6 +53,7 @@ pub mod cosmos {
 
     /// Base functionality.
     pub mod base {
+        #[cfg(feature = "abci")]
         /// Application BlockChain Interface (ABCI).
         ///
         /// Interface that defines the boundary between the replication engine
@@ -52,6 +78,7 @@ pub mod cosmos {
             }
         }
 
+        #[cfg(feature = "reflection")]
         /// Reflection support.
         pub mod reflection {
             pub mod v1beta1 {
@@ -70,6 +97,7 @@ pub mod cosmos {
             }
         }
 
+        #[cfg(feature = "abci")]
         /// Data structure that holds the state of the application.
         pub mod store {
             pub mod v1beta1 {
@@ -84,6 +112,7 @@ pub mod cosmos {
             include!("buf/cosmos.base.v1beta1.rs");
         }
 
+        #[cfg(feature = "tendermint")]
         /// For consensus types related to blocks, block headers, and merkle
         /// proofs.
         pub mod tendermint {
@@ -93,12 +122,14 @@ pub mod cosmos {
         }
     }
 
+    #[cfg(feature = "crisis")]
     pub mod crisis {
         pub mod v1beta1 {
             include!("buf/cosmos.crisis.v1beta1.rs");
         }
     }
 
+    #[cfg(feature = "crypto")]
     pub mod crypto {
         pub mod v1beta1 {
             include!("buf/cosmos.crisis.v1beta1.rs");
@@ -125,17 +156,32 @@ pub mod cosmos {
                 include!("buf/cosmos.crypto.multisig.v1beta1.rs");
             }
         }
+        pub mod secp256k1 {
+            include!("buf/cosmos.crypto.secp256k1.rs");
+        }
+
         pub mod secp256r1 {
             include!("buf/cosmos.crypto.secp256r1.rs");
         }
     }
 
+    #[cfg(feature = "feegrant")]
+    /// Fee allowances, which let a grantee pay transaction fees from the
+    /// granter's account.
+    pub mod feegrant {
+        pub mod v1beta1 {
+            include!("buf/cosmos.feegrant.v1beta1.rs");
+        }
+    }
+
+    #[cfg(feature = "genutil")]
     pub mod genutil {
         pub mod v1beta1 {
             include!("buf/cosmos.genutil.v1beta1.rs");
         }
     }
 
+    #[cfg(feature = "gov")]
     /// Types related to decentralized governance of the network.
     pub mod gov {
         pub mod v1 {
@@ -143,44 +189,52 @@ pub mod cosmos {
         }
     }
 
+    #[cfg(feature = "group")]
     pub mod group {
         pub mod v1 {
             include!("buf/cosmos.group.v1.rs");
         }
     }
 
+    #[cfg(feature = "mint")]
     pub mod mint {
         pub mod v1beta1 {
             include!("buf/cosmos.mint.v1beta1.rs");
         }
     }
 
+    #[cfg(feature = "nft")]
     pub mod nft {
         pub mod v1beta1 {
             include!("buf/cosmos.nft.v1beta1.rs");
         }
     }
 
+    #[cfg(feature = "params")]
     pub mod params {
         pub mod v1beta1 {
             include!("buf/cosmos.params.v1beta1.rs");
         }
     }
+    #[cfg(feature = "reflection")]
     pub mod reflection {
         pub mod v1 {
             include!("buf/cosmos.reflection.v1.rs");
         }
     }
+    #[cfg(feature = "slashing")]
     pub mod slashing {
         pub mod v1beta1 {
             include!("buf/cosmos.slashing.v1beta1.rs");
         }
     }
+    #[cfg(feature = "staking")]
     pub mod staking {
         pub mod v1beta1 {
             include!("buf/cosmos.staking.v1beta1.rs");
         }
     }
+    #[cfg(feature = "tx")]
     pub mod tx {
         pub mod config {
             pub mod v1 {
@@ -197,12 +251,14 @@ pub mod cosmos {
         }
     }
 
+    #[cfg(feature = "upgrade")]
     pub mod upgrade {
         pub mod v1beta1 {
             include!("buf/cosmos.upgrade.v1beta1.rs");
         }
     }
 
+    #[cfg(feature = "vesting")]
     pub mod vesting {
         pub mod v1beta1 {
             include!("buf/cosmos.vesting.v1beta1.rs");
@@ -215,67 +271,136 @@ pub mod cosmos {
     // TODO: protobuf mod for cosmos crypto
     // TODO: protobuf mod for cosmos distribution
     // TODO: protobuf mod for cosmos evidence
-    // TODO: protobuf mod for cosmos feegrant
 }
 
+#[allow(
+    clippy::doc_lazy_continuation,
+    clippy::doc_overindented_list_items,
+    clippy::large_enum_variant,
+    clippy::tabs_in_doc_comments
+)]
 pub mod nibiru {
+    #[cfg(feature = "devgas")]
     pub mod devgas {
         include!("buf/nibiru.devgas.v1.rs");
     }
+    #[cfg(feature = "epochs")]
     pub mod epochs {
         include!("buf/nibiru.epochs.v1.rs");
     }
+    #[cfg(feature = "genmsg")]
     pub mod genmsg {
         include!("buf/nibiru.genmsg.v1.rs");
     }
+    #[cfg(feature = "inflation")]
     pub mod inflation {
         include!("buf/nibiru.inflation.v1.rs");
     }
+    #[cfg(feature = "oracle")]
     pub mod oracle {
         include!("buf/nibiru.oracle.v1.rs");
     }
+    #[cfg(feature = "perp")]
     pub mod perp {
         include!("buf/nibiru.perp.v2.rs");
     }
+    #[cfg(feature = "spot")]
     pub mod spot {
         include!("buf/nibiru.spot.v1.rs");
     }
+    #[cfg(feature = "sudo")]
     pub mod sudo {
         include!("buf/nibiru.sudo.v1.rs");
     }
+    #[cfg(feature = "tokenfactory")]
     pub mod tokenfactory {
         include!("buf/nibiru.tokenfactory.v1.rs");
     }
 }
 
+#[allow(
+    clippy::doc_lazy_continuation,
+    clippy::doc_overindented_list_items,
+    clippy::large_enum_variant,
+    clippy::tabs_in_doc_comments
+)]
 pub mod tendermint {
+    #[cfg(feature = "tendermint")]
     pub mod abci {
         include!("buf/tendermint.abci.rs");
     }
+    #[cfg(feature = "tendermint")]
     pub mod crypto {
         include!("buf/tendermint.crypto.rs");
     }
+    #[cfg(feature = "tendermint")]
     pub mod p2p {
         include!("buf/tendermint.p2p.rs");
     }
+    #[cfg(feature = "tendermint")]
     pub mod types {
         include!("buf/tendermint.types.rs");
     }
+    #[cfg(feature = "tendermint")]
     pub mod version {
         include!("buf/tendermint.version.rs");
     }
 }
 
+#[allow(
+    clippy::doc_lazy_continuation,
+    clippy::doc_overindented_list_items,
+    clippy::large_enum_variant,
+    clippy::tabs_in_doc_comments
+)]
 pub mod eth {
+    #[cfg(feature = "evm")]
     pub mod evm {
         include!("buf/eth.evm.v1.rs");
     }
+    #[cfg(feature = "evm")]
     pub mod types {
         include!("buf/eth.types.v1.rs");
     }
 }
 
-#[cfg(test)]
+#[allow(
+    clippy::doc_lazy_continuation,
+    clippy::doc_overindented_list_items,
+    clippy::large_enum_variant,
+    clippy::tabs_in_doc_comments
+)]
+pub mod ibc {
+    pub mod applications {
+        #[cfg(feature = "ibc")]
+        /// ICS-20 fungible token transfers.
+        pub mod transfer {
+            pub mod v1 {
+                include!("buf/ibc.applications.transfer.v1.rs");
+            }
+        }
+    }
+
+    pub mod core {
+        #[cfg(feature = "ibc")]
+        /// ICS-4 channels and packets.
+        pub mod channel {
+            pub mod v1 {
+                include!("buf/ibc.core.channel.v1.rs");
+            }
+        }
+
+        #[cfg(feature = "ibc")]
+        /// ICS-2 light clients and the `Height` of a counterparty chain.
+        pub mod client {
+            pub mod v1 {
+                include!("buf/ibc.core.client.v1.rs");
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "all_protos"))]
 mod tests {
 
     use super::{
@@ -0,0 +1,105 @@
+//! nibiru-std::proto - time.rs : Conversions between the protobuf
+//! `Timestamp` and `Duration` well-known types and `cosmwasm_std::Timestamp`
+//! and seconds.
+
+use cosmwasm_std as cw;
+
+use crate::errors::{MathError, NibiruResult};
+
+/// Converts a block time, such as `env.block.time`, to a protobuf
+/// `Timestamp`.
+pub fn proto_timestamp_from_cw(time: cw::Timestamp) -> prost_types::Timestamp {
+    prost_types::Timestamp {
+        seconds: time.seconds() as i64,
+        nanos: time.subsec_nanos() as i32,
+    }
+}
+
+/// Converts a protobuf `Timestamp` to a `cosmwasm_std::Timestamp`. Errors
+/// for times before the Unix epoch or after the year 2554, which
+/// `cosmwasm_std` cannot represent in u64 nanoseconds.
+pub fn cw_timestamp_from_proto(
+    pb_time: &prost_types::Timestamp,
+) -> NibiruResult<cw::Timestamp> {
+    let out_of_range = || MathError::OutOfRange {
+        value: pb_time.to_string(),
+        target: "cosmwasm_std::Timestamp".to_string(),
+    };
+    let seconds = u64::try_from(pb_time.seconds).map_err(|_| out_of_range())?;
+    let nanos = u64::try_from(pb_time.nanos).map_err(|_| out_of_range())?;
+    let total_nanos = seconds
+        .checked_mul(1_000_000_000)
+        .and_then(|secs_nanos| secs_nanos.checked_add(nanos))
+        .ok_or_else(out_of_range)?;
+    Ok(cw::Timestamp::from_nanos(total_nanos))
+}
+
+/// Protobuf `Duration` of a whole number of seconds. Errors above
+/// `i64::MAX` seconds, which the signed protobuf field cannot hold.
+pub fn proto_duration_from_secs(
+    seconds: u64,
+) -> NibiruResult<prost_types::Duration> {
+    let seconds = i64::try_from(seconds).map_err(|_| MathError::OutOfRange {
+        value: seconds.to_string(),
+        target: "protobuf Duration seconds".to_string(),
+    })?;
+    Ok(prost_types::Duration { seconds, nanos: 0 })
+}
+
+/// Converts a protobuf `Duration` to whole seconds, rounding down. Errors on
+/// negative durations.
+pub fn secs_from_proto_duration(
+    pb_duration: &prost_types::Duration,
+) -> NibiruResult<u64> {
+    u64::try_from(pb_duration.seconds).map_err(|_| {
+        MathError::OutOfRange {
+            value: pb_duration.to_string(),
+            target: "u64 seconds".to_string(),
+        }
+        .into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std as cw;
+
+    use super::*;
+    use crate::errors::TestResult;
+
+    #[test]
+    fn timestamp_round_trip() -> TestResult {
+        let time = cw::Timestamp::from_nanos(1_700_000_000_123_456_789);
+        let pb_time = proto_timestamp_from_cw(time);
+        assert_eq!(pb_time.seconds, 1_700_000_000);
+        assert_eq!(pb_time.nanos, 123_456_789);
+        assert_eq!(cw_timestamp_from_proto(&pb_time)?, time);
+
+        let before_epoch = prost_types::Timestamp {
+            seconds: -1,
+            nanos: 0,
+        };
+        assert!(cw_timestamp_from_proto(&before_epoch).is_err());
+
+        // Go's max time, 9999-12-31T23:59:59Z, overflows u64 nanoseconds
+        let max_go_time = prost_types::Timestamp {
+            seconds: 253_402_300_799,
+            nanos: 999_999_999,
+        };
+        assert!(cw_timestamp_from_proto(&max_go_time).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn duration_round_trip() -> TestResult {
+        let pb_duration = proto_duration_from_secs(86_400)?;
+        assert_eq!(secs_from_proto_duration(&pb_duration)?, 86_400);
+        assert!(proto_duration_from_secs(i64::MAX as u64 + 1).is_err());
+        let negative = prost_types::Duration {
+            seconds: -5,
+            nanos: 0,
+        };
+        assert!(secs_from_proto_duration(&negative).is_err());
+        Ok(())
+    }
+}
@@ -1,11 +1,11 @@
 //! nibiru-std::proto - traits.rs : Implements extensions for prost::Message
 //! types for easy conversion to types needed for CosmWasm smart contracts.
 
-// Allow deprecated variant `cosmwasm_std::CosmosMsg::Stargate` for compatibility
-// with CosmWasm v1. Once we upgrade everything to v2 on Nibiru, we can remove
-// this deprecate statement.
-#![allow(deprecated)]
-// TODO: remove allow(deprevated) ↑
+// `CosmosMsg::Stargate` and `QueryRequest::Stargate` are deprecated in later
+// 1.x releases. With the "cosmwasm_2" feature, messages and queries go out as
+// `CosmosMsg::Any` and `QueryRequest::Grpc` instead, so the allow is only
+// needed for CosmWasm v1.
+#![cfg_attr(feature = "cosmwasm_1", allow(deprecated))]
 
 use cosmwasm_std::{
     to_json_vec, Binary, ContractResult, CosmosMsg, CustomQuery, QuerierWrapper,
@@ -14,7 +14,89 @@ use cosmwasm_std::{
 
 use crate::errors::{NibiruError, NibiruResult};
 
-use crate::proto::cosmos;
+use crate::proto::{type_url_cosmos, type_url_ibc, type_url_nibiru};
+
+/// Wraps an encoded protobuf message in the `CosmosMsg` variant of the
+/// selected CosmWasm version: `Stargate` for v1 and `Any` for v2.
+#[cfg(feature = "cosmwasm_1")]
+pub fn proto_cosmos_msg(
+    type_url: impl Into<String>,
+    value: Binary,
+) -> CosmosMsg {
+    CosmosMsg::Stargate {
+        type_url: type_url.into(),
+        value,
+    }
+}
+
+/// Wraps an encoded protobuf message in the `CosmosMsg` variant of the
+/// selected CosmWasm version: `Stargate` for v1 and `Any` for v2.
+#[cfg(not(feature = "cosmwasm_1"))]
+pub fn proto_cosmos_msg(
+    type_url: impl Into<String>,
+    value: Binary,
+) -> CosmosMsg {
+    CosmosMsg::Any(cosmwasm_std::AnyMsg {
+        type_url: type_url.into(),
+        value,
+    })
+}
+
+/// Builds the `QueryRequest` variant of the selected CosmWasm version for a
+/// gRPC query: `Stargate` for v1 and `Grpc` for v2.
+#[cfg(feature = "cosmwasm_1")]
+pub fn proto_query_request<C: CustomQuery>(
+    path: impl Into<String>,
+    data: Binary,
+) -> QueryRequest<C> {
+    QueryRequest::Stargate {
+        path: path.into(),
+        data,
+    }
+}
+
+/// Builds the `QueryRequest` variant of the selected CosmWasm version for a
+/// gRPC query: `Stargate` for v1 and `Grpc` for v2.
+#[cfg(not(feature = "cosmwasm_1"))]
+pub fn proto_query_request<C: CustomQuery>(
+    path: impl Into<String>,
+    data: Binary,
+) -> QueryRequest<C> {
+    QueryRequest::Grpc(cosmwasm_std::GrpcQuery {
+        path: path.into(),
+        data,
+    })
+}
+
+/// Returns the type URL and encoded value of a protobuf `CosmosMsg` built by
+/// [proto_cosmos_msg], regardless of the CosmWasm version.
+pub fn proto_msg_parts<T>(msg: &CosmosMsg<T>) -> Option<(&str, &Binary)> {
+    match msg {
+        #[cfg(feature = "cosmwasm_1")]
+        CosmosMsg::Stargate { type_url, value } => Some((type_url, value)),
+        #[cfg(not(feature = "cosmwasm_1"))]
+        CosmosMsg::Any(cosmwasm_std::AnyMsg { type_url, value }) => {
+            Some((type_url, value))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the gRPC path and encoded request of a protobuf `QueryRequest`
+/// built by [proto_query_request], regardless of the CosmWasm version.
+pub fn proto_query_parts<C: CustomQuery>(
+    query: &QueryRequest<C>,
+) -> Option<(&str, &Binary)> {
+    match query {
+        #[cfg(feature = "cosmwasm_1")]
+        QueryRequest::Stargate { path, data } => Some((path, data)),
+        #[cfg(not(feature = "cosmwasm_1"))]
+        QueryRequest::Grpc(cosmwasm_std::GrpcQuery { path, data }) => {
+            Some((path, data))
+        }
+        _ => None,
+    }
+}
 
 pub trait NibiruProstMsg: prost::Message {
     /// Serialize this protobuf message as a byte vector
@@ -53,11 +135,7 @@ pub trait NibiruProstMsg: prost::Message {
     /// }
     /// ```
     fn try_into_stargate_msg(&self, type_url: &str) -> CosmosMsg {
-        let value = self.to_binary();
-        CosmosMsg::Stargate {
-            type_url: type_url.to_string(),
-            value,
-        }
+        proto_cosmos_msg(type_url, self.to_binary())
     }
 
     /// Parse into this protobuf type from `prost_types::Any`.
@@ -87,13 +165,18 @@ pub trait NibiruStargateMsg: prost::Message + prost::Name {
     fn into_stargate_msg(&self) -> CosmosMsg;
 
     fn type_url(&self) -> String;
+
+    /// Wraps the message as a `prost_types::Any`, as needed to nest it
+    /// inside another message, like an authz `MsgExec`.
+    fn to_any(&self) -> prost_types::Any;
 }
 
 impl<M> NibiruStargateMsg for M
 where
     M: prost::Message + prost::Name,
 {
-    /// Returns the `prost::Message` as a `CosmosMsg::Stargate` object.
+    /// Returns the `prost::Message` as a `CosmosMsg::Stargate` object, or
+    /// `CosmosMsg::Any` with the "cosmwasm_2" feature.
     ///
     /// ```rust
     /// use cosmwasm_std::CosmosMsg;
@@ -116,10 +199,7 @@ where
     /// }
     /// ```
     fn into_stargate_msg(&self) -> CosmosMsg {
-        CosmosMsg::Stargate {
-            type_url: self.type_url(),
-            value: self.to_binary(),
-        }
+        proto_cosmos_msg(self.type_url(), self.to_binary())
     }
 
     /// The "type URL" in the context of protobuf is used with a feature
@@ -141,6 +221,13 @@ where
     fn type_url(&self) -> String {
         format!("/{}.{}", Self::PACKAGE, Self::NAME)
     }
+
+    fn to_any(&self) -> prost_types::Any {
+        prost_types::Any {
+            type_url: self.type_url(),
+            value: self.encode_to_vec(),
+        }
+    }
 }
 
 pub trait NibiruStargateQuery: prost::Message + prost::Name {
@@ -156,9 +243,9 @@ impl<M> NibiruStargateQuery for M
 where
     M: prost::Message + prost::Name,
 {
-    /// Returns the `prost::Message` as a `QueryRequest::Stargate` object.
-    /// Errors if the `prost::Name::type_url` does not indicate the type is a
-    /// query.
+    /// Returns the `prost::Message` as a `QueryRequest::Stargate` object, or
+    /// `QueryRequest::Grpc` with the "cosmwasm_2" feature. Errors if the
+    /// `prost::Name::type_url` does not indicate the type is a query.
     ///
     /// ```rust
     /// use cosmwasm_std::{Empty, QueryRequest};
@@ -179,20 +266,27 @@ where
     fn into_stargate_query(
         &self,
     ) -> NibiruResult<QueryRequest<cosmwasm_std::Empty>> {
-        if !self.type_url().contains("Query") {
+        let is_query = grpc_query_path(&Self::full_name()).is_some()
+            || self.type_url().contains("Query");
+        if !is_query {
             return Err(NibiruError::ProstNameisNotQuery {
                 type_url: self.type_url(),
             });
         }
-        Ok(QueryRequest::Stargate {
-            path: self.path(),
-            data: self.to_binary(),
-        })
+        Ok(proto_query_request(self.path(), self.to_binary()))
     }
 
     /// Fully qualified gRPC service path used for routing.
     /// Ex.: "/cosmos.bank.v1beta1.Query/SupplyOf"
+    ///
+    /// Uses the path generated from the proto descriptor set when a single
+    /// query method serves this request type (see [NibiruGrpcQuery]).
+    /// Otherwise, falls back to the "Query/{NAME}" naming convention with the
+    /// "Query" prefix and "Request" suffix trimmed.
     fn path(&self) -> String {
+        if let Some(path) = grpc_query_path(&Self::full_name()) {
+            return path.to_string();
+        }
         let service_name = format!(
             "Query/{}",
             Self::NAME
@@ -203,6 +297,53 @@ where
     }
 }
 
+/// NibiruGrpcQuery: A protobuf request served by exactly one gRPC query
+/// method. The impls are generated from the proto descriptor set in
+/// "proto/buf" with `cargo run --bin type_url_gen`.
+///
+/// ```rust
+/// use nibiru_std::proto::{
+///     cosmos, nibiru, NibiruGrpcQuery, NibiruStargateQuery,
+/// };
+///
+/// // Request names that break the "Query/{Name}" convention still route to
+/// // the method that serves them.
+/// type Req = nibiru::perp::QueryPositionRequest;
+/// assert_eq!(Req::GRPC_PATH, "/nibiru.perp.v2.Query/QueryPosition");
+/// assert_eq!(Req::default().path(), Req::GRPC_PATH);
+///
+/// // Services not named "Query" work too.
+/// type TxReq = cosmos::tx::v1beta1::GetTxRequest;
+/// assert_eq!(TxReq::GRPC_PATH, "/cosmos.tx.v1beta1.Service/GetTx");
+/// ```
+pub trait NibiruGrpcQuery: prost::Message + prost::Name + Default {
+    /// Fully qualified gRPC method path, Ex.:
+    /// "/cosmos.bank.v1beta1.Query/Balance"
+    const GRPC_PATH: &'static str;
+    /// Response type returned by the gRPC method.
+    type Response: prost::Message + prost::Name + Default;
+}
+
+/// Returns the generated gRPC query path for a request given its fully
+/// qualified proto name, Ex.: "cosmos.bank.v1beta1.QueryBalanceRequest".
+pub fn grpc_query_path(full_name: &str) -> Option<&'static str> {
+    type_url_cosmos::grpc_query_path(full_name)
+        .or_else(|| type_url_nibiru::grpc_query_path(full_name))
+        .or_else(|| type_url_ibc::grpc_query_path(full_name))
+}
+
+/// (request full name, gRPC path, response full name) of a [NibiruGrpcQuery]
+/// impl.
+#[cfg(all(test, feature = "all_protos"))]
+pub(crate) fn grpc_query_entry<Req: NibiruGrpcQuery>(
+) -> (String, &'static str, String) {
+    (
+        Req::full_name(),
+        Req::GRPC_PATH,
+        <Req::Response as prost::Name>::full_name(),
+    )
+}
+
 /// Runs a Stargate query and decodes the protobuf response into a strong type.
 ///
 /// `QuerierWrapper::query` decodes responses with serde JSON. Stargate query
@@ -238,7 +379,53 @@ where
     Resp: prost::Message + Default,
 {
     let query = req.into_stargate_query()?;
-    let raw_query = to_json_vec(&query)?;
+    raw_query_proto(querier, &query)
+}
+
+/// Runs the protobuf query `req` at an explicit gRPC `path` and decodes the
+/// response. Needed for methods that share their request type with another
+/// method, since [NibiruStargateQuery::path] can only route to one of them.
+///
+/// ```rust
+/// use cosmwasm_std::{Deps, StdResult};
+/// use nibiru_std::proto::{
+///     nibiru::oracle::{QueryExchangeRateRequest, QueryExchangeRateResponse},
+///     query_proto_at_path,
+/// };
+///
+/// pub fn query_twap(deps: Deps, pair: String) -> StdResult<QueryExchangeRateResponse> {
+///     query_proto_at_path(
+///         &deps.querier,
+///         "/nibiru.oracle.v1.Query/ExchangeRateTwap",
+///         &QueryExchangeRateRequest { pair },
+///     )
+/// }
+/// ```
+pub fn query_proto_at_path<C, Req, Resp>(
+    querier: &QuerierWrapper<C>,
+    path: &str,
+    req: &Req,
+) -> StdResult<Resp>
+where
+    C: CustomQuery,
+    Req: prost::Message,
+    Resp: prost::Message + Default,
+{
+    let query: QueryRequest<cosmwasm_std::Empty> =
+        proto_query_request(path, Binary::from(req.encode_to_vec()));
+    raw_query_proto(querier, &query)
+}
+
+/// Sends an encoded protobuf query and decodes its response as `Resp`.
+fn raw_query_proto<C, Resp>(
+    querier: &QuerierWrapper<C>,
+    query: &QueryRequest<cosmwasm_std::Empty>,
+) -> StdResult<Resp>
+where
+    C: CustomQuery,
+    Resp: prost::Message + Default,
+{
+    let raw_query = to_json_vec(query)?;
 
     let response = match querier.raw_query(&raw_query) {
         SystemResult::Ok(ContractResult::Ok(response)) => response,
@@ -259,16 +446,37 @@ where
     })
 }
 
-impl From<cosmwasm_std::Coin> for cosmos::base::v1beta1::Coin {
-    fn from(cw_coin: cosmwasm_std::Coin) -> Self {
-        cosmos::base::v1beta1::Coin {
-            denom: cw_coin.denom,
-            amount: cw_coin.amount.to_string(),
-        }
-    }
+/// Runs the gRPC query of a [NibiruGrpcQuery] request and decodes its
+/// response type. Unlike [query_stargate_proto], the response type is known
+/// from the request.
+///
+/// ```rust
+/// use cosmwasm_std::{Deps, StdResult};
+/// use nibiru_std::proto::{
+///     cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse},
+///     query_grpc_proto,
+/// };
+///
+/// pub fn query_bank_balance(
+///     deps: Deps,
+///     address: String,
+///     denom: String,
+/// ) -> StdResult<QueryBalanceResponse> {
+///     query_grpc_proto(&deps.querier, &QueryBalanceRequest { address, denom })
+/// }
+/// ```
+pub fn query_grpc_proto<C, Req>(
+    querier: &QuerierWrapper<C>,
+    req: &Req,
+) -> StdResult<Req::Response>
+where
+    C: CustomQuery,
+    Req: NibiruGrpcQuery,
+{
+    query_stargate_proto(querier, req)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "all_protos"))]
 mod tests {
     use cosmwasm_std::{
         from_json, Binary, ContractResult, Empty, Querier, QuerierResult,
@@ -276,7 +484,10 @@ mod tests {
     };
     use prost::Message;
 
-    use super::{query_stargate_proto, NibiruStargateQuery};
+    use super::{
+        proto_msg_parts, proto_query_parts, query_stargate_proto,
+        NibiruStargateMsg, NibiruStargateQuery,
+    };
     use crate::proto::cosmos;
 
     struct BankBalanceStargateQuerier {
@@ -296,10 +507,8 @@ mod tests {
                 }
             };
 
-            match request {
-                QueryRequest::Stargate { path, .. }
-                    if path == self.expected_path =>
-                {
+            match proto_query_parts(&request) {
+                Some((path, _)) if path == self.expected_path => {
                     SystemResult::Ok(ContractResult::Ok(self.response.clone()))
                 }
                 _ => SystemResult::Err(SystemError::UnsupportedRequest {
@@ -309,6 +518,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn proto_msgs_use_version_specific_variants() {
+        let msg = cosmos::bank::v1beta1::MsgSend::default().into_stargate_msg();
+        let query = cosmos::bank::v1beta1::QuerySupplyOfRequest::default()
+            .into_stargate_query()
+            .unwrap();
+
+        #[cfg(feature = "cosmwasm_1")]
+        {
+            assert!(matches!(msg, cosmwasm_std::CosmosMsg::Stargate { .. }));
+            assert!(matches!(query, QueryRequest::Stargate { .. }));
+        }
+        #[cfg(not(feature = "cosmwasm_1"))]
+        {
+            assert!(matches!(msg, cosmwasm_std::CosmosMsg::Any(_)));
+            assert!(matches!(query, QueryRequest::Grpc(_)));
+        }
+
+        assert_eq!(
+            proto_msg_parts(&msg).map(|(type_url, _)| type_url),
+            Some("/cosmos.bank.v1beta1.MsgSend")
+        );
+        assert_eq!(
+            proto_msg_parts::<cosmwasm_std::Empty>(
+                &cosmwasm_std::BankMsg::Burn { amount: vec![] }.into()
+            ),
+            None
+        );
+    }
+
     #[test]
     fn query_stargate_proto_decodes_bank_balance_response() {
         let expected = cosmos::bank::v1beta1::QueryBalanceResponse {
@@ -331,11 +570,10 @@ mod tests {
         let stargate_query = req
             .into_stargate_query()
             .expect("bank balance request should convert to Stargate");
-        assert!(matches!(
-            stargate_query,
-            QueryRequest::Stargate { ref path, .. }
-                if path == "/cosmos.bank.v1beta1.Query/Balance"
-        ));
+        assert_eq!(
+            proto_query_parts(&stargate_query).map(|(path, _)| path),
+            Some("/cosmos.bank.v1beta1.Query/Balance")
+        );
 
         let actual: cosmos::bank::v1beta1::QueryBalanceResponse =
             query_stargate_proto(&wrapper, &req).unwrap();
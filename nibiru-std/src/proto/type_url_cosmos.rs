@@ -1,208 +1,5530 @@
 //! Implements the prost::Name trait for cosmos protobuf types, which defines
-//! the prost::Message.type_url function needed for CosmWasm smart contracts.
+//! the prost::Message.type_url function needed for CosmWasm smart contracts,
+//! and the NibiruGrpcQuery trait for requests with a known gRPC query path.
+//!
+//! Code generated from "proto/buf/proto_descriptor.bin" by
+//! `cargo run --bin type_url_gen` in the scripts directory. DO NOT EDIT.
 
+#[allow(unused_imports)]
 use prost::Name;
 
-use crate::proto::cosmos;
+#[allow(unused_imports)]
+use crate::proto::{cosmos, NibiruGrpcQuery};
 
-const PACKAGE_BANK: &str = "cosmos.bank.v1beta1";
-const PACKAGE_AUTH: &str = "cosmos.auth.v1beta1";
-const PACKAGE_GOV: &str = "cosmos.gov.v1";
+#[cfg(feature = "auth")]
+const PACKAGE_AUTH_V1BETA1: &str = "cosmos.auth.v1beta1";
+#[cfg(feature = "authz")]
+const PACKAGE_AUTHZ_V1BETA1: &str = "cosmos.authz.v1beta1";
+#[cfg(feature = "bank")]
+const PACKAGE_BANK_V1BETA1: &str = "cosmos.bank.v1beta1";
+#[cfg(feature = "abci")]
+const PACKAGE_BASE_ABCI_V1BETA1: &str = "cosmos.base.abci.v1beta1";
+const PACKAGE_BASE_KV_V1BETA1: &str = "cosmos.base.kv.v1beta1";
+const PACKAGE_BASE_QUERY_V1BETA1: &str = "cosmos.base.query.v1beta1";
+#[cfg(feature = "reflection")]
+const PACKAGE_BASE_REFLECTION_V1BETA1: &str = "cosmos.base.reflection.v1beta1";
+#[cfg(feature = "reflection")]
+const PACKAGE_BASE_REFLECTION_V2ALPHA1: &str = "cosmos.base.reflection.v2alpha1";
+const PACKAGE_BASE_SNAPSHOTS_V1BETA1: &str = "cosmos.base.snapshots.v1beta1";
+#[cfg(feature = "abci")]
+const PACKAGE_BASE_STORE_V1BETA1: &str = "cosmos.base.store.v1beta1";
+#[cfg(feature = "tendermint")]
+const PACKAGE_BASE_TENDERMINT_V1BETA1: &str = "cosmos.base.tendermint.v1beta1";
+const PACKAGE_BASE_V1BETA1: &str = "cosmos.base.v1beta1";
+#[cfg(feature = "crisis")]
+const PACKAGE_CRISIS_V1BETA1: &str = "cosmos.crisis.v1beta1";
+#[cfg(feature = "crypto")]
+const PACKAGE_CRYPTO_ED25519: &str = "cosmos.crypto.ed25519";
+#[cfg(feature = "crypto")]
+const PACKAGE_CRYPTO_HD_V1: &str = "cosmos.crypto.hd.v1";
+#[cfg(feature = "crypto")]
+const PACKAGE_CRYPTO_KEYRING_V1: &str = "cosmos.crypto.keyring.v1";
+#[cfg(feature = "crypto")]
+const PACKAGE_CRYPTO_MULTISIG_V1BETA1: &str = "cosmos.crypto.multisig.v1beta1";
+#[cfg(feature = "crypto")]
+const PACKAGE_CRYPTO_SECP256K1: &str = "cosmos.crypto.secp256k1";
+#[cfg(feature = "crypto")]
+const PACKAGE_CRYPTO_SECP256R1: &str = "cosmos.crypto.secp256r1";
+#[cfg(feature = "feegrant")]
+const PACKAGE_FEEGRANT_V1BETA1: &str = "cosmos.feegrant.v1beta1";
+#[cfg(feature = "genutil")]
+const PACKAGE_GENUTIL_V1BETA1: &str = "cosmos.genutil.v1beta1";
+#[cfg(feature = "gov")]
+const PACKAGE_GOV_V1: &str = "cosmos.gov.v1";
+#[cfg(feature = "group")]
+const PACKAGE_GROUP_V1: &str = "cosmos.group.v1";
+#[cfg(feature = "mint")]
+const PACKAGE_MINT_V1BETA1: &str = "cosmos.mint.v1beta1";
+#[cfg(feature = "nft")]
+const PACKAGE_NFT_V1BETA1: &str = "cosmos.nft.v1beta1";
+#[cfg(feature = "params")]
+const PACKAGE_PARAMS_V1BETA1: &str = "cosmos.params.v1beta1";
+#[cfg(feature = "reflection")]
+const PACKAGE_REFLECTION_V1: &str = "cosmos.reflection.v1";
+#[cfg(feature = "slashing")]
+const PACKAGE_SLASHING_V1BETA1: &str = "cosmos.slashing.v1beta1";
+#[cfg(feature = "staking")]
+const PACKAGE_STAKING_V1BETA1: &str = "cosmos.staking.v1beta1";
+#[cfg(feature = "tx")]
+const PACKAGE_TX_CONFIG_V1: &str = "cosmos.tx.config.v1";
+#[cfg(feature = "tx")]
+const PACKAGE_TX_SIGNING_V1BETA1: &str = "cosmos.tx.signing.v1beta1";
+#[cfg(feature = "tx")]
+const PACKAGE_TX_V1BETA1: &str = "cosmos.tx.v1beta1";
+#[cfg(feature = "upgrade")]
+const PACKAGE_UPGRADE_V1BETA1: &str = "cosmos.upgrade.v1beta1";
+#[cfg(feature = "vesting")]
+const PACKAGE_VESTING_V1BETA1: &str = "cosmos.vesting.v1beta1";
 
-// BANK tx msg
+// cosmos.auth.v1beta1
 
-impl Name for cosmos::bank::v1beta1::MsgSend {
-    const NAME: &'static str = "MsgSend";
-    const PACKAGE: &'static str = PACKAGE_BANK;
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::AddressBytesToStringRequest {
+    const NAME: &'static str = "AddressBytesToStringRequest";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
 }
 
-impl Name for cosmos::bank::v1beta1::MsgMultiSend {
-    const NAME: &'static str = "MsgMultiSend";
-    const PACKAGE: &'static str = PACKAGE_BANK;
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::AddressBytesToStringResponse {
+    const NAME: &'static str = "AddressBytesToStringResponse";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
 }
 
-impl Name for cosmos::bank::v1beta1::MsgUpdateParams {
-    const NAME: &'static str = "MsgUpdateParams";
-    const PACKAGE: &'static str = PACKAGE_BANK;
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::AddressStringToBytesRequest {
+    const NAME: &'static str = "AddressStringToBytesRequest";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
 }
 
-impl Name for cosmos::bank::v1beta1::MsgSetSendEnabled {
-    const NAME: &'static str = "MsgSetSendEnabled";
-    const PACKAGE: &'static str = PACKAGE_BANK;
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::AddressStringToBytesResponse {
+    const NAME: &'static str = "AddressStringToBytesResponse";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
 }
 
-// BANK query
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::BaseAccount {
+    const NAME: &'static str = "BaseAccount";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
+}
 
-impl Name for cosmos::bank::v1beta1::QuerySupplyOfRequest {
-    const NAME: &'static str = "QuerySupplyOfRequest";
-    const PACKAGE: &'static str = PACKAGE_BANK;
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::Bech32PrefixRequest {
+    const NAME: &'static str = "Bech32PrefixRequest";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
 }
 
-impl Name for cosmos::bank::v1beta1::QueryBalanceRequest {
-    const NAME: &'static str = "QueryBalanceRequest";
-    const PACKAGE: &'static str = PACKAGE_BANK;
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::Bech32PrefixResponse {
+    const NAME: &'static str = "Bech32PrefixResponse";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
 }
 
-impl Name for cosmos::bank::v1beta1::QueryAllBalancesRequest {
-    const NAME: &'static str = "QueryAllBalancesRequest";
-    const PACKAGE: &'static str = PACKAGE_BANK;
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
 }
 
-impl Name for cosmos::bank::v1beta1::QueryDenomMetadataRequest {
-    const NAME: &'static str = "QueryDenomMetadataRequest";
-    const PACKAGE: &'static str = PACKAGE_BANK;
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::ModuleAccount {
+    const NAME: &'static str = "ModuleAccount";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
 }
 
-// AUTH tx msg
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::ModuleCredential {
+    const NAME: &'static str = "ModuleCredential";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
+}
 
+#[cfg(feature = "auth")]
 impl Name for cosmos::auth::v1beta1::MsgUpdateParams {
     const NAME: &'static str = "MsgUpdateParams";
-    const PACKAGE: &'static str = PACKAGE_AUTH;
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
+}
+
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::MsgUpdateParamsResponse {
+    const NAME: &'static str = "MsgUpdateParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
+}
+
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
+}
+
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::QueryAccountAddressByIdRequest {
+    const NAME: &'static str = "QueryAccountAddressByIDRequest";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
 }
 
-// AUTH query
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::QueryAccountAddressByIdResponse {
+    const NAME: &'static str = "QueryAccountAddressByIDResponse";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
+}
 
+#[cfg(feature = "auth")]
 impl Name for cosmos::auth::v1beta1::QueryAccountInfoRequest {
     const NAME: &'static str = "QueryAccountInfoRequest";
-    const PACKAGE: &'static str = PACKAGE_AUTH;
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
+}
+
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::QueryAccountInfoResponse {
+    const NAME: &'static str = "QueryAccountInfoResponse";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
 }
 
+#[cfg(feature = "auth")]
 impl Name for cosmos::auth::v1beta1::QueryAccountRequest {
     const NAME: &'static str = "QueryAccountRequest";
-    const PACKAGE: &'static str = PACKAGE_AUTH;
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
 }
 
-impl Name for cosmos::auth::v1beta1::QueryModuleAccountsRequest {
-    const NAME: &'static str = "QueryModuleAccountsRequest";
-    const PACKAGE: &'static str = PACKAGE_AUTH;
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::QueryAccountResponse {
+    const NAME: &'static str = "QueryAccountResponse";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
+}
+
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::QueryAccountsRequest {
+    const NAME: &'static str = "QueryAccountsRequest";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
+}
+
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::QueryAccountsResponse {
+    const NAME: &'static str = "QueryAccountsResponse";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
 }
 
+#[cfg(feature = "auth")]
 impl Name for cosmos::auth::v1beta1::QueryModuleAccountByNameRequest {
     const NAME: &'static str = "QueryModuleAccountByNameRequest";
-    const PACKAGE: &'static str = PACKAGE_AUTH;
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
 }
 
-// GOV tx msg
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::QueryModuleAccountByNameResponse {
+    const NAME: &'static str = "QueryModuleAccountByNameResponse";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
+}
 
-impl Name for cosmos::gov::v1::MsgSubmitProposal {
-    const NAME: &'static str = "MsgSubmitProposal";
-    const PACKAGE: &'static str = PACKAGE_GOV;
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::QueryModuleAccountsRequest {
+    const NAME: &'static str = "QueryModuleAccountsRequest";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
 }
 
-impl Name for cosmos::gov::v1::MsgExecLegacyContent {
-    const NAME: &'static str = "MsgExecLegacyContent";
-    const PACKAGE: &'static str = PACKAGE_GOV;
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::QueryModuleAccountsResponse {
+    const NAME: &'static str = "QueryModuleAccountsResponse";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
 }
 
-impl Name for cosmos::gov::v1::MsgVote {
-    const NAME: &'static str = "MsgVote";
-    const PACKAGE: &'static str = PACKAGE_GOV;
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
 }
 
-impl Name for cosmos::gov::v1::MsgVoteWeighted {
-    const NAME: &'static str = "MsgVoteWeighted";
-    const PACKAGE: &'static str = PACKAGE_GOV;
+#[cfg(feature = "auth")]
+impl Name for cosmos::auth::v1beta1::QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_AUTH_V1BETA1;
 }
 
-impl Name for cosmos::gov::v1::MsgDeposit {
-    const NAME: &'static str = "MsgDeposit";
-    const PACKAGE: &'static str = PACKAGE_GOV;
+// cosmos.authz.v1beta1
+
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::EventGrant {
+    const NAME: &'static str = "EventGrant";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
 }
 
-impl Name for cosmos::gov::v1::MsgUpdateParams {
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::EventRevoke {
+    const NAME: &'static str = "EventRevoke";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
+}
+
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::GenericAuthorization {
+    const NAME: &'static str = "GenericAuthorization";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
+}
+
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
+}
+
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::Grant {
+    const NAME: &'static str = "Grant";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
+}
+
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::GrantAuthorization {
+    const NAME: &'static str = "GrantAuthorization";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
+}
+
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::GrantQueueItem {
+    const NAME: &'static str = "GrantQueueItem";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
+}
+
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::MsgExec {
+    const NAME: &'static str = "MsgExec";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
+}
+
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::MsgExecResponse {
+    const NAME: &'static str = "MsgExecResponse";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
+}
+
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::MsgGrant {
+    const NAME: &'static str = "MsgGrant";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
+}
+
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::MsgGrantResponse {
+    const NAME: &'static str = "MsgGrantResponse";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
+}
+
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::MsgRevoke {
+    const NAME: &'static str = "MsgRevoke";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
+}
+
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::MsgRevokeResponse {
+    const NAME: &'static str = "MsgRevokeResponse";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
+}
+
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::QueryGranteeGrantsRequest {
+    const NAME: &'static str = "QueryGranteeGrantsRequest";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
+}
+
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::QueryGranteeGrantsResponse {
+    const NAME: &'static str = "QueryGranteeGrantsResponse";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
+}
+
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::QueryGranterGrantsRequest {
+    const NAME: &'static str = "QueryGranterGrantsRequest";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
+}
+
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::QueryGranterGrantsResponse {
+    const NAME: &'static str = "QueryGranterGrantsResponse";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
+}
+
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::QueryGrantsRequest {
+    const NAME: &'static str = "QueryGrantsRequest";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
+}
+
+#[cfg(feature = "authz")]
+impl Name for cosmos::authz::v1beta1::QueryGrantsResponse {
+    const NAME: &'static str = "QueryGrantsResponse";
+    const PACKAGE: &'static str = PACKAGE_AUTHZ_V1BETA1;
+}
+
+// cosmos.bank.v1beta1
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::Balance {
+    const NAME: &'static str = "Balance";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::DenomOwner {
+    const NAME: &'static str = "DenomOwner";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::DenomUnit {
+    const NAME: &'static str = "DenomUnit";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::Input {
+    const NAME: &'static str = "Input";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::Metadata {
+    const NAME: &'static str = "Metadata";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::MsgMultiSend {
+    const NAME: &'static str = "MsgMultiSend";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::MsgMultiSendResponse {
+    const NAME: &'static str = "MsgMultiSendResponse";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::MsgSend {
+    const NAME: &'static str = "MsgSend";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::MsgSendResponse {
+    const NAME: &'static str = "MsgSendResponse";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::MsgSetSendEnabled {
+    const NAME: &'static str = "MsgSetSendEnabled";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::MsgSetSendEnabledResponse {
+    const NAME: &'static str = "MsgSetSendEnabledResponse";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::MsgUpdateParams {
     const NAME: &'static str = "MsgUpdateParams";
-    const PACKAGE: &'static str = PACKAGE_GOV;
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
 }
 
-// GOV query
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::MsgUpdateParamsResponse {
+    const NAME: &'static str = "MsgUpdateParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
 
-impl Name for cosmos::gov::v1::QueryProposalRequest {
-    const NAME: &'static str = "QueryProposalRequest";
-    const PACKAGE: &'static str = PACKAGE_GOV;
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::Output {
+    const NAME: &'static str = "Output";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
 }
 
-impl Name for cosmos::gov::v1::QueryProposalsRequest {
-    const NAME: &'static str = "QueryProposalsRequest";
-    const PACKAGE: &'static str = PACKAGE_GOV;
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
 }
 
-impl Name for cosmos::gov::v1::QueryVoteRequest {
-    const NAME: &'static str = "QueryVoteRequest";
-    const PACKAGE: &'static str = PACKAGE_GOV;
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QueryAllBalancesRequest {
+    const NAME: &'static str = "QueryAllBalancesRequest";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
 }
 
-impl Name for cosmos::gov::v1::QueryVotesRequest {
-    const NAME: &'static str = "QueryVotesRequest";
-    const PACKAGE: &'static str = PACKAGE_GOV;
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QueryAllBalancesResponse {
+    const NAME: &'static str = "QueryAllBalancesResponse";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
 }
 
-impl Name for cosmos::gov::v1::QueryParamsRequest {
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QueryBalanceRequest {
+    const NAME: &'static str = "QueryBalanceRequest";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QueryBalanceResponse {
+    const NAME: &'static str = "QueryBalanceResponse";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QueryDenomMetadataRequest {
+    const NAME: &'static str = "QueryDenomMetadataRequest";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QueryDenomMetadataResponse {
+    const NAME: &'static str = "QueryDenomMetadataResponse";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QueryDenomOwnersRequest {
+    const NAME: &'static str = "QueryDenomOwnersRequest";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QueryDenomOwnersResponse {
+    const NAME: &'static str = "QueryDenomOwnersResponse";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QueryDenomsMetadataRequest {
+    const NAME: &'static str = "QueryDenomsMetadataRequest";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QueryDenomsMetadataResponse {
+    const NAME: &'static str = "QueryDenomsMetadataResponse";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QueryParamsRequest {
     const NAME: &'static str = "QueryParamsRequest";
-    const PACKAGE: &'static str = PACKAGE_GOV;
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
 }
 
-impl Name for cosmos::gov::v1::QueryDepositRequest {
-    const NAME: &'static str = "QueryDepositRequest";
-    const PACKAGE: &'static str = PACKAGE_GOV;
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
 }
 
-impl Name for cosmos::gov::v1::QueryDepositsRequest {
-    const NAME: &'static str = "QueryDepositsRequest";
-    const PACKAGE: &'static str = PACKAGE_GOV;
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QuerySendEnabledRequest {
+    const NAME: &'static str = "QuerySendEnabledRequest";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
 }
 
-impl Name for cosmos::gov::v1::QueryTallyResultRequest {
-    const NAME: &'static str = "QueryTallyResultRequest";
-    const PACKAGE: &'static str = PACKAGE_GOV;
-}
-
-#[cfg(test)]
-mod tests {
-
-    use cosmwasm_std::{Empty, QueryRequest};
-
-    use crate::{
-        errors::{NibiruResult, TestResult},
-        proto::{cosmos, NibiruStargateQuery},
-    };
-
-    #[test]
-    fn stargate_query_conversion() -> TestResult {
-        let test_cases: Vec<(&str, NibiruResult<QueryRequest<Empty>>)> = vec![
-            (
-                "/cosmos.bank.v1beta1.Query/SupplyOf",
-                cosmos::bank::v1beta1::QuerySupplyOfRequest {
-                    denom: String::from("some_denom"),
-                }
-                .into_stargate_query(),
-            ),
-            (
-                "/cosmos.bank.v1beta1.Query/Balance",
-                cosmos::bank::v1beta1::QueryBalanceRequest {
-                    address: String::from("some_address"),
-                    denom: String::from("some_denom"),
-                }
-                .into_stargate_query(),
-            ),
-            (
-                "/cosmos.bank.v1beta1.Query/DenomMetadata",
-                cosmos::bank::v1beta1::QueryDenomMetadataRequest {
-                    denom: String::from("some_denom"),
-                }
-                .into_stargate_query(),
-            ),
-        ];
-
-        for test_case in test_cases {
-            let test_case_path = test_case.0;
-            let pb_query = test_case.1?;
-            if let QueryRequest::Stargate { path, data: _data } = &pb_query {
-                assert_eq!(test_case_path, path)
-            } else {
-                panic!("failed test on case: {:#?} ", test_case_path)
-            }
-        }
-
-        Ok(())
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QuerySendEnabledResponse {
+    const NAME: &'static str = "QuerySendEnabledResponse";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QuerySpendableBalanceByDenomRequest {
+    const NAME: &'static str = "QuerySpendableBalanceByDenomRequest";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QuerySpendableBalanceByDenomResponse {
+    const NAME: &'static str = "QuerySpendableBalanceByDenomResponse";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QuerySpendableBalancesRequest {
+    const NAME: &'static str = "QuerySpendableBalancesRequest";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QuerySpendableBalancesResponse {
+    const NAME: &'static str = "QuerySpendableBalancesResponse";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QuerySupplyOfRequest {
+    const NAME: &'static str = "QuerySupplyOfRequest";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QuerySupplyOfResponse {
+    const NAME: &'static str = "QuerySupplyOfResponse";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QueryTotalSupplyRequest {
+    const NAME: &'static str = "QueryTotalSupplyRequest";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::QueryTotalSupplyResponse {
+    const NAME: &'static str = "QueryTotalSupplyResponse";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::SendAuthorization {
+    const NAME: &'static str = "SendAuthorization";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::SendEnabled {
+    const NAME: &'static str = "SendEnabled";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+#[cfg(feature = "bank")]
+impl Name for cosmos::bank::v1beta1::Supply {
+    const NAME: &'static str = "Supply";
+    const PACKAGE: &'static str = PACKAGE_BANK_V1BETA1;
+}
+
+// cosmos.base.abci.v1beta1
+
+#[cfg(feature = "abci")]
+impl Name for cosmos::base::abci::v1beta1::AbciMessageLog {
+    const NAME: &'static str = "ABCIMessageLog";
+    const PACKAGE: &'static str = PACKAGE_BASE_ABCI_V1BETA1;
+}
+
+#[cfg(feature = "abci")]
+impl Name for cosmos::base::abci::v1beta1::Attribute {
+    const NAME: &'static str = "Attribute";
+    const PACKAGE: &'static str = PACKAGE_BASE_ABCI_V1BETA1;
+}
+
+#[cfg(feature = "abci")]
+impl Name for cosmos::base::abci::v1beta1::GasInfo {
+    const NAME: &'static str = "GasInfo";
+    const PACKAGE: &'static str = PACKAGE_BASE_ABCI_V1BETA1;
+}
+
+#[cfg(feature = "abci")]
+impl Name for cosmos::base::abci::v1beta1::MsgData {
+    const NAME: &'static str = "MsgData";
+    const PACKAGE: &'static str = PACKAGE_BASE_ABCI_V1BETA1;
+}
+
+#[cfg(feature = "abci")]
+impl Name for cosmos::base::abci::v1beta1::Result {
+    const NAME: &'static str = "Result";
+    const PACKAGE: &'static str = PACKAGE_BASE_ABCI_V1BETA1;
+}
+
+#[cfg(feature = "abci")]
+impl Name for cosmos::base::abci::v1beta1::SearchTxsResult {
+    const NAME: &'static str = "SearchTxsResult";
+    const PACKAGE: &'static str = PACKAGE_BASE_ABCI_V1BETA1;
+}
+
+#[cfg(feature = "abci")]
+impl Name for cosmos::base::abci::v1beta1::SimulationResponse {
+    const NAME: &'static str = "SimulationResponse";
+    const PACKAGE: &'static str = PACKAGE_BASE_ABCI_V1BETA1;
+}
+
+#[cfg(feature = "abci")]
+impl Name for cosmos::base::abci::v1beta1::StringEvent {
+    const NAME: &'static str = "StringEvent";
+    const PACKAGE: &'static str = PACKAGE_BASE_ABCI_V1BETA1;
+}
+
+#[cfg(feature = "abci")]
+impl Name for cosmos::base::abci::v1beta1::TxMsgData {
+    const NAME: &'static str = "TxMsgData";
+    const PACKAGE: &'static str = PACKAGE_BASE_ABCI_V1BETA1;
+}
+
+#[cfg(feature = "abci")]
+impl Name for cosmos::base::abci::v1beta1::TxResponse {
+    const NAME: &'static str = "TxResponse";
+    const PACKAGE: &'static str = PACKAGE_BASE_ABCI_V1BETA1;
+}
+
+// cosmos.base.kv.v1beta1
+
+impl Name for cosmos::base::kv::v1beta1::Pair {
+    const NAME: &'static str = "Pair";
+    const PACKAGE: &'static str = PACKAGE_BASE_KV_V1BETA1;
+}
+
+impl Name for cosmos::base::kv::v1beta1::Pairs {
+    const NAME: &'static str = "Pairs";
+    const PACKAGE: &'static str = PACKAGE_BASE_KV_V1BETA1;
+}
+
+// cosmos.base.query.v1beta1
+
+impl Name for cosmos::base::query::v1beta1::PageRequest {
+    const NAME: &'static str = "PageRequest";
+    const PACKAGE: &'static str = PACKAGE_BASE_QUERY_V1BETA1;
+}
+
+impl Name for cosmos::base::query::v1beta1::PageResponse {
+    const NAME: &'static str = "PageResponse";
+    const PACKAGE: &'static str = PACKAGE_BASE_QUERY_V1BETA1;
+}
+
+// cosmos.base.reflection.v1beta1
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v1beta1::ListAllInterfacesRequest {
+    const NAME: &'static str = "ListAllInterfacesRequest";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V1BETA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v1beta1::ListAllInterfacesResponse {
+    const NAME: &'static str = "ListAllInterfacesResponse";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V1BETA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v1beta1::ListImplementationsRequest {
+    const NAME: &'static str = "ListImplementationsRequest";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V1BETA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v1beta1::ListImplementationsResponse {
+    const NAME: &'static str = "ListImplementationsResponse";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V1BETA1;
+}
+
+// cosmos.base.reflection.v2alpha1
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::AppDescriptor {
+    const NAME: &'static str = "AppDescriptor";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::AuthnDescriptor {
+    const NAME: &'static str = "AuthnDescriptor";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::ChainDescriptor {
+    const NAME: &'static str = "ChainDescriptor";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::CodecDescriptor {
+    const NAME: &'static str = "CodecDescriptor";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::ConfigurationDescriptor {
+    const NAME: &'static str = "ConfigurationDescriptor";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::GetAuthnDescriptorRequest {
+    const NAME: &'static str = "GetAuthnDescriptorRequest";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::GetAuthnDescriptorResponse {
+    const NAME: &'static str = "GetAuthnDescriptorResponse";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::GetChainDescriptorRequest {
+    const NAME: &'static str = "GetChainDescriptorRequest";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::GetChainDescriptorResponse {
+    const NAME: &'static str = "GetChainDescriptorResponse";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::GetCodecDescriptorRequest {
+    const NAME: &'static str = "GetCodecDescriptorRequest";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::GetCodecDescriptorResponse {
+    const NAME: &'static str = "GetCodecDescriptorResponse";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name
+    for cosmos::base::reflection::v2alpha1::GetConfigurationDescriptorRequest
+{
+    const NAME: &'static str = "GetConfigurationDescriptorRequest";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name
+    for cosmos::base::reflection::v2alpha1::GetConfigurationDescriptorResponse
+{
+    const NAME: &'static str = "GetConfigurationDescriptorResponse";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name
+    for cosmos::base::reflection::v2alpha1::GetQueryServicesDescriptorRequest
+{
+    const NAME: &'static str = "GetQueryServicesDescriptorRequest";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name
+    for cosmos::base::reflection::v2alpha1::GetQueryServicesDescriptorResponse
+{
+    const NAME: &'static str = "GetQueryServicesDescriptorResponse";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::GetTxDescriptorRequest {
+    const NAME: &'static str = "GetTxDescriptorRequest";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::GetTxDescriptorResponse {
+    const NAME: &'static str = "GetTxDescriptorResponse";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name
+    for cosmos::base::reflection::v2alpha1::InterfaceAcceptingMessageDescriptor
+{
+    const NAME: &'static str = "InterfaceAcceptingMessageDescriptor";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::InterfaceDescriptor {
+    const NAME: &'static str = "InterfaceDescriptor";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name
+    for cosmos::base::reflection::v2alpha1::InterfaceImplementerDescriptor
+{
+    const NAME: &'static str = "InterfaceImplementerDescriptor";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::MsgDescriptor {
+    const NAME: &'static str = "MsgDescriptor";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::QueryMethodDescriptor {
+    const NAME: &'static str = "QueryMethodDescriptor";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::QueryServiceDescriptor {
+    const NAME: &'static str = "QueryServiceDescriptor";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::QueryServicesDescriptor {
+    const NAME: &'static str = "QueryServicesDescriptor";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::SigningModeDescriptor {
+    const NAME: &'static str = "SigningModeDescriptor";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::base::reflection::v2alpha1::TxDescriptor {
+    const NAME: &'static str = "TxDescriptor";
+    const PACKAGE: &'static str = PACKAGE_BASE_REFLECTION_V2ALPHA1;
+}
+
+// cosmos.base.snapshots.v1beta1
+
+impl Name for cosmos::base::snapshots::v1beta1::Metadata {
+    const NAME: &'static str = "Metadata";
+    const PACKAGE: &'static str = PACKAGE_BASE_SNAPSHOTS_V1BETA1;
+}
+
+impl Name for cosmos::base::snapshots::v1beta1::Snapshot {
+    const NAME: &'static str = "Snapshot";
+    const PACKAGE: &'static str = PACKAGE_BASE_SNAPSHOTS_V1BETA1;
+}
+
+impl Name for cosmos::base::snapshots::v1beta1::SnapshotExtensionMeta {
+    const NAME: &'static str = "SnapshotExtensionMeta";
+    const PACKAGE: &'static str = PACKAGE_BASE_SNAPSHOTS_V1BETA1;
+}
+
+impl Name for cosmos::base::snapshots::v1beta1::SnapshotExtensionPayload {
+    const NAME: &'static str = "SnapshotExtensionPayload";
+    const PACKAGE: &'static str = PACKAGE_BASE_SNAPSHOTS_V1BETA1;
+}
+
+impl Name for cosmos::base::snapshots::v1beta1::SnapshotIavlItem {
+    const NAME: &'static str = "SnapshotIAVLItem";
+    const PACKAGE: &'static str = PACKAGE_BASE_SNAPSHOTS_V1BETA1;
+}
+
+impl Name for cosmos::base::snapshots::v1beta1::SnapshotItem {
+    const NAME: &'static str = "SnapshotItem";
+    const PACKAGE: &'static str = PACKAGE_BASE_SNAPSHOTS_V1BETA1;
+}
+
+impl Name for cosmos::base::snapshots::v1beta1::SnapshotKvItem {
+    const NAME: &'static str = "SnapshotKVItem";
+    const PACKAGE: &'static str = PACKAGE_BASE_SNAPSHOTS_V1BETA1;
+}
+
+impl Name for cosmos::base::snapshots::v1beta1::SnapshotSchema {
+    const NAME: &'static str = "SnapshotSchema";
+    const PACKAGE: &'static str = PACKAGE_BASE_SNAPSHOTS_V1BETA1;
+}
+
+impl Name for cosmos::base::snapshots::v1beta1::SnapshotStoreItem {
+    const NAME: &'static str = "SnapshotStoreItem";
+    const PACKAGE: &'static str = PACKAGE_BASE_SNAPSHOTS_V1BETA1;
+}
+
+// cosmos.base.store.v1beta1
+
+#[cfg(feature = "abci")]
+impl Name for cosmos::base::store::v1beta1::BlockMetadata {
+    const NAME: &'static str = "BlockMetadata";
+    const PACKAGE: &'static str = PACKAGE_BASE_STORE_V1BETA1;
+}
+
+#[cfg(feature = "abci")]
+impl Name for cosmos::base::store::v1beta1::CommitId {
+    const NAME: &'static str = "CommitID";
+    const PACKAGE: &'static str = PACKAGE_BASE_STORE_V1BETA1;
+}
+
+#[cfg(feature = "abci")]
+impl Name for cosmos::base::store::v1beta1::CommitInfo {
+    const NAME: &'static str = "CommitInfo";
+    const PACKAGE: &'static str = PACKAGE_BASE_STORE_V1BETA1;
+}
+
+#[cfg(feature = "abci")]
+impl Name for cosmos::base::store::v1beta1::StoreInfo {
+    const NAME: &'static str = "StoreInfo";
+    const PACKAGE: &'static str = PACKAGE_BASE_STORE_V1BETA1;
+}
+
+#[cfg(feature = "abci")]
+impl Name for cosmos::base::store::v1beta1::StoreKvPair {
+    const NAME: &'static str = "StoreKVPair";
+    const PACKAGE: &'static str = PACKAGE_BASE_STORE_V1BETA1;
+}
+
+// cosmos.base.tendermint.v1beta1
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::AbciQueryRequest {
+    const NAME: &'static str = "ABCIQueryRequest";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::AbciQueryResponse {
+    const NAME: &'static str = "ABCIQueryResponse";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::Block {
+    const NAME: &'static str = "Block";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::GetBlockByHeightRequest {
+    const NAME: &'static str = "GetBlockByHeightRequest";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::GetBlockByHeightResponse {
+    const NAME: &'static str = "GetBlockByHeightResponse";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::GetLatestBlockRequest {
+    const NAME: &'static str = "GetLatestBlockRequest";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::GetLatestBlockResponse {
+    const NAME: &'static str = "GetLatestBlockResponse";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::GetLatestValidatorSetRequest {
+    const NAME: &'static str = "GetLatestValidatorSetRequest";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::GetLatestValidatorSetResponse {
+    const NAME: &'static str = "GetLatestValidatorSetResponse";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::GetNodeInfoRequest {
+    const NAME: &'static str = "GetNodeInfoRequest";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::GetNodeInfoResponse {
+    const NAME: &'static str = "GetNodeInfoResponse";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::GetSyncingRequest {
+    const NAME: &'static str = "GetSyncingRequest";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::GetSyncingResponse {
+    const NAME: &'static str = "GetSyncingResponse";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::GetValidatorSetByHeightRequest {
+    const NAME: &'static str = "GetValidatorSetByHeightRequest";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name
+    for cosmos::base::tendermint::v1beta1::GetValidatorSetByHeightResponse
+{
+    const NAME: &'static str = "GetValidatorSetByHeightResponse";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::Header {
+    const NAME: &'static str = "Header";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::Module {
+    const NAME: &'static str = "Module";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::ProofOp {
+    const NAME: &'static str = "ProofOp";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::ProofOps {
+    const NAME: &'static str = "ProofOps";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::Validator {
+    const NAME: &'static str = "Validator";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+#[cfg(feature = "tendermint")]
+impl Name for cosmos::base::tendermint::v1beta1::VersionInfo {
+    const NAME: &'static str = "VersionInfo";
+    const PACKAGE: &'static str = PACKAGE_BASE_TENDERMINT_V1BETA1;
+}
+
+// cosmos.base.v1beta1
+
+impl Name for cosmos::base::v1beta1::Coin {
+    const NAME: &'static str = "Coin";
+    const PACKAGE: &'static str = PACKAGE_BASE_V1BETA1;
+}
+
+impl Name for cosmos::base::v1beta1::DecCoin {
+    const NAME: &'static str = "DecCoin";
+    const PACKAGE: &'static str = PACKAGE_BASE_V1BETA1;
+}
+
+impl Name for cosmos::base::v1beta1::DecProto {
+    const NAME: &'static str = "DecProto";
+    const PACKAGE: &'static str = PACKAGE_BASE_V1BETA1;
+}
+
+impl Name for cosmos::base::v1beta1::IntProto {
+    const NAME: &'static str = "IntProto";
+    const PACKAGE: &'static str = PACKAGE_BASE_V1BETA1;
+}
+
+// cosmos.crisis.v1beta1
+
+#[cfg(feature = "crisis")]
+impl Name for cosmos::crisis::v1beta1::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_CRISIS_V1BETA1;
+}
+
+#[cfg(feature = "crisis")]
+impl Name for cosmos::crisis::v1beta1::MsgUpdateParams {
+    const NAME: &'static str = "MsgUpdateParams";
+    const PACKAGE: &'static str = PACKAGE_CRISIS_V1BETA1;
+}
+
+#[cfg(feature = "crisis")]
+impl Name for cosmos::crisis::v1beta1::MsgUpdateParamsResponse {
+    const NAME: &'static str = "MsgUpdateParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_CRISIS_V1BETA1;
+}
+
+#[cfg(feature = "crisis")]
+impl Name for cosmos::crisis::v1beta1::MsgVerifyInvariant {
+    const NAME: &'static str = "MsgVerifyInvariant";
+    const PACKAGE: &'static str = PACKAGE_CRISIS_V1BETA1;
+}
+
+#[cfg(feature = "crisis")]
+impl Name for cosmos::crisis::v1beta1::MsgVerifyInvariantResponse {
+    const NAME: &'static str = "MsgVerifyInvariantResponse";
+    const PACKAGE: &'static str = PACKAGE_CRISIS_V1BETA1;
+}
+
+// cosmos.crypto.ed25519
+
+#[cfg(feature = "crypto")]
+impl Name for cosmos::crypto::ed25519::PrivKey {
+    const NAME: &'static str = "PrivKey";
+    const PACKAGE: &'static str = PACKAGE_CRYPTO_ED25519;
+}
+
+#[cfg(feature = "crypto")]
+impl Name for cosmos::crypto::ed25519::PubKey {
+    const NAME: &'static str = "PubKey";
+    const PACKAGE: &'static str = PACKAGE_CRYPTO_ED25519;
+}
+
+// cosmos.crypto.hd.v1
+
+#[cfg(feature = "crypto")]
+impl Name for cosmos::crypto::hd::v1::Bip44Params {
+    const NAME: &'static str = "BIP44Params";
+    const PACKAGE: &'static str = PACKAGE_CRYPTO_HD_V1;
+}
+
+// cosmos.crypto.keyring.v1
+
+#[cfg(feature = "crypto")]
+impl Name for cosmos::crypto::keyring::v1::Record {
+    const NAME: &'static str = "Record";
+    const PACKAGE: &'static str = PACKAGE_CRYPTO_KEYRING_V1;
+}
+
+// cosmos.crypto.multisig.v1beta1
+
+#[cfg(feature = "crypto")]
+impl Name for cosmos::crypto::multisig::v1beta1::CompactBitArray {
+    const NAME: &'static str = "CompactBitArray";
+    const PACKAGE: &'static str = PACKAGE_CRYPTO_MULTISIG_V1BETA1;
+}
+
+#[cfg(feature = "crypto")]
+impl Name for cosmos::crypto::multisig::v1beta1::MultiSignature {
+    const NAME: &'static str = "MultiSignature";
+    const PACKAGE: &'static str = PACKAGE_CRYPTO_MULTISIG_V1BETA1;
+}
+
+// cosmos.crypto.secp256k1
+
+#[cfg(feature = "crypto")]
+impl Name for cosmos::crypto::secp256k1::PrivKey {
+    const NAME: &'static str = "PrivKey";
+    const PACKAGE: &'static str = PACKAGE_CRYPTO_SECP256K1;
+}
+
+#[cfg(feature = "crypto")]
+impl Name for cosmos::crypto::secp256k1::PubKey {
+    const NAME: &'static str = "PubKey";
+    const PACKAGE: &'static str = PACKAGE_CRYPTO_SECP256K1;
+}
+
+// cosmos.crypto.secp256r1
+
+#[cfg(feature = "crypto")]
+impl Name for cosmos::crypto::secp256r1::PrivKey {
+    const NAME: &'static str = "PrivKey";
+    const PACKAGE: &'static str = PACKAGE_CRYPTO_SECP256R1;
+}
+
+#[cfg(feature = "crypto")]
+impl Name for cosmos::crypto::secp256r1::PubKey {
+    const NAME: &'static str = "PubKey";
+    const PACKAGE: &'static str = PACKAGE_CRYPTO_SECP256R1;
+}
+
+// cosmos.feegrant.v1beta1
+
+#[cfg(feature = "feegrant")]
+impl Name for cosmos::feegrant::v1beta1::AllowedMsgAllowance {
+    const NAME: &'static str = "AllowedMsgAllowance";
+    const PACKAGE: &'static str = PACKAGE_FEEGRANT_V1BETA1;
+}
+
+#[cfg(feature = "feegrant")]
+impl Name for cosmos::feegrant::v1beta1::BasicAllowance {
+    const NAME: &'static str = "BasicAllowance";
+    const PACKAGE: &'static str = PACKAGE_FEEGRANT_V1BETA1;
+}
+
+#[cfg(feature = "feegrant")]
+impl Name for cosmos::feegrant::v1beta1::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_FEEGRANT_V1BETA1;
+}
+
+#[cfg(feature = "feegrant")]
+impl Name for cosmos::feegrant::v1beta1::Grant {
+    const NAME: &'static str = "Grant";
+    const PACKAGE: &'static str = PACKAGE_FEEGRANT_V1BETA1;
+}
+
+#[cfg(feature = "feegrant")]
+impl Name for cosmos::feegrant::v1beta1::MsgGrantAllowance {
+    const NAME: &'static str = "MsgGrantAllowance";
+    const PACKAGE: &'static str = PACKAGE_FEEGRANT_V1BETA1;
+}
+
+#[cfg(feature = "feegrant")]
+impl Name for cosmos::feegrant::v1beta1::MsgGrantAllowanceResponse {
+    const NAME: &'static str = "MsgGrantAllowanceResponse";
+    const PACKAGE: &'static str = PACKAGE_FEEGRANT_V1BETA1;
+}
+
+#[cfg(feature = "feegrant")]
+impl Name for cosmos::feegrant::v1beta1::MsgRevokeAllowance {
+    const NAME: &'static str = "MsgRevokeAllowance";
+    const PACKAGE: &'static str = PACKAGE_FEEGRANT_V1BETA1;
+}
+
+#[cfg(feature = "feegrant")]
+impl Name for cosmos::feegrant::v1beta1::MsgRevokeAllowanceResponse {
+    const NAME: &'static str = "MsgRevokeAllowanceResponse";
+    const PACKAGE: &'static str = PACKAGE_FEEGRANT_V1BETA1;
+}
+
+#[cfg(feature = "feegrant")]
+impl Name for cosmos::feegrant::v1beta1::PeriodicAllowance {
+    const NAME: &'static str = "PeriodicAllowance";
+    const PACKAGE: &'static str = PACKAGE_FEEGRANT_V1BETA1;
+}
+
+#[cfg(feature = "feegrant")]
+impl Name for cosmos::feegrant::v1beta1::QueryAllowanceRequest {
+    const NAME: &'static str = "QueryAllowanceRequest";
+    const PACKAGE: &'static str = PACKAGE_FEEGRANT_V1BETA1;
+}
+
+#[cfg(feature = "feegrant")]
+impl Name for cosmos::feegrant::v1beta1::QueryAllowanceResponse {
+    const NAME: &'static str = "QueryAllowanceResponse";
+    const PACKAGE: &'static str = PACKAGE_FEEGRANT_V1BETA1;
+}
+
+#[cfg(feature = "feegrant")]
+impl Name for cosmos::feegrant::v1beta1::QueryAllowancesByGranterRequest {
+    const NAME: &'static str = "QueryAllowancesByGranterRequest";
+    const PACKAGE: &'static str = PACKAGE_FEEGRANT_V1BETA1;
+}
+
+#[cfg(feature = "feegrant")]
+impl Name for cosmos::feegrant::v1beta1::QueryAllowancesByGranterResponse {
+    const NAME: &'static str = "QueryAllowancesByGranterResponse";
+    const PACKAGE: &'static str = PACKAGE_FEEGRANT_V1BETA1;
+}
+
+#[cfg(feature = "feegrant")]
+impl Name for cosmos::feegrant::v1beta1::QueryAllowancesRequest {
+    const NAME: &'static str = "QueryAllowancesRequest";
+    const PACKAGE: &'static str = PACKAGE_FEEGRANT_V1BETA1;
+}
+
+#[cfg(feature = "feegrant")]
+impl Name for cosmos::feegrant::v1beta1::QueryAllowancesResponse {
+    const NAME: &'static str = "QueryAllowancesResponse";
+    const PACKAGE: &'static str = PACKAGE_FEEGRANT_V1BETA1;
+}
+
+// cosmos.genutil.v1beta1
+
+#[cfg(feature = "genutil")]
+impl Name for cosmos::genutil::v1beta1::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_GENUTIL_V1BETA1;
+}
+
+// cosmos.gov.v1
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::Deposit {
+    const NAME: &'static str = "Deposit";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::DepositParams {
+    const NAME: &'static str = "DepositParams";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::MsgDeposit {
+    const NAME: &'static str = "MsgDeposit";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::MsgDepositResponse {
+    const NAME: &'static str = "MsgDepositResponse";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::MsgExecLegacyContent {
+    const NAME: &'static str = "MsgExecLegacyContent";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::MsgExecLegacyContentResponse {
+    const NAME: &'static str = "MsgExecLegacyContentResponse";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::MsgSubmitProposal {
+    const NAME: &'static str = "MsgSubmitProposal";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::MsgSubmitProposalResponse {
+    const NAME: &'static str = "MsgSubmitProposalResponse";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::MsgUpdateParams {
+    const NAME: &'static str = "MsgUpdateParams";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::MsgUpdateParamsResponse {
+    const NAME: &'static str = "MsgUpdateParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::MsgVote {
+    const NAME: &'static str = "MsgVote";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::MsgVoteResponse {
+    const NAME: &'static str = "MsgVoteResponse";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::MsgVoteWeighted {
+    const NAME: &'static str = "MsgVoteWeighted";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::MsgVoteWeightedResponse {
+    const NAME: &'static str = "MsgVoteWeightedResponse";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::Proposal {
+    const NAME: &'static str = "Proposal";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::QueryDepositRequest {
+    const NAME: &'static str = "QueryDepositRequest";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::QueryDepositResponse {
+    const NAME: &'static str = "QueryDepositResponse";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::QueryDepositsRequest {
+    const NAME: &'static str = "QueryDepositsRequest";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::QueryDepositsResponse {
+    const NAME: &'static str = "QueryDepositsResponse";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::QueryProposalRequest {
+    const NAME: &'static str = "QueryProposalRequest";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::QueryProposalResponse {
+    const NAME: &'static str = "QueryProposalResponse";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::QueryProposalsRequest {
+    const NAME: &'static str = "QueryProposalsRequest";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::QueryProposalsResponse {
+    const NAME: &'static str = "QueryProposalsResponse";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::QueryTallyResultRequest {
+    const NAME: &'static str = "QueryTallyResultRequest";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::QueryTallyResultResponse {
+    const NAME: &'static str = "QueryTallyResultResponse";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::QueryVoteRequest {
+    const NAME: &'static str = "QueryVoteRequest";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::QueryVoteResponse {
+    const NAME: &'static str = "QueryVoteResponse";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::QueryVotesRequest {
+    const NAME: &'static str = "QueryVotesRequest";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::QueryVotesResponse {
+    const NAME: &'static str = "QueryVotesResponse";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::TallyParams {
+    const NAME: &'static str = "TallyParams";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::TallyResult {
+    const NAME: &'static str = "TallyResult";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::Vote {
+    const NAME: &'static str = "Vote";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::VotingParams {
+    const NAME: &'static str = "VotingParams";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+#[cfg(feature = "gov")]
+impl Name for cosmos::gov::v1::WeightedVoteOption {
+    const NAME: &'static str = "WeightedVoteOption";
+    const PACKAGE: &'static str = PACKAGE_GOV_V1;
+}
+
+// cosmos.group.v1
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::DecisionPolicyWindows {
+    const NAME: &'static str = "DecisionPolicyWindows";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::EventCreateGroup {
+    const NAME: &'static str = "EventCreateGroup";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::EventCreateGroupPolicy {
+    const NAME: &'static str = "EventCreateGroupPolicy";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::EventExec {
+    const NAME: &'static str = "EventExec";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::EventLeaveGroup {
+    const NAME: &'static str = "EventLeaveGroup";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::EventProposalPruned {
+    const NAME: &'static str = "EventProposalPruned";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::EventSubmitProposal {
+    const NAME: &'static str = "EventSubmitProposal";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::EventUpdateGroup {
+    const NAME: &'static str = "EventUpdateGroup";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::EventUpdateGroupPolicy {
+    const NAME: &'static str = "EventUpdateGroupPolicy";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::EventVote {
+    const NAME: &'static str = "EventVote";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::EventWithdrawProposal {
+    const NAME: &'static str = "EventWithdrawProposal";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::GroupInfo {
+    const NAME: &'static str = "GroupInfo";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::GroupMember {
+    const NAME: &'static str = "GroupMember";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::GroupPolicyInfo {
+    const NAME: &'static str = "GroupPolicyInfo";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::Member {
+    const NAME: &'static str = "Member";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MemberRequest {
+    const NAME: &'static str = "MemberRequest";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgCreateGroup {
+    const NAME: &'static str = "MsgCreateGroup";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgCreateGroupPolicy {
+    const NAME: &'static str = "MsgCreateGroupPolicy";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgCreateGroupPolicyResponse {
+    const NAME: &'static str = "MsgCreateGroupPolicyResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgCreateGroupResponse {
+    const NAME: &'static str = "MsgCreateGroupResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgCreateGroupWithPolicy {
+    const NAME: &'static str = "MsgCreateGroupWithPolicy";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgCreateGroupWithPolicyResponse {
+    const NAME: &'static str = "MsgCreateGroupWithPolicyResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgExec {
+    const NAME: &'static str = "MsgExec";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgExecResponse {
+    const NAME: &'static str = "MsgExecResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgLeaveGroup {
+    const NAME: &'static str = "MsgLeaveGroup";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgLeaveGroupResponse {
+    const NAME: &'static str = "MsgLeaveGroupResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgSubmitProposal {
+    const NAME: &'static str = "MsgSubmitProposal";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgSubmitProposalResponse {
+    const NAME: &'static str = "MsgSubmitProposalResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgUpdateGroupAdmin {
+    const NAME: &'static str = "MsgUpdateGroupAdmin";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgUpdateGroupAdminResponse {
+    const NAME: &'static str = "MsgUpdateGroupAdminResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgUpdateGroupMembers {
+    const NAME: &'static str = "MsgUpdateGroupMembers";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgUpdateGroupMembersResponse {
+    const NAME: &'static str = "MsgUpdateGroupMembersResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgUpdateGroupMetadata {
+    const NAME: &'static str = "MsgUpdateGroupMetadata";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgUpdateGroupMetadataResponse {
+    const NAME: &'static str = "MsgUpdateGroupMetadataResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgUpdateGroupPolicyAdmin {
+    const NAME: &'static str = "MsgUpdateGroupPolicyAdmin";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgUpdateGroupPolicyAdminResponse {
+    const NAME: &'static str = "MsgUpdateGroupPolicyAdminResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgUpdateGroupPolicyDecisionPolicy {
+    const NAME: &'static str = "MsgUpdateGroupPolicyDecisionPolicy";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgUpdateGroupPolicyDecisionPolicyResponse {
+    const NAME: &'static str = "MsgUpdateGroupPolicyDecisionPolicyResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgUpdateGroupPolicyMetadata {
+    const NAME: &'static str = "MsgUpdateGroupPolicyMetadata";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgUpdateGroupPolicyMetadataResponse {
+    const NAME: &'static str = "MsgUpdateGroupPolicyMetadataResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgVote {
+    const NAME: &'static str = "MsgVote";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgVoteResponse {
+    const NAME: &'static str = "MsgVoteResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgWithdrawProposal {
+    const NAME: &'static str = "MsgWithdrawProposal";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::MsgWithdrawProposalResponse {
+    const NAME: &'static str = "MsgWithdrawProposalResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::PercentageDecisionPolicy {
+    const NAME: &'static str = "PercentageDecisionPolicy";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::Proposal {
+    const NAME: &'static str = "Proposal";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryGroupInfoRequest {
+    const NAME: &'static str = "QueryGroupInfoRequest";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryGroupInfoResponse {
+    const NAME: &'static str = "QueryGroupInfoResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryGroupMembersRequest {
+    const NAME: &'static str = "QueryGroupMembersRequest";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryGroupMembersResponse {
+    const NAME: &'static str = "QueryGroupMembersResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryGroupPoliciesByAdminRequest {
+    const NAME: &'static str = "QueryGroupPoliciesByAdminRequest";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryGroupPoliciesByAdminResponse {
+    const NAME: &'static str = "QueryGroupPoliciesByAdminResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryGroupPoliciesByGroupRequest {
+    const NAME: &'static str = "QueryGroupPoliciesByGroupRequest";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryGroupPoliciesByGroupResponse {
+    const NAME: &'static str = "QueryGroupPoliciesByGroupResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryGroupPolicyInfoRequest {
+    const NAME: &'static str = "QueryGroupPolicyInfoRequest";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryGroupPolicyInfoResponse {
+    const NAME: &'static str = "QueryGroupPolicyInfoResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryGroupsByAdminRequest {
+    const NAME: &'static str = "QueryGroupsByAdminRequest";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryGroupsByAdminResponse {
+    const NAME: &'static str = "QueryGroupsByAdminResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryGroupsByMemberRequest {
+    const NAME: &'static str = "QueryGroupsByMemberRequest";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryGroupsByMemberResponse {
+    const NAME: &'static str = "QueryGroupsByMemberResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryGroupsRequest {
+    const NAME: &'static str = "QueryGroupsRequest";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryGroupsResponse {
+    const NAME: &'static str = "QueryGroupsResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryProposalRequest {
+    const NAME: &'static str = "QueryProposalRequest";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryProposalResponse {
+    const NAME: &'static str = "QueryProposalResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryProposalsByGroupPolicyRequest {
+    const NAME: &'static str = "QueryProposalsByGroupPolicyRequest";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryProposalsByGroupPolicyResponse {
+    const NAME: &'static str = "QueryProposalsByGroupPolicyResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryTallyResultRequest {
+    const NAME: &'static str = "QueryTallyResultRequest";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryTallyResultResponse {
+    const NAME: &'static str = "QueryTallyResultResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryVoteByProposalVoterRequest {
+    const NAME: &'static str = "QueryVoteByProposalVoterRequest";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryVoteByProposalVoterResponse {
+    const NAME: &'static str = "QueryVoteByProposalVoterResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryVotesByProposalRequest {
+    const NAME: &'static str = "QueryVotesByProposalRequest";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryVotesByProposalResponse {
+    const NAME: &'static str = "QueryVotesByProposalResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryVotesByVoterRequest {
+    const NAME: &'static str = "QueryVotesByVoterRequest";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::QueryVotesByVoterResponse {
+    const NAME: &'static str = "QueryVotesByVoterResponse";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::TallyResult {
+    const NAME: &'static str = "TallyResult";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::ThresholdDecisionPolicy {
+    const NAME: &'static str = "ThresholdDecisionPolicy";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+#[cfg(feature = "group")]
+impl Name for cosmos::group::v1::Vote {
+    const NAME: &'static str = "Vote";
+    const PACKAGE: &'static str = PACKAGE_GROUP_V1;
+}
+
+// cosmos.mint.v1beta1
+
+#[cfg(feature = "mint")]
+impl Name for cosmos::mint::v1beta1::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_MINT_V1BETA1;
+}
+
+#[cfg(feature = "mint")]
+impl Name for cosmos::mint::v1beta1::Minter {
+    const NAME: &'static str = "Minter";
+    const PACKAGE: &'static str = PACKAGE_MINT_V1BETA1;
+}
+
+#[cfg(feature = "mint")]
+impl Name for cosmos::mint::v1beta1::MsgUpdateParams {
+    const NAME: &'static str = "MsgUpdateParams";
+    const PACKAGE: &'static str = PACKAGE_MINT_V1BETA1;
+}
+
+#[cfg(feature = "mint")]
+impl Name for cosmos::mint::v1beta1::MsgUpdateParamsResponse {
+    const NAME: &'static str = "MsgUpdateParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_MINT_V1BETA1;
+}
+
+#[cfg(feature = "mint")]
+impl Name for cosmos::mint::v1beta1::Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = PACKAGE_MINT_V1BETA1;
+}
+
+#[cfg(feature = "mint")]
+impl Name for cosmos::mint::v1beta1::QueryAnnualProvisionsRequest {
+    const NAME: &'static str = "QueryAnnualProvisionsRequest";
+    const PACKAGE: &'static str = PACKAGE_MINT_V1BETA1;
+}
+
+#[cfg(feature = "mint")]
+impl Name for cosmos::mint::v1beta1::QueryAnnualProvisionsResponse {
+    const NAME: &'static str = "QueryAnnualProvisionsResponse";
+    const PACKAGE: &'static str = PACKAGE_MINT_V1BETA1;
+}
+
+#[cfg(feature = "mint")]
+impl Name for cosmos::mint::v1beta1::QueryInflationRequest {
+    const NAME: &'static str = "QueryInflationRequest";
+    const PACKAGE: &'static str = PACKAGE_MINT_V1BETA1;
+}
+
+#[cfg(feature = "mint")]
+impl Name for cosmos::mint::v1beta1::QueryInflationResponse {
+    const NAME: &'static str = "QueryInflationResponse";
+    const PACKAGE: &'static str = PACKAGE_MINT_V1BETA1;
+}
+
+#[cfg(feature = "mint")]
+impl Name for cosmos::mint::v1beta1::QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = PACKAGE_MINT_V1BETA1;
+}
+
+#[cfg(feature = "mint")]
+impl Name for cosmos::mint::v1beta1::QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_MINT_V1BETA1;
+}
+
+// cosmos.nft.v1beta1
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::Class {
+    const NAME: &'static str = "Class";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::Entry {
+    const NAME: &'static str = "Entry";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::EventBurn {
+    const NAME: &'static str = "EventBurn";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::EventMint {
+    const NAME: &'static str = "EventMint";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::EventSend {
+    const NAME: &'static str = "EventSend";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::MsgSend {
+    const NAME: &'static str = "MsgSend";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::MsgSendResponse {
+    const NAME: &'static str = "MsgSendResponse";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::Nft {
+    const NAME: &'static str = "NFT";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::QueryBalanceRequest {
+    const NAME: &'static str = "QueryBalanceRequest";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::QueryBalanceResponse {
+    const NAME: &'static str = "QueryBalanceResponse";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::QueryClassRequest {
+    const NAME: &'static str = "QueryClassRequest";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::QueryClassResponse {
+    const NAME: &'static str = "QueryClassResponse";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::QueryClassesRequest {
+    const NAME: &'static str = "QueryClassesRequest";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::QueryClassesResponse {
+    const NAME: &'static str = "QueryClassesResponse";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::QueryNftRequest {
+    const NAME: &'static str = "QueryNFTRequest";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::QueryNftResponse {
+    const NAME: &'static str = "QueryNFTResponse";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::QueryNfTsRequest {
+    const NAME: &'static str = "QueryNFTsRequest";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::QueryNfTsResponse {
+    const NAME: &'static str = "QueryNFTsResponse";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::QueryOwnerRequest {
+    const NAME: &'static str = "QueryOwnerRequest";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::QueryOwnerResponse {
+    const NAME: &'static str = "QueryOwnerResponse";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::QuerySupplyRequest {
+    const NAME: &'static str = "QuerySupplyRequest";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+#[cfg(feature = "nft")]
+impl Name for cosmos::nft::v1beta1::QuerySupplyResponse {
+    const NAME: &'static str = "QuerySupplyResponse";
+    const PACKAGE: &'static str = PACKAGE_NFT_V1BETA1;
+}
+
+// cosmos.params.v1beta1
+
+#[cfg(feature = "params")]
+impl Name for cosmos::params::v1beta1::ParamChange {
+    const NAME: &'static str = "ParamChange";
+    const PACKAGE: &'static str = PACKAGE_PARAMS_V1BETA1;
+}
+
+#[cfg(feature = "params")]
+impl Name for cosmos::params::v1beta1::ParameterChangeProposal {
+    const NAME: &'static str = "ParameterChangeProposal";
+    const PACKAGE: &'static str = PACKAGE_PARAMS_V1BETA1;
+}
+
+#[cfg(feature = "params")]
+impl Name for cosmos::params::v1beta1::QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = PACKAGE_PARAMS_V1BETA1;
+}
+
+#[cfg(feature = "params")]
+impl Name for cosmos::params::v1beta1::QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_PARAMS_V1BETA1;
+}
+
+#[cfg(feature = "params")]
+impl Name for cosmos::params::v1beta1::QuerySubspacesRequest {
+    const NAME: &'static str = "QuerySubspacesRequest";
+    const PACKAGE: &'static str = PACKAGE_PARAMS_V1BETA1;
+}
+
+#[cfg(feature = "params")]
+impl Name for cosmos::params::v1beta1::QuerySubspacesResponse {
+    const NAME: &'static str = "QuerySubspacesResponse";
+    const PACKAGE: &'static str = PACKAGE_PARAMS_V1BETA1;
+}
+
+#[cfg(feature = "params")]
+impl Name for cosmos::params::v1beta1::Subspace {
+    const NAME: &'static str = "Subspace";
+    const PACKAGE: &'static str = PACKAGE_PARAMS_V1BETA1;
+}
+
+// cosmos.reflection.v1
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::reflection::v1::FileDescriptorsRequest {
+    const NAME: &'static str = "FileDescriptorsRequest";
+    const PACKAGE: &'static str = PACKAGE_REFLECTION_V1;
+}
+
+#[cfg(feature = "reflection")]
+impl Name for cosmos::reflection::v1::FileDescriptorsResponse {
+    const NAME: &'static str = "FileDescriptorsResponse";
+    const PACKAGE: &'static str = PACKAGE_REFLECTION_V1;
+}
+
+// cosmos.slashing.v1beta1
+
+#[cfg(feature = "slashing")]
+impl Name for cosmos::slashing::v1beta1::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_SLASHING_V1BETA1;
+}
+
+#[cfg(feature = "slashing")]
+impl Name for cosmos::slashing::v1beta1::MissedBlock {
+    const NAME: &'static str = "MissedBlock";
+    const PACKAGE: &'static str = PACKAGE_SLASHING_V1BETA1;
+}
+
+#[cfg(feature = "slashing")]
+impl Name for cosmos::slashing::v1beta1::MsgUnjail {
+    const NAME: &'static str = "MsgUnjail";
+    const PACKAGE: &'static str = PACKAGE_SLASHING_V1BETA1;
+}
+
+#[cfg(feature = "slashing")]
+impl Name for cosmos::slashing::v1beta1::MsgUnjailResponse {
+    const NAME: &'static str = "MsgUnjailResponse";
+    const PACKAGE: &'static str = PACKAGE_SLASHING_V1BETA1;
+}
+
+#[cfg(feature = "slashing")]
+impl Name for cosmos::slashing::v1beta1::MsgUpdateParams {
+    const NAME: &'static str = "MsgUpdateParams";
+    const PACKAGE: &'static str = PACKAGE_SLASHING_V1BETA1;
+}
+
+#[cfg(feature = "slashing")]
+impl Name for cosmos::slashing::v1beta1::MsgUpdateParamsResponse {
+    const NAME: &'static str = "MsgUpdateParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_SLASHING_V1BETA1;
+}
+
+#[cfg(feature = "slashing")]
+impl Name for cosmos::slashing::v1beta1::Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = PACKAGE_SLASHING_V1BETA1;
+}
+
+#[cfg(feature = "slashing")]
+impl Name for cosmos::slashing::v1beta1::QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = PACKAGE_SLASHING_V1BETA1;
+}
+
+#[cfg(feature = "slashing")]
+impl Name for cosmos::slashing::v1beta1::QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_SLASHING_V1BETA1;
+}
+
+#[cfg(feature = "slashing")]
+impl Name for cosmos::slashing::v1beta1::QuerySigningInfoRequest {
+    const NAME: &'static str = "QuerySigningInfoRequest";
+    const PACKAGE: &'static str = PACKAGE_SLASHING_V1BETA1;
+}
+
+#[cfg(feature = "slashing")]
+impl Name for cosmos::slashing::v1beta1::QuerySigningInfoResponse {
+    const NAME: &'static str = "QuerySigningInfoResponse";
+    const PACKAGE: &'static str = PACKAGE_SLASHING_V1BETA1;
+}
+
+#[cfg(feature = "slashing")]
+impl Name for cosmos::slashing::v1beta1::QuerySigningInfosRequest {
+    const NAME: &'static str = "QuerySigningInfosRequest";
+    const PACKAGE: &'static str = PACKAGE_SLASHING_V1BETA1;
+}
+
+#[cfg(feature = "slashing")]
+impl Name for cosmos::slashing::v1beta1::QuerySigningInfosResponse {
+    const NAME: &'static str = "QuerySigningInfosResponse";
+    const PACKAGE: &'static str = PACKAGE_SLASHING_V1BETA1;
+}
+
+#[cfg(feature = "slashing")]
+impl Name for cosmos::slashing::v1beta1::SigningInfo {
+    const NAME: &'static str = "SigningInfo";
+    const PACKAGE: &'static str = PACKAGE_SLASHING_V1BETA1;
+}
+
+#[cfg(feature = "slashing")]
+impl Name for cosmos::slashing::v1beta1::ValidatorMissedBlocks {
+    const NAME: &'static str = "ValidatorMissedBlocks";
+    const PACKAGE: &'static str = PACKAGE_SLASHING_V1BETA1;
+}
+
+#[cfg(feature = "slashing")]
+impl Name for cosmos::slashing::v1beta1::ValidatorSigningInfo {
+    const NAME: &'static str = "ValidatorSigningInfo";
+    const PACKAGE: &'static str = PACKAGE_SLASHING_V1BETA1;
+}
+
+// cosmos.staking.v1beta1
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::Commission {
+    const NAME: &'static str = "Commission";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::CommissionRates {
+    const NAME: &'static str = "CommissionRates";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::DvPair {
+    const NAME: &'static str = "DVPair";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::DvPairs {
+    const NAME: &'static str = "DVPairs";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::DvvTriplet {
+    const NAME: &'static str = "DVVTriplet";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::DvvTriplets {
+    const NAME: &'static str = "DVVTriplets";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::Delegation {
+    const NAME: &'static str = "Delegation";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::DelegationResponse {
+    const NAME: &'static str = "DelegationResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::Description {
+    const NAME: &'static str = "Description";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::HistoricalInfo {
+    const NAME: &'static str = "HistoricalInfo";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::LastValidatorPower {
+    const NAME: &'static str = "LastValidatorPower";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::MsgBeginRedelegate {
+    const NAME: &'static str = "MsgBeginRedelegate";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::MsgBeginRedelegateResponse {
+    const NAME: &'static str = "MsgBeginRedelegateResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::MsgCancelUnbondingDelegation {
+    const NAME: &'static str = "MsgCancelUnbondingDelegation";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::MsgCancelUnbondingDelegationResponse {
+    const NAME: &'static str = "MsgCancelUnbondingDelegationResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::MsgCreateValidator {
+    const NAME: &'static str = "MsgCreateValidator";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::MsgCreateValidatorResponse {
+    const NAME: &'static str = "MsgCreateValidatorResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::MsgDelegate {
+    const NAME: &'static str = "MsgDelegate";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::MsgDelegateResponse {
+    const NAME: &'static str = "MsgDelegateResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::MsgEditValidator {
+    const NAME: &'static str = "MsgEditValidator";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::MsgEditValidatorResponse {
+    const NAME: &'static str = "MsgEditValidatorResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::MsgUndelegate {
+    const NAME: &'static str = "MsgUndelegate";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::MsgUndelegateResponse {
+    const NAME: &'static str = "MsgUndelegateResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::MsgUpdateParams {
+    const NAME: &'static str = "MsgUpdateParams";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::MsgUpdateParamsResponse {
+    const NAME: &'static str = "MsgUpdateParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::Pool {
+    const NAME: &'static str = "Pool";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryDelegationRequest {
+    const NAME: &'static str = "QueryDelegationRequest";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryDelegationResponse {
+    const NAME: &'static str = "QueryDelegationResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryDelegatorDelegationsRequest {
+    const NAME: &'static str = "QueryDelegatorDelegationsRequest";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryDelegatorDelegationsResponse {
+    const NAME: &'static str = "QueryDelegatorDelegationsResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name
+    for cosmos::staking::v1beta1::QueryDelegatorUnbondingDelegationsRequest
+{
+    const NAME: &'static str = "QueryDelegatorUnbondingDelegationsRequest";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name
+    for cosmos::staking::v1beta1::QueryDelegatorUnbondingDelegationsResponse
+{
+    const NAME: &'static str = "QueryDelegatorUnbondingDelegationsResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryDelegatorValidatorRequest {
+    const NAME: &'static str = "QueryDelegatorValidatorRequest";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryDelegatorValidatorResponse {
+    const NAME: &'static str = "QueryDelegatorValidatorResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryDelegatorValidatorsRequest {
+    const NAME: &'static str = "QueryDelegatorValidatorsRequest";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryDelegatorValidatorsResponse {
+    const NAME: &'static str = "QueryDelegatorValidatorsResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryHistoricalInfoRequest {
+    const NAME: &'static str = "QueryHistoricalInfoRequest";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryHistoricalInfoResponse {
+    const NAME: &'static str = "QueryHistoricalInfoResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryPoolRequest {
+    const NAME: &'static str = "QueryPoolRequest";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryPoolResponse {
+    const NAME: &'static str = "QueryPoolResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryRedelegationsRequest {
+    const NAME: &'static str = "QueryRedelegationsRequest";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryRedelegationsResponse {
+    const NAME: &'static str = "QueryRedelegationsResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryUnbondingDelegationRequest {
+    const NAME: &'static str = "QueryUnbondingDelegationRequest";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryUnbondingDelegationResponse {
+    const NAME: &'static str = "QueryUnbondingDelegationResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryValidatorDelegationsRequest {
+    const NAME: &'static str = "QueryValidatorDelegationsRequest";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryValidatorDelegationsResponse {
+    const NAME: &'static str = "QueryValidatorDelegationsResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryValidatorRequest {
+    const NAME: &'static str = "QueryValidatorRequest";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryValidatorResponse {
+    const NAME: &'static str = "QueryValidatorResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name
+    for cosmos::staking::v1beta1::QueryValidatorUnbondingDelegationsRequest
+{
+    const NAME: &'static str = "QueryValidatorUnbondingDelegationsRequest";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name
+    for cosmos::staking::v1beta1::QueryValidatorUnbondingDelegationsResponse
+{
+    const NAME: &'static str = "QueryValidatorUnbondingDelegationsResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryValidatorsRequest {
+    const NAME: &'static str = "QueryValidatorsRequest";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::QueryValidatorsResponse {
+    const NAME: &'static str = "QueryValidatorsResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::Redelegation {
+    const NAME: &'static str = "Redelegation";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::RedelegationEntry {
+    const NAME: &'static str = "RedelegationEntry";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::RedelegationEntryResponse {
+    const NAME: &'static str = "RedelegationEntryResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::RedelegationResponse {
+    const NAME: &'static str = "RedelegationResponse";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::StakeAuthorization {
+    const NAME: &'static str = "StakeAuthorization";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::UnbondingDelegation {
+    const NAME: &'static str = "UnbondingDelegation";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::UnbondingDelegationEntry {
+    const NAME: &'static str = "UnbondingDelegationEntry";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::ValAddresses {
+    const NAME: &'static str = "ValAddresses";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::Validator {
+    const NAME: &'static str = "Validator";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+#[cfg(feature = "staking")]
+impl Name for cosmos::staking::v1beta1::ValidatorUpdates {
+    const NAME: &'static str = "ValidatorUpdates";
+    const PACKAGE: &'static str = PACKAGE_STAKING_V1BETA1;
+}
+
+// cosmos.tx.config.v1
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::config::v1::Config {
+    const NAME: &'static str = "Config";
+    const PACKAGE: &'static str = PACKAGE_TX_CONFIG_V1;
+}
+
+// cosmos.tx.signing.v1beta1
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::signing::v1beta1::SignatureDescriptor {
+    const NAME: &'static str = "SignatureDescriptor";
+    const PACKAGE: &'static str = PACKAGE_TX_SIGNING_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::signing::v1beta1::SignatureDescriptors {
+    const NAME: &'static str = "SignatureDescriptors";
+    const PACKAGE: &'static str = PACKAGE_TX_SIGNING_V1BETA1;
+}
+
+// cosmos.tx.v1beta1
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::AuthInfo {
+    const NAME: &'static str = "AuthInfo";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::AuxSignerData {
+    const NAME: &'static str = "AuxSignerData";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::BroadcastTxRequest {
+    const NAME: &'static str = "BroadcastTxRequest";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::BroadcastTxResponse {
+    const NAME: &'static str = "BroadcastTxResponse";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::Fee {
+    const NAME: &'static str = "Fee";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::GetBlockWithTxsRequest {
+    const NAME: &'static str = "GetBlockWithTxsRequest";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::GetBlockWithTxsResponse {
+    const NAME: &'static str = "GetBlockWithTxsResponse";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::GetTxRequest {
+    const NAME: &'static str = "GetTxRequest";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::GetTxResponse {
+    const NAME: &'static str = "GetTxResponse";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::GetTxsEventRequest {
+    const NAME: &'static str = "GetTxsEventRequest";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::GetTxsEventResponse {
+    const NAME: &'static str = "GetTxsEventResponse";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::ModeInfo {
+    const NAME: &'static str = "ModeInfo";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::SignDoc {
+    const NAME: &'static str = "SignDoc";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::SignDocDirectAux {
+    const NAME: &'static str = "SignDocDirectAux";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::SignerInfo {
+    const NAME: &'static str = "SignerInfo";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::SimulateRequest {
+    const NAME: &'static str = "SimulateRequest";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::SimulateResponse {
+    const NAME: &'static str = "SimulateResponse";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::Tip {
+    const NAME: &'static str = "Tip";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::Tx {
+    const NAME: &'static str = "Tx";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::TxBody {
+    const NAME: &'static str = "TxBody";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::TxDecodeAminoRequest {
+    const NAME: &'static str = "TxDecodeAminoRequest";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::TxDecodeAminoResponse {
+    const NAME: &'static str = "TxDecodeAminoResponse";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::TxDecodeRequest {
+    const NAME: &'static str = "TxDecodeRequest";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::TxDecodeResponse {
+    const NAME: &'static str = "TxDecodeResponse";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::TxEncodeAminoRequest {
+    const NAME: &'static str = "TxEncodeAminoRequest";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::TxEncodeAminoResponse {
+    const NAME: &'static str = "TxEncodeAminoResponse";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::TxEncodeRequest {
+    const NAME: &'static str = "TxEncodeRequest";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::TxEncodeResponse {
+    const NAME: &'static str = "TxEncodeResponse";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+#[cfg(feature = "tx")]
+impl Name for cosmos::tx::v1beta1::TxRaw {
+    const NAME: &'static str = "TxRaw";
+    const PACKAGE: &'static str = PACKAGE_TX_V1BETA1;
+}
+
+// cosmos.upgrade.v1beta1
+
+#[cfg(feature = "upgrade")]
+impl Name for cosmos::upgrade::v1beta1::CancelSoftwareUpgradeProposal {
+    const NAME: &'static str = "CancelSoftwareUpgradeProposal";
+    const PACKAGE: &'static str = PACKAGE_UPGRADE_V1BETA1;
+}
+
+#[cfg(feature = "upgrade")]
+impl Name for cosmos::upgrade::v1beta1::ModuleVersion {
+    const NAME: &'static str = "ModuleVersion";
+    const PACKAGE: &'static str = PACKAGE_UPGRADE_V1BETA1;
+}
+
+#[cfg(feature = "upgrade")]
+impl Name for cosmos::upgrade::v1beta1::MsgCancelUpgrade {
+    const NAME: &'static str = "MsgCancelUpgrade";
+    const PACKAGE: &'static str = PACKAGE_UPGRADE_V1BETA1;
+}
+
+#[cfg(feature = "upgrade")]
+impl Name for cosmos::upgrade::v1beta1::MsgCancelUpgradeResponse {
+    const NAME: &'static str = "MsgCancelUpgradeResponse";
+    const PACKAGE: &'static str = PACKAGE_UPGRADE_V1BETA1;
+}
+
+#[cfg(feature = "upgrade")]
+impl Name for cosmos::upgrade::v1beta1::MsgSoftwareUpgrade {
+    const NAME: &'static str = "MsgSoftwareUpgrade";
+    const PACKAGE: &'static str = PACKAGE_UPGRADE_V1BETA1;
+}
+
+#[cfg(feature = "upgrade")]
+impl Name for cosmos::upgrade::v1beta1::MsgSoftwareUpgradeResponse {
+    const NAME: &'static str = "MsgSoftwareUpgradeResponse";
+    const PACKAGE: &'static str = PACKAGE_UPGRADE_V1BETA1;
+}
+
+#[cfg(feature = "upgrade")]
+impl Name for cosmos::upgrade::v1beta1::Plan {
+    const NAME: &'static str = "Plan";
+    const PACKAGE: &'static str = PACKAGE_UPGRADE_V1BETA1;
+}
+
+#[cfg(feature = "upgrade")]
+impl Name for cosmos::upgrade::v1beta1::QueryAppliedPlanRequest {
+    const NAME: &'static str = "QueryAppliedPlanRequest";
+    const PACKAGE: &'static str = PACKAGE_UPGRADE_V1BETA1;
+}
+
+#[cfg(feature = "upgrade")]
+impl Name for cosmos::upgrade::v1beta1::QueryAppliedPlanResponse {
+    const NAME: &'static str = "QueryAppliedPlanResponse";
+    const PACKAGE: &'static str = PACKAGE_UPGRADE_V1BETA1;
+}
+
+#[cfg(feature = "upgrade")]
+impl Name for cosmos::upgrade::v1beta1::QueryAuthorityRequest {
+    const NAME: &'static str = "QueryAuthorityRequest";
+    const PACKAGE: &'static str = PACKAGE_UPGRADE_V1BETA1;
+}
+
+#[cfg(feature = "upgrade")]
+impl Name for cosmos::upgrade::v1beta1::QueryAuthorityResponse {
+    const NAME: &'static str = "QueryAuthorityResponse";
+    const PACKAGE: &'static str = PACKAGE_UPGRADE_V1BETA1;
+}
+
+#[cfg(feature = "upgrade")]
+impl Name for cosmos::upgrade::v1beta1::QueryCurrentPlanRequest {
+    const NAME: &'static str = "QueryCurrentPlanRequest";
+    const PACKAGE: &'static str = PACKAGE_UPGRADE_V1BETA1;
+}
+
+#[cfg(feature = "upgrade")]
+impl Name for cosmos::upgrade::v1beta1::QueryCurrentPlanResponse {
+    const NAME: &'static str = "QueryCurrentPlanResponse";
+    const PACKAGE: &'static str = PACKAGE_UPGRADE_V1BETA1;
+}
+
+#[cfg(feature = "upgrade")]
+impl Name for cosmos::upgrade::v1beta1::QueryModuleVersionsRequest {
+    const NAME: &'static str = "QueryModuleVersionsRequest";
+    const PACKAGE: &'static str = PACKAGE_UPGRADE_V1BETA1;
+}
+
+#[cfg(feature = "upgrade")]
+impl Name for cosmos::upgrade::v1beta1::QueryModuleVersionsResponse {
+    const NAME: &'static str = "QueryModuleVersionsResponse";
+    const PACKAGE: &'static str = PACKAGE_UPGRADE_V1BETA1;
+}
+
+#[cfg(feature = "upgrade")]
+impl Name for cosmos::upgrade::v1beta1::QueryUpgradedConsensusStateRequest {
+    const NAME: &'static str = "QueryUpgradedConsensusStateRequest";
+    const PACKAGE: &'static str = PACKAGE_UPGRADE_V1BETA1;
+}
+
+#[cfg(feature = "upgrade")]
+impl Name for cosmos::upgrade::v1beta1::QueryUpgradedConsensusStateResponse {
+    const NAME: &'static str = "QueryUpgradedConsensusStateResponse";
+    const PACKAGE: &'static str = PACKAGE_UPGRADE_V1BETA1;
+}
+
+#[cfg(feature = "upgrade")]
+impl Name for cosmos::upgrade::v1beta1::SoftwareUpgradeProposal {
+    const NAME: &'static str = "SoftwareUpgradeProposal";
+    const PACKAGE: &'static str = PACKAGE_UPGRADE_V1BETA1;
+}
+
+// cosmos.vesting.v1beta1
+
+#[cfg(feature = "vesting")]
+impl Name for cosmos::vesting::v1beta1::BaseVestingAccount {
+    const NAME: &'static str = "BaseVestingAccount";
+    const PACKAGE: &'static str = PACKAGE_VESTING_V1BETA1;
+}
+
+#[cfg(feature = "vesting")]
+impl Name for cosmos::vesting::v1beta1::ContinuousVestingAccount {
+    const NAME: &'static str = "ContinuousVestingAccount";
+    const PACKAGE: &'static str = PACKAGE_VESTING_V1BETA1;
+}
+
+#[cfg(feature = "vesting")]
+impl Name for cosmos::vesting::v1beta1::DelayedVestingAccount {
+    const NAME: &'static str = "DelayedVestingAccount";
+    const PACKAGE: &'static str = PACKAGE_VESTING_V1BETA1;
+}
+
+#[cfg(feature = "vesting")]
+impl Name for cosmos::vesting::v1beta1::MsgCreatePeriodicVestingAccount {
+    const NAME: &'static str = "MsgCreatePeriodicVestingAccount";
+    const PACKAGE: &'static str = PACKAGE_VESTING_V1BETA1;
+}
+
+#[cfg(feature = "vesting")]
+impl Name for cosmos::vesting::v1beta1::MsgCreatePeriodicVestingAccountResponse {
+    const NAME: &'static str = "MsgCreatePeriodicVestingAccountResponse";
+    const PACKAGE: &'static str = PACKAGE_VESTING_V1BETA1;
+}
+
+#[cfg(feature = "vesting")]
+impl Name for cosmos::vesting::v1beta1::MsgCreatePermanentLockedAccount {
+    const NAME: &'static str = "MsgCreatePermanentLockedAccount";
+    const PACKAGE: &'static str = PACKAGE_VESTING_V1BETA1;
+}
+
+#[cfg(feature = "vesting")]
+impl Name for cosmos::vesting::v1beta1::MsgCreatePermanentLockedAccountResponse {
+    const NAME: &'static str = "MsgCreatePermanentLockedAccountResponse";
+    const PACKAGE: &'static str = PACKAGE_VESTING_V1BETA1;
+}
+
+#[cfg(feature = "vesting")]
+impl Name for cosmos::vesting::v1beta1::MsgCreateVestingAccount {
+    const NAME: &'static str = "MsgCreateVestingAccount";
+    const PACKAGE: &'static str = PACKAGE_VESTING_V1BETA1;
+}
+
+#[cfg(feature = "vesting")]
+impl Name for cosmos::vesting::v1beta1::MsgCreateVestingAccountResponse {
+    const NAME: &'static str = "MsgCreateVestingAccountResponse";
+    const PACKAGE: &'static str = PACKAGE_VESTING_V1BETA1;
+}
+
+#[cfg(feature = "vesting")]
+impl Name for cosmos::vesting::v1beta1::Period {
+    const NAME: &'static str = "Period";
+    const PACKAGE: &'static str = PACKAGE_VESTING_V1BETA1;
+}
+
+#[cfg(feature = "vesting")]
+impl Name for cosmos::vesting::v1beta1::PeriodicVestingAccount {
+    const NAME: &'static str = "PeriodicVestingAccount";
+    const PACKAGE: &'static str = PACKAGE_VESTING_V1BETA1;
+}
+
+#[cfg(feature = "vesting")]
+impl Name for cosmos::vesting::v1beta1::PermanentLockedAccount {
+    const NAME: &'static str = "PermanentLockedAccount";
+    const PACKAGE: &'static str = PACKAGE_VESTING_V1BETA1;
+}
+
+// gRPC query paths
+
+#[cfg(feature = "auth")]
+impl NibiruGrpcQuery for cosmos::auth::v1beta1::AddressBytesToStringRequest {
+    const GRPC_PATH: &'static str =
+        "/cosmos.auth.v1beta1.Query/AddressBytesToString";
+    type Response = cosmos::auth::v1beta1::AddressBytesToStringResponse;
+}
+
+#[cfg(feature = "auth")]
+impl NibiruGrpcQuery for cosmos::auth::v1beta1::AddressStringToBytesRequest {
+    const GRPC_PATH: &'static str =
+        "/cosmos.auth.v1beta1.Query/AddressStringToBytes";
+    type Response = cosmos::auth::v1beta1::AddressStringToBytesResponse;
+}
+
+#[cfg(feature = "auth")]
+impl NibiruGrpcQuery for cosmos::auth::v1beta1::Bech32PrefixRequest {
+    const GRPC_PATH: &'static str = "/cosmos.auth.v1beta1.Query/Bech32Prefix";
+    type Response = cosmos::auth::v1beta1::Bech32PrefixResponse;
+}
+
+#[cfg(feature = "auth")]
+impl NibiruGrpcQuery for cosmos::auth::v1beta1::QueryAccountAddressByIdRequest {
+    const GRPC_PATH: &'static str =
+        "/cosmos.auth.v1beta1.Query/AccountAddressByID";
+    type Response = cosmos::auth::v1beta1::QueryAccountAddressByIdResponse;
+}
+
+#[cfg(feature = "auth")]
+impl NibiruGrpcQuery for cosmos::auth::v1beta1::QueryAccountInfoRequest {
+    const GRPC_PATH: &'static str = "/cosmos.auth.v1beta1.Query/AccountInfo";
+    type Response = cosmos::auth::v1beta1::QueryAccountInfoResponse;
+}
+
+#[cfg(feature = "auth")]
+impl NibiruGrpcQuery for cosmos::auth::v1beta1::QueryAccountRequest {
+    const GRPC_PATH: &'static str = "/cosmos.auth.v1beta1.Query/Account";
+    type Response = cosmos::auth::v1beta1::QueryAccountResponse;
+}
+
+#[cfg(feature = "auth")]
+impl NibiruGrpcQuery for cosmos::auth::v1beta1::QueryAccountsRequest {
+    const GRPC_PATH: &'static str = "/cosmos.auth.v1beta1.Query/Accounts";
+    type Response = cosmos::auth::v1beta1::QueryAccountsResponse;
+}
+
+#[cfg(feature = "auth")]
+impl NibiruGrpcQuery for cosmos::auth::v1beta1::QueryModuleAccountByNameRequest {
+    const GRPC_PATH: &'static str =
+        "/cosmos.auth.v1beta1.Query/ModuleAccountByName";
+    type Response = cosmos::auth::v1beta1::QueryModuleAccountByNameResponse;
+}
+
+#[cfg(feature = "auth")]
+impl NibiruGrpcQuery for cosmos::auth::v1beta1::QueryModuleAccountsRequest {
+    const GRPC_PATH: &'static str = "/cosmos.auth.v1beta1.Query/ModuleAccounts";
+    type Response = cosmos::auth::v1beta1::QueryModuleAccountsResponse;
+}
+
+#[cfg(feature = "auth")]
+impl NibiruGrpcQuery for cosmos::auth::v1beta1::QueryParamsRequest {
+    const GRPC_PATH: &'static str = "/cosmos.auth.v1beta1.Query/Params";
+    type Response = cosmos::auth::v1beta1::QueryParamsResponse;
+}
+
+#[cfg(feature = "authz")]
+impl NibiruGrpcQuery for cosmos::authz::v1beta1::QueryGranteeGrantsRequest {
+    const GRPC_PATH: &'static str = "/cosmos.authz.v1beta1.Query/GranteeGrants";
+    type Response = cosmos::authz::v1beta1::QueryGranteeGrantsResponse;
+}
+
+#[cfg(feature = "authz")]
+impl NibiruGrpcQuery for cosmos::authz::v1beta1::QueryGranterGrantsRequest {
+    const GRPC_PATH: &'static str = "/cosmos.authz.v1beta1.Query/GranterGrants";
+    type Response = cosmos::authz::v1beta1::QueryGranterGrantsResponse;
+}
+
+#[cfg(feature = "authz")]
+impl NibiruGrpcQuery for cosmos::authz::v1beta1::QueryGrantsRequest {
+    const GRPC_PATH: &'static str = "/cosmos.authz.v1beta1.Query/Grants";
+    type Response = cosmos::authz::v1beta1::QueryGrantsResponse;
+}
+
+#[cfg(feature = "bank")]
+impl NibiruGrpcQuery for cosmos::bank::v1beta1::QueryAllBalancesRequest {
+    const GRPC_PATH: &'static str = "/cosmos.bank.v1beta1.Query/AllBalances";
+    type Response = cosmos::bank::v1beta1::QueryAllBalancesResponse;
+}
+
+#[cfg(feature = "bank")]
+impl NibiruGrpcQuery for cosmos::bank::v1beta1::QueryBalanceRequest {
+    const GRPC_PATH: &'static str = "/cosmos.bank.v1beta1.Query/Balance";
+    type Response = cosmos::bank::v1beta1::QueryBalanceResponse;
+}
+
+#[cfg(feature = "bank")]
+impl NibiruGrpcQuery for cosmos::bank::v1beta1::QueryDenomMetadataRequest {
+    const GRPC_PATH: &'static str = "/cosmos.bank.v1beta1.Query/DenomMetadata";
+    type Response = cosmos::bank::v1beta1::QueryDenomMetadataResponse;
+}
+
+#[cfg(feature = "bank")]
+impl NibiruGrpcQuery for cosmos::bank::v1beta1::QueryDenomOwnersRequest {
+    const GRPC_PATH: &'static str = "/cosmos.bank.v1beta1.Query/DenomOwners";
+    type Response = cosmos::bank::v1beta1::QueryDenomOwnersResponse;
+}
+
+#[cfg(feature = "bank")]
+impl NibiruGrpcQuery for cosmos::bank::v1beta1::QueryDenomsMetadataRequest {
+    const GRPC_PATH: &'static str = "/cosmos.bank.v1beta1.Query/DenomsMetadata";
+    type Response = cosmos::bank::v1beta1::QueryDenomsMetadataResponse;
+}
+
+#[cfg(feature = "bank")]
+impl NibiruGrpcQuery for cosmos::bank::v1beta1::QueryParamsRequest {
+    const GRPC_PATH: &'static str = "/cosmos.bank.v1beta1.Query/Params";
+    type Response = cosmos::bank::v1beta1::QueryParamsResponse;
+}
+
+#[cfg(feature = "bank")]
+impl NibiruGrpcQuery for cosmos::bank::v1beta1::QuerySendEnabledRequest {
+    const GRPC_PATH: &'static str = "/cosmos.bank.v1beta1.Query/SendEnabled";
+    type Response = cosmos::bank::v1beta1::QuerySendEnabledResponse;
+}
+
+#[cfg(feature = "bank")]
+impl NibiruGrpcQuery
+    for cosmos::bank::v1beta1::QuerySpendableBalanceByDenomRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.bank.v1beta1.Query/SpendableBalanceByDenom";
+    type Response = cosmos::bank::v1beta1::QuerySpendableBalanceByDenomResponse;
+}
+
+#[cfg(feature = "bank")]
+impl NibiruGrpcQuery for cosmos::bank::v1beta1::QuerySpendableBalancesRequest {
+    const GRPC_PATH: &'static str =
+        "/cosmos.bank.v1beta1.Query/SpendableBalances";
+    type Response = cosmos::bank::v1beta1::QuerySpendableBalancesResponse;
+}
+
+#[cfg(feature = "bank")]
+impl NibiruGrpcQuery for cosmos::bank::v1beta1::QuerySupplyOfRequest {
+    const GRPC_PATH: &'static str = "/cosmos.bank.v1beta1.Query/SupplyOf";
+    type Response = cosmos::bank::v1beta1::QuerySupplyOfResponse;
+}
+
+#[cfg(feature = "bank")]
+impl NibiruGrpcQuery for cosmos::bank::v1beta1::QueryTotalSupplyRequest {
+    const GRPC_PATH: &'static str = "/cosmos.bank.v1beta1.Query/TotalSupply";
+    type Response = cosmos::bank::v1beta1::QueryTotalSupplyResponse;
+}
+
+#[cfg(feature = "reflection")]
+impl NibiruGrpcQuery
+    for cosmos::base::reflection::v1beta1::ListAllInterfacesRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.base.reflection.v1beta1.ReflectionService/ListAllInterfaces";
+    type Response = cosmos::base::reflection::v1beta1::ListAllInterfacesResponse;
+}
+
+#[cfg(feature = "reflection")]
+impl NibiruGrpcQuery
+    for cosmos::base::reflection::v1beta1::ListImplementationsRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.base.reflection.v1beta1.ReflectionService/ListImplementations";
+    type Response =
+        cosmos::base::reflection::v1beta1::ListImplementationsResponse;
+}
+
+#[cfg(feature = "reflection")]
+impl NibiruGrpcQuery
+    for cosmos::base::reflection::v2alpha1::GetAuthnDescriptorRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.base.reflection.v2alpha1.ReflectionService/GetAuthnDescriptor";
+    type Response =
+        cosmos::base::reflection::v2alpha1::GetAuthnDescriptorResponse;
+}
+
+#[cfg(feature = "reflection")]
+impl NibiruGrpcQuery
+    for cosmos::base::reflection::v2alpha1::GetChainDescriptorRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.base.reflection.v2alpha1.ReflectionService/GetChainDescriptor";
+    type Response =
+        cosmos::base::reflection::v2alpha1::GetChainDescriptorResponse;
+}
+
+#[cfg(feature = "reflection")]
+impl NibiruGrpcQuery
+    for cosmos::base::reflection::v2alpha1::GetCodecDescriptorRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.base.reflection.v2alpha1.ReflectionService/GetCodecDescriptor";
+    type Response =
+        cosmos::base::reflection::v2alpha1::GetCodecDescriptorResponse;
+}
+
+#[cfg(feature = "reflection")]
+impl NibiruGrpcQuery
+    for cosmos::base::reflection::v2alpha1::GetConfigurationDescriptorRequest
+{
+    const GRPC_PATH: &'static str = "/cosmos.base.reflection.v2alpha1.ReflectionService/GetConfigurationDescriptor";
+    type Response =
+        cosmos::base::reflection::v2alpha1::GetConfigurationDescriptorResponse;
+}
+
+#[cfg(feature = "reflection")]
+impl NibiruGrpcQuery
+    for cosmos::base::reflection::v2alpha1::GetQueryServicesDescriptorRequest
+{
+    const GRPC_PATH: &'static str = "/cosmos.base.reflection.v2alpha1.ReflectionService/GetQueryServicesDescriptor";
+    type Response =
+        cosmos::base::reflection::v2alpha1::GetQueryServicesDescriptorResponse;
+}
+
+#[cfg(feature = "reflection")]
+impl NibiruGrpcQuery
+    for cosmos::base::reflection::v2alpha1::GetTxDescriptorRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.base.reflection.v2alpha1.ReflectionService/GetTxDescriptor";
+    type Response = cosmos::base::reflection::v2alpha1::GetTxDescriptorResponse;
+}
+
+#[cfg(feature = "tendermint")]
+impl NibiruGrpcQuery for cosmos::base::tendermint::v1beta1::AbciQueryRequest {
+    const GRPC_PATH: &'static str =
+        "/cosmos.base.tendermint.v1beta1.Service/ABCIQuery";
+    type Response = cosmos::base::tendermint::v1beta1::AbciQueryResponse;
+}
+
+#[cfg(feature = "tendermint")]
+impl NibiruGrpcQuery
+    for cosmos::base::tendermint::v1beta1::GetBlockByHeightRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.base.tendermint.v1beta1.Service/GetBlockByHeight";
+    type Response = cosmos::base::tendermint::v1beta1::GetBlockByHeightResponse;
+}
+
+#[cfg(feature = "tendermint")]
+impl NibiruGrpcQuery
+    for cosmos::base::tendermint::v1beta1::GetLatestBlockRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.base.tendermint.v1beta1.Service/GetLatestBlock";
+    type Response = cosmos::base::tendermint::v1beta1::GetLatestBlockResponse;
+}
+
+#[cfg(feature = "tendermint")]
+impl NibiruGrpcQuery
+    for cosmos::base::tendermint::v1beta1::GetLatestValidatorSetRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.base.tendermint.v1beta1.Service/GetLatestValidatorSet";
+    type Response =
+        cosmos::base::tendermint::v1beta1::GetLatestValidatorSetResponse;
+}
+
+#[cfg(feature = "tendermint")]
+impl NibiruGrpcQuery for cosmos::base::tendermint::v1beta1::GetNodeInfoRequest {
+    const GRPC_PATH: &'static str =
+        "/cosmos.base.tendermint.v1beta1.Service/GetNodeInfo";
+    type Response = cosmos::base::tendermint::v1beta1::GetNodeInfoResponse;
+}
+
+#[cfg(feature = "tendermint")]
+impl NibiruGrpcQuery for cosmos::base::tendermint::v1beta1::GetSyncingRequest {
+    const GRPC_PATH: &'static str =
+        "/cosmos.base.tendermint.v1beta1.Service/GetSyncing";
+    type Response = cosmos::base::tendermint::v1beta1::GetSyncingResponse;
+}
+
+#[cfg(feature = "tendermint")]
+impl NibiruGrpcQuery
+    for cosmos::base::tendermint::v1beta1::GetValidatorSetByHeightRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.base.tendermint.v1beta1.Service/GetValidatorSetByHeight";
+    type Response =
+        cosmos::base::tendermint::v1beta1::GetValidatorSetByHeightResponse;
+}
+
+#[cfg(feature = "feegrant")]
+impl NibiruGrpcQuery for cosmos::feegrant::v1beta1::QueryAllowanceRequest {
+    const GRPC_PATH: &'static str = "/cosmos.feegrant.v1beta1.Query/Allowance";
+    type Response = cosmos::feegrant::v1beta1::QueryAllowanceResponse;
+}
+
+#[cfg(feature = "feegrant")]
+impl NibiruGrpcQuery
+    for cosmos::feegrant::v1beta1::QueryAllowancesByGranterRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.feegrant.v1beta1.Query/AllowancesByGranter";
+    type Response = cosmos::feegrant::v1beta1::QueryAllowancesByGranterResponse;
+}
+
+#[cfg(feature = "feegrant")]
+impl NibiruGrpcQuery for cosmos::feegrant::v1beta1::QueryAllowancesRequest {
+    const GRPC_PATH: &'static str = "/cosmos.feegrant.v1beta1.Query/Allowances";
+    type Response = cosmos::feegrant::v1beta1::QueryAllowancesResponse;
+}
+
+#[cfg(feature = "gov")]
+impl NibiruGrpcQuery for cosmos::gov::v1::QueryDepositRequest {
+    const GRPC_PATH: &'static str = "/cosmos.gov.v1.Query/Deposit";
+    type Response = cosmos::gov::v1::QueryDepositResponse;
+}
+
+#[cfg(feature = "gov")]
+impl NibiruGrpcQuery for cosmos::gov::v1::QueryDepositsRequest {
+    const GRPC_PATH: &'static str = "/cosmos.gov.v1.Query/Deposits";
+    type Response = cosmos::gov::v1::QueryDepositsResponse;
+}
+
+#[cfg(feature = "gov")]
+impl NibiruGrpcQuery for cosmos::gov::v1::QueryParamsRequest {
+    const GRPC_PATH: &'static str = "/cosmos.gov.v1.Query/Params";
+    type Response = cosmos::gov::v1::QueryParamsResponse;
+}
+
+#[cfg(feature = "gov")]
+impl NibiruGrpcQuery for cosmos::gov::v1::QueryProposalRequest {
+    const GRPC_PATH: &'static str = "/cosmos.gov.v1.Query/Proposal";
+    type Response = cosmos::gov::v1::QueryProposalResponse;
+}
+
+#[cfg(feature = "gov")]
+impl NibiruGrpcQuery for cosmos::gov::v1::QueryProposalsRequest {
+    const GRPC_PATH: &'static str = "/cosmos.gov.v1.Query/Proposals";
+    type Response = cosmos::gov::v1::QueryProposalsResponse;
+}
+
+#[cfg(feature = "gov")]
+impl NibiruGrpcQuery for cosmos::gov::v1::QueryTallyResultRequest {
+    const GRPC_PATH: &'static str = "/cosmos.gov.v1.Query/TallyResult";
+    type Response = cosmos::gov::v1::QueryTallyResultResponse;
+}
+
+#[cfg(feature = "gov")]
+impl NibiruGrpcQuery for cosmos::gov::v1::QueryVoteRequest {
+    const GRPC_PATH: &'static str = "/cosmos.gov.v1.Query/Vote";
+    type Response = cosmos::gov::v1::QueryVoteResponse;
+}
+
+#[cfg(feature = "gov")]
+impl NibiruGrpcQuery for cosmos::gov::v1::QueryVotesRequest {
+    const GRPC_PATH: &'static str = "/cosmos.gov.v1.Query/Votes";
+    type Response = cosmos::gov::v1::QueryVotesResponse;
+}
+
+#[cfg(feature = "group")]
+impl NibiruGrpcQuery for cosmos::group::v1::QueryGroupInfoRequest {
+    const GRPC_PATH: &'static str = "/cosmos.group.v1.Query/GroupInfo";
+    type Response = cosmos::group::v1::QueryGroupInfoResponse;
+}
+
+#[cfg(feature = "group")]
+impl NibiruGrpcQuery for cosmos::group::v1::QueryGroupMembersRequest {
+    const GRPC_PATH: &'static str = "/cosmos.group.v1.Query/GroupMembers";
+    type Response = cosmos::group::v1::QueryGroupMembersResponse;
+}
+
+#[cfg(feature = "group")]
+impl NibiruGrpcQuery for cosmos::group::v1::QueryGroupPoliciesByAdminRequest {
+    const GRPC_PATH: &'static str =
+        "/cosmos.group.v1.Query/GroupPoliciesByAdmin";
+    type Response = cosmos::group::v1::QueryGroupPoliciesByAdminResponse;
+}
+
+#[cfg(feature = "group")]
+impl NibiruGrpcQuery for cosmos::group::v1::QueryGroupPoliciesByGroupRequest {
+    const GRPC_PATH: &'static str =
+        "/cosmos.group.v1.Query/GroupPoliciesByGroup";
+    type Response = cosmos::group::v1::QueryGroupPoliciesByGroupResponse;
+}
+
+#[cfg(feature = "group")]
+impl NibiruGrpcQuery for cosmos::group::v1::QueryGroupPolicyInfoRequest {
+    const GRPC_PATH: &'static str = "/cosmos.group.v1.Query/GroupPolicyInfo";
+    type Response = cosmos::group::v1::QueryGroupPolicyInfoResponse;
+}
+
+#[cfg(feature = "group")]
+impl NibiruGrpcQuery for cosmos::group::v1::QueryGroupsByAdminRequest {
+    const GRPC_PATH: &'static str = "/cosmos.group.v1.Query/GroupsByAdmin";
+    type Response = cosmos::group::v1::QueryGroupsByAdminResponse;
+}
+
+#[cfg(feature = "group")]
+impl NibiruGrpcQuery for cosmos::group::v1::QueryGroupsByMemberRequest {
+    const GRPC_PATH: &'static str = "/cosmos.group.v1.Query/GroupsByMember";
+    type Response = cosmos::group::v1::QueryGroupsByMemberResponse;
+}
+
+#[cfg(feature = "group")]
+impl NibiruGrpcQuery for cosmos::group::v1::QueryGroupsRequest {
+    const GRPC_PATH: &'static str = "/cosmos.group.v1.Query/Groups";
+    type Response = cosmos::group::v1::QueryGroupsResponse;
+}
+
+#[cfg(feature = "group")]
+impl NibiruGrpcQuery for cosmos::group::v1::QueryProposalRequest {
+    const GRPC_PATH: &'static str = "/cosmos.group.v1.Query/Proposal";
+    type Response = cosmos::group::v1::QueryProposalResponse;
+}
+
+#[cfg(feature = "group")]
+impl NibiruGrpcQuery for cosmos::group::v1::QueryProposalsByGroupPolicyRequest {
+    const GRPC_PATH: &'static str =
+        "/cosmos.group.v1.Query/ProposalsByGroupPolicy";
+    type Response = cosmos::group::v1::QueryProposalsByGroupPolicyResponse;
+}
+
+#[cfg(feature = "group")]
+impl NibiruGrpcQuery for cosmos::group::v1::QueryTallyResultRequest {
+    const GRPC_PATH: &'static str = "/cosmos.group.v1.Query/TallyResult";
+    type Response = cosmos::group::v1::QueryTallyResultResponse;
+}
+
+#[cfg(feature = "group")]
+impl NibiruGrpcQuery for cosmos::group::v1::QueryVoteByProposalVoterRequest {
+    const GRPC_PATH: &'static str = "/cosmos.group.v1.Query/VoteByProposalVoter";
+    type Response = cosmos::group::v1::QueryVoteByProposalVoterResponse;
+}
+
+#[cfg(feature = "group")]
+impl NibiruGrpcQuery for cosmos::group::v1::QueryVotesByProposalRequest {
+    const GRPC_PATH: &'static str = "/cosmos.group.v1.Query/VotesByProposal";
+    type Response = cosmos::group::v1::QueryVotesByProposalResponse;
+}
+
+#[cfg(feature = "group")]
+impl NibiruGrpcQuery for cosmos::group::v1::QueryVotesByVoterRequest {
+    const GRPC_PATH: &'static str = "/cosmos.group.v1.Query/VotesByVoter";
+    type Response = cosmos::group::v1::QueryVotesByVoterResponse;
+}
+
+#[cfg(feature = "mint")]
+impl NibiruGrpcQuery for cosmos::mint::v1beta1::QueryAnnualProvisionsRequest {
+    const GRPC_PATH: &'static str =
+        "/cosmos.mint.v1beta1.Query/AnnualProvisions";
+    type Response = cosmos::mint::v1beta1::QueryAnnualProvisionsResponse;
+}
+
+#[cfg(feature = "mint")]
+impl NibiruGrpcQuery for cosmos::mint::v1beta1::QueryInflationRequest {
+    const GRPC_PATH: &'static str = "/cosmos.mint.v1beta1.Query/Inflation";
+    type Response = cosmos::mint::v1beta1::QueryInflationResponse;
+}
+
+#[cfg(feature = "mint")]
+impl NibiruGrpcQuery for cosmos::mint::v1beta1::QueryParamsRequest {
+    const GRPC_PATH: &'static str = "/cosmos.mint.v1beta1.Query/Params";
+    type Response = cosmos::mint::v1beta1::QueryParamsResponse;
+}
+
+#[cfg(feature = "nft")]
+impl NibiruGrpcQuery for cosmos::nft::v1beta1::QueryBalanceRequest {
+    const GRPC_PATH: &'static str = "/cosmos.nft.v1beta1.Query/Balance";
+    type Response = cosmos::nft::v1beta1::QueryBalanceResponse;
+}
+
+#[cfg(feature = "nft")]
+impl NibiruGrpcQuery for cosmos::nft::v1beta1::QueryClassRequest {
+    const GRPC_PATH: &'static str = "/cosmos.nft.v1beta1.Query/Class";
+    type Response = cosmos::nft::v1beta1::QueryClassResponse;
+}
+
+#[cfg(feature = "nft")]
+impl NibiruGrpcQuery for cosmos::nft::v1beta1::QueryClassesRequest {
+    const GRPC_PATH: &'static str = "/cosmos.nft.v1beta1.Query/Classes";
+    type Response = cosmos::nft::v1beta1::QueryClassesResponse;
+}
+
+#[cfg(feature = "nft")]
+impl NibiruGrpcQuery for cosmos::nft::v1beta1::QueryNftRequest {
+    const GRPC_PATH: &'static str = "/cosmos.nft.v1beta1.Query/NFT";
+    type Response = cosmos::nft::v1beta1::QueryNftResponse;
+}
+
+#[cfg(feature = "nft")]
+impl NibiruGrpcQuery for cosmos::nft::v1beta1::QueryNfTsRequest {
+    const GRPC_PATH: &'static str = "/cosmos.nft.v1beta1.Query/NFTs";
+    type Response = cosmos::nft::v1beta1::QueryNfTsResponse;
+}
+
+#[cfg(feature = "nft")]
+impl NibiruGrpcQuery for cosmos::nft::v1beta1::QueryOwnerRequest {
+    const GRPC_PATH: &'static str = "/cosmos.nft.v1beta1.Query/Owner";
+    type Response = cosmos::nft::v1beta1::QueryOwnerResponse;
+}
+
+#[cfg(feature = "nft")]
+impl NibiruGrpcQuery for cosmos::nft::v1beta1::QuerySupplyRequest {
+    const GRPC_PATH: &'static str = "/cosmos.nft.v1beta1.Query/Supply";
+    type Response = cosmos::nft::v1beta1::QuerySupplyResponse;
+}
+
+#[cfg(feature = "params")]
+impl NibiruGrpcQuery for cosmos::params::v1beta1::QueryParamsRequest {
+    const GRPC_PATH: &'static str = "/cosmos.params.v1beta1.Query/Params";
+    type Response = cosmos::params::v1beta1::QueryParamsResponse;
+}
+
+#[cfg(feature = "params")]
+impl NibiruGrpcQuery for cosmos::params::v1beta1::QuerySubspacesRequest {
+    const GRPC_PATH: &'static str = "/cosmos.params.v1beta1.Query/Subspaces";
+    type Response = cosmos::params::v1beta1::QuerySubspacesResponse;
+}
+
+#[cfg(feature = "reflection")]
+impl NibiruGrpcQuery for cosmos::reflection::v1::FileDescriptorsRequest {
+    const GRPC_PATH: &'static str =
+        "/cosmos.reflection.v1.ReflectionService/FileDescriptors";
+    type Response = cosmos::reflection::v1::FileDescriptorsResponse;
+}
+
+#[cfg(feature = "slashing")]
+impl NibiruGrpcQuery for cosmos::slashing::v1beta1::QueryParamsRequest {
+    const GRPC_PATH: &'static str = "/cosmos.slashing.v1beta1.Query/Params";
+    type Response = cosmos::slashing::v1beta1::QueryParamsResponse;
+}
+
+#[cfg(feature = "slashing")]
+impl NibiruGrpcQuery for cosmos::slashing::v1beta1::QuerySigningInfoRequest {
+    const GRPC_PATH: &'static str = "/cosmos.slashing.v1beta1.Query/SigningInfo";
+    type Response = cosmos::slashing::v1beta1::QuerySigningInfoResponse;
+}
+
+#[cfg(feature = "slashing")]
+impl NibiruGrpcQuery for cosmos::slashing::v1beta1::QuerySigningInfosRequest {
+    const GRPC_PATH: &'static str =
+        "/cosmos.slashing.v1beta1.Query/SigningInfos";
+    type Response = cosmos::slashing::v1beta1::QuerySigningInfosResponse;
+}
+
+#[cfg(feature = "staking")]
+impl NibiruGrpcQuery for cosmos::staking::v1beta1::QueryDelegationRequest {
+    const GRPC_PATH: &'static str = "/cosmos.staking.v1beta1.Query/Delegation";
+    type Response = cosmos::staking::v1beta1::QueryDelegationResponse;
+}
+
+#[cfg(feature = "staking")]
+impl NibiruGrpcQuery
+    for cosmos::staking::v1beta1::QueryDelegatorDelegationsRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.staking.v1beta1.Query/DelegatorDelegations";
+    type Response = cosmos::staking::v1beta1::QueryDelegatorDelegationsResponse;
+}
+
+#[cfg(feature = "staking")]
+impl NibiruGrpcQuery
+    for cosmos::staking::v1beta1::QueryDelegatorUnbondingDelegationsRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.staking.v1beta1.Query/DelegatorUnbondingDelegations";
+    type Response =
+        cosmos::staking::v1beta1::QueryDelegatorUnbondingDelegationsResponse;
+}
+
+#[cfg(feature = "staking")]
+impl NibiruGrpcQuery
+    for cosmos::staking::v1beta1::QueryDelegatorValidatorRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.staking.v1beta1.Query/DelegatorValidator";
+    type Response = cosmos::staking::v1beta1::QueryDelegatorValidatorResponse;
+}
+
+#[cfg(feature = "staking")]
+impl NibiruGrpcQuery
+    for cosmos::staking::v1beta1::QueryDelegatorValidatorsRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.staking.v1beta1.Query/DelegatorValidators";
+    type Response = cosmos::staking::v1beta1::QueryDelegatorValidatorsResponse;
+}
+
+#[cfg(feature = "staking")]
+impl NibiruGrpcQuery for cosmos::staking::v1beta1::QueryHistoricalInfoRequest {
+    const GRPC_PATH: &'static str =
+        "/cosmos.staking.v1beta1.Query/HistoricalInfo";
+    type Response = cosmos::staking::v1beta1::QueryHistoricalInfoResponse;
+}
+
+#[cfg(feature = "staking")]
+impl NibiruGrpcQuery for cosmos::staking::v1beta1::QueryParamsRequest {
+    const GRPC_PATH: &'static str = "/cosmos.staking.v1beta1.Query/Params";
+    type Response = cosmos::staking::v1beta1::QueryParamsResponse;
+}
+
+#[cfg(feature = "staking")]
+impl NibiruGrpcQuery for cosmos::staking::v1beta1::QueryPoolRequest {
+    const GRPC_PATH: &'static str = "/cosmos.staking.v1beta1.Query/Pool";
+    type Response = cosmos::staking::v1beta1::QueryPoolResponse;
+}
+
+#[cfg(feature = "staking")]
+impl NibiruGrpcQuery for cosmos::staking::v1beta1::QueryRedelegationsRequest {
+    const GRPC_PATH: &'static str =
+        "/cosmos.staking.v1beta1.Query/Redelegations";
+    type Response = cosmos::staking::v1beta1::QueryRedelegationsResponse;
+}
+
+#[cfg(feature = "staking")]
+impl NibiruGrpcQuery
+    for cosmos::staking::v1beta1::QueryUnbondingDelegationRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.staking.v1beta1.Query/UnbondingDelegation";
+    type Response = cosmos::staking::v1beta1::QueryUnbondingDelegationResponse;
+}
+
+#[cfg(feature = "staking")]
+impl NibiruGrpcQuery
+    for cosmos::staking::v1beta1::QueryValidatorDelegationsRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.staking.v1beta1.Query/ValidatorDelegations";
+    type Response = cosmos::staking::v1beta1::QueryValidatorDelegationsResponse;
+}
+
+#[cfg(feature = "staking")]
+impl NibiruGrpcQuery for cosmos::staking::v1beta1::QueryValidatorRequest {
+    const GRPC_PATH: &'static str = "/cosmos.staking.v1beta1.Query/Validator";
+    type Response = cosmos::staking::v1beta1::QueryValidatorResponse;
+}
+
+#[cfg(feature = "staking")]
+impl NibiruGrpcQuery
+    for cosmos::staking::v1beta1::QueryValidatorUnbondingDelegationsRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.staking.v1beta1.Query/ValidatorUnbondingDelegations";
+    type Response =
+        cosmos::staking::v1beta1::QueryValidatorUnbondingDelegationsResponse;
+}
+
+#[cfg(feature = "staking")]
+impl NibiruGrpcQuery for cosmos::staking::v1beta1::QueryValidatorsRequest {
+    const GRPC_PATH: &'static str = "/cosmos.staking.v1beta1.Query/Validators";
+    type Response = cosmos::staking::v1beta1::QueryValidatorsResponse;
+}
+
+#[cfg(feature = "tx")]
+impl NibiruGrpcQuery for cosmos::tx::v1beta1::BroadcastTxRequest {
+    const GRPC_PATH: &'static str = "/cosmos.tx.v1beta1.Service/BroadcastTx";
+    type Response = cosmos::tx::v1beta1::BroadcastTxResponse;
+}
+
+#[cfg(feature = "tx")]
+impl NibiruGrpcQuery for cosmos::tx::v1beta1::GetBlockWithTxsRequest {
+    const GRPC_PATH: &'static str = "/cosmos.tx.v1beta1.Service/GetBlockWithTxs";
+    type Response = cosmos::tx::v1beta1::GetBlockWithTxsResponse;
+}
+
+#[cfg(feature = "tx")]
+impl NibiruGrpcQuery for cosmos::tx::v1beta1::GetTxRequest {
+    const GRPC_PATH: &'static str = "/cosmos.tx.v1beta1.Service/GetTx";
+    type Response = cosmos::tx::v1beta1::GetTxResponse;
+}
+
+#[cfg(feature = "tx")]
+impl NibiruGrpcQuery for cosmos::tx::v1beta1::GetTxsEventRequest {
+    const GRPC_PATH: &'static str = "/cosmos.tx.v1beta1.Service/GetTxsEvent";
+    type Response = cosmos::tx::v1beta1::GetTxsEventResponse;
+}
+
+#[cfg(feature = "tx")]
+impl NibiruGrpcQuery for cosmos::tx::v1beta1::SimulateRequest {
+    const GRPC_PATH: &'static str = "/cosmos.tx.v1beta1.Service/Simulate";
+    type Response = cosmos::tx::v1beta1::SimulateResponse;
+}
+
+#[cfg(feature = "tx")]
+impl NibiruGrpcQuery for cosmos::tx::v1beta1::TxDecodeAminoRequest {
+    const GRPC_PATH: &'static str = "/cosmos.tx.v1beta1.Service/TxDecodeAmino";
+    type Response = cosmos::tx::v1beta1::TxDecodeAminoResponse;
+}
+
+#[cfg(feature = "tx")]
+impl NibiruGrpcQuery for cosmos::tx::v1beta1::TxDecodeRequest {
+    const GRPC_PATH: &'static str = "/cosmos.tx.v1beta1.Service/TxDecode";
+    type Response = cosmos::tx::v1beta1::TxDecodeResponse;
+}
+
+#[cfg(feature = "tx")]
+impl NibiruGrpcQuery for cosmos::tx::v1beta1::TxEncodeAminoRequest {
+    const GRPC_PATH: &'static str = "/cosmos.tx.v1beta1.Service/TxEncodeAmino";
+    type Response = cosmos::tx::v1beta1::TxEncodeAminoResponse;
+}
+
+#[cfg(feature = "tx")]
+impl NibiruGrpcQuery for cosmos::tx::v1beta1::TxEncodeRequest {
+    const GRPC_PATH: &'static str = "/cosmos.tx.v1beta1.Service/TxEncode";
+    type Response = cosmos::tx::v1beta1::TxEncodeResponse;
+}
+
+#[cfg(feature = "upgrade")]
+impl NibiruGrpcQuery for cosmos::upgrade::v1beta1::QueryAppliedPlanRequest {
+    const GRPC_PATH: &'static str = "/cosmos.upgrade.v1beta1.Query/AppliedPlan";
+    type Response = cosmos::upgrade::v1beta1::QueryAppliedPlanResponse;
+}
+
+#[cfg(feature = "upgrade")]
+impl NibiruGrpcQuery for cosmos::upgrade::v1beta1::QueryAuthorityRequest {
+    const GRPC_PATH: &'static str = "/cosmos.upgrade.v1beta1.Query/Authority";
+    type Response = cosmos::upgrade::v1beta1::QueryAuthorityResponse;
+}
+
+#[cfg(feature = "upgrade")]
+impl NibiruGrpcQuery for cosmos::upgrade::v1beta1::QueryCurrentPlanRequest {
+    const GRPC_PATH: &'static str = "/cosmos.upgrade.v1beta1.Query/CurrentPlan";
+    type Response = cosmos::upgrade::v1beta1::QueryCurrentPlanResponse;
+}
+
+#[cfg(feature = "upgrade")]
+impl NibiruGrpcQuery for cosmos::upgrade::v1beta1::QueryModuleVersionsRequest {
+    const GRPC_PATH: &'static str =
+        "/cosmos.upgrade.v1beta1.Query/ModuleVersions";
+    type Response = cosmos::upgrade::v1beta1::QueryModuleVersionsResponse;
+}
+
+#[cfg(feature = "upgrade")]
+impl NibiruGrpcQuery
+    for cosmos::upgrade::v1beta1::QueryUpgradedConsensusStateRequest
+{
+    const GRPC_PATH: &'static str =
+        "/cosmos.upgrade.v1beta1.Query/UpgradedConsensusState";
+    type Response =
+        cosmos::upgrade::v1beta1::QueryUpgradedConsensusStateResponse;
+}
+
+/// Returns the gRPC query path of the request with the given fully qualified
+/// proto name, or `None` if no single query method serves it.
+#[allow(clippy::match_single_binding)]
+pub(crate) fn grpc_query_path(full_name: &str) -> Option<&'static str> {
+    match full_name {
+        #[cfg(feature = "auth")]
+        "cosmos.auth.v1beta1.AddressBytesToStringRequest" => Some(<cosmos::auth::v1beta1::AddressBytesToStringRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "auth")]
+        "cosmos.auth.v1beta1.AddressStringToBytesRequest" => Some(<cosmos::auth::v1beta1::AddressStringToBytesRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "auth")]
+        "cosmos.auth.v1beta1.Bech32PrefixRequest" => Some(<cosmos::auth::v1beta1::Bech32PrefixRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "auth")]
+        "cosmos.auth.v1beta1.QueryAccountAddressByIDRequest" => Some(<cosmos::auth::v1beta1::QueryAccountAddressByIdRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "auth")]
+        "cosmos.auth.v1beta1.QueryAccountInfoRequest" => Some(<cosmos::auth::v1beta1::QueryAccountInfoRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "auth")]
+        "cosmos.auth.v1beta1.QueryAccountRequest" => Some(<cosmos::auth::v1beta1::QueryAccountRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "auth")]
+        "cosmos.auth.v1beta1.QueryAccountsRequest" => Some(<cosmos::auth::v1beta1::QueryAccountsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "auth")]
+        "cosmos.auth.v1beta1.QueryModuleAccountByNameRequest" => Some(<cosmos::auth::v1beta1::QueryModuleAccountByNameRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "auth")]
+        "cosmos.auth.v1beta1.QueryModuleAccountsRequest" => Some(<cosmos::auth::v1beta1::QueryModuleAccountsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "auth")]
+        "cosmos.auth.v1beta1.QueryParamsRequest" => Some(<cosmos::auth::v1beta1::QueryParamsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "authz")]
+        "cosmos.authz.v1beta1.QueryGranteeGrantsRequest" => Some(<cosmos::authz::v1beta1::QueryGranteeGrantsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "authz")]
+        "cosmos.authz.v1beta1.QueryGranterGrantsRequest" => Some(<cosmos::authz::v1beta1::QueryGranterGrantsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "authz")]
+        "cosmos.authz.v1beta1.QueryGrantsRequest" => Some(<cosmos::authz::v1beta1::QueryGrantsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "bank")]
+        "cosmos.bank.v1beta1.QueryAllBalancesRequest" => Some(<cosmos::bank::v1beta1::QueryAllBalancesRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "bank")]
+        "cosmos.bank.v1beta1.QueryBalanceRequest" => Some(<cosmos::bank::v1beta1::QueryBalanceRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "bank")]
+        "cosmos.bank.v1beta1.QueryDenomMetadataRequest" => Some(<cosmos::bank::v1beta1::QueryDenomMetadataRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "bank")]
+        "cosmos.bank.v1beta1.QueryDenomOwnersRequest" => Some(<cosmos::bank::v1beta1::QueryDenomOwnersRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "bank")]
+        "cosmos.bank.v1beta1.QueryDenomsMetadataRequest" => Some(<cosmos::bank::v1beta1::QueryDenomsMetadataRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "bank")]
+        "cosmos.bank.v1beta1.QueryParamsRequest" => Some(<cosmos::bank::v1beta1::QueryParamsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "bank")]
+        "cosmos.bank.v1beta1.QuerySendEnabledRequest" => Some(<cosmos::bank::v1beta1::QuerySendEnabledRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "bank")]
+        "cosmos.bank.v1beta1.QuerySpendableBalanceByDenomRequest" => Some(<cosmos::bank::v1beta1::QuerySpendableBalanceByDenomRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "bank")]
+        "cosmos.bank.v1beta1.QuerySpendableBalancesRequest" => Some(<cosmos::bank::v1beta1::QuerySpendableBalancesRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "bank")]
+        "cosmos.bank.v1beta1.QuerySupplyOfRequest" => Some(<cosmos::bank::v1beta1::QuerySupplyOfRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "bank")]
+        "cosmos.bank.v1beta1.QueryTotalSupplyRequest" => Some(<cosmos::bank::v1beta1::QueryTotalSupplyRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "reflection")]
+        "cosmos.base.reflection.v1beta1.ListAllInterfacesRequest" => Some(<cosmos::base::reflection::v1beta1::ListAllInterfacesRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "reflection")]
+        "cosmos.base.reflection.v1beta1.ListImplementationsRequest" => Some(<cosmos::base::reflection::v1beta1::ListImplementationsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "reflection")]
+        "cosmos.base.reflection.v2alpha1.GetAuthnDescriptorRequest" => Some(<cosmos::base::reflection::v2alpha1::GetAuthnDescriptorRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "reflection")]
+        "cosmos.base.reflection.v2alpha1.GetChainDescriptorRequest" => Some(<cosmos::base::reflection::v2alpha1::GetChainDescriptorRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "reflection")]
+        "cosmos.base.reflection.v2alpha1.GetCodecDescriptorRequest" => Some(<cosmos::base::reflection::v2alpha1::GetCodecDescriptorRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "reflection")]
+        "cosmos.base.reflection.v2alpha1.GetConfigurationDescriptorRequest" => Some(<cosmos::base::reflection::v2alpha1::GetConfigurationDescriptorRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "reflection")]
+        "cosmos.base.reflection.v2alpha1.GetQueryServicesDescriptorRequest" => Some(<cosmos::base::reflection::v2alpha1::GetQueryServicesDescriptorRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "reflection")]
+        "cosmos.base.reflection.v2alpha1.GetTxDescriptorRequest" => Some(<cosmos::base::reflection::v2alpha1::GetTxDescriptorRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tendermint")]
+        "cosmos.base.tendermint.v1beta1.ABCIQueryRequest" => Some(<cosmos::base::tendermint::v1beta1::AbciQueryRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tendermint")]
+        "cosmos.base.tendermint.v1beta1.GetBlockByHeightRequest" => Some(<cosmos::base::tendermint::v1beta1::GetBlockByHeightRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tendermint")]
+        "cosmos.base.tendermint.v1beta1.GetLatestBlockRequest" => Some(<cosmos::base::tendermint::v1beta1::GetLatestBlockRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tendermint")]
+        "cosmos.base.tendermint.v1beta1.GetLatestValidatorSetRequest" => Some(<cosmos::base::tendermint::v1beta1::GetLatestValidatorSetRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tendermint")]
+        "cosmos.base.tendermint.v1beta1.GetNodeInfoRequest" => Some(<cosmos::base::tendermint::v1beta1::GetNodeInfoRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tendermint")]
+        "cosmos.base.tendermint.v1beta1.GetSyncingRequest" => Some(<cosmos::base::tendermint::v1beta1::GetSyncingRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tendermint")]
+        "cosmos.base.tendermint.v1beta1.GetValidatorSetByHeightRequest" => Some(<cosmos::base::tendermint::v1beta1::GetValidatorSetByHeightRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "feegrant")]
+        "cosmos.feegrant.v1beta1.QueryAllowanceRequest" => Some(<cosmos::feegrant::v1beta1::QueryAllowanceRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "feegrant")]
+        "cosmos.feegrant.v1beta1.QueryAllowancesByGranterRequest" => Some(<cosmos::feegrant::v1beta1::QueryAllowancesByGranterRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "feegrant")]
+        "cosmos.feegrant.v1beta1.QueryAllowancesRequest" => Some(<cosmos::feegrant::v1beta1::QueryAllowancesRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "gov")]
+        "cosmos.gov.v1.QueryDepositRequest" => Some(<cosmos::gov::v1::QueryDepositRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "gov")]
+        "cosmos.gov.v1.QueryDepositsRequest" => Some(<cosmos::gov::v1::QueryDepositsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "gov")]
+        "cosmos.gov.v1.QueryParamsRequest" => Some(<cosmos::gov::v1::QueryParamsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "gov")]
+        "cosmos.gov.v1.QueryProposalRequest" => Some(<cosmos::gov::v1::QueryProposalRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "gov")]
+        "cosmos.gov.v1.QueryProposalsRequest" => Some(<cosmos::gov::v1::QueryProposalsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "gov")]
+        "cosmos.gov.v1.QueryTallyResultRequest" => Some(<cosmos::gov::v1::QueryTallyResultRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "gov")]
+        "cosmos.gov.v1.QueryVoteRequest" => Some(<cosmos::gov::v1::QueryVoteRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "gov")]
+        "cosmos.gov.v1.QueryVotesRequest" => Some(<cosmos::gov::v1::QueryVotesRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "group")]
+        "cosmos.group.v1.QueryGroupInfoRequest" => Some(<cosmos::group::v1::QueryGroupInfoRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "group")]
+        "cosmos.group.v1.QueryGroupMembersRequest" => Some(<cosmos::group::v1::QueryGroupMembersRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "group")]
+        "cosmos.group.v1.QueryGroupPoliciesByAdminRequest" => Some(<cosmos::group::v1::QueryGroupPoliciesByAdminRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "group")]
+        "cosmos.group.v1.QueryGroupPoliciesByGroupRequest" => Some(<cosmos::group::v1::QueryGroupPoliciesByGroupRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "group")]
+        "cosmos.group.v1.QueryGroupPolicyInfoRequest" => Some(<cosmos::group::v1::QueryGroupPolicyInfoRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "group")]
+        "cosmos.group.v1.QueryGroupsByAdminRequest" => Some(<cosmos::group::v1::QueryGroupsByAdminRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "group")]
+        "cosmos.group.v1.QueryGroupsByMemberRequest" => Some(<cosmos::group::v1::QueryGroupsByMemberRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "group")]
+        "cosmos.group.v1.QueryGroupsRequest" => Some(<cosmos::group::v1::QueryGroupsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "group")]
+        "cosmos.group.v1.QueryProposalRequest" => Some(<cosmos::group::v1::QueryProposalRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "group")]
+        "cosmos.group.v1.QueryProposalsByGroupPolicyRequest" => Some(<cosmos::group::v1::QueryProposalsByGroupPolicyRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "group")]
+        "cosmos.group.v1.QueryTallyResultRequest" => Some(<cosmos::group::v1::QueryTallyResultRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "group")]
+        "cosmos.group.v1.QueryVoteByProposalVoterRequest" => Some(<cosmos::group::v1::QueryVoteByProposalVoterRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "group")]
+        "cosmos.group.v1.QueryVotesByProposalRequest" => Some(<cosmos::group::v1::QueryVotesByProposalRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "group")]
+        "cosmos.group.v1.QueryVotesByVoterRequest" => Some(<cosmos::group::v1::QueryVotesByVoterRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "mint")]
+        "cosmos.mint.v1beta1.QueryAnnualProvisionsRequest" => Some(<cosmos::mint::v1beta1::QueryAnnualProvisionsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "mint")]
+        "cosmos.mint.v1beta1.QueryInflationRequest" => Some(<cosmos::mint::v1beta1::QueryInflationRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "mint")]
+        "cosmos.mint.v1beta1.QueryParamsRequest" => Some(<cosmos::mint::v1beta1::QueryParamsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "nft")]
+        "cosmos.nft.v1beta1.QueryBalanceRequest" => Some(<cosmos::nft::v1beta1::QueryBalanceRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "nft")]
+        "cosmos.nft.v1beta1.QueryClassRequest" => Some(<cosmos::nft::v1beta1::QueryClassRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "nft")]
+        "cosmos.nft.v1beta1.QueryClassesRequest" => Some(<cosmos::nft::v1beta1::QueryClassesRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "nft")]
+        "cosmos.nft.v1beta1.QueryNFTRequest" => Some(<cosmos::nft::v1beta1::QueryNftRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "nft")]
+        "cosmos.nft.v1beta1.QueryNFTsRequest" => Some(<cosmos::nft::v1beta1::QueryNfTsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "nft")]
+        "cosmos.nft.v1beta1.QueryOwnerRequest" => Some(<cosmos::nft::v1beta1::QueryOwnerRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "nft")]
+        "cosmos.nft.v1beta1.QuerySupplyRequest" => Some(<cosmos::nft::v1beta1::QuerySupplyRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "params")]
+        "cosmos.params.v1beta1.QueryParamsRequest" => Some(<cosmos::params::v1beta1::QueryParamsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "params")]
+        "cosmos.params.v1beta1.QuerySubspacesRequest" => Some(<cosmos::params::v1beta1::QuerySubspacesRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "reflection")]
+        "cosmos.reflection.v1.FileDescriptorsRequest" => Some(<cosmos::reflection::v1::FileDescriptorsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "slashing")]
+        "cosmos.slashing.v1beta1.QueryParamsRequest" => Some(<cosmos::slashing::v1beta1::QueryParamsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "slashing")]
+        "cosmos.slashing.v1beta1.QuerySigningInfoRequest" => Some(<cosmos::slashing::v1beta1::QuerySigningInfoRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "slashing")]
+        "cosmos.slashing.v1beta1.QuerySigningInfosRequest" => Some(<cosmos::slashing::v1beta1::QuerySigningInfosRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "staking")]
+        "cosmos.staking.v1beta1.QueryDelegationRequest" => Some(<cosmos::staking::v1beta1::QueryDelegationRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "staking")]
+        "cosmos.staking.v1beta1.QueryDelegatorDelegationsRequest" => Some(<cosmos::staking::v1beta1::QueryDelegatorDelegationsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "staking")]
+        "cosmos.staking.v1beta1.QueryDelegatorUnbondingDelegationsRequest" => Some(<cosmos::staking::v1beta1::QueryDelegatorUnbondingDelegationsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "staking")]
+        "cosmos.staking.v1beta1.QueryDelegatorValidatorRequest" => Some(<cosmos::staking::v1beta1::QueryDelegatorValidatorRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "staking")]
+        "cosmos.staking.v1beta1.QueryDelegatorValidatorsRequest" => Some(<cosmos::staking::v1beta1::QueryDelegatorValidatorsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "staking")]
+        "cosmos.staking.v1beta1.QueryHistoricalInfoRequest" => Some(<cosmos::staking::v1beta1::QueryHistoricalInfoRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "staking")]
+        "cosmos.staking.v1beta1.QueryParamsRequest" => Some(<cosmos::staking::v1beta1::QueryParamsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "staking")]
+        "cosmos.staking.v1beta1.QueryPoolRequest" => Some(<cosmos::staking::v1beta1::QueryPoolRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "staking")]
+        "cosmos.staking.v1beta1.QueryRedelegationsRequest" => Some(<cosmos::staking::v1beta1::QueryRedelegationsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "staking")]
+        "cosmos.staking.v1beta1.QueryUnbondingDelegationRequest" => Some(<cosmos::staking::v1beta1::QueryUnbondingDelegationRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "staking")]
+        "cosmos.staking.v1beta1.QueryValidatorDelegationsRequest" => Some(<cosmos::staking::v1beta1::QueryValidatorDelegationsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "staking")]
+        "cosmos.staking.v1beta1.QueryValidatorRequest" => Some(<cosmos::staking::v1beta1::QueryValidatorRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "staking")]
+        "cosmos.staking.v1beta1.QueryValidatorUnbondingDelegationsRequest" => Some(<cosmos::staking::v1beta1::QueryValidatorUnbondingDelegationsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "staking")]
+        "cosmos.staking.v1beta1.QueryValidatorsRequest" => Some(<cosmos::staking::v1beta1::QueryValidatorsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tx")]
+        "cosmos.tx.v1beta1.BroadcastTxRequest" => Some(<cosmos::tx::v1beta1::BroadcastTxRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tx")]
+        "cosmos.tx.v1beta1.GetBlockWithTxsRequest" => Some(<cosmos::tx::v1beta1::GetBlockWithTxsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tx")]
+        "cosmos.tx.v1beta1.GetTxRequest" => Some(<cosmos::tx::v1beta1::GetTxRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tx")]
+        "cosmos.tx.v1beta1.GetTxsEventRequest" => Some(<cosmos::tx::v1beta1::GetTxsEventRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tx")]
+        "cosmos.tx.v1beta1.SimulateRequest" => Some(<cosmos::tx::v1beta1::SimulateRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tx")]
+        "cosmos.tx.v1beta1.TxDecodeAminoRequest" => Some(<cosmos::tx::v1beta1::TxDecodeAminoRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tx")]
+        "cosmos.tx.v1beta1.TxDecodeRequest" => Some(<cosmos::tx::v1beta1::TxDecodeRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tx")]
+        "cosmos.tx.v1beta1.TxEncodeAminoRequest" => Some(<cosmos::tx::v1beta1::TxEncodeAminoRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tx")]
+        "cosmos.tx.v1beta1.TxEncodeRequest" => Some(<cosmos::tx::v1beta1::TxEncodeRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "upgrade")]
+        "cosmos.upgrade.v1beta1.QueryAppliedPlanRequest" => Some(<cosmos::upgrade::v1beta1::QueryAppliedPlanRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "upgrade")]
+        "cosmos.upgrade.v1beta1.QueryAuthorityRequest" => Some(<cosmos::upgrade::v1beta1::QueryAuthorityRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "upgrade")]
+        "cosmos.upgrade.v1beta1.QueryCurrentPlanRequest" => Some(<cosmos::upgrade::v1beta1::QueryCurrentPlanRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "upgrade")]
+        "cosmos.upgrade.v1beta1.QueryModuleVersionsRequest" => Some(<cosmos::upgrade::v1beta1::QueryModuleVersionsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "upgrade")]
+        "cosmos.upgrade.v1beta1.QueryUpgradedConsensusStateRequest" => Some(<cosmos::upgrade::v1beta1::QueryUpgradedConsensusStateRequest as NibiruGrpcQuery>::GRPC_PATH),
+        _ => None,
+    }
+}
+
+/// (request, gRPC path, response) for every generated [NibiruGrpcQuery] impl.
+#[cfg(all(test, feature = "all_protos"))]
+#[allow(clippy::vec_init_then_push)]
+pub(crate) fn grpc_queries() -> Vec<(String, &'static str, String)> {
+    #[allow(unused_imports)]
+    use crate::proto::grpc_query_entry;
+    #[allow(unused_mut)]
+    let mut queries = vec![];
+    #[cfg(feature = "auth")]
+    queries.push(grpc_query_entry::<
+        cosmos::auth::v1beta1::AddressBytesToStringRequest,
+    >());
+    #[cfg(feature = "auth")]
+    queries.push(grpc_query_entry::<
+        cosmos::auth::v1beta1::AddressStringToBytesRequest,
+    >());
+    #[cfg(feature = "auth")]
+    queries
+        .push(grpc_query_entry::<cosmos::auth::v1beta1::Bech32PrefixRequest>());
+    #[cfg(feature = "auth")]
+    queries.push(grpc_query_entry::<
+        cosmos::auth::v1beta1::QueryAccountAddressByIdRequest,
+    >());
+    #[cfg(feature = "auth")]
+    queries.push(grpc_query_entry::<
+        cosmos::auth::v1beta1::QueryAccountInfoRequest,
+    >());
+    #[cfg(feature = "auth")]
+    queries
+        .push(grpc_query_entry::<cosmos::auth::v1beta1::QueryAccountRequest>());
+    #[cfg(feature = "auth")]
+    queries.push(grpc_query_entry::<
+        cosmos::auth::v1beta1::QueryAccountsRequest,
+    >());
+    #[cfg(feature = "auth")]
+    queries.push(grpc_query_entry::<
+        cosmos::auth::v1beta1::QueryModuleAccountByNameRequest,
+    >());
+    #[cfg(feature = "auth")]
+    queries.push(grpc_query_entry::<
+        cosmos::auth::v1beta1::QueryModuleAccountsRequest,
+    >());
+    #[cfg(feature = "auth")]
+    queries
+        .push(grpc_query_entry::<cosmos::auth::v1beta1::QueryParamsRequest>());
+    #[cfg(feature = "authz")]
+    queries.push(grpc_query_entry::<
+        cosmos::authz::v1beta1::QueryGranteeGrantsRequest,
+    >());
+    #[cfg(feature = "authz")]
+    queries.push(grpc_query_entry::<
+        cosmos::authz::v1beta1::QueryGranterGrantsRequest,
+    >());
+    #[cfg(feature = "authz")]
+    queries
+        .push(grpc_query_entry::<cosmos::authz::v1beta1::QueryGrantsRequest>());
+    #[cfg(feature = "bank")]
+    queries.push(grpc_query_entry::<
+        cosmos::bank::v1beta1::QueryAllBalancesRequest,
+    >());
+    #[cfg(feature = "bank")]
+    queries
+        .push(grpc_query_entry::<cosmos::bank::v1beta1::QueryBalanceRequest>());
+    #[cfg(feature = "bank")]
+    queries.push(grpc_query_entry::<
+        cosmos::bank::v1beta1::QueryDenomMetadataRequest,
+    >());
+    #[cfg(feature = "bank")]
+    queries.push(grpc_query_entry::<
+        cosmos::bank::v1beta1::QueryDenomOwnersRequest,
+    >());
+    #[cfg(feature = "bank")]
+    queries.push(grpc_query_entry::<
+        cosmos::bank::v1beta1::QueryDenomsMetadataRequest,
+    >());
+    #[cfg(feature = "bank")]
+    queries
+        .push(grpc_query_entry::<cosmos::bank::v1beta1::QueryParamsRequest>());
+    #[cfg(feature = "bank")]
+    queries.push(grpc_query_entry::<
+        cosmos::bank::v1beta1::QuerySendEnabledRequest,
+    >());
+    #[cfg(feature = "bank")]
+    queries.push(grpc_query_entry::<
+        cosmos::bank::v1beta1::QuerySpendableBalanceByDenomRequest,
+    >());
+    #[cfg(feature = "bank")]
+    queries.push(grpc_query_entry::<
+        cosmos::bank::v1beta1::QuerySpendableBalancesRequest,
+    >());
+    #[cfg(feature = "bank")]
+    queries.push(grpc_query_entry::<
+        cosmos::bank::v1beta1::QuerySupplyOfRequest,
+    >());
+    #[cfg(feature = "bank")]
+    queries.push(grpc_query_entry::<
+        cosmos::bank::v1beta1::QueryTotalSupplyRequest,
+    >());
+    #[cfg(feature = "reflection")]
+    queries.push(grpc_query_entry::<
+        cosmos::base::reflection::v1beta1::ListAllInterfacesRequest,
+    >());
+    #[cfg(feature = "reflection")]
+    queries.push(grpc_query_entry::<
+        cosmos::base::reflection::v1beta1::ListImplementationsRequest,
+    >());
+    #[cfg(feature = "reflection")]
+    queries.push(grpc_query_entry::<
+        cosmos::base::reflection::v2alpha1::GetAuthnDescriptorRequest,
+    >());
+    #[cfg(feature = "reflection")]
+    queries.push(grpc_query_entry::<
+        cosmos::base::reflection::v2alpha1::GetChainDescriptorRequest,
+    >());
+    #[cfg(feature = "reflection")]
+    queries.push(grpc_query_entry::<
+        cosmos::base::reflection::v2alpha1::GetCodecDescriptorRequest,
+    >());
+    #[cfg(feature = "reflection")]
+    queries.push(grpc_query_entry::<
+        cosmos::base::reflection::v2alpha1::GetConfigurationDescriptorRequest,
+    >());
+    #[cfg(feature = "reflection")]
+    queries.push(grpc_query_entry::<
+        cosmos::base::reflection::v2alpha1::GetQueryServicesDescriptorRequest,
+    >());
+    #[cfg(feature = "reflection")]
+    queries.push(grpc_query_entry::<
+        cosmos::base::reflection::v2alpha1::GetTxDescriptorRequest,
+    >());
+    #[cfg(feature = "tendermint")]
+    queries.push(grpc_query_entry::<
+        cosmos::base::tendermint::v1beta1::AbciQueryRequest,
+    >());
+    #[cfg(feature = "tendermint")]
+    queries.push(grpc_query_entry::<
+        cosmos::base::tendermint::v1beta1::GetBlockByHeightRequest,
+    >());
+    #[cfg(feature = "tendermint")]
+    queries.push(grpc_query_entry::<
+        cosmos::base::tendermint::v1beta1::GetLatestBlockRequest,
+    >());
+    #[cfg(feature = "tendermint")]
+    queries.push(grpc_query_entry::<
+        cosmos::base::tendermint::v1beta1::GetLatestValidatorSetRequest,
+    >());
+    #[cfg(feature = "tendermint")]
+    queries.push(grpc_query_entry::<
+        cosmos::base::tendermint::v1beta1::GetNodeInfoRequest,
+    >());
+    #[cfg(feature = "tendermint")]
+    queries.push(grpc_query_entry::<
+        cosmos::base::tendermint::v1beta1::GetSyncingRequest,
+    >());
+    #[cfg(feature = "tendermint")]
+    queries.push(grpc_query_entry::<
+        cosmos::base::tendermint::v1beta1::GetValidatorSetByHeightRequest,
+    >());
+    #[cfg(feature = "feegrant")]
+    queries.push(grpc_query_entry::<
+        cosmos::feegrant::v1beta1::QueryAllowanceRequest,
+    >());
+    #[cfg(feature = "feegrant")]
+    queries.push(grpc_query_entry::<
+        cosmos::feegrant::v1beta1::QueryAllowancesByGranterRequest,
+    >());
+    #[cfg(feature = "feegrant")]
+    queries.push(grpc_query_entry::<
+        cosmos::feegrant::v1beta1::QueryAllowancesRequest,
+    >());
+    #[cfg(feature = "gov")]
+    queries.push(grpc_query_entry::<cosmos::gov::v1::QueryDepositRequest>());
+    #[cfg(feature = "gov")]
+    queries.push(grpc_query_entry::<cosmos::gov::v1::QueryDepositsRequest>());
+    #[cfg(feature = "gov")]
+    queries.push(grpc_query_entry::<cosmos::gov::v1::QueryParamsRequest>());
+    #[cfg(feature = "gov")]
+    queries.push(grpc_query_entry::<cosmos::gov::v1::QueryProposalRequest>());
+    #[cfg(feature = "gov")]
+    queries.push(grpc_query_entry::<cosmos::gov::v1::QueryProposalsRequest>());
+    #[cfg(feature = "gov")]
+    queries.push(grpc_query_entry::<cosmos::gov::v1::QueryTallyResultRequest>());
+    #[cfg(feature = "gov")]
+    queries.push(grpc_query_entry::<cosmos::gov::v1::QueryVoteRequest>());
+    #[cfg(feature = "gov")]
+    queries.push(grpc_query_entry::<cosmos::gov::v1::QueryVotesRequest>());
+    #[cfg(feature = "group")]
+    queries.push(grpc_query_entry::<cosmos::group::v1::QueryGroupInfoRequest>());
+    #[cfg(feature = "group")]
+    queries.push(grpc_query_entry::<
+        cosmos::group::v1::QueryGroupMembersRequest,
+    >());
+    #[cfg(feature = "group")]
+    queries.push(grpc_query_entry::<
+        cosmos::group::v1::QueryGroupPoliciesByAdminRequest,
+    >());
+    #[cfg(feature = "group")]
+    queries.push(grpc_query_entry::<
+        cosmos::group::v1::QueryGroupPoliciesByGroupRequest,
+    >());
+    #[cfg(feature = "group")]
+    queries.push(grpc_query_entry::<
+        cosmos::group::v1::QueryGroupPolicyInfoRequest,
+    >());
+    #[cfg(feature = "group")]
+    queries.push(grpc_query_entry::<
+        cosmos::group::v1::QueryGroupsByAdminRequest,
+    >());
+    #[cfg(feature = "group")]
+    queries.push(grpc_query_entry::<
+        cosmos::group::v1::QueryGroupsByMemberRequest,
+    >());
+    #[cfg(feature = "group")]
+    queries.push(grpc_query_entry::<cosmos::group::v1::QueryGroupsRequest>());
+    #[cfg(feature = "group")]
+    queries.push(grpc_query_entry::<cosmos::group::v1::QueryProposalRequest>());
+    #[cfg(feature = "group")]
+    queries.push(grpc_query_entry::<
+        cosmos::group::v1::QueryProposalsByGroupPolicyRequest,
+    >());
+    #[cfg(feature = "group")]
+    queries
+        .push(grpc_query_entry::<cosmos::group::v1::QueryTallyResultRequest>());
+    #[cfg(feature = "group")]
+    queries.push(grpc_query_entry::<
+        cosmos::group::v1::QueryVoteByProposalVoterRequest,
+    >());
+    #[cfg(feature = "group")]
+    queries.push(grpc_query_entry::<
+        cosmos::group::v1::QueryVotesByProposalRequest,
+    >());
+    #[cfg(feature = "group")]
+    queries.push(grpc_query_entry::<
+        cosmos::group::v1::QueryVotesByVoterRequest,
+    >());
+    #[cfg(feature = "mint")]
+    queries.push(grpc_query_entry::<
+        cosmos::mint::v1beta1::QueryAnnualProvisionsRequest,
+    >());
+    #[cfg(feature = "mint")]
+    queries.push(grpc_query_entry::<
+        cosmos::mint::v1beta1::QueryInflationRequest,
+    >());
+    #[cfg(feature = "mint")]
+    queries
+        .push(grpc_query_entry::<cosmos::mint::v1beta1::QueryParamsRequest>());
+    #[cfg(feature = "nft")]
+    queries
+        .push(grpc_query_entry::<cosmos::nft::v1beta1::QueryBalanceRequest>());
+    #[cfg(feature = "nft")]
+    queries.push(grpc_query_entry::<cosmos::nft::v1beta1::QueryClassRequest>());
+    #[cfg(feature = "nft")]
+    queries
+        .push(grpc_query_entry::<cosmos::nft::v1beta1::QueryClassesRequest>());
+    #[cfg(feature = "nft")]
+    queries.push(grpc_query_entry::<cosmos::nft::v1beta1::QueryNftRequest>());
+    #[cfg(feature = "nft")]
+    queries.push(grpc_query_entry::<cosmos::nft::v1beta1::QueryNfTsRequest>());
+    #[cfg(feature = "nft")]
+    queries.push(grpc_query_entry::<cosmos::nft::v1beta1::QueryOwnerRequest>());
+    #[cfg(feature = "nft")]
+    queries.push(grpc_query_entry::<cosmos::nft::v1beta1::QuerySupplyRequest>());
+    #[cfg(feature = "params")]
+    queries.push(grpc_query_entry::<
+        cosmos::params::v1beta1::QueryParamsRequest,
+    >());
+    #[cfg(feature = "params")]
+    queries.push(grpc_query_entry::<
+        cosmos::params::v1beta1::QuerySubspacesRequest,
+    >());
+    #[cfg(feature = "reflection")]
+    queries.push(grpc_query_entry::<
+        cosmos::reflection::v1::FileDescriptorsRequest,
+    >());
+    #[cfg(feature = "slashing")]
+    queries.push(grpc_query_entry::<
+        cosmos::slashing::v1beta1::QueryParamsRequest,
+    >());
+    #[cfg(feature = "slashing")]
+    queries.push(grpc_query_entry::<
+        cosmos::slashing::v1beta1::QuerySigningInfoRequest,
+    >());
+    #[cfg(feature = "slashing")]
+    queries.push(grpc_query_entry::<
+        cosmos::slashing::v1beta1::QuerySigningInfosRequest,
+    >());
+    #[cfg(feature = "staking")]
+    queries.push(grpc_query_entry::<
+        cosmos::staking::v1beta1::QueryDelegationRequest,
+    >());
+    #[cfg(feature = "staking")]
+    queries.push(grpc_query_entry::<
+        cosmos::staking::v1beta1::QueryDelegatorDelegationsRequest,
+    >());
+    #[cfg(feature = "staking")]
+    queries.push(grpc_query_entry::<
+        cosmos::staking::v1beta1::QueryDelegatorUnbondingDelegationsRequest,
+    >());
+    #[cfg(feature = "staking")]
+    queries.push(grpc_query_entry::<
+        cosmos::staking::v1beta1::QueryDelegatorValidatorRequest,
+    >());
+    #[cfg(feature = "staking")]
+    queries.push(grpc_query_entry::<
+        cosmos::staking::v1beta1::QueryDelegatorValidatorsRequest,
+    >());
+    #[cfg(feature = "staking")]
+    queries.push(grpc_query_entry::<
+        cosmos::staking::v1beta1::QueryHistoricalInfoRequest,
+    >());
+    #[cfg(feature = "staking")]
+    queries.push(grpc_query_entry::<
+        cosmos::staking::v1beta1::QueryParamsRequest,
+    >());
+    #[cfg(feature = "staking")]
+    queries
+        .push(grpc_query_entry::<cosmos::staking::v1beta1::QueryPoolRequest>());
+    #[cfg(feature = "staking")]
+    queries.push(grpc_query_entry::<
+        cosmos::staking::v1beta1::QueryRedelegationsRequest,
+    >());
+    #[cfg(feature = "staking")]
+    queries.push(grpc_query_entry::<
+        cosmos::staking::v1beta1::QueryUnbondingDelegationRequest,
+    >());
+    #[cfg(feature = "staking")]
+    queries.push(grpc_query_entry::<
+        cosmos::staking::v1beta1::QueryValidatorDelegationsRequest,
+    >());
+    #[cfg(feature = "staking")]
+    queries.push(grpc_query_entry::<
+        cosmos::staking::v1beta1::QueryValidatorRequest,
+    >());
+    #[cfg(feature = "staking")]
+    queries.push(grpc_query_entry::<
+        cosmos::staking::v1beta1::QueryValidatorUnbondingDelegationsRequest,
+    >());
+    #[cfg(feature = "staking")]
+    queries.push(grpc_query_entry::<
+        cosmos::staking::v1beta1::QueryValidatorsRequest,
+    >());
+    #[cfg(feature = "tx")]
+    queries.push(grpc_query_entry::<cosmos::tx::v1beta1::BroadcastTxRequest>());
+    #[cfg(feature = "tx")]
+    queries.push(grpc_query_entry::<
+        cosmos::tx::v1beta1::GetBlockWithTxsRequest,
+    >());
+    #[cfg(feature = "tx")]
+    queries.push(grpc_query_entry::<cosmos::tx::v1beta1::GetTxRequest>());
+    #[cfg(feature = "tx")]
+    queries.push(grpc_query_entry::<cosmos::tx::v1beta1::GetTxsEventRequest>());
+    #[cfg(feature = "tx")]
+    queries.push(grpc_query_entry::<cosmos::tx::v1beta1::SimulateRequest>());
+    #[cfg(feature = "tx")]
+    queries
+        .push(grpc_query_entry::<cosmos::tx::v1beta1::TxDecodeAminoRequest>());
+    #[cfg(feature = "tx")]
+    queries.push(grpc_query_entry::<cosmos::tx::v1beta1::TxDecodeRequest>());
+    #[cfg(feature = "tx")]
+    queries
+        .push(grpc_query_entry::<cosmos::tx::v1beta1::TxEncodeAminoRequest>());
+    #[cfg(feature = "tx")]
+    queries.push(grpc_query_entry::<cosmos::tx::v1beta1::TxEncodeRequest>());
+    #[cfg(feature = "upgrade")]
+    queries.push(grpc_query_entry::<
+        cosmos::upgrade::v1beta1::QueryAppliedPlanRequest,
+    >());
+    #[cfg(feature = "upgrade")]
+    queries.push(grpc_query_entry::<
+        cosmos::upgrade::v1beta1::QueryAuthorityRequest,
+    >());
+    #[cfg(feature = "upgrade")]
+    queries.push(grpc_query_entry::<
+        cosmos::upgrade::v1beta1::QueryCurrentPlanRequest,
+    >());
+    #[cfg(feature = "upgrade")]
+    queries.push(grpc_query_entry::<
+        cosmos::upgrade::v1beta1::QueryModuleVersionsRequest,
+    >());
+    #[cfg(feature = "upgrade")]
+    queries.push(grpc_query_entry::<
+        cosmos::upgrade::v1beta1::QueryUpgradedConsensusStateRequest,
+    >());
+    queries
+}
+
+/// Returns the JSON codec of the message with the given type URL, which
+/// (de)serializes `Any` fields under the "serde" feature.
+#[cfg(feature = "serde")]
+#[allow(clippy::match_single_binding)]
+pub(crate) fn any_json(type_url: &str) -> Option<crate::proto::json::AnyJson> {
+    #[allow(unused_imports)]
+    use crate::proto::json::AnyJson;
+    match type_url {
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.AddressBytesToStringRequest" => Some(AnyJson::of::<cosmos::auth::v1beta1::AddressBytesToStringRequest>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.AddressBytesToStringResponse" => Some(AnyJson::of::<cosmos::auth::v1beta1::AddressBytesToStringResponse>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.AddressStringToBytesRequest" => Some(AnyJson::of::<cosmos::auth::v1beta1::AddressStringToBytesRequest>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.AddressStringToBytesResponse" => Some(AnyJson::of::<cosmos::auth::v1beta1::AddressStringToBytesResponse>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.BaseAccount" => Some(AnyJson::of::<cosmos::auth::v1beta1::BaseAccount>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.Bech32PrefixRequest" => Some(AnyJson::of::<cosmos::auth::v1beta1::Bech32PrefixRequest>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.Bech32PrefixResponse" => Some(AnyJson::of::<cosmos::auth::v1beta1::Bech32PrefixResponse>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.GenesisState" => Some(AnyJson::of::<cosmos::auth::v1beta1::GenesisState>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.ModuleAccount" => Some(AnyJson::of::<cosmos::auth::v1beta1::ModuleAccount>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.ModuleCredential" => Some(AnyJson::of::<cosmos::auth::v1beta1::ModuleCredential>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.MsgUpdateParams" => Some(AnyJson::of::<cosmos::auth::v1beta1::MsgUpdateParams>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.MsgUpdateParamsResponse" => Some(AnyJson::of::<cosmos::auth::v1beta1::MsgUpdateParamsResponse>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.Params" => Some(AnyJson::of::<cosmos::auth::v1beta1::Params>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.QueryAccountAddressByIDRequest" => Some(AnyJson::of::<cosmos::auth::v1beta1::QueryAccountAddressByIdRequest>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.QueryAccountAddressByIDResponse" => Some(AnyJson::of::<cosmos::auth::v1beta1::QueryAccountAddressByIdResponse>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.QueryAccountInfoRequest" => Some(AnyJson::of::<cosmos::auth::v1beta1::QueryAccountInfoRequest>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.QueryAccountInfoResponse" => Some(AnyJson::of::<cosmos::auth::v1beta1::QueryAccountInfoResponse>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.QueryAccountRequest" => Some(AnyJson::of::<cosmos::auth::v1beta1::QueryAccountRequest>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.QueryAccountResponse" => Some(AnyJson::of::<cosmos::auth::v1beta1::QueryAccountResponse>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.QueryAccountsRequest" => Some(AnyJson::of::<cosmos::auth::v1beta1::QueryAccountsRequest>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.QueryAccountsResponse" => Some(AnyJson::of::<cosmos::auth::v1beta1::QueryAccountsResponse>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.QueryModuleAccountByNameRequest" => Some(AnyJson::of::<cosmos::auth::v1beta1::QueryModuleAccountByNameRequest>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.QueryModuleAccountByNameResponse" => Some(AnyJson::of::<cosmos::auth::v1beta1::QueryModuleAccountByNameResponse>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.QueryModuleAccountsRequest" => Some(AnyJson::of::<cosmos::auth::v1beta1::QueryModuleAccountsRequest>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.QueryModuleAccountsResponse" => Some(AnyJson::of::<cosmos::auth::v1beta1::QueryModuleAccountsResponse>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.QueryParamsRequest" => Some(AnyJson::of::<cosmos::auth::v1beta1::QueryParamsRequest>()),
+        #[cfg(feature = "auth")]
+        "/cosmos.auth.v1beta1.QueryParamsResponse" => Some(AnyJson::of::<cosmos::auth::v1beta1::QueryParamsResponse>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.EventGrant" => Some(AnyJson::of::<cosmos::authz::v1beta1::EventGrant>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.EventRevoke" => Some(AnyJson::of::<cosmos::authz::v1beta1::EventRevoke>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.GenericAuthorization" => Some(AnyJson::of::<cosmos::authz::v1beta1::GenericAuthorization>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.GenesisState" => Some(AnyJson::of::<cosmos::authz::v1beta1::GenesisState>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.Grant" => Some(AnyJson::of::<cosmos::authz::v1beta1::Grant>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.GrantAuthorization" => Some(AnyJson::of::<cosmos::authz::v1beta1::GrantAuthorization>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.GrantQueueItem" => Some(AnyJson::of::<cosmos::authz::v1beta1::GrantQueueItem>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.MsgExec" => Some(AnyJson::of::<cosmos::authz::v1beta1::MsgExec>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.MsgExecResponse" => Some(AnyJson::of::<cosmos::authz::v1beta1::MsgExecResponse>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.MsgGrant" => Some(AnyJson::of::<cosmos::authz::v1beta1::MsgGrant>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.MsgGrantResponse" => Some(AnyJson::of::<cosmos::authz::v1beta1::MsgGrantResponse>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.MsgRevoke" => Some(AnyJson::of::<cosmos::authz::v1beta1::MsgRevoke>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.MsgRevokeResponse" => Some(AnyJson::of::<cosmos::authz::v1beta1::MsgRevokeResponse>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.QueryGranteeGrantsRequest" => Some(AnyJson::of::<cosmos::authz::v1beta1::QueryGranteeGrantsRequest>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.QueryGranteeGrantsResponse" => Some(AnyJson::of::<cosmos::authz::v1beta1::QueryGranteeGrantsResponse>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.QueryGranterGrantsRequest" => Some(AnyJson::of::<cosmos::authz::v1beta1::QueryGranterGrantsRequest>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.QueryGranterGrantsResponse" => Some(AnyJson::of::<cosmos::authz::v1beta1::QueryGranterGrantsResponse>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.QueryGrantsRequest" => Some(AnyJson::of::<cosmos::authz::v1beta1::QueryGrantsRequest>()),
+        #[cfg(feature = "authz")]
+        "/cosmos.authz.v1beta1.QueryGrantsResponse" => Some(AnyJson::of::<cosmos::authz::v1beta1::QueryGrantsResponse>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.Balance" => Some(AnyJson::of::<cosmos::bank::v1beta1::Balance>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.DenomOwner" => Some(AnyJson::of::<cosmos::bank::v1beta1::DenomOwner>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.DenomUnit" => Some(AnyJson::of::<cosmos::bank::v1beta1::DenomUnit>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.GenesisState" => Some(AnyJson::of::<cosmos::bank::v1beta1::GenesisState>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.Input" => Some(AnyJson::of::<cosmos::bank::v1beta1::Input>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.Metadata" => Some(AnyJson::of::<cosmos::bank::v1beta1::Metadata>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.MsgMultiSend" => Some(AnyJson::of::<cosmos::bank::v1beta1::MsgMultiSend>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.MsgMultiSendResponse" => Some(AnyJson::of::<cosmos::bank::v1beta1::MsgMultiSendResponse>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.MsgSend" => Some(AnyJson::of::<cosmos::bank::v1beta1::MsgSend>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.MsgSendResponse" => Some(AnyJson::of::<cosmos::bank::v1beta1::MsgSendResponse>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.MsgSetSendEnabled" => Some(AnyJson::of::<cosmos::bank::v1beta1::MsgSetSendEnabled>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.MsgSetSendEnabledResponse" => Some(AnyJson::of::<cosmos::bank::v1beta1::MsgSetSendEnabledResponse>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.MsgUpdateParams" => Some(AnyJson::of::<cosmos::bank::v1beta1::MsgUpdateParams>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.MsgUpdateParamsResponse" => Some(AnyJson::of::<cosmos::bank::v1beta1::MsgUpdateParamsResponse>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.Output" => Some(AnyJson::of::<cosmos::bank::v1beta1::Output>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.Params" => Some(AnyJson::of::<cosmos::bank::v1beta1::Params>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QueryAllBalancesRequest" => Some(AnyJson::of::<cosmos::bank::v1beta1::QueryAllBalancesRequest>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QueryAllBalancesResponse" => Some(AnyJson::of::<cosmos::bank::v1beta1::QueryAllBalancesResponse>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QueryBalanceRequest" => Some(AnyJson::of::<cosmos::bank::v1beta1::QueryBalanceRequest>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QueryBalanceResponse" => Some(AnyJson::of::<cosmos::bank::v1beta1::QueryBalanceResponse>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QueryDenomMetadataRequest" => Some(AnyJson::of::<cosmos::bank::v1beta1::QueryDenomMetadataRequest>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QueryDenomMetadataResponse" => Some(AnyJson::of::<cosmos::bank::v1beta1::QueryDenomMetadataResponse>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QueryDenomOwnersRequest" => Some(AnyJson::of::<cosmos::bank::v1beta1::QueryDenomOwnersRequest>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QueryDenomOwnersResponse" => Some(AnyJson::of::<cosmos::bank::v1beta1::QueryDenomOwnersResponse>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QueryDenomsMetadataRequest" => Some(AnyJson::of::<cosmos::bank::v1beta1::QueryDenomsMetadataRequest>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QueryDenomsMetadataResponse" => Some(AnyJson::of::<cosmos::bank::v1beta1::QueryDenomsMetadataResponse>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QueryParamsRequest" => Some(AnyJson::of::<cosmos::bank::v1beta1::QueryParamsRequest>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QueryParamsResponse" => Some(AnyJson::of::<cosmos::bank::v1beta1::QueryParamsResponse>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QuerySendEnabledRequest" => Some(AnyJson::of::<cosmos::bank::v1beta1::QuerySendEnabledRequest>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QuerySendEnabledResponse" => Some(AnyJson::of::<cosmos::bank::v1beta1::QuerySendEnabledResponse>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QuerySpendableBalanceByDenomRequest" => Some(AnyJson::of::<cosmos::bank::v1beta1::QuerySpendableBalanceByDenomRequest>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QuerySpendableBalanceByDenomResponse" => Some(AnyJson::of::<cosmos::bank::v1beta1::QuerySpendableBalanceByDenomResponse>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QuerySpendableBalancesRequest" => Some(AnyJson::of::<cosmos::bank::v1beta1::QuerySpendableBalancesRequest>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QuerySpendableBalancesResponse" => Some(AnyJson::of::<cosmos::bank::v1beta1::QuerySpendableBalancesResponse>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QuerySupplyOfRequest" => Some(AnyJson::of::<cosmos::bank::v1beta1::QuerySupplyOfRequest>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QuerySupplyOfResponse" => Some(AnyJson::of::<cosmos::bank::v1beta1::QuerySupplyOfResponse>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QueryTotalSupplyRequest" => Some(AnyJson::of::<cosmos::bank::v1beta1::QueryTotalSupplyRequest>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.QueryTotalSupplyResponse" => Some(AnyJson::of::<cosmos::bank::v1beta1::QueryTotalSupplyResponse>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.SendAuthorization" => Some(AnyJson::of::<cosmos::bank::v1beta1::SendAuthorization>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.SendEnabled" => Some(AnyJson::of::<cosmos::bank::v1beta1::SendEnabled>()),
+        #[cfg(feature = "bank")]
+        "/cosmos.bank.v1beta1.Supply" => Some(AnyJson::of::<cosmos::bank::v1beta1::Supply>()),
+        #[cfg(feature = "abci")]
+        "/cosmos.base.abci.v1beta1.ABCIMessageLog" => Some(AnyJson::of::<cosmos::base::abci::v1beta1::AbciMessageLog>()),
+        #[cfg(feature = "abci")]
+        "/cosmos.base.abci.v1beta1.Attribute" => Some(AnyJson::of::<cosmos::base::abci::v1beta1::Attribute>()),
+        #[cfg(feature = "abci")]
+        "/cosmos.base.abci.v1beta1.GasInfo" => Some(AnyJson::of::<cosmos::base::abci::v1beta1::GasInfo>()),
+        #[cfg(feature = "abci")]
+        "/cosmos.base.abci.v1beta1.MsgData" => Some(AnyJson::of::<cosmos::base::abci::v1beta1::MsgData>()),
+        #[cfg(feature = "abci")]
+        "/cosmos.base.abci.v1beta1.Result" => Some(AnyJson::of::<cosmos::base::abci::v1beta1::Result>()),
+        #[cfg(feature = "abci")]
+        "/cosmos.base.abci.v1beta1.SearchTxsResult" => Some(AnyJson::of::<cosmos::base::abci::v1beta1::SearchTxsResult>()),
+        #[cfg(feature = "abci")]
+        "/cosmos.base.abci.v1beta1.SimulationResponse" => Some(AnyJson::of::<cosmos::base::abci::v1beta1::SimulationResponse>()),
+        #[cfg(feature = "abci")]
+        "/cosmos.base.abci.v1beta1.StringEvent" => Some(AnyJson::of::<cosmos::base::abci::v1beta1::StringEvent>()),
+        #[cfg(feature = "abci")]
+        "/cosmos.base.abci.v1beta1.TxMsgData" => Some(AnyJson::of::<cosmos::base::abci::v1beta1::TxMsgData>()),
+        #[cfg(feature = "abci")]
+        "/cosmos.base.abci.v1beta1.TxResponse" => Some(AnyJson::of::<cosmos::base::abci::v1beta1::TxResponse>()),
+        "/cosmos.base.kv.v1beta1.Pair" => Some(AnyJson::of::<cosmos::base::kv::v1beta1::Pair>()),
+        "/cosmos.base.kv.v1beta1.Pairs" => Some(AnyJson::of::<cosmos::base::kv::v1beta1::Pairs>()),
+        "/cosmos.base.query.v1beta1.PageRequest" => Some(AnyJson::of::<cosmos::base::query::v1beta1::PageRequest>()),
+        "/cosmos.base.query.v1beta1.PageResponse" => Some(AnyJson::of::<cosmos::base::query::v1beta1::PageResponse>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v1beta1.ListAllInterfacesRequest" => Some(AnyJson::of::<cosmos::base::reflection::v1beta1::ListAllInterfacesRequest>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v1beta1.ListAllInterfacesResponse" => Some(AnyJson::of::<cosmos::base::reflection::v1beta1::ListAllInterfacesResponse>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v1beta1.ListImplementationsRequest" => Some(AnyJson::of::<cosmos::base::reflection::v1beta1::ListImplementationsRequest>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v1beta1.ListImplementationsResponse" => Some(AnyJson::of::<cosmos::base::reflection::v1beta1::ListImplementationsResponse>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.AppDescriptor" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::AppDescriptor>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.AuthnDescriptor" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::AuthnDescriptor>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.ChainDescriptor" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::ChainDescriptor>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.CodecDescriptor" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::CodecDescriptor>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.ConfigurationDescriptor" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::ConfigurationDescriptor>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.GetAuthnDescriptorRequest" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::GetAuthnDescriptorRequest>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.GetAuthnDescriptorResponse" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::GetAuthnDescriptorResponse>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.GetChainDescriptorRequest" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::GetChainDescriptorRequest>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.GetChainDescriptorResponse" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::GetChainDescriptorResponse>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.GetCodecDescriptorRequest" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::GetCodecDescriptorRequest>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.GetCodecDescriptorResponse" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::GetCodecDescriptorResponse>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.GetConfigurationDescriptorRequest" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::GetConfigurationDescriptorRequest>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.GetConfigurationDescriptorResponse" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::GetConfigurationDescriptorResponse>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.GetQueryServicesDescriptorRequest" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::GetQueryServicesDescriptorRequest>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.GetQueryServicesDescriptorResponse" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::GetQueryServicesDescriptorResponse>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.GetTxDescriptorRequest" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::GetTxDescriptorRequest>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.GetTxDescriptorResponse" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::GetTxDescriptorResponse>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.InterfaceAcceptingMessageDescriptor" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::InterfaceAcceptingMessageDescriptor>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.InterfaceDescriptor" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::InterfaceDescriptor>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.InterfaceImplementerDescriptor" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::InterfaceImplementerDescriptor>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.MsgDescriptor" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::MsgDescriptor>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.QueryMethodDescriptor" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::QueryMethodDescriptor>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.QueryServiceDescriptor" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::QueryServiceDescriptor>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.QueryServicesDescriptor" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::QueryServicesDescriptor>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.SigningModeDescriptor" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::SigningModeDescriptor>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.base.reflection.v2alpha1.TxDescriptor" => Some(AnyJson::of::<cosmos::base::reflection::v2alpha1::TxDescriptor>()),
+        "/cosmos.base.snapshots.v1beta1.Metadata" => Some(AnyJson::of::<cosmos::base::snapshots::v1beta1::Metadata>()),
+        "/cosmos.base.snapshots.v1beta1.Snapshot" => Some(AnyJson::of::<cosmos::base::snapshots::v1beta1::Snapshot>()),
+        "/cosmos.base.snapshots.v1beta1.SnapshotExtensionMeta" => Some(AnyJson::of::<cosmos::base::snapshots::v1beta1::SnapshotExtensionMeta>()),
+        "/cosmos.base.snapshots.v1beta1.SnapshotExtensionPayload" => Some(AnyJson::of::<cosmos::base::snapshots::v1beta1::SnapshotExtensionPayload>()),
+        "/cosmos.base.snapshots.v1beta1.SnapshotIAVLItem" => Some(AnyJson::of::<cosmos::base::snapshots::v1beta1::SnapshotIavlItem>()),
+        "/cosmos.base.snapshots.v1beta1.SnapshotItem" => Some(AnyJson::of::<cosmos::base::snapshots::v1beta1::SnapshotItem>()),
+        "/cosmos.base.snapshots.v1beta1.SnapshotKVItem" => Some(AnyJson::of::<cosmos::base::snapshots::v1beta1::SnapshotKvItem>()),
+        "/cosmos.base.snapshots.v1beta1.SnapshotSchema" => Some(AnyJson::of::<cosmos::base::snapshots::v1beta1::SnapshotSchema>()),
+        "/cosmos.base.snapshots.v1beta1.SnapshotStoreItem" => Some(AnyJson::of::<cosmos::base::snapshots::v1beta1::SnapshotStoreItem>()),
+        #[cfg(feature = "abci")]
+        "/cosmos.base.store.v1beta1.BlockMetadata" => Some(AnyJson::of::<cosmos::base::store::v1beta1::BlockMetadata>()),
+        #[cfg(feature = "abci")]
+        "/cosmos.base.store.v1beta1.CommitID" => Some(AnyJson::of::<cosmos::base::store::v1beta1::CommitId>()),
+        #[cfg(feature = "abci")]
+        "/cosmos.base.store.v1beta1.CommitInfo" => Some(AnyJson::of::<cosmos::base::store::v1beta1::CommitInfo>()),
+        #[cfg(feature = "abci")]
+        "/cosmos.base.store.v1beta1.StoreInfo" => Some(AnyJson::of::<cosmos::base::store::v1beta1::StoreInfo>()),
+        #[cfg(feature = "abci")]
+        "/cosmos.base.store.v1beta1.StoreKVPair" => Some(AnyJson::of::<cosmos::base::store::v1beta1::StoreKvPair>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.ABCIQueryRequest" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::AbciQueryRequest>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.ABCIQueryResponse" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::AbciQueryResponse>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.Block" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::Block>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.GetBlockByHeightRequest" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::GetBlockByHeightRequest>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.GetBlockByHeightResponse" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::GetBlockByHeightResponse>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.GetLatestBlockRequest" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::GetLatestBlockRequest>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.GetLatestBlockResponse" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::GetLatestBlockResponse>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.GetLatestValidatorSetRequest" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::GetLatestValidatorSetRequest>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.GetLatestValidatorSetResponse" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::GetLatestValidatorSetResponse>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.GetNodeInfoRequest" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::GetNodeInfoRequest>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.GetNodeInfoResponse" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::GetNodeInfoResponse>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.GetSyncingRequest" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::GetSyncingRequest>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.GetSyncingResponse" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::GetSyncingResponse>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.GetValidatorSetByHeightRequest" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::GetValidatorSetByHeightRequest>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.GetValidatorSetByHeightResponse" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::GetValidatorSetByHeightResponse>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.Header" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::Header>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.Module" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::Module>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.ProofOp" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::ProofOp>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.ProofOps" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::ProofOps>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.Validator" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::Validator>()),
+        #[cfg(feature = "tendermint")]
+        "/cosmos.base.tendermint.v1beta1.VersionInfo" => Some(AnyJson::of::<cosmos::base::tendermint::v1beta1::VersionInfo>()),
+        "/cosmos.base.v1beta1.Coin" => Some(AnyJson::of::<cosmos::base::v1beta1::Coin>()),
+        "/cosmos.base.v1beta1.DecCoin" => Some(AnyJson::of::<cosmos::base::v1beta1::DecCoin>()),
+        "/cosmos.base.v1beta1.DecProto" => Some(AnyJson::of::<cosmos::base::v1beta1::DecProto>()),
+        "/cosmos.base.v1beta1.IntProto" => Some(AnyJson::of::<cosmos::base::v1beta1::IntProto>()),
+        #[cfg(feature = "crisis")]
+        "/cosmos.crisis.v1beta1.GenesisState" => Some(AnyJson::of::<cosmos::crisis::v1beta1::GenesisState>()),
+        #[cfg(feature = "crisis")]
+        "/cosmos.crisis.v1beta1.MsgUpdateParams" => Some(AnyJson::of::<cosmos::crisis::v1beta1::MsgUpdateParams>()),
+        #[cfg(feature = "crisis")]
+        "/cosmos.crisis.v1beta1.MsgUpdateParamsResponse" => Some(AnyJson::of::<cosmos::crisis::v1beta1::MsgUpdateParamsResponse>()),
+        #[cfg(feature = "crisis")]
+        "/cosmos.crisis.v1beta1.MsgVerifyInvariant" => Some(AnyJson::of::<cosmos::crisis::v1beta1::MsgVerifyInvariant>()),
+        #[cfg(feature = "crisis")]
+        "/cosmos.crisis.v1beta1.MsgVerifyInvariantResponse" => Some(AnyJson::of::<cosmos::crisis::v1beta1::MsgVerifyInvariantResponse>()),
+        #[cfg(feature = "crypto")]
+        "/cosmos.crypto.ed25519.PrivKey" => Some(AnyJson::of::<cosmos::crypto::ed25519::PrivKey>()),
+        #[cfg(feature = "crypto")]
+        "/cosmos.crypto.ed25519.PubKey" => Some(AnyJson::of::<cosmos::crypto::ed25519::PubKey>()),
+        #[cfg(feature = "crypto")]
+        "/cosmos.crypto.hd.v1.BIP44Params" => Some(AnyJson::of::<cosmos::crypto::hd::v1::Bip44Params>()),
+        #[cfg(feature = "crypto")]
+        "/cosmos.crypto.keyring.v1.Record" => Some(AnyJson::of::<cosmos::crypto::keyring::v1::Record>()),
+        #[cfg(feature = "crypto")]
+        "/cosmos.crypto.multisig.v1beta1.CompactBitArray" => Some(AnyJson::of::<cosmos::crypto::multisig::v1beta1::CompactBitArray>()),
+        #[cfg(feature = "crypto")]
+        "/cosmos.crypto.multisig.v1beta1.MultiSignature" => Some(AnyJson::of::<cosmos::crypto::multisig::v1beta1::MultiSignature>()),
+        #[cfg(feature = "crypto")]
+        "/cosmos.crypto.secp256k1.PrivKey" => Some(AnyJson::of::<cosmos::crypto::secp256k1::PrivKey>()),
+        #[cfg(feature = "crypto")]
+        "/cosmos.crypto.secp256k1.PubKey" => Some(AnyJson::of::<cosmos::crypto::secp256k1::PubKey>()),
+        #[cfg(feature = "crypto")]
+        "/cosmos.crypto.secp256r1.PrivKey" => Some(AnyJson::of::<cosmos::crypto::secp256r1::PrivKey>()),
+        #[cfg(feature = "crypto")]
+        "/cosmos.crypto.secp256r1.PubKey" => Some(AnyJson::of::<cosmos::crypto::secp256r1::PubKey>()),
+        #[cfg(feature = "feegrant")]
+        "/cosmos.feegrant.v1beta1.AllowedMsgAllowance" => Some(AnyJson::of::<cosmos::feegrant::v1beta1::AllowedMsgAllowance>()),
+        #[cfg(feature = "feegrant")]
+        "/cosmos.feegrant.v1beta1.BasicAllowance" => Some(AnyJson::of::<cosmos::feegrant::v1beta1::BasicAllowance>()),
+        #[cfg(feature = "feegrant")]
+        "/cosmos.feegrant.v1beta1.GenesisState" => Some(AnyJson::of::<cosmos::feegrant::v1beta1::GenesisState>()),
+        #[cfg(feature = "feegrant")]
+        "/cosmos.feegrant.v1beta1.Grant" => Some(AnyJson::of::<cosmos::feegrant::v1beta1::Grant>()),
+        #[cfg(feature = "feegrant")]
+        "/cosmos.feegrant.v1beta1.MsgGrantAllowance" => Some(AnyJson::of::<cosmos::feegrant::v1beta1::MsgGrantAllowance>()),
+        #[cfg(feature = "feegrant")]
+        "/cosmos.feegrant.v1beta1.MsgGrantAllowanceResponse" => Some(AnyJson::of::<cosmos::feegrant::v1beta1::MsgGrantAllowanceResponse>()),
+        #[cfg(feature = "feegrant")]
+        "/cosmos.feegrant.v1beta1.MsgRevokeAllowance" => Some(AnyJson::of::<cosmos::feegrant::v1beta1::MsgRevokeAllowance>()),
+        #[cfg(feature = "feegrant")]
+        "/cosmos.feegrant.v1beta1.MsgRevokeAllowanceResponse" => Some(AnyJson::of::<cosmos::feegrant::v1beta1::MsgRevokeAllowanceResponse>()),
+        #[cfg(feature = "feegrant")]
+        "/cosmos.feegrant.v1beta1.PeriodicAllowance" => Some(AnyJson::of::<cosmos::feegrant::v1beta1::PeriodicAllowance>()),
+        #[cfg(feature = "feegrant")]
+        "/cosmos.feegrant.v1beta1.QueryAllowanceRequest" => Some(AnyJson::of::<cosmos::feegrant::v1beta1::QueryAllowanceRequest>()),
+        #[cfg(feature = "feegrant")]
+        "/cosmos.feegrant.v1beta1.QueryAllowanceResponse" => Some(AnyJson::of::<cosmos::feegrant::v1beta1::QueryAllowanceResponse>()),
+        #[cfg(feature = "feegrant")]
+        "/cosmos.feegrant.v1beta1.QueryAllowancesByGranterRequest" => Some(AnyJson::of::<cosmos::feegrant::v1beta1::QueryAllowancesByGranterRequest>()),
+        #[cfg(feature = "feegrant")]
+        "/cosmos.feegrant.v1beta1.QueryAllowancesByGranterResponse" => Some(AnyJson::of::<cosmos::feegrant::v1beta1::QueryAllowancesByGranterResponse>()),
+        #[cfg(feature = "feegrant")]
+        "/cosmos.feegrant.v1beta1.QueryAllowancesRequest" => Some(AnyJson::of::<cosmos::feegrant::v1beta1::QueryAllowancesRequest>()),
+        #[cfg(feature = "feegrant")]
+        "/cosmos.feegrant.v1beta1.QueryAllowancesResponse" => Some(AnyJson::of::<cosmos::feegrant::v1beta1::QueryAllowancesResponse>()),
+        #[cfg(feature = "genutil")]
+        "/cosmos.genutil.v1beta1.GenesisState" => Some(AnyJson::of::<cosmos::genutil::v1beta1::GenesisState>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.Deposit" => Some(AnyJson::of::<cosmos::gov::v1::Deposit>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.DepositParams" => Some(AnyJson::of::<cosmos::gov::v1::DepositParams>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.GenesisState" => Some(AnyJson::of::<cosmos::gov::v1::GenesisState>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.MsgDeposit" => Some(AnyJson::of::<cosmos::gov::v1::MsgDeposit>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.MsgDepositResponse" => Some(AnyJson::of::<cosmos::gov::v1::MsgDepositResponse>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.MsgExecLegacyContent" => Some(AnyJson::of::<cosmos::gov::v1::MsgExecLegacyContent>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.MsgExecLegacyContentResponse" => Some(AnyJson::of::<cosmos::gov::v1::MsgExecLegacyContentResponse>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.MsgSubmitProposal" => Some(AnyJson::of::<cosmos::gov::v1::MsgSubmitProposal>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.MsgSubmitProposalResponse" => Some(AnyJson::of::<cosmos::gov::v1::MsgSubmitProposalResponse>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.MsgUpdateParams" => Some(AnyJson::of::<cosmos::gov::v1::MsgUpdateParams>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.MsgUpdateParamsResponse" => Some(AnyJson::of::<cosmos::gov::v1::MsgUpdateParamsResponse>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.MsgVote" => Some(AnyJson::of::<cosmos::gov::v1::MsgVote>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.MsgVoteResponse" => Some(AnyJson::of::<cosmos::gov::v1::MsgVoteResponse>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.MsgVoteWeighted" => Some(AnyJson::of::<cosmos::gov::v1::MsgVoteWeighted>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.MsgVoteWeightedResponse" => Some(AnyJson::of::<cosmos::gov::v1::MsgVoteWeightedResponse>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.Params" => Some(AnyJson::of::<cosmos::gov::v1::Params>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.Proposal" => Some(AnyJson::of::<cosmos::gov::v1::Proposal>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.QueryDepositRequest" => Some(AnyJson::of::<cosmos::gov::v1::QueryDepositRequest>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.QueryDepositResponse" => Some(AnyJson::of::<cosmos::gov::v1::QueryDepositResponse>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.QueryDepositsRequest" => Some(AnyJson::of::<cosmos::gov::v1::QueryDepositsRequest>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.QueryDepositsResponse" => Some(AnyJson::of::<cosmos::gov::v1::QueryDepositsResponse>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.QueryParamsRequest" => Some(AnyJson::of::<cosmos::gov::v1::QueryParamsRequest>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.QueryParamsResponse" => Some(AnyJson::of::<cosmos::gov::v1::QueryParamsResponse>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.QueryProposalRequest" => Some(AnyJson::of::<cosmos::gov::v1::QueryProposalRequest>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.QueryProposalResponse" => Some(AnyJson::of::<cosmos::gov::v1::QueryProposalResponse>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.QueryProposalsRequest" => Some(AnyJson::of::<cosmos::gov::v1::QueryProposalsRequest>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.QueryProposalsResponse" => Some(AnyJson::of::<cosmos::gov::v1::QueryProposalsResponse>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.QueryTallyResultRequest" => Some(AnyJson::of::<cosmos::gov::v1::QueryTallyResultRequest>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.QueryTallyResultResponse" => Some(AnyJson::of::<cosmos::gov::v1::QueryTallyResultResponse>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.QueryVoteRequest" => Some(AnyJson::of::<cosmos::gov::v1::QueryVoteRequest>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.QueryVoteResponse" => Some(AnyJson::of::<cosmos::gov::v1::QueryVoteResponse>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.QueryVotesRequest" => Some(AnyJson::of::<cosmos::gov::v1::QueryVotesRequest>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.QueryVotesResponse" => Some(AnyJson::of::<cosmos::gov::v1::QueryVotesResponse>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.TallyParams" => Some(AnyJson::of::<cosmos::gov::v1::TallyParams>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.TallyResult" => Some(AnyJson::of::<cosmos::gov::v1::TallyResult>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.Vote" => Some(AnyJson::of::<cosmos::gov::v1::Vote>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.VotingParams" => Some(AnyJson::of::<cosmos::gov::v1::VotingParams>()),
+        #[cfg(feature = "gov")]
+        "/cosmos.gov.v1.WeightedVoteOption" => Some(AnyJson::of::<cosmos::gov::v1::WeightedVoteOption>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.DecisionPolicyWindows" => Some(AnyJson::of::<cosmos::group::v1::DecisionPolicyWindows>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.EventCreateGroup" => Some(AnyJson::of::<cosmos::group::v1::EventCreateGroup>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.EventCreateGroupPolicy" => Some(AnyJson::of::<cosmos::group::v1::EventCreateGroupPolicy>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.EventExec" => Some(AnyJson::of::<cosmos::group::v1::EventExec>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.EventLeaveGroup" => Some(AnyJson::of::<cosmos::group::v1::EventLeaveGroup>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.EventProposalPruned" => Some(AnyJson::of::<cosmos::group::v1::EventProposalPruned>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.EventSubmitProposal" => Some(AnyJson::of::<cosmos::group::v1::EventSubmitProposal>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.EventUpdateGroup" => Some(AnyJson::of::<cosmos::group::v1::EventUpdateGroup>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.EventUpdateGroupPolicy" => Some(AnyJson::of::<cosmos::group::v1::EventUpdateGroupPolicy>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.EventVote" => Some(AnyJson::of::<cosmos::group::v1::EventVote>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.EventWithdrawProposal" => Some(AnyJson::of::<cosmos::group::v1::EventWithdrawProposal>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.GenesisState" => Some(AnyJson::of::<cosmos::group::v1::GenesisState>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.GroupInfo" => Some(AnyJson::of::<cosmos::group::v1::GroupInfo>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.GroupMember" => Some(AnyJson::of::<cosmos::group::v1::GroupMember>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.GroupPolicyInfo" => Some(AnyJson::of::<cosmos::group::v1::GroupPolicyInfo>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.Member" => Some(AnyJson::of::<cosmos::group::v1::Member>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MemberRequest" => Some(AnyJson::of::<cosmos::group::v1::MemberRequest>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgCreateGroup" => Some(AnyJson::of::<cosmos::group::v1::MsgCreateGroup>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgCreateGroupPolicy" => Some(AnyJson::of::<cosmos::group::v1::MsgCreateGroupPolicy>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgCreateGroupPolicyResponse" => Some(AnyJson::of::<cosmos::group::v1::MsgCreateGroupPolicyResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgCreateGroupResponse" => Some(AnyJson::of::<cosmos::group::v1::MsgCreateGroupResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgCreateGroupWithPolicy" => Some(AnyJson::of::<cosmos::group::v1::MsgCreateGroupWithPolicy>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgCreateGroupWithPolicyResponse" => Some(AnyJson::of::<cosmos::group::v1::MsgCreateGroupWithPolicyResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgExec" => Some(AnyJson::of::<cosmos::group::v1::MsgExec>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgExecResponse" => Some(AnyJson::of::<cosmos::group::v1::MsgExecResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgLeaveGroup" => Some(AnyJson::of::<cosmos::group::v1::MsgLeaveGroup>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgLeaveGroupResponse" => Some(AnyJson::of::<cosmos::group::v1::MsgLeaveGroupResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgSubmitProposal" => Some(AnyJson::of::<cosmos::group::v1::MsgSubmitProposal>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgSubmitProposalResponse" => Some(AnyJson::of::<cosmos::group::v1::MsgSubmitProposalResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgUpdateGroupAdmin" => Some(AnyJson::of::<cosmos::group::v1::MsgUpdateGroupAdmin>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgUpdateGroupAdminResponse" => Some(AnyJson::of::<cosmos::group::v1::MsgUpdateGroupAdminResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgUpdateGroupMembers" => Some(AnyJson::of::<cosmos::group::v1::MsgUpdateGroupMembers>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgUpdateGroupMembersResponse" => Some(AnyJson::of::<cosmos::group::v1::MsgUpdateGroupMembersResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgUpdateGroupMetadata" => Some(AnyJson::of::<cosmos::group::v1::MsgUpdateGroupMetadata>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgUpdateGroupMetadataResponse" => Some(AnyJson::of::<cosmos::group::v1::MsgUpdateGroupMetadataResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgUpdateGroupPolicyAdmin" => Some(AnyJson::of::<cosmos::group::v1::MsgUpdateGroupPolicyAdmin>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgUpdateGroupPolicyAdminResponse" => Some(AnyJson::of::<cosmos::group::v1::MsgUpdateGroupPolicyAdminResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgUpdateGroupPolicyDecisionPolicy" => Some(AnyJson::of::<cosmos::group::v1::MsgUpdateGroupPolicyDecisionPolicy>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgUpdateGroupPolicyDecisionPolicyResponse" => Some(AnyJson::of::<cosmos::group::v1::MsgUpdateGroupPolicyDecisionPolicyResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgUpdateGroupPolicyMetadata" => Some(AnyJson::of::<cosmos::group::v1::MsgUpdateGroupPolicyMetadata>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgUpdateGroupPolicyMetadataResponse" => Some(AnyJson::of::<cosmos::group::v1::MsgUpdateGroupPolicyMetadataResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgVote" => Some(AnyJson::of::<cosmos::group::v1::MsgVote>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgVoteResponse" => Some(AnyJson::of::<cosmos::group::v1::MsgVoteResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgWithdrawProposal" => Some(AnyJson::of::<cosmos::group::v1::MsgWithdrawProposal>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.MsgWithdrawProposalResponse" => Some(AnyJson::of::<cosmos::group::v1::MsgWithdrawProposalResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.PercentageDecisionPolicy" => Some(AnyJson::of::<cosmos::group::v1::PercentageDecisionPolicy>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.Proposal" => Some(AnyJson::of::<cosmos::group::v1::Proposal>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryGroupInfoRequest" => Some(AnyJson::of::<cosmos::group::v1::QueryGroupInfoRequest>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryGroupInfoResponse" => Some(AnyJson::of::<cosmos::group::v1::QueryGroupInfoResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryGroupMembersRequest" => Some(AnyJson::of::<cosmos::group::v1::QueryGroupMembersRequest>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryGroupMembersResponse" => Some(AnyJson::of::<cosmos::group::v1::QueryGroupMembersResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryGroupPoliciesByAdminRequest" => Some(AnyJson::of::<cosmos::group::v1::QueryGroupPoliciesByAdminRequest>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryGroupPoliciesByAdminResponse" => Some(AnyJson::of::<cosmos::group::v1::QueryGroupPoliciesByAdminResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryGroupPoliciesByGroupRequest" => Some(AnyJson::of::<cosmos::group::v1::QueryGroupPoliciesByGroupRequest>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryGroupPoliciesByGroupResponse" => Some(AnyJson::of::<cosmos::group::v1::QueryGroupPoliciesByGroupResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryGroupPolicyInfoRequest" => Some(AnyJson::of::<cosmos::group::v1::QueryGroupPolicyInfoRequest>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryGroupPolicyInfoResponse" => Some(AnyJson::of::<cosmos::group::v1::QueryGroupPolicyInfoResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryGroupsByAdminRequest" => Some(AnyJson::of::<cosmos::group::v1::QueryGroupsByAdminRequest>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryGroupsByAdminResponse" => Some(AnyJson::of::<cosmos::group::v1::QueryGroupsByAdminResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryGroupsByMemberRequest" => Some(AnyJson::of::<cosmos::group::v1::QueryGroupsByMemberRequest>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryGroupsByMemberResponse" => Some(AnyJson::of::<cosmos::group::v1::QueryGroupsByMemberResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryGroupsRequest" => Some(AnyJson::of::<cosmos::group::v1::QueryGroupsRequest>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryGroupsResponse" => Some(AnyJson::of::<cosmos::group::v1::QueryGroupsResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryProposalRequest" => Some(AnyJson::of::<cosmos::group::v1::QueryProposalRequest>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryProposalResponse" => Some(AnyJson::of::<cosmos::group::v1::QueryProposalResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryProposalsByGroupPolicyRequest" => Some(AnyJson::of::<cosmos::group::v1::QueryProposalsByGroupPolicyRequest>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryProposalsByGroupPolicyResponse" => Some(AnyJson::of::<cosmos::group::v1::QueryProposalsByGroupPolicyResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryTallyResultRequest" => Some(AnyJson::of::<cosmos::group::v1::QueryTallyResultRequest>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryTallyResultResponse" => Some(AnyJson::of::<cosmos::group::v1::QueryTallyResultResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryVoteByProposalVoterRequest" => Some(AnyJson::of::<cosmos::group::v1::QueryVoteByProposalVoterRequest>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryVoteByProposalVoterResponse" => Some(AnyJson::of::<cosmos::group::v1::QueryVoteByProposalVoterResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryVotesByProposalRequest" => Some(AnyJson::of::<cosmos::group::v1::QueryVotesByProposalRequest>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryVotesByProposalResponse" => Some(AnyJson::of::<cosmos::group::v1::QueryVotesByProposalResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryVotesByVoterRequest" => Some(AnyJson::of::<cosmos::group::v1::QueryVotesByVoterRequest>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.QueryVotesByVoterResponse" => Some(AnyJson::of::<cosmos::group::v1::QueryVotesByVoterResponse>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.TallyResult" => Some(AnyJson::of::<cosmos::group::v1::TallyResult>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.ThresholdDecisionPolicy" => Some(AnyJson::of::<cosmos::group::v1::ThresholdDecisionPolicy>()),
+        #[cfg(feature = "group")]
+        "/cosmos.group.v1.Vote" => Some(AnyJson::of::<cosmos::group::v1::Vote>()),
+        #[cfg(feature = "mint")]
+        "/cosmos.mint.v1beta1.GenesisState" => Some(AnyJson::of::<cosmos::mint::v1beta1::GenesisState>()),
+        #[cfg(feature = "mint")]
+        "/cosmos.mint.v1beta1.Minter" => Some(AnyJson::of::<cosmos::mint::v1beta1::Minter>()),
+        #[cfg(feature = "mint")]
+        "/cosmos.mint.v1beta1.MsgUpdateParams" => Some(AnyJson::of::<cosmos::mint::v1beta1::MsgUpdateParams>()),
+        #[cfg(feature = "mint")]
+        "/cosmos.mint.v1beta1.MsgUpdateParamsResponse" => Some(AnyJson::of::<cosmos::mint::v1beta1::MsgUpdateParamsResponse>()),
+        #[cfg(feature = "mint")]
+        "/cosmos.mint.v1beta1.Params" => Some(AnyJson::of::<cosmos::mint::v1beta1::Params>()),
+        #[cfg(feature = "mint")]
+        "/cosmos.mint.v1beta1.QueryAnnualProvisionsRequest" => Some(AnyJson::of::<cosmos::mint::v1beta1::QueryAnnualProvisionsRequest>()),
+        #[cfg(feature = "mint")]
+        "/cosmos.mint.v1beta1.QueryAnnualProvisionsResponse" => Some(AnyJson::of::<cosmos::mint::v1beta1::QueryAnnualProvisionsResponse>()),
+        #[cfg(feature = "mint")]
+        "/cosmos.mint.v1beta1.QueryInflationRequest" => Some(AnyJson::of::<cosmos::mint::v1beta1::QueryInflationRequest>()),
+        #[cfg(feature = "mint")]
+        "/cosmos.mint.v1beta1.QueryInflationResponse" => Some(AnyJson::of::<cosmos::mint::v1beta1::QueryInflationResponse>()),
+        #[cfg(feature = "mint")]
+        "/cosmos.mint.v1beta1.QueryParamsRequest" => Some(AnyJson::of::<cosmos::mint::v1beta1::QueryParamsRequest>()),
+        #[cfg(feature = "mint")]
+        "/cosmos.mint.v1beta1.QueryParamsResponse" => Some(AnyJson::of::<cosmos::mint::v1beta1::QueryParamsResponse>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.Class" => Some(AnyJson::of::<cosmos::nft::v1beta1::Class>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.Entry" => Some(AnyJson::of::<cosmos::nft::v1beta1::Entry>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.EventBurn" => Some(AnyJson::of::<cosmos::nft::v1beta1::EventBurn>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.EventMint" => Some(AnyJson::of::<cosmos::nft::v1beta1::EventMint>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.EventSend" => Some(AnyJson::of::<cosmos::nft::v1beta1::EventSend>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.GenesisState" => Some(AnyJson::of::<cosmos::nft::v1beta1::GenesisState>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.MsgSend" => Some(AnyJson::of::<cosmos::nft::v1beta1::MsgSend>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.MsgSendResponse" => Some(AnyJson::of::<cosmos::nft::v1beta1::MsgSendResponse>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.NFT" => Some(AnyJson::of::<cosmos::nft::v1beta1::Nft>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.QueryBalanceRequest" => Some(AnyJson::of::<cosmos::nft::v1beta1::QueryBalanceRequest>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.QueryBalanceResponse" => Some(AnyJson::of::<cosmos::nft::v1beta1::QueryBalanceResponse>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.QueryClassRequest" => Some(AnyJson::of::<cosmos::nft::v1beta1::QueryClassRequest>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.QueryClassResponse" => Some(AnyJson::of::<cosmos::nft::v1beta1::QueryClassResponse>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.QueryClassesRequest" => Some(AnyJson::of::<cosmos::nft::v1beta1::QueryClassesRequest>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.QueryClassesResponse" => Some(AnyJson::of::<cosmos::nft::v1beta1::QueryClassesResponse>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.QueryNFTRequest" => Some(AnyJson::of::<cosmos::nft::v1beta1::QueryNftRequest>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.QueryNFTResponse" => Some(AnyJson::of::<cosmos::nft::v1beta1::QueryNftResponse>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.QueryNFTsRequest" => Some(AnyJson::of::<cosmos::nft::v1beta1::QueryNfTsRequest>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.QueryNFTsResponse" => Some(AnyJson::of::<cosmos::nft::v1beta1::QueryNfTsResponse>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.QueryOwnerRequest" => Some(AnyJson::of::<cosmos::nft::v1beta1::QueryOwnerRequest>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.QueryOwnerResponse" => Some(AnyJson::of::<cosmos::nft::v1beta1::QueryOwnerResponse>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.QuerySupplyRequest" => Some(AnyJson::of::<cosmos::nft::v1beta1::QuerySupplyRequest>()),
+        #[cfg(feature = "nft")]
+        "/cosmos.nft.v1beta1.QuerySupplyResponse" => Some(AnyJson::of::<cosmos::nft::v1beta1::QuerySupplyResponse>()),
+        #[cfg(feature = "params")]
+        "/cosmos.params.v1beta1.ParamChange" => Some(AnyJson::of::<cosmos::params::v1beta1::ParamChange>()),
+        #[cfg(feature = "params")]
+        "/cosmos.params.v1beta1.ParameterChangeProposal" => Some(AnyJson::of::<cosmos::params::v1beta1::ParameterChangeProposal>()),
+        #[cfg(feature = "params")]
+        "/cosmos.params.v1beta1.QueryParamsRequest" => Some(AnyJson::of::<cosmos::params::v1beta1::QueryParamsRequest>()),
+        #[cfg(feature = "params")]
+        "/cosmos.params.v1beta1.QueryParamsResponse" => Some(AnyJson::of::<cosmos::params::v1beta1::QueryParamsResponse>()),
+        #[cfg(feature = "params")]
+        "/cosmos.params.v1beta1.QuerySubspacesRequest" => Some(AnyJson::of::<cosmos::params::v1beta1::QuerySubspacesRequest>()),
+        #[cfg(feature = "params")]
+        "/cosmos.params.v1beta1.QuerySubspacesResponse" => Some(AnyJson::of::<cosmos::params::v1beta1::QuerySubspacesResponse>()),
+        #[cfg(feature = "params")]
+        "/cosmos.params.v1beta1.Subspace" => Some(AnyJson::of::<cosmos::params::v1beta1::Subspace>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.reflection.v1.FileDescriptorsRequest" => Some(AnyJson::of::<cosmos::reflection::v1::FileDescriptorsRequest>()),
+        #[cfg(feature = "reflection")]
+        "/cosmos.reflection.v1.FileDescriptorsResponse" => Some(AnyJson::of::<cosmos::reflection::v1::FileDescriptorsResponse>()),
+        #[cfg(feature = "slashing")]
+        "/cosmos.slashing.v1beta1.GenesisState" => Some(AnyJson::of::<cosmos::slashing::v1beta1::GenesisState>()),
+        #[cfg(feature = "slashing")]
+        "/cosmos.slashing.v1beta1.MissedBlock" => Some(AnyJson::of::<cosmos::slashing::v1beta1::MissedBlock>()),
+        #[cfg(feature = "slashing")]
+        "/cosmos.slashing.v1beta1.MsgUnjail" => Some(AnyJson::of::<cosmos::slashing::v1beta1::MsgUnjail>()),
+        #[cfg(feature = "slashing")]
+        "/cosmos.slashing.v1beta1.MsgUnjailResponse" => Some(AnyJson::of::<cosmos::slashing::v1beta1::MsgUnjailResponse>()),
+        #[cfg(feature = "slashing")]
+        "/cosmos.slashing.v1beta1.MsgUpdateParams" => Some(AnyJson::of::<cosmos::slashing::v1beta1::MsgUpdateParams>()),
+        #[cfg(feature = "slashing")]
+        "/cosmos.slashing.v1beta1.MsgUpdateParamsResponse" => Some(AnyJson::of::<cosmos::slashing::v1beta1::MsgUpdateParamsResponse>()),
+        #[cfg(feature = "slashing")]
+        "/cosmos.slashing.v1beta1.Params" => Some(AnyJson::of::<cosmos::slashing::v1beta1::Params>()),
+        #[cfg(feature = "slashing")]
+        "/cosmos.slashing.v1beta1.QueryParamsRequest" => Some(AnyJson::of::<cosmos::slashing::v1beta1::QueryParamsRequest>()),
+        #[cfg(feature = "slashing")]
+        "/cosmos.slashing.v1beta1.QueryParamsResponse" => Some(AnyJson::of::<cosmos::slashing::v1beta1::QueryParamsResponse>()),
+        #[cfg(feature = "slashing")]
+        "/cosmos.slashing.v1beta1.QuerySigningInfoRequest" => Some(AnyJson::of::<cosmos::slashing::v1beta1::QuerySigningInfoRequest>()),
+        #[cfg(feature = "slashing")]
+        "/cosmos.slashing.v1beta1.QuerySigningInfoResponse" => Some(AnyJson::of::<cosmos::slashing::v1beta1::QuerySigningInfoResponse>()),
+        #[cfg(feature = "slashing")]
+        "/cosmos.slashing.v1beta1.QuerySigningInfosRequest" => Some(AnyJson::of::<cosmos::slashing::v1beta1::QuerySigningInfosRequest>()),
+        #[cfg(feature = "slashing")]
+        "/cosmos.slashing.v1beta1.QuerySigningInfosResponse" => Some(AnyJson::of::<cosmos::slashing::v1beta1::QuerySigningInfosResponse>()),
+        #[cfg(feature = "slashing")]
+        "/cosmos.slashing.v1beta1.SigningInfo" => Some(AnyJson::of::<cosmos::slashing::v1beta1::SigningInfo>()),
+        #[cfg(feature = "slashing")]
+        "/cosmos.slashing.v1beta1.ValidatorMissedBlocks" => Some(AnyJson::of::<cosmos::slashing::v1beta1::ValidatorMissedBlocks>()),
+        #[cfg(feature = "slashing")]
+        "/cosmos.slashing.v1beta1.ValidatorSigningInfo" => Some(AnyJson::of::<cosmos::slashing::v1beta1::ValidatorSigningInfo>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.Commission" => Some(AnyJson::of::<cosmos::staking::v1beta1::Commission>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.CommissionRates" => Some(AnyJson::of::<cosmos::staking::v1beta1::CommissionRates>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.DVPair" => Some(AnyJson::of::<cosmos::staking::v1beta1::DvPair>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.DVPairs" => Some(AnyJson::of::<cosmos::staking::v1beta1::DvPairs>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.DVVTriplet" => Some(AnyJson::of::<cosmos::staking::v1beta1::DvvTriplet>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.DVVTriplets" => Some(AnyJson::of::<cosmos::staking::v1beta1::DvvTriplets>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.Delegation" => Some(AnyJson::of::<cosmos::staking::v1beta1::Delegation>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.DelegationResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::DelegationResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.Description" => Some(AnyJson::of::<cosmos::staking::v1beta1::Description>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.GenesisState" => Some(AnyJson::of::<cosmos::staking::v1beta1::GenesisState>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.HistoricalInfo" => Some(AnyJson::of::<cosmos::staking::v1beta1::HistoricalInfo>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.LastValidatorPower" => Some(AnyJson::of::<cosmos::staking::v1beta1::LastValidatorPower>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.MsgBeginRedelegate" => Some(AnyJson::of::<cosmos::staking::v1beta1::MsgBeginRedelegate>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.MsgBeginRedelegateResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::MsgBeginRedelegateResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.MsgCancelUnbondingDelegation" => Some(AnyJson::of::<cosmos::staking::v1beta1::MsgCancelUnbondingDelegation>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.MsgCancelUnbondingDelegationResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::MsgCancelUnbondingDelegationResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.MsgCreateValidator" => Some(AnyJson::of::<cosmos::staking::v1beta1::MsgCreateValidator>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.MsgCreateValidatorResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::MsgCreateValidatorResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.MsgDelegate" => Some(AnyJson::of::<cosmos::staking::v1beta1::MsgDelegate>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.MsgDelegateResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::MsgDelegateResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.MsgEditValidator" => Some(AnyJson::of::<cosmos::staking::v1beta1::MsgEditValidator>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.MsgEditValidatorResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::MsgEditValidatorResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.MsgUndelegate" => Some(AnyJson::of::<cosmos::staking::v1beta1::MsgUndelegate>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.MsgUndelegateResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::MsgUndelegateResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.MsgUpdateParams" => Some(AnyJson::of::<cosmos::staking::v1beta1::MsgUpdateParams>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.MsgUpdateParamsResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::MsgUpdateParamsResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.Params" => Some(AnyJson::of::<cosmos::staking::v1beta1::Params>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.Pool" => Some(AnyJson::of::<cosmos::staking::v1beta1::Pool>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryDelegationRequest" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryDelegationRequest>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryDelegationResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryDelegationResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryDelegatorDelegationsRequest" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryDelegatorDelegationsRequest>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryDelegatorDelegationsResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryDelegatorDelegationsResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryDelegatorUnbondingDelegationsRequest" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryDelegatorUnbondingDelegationsRequest>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryDelegatorUnbondingDelegationsResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryDelegatorUnbondingDelegationsResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryDelegatorValidatorRequest" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryDelegatorValidatorRequest>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryDelegatorValidatorResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryDelegatorValidatorResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryDelegatorValidatorsRequest" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryDelegatorValidatorsRequest>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryDelegatorValidatorsResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryDelegatorValidatorsResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryHistoricalInfoRequest" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryHistoricalInfoRequest>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryHistoricalInfoResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryHistoricalInfoResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryParamsRequest" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryParamsRequest>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryParamsResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryParamsResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryPoolRequest" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryPoolRequest>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryPoolResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryPoolResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryRedelegationsRequest" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryRedelegationsRequest>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryRedelegationsResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryRedelegationsResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryUnbondingDelegationRequest" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryUnbondingDelegationRequest>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryUnbondingDelegationResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryUnbondingDelegationResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryValidatorDelegationsRequest" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryValidatorDelegationsRequest>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryValidatorDelegationsResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryValidatorDelegationsResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryValidatorRequest" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryValidatorRequest>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryValidatorResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryValidatorResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryValidatorUnbondingDelegationsRequest" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryValidatorUnbondingDelegationsRequest>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryValidatorUnbondingDelegationsResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryValidatorUnbondingDelegationsResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryValidatorsRequest" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryValidatorsRequest>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.QueryValidatorsResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::QueryValidatorsResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.Redelegation" => Some(AnyJson::of::<cosmos::staking::v1beta1::Redelegation>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.RedelegationEntry" => Some(AnyJson::of::<cosmos::staking::v1beta1::RedelegationEntry>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.RedelegationEntryResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::RedelegationEntryResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.RedelegationResponse" => Some(AnyJson::of::<cosmos::staking::v1beta1::RedelegationResponse>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.StakeAuthorization" => Some(AnyJson::of::<cosmos::staking::v1beta1::StakeAuthorization>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.UnbondingDelegation" => Some(AnyJson::of::<cosmos::staking::v1beta1::UnbondingDelegation>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.UnbondingDelegationEntry" => Some(AnyJson::of::<cosmos::staking::v1beta1::UnbondingDelegationEntry>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.ValAddresses" => Some(AnyJson::of::<cosmos::staking::v1beta1::ValAddresses>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.Validator" => Some(AnyJson::of::<cosmos::staking::v1beta1::Validator>()),
+        #[cfg(feature = "staking")]
+        "/cosmos.staking.v1beta1.ValidatorUpdates" => Some(AnyJson::of::<cosmos::staking::v1beta1::ValidatorUpdates>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.config.v1.Config" => Some(AnyJson::of::<cosmos::tx::config::v1::Config>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.signing.v1beta1.SignatureDescriptor" => Some(AnyJson::of::<cosmos::tx::signing::v1beta1::SignatureDescriptor>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.signing.v1beta1.SignatureDescriptors" => Some(AnyJson::of::<cosmos::tx::signing::v1beta1::SignatureDescriptors>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.AuthInfo" => Some(AnyJson::of::<cosmos::tx::v1beta1::AuthInfo>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.AuxSignerData" => Some(AnyJson::of::<cosmos::tx::v1beta1::AuxSignerData>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.BroadcastTxRequest" => Some(AnyJson::of::<cosmos::tx::v1beta1::BroadcastTxRequest>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.BroadcastTxResponse" => Some(AnyJson::of::<cosmos::tx::v1beta1::BroadcastTxResponse>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.Fee" => Some(AnyJson::of::<cosmos::tx::v1beta1::Fee>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.GetBlockWithTxsRequest" => Some(AnyJson::of::<cosmos::tx::v1beta1::GetBlockWithTxsRequest>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.GetBlockWithTxsResponse" => Some(AnyJson::of::<cosmos::tx::v1beta1::GetBlockWithTxsResponse>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.GetTxRequest" => Some(AnyJson::of::<cosmos::tx::v1beta1::GetTxRequest>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.GetTxResponse" => Some(AnyJson::of::<cosmos::tx::v1beta1::GetTxResponse>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.GetTxsEventRequest" => Some(AnyJson::of::<cosmos::tx::v1beta1::GetTxsEventRequest>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.GetTxsEventResponse" => Some(AnyJson::of::<cosmos::tx::v1beta1::GetTxsEventResponse>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.ModeInfo" => Some(AnyJson::of::<cosmos::tx::v1beta1::ModeInfo>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.SignDoc" => Some(AnyJson::of::<cosmos::tx::v1beta1::SignDoc>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.SignDocDirectAux" => Some(AnyJson::of::<cosmos::tx::v1beta1::SignDocDirectAux>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.SignerInfo" => Some(AnyJson::of::<cosmos::tx::v1beta1::SignerInfo>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.SimulateRequest" => Some(AnyJson::of::<cosmos::tx::v1beta1::SimulateRequest>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.SimulateResponse" => Some(AnyJson::of::<cosmos::tx::v1beta1::SimulateResponse>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.Tip" => Some(AnyJson::of::<cosmos::tx::v1beta1::Tip>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.Tx" => Some(AnyJson::of::<cosmos::tx::v1beta1::Tx>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.TxBody" => Some(AnyJson::of::<cosmos::tx::v1beta1::TxBody>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.TxDecodeAminoRequest" => Some(AnyJson::of::<cosmos::tx::v1beta1::TxDecodeAminoRequest>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.TxDecodeAminoResponse" => Some(AnyJson::of::<cosmos::tx::v1beta1::TxDecodeAminoResponse>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.TxDecodeRequest" => Some(AnyJson::of::<cosmos::tx::v1beta1::TxDecodeRequest>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.TxDecodeResponse" => Some(AnyJson::of::<cosmos::tx::v1beta1::TxDecodeResponse>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.TxEncodeAminoRequest" => Some(AnyJson::of::<cosmos::tx::v1beta1::TxEncodeAminoRequest>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.TxEncodeAminoResponse" => Some(AnyJson::of::<cosmos::tx::v1beta1::TxEncodeAminoResponse>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.TxEncodeRequest" => Some(AnyJson::of::<cosmos::tx::v1beta1::TxEncodeRequest>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.TxEncodeResponse" => Some(AnyJson::of::<cosmos::tx::v1beta1::TxEncodeResponse>()),
+        #[cfg(feature = "tx")]
+        "/cosmos.tx.v1beta1.TxRaw" => Some(AnyJson::of::<cosmos::tx::v1beta1::TxRaw>()),
+        #[cfg(feature = "upgrade")]
+        "/cosmos.upgrade.v1beta1.CancelSoftwareUpgradeProposal" => Some(AnyJson::of::<cosmos::upgrade::v1beta1::CancelSoftwareUpgradeProposal>()),
+        #[cfg(feature = "upgrade")]
+        "/cosmos.upgrade.v1beta1.ModuleVersion" => Some(AnyJson::of::<cosmos::upgrade::v1beta1::ModuleVersion>()),
+        #[cfg(feature = "upgrade")]
+        "/cosmos.upgrade.v1beta1.MsgCancelUpgrade" => Some(AnyJson::of::<cosmos::upgrade::v1beta1::MsgCancelUpgrade>()),
+        #[cfg(feature = "upgrade")]
+        "/cosmos.upgrade.v1beta1.MsgCancelUpgradeResponse" => Some(AnyJson::of::<cosmos::upgrade::v1beta1::MsgCancelUpgradeResponse>()),
+        #[cfg(feature = "upgrade")]
+        "/cosmos.upgrade.v1beta1.MsgSoftwareUpgrade" => Some(AnyJson::of::<cosmos::upgrade::v1beta1::MsgSoftwareUpgrade>()),
+        #[cfg(feature = "upgrade")]
+        "/cosmos.upgrade.v1beta1.MsgSoftwareUpgradeResponse" => Some(AnyJson::of::<cosmos::upgrade::v1beta1::MsgSoftwareUpgradeResponse>()),
+        #[cfg(feature = "upgrade")]
+        "/cosmos.upgrade.v1beta1.Plan" => Some(AnyJson::of::<cosmos::upgrade::v1beta1::Plan>()),
+        #[cfg(feature = "upgrade")]
+        "/cosmos.upgrade.v1beta1.QueryAppliedPlanRequest" => Some(AnyJson::of::<cosmos::upgrade::v1beta1::QueryAppliedPlanRequest>()),
+        #[cfg(feature = "upgrade")]
+        "/cosmos.upgrade.v1beta1.QueryAppliedPlanResponse" => Some(AnyJson::of::<cosmos::upgrade::v1beta1::QueryAppliedPlanResponse>()),
+        #[cfg(feature = "upgrade")]
+        "/cosmos.upgrade.v1beta1.QueryAuthorityRequest" => Some(AnyJson::of::<cosmos::upgrade::v1beta1::QueryAuthorityRequest>()),
+        #[cfg(feature = "upgrade")]
+        "/cosmos.upgrade.v1beta1.QueryAuthorityResponse" => Some(AnyJson::of::<cosmos::upgrade::v1beta1::QueryAuthorityResponse>()),
+        #[cfg(feature = "upgrade")]
+        "/cosmos.upgrade.v1beta1.QueryCurrentPlanRequest" => Some(AnyJson::of::<cosmos::upgrade::v1beta1::QueryCurrentPlanRequest>()),
+        #[cfg(feature = "upgrade")]
+        "/cosmos.upgrade.v1beta1.QueryCurrentPlanResponse" => Some(AnyJson::of::<cosmos::upgrade::v1beta1::QueryCurrentPlanResponse>()),
+        #[cfg(feature = "upgrade")]
+        "/cosmos.upgrade.v1beta1.QueryModuleVersionsRequest" => Some(AnyJson::of::<cosmos::upgrade::v1beta1::QueryModuleVersionsRequest>()),
+        #[cfg(feature = "upgrade")]
+        "/cosmos.upgrade.v1beta1.QueryModuleVersionsResponse" => Some(AnyJson::of::<cosmos::upgrade::v1beta1::QueryModuleVersionsResponse>()),
+        #[cfg(feature = "upgrade")]
+        "/cosmos.upgrade.v1beta1.QueryUpgradedConsensusStateRequest" => Some(AnyJson::of::<cosmos::upgrade::v1beta1::QueryUpgradedConsensusStateRequest>()),
+        #[cfg(feature = "upgrade")]
+        "/cosmos.upgrade.v1beta1.QueryUpgradedConsensusStateResponse" => Some(AnyJson::of::<cosmos::upgrade::v1beta1::QueryUpgradedConsensusStateResponse>()),
+        #[cfg(feature = "upgrade")]
+        "/cosmos.upgrade.v1beta1.SoftwareUpgradeProposal" => Some(AnyJson::of::<cosmos::upgrade::v1beta1::SoftwareUpgradeProposal>()),
+        #[cfg(feature = "vesting")]
+        "/cosmos.vesting.v1beta1.BaseVestingAccount" => Some(AnyJson::of::<cosmos::vesting::v1beta1::BaseVestingAccount>()),
+        #[cfg(feature = "vesting")]
+        "/cosmos.vesting.v1beta1.ContinuousVestingAccount" => Some(AnyJson::of::<cosmos::vesting::v1beta1::ContinuousVestingAccount>()),
+        #[cfg(feature = "vesting")]
+        "/cosmos.vesting.v1beta1.DelayedVestingAccount" => Some(AnyJson::of::<cosmos::vesting::v1beta1::DelayedVestingAccount>()),
+        #[cfg(feature = "vesting")]
+        "/cosmos.vesting.v1beta1.MsgCreatePeriodicVestingAccount" => Some(AnyJson::of::<cosmos::vesting::v1beta1::MsgCreatePeriodicVestingAccount>()),
+        #[cfg(feature = "vesting")]
+        "/cosmos.vesting.v1beta1.MsgCreatePeriodicVestingAccountResponse" => Some(AnyJson::of::<cosmos::vesting::v1beta1::MsgCreatePeriodicVestingAccountResponse>()),
+        #[cfg(feature = "vesting")]
+        "/cosmos.vesting.v1beta1.MsgCreatePermanentLockedAccount" => Some(AnyJson::of::<cosmos::vesting::v1beta1::MsgCreatePermanentLockedAccount>()),
+        #[cfg(feature = "vesting")]
+        "/cosmos.vesting.v1beta1.MsgCreatePermanentLockedAccountResponse" => Some(AnyJson::of::<cosmos::vesting::v1beta1::MsgCreatePermanentLockedAccountResponse>()),
+        #[cfg(feature = "vesting")]
+        "/cosmos.vesting.v1beta1.MsgCreateVestingAccount" => Some(AnyJson::of::<cosmos::vesting::v1beta1::MsgCreateVestingAccount>()),
+        #[cfg(feature = "vesting")]
+        "/cosmos.vesting.v1beta1.MsgCreateVestingAccountResponse" => Some(AnyJson::of::<cosmos::vesting::v1beta1::MsgCreateVestingAccountResponse>()),
+        #[cfg(feature = "vesting")]
+        "/cosmos.vesting.v1beta1.Period" => Some(AnyJson::of::<cosmos::vesting::v1beta1::Period>()),
+        #[cfg(feature = "vesting")]
+        "/cosmos.vesting.v1beta1.PeriodicVestingAccount" => Some(AnyJson::of::<cosmos::vesting::v1beta1::PeriodicVestingAccount>()),
+        #[cfg(feature = "vesting")]
+        "/cosmos.vesting.v1beta1.PermanentLockedAccount" => Some(AnyJson::of::<cosmos::vesting::v1beta1::PermanentLockedAccount>()),
+        _ => None,
     }
 }
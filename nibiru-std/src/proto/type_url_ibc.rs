@@ -0,0 +1,1033 @@
+//! Implements the prost::Name trait for IBC protobuf types, which defines
+//! the prost::Message.type_url function needed for CosmWasm smart contracts,
+//! and the NibiruGrpcQuery trait for requests with a known gRPC query path.
+//!
+//! Code generated from "proto/buf/proto_descriptor.bin" by
+//! `cargo run --bin type_url_gen` in the scripts directory. DO NOT EDIT.
+
+#[allow(unused_imports)]
+use prost::Name;
+
+#[allow(unused_imports)]
+use crate::proto::{ibc, NibiruGrpcQuery};
+
+#[cfg(feature = "ibc")]
+const PACKAGE_APPLICATIONS_TRANSFER_V1: &str = "ibc.applications.transfer.v1";
+#[cfg(feature = "ibc")]
+const PACKAGE_CORE_CHANNEL_V1: &str = "ibc.core.channel.v1";
+#[cfg(feature = "ibc")]
+const PACKAGE_CORE_CLIENT_V1: &str = "ibc.core.client.v1";
+
+// ibc.applications.transfer.v1
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::Allocation {
+    const NAME: &'static str = "Allocation";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::DenomTrace {
+    const NAME: &'static str = "DenomTrace";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::MsgTransfer {
+    const NAME: &'static str = "MsgTransfer";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::MsgTransferResponse {
+    const NAME: &'static str = "MsgTransferResponse";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::QueryDenomHashRequest {
+    const NAME: &'static str = "QueryDenomHashRequest";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::QueryDenomHashResponse {
+    const NAME: &'static str = "QueryDenomHashResponse";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::QueryDenomTraceRequest {
+    const NAME: &'static str = "QueryDenomTraceRequest";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::QueryDenomTraceResponse {
+    const NAME: &'static str = "QueryDenomTraceResponse";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::QueryDenomTracesRequest {
+    const NAME: &'static str = "QueryDenomTracesRequest";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::QueryDenomTracesResponse {
+    const NAME: &'static str = "QueryDenomTracesResponse";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::QueryEscrowAddressRequest {
+    const NAME: &'static str = "QueryEscrowAddressRequest";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::QueryEscrowAddressResponse {
+    const NAME: &'static str = "QueryEscrowAddressResponse";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::QueryTotalEscrowForDenomRequest {
+    const NAME: &'static str = "QueryTotalEscrowForDenomRequest";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::QueryTotalEscrowForDenomResponse {
+    const NAME: &'static str = "QueryTotalEscrowForDenomResponse";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::applications::transfer::v1::TransferAuthorization {
+    const NAME: &'static str = "TransferAuthorization";
+    const PACKAGE: &'static str = PACKAGE_APPLICATIONS_TRANSFER_V1;
+}
+
+// ibc.core.channel.v1
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::Acknowledgement {
+    const NAME: &'static str = "Acknowledgement";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::Channel {
+    const NAME: &'static str = "Channel";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::Counterparty {
+    const NAME: &'static str = "Counterparty";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::IdentifiedChannel {
+    const NAME: &'static str = "IdentifiedChannel";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::Packet {
+    const NAME: &'static str = "Packet";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::PacketId {
+    const NAME: &'static str = "PacketId";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::PacketState {
+    const NAME: &'static str = "PacketState";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryChannelClientStateRequest {
+    const NAME: &'static str = "QueryChannelClientStateRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryChannelClientStateResponse {
+    const NAME: &'static str = "QueryChannelClientStateResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryChannelConsensusStateRequest {
+    const NAME: &'static str = "QueryChannelConsensusStateRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryChannelConsensusStateResponse {
+    const NAME: &'static str = "QueryChannelConsensusStateResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryChannelRequest {
+    const NAME: &'static str = "QueryChannelRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryChannelResponse {
+    const NAME: &'static str = "QueryChannelResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryChannelsRequest {
+    const NAME: &'static str = "QueryChannelsRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryChannelsResponse {
+    const NAME: &'static str = "QueryChannelsResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryConnectionChannelsRequest {
+    const NAME: &'static str = "QueryConnectionChannelsRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryConnectionChannelsResponse {
+    const NAME: &'static str = "QueryConnectionChannelsResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryNextSequenceReceiveRequest {
+    const NAME: &'static str = "QueryNextSequenceReceiveRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryNextSequenceReceiveResponse {
+    const NAME: &'static str = "QueryNextSequenceReceiveResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryPacketAcknowledgementRequest {
+    const NAME: &'static str = "QueryPacketAcknowledgementRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryPacketAcknowledgementResponse {
+    const NAME: &'static str = "QueryPacketAcknowledgementResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryPacketAcknowledgementsRequest {
+    const NAME: &'static str = "QueryPacketAcknowledgementsRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryPacketAcknowledgementsResponse {
+    const NAME: &'static str = "QueryPacketAcknowledgementsResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryPacketCommitmentRequest {
+    const NAME: &'static str = "QueryPacketCommitmentRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryPacketCommitmentResponse {
+    const NAME: &'static str = "QueryPacketCommitmentResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryPacketCommitmentsRequest {
+    const NAME: &'static str = "QueryPacketCommitmentsRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryPacketCommitmentsResponse {
+    const NAME: &'static str = "QueryPacketCommitmentsResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryPacketReceiptRequest {
+    const NAME: &'static str = "QueryPacketReceiptRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryPacketReceiptResponse {
+    const NAME: &'static str = "QueryPacketReceiptResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryUnreceivedAcksRequest {
+    const NAME: &'static str = "QueryUnreceivedAcksRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryUnreceivedAcksResponse {
+    const NAME: &'static str = "QueryUnreceivedAcksResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryUnreceivedPacketsRequest {
+    const NAME: &'static str = "QueryUnreceivedPacketsRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::channel::v1::QueryUnreceivedPacketsResponse {
+    const NAME: &'static str = "QueryUnreceivedPacketsResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CHANNEL_V1;
+}
+
+// ibc.core.client.v1
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::ClientConsensusStates {
+    const NAME: &'static str = "ClientConsensusStates";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::ConsensusStateWithHeight {
+    const NAME: &'static str = "ConsensusStateWithHeight";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::Height {
+    const NAME: &'static str = "Height";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::IdentifiedClientState {
+    const NAME: &'static str = "IdentifiedClientState";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::QueryClientParamsRequest {
+    const NAME: &'static str = "QueryClientParamsRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::QueryClientParamsResponse {
+    const NAME: &'static str = "QueryClientParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::QueryClientStateRequest {
+    const NAME: &'static str = "QueryClientStateRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::QueryClientStateResponse {
+    const NAME: &'static str = "QueryClientStateResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::QueryClientStatesRequest {
+    const NAME: &'static str = "QueryClientStatesRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::QueryClientStatesResponse {
+    const NAME: &'static str = "QueryClientStatesResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::QueryClientStatusRequest {
+    const NAME: &'static str = "QueryClientStatusRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::QueryClientStatusResponse {
+    const NAME: &'static str = "QueryClientStatusResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::QueryConsensusStateHeightsRequest {
+    const NAME: &'static str = "QueryConsensusStateHeightsRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::QueryConsensusStateHeightsResponse {
+    const NAME: &'static str = "QueryConsensusStateHeightsResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::QueryConsensusStateRequest {
+    const NAME: &'static str = "QueryConsensusStateRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::QueryConsensusStateResponse {
+    const NAME: &'static str = "QueryConsensusStateResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::QueryConsensusStatesRequest {
+    const NAME: &'static str = "QueryConsensusStatesRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::QueryConsensusStatesResponse {
+    const NAME: &'static str = "QueryConsensusStatesResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::QueryUpgradedClientStateRequest {
+    const NAME: &'static str = "QueryUpgradedClientStateRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::QueryUpgradedClientStateResponse {
+    const NAME: &'static str = "QueryUpgradedClientStateResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::QueryUpgradedConsensusStateRequest {
+    const NAME: &'static str = "QueryUpgradedConsensusStateRequest";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+#[cfg(feature = "ibc")]
+impl Name for ibc::core::client::v1::QueryUpgradedConsensusStateResponse {
+    const NAME: &'static str = "QueryUpgradedConsensusStateResponse";
+    const PACKAGE: &'static str = PACKAGE_CORE_CLIENT_V1;
+}
+
+// gRPC query paths
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery for ibc::applications::transfer::v1::QueryDenomHashRequest {
+    const GRPC_PATH: &'static str =
+        "/ibc.applications.transfer.v1.Query/DenomHash";
+    type Response = ibc::applications::transfer::v1::QueryDenomHashResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery
+    for ibc::applications::transfer::v1::QueryDenomTraceRequest
+{
+    const GRPC_PATH: &'static str =
+        "/ibc.applications.transfer.v1.Query/DenomTrace";
+    type Response = ibc::applications::transfer::v1::QueryDenomTraceResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery
+    for ibc::applications::transfer::v1::QueryDenomTracesRequest
+{
+    const GRPC_PATH: &'static str =
+        "/ibc.applications.transfer.v1.Query/DenomTraces";
+    type Response = ibc::applications::transfer::v1::QueryDenomTracesResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery
+    for ibc::applications::transfer::v1::QueryEscrowAddressRequest
+{
+    const GRPC_PATH: &'static str =
+        "/ibc.applications.transfer.v1.Query/EscrowAddress";
+    type Response = ibc::applications::transfer::v1::QueryEscrowAddressResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery for ibc::applications::transfer::v1::QueryParamsRequest {
+    const GRPC_PATH: &'static str = "/ibc.applications.transfer.v1.Query/Params";
+    type Response = ibc::applications::transfer::v1::QueryParamsResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery
+    for ibc::applications::transfer::v1::QueryTotalEscrowForDenomRequest
+{
+    const GRPC_PATH: &'static str =
+        "/ibc.applications.transfer.v1.Query/TotalEscrowForDenom";
+    type Response =
+        ibc::applications::transfer::v1::QueryTotalEscrowForDenomResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery for ibc::core::channel::v1::QueryChannelClientStateRequest {
+    const GRPC_PATH: &'static str =
+        "/ibc.core.channel.v1.Query/ChannelClientState";
+    type Response = ibc::core::channel::v1::QueryChannelClientStateResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery
+    for ibc::core::channel::v1::QueryChannelConsensusStateRequest
+{
+    const GRPC_PATH: &'static str =
+        "/ibc.core.channel.v1.Query/ChannelConsensusState";
+    type Response = ibc::core::channel::v1::QueryChannelConsensusStateResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery for ibc::core::channel::v1::QueryChannelRequest {
+    const GRPC_PATH: &'static str = "/ibc.core.channel.v1.Query/Channel";
+    type Response = ibc::core::channel::v1::QueryChannelResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery for ibc::core::channel::v1::QueryChannelsRequest {
+    const GRPC_PATH: &'static str = "/ibc.core.channel.v1.Query/Channels";
+    type Response = ibc::core::channel::v1::QueryChannelsResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery for ibc::core::channel::v1::QueryConnectionChannelsRequest {
+    const GRPC_PATH: &'static str =
+        "/ibc.core.channel.v1.Query/ConnectionChannels";
+    type Response = ibc::core::channel::v1::QueryConnectionChannelsResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery
+    for ibc::core::channel::v1::QueryNextSequenceReceiveRequest
+{
+    const GRPC_PATH: &'static str =
+        "/ibc.core.channel.v1.Query/NextSequenceReceive";
+    type Response = ibc::core::channel::v1::QueryNextSequenceReceiveResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery
+    for ibc::core::channel::v1::QueryPacketAcknowledgementRequest
+{
+    const GRPC_PATH: &'static str =
+        "/ibc.core.channel.v1.Query/PacketAcknowledgement";
+    type Response = ibc::core::channel::v1::QueryPacketAcknowledgementResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery
+    for ibc::core::channel::v1::QueryPacketAcknowledgementsRequest
+{
+    const GRPC_PATH: &'static str =
+        "/ibc.core.channel.v1.Query/PacketAcknowledgements";
+    type Response = ibc::core::channel::v1::QueryPacketAcknowledgementsResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery for ibc::core::channel::v1::QueryPacketCommitmentRequest {
+    const GRPC_PATH: &'static str =
+        "/ibc.core.channel.v1.Query/PacketCommitment";
+    type Response = ibc::core::channel::v1::QueryPacketCommitmentResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery for ibc::core::channel::v1::QueryPacketCommitmentsRequest {
+    const GRPC_PATH: &'static str =
+        "/ibc.core.channel.v1.Query/PacketCommitments";
+    type Response = ibc::core::channel::v1::QueryPacketCommitmentsResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery for ibc::core::channel::v1::QueryPacketReceiptRequest {
+    const GRPC_PATH: &'static str = "/ibc.core.channel.v1.Query/PacketReceipt";
+    type Response = ibc::core::channel::v1::QueryPacketReceiptResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery for ibc::core::channel::v1::QueryUnreceivedAcksRequest {
+    const GRPC_PATH: &'static str = "/ibc.core.channel.v1.Query/UnreceivedAcks";
+    type Response = ibc::core::channel::v1::QueryUnreceivedAcksResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery for ibc::core::channel::v1::QueryUnreceivedPacketsRequest {
+    const GRPC_PATH: &'static str =
+        "/ibc.core.channel.v1.Query/UnreceivedPackets";
+    type Response = ibc::core::channel::v1::QueryUnreceivedPacketsResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery for ibc::core::client::v1::QueryClientParamsRequest {
+    const GRPC_PATH: &'static str = "/ibc.core.client.v1.Query/ClientParams";
+    type Response = ibc::core::client::v1::QueryClientParamsResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery for ibc::core::client::v1::QueryClientStateRequest {
+    const GRPC_PATH: &'static str = "/ibc.core.client.v1.Query/ClientState";
+    type Response = ibc::core::client::v1::QueryClientStateResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery for ibc::core::client::v1::QueryClientStatesRequest {
+    const GRPC_PATH: &'static str = "/ibc.core.client.v1.Query/ClientStates";
+    type Response = ibc::core::client::v1::QueryClientStatesResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery for ibc::core::client::v1::QueryClientStatusRequest {
+    const GRPC_PATH: &'static str = "/ibc.core.client.v1.Query/ClientStatus";
+    type Response = ibc::core::client::v1::QueryClientStatusResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery
+    for ibc::core::client::v1::QueryConsensusStateHeightsRequest
+{
+    const GRPC_PATH: &'static str =
+        "/ibc.core.client.v1.Query/ConsensusStateHeights";
+    type Response = ibc::core::client::v1::QueryConsensusStateHeightsResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery for ibc::core::client::v1::QueryConsensusStateRequest {
+    const GRPC_PATH: &'static str = "/ibc.core.client.v1.Query/ConsensusState";
+    type Response = ibc::core::client::v1::QueryConsensusStateResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery for ibc::core::client::v1::QueryConsensusStatesRequest {
+    const GRPC_PATH: &'static str = "/ibc.core.client.v1.Query/ConsensusStates";
+    type Response = ibc::core::client::v1::QueryConsensusStatesResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery for ibc::core::client::v1::QueryUpgradedClientStateRequest {
+    const GRPC_PATH: &'static str =
+        "/ibc.core.client.v1.Query/UpgradedClientState";
+    type Response = ibc::core::client::v1::QueryUpgradedClientStateResponse;
+}
+
+#[cfg(feature = "ibc")]
+impl NibiruGrpcQuery
+    for ibc::core::client::v1::QueryUpgradedConsensusStateRequest
+{
+    const GRPC_PATH: &'static str =
+        "/ibc.core.client.v1.Query/UpgradedConsensusState";
+    type Response = ibc::core::client::v1::QueryUpgradedConsensusStateResponse;
+}
+
+/// Returns the gRPC query path of the request with the given fully qualified
+/// proto name, or `None` if no single query method serves it.
+#[allow(clippy::match_single_binding)]
+pub(crate) fn grpc_query_path(full_name: &str) -> Option<&'static str> {
+    match full_name {
+        #[cfg(feature = "ibc")]
+        "ibc.applications.transfer.v1.QueryDenomHashRequest" => Some(<ibc::applications::transfer::v1::QueryDenomHashRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.applications.transfer.v1.QueryDenomTraceRequest" => Some(<ibc::applications::transfer::v1::QueryDenomTraceRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.applications.transfer.v1.QueryDenomTracesRequest" => Some(<ibc::applications::transfer::v1::QueryDenomTracesRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.applications.transfer.v1.QueryEscrowAddressRequest" => Some(<ibc::applications::transfer::v1::QueryEscrowAddressRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.applications.transfer.v1.QueryParamsRequest" => Some(<ibc::applications::transfer::v1::QueryParamsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.applications.transfer.v1.QueryTotalEscrowForDenomRequest" => Some(<ibc::applications::transfer::v1::QueryTotalEscrowForDenomRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.channel.v1.QueryChannelClientStateRequest" => Some(<ibc::core::channel::v1::QueryChannelClientStateRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.channel.v1.QueryChannelConsensusStateRequest" => Some(<ibc::core::channel::v1::QueryChannelConsensusStateRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.channel.v1.QueryChannelRequest" => Some(<ibc::core::channel::v1::QueryChannelRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.channel.v1.QueryChannelsRequest" => Some(<ibc::core::channel::v1::QueryChannelsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.channel.v1.QueryConnectionChannelsRequest" => Some(<ibc::core::channel::v1::QueryConnectionChannelsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.channel.v1.QueryNextSequenceReceiveRequest" => Some(<ibc::core::channel::v1::QueryNextSequenceReceiveRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.channel.v1.QueryPacketAcknowledgementRequest" => Some(<ibc::core::channel::v1::QueryPacketAcknowledgementRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.channel.v1.QueryPacketAcknowledgementsRequest" => Some(<ibc::core::channel::v1::QueryPacketAcknowledgementsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.channel.v1.QueryPacketCommitmentRequest" => Some(<ibc::core::channel::v1::QueryPacketCommitmentRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.channel.v1.QueryPacketCommitmentsRequest" => Some(<ibc::core::channel::v1::QueryPacketCommitmentsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.channel.v1.QueryPacketReceiptRequest" => Some(<ibc::core::channel::v1::QueryPacketReceiptRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.channel.v1.QueryUnreceivedAcksRequest" => Some(<ibc::core::channel::v1::QueryUnreceivedAcksRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.channel.v1.QueryUnreceivedPacketsRequest" => Some(<ibc::core::channel::v1::QueryUnreceivedPacketsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.client.v1.QueryClientParamsRequest" => Some(<ibc::core::client::v1::QueryClientParamsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.client.v1.QueryClientStateRequest" => Some(<ibc::core::client::v1::QueryClientStateRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.client.v1.QueryClientStatesRequest" => Some(<ibc::core::client::v1::QueryClientStatesRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.client.v1.QueryClientStatusRequest" => Some(<ibc::core::client::v1::QueryClientStatusRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.client.v1.QueryConsensusStateHeightsRequest" => Some(<ibc::core::client::v1::QueryConsensusStateHeightsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.client.v1.QueryConsensusStateRequest" => Some(<ibc::core::client::v1::QueryConsensusStateRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.client.v1.QueryConsensusStatesRequest" => Some(<ibc::core::client::v1::QueryConsensusStatesRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.client.v1.QueryUpgradedClientStateRequest" => Some(<ibc::core::client::v1::QueryUpgradedClientStateRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "ibc")]
+        "ibc.core.client.v1.QueryUpgradedConsensusStateRequest" => Some(<ibc::core::client::v1::QueryUpgradedConsensusStateRequest as NibiruGrpcQuery>::GRPC_PATH),
+        _ => None,
+    }
+}
+
+/// (request, gRPC path, response) for every generated [NibiruGrpcQuery] impl.
+#[cfg(all(test, feature = "all_protos"))]
+#[allow(clippy::vec_init_then_push)]
+pub(crate) fn grpc_queries() -> Vec<(String, &'static str, String)> {
+    #[allow(unused_imports)]
+    use crate::proto::grpc_query_entry;
+    #[allow(unused_mut)]
+    let mut queries = vec![];
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::applications::transfer::v1::QueryDenomHashRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::applications::transfer::v1::QueryDenomTraceRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::applications::transfer::v1::QueryDenomTracesRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::applications::transfer::v1::QueryEscrowAddressRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::applications::transfer::v1::QueryParamsRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::applications::transfer::v1::QueryTotalEscrowForDenomRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::channel::v1::QueryChannelClientStateRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::channel::v1::QueryChannelConsensusStateRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::channel::v1::QueryChannelRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::channel::v1::QueryChannelsRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::channel::v1::QueryConnectionChannelsRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::channel::v1::QueryNextSequenceReceiveRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::channel::v1::QueryPacketAcknowledgementRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::channel::v1::QueryPacketAcknowledgementsRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::channel::v1::QueryPacketCommitmentRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::channel::v1::QueryPacketCommitmentsRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::channel::v1::QueryPacketReceiptRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::channel::v1::QueryUnreceivedAcksRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::channel::v1::QueryUnreceivedPacketsRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::client::v1::QueryClientParamsRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::client::v1::QueryClientStateRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::client::v1::QueryClientStatesRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::client::v1::QueryClientStatusRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::client::v1::QueryConsensusStateHeightsRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::client::v1::QueryConsensusStateRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::client::v1::QueryConsensusStatesRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::client::v1::QueryUpgradedClientStateRequest,
+    >());
+    #[cfg(feature = "ibc")]
+    queries.push(grpc_query_entry::<
+        ibc::core::client::v1::QueryUpgradedConsensusStateRequest,
+    >());
+    queries
+}
+
+/// Returns the JSON codec of the message with the given type URL, which
+/// (de)serializes `Any` fields under the "serde" feature.
+#[cfg(feature = "serde")]
+#[allow(clippy::match_single_binding)]
+pub(crate) fn any_json(type_url: &str) -> Option<crate::proto::json::AnyJson> {
+    #[allow(unused_imports)]
+    use crate::proto::json::AnyJson;
+    match type_url {
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.Allocation" => Some(AnyJson::of::<ibc::applications::transfer::v1::Allocation>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.DenomTrace" => Some(AnyJson::of::<ibc::applications::transfer::v1::DenomTrace>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.GenesisState" => Some(AnyJson::of::<ibc::applications::transfer::v1::GenesisState>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.MsgTransfer" => Some(AnyJson::of::<ibc::applications::transfer::v1::MsgTransfer>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.MsgTransferResponse" => Some(AnyJson::of::<ibc::applications::transfer::v1::MsgTransferResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.Params" => Some(AnyJson::of::<ibc::applications::transfer::v1::Params>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.QueryDenomHashRequest" => Some(AnyJson::of::<ibc::applications::transfer::v1::QueryDenomHashRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.QueryDenomHashResponse" => Some(AnyJson::of::<ibc::applications::transfer::v1::QueryDenomHashResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.QueryDenomTraceRequest" => Some(AnyJson::of::<ibc::applications::transfer::v1::QueryDenomTraceRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.QueryDenomTraceResponse" => Some(AnyJson::of::<ibc::applications::transfer::v1::QueryDenomTraceResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.QueryDenomTracesRequest" => Some(AnyJson::of::<ibc::applications::transfer::v1::QueryDenomTracesRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.QueryDenomTracesResponse" => Some(AnyJson::of::<ibc::applications::transfer::v1::QueryDenomTracesResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.QueryEscrowAddressRequest" => Some(AnyJson::of::<ibc::applications::transfer::v1::QueryEscrowAddressRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.QueryEscrowAddressResponse" => Some(AnyJson::of::<ibc::applications::transfer::v1::QueryEscrowAddressResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.QueryParamsRequest" => Some(AnyJson::of::<ibc::applications::transfer::v1::QueryParamsRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.QueryParamsResponse" => Some(AnyJson::of::<ibc::applications::transfer::v1::QueryParamsResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.QueryTotalEscrowForDenomRequest" => Some(AnyJson::of::<ibc::applications::transfer::v1::QueryTotalEscrowForDenomRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.QueryTotalEscrowForDenomResponse" => Some(AnyJson::of::<ibc::applications::transfer::v1::QueryTotalEscrowForDenomResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.applications.transfer.v1.TransferAuthorization" => Some(AnyJson::of::<ibc::applications::transfer::v1::TransferAuthorization>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.Acknowledgement" => Some(AnyJson::of::<ibc::core::channel::v1::Acknowledgement>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.Channel" => Some(AnyJson::of::<ibc::core::channel::v1::Channel>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.Counterparty" => Some(AnyJson::of::<ibc::core::channel::v1::Counterparty>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.IdentifiedChannel" => Some(AnyJson::of::<ibc::core::channel::v1::IdentifiedChannel>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.Packet" => Some(AnyJson::of::<ibc::core::channel::v1::Packet>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.PacketId" => Some(AnyJson::of::<ibc::core::channel::v1::PacketId>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.PacketState" => Some(AnyJson::of::<ibc::core::channel::v1::PacketState>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryChannelClientStateRequest" => Some(AnyJson::of::<ibc::core::channel::v1::QueryChannelClientStateRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryChannelClientStateResponse" => Some(AnyJson::of::<ibc::core::channel::v1::QueryChannelClientStateResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryChannelConsensusStateRequest" => Some(AnyJson::of::<ibc::core::channel::v1::QueryChannelConsensusStateRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryChannelConsensusStateResponse" => Some(AnyJson::of::<ibc::core::channel::v1::QueryChannelConsensusStateResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryChannelRequest" => Some(AnyJson::of::<ibc::core::channel::v1::QueryChannelRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryChannelResponse" => Some(AnyJson::of::<ibc::core::channel::v1::QueryChannelResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryChannelsRequest" => Some(AnyJson::of::<ibc::core::channel::v1::QueryChannelsRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryChannelsResponse" => Some(AnyJson::of::<ibc::core::channel::v1::QueryChannelsResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryConnectionChannelsRequest" => Some(AnyJson::of::<ibc::core::channel::v1::QueryConnectionChannelsRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryConnectionChannelsResponse" => Some(AnyJson::of::<ibc::core::channel::v1::QueryConnectionChannelsResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryNextSequenceReceiveRequest" => Some(AnyJson::of::<ibc::core::channel::v1::QueryNextSequenceReceiveRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryNextSequenceReceiveResponse" => Some(AnyJson::of::<ibc::core::channel::v1::QueryNextSequenceReceiveResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryPacketAcknowledgementRequest" => Some(AnyJson::of::<ibc::core::channel::v1::QueryPacketAcknowledgementRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryPacketAcknowledgementResponse" => Some(AnyJson::of::<ibc::core::channel::v1::QueryPacketAcknowledgementResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryPacketAcknowledgementsRequest" => Some(AnyJson::of::<ibc::core::channel::v1::QueryPacketAcknowledgementsRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryPacketAcknowledgementsResponse" => Some(AnyJson::of::<ibc::core::channel::v1::QueryPacketAcknowledgementsResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryPacketCommitmentRequest" => Some(AnyJson::of::<ibc::core::channel::v1::QueryPacketCommitmentRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryPacketCommitmentResponse" => Some(AnyJson::of::<ibc::core::channel::v1::QueryPacketCommitmentResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryPacketCommitmentsRequest" => Some(AnyJson::of::<ibc::core::channel::v1::QueryPacketCommitmentsRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryPacketCommitmentsResponse" => Some(AnyJson::of::<ibc::core::channel::v1::QueryPacketCommitmentsResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryPacketReceiptRequest" => Some(AnyJson::of::<ibc::core::channel::v1::QueryPacketReceiptRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryPacketReceiptResponse" => Some(AnyJson::of::<ibc::core::channel::v1::QueryPacketReceiptResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryUnreceivedAcksRequest" => Some(AnyJson::of::<ibc::core::channel::v1::QueryUnreceivedAcksRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryUnreceivedAcksResponse" => Some(AnyJson::of::<ibc::core::channel::v1::QueryUnreceivedAcksResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryUnreceivedPacketsRequest" => Some(AnyJson::of::<ibc::core::channel::v1::QueryUnreceivedPacketsRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.channel.v1.QueryUnreceivedPacketsResponse" => Some(AnyJson::of::<ibc::core::channel::v1::QueryUnreceivedPacketsResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.ClientConsensusStates" => Some(AnyJson::of::<ibc::core::client::v1::ClientConsensusStates>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.ConsensusStateWithHeight" => Some(AnyJson::of::<ibc::core::client::v1::ConsensusStateWithHeight>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.Height" => Some(AnyJson::of::<ibc::core::client::v1::Height>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.IdentifiedClientState" => Some(AnyJson::of::<ibc::core::client::v1::IdentifiedClientState>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.Params" => Some(AnyJson::of::<ibc::core::client::v1::Params>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.QueryClientParamsRequest" => Some(AnyJson::of::<ibc::core::client::v1::QueryClientParamsRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.QueryClientParamsResponse" => Some(AnyJson::of::<ibc::core::client::v1::QueryClientParamsResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.QueryClientStateRequest" => Some(AnyJson::of::<ibc::core::client::v1::QueryClientStateRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.QueryClientStateResponse" => Some(AnyJson::of::<ibc::core::client::v1::QueryClientStateResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.QueryClientStatesRequest" => Some(AnyJson::of::<ibc::core::client::v1::QueryClientStatesRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.QueryClientStatesResponse" => Some(AnyJson::of::<ibc::core::client::v1::QueryClientStatesResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.QueryClientStatusRequest" => Some(AnyJson::of::<ibc::core::client::v1::QueryClientStatusRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.QueryClientStatusResponse" => Some(AnyJson::of::<ibc::core::client::v1::QueryClientStatusResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.QueryConsensusStateHeightsRequest" => Some(AnyJson::of::<ibc::core::client::v1::QueryConsensusStateHeightsRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.QueryConsensusStateHeightsResponse" => Some(AnyJson::of::<ibc::core::client::v1::QueryConsensusStateHeightsResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.QueryConsensusStateRequest" => Some(AnyJson::of::<ibc::core::client::v1::QueryConsensusStateRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.QueryConsensusStateResponse" => Some(AnyJson::of::<ibc::core::client::v1::QueryConsensusStateResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.QueryConsensusStatesRequest" => Some(AnyJson::of::<ibc::core::client::v1::QueryConsensusStatesRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.QueryConsensusStatesResponse" => Some(AnyJson::of::<ibc::core::client::v1::QueryConsensusStatesResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.QueryUpgradedClientStateRequest" => Some(AnyJson::of::<ibc::core::client::v1::QueryUpgradedClientStateRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.QueryUpgradedClientStateResponse" => Some(AnyJson::of::<ibc::core::client::v1::QueryUpgradedClientStateResponse>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.QueryUpgradedConsensusStateRequest" => Some(AnyJson::of::<ibc::core::client::v1::QueryUpgradedConsensusStateRequest>()),
+        #[cfg(feature = "ibc")]
+        "/ibc.core.client.v1.QueryUpgradedConsensusStateResponse" => Some(AnyJson::of::<ibc::core::client::v1::QueryUpgradedConsensusStateResponse>()),
+        _ => None,
+    }
+}
@@ -1,565 +1,3787 @@
 //! Implements the prost::Name trait for Nibiru protobuf types, which defines
-//! the prost::Message.type_url function needed for CosmWasm smart contracts.
+//! the prost::Message.type_url function needed for CosmWasm smart contracts,
+//! and the NibiruGrpcQuery trait for requests with a known gRPC query path.
+//!
+//! Code generated from "proto/buf/proto_descriptor.bin" by
+//! `cargo run --bin type_url_gen` in the scripts directory. DO NOT EDIT.
 
+#[allow(unused_imports)]
 use prost::Name;
 
-use crate::proto::{eth, nibiru};
+#[allow(unused_imports)]
+use crate::proto::{eth, nibiru, NibiruGrpcQuery};
 
-const PACKAGE_TOKENFACTORY: &str = "nibiru.tokenfactory.v1";
-const PACKAGE_ORACLE: &str = "nibiru.oracle.v1";
+#[cfg(feature = "evm")]
+const PACKAGE_EVM: &str = "eth.evm.v1";
+#[cfg(feature = "evm")]
+const PACKAGE_TYPES: &str = "eth.types.v1";
+#[cfg(feature = "devgas")]
+const PACKAGE_DEVGAS: &str = "nibiru.devgas.v1";
+#[cfg(feature = "epochs")]
 const PACKAGE_EPOCHS: &str = "nibiru.epochs.v1";
-const PACKAGE_SPOT: &str = "nibiru.spot.v1";
-const PACKAGE_PERP: &str = "nibiru.perp.v2";
+#[cfg(feature = "genmsg")]
+const PACKAGE_GENMSG: &str = "nibiru.genmsg.v1";
+#[cfg(feature = "inflation")]
 const PACKAGE_INFLATION: &str = "nibiru.inflation.v1";
-const PACKAGE_DEVGAS: &str = "nibiru.devgas.v1";
+#[cfg(feature = "oracle")]
+const PACKAGE_ORACLE: &str = "nibiru.oracle.v1";
+#[cfg(feature = "perp")]
+const PACKAGE_PERP: &str = "nibiru.perp.v2";
+#[cfg(feature = "spot")]
+const PACKAGE_SPOT: &str = "nibiru.spot.v1";
+#[cfg(feature = "sudo")]
 const PACKAGE_SUDO: &str = "nibiru.sudo.v1";
-const PACKAGE_EVM: &str = "eth.evm.v1";
+#[cfg(feature = "tokenfactory")]
+const PACKAGE_TOKENFACTORY: &str = "nibiru.tokenfactory.v1";
 
-// TOKENFACTORY tx msg
+// eth.evm.v1
 
-impl Name for nibiru::tokenfactory::MsgCreateDenom {
-    const NAME: &'static str = "MsgCreateDenom";
-    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
-}
-impl Name for nibiru::tokenfactory::MsgChangeAdmin {
-    const NAME: &'static str = "MsgChangeAdmin";
-    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+#[cfg(feature = "evm")]
+impl Name for eth::evm::AccessListTx {
+    const NAME: &'static str = "AccessListTx";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::tokenfactory::MsgUpdateModuleParams {
-    const NAME: &'static str = "MsgUpdateModuleParams";
-    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::AccessTuple {
+    const NAME: &'static str = "AccessTuple";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::tokenfactory::MsgMint {
-    const NAME: &'static str = "MsgMint";
-    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::DynamicFeeTx {
+    const NAME: &'static str = "DynamicFeeTx";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::tokenfactory::MsgBurn {
-    const NAME: &'static str = "MsgBurn";
-    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::EstimateGasResponse {
+    const NAME: &'static str = "EstimateGasResponse";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::tokenfactory::MsgSetDenomMetadata {
-    const NAME: &'static str = "MsgSetDenomMetadata";
-    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::EthCallRequest {
+    const NAME: &'static str = "EthCallRequest";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
 
-// TOKENFACTORY query
+#[cfg(feature = "evm")]
+impl Name for eth::evm::EventBlockBloom {
+    const NAME: &'static str = "EventBlockBloom";
+    const PACKAGE: &'static str = PACKAGE_EVM;
+}
 
-impl Name for nibiru::tokenfactory::QueryParamsRequest {
-    const NAME: &'static str = "QueryParamsRequest";
-    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+#[cfg(feature = "evm")]
+impl Name for eth::evm::EventContractDeployed {
+    const NAME: &'static str = "EventContractDeployed";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::tokenfactory::QueryDenomsRequest {
-    const NAME: &'static str = "QueryDenomsRequest";
-    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::EventContractExecuted {
+    const NAME: &'static str = "EventContractExecuted";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::tokenfactory::QueryDenomInfoRequest {
-    const NAME: &'static str = "QueryDenomInfoRequest";
-    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::EventConvertCoinToEvm {
+    const NAME: &'static str = "EventConvertCoinToEvm";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
 
-// EPOCHS query
+#[cfg(feature = "evm")]
+impl Name for eth::evm::EventEthereumTx {
+    const NAME: &'static str = "EventEthereumTx";
+    const PACKAGE: &'static str = PACKAGE_EVM;
+}
 
-impl Name for nibiru::epochs::QueryEpochInfosRequest {
-    const NAME: &'static str = "QueryEpochInfosRequest";
-    const PACKAGE: &'static str = PACKAGE_EPOCHS;
+#[cfg(feature = "evm")]
+impl Name for eth::evm::EventFunTokenCreated {
+    const NAME: &'static str = "EventFunTokenCreated";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::epochs::QueryCurrentEpochRequest {
-    const NAME: &'static str = "QueryCurrentEpochRequest";
-    const PACKAGE: &'static str = PACKAGE_EPOCHS;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::EventTransfer {
+    const NAME: &'static str = "EventTransfer";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
 
-// ORACLE tx msg
+#[cfg(feature = "evm")]
+impl Name for eth::evm::EventTxLog {
+    const NAME: &'static str = "EventTxLog";
+    const PACKAGE: &'static str = PACKAGE_EVM;
+}
 
-impl Name for nibiru::oracle::MsgEditOracleParams {
-    const NAME: &'static str = "MsgEditOracleParams";
-    const PACKAGE: &'static str = PACKAGE_ORACLE;
+#[cfg(feature = "evm")]
+impl Name for eth::evm::ExtensionOptionsEthereumTx {
+    const NAME: &'static str = "ExtensionOptionsEthereumTx";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
 
-// ORACLE query
+#[cfg(feature = "evm")]
+impl Name for eth::evm::FunToken {
+    const NAME: &'static str = "FunToken";
+    const PACKAGE: &'static str = PACKAGE_EVM;
+}
 
-impl Name for nibiru::oracle::QueryExchangeRateRequest {
-    const NAME: &'static str = "QueryExchangeRateRequest";
-    const PACKAGE: &'static str = PACKAGE_ORACLE;
+#[cfg(feature = "evm")]
+impl Name for eth::evm::GenesisAccount {
+    const NAME: &'static str = "GenesisAccount";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-// TODO: This type exists but was not exported by the protos. Why?
-// impl Name for nibiru::oracle::QueryExchangeRateTwapRequest {
-//     const NAME: &'static str = "QueryExchangeRateTwapRequest";
-//     const PACKAGE: &'static str = PACKAGE_ORACLE;
-// }
-impl Name for nibiru::oracle::QueryExchangeRatesRequest {
-    const NAME: &'static str = "QueryExchangeRatesRequest";
-    const PACKAGE: &'static str = PACKAGE_ORACLE;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::oracle::QueryActivesRequest {
-    const NAME: &'static str = "QueryActivesRequest";
-    const PACKAGE: &'static str = PACKAGE_ORACLE;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::LegacyTx {
+    const NAME: &'static str = "LegacyTx";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::oracle::QueryVoteTargetsRequest {
-    const NAME: &'static str = "QueryVoteTargetsRequest";
-    const PACKAGE: &'static str = PACKAGE_ORACLE;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::Log {
+    const NAME: &'static str = "Log";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::oracle::QueryFeederDelegationRequest {
-    const NAME: &'static str = "QueryFeederDelegationRequest";
-    const PACKAGE: &'static str = PACKAGE_ORACLE;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::MsgConvertCoinToEvm {
+    const NAME: &'static str = "MsgConvertCoinToEvm";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::oracle::QueryMissCounterRequest {
-    const NAME: &'static str = "QueryMissCounterRequest";
-    const PACKAGE: &'static str = PACKAGE_ORACLE;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::MsgConvertCoinToEvmResponse {
+    const NAME: &'static str = "MsgConvertCoinToEvmResponse";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::oracle::QueryAggregatePrevoteRequest {
-    const NAME: &'static str = "QueryAggregatePrevoteRequest";
-    const PACKAGE: &'static str = PACKAGE_ORACLE;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::MsgCreateFunToken {
+    const NAME: &'static str = "MsgCreateFunToken";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::oracle::QueryAggregatePrevotesRequest {
-    const NAME: &'static str = "QueryAggregatePrevotesRequest";
-    const PACKAGE: &'static str = PACKAGE_ORACLE;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::MsgCreateFunTokenResponse {
+    const NAME: &'static str = "MsgCreateFunTokenResponse";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::oracle::QueryAggregateVoteRequest {
-    const NAME: &'static str = "QueryAggregateVoteRequest";
-    const PACKAGE: &'static str = PACKAGE_ORACLE;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::MsgEthereumTx {
+    const NAME: &'static str = "MsgEthereumTx";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::oracle::QueryAggregateVotesRequest {
-    const NAME: &'static str = "QueryAggregateVotesRequest";
-    const PACKAGE: &'static str = PACKAGE_ORACLE;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::MsgEthereumTxResponse {
+    const NAME: &'static str = "MsgEthereumTxResponse";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::oracle::QueryParamsRequest {
-    const NAME: &'static str = "QueryParamsRequest";
-    const PACKAGE: &'static str = PACKAGE_ORACLE;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::MsgUpdateParams {
+    const NAME: &'static str = "MsgUpdateParams";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
 
-// SPOT query
+#[cfg(feature = "evm")]
+impl Name for eth::evm::MsgUpdateParamsResponse {
+    const NAME: &'static str = "MsgUpdateParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_EVM;
+}
 
-impl Name for nibiru::spot::QueryParamsRequest {
-    const NAME: &'static str = "QueryParamsRequest";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+#[cfg(feature = "evm")]
+impl Name for eth::evm::Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::spot::QueryPoolNumberRequest {
-    const NAME: &'static str = "QueryPoolNumberRequest";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryBalanceRequest {
+    const NAME: &'static str = "QueryBalanceRequest";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::spot::QueryPoolRequest {
-    const NAME: &'static str = "QueryPoolRequest";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryBalanceResponse {
+    const NAME: &'static str = "QueryBalanceResponse";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::spot::QueryPoolsRequest {
-    const NAME: &'static str = "QueryPoolsRequest";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryBaseFeeRequest {
+    const NAME: &'static str = "QueryBaseFeeRequest";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::spot::QueryPoolParamsRequest {
-    const NAME: &'static str = "QueryPoolParamsRequest";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryBaseFeeResponse {
+    const NAME: &'static str = "QueryBaseFeeResponse";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::spot::QueryNumPoolsRequest {
-    const NAME: &'static str = "QueryNumPoolsRequest";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryCodeRequest {
+    const NAME: &'static str = "QueryCodeRequest";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::spot::QueryTotalLiquidityRequest {
-    const NAME: &'static str = "QueryTotalLiquidityRequest";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryCodeResponse {
+    const NAME: &'static str = "QueryCodeResponse";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::spot::QueryTotalPoolLiquidityRequest {
-    const NAME: &'static str = "QueryTotalPoolLiquidityRequest";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryEthAccountRequest {
+    const NAME: &'static str = "QueryEthAccountRequest";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::spot::QueryTotalSharesRequest {
-    const NAME: &'static str = "QueryTotalSharesRequest";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryEthAccountResponse {
+    const NAME: &'static str = "QueryEthAccountResponse";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::spot::QuerySpotPriceRequest {
-    const NAME: &'static str = "QuerySpotPriceRequest";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryFunTokenMappingRequest {
+    const NAME: &'static str = "QueryFunTokenMappingRequest";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::spot::QuerySwapExactAmountInRequest {
-    const NAME: &'static str = "QuerySwapExactAmountInRequest";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryFunTokenMappingResponse {
+    const NAME: &'static str = "QueryFunTokenMappingResponse";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::spot::QuerySwapExactAmountOutRequest {
-    const NAME: &'static str = "QuerySwapExactAmountOutRequest";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::spot::QueryJoinExactAmountInRequest {
-    const NAME: &'static str = "QueryJoinExactAmountInRequest";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::spot::QueryJoinExactAmountOutRequest {
-    const NAME: &'static str = "QueryJoinExactAmountOutRequest";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryStorageRequest {
+    const NAME: &'static str = "QueryStorageRequest";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::spot::QueryExitExactAmountInRequest {
-    const NAME: &'static str = "QueryExitExactAmountInRequest";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryStorageResponse {
+    const NAME: &'static str = "QueryStorageResponse";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::spot::QueryExitExactAmountOutRequest {
-    const NAME: &'static str = "QueryExitExactAmountOutRequest";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryTraceBlockRequest {
+    const NAME: &'static str = "QueryTraceBlockRequest";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
 
-// SPOT tx msg
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryTraceBlockResponse {
+    const NAME: &'static str = "QueryTraceBlockResponse";
+    const PACKAGE: &'static str = PACKAGE_EVM;
+}
 
-impl Name for nibiru::spot::MsgCreatePool {
-    const NAME: &'static str = "MsgCreatePool";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryTraceTxRequest {
+    const NAME: &'static str = "QueryTraceTxRequest";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::spot::MsgJoinPool {
-    const NAME: &'static str = "MsgJoinPool";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryTraceTxResponse {
+    const NAME: &'static str = "QueryTraceTxResponse";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::spot::MsgExitPool {
-    const NAME: &'static str = "MsgExitPool";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryTxLogsRequest {
+    const NAME: &'static str = "QueryTxLogsRequest";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::spot::MsgSwapAssets {
-    const NAME: &'static str = "MsgSwapAssets";
-    const PACKAGE: &'static str = PACKAGE_SPOT;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryTxLogsResponse {
+    const NAME: &'static str = "QueryTxLogsResponse";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
 
-// PERP tx msg
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryValidatorAccountRequest {
+    const NAME: &'static str = "QueryValidatorAccountRequest";
+    const PACKAGE: &'static str = PACKAGE_EVM;
+}
 
-impl Name for nibiru::perp::MsgRemoveMargin {
-    const NAME: &'static str = "MsgRemoveMargin";
-    const PACKAGE: &'static str = PACKAGE_PERP;
+#[cfg(feature = "evm")]
+impl Name for eth::evm::QueryValidatorAccountResponse {
+    const NAME: &'static str = "QueryValidatorAccountResponse";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::perp::MsgAddMargin {
-    const NAME: &'static str = "MsgAddMargin";
-    const PACKAGE: &'static str = PACKAGE_PERP;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::State {
+    const NAME: &'static str = "State";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::perp::MsgMultiLiquidate {
-    const NAME: &'static str = "MsgMultiLiquidate";
-    const PACKAGE: &'static str = PACKAGE_PERP;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::TraceConfig {
+    const NAME: &'static str = "TraceConfig";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
-impl Name for nibiru::perp::MsgMarketOrder {
-    const NAME: &'static str = "MsgMarketOrder";
-    const PACKAGE: &'static str = PACKAGE_PERP;
-}
-impl Name for nibiru::perp::MsgClosePosition {
-    const NAME: &'static str = "MsgClosePosition";
-    const PACKAGE: &'static str = PACKAGE_PERP;
-}
-impl Name for nibiru::perp::MsgPartialClose {
-    const NAME: &'static str = "MsgPartialClose";
-    const PACKAGE: &'static str = PACKAGE_PERP;
-}
-impl Name for nibiru::perp::MsgDonateToEcosystemFund {
-    const NAME: &'static str = "MsgDonateToEcosystemFund";
-    const PACKAGE: &'static str = PACKAGE_PERP;
-}
-impl Name for nibiru::perp::MsgSettlePosition {
-    const NAME: &'static str = "MsgSettlePosition";
-    const PACKAGE: &'static str = PACKAGE_PERP;
-}
-impl Name for nibiru::perp::MsgChangeCollateralDenom {
-    const NAME: &'static str = "MsgChangeCollateralDenom";
-    const PACKAGE: &'static str = PACKAGE_PERP;
-}
-impl Name for nibiru::perp::MsgAllocateEpochRebates {
-    const NAME: &'static str = "MsgAllocateEpochRebates";
-    const PACKAGE: &'static str = PACKAGE_PERP;
-}
-impl Name for nibiru::perp::MsgWithdrawEpochRebates {
-    const NAME: &'static str = "MsgWithdrawEpochRebates";
-    const PACKAGE: &'static str = PACKAGE_PERP;
-}
-impl Name for nibiru::perp::MsgShiftPegMultiplier {
-    const NAME: &'static str = "MsgShiftPegMultiplier";
-    const PACKAGE: &'static str = PACKAGE_PERP;
-}
-impl Name for nibiru::perp::MsgShiftSwapInvariant {
-    const NAME: &'static str = "MsgShiftSwapInvariant";
-    const PACKAGE: &'static str = PACKAGE_PERP;
-}
-impl Name for nibiru::perp::MsgWithdrawFromPerpFund {
-    const NAME: &'static str = "MsgWithdrawFromPerpFund";
-    const PACKAGE: &'static str = PACKAGE_PERP;
-}
-impl Name for nibiru::perp::MsgCloseMarket {
-    const NAME: &'static str = "MsgCloseMarket";
-    const PACKAGE: &'static str = PACKAGE_PERP;
+
+#[cfg(feature = "evm")]
+impl Name for eth::evm::TracerConfig {
+    const NAME: &'static str = "TracerConfig";
+    const PACKAGE: &'static str = PACKAGE_EVM;
 }
 
-// PERP query
+// eth.types.v1
 
-impl Name for nibiru::perp::QueryPositionRequest {
-    const NAME: &'static str = "QueryPositionRequest";
-    const PACKAGE: &'static str = PACKAGE_PERP;
-}
-impl Name for nibiru::perp::QueryPositionsRequest {
-    const NAME: &'static str = "QueryPositionsRequest";
-    const PACKAGE: &'static str = PACKAGE_PERP;
-}
-impl Name for nibiru::perp::QueryPositionStoreRequest {
-    const NAME: &'static str = "QueryPositionStoreRequest";
-    const PACKAGE: &'static str = PACKAGE_PERP;
-}
-impl Name for nibiru::perp::QueryModuleAccountsRequest {
-    const NAME: &'static str = "QueryModuleAccountsRequest";
-    const PACKAGE: &'static str = PACKAGE_PERP;
+#[cfg(feature = "evm")]
+impl Name for eth::types::EthAccount {
+    const NAME: &'static str = "EthAccount";
+    const PACKAGE: &'static str = PACKAGE_TYPES;
 }
-impl Name for nibiru::perp::QueryMarketsRequest {
-    const NAME: &'static str = "QueryMarketsRequest";
-    const PACKAGE: &'static str = PACKAGE_PERP;
+
+#[cfg(feature = "evm")]
+impl Name for eth::types::TxResult {
+    const NAME: &'static str = "TxResult";
+    const PACKAGE: &'static str = PACKAGE_TYPES;
 }
-impl Name for nibiru::perp::QueryCollateralRequest {
-    const NAME: &'static str = "QueryCollateralRequest";
-    const PACKAGE: &'static str = PACKAGE_PERP;
+
+// nibiru.devgas.v1
+
+#[cfg(feature = "devgas")]
+impl Name for nibiru::devgas::EventCancelDevGas {
+    const NAME: &'static str = "EventCancelDevGas";
+    const PACKAGE: &'static str = PACKAGE_DEVGAS;
 }
 
-// INFLATION query
+#[cfg(feature = "devgas")]
+impl Name for nibiru::devgas::EventPayoutDevGas {
+    const NAME: &'static str = "EventPayoutDevGas";
+    const PACKAGE: &'static str = PACKAGE_DEVGAS;
+}
 
-impl Name for nibiru::inflation::QueryPeriodRequest {
-    const NAME: &'static str = "QueryPeriodRequest";
-    const PACKAGE: &'static str = PACKAGE_INFLATION;
+#[cfg(feature = "devgas")]
+impl Name for nibiru::devgas::EventRegisterDevGas {
+    const NAME: &'static str = "EventRegisterDevGas";
+    const PACKAGE: &'static str = PACKAGE_DEVGAS;
 }
-impl Name for nibiru::inflation::QueryEpochMintProvisionRequest {
-    const NAME: &'static str = "QueryEpochMintProvisionRequest";
-    const PACKAGE: &'static str = PACKAGE_INFLATION;
+
+#[cfg(feature = "devgas")]
+impl Name for nibiru::devgas::EventUpdateDevGas {
+    const NAME: &'static str = "EventUpdateDevGas";
+    const PACKAGE: &'static str = PACKAGE_DEVGAS;
 }
-impl Name for nibiru::inflation::QuerySkippedEpochsRequest {
-    const NAME: &'static str = "QuerySkippedEpochsRequest";
-    const PACKAGE: &'static str = PACKAGE_INFLATION;
+
+#[cfg(feature = "devgas")]
+impl Name for nibiru::devgas::FeeShare {
+    const NAME: &'static str = "FeeShare";
+    const PACKAGE: &'static str = PACKAGE_DEVGAS;
 }
-impl Name for nibiru::inflation::QueryCirculatingSupplyRequest {
-    const NAME: &'static str = "QueryCirculatingSupplyRequest";
-    const PACKAGE: &'static str = PACKAGE_INFLATION;
+
+#[cfg(feature = "devgas")]
+impl Name for nibiru::devgas::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_DEVGAS;
 }
-impl Name for nibiru::inflation::QueryInflationRateRequest {
-    const NAME: &'static str = "QueryInflationRateRequest";
-    const PACKAGE: &'static str = PACKAGE_INFLATION;
+
+#[cfg(feature = "devgas")]
+impl Name for nibiru::devgas::ModuleParams {
+    const NAME: &'static str = "ModuleParams";
+    const PACKAGE: &'static str = PACKAGE_DEVGAS;
 }
-impl Name for nibiru::inflation::QueryParamsRequest {
-    const NAME: &'static str = "QueryParamsRequest";
-    const PACKAGE: &'static str = PACKAGE_INFLATION;
+
+#[cfg(feature = "devgas")]
+impl Name for nibiru::devgas::MsgCancelFeeShare {
+    const NAME: &'static str = "MsgCancelFeeShare";
+    const PACKAGE: &'static str = PACKAGE_DEVGAS;
 }
 
-// DEVGAS tx msg
+#[cfg(feature = "devgas")]
+impl Name for nibiru::devgas::MsgCancelFeeShareResponse {
+    const NAME: &'static str = "MsgCancelFeeShareResponse";
+    const PACKAGE: &'static str = PACKAGE_DEVGAS;
+}
 
+#[cfg(feature = "devgas")]
 impl Name for nibiru::devgas::MsgRegisterFeeShare {
     const NAME: &'static str = "MsgRegisterFeeShare";
     const PACKAGE: &'static str = PACKAGE_DEVGAS;
 }
+
+#[cfg(feature = "devgas")]
+impl Name for nibiru::devgas::MsgRegisterFeeShareResponse {
+    const NAME: &'static str = "MsgRegisterFeeShareResponse";
+    const PACKAGE: &'static str = PACKAGE_DEVGAS;
+}
+
+#[cfg(feature = "devgas")]
 impl Name for nibiru::devgas::MsgUpdateFeeShare {
     const NAME: &'static str = "MsgUpdateFeeShare";
     const PACKAGE: &'static str = PACKAGE_DEVGAS;
 }
-impl Name for nibiru::devgas::MsgCancelFeeShare {
-    const NAME: &'static str = "MsgCancelFeeShare";
+
+#[cfg(feature = "devgas")]
+impl Name for nibiru::devgas::MsgUpdateFeeShareResponse {
+    const NAME: &'static str = "MsgUpdateFeeShareResponse";
     const PACKAGE: &'static str = PACKAGE_DEVGAS;
 }
+
+#[cfg(feature = "devgas")]
 impl Name for nibiru::devgas::MsgUpdateParams {
     const NAME: &'static str = "MsgUpdateParams";
     const PACKAGE: &'static str = PACKAGE_DEVGAS;
 }
 
-// DEVGAS query
+#[cfg(feature = "devgas")]
+impl Name for nibiru::devgas::MsgUpdateParamsResponse {
+    const NAME: &'static str = "MsgUpdateParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_DEVGAS;
+}
+
+#[cfg(feature = "devgas")]
+impl Name for nibiru::devgas::QueryFeeShareRequest {
+    const NAME: &'static str = "QueryFeeShareRequest";
+    const PACKAGE: &'static str = PACKAGE_DEVGAS;
+}
+
+#[cfg(feature = "devgas")]
+impl Name for nibiru::devgas::QueryFeeShareResponse {
+    const NAME: &'static str = "QueryFeeShareResponse";
+    const PACKAGE: &'static str = PACKAGE_DEVGAS;
+}
+
+#[cfg(feature = "devgas")]
+impl Name for nibiru::devgas::QueryFeeSharesByWithdrawerRequest {
+    const NAME: &'static str = "QueryFeeSharesByWithdrawerRequest";
+    const PACKAGE: &'static str = PACKAGE_DEVGAS;
+}
 
+#[cfg(feature = "devgas")]
+impl Name for nibiru::devgas::QueryFeeSharesByWithdrawerResponse {
+    const NAME: &'static str = "QueryFeeSharesByWithdrawerResponse";
+    const PACKAGE: &'static str = PACKAGE_DEVGAS;
+}
+
+#[cfg(feature = "devgas")]
 impl Name for nibiru::devgas::QueryFeeSharesRequest {
     const NAME: &'static str = "QueryFeeSharesRequest";
     const PACKAGE: &'static str = PACKAGE_DEVGAS;
 }
-impl Name for nibiru::devgas::QueryFeeShareRequest {
-    const NAME: &'static str = "QueryFeeShareRequest";
+
+#[cfg(feature = "devgas")]
+impl Name for nibiru::devgas::QueryFeeSharesResponse {
+    const NAME: &'static str = "QueryFeeSharesResponse";
     const PACKAGE: &'static str = PACKAGE_DEVGAS;
 }
+
+#[cfg(feature = "devgas")]
 impl Name for nibiru::devgas::QueryParamsRequest {
     const NAME: &'static str = "QueryParamsRequest";
     const PACKAGE: &'static str = PACKAGE_DEVGAS;
 }
-impl Name for nibiru::devgas::QueryFeeSharesByWithdrawerRequest {
-    const NAME: &'static str = "QueryFeeSharesByWithdrawerRequest";
+
+#[cfg(feature = "devgas")]
+impl Name for nibiru::devgas::QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
     const PACKAGE: &'static str = PACKAGE_DEVGAS;
 }
 
-// SUDO tx msg
+// nibiru.epochs.v1
 
-impl Name for nibiru::sudo::MsgEditSudoers {
-    const NAME: &'static str = "MsgEditSudoers";
-    const PACKAGE: &'static str = PACKAGE_SUDO;
+#[cfg(feature = "epochs")]
+impl Name for nibiru::epochs::EpochInfo {
+    const NAME: &'static str = "EpochInfo";
+    const PACKAGE: &'static str = PACKAGE_EPOCHS;
 }
-impl Name for nibiru::sudo::MsgChangeRoot {
-    const NAME: &'static str = "MsgChangeRoot";
-    const PACKAGE: &'static str = PACKAGE_SUDO;
+
+#[cfg(feature = "epochs")]
+impl Name for nibiru::epochs::EventEpochEnd {
+    const NAME: &'static str = "EventEpochEnd";
+    const PACKAGE: &'static str = PACKAGE_EPOCHS;
 }
 
-// SUDO query
+#[cfg(feature = "epochs")]
+impl Name for nibiru::epochs::EventEpochStart {
+    const NAME: &'static str = "EventEpochStart";
+    const PACKAGE: &'static str = PACKAGE_EPOCHS;
+}
 
-impl Name for nibiru::sudo::QuerySudoersRequest {
-    const NAME: &'static str = "QuerySudoersRequest";
-    const PACKAGE: &'static str = PACKAGE_SUDO;
+#[cfg(feature = "epochs")]
+impl Name for nibiru::epochs::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_EPOCHS;
 }
 
-// EVM tx msg
+#[cfg(feature = "epochs")]
+impl Name for nibiru::epochs::QueryCurrentEpochRequest {
+    const NAME: &'static str = "QueryCurrentEpochRequest";
+    const PACKAGE: &'static str = PACKAGE_EPOCHS;
+}
 
-impl Name for eth::evm::MsgEthereumTx {
-    const NAME: &'static str = "MsgEthereumTx";
-    const PACKAGE: &'static str = PACKAGE_EVM;
+#[cfg(feature = "epochs")]
+impl Name for nibiru::epochs::QueryCurrentEpochResponse {
+    const NAME: &'static str = "QueryCurrentEpochResponse";
+    const PACKAGE: &'static str = PACKAGE_EPOCHS;
 }
-impl Name for eth::evm::MsgUpdateParams {
-    const NAME: &'static str = "MsgUpdateParams";
-    const PACKAGE: &'static str = PACKAGE_EVM;
+
+#[cfg(feature = "epochs")]
+impl Name for nibiru::epochs::QueryEpochInfosRequest {
+    const NAME: &'static str = "QueryEpochInfosRequest";
+    const PACKAGE: &'static str = PACKAGE_EPOCHS;
 }
-impl Name for eth::evm::MsgCreateFunToken {
-    const NAME: &'static str = "MsgCreateFunToken";
-    const PACKAGE: &'static str = PACKAGE_EVM;
+
+#[cfg(feature = "epochs")]
+impl Name for nibiru::epochs::QueryEpochInfosResponse {
+    const NAME: &'static str = "QueryEpochInfosResponse";
+    const PACKAGE: &'static str = PACKAGE_EPOCHS;
 }
-impl Name for eth::evm::MsgConvertCoinToEvm {
-    const NAME: &'static str = "MsgConvertCoinToEvm";
-    const PACKAGE: &'static str = PACKAGE_EVM;
+
+// nibiru.genmsg.v1
+
+#[cfg(feature = "genmsg")]
+impl Name for nibiru::genmsg::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_GENMSG;
 }
 
-#[cfg(test)]
-pub mod tests {
-
-    use crate::{
-        errors::TestResult,
-        proto::{
-            cosmos, eth,
-            nibiru::{self},
-            NibiruProstMsg, NibiruStargateMsg, NibiruStargateQuery,
-        },
-    };
-
-    use cosmwasm_std as cw;
-
-    #[test]
-    fn stargate_tokenfactory_msgs() -> TestResult {
-        let test_cases: Vec<(&str, cw::CosmosMsg)> = vec![
-            (
-                "/nibiru.tokenfactory.v1.MsgMint",
-                nibiru::tokenfactory::MsgMint::default().into_stargate_msg(),
-            ),
-            (
-                "/nibiru.tokenfactory.v1.MsgBurn",
-                nibiru::tokenfactory::MsgBurn::default().into_stargate_msg(),
-            ),
-            (
-                "/nibiru.tokenfactory.v1.MsgChangeAdmin",
-                nibiru::tokenfactory::MsgChangeAdmin::default()
-                    .into_stargate_msg(),
-            ),
-            (
-                "/nibiru.tokenfactory.v1.MsgSetDenomMetadata",
-                nibiru::tokenfactory::MsgSetDenomMetadata::default()
-                    .into_stargate_msg(),
-            ),
-            (
-                "/nibiru.tokenfactory.v1.MsgUpdateModuleParams",
-                nibiru::tokenfactory::MsgUpdateModuleParams::default()
-                    .into_stargate_msg(),
-            ),
-        ];
-
-        for test_case in test_cases {
-            let (tc_type_url, stargate_msg) = test_case;
-            if let cw::CosmosMsg::Stargate {
-                type_url,
-                value: _value,
-            } = stargate_msg.clone()
-            {
-                assert_eq!(tc_type_url, type_url)
-            } else {
-                panic!(
-                    "Expected CosmosMsg::Stargate from CosmosMsg: {:#?}",
-                    stargate_msg
-                )
-            }
-        }
-
-        println!(
-            "prost::Name corresponding to a CosmosMsg should error if we \
-            try converting it to QueryRequest::Stargate"
-        );
-        let pb_msg = nibiru::tokenfactory::MsgSetDenomMetadata::default();
-        pb_msg
-            .into_stargate_query()
-            .expect_err("query is not a Msg");
-
-        Ok(())
-    }
+// nibiru.inflation.v1
 
-    /// Uses values produced from the chain's protobuf marshaler to verify that
-    /// our `nibiru-std` types encode the same way.
-    ///
-    /// ```
-    /// // For example, one test case came from:
-    /// fmt.Printf("%v\n", encodingConfig.Marshaler.MustMarshal(&tokenfactorytypes.MsgCreateDenom{
-    ///     Sender:   "sender",
-    ///     Subdenom: "subdenom",
-    /// }))
-    /// // which outputs "[10 6 115 101 110 100 101 114 18 8 115 117 98 100 101 110 111 109]"
-    /// ```
-    #[test]
-    fn stargate_encoding() -> TestResult {
-        let test_cases: Vec<(Box<dyn NibiruProstMsg>, Vec<u8>)> = vec![
-            (
-                Box::new(nibiru::tokenfactory::MsgCreateDenom {
-                            sender: String::from("sender"),
-                            subdenom: String::from("subdenom"),
-                        }),
-                parse_byte_string(
-                            "[10 6 115 101 110 100 101 114 18 8 115 117 98 100 101 110 111 109]",
-                        ),
-            ),
-            (
-                Box::new(nibiru::tokenfactory::MsgMint {
-                    sender: String::from("sender"),
-                    coin: Some(cosmos::base::v1beta1::Coin { denom: String::from("abcxyz"), amount: String::from("123") }),
-                    mint_to: String::from("mint_to") }),
-                vec![10u8, 6, 115, 101, 110, 100, 101, 114, 18, 13, 10, 6, 97, 98, 99, 120, 121, 122, 18, 3, 49, 50, 51, 26, 7, 109, 105, 110, 116, 95, 116, 111]
-            ),
-        ];
-
-        for (pb_msg, want_bz) in &test_cases {
-            println!("pb_msg {{ value: {:?} }}", pb_msg.to_bytes(),);
-            assert_eq!(*want_bz, pb_msg.to_bytes(), "pb_msg: {pb_msg:?}");
-        }
-
-        Ok(())
-    }
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::EventInflationDistribution {
+    const NAME: &'static str = "EventInflationDistribution";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
 
-    #[test]
-    fn stargate_evm_msgs() -> TestResult {
-        let test_cases: Vec<(&str, cw::CosmosMsg)> = vec![
-            (
-                "/eth.evm.v1.MsgEthereumTx",
-                eth::evm::MsgEthereumTx::default().into_stargate_msg(),
-            ),
-            (
-                "/eth.evm.v1.MsgUpdateParams",
-                eth::evm::MsgUpdateParams::default().into_stargate_msg(),
-            ),
-            (
-                "/eth.evm.v1.MsgCreateFunToken",
-                eth::evm::MsgCreateFunToken::default().into_stargate_msg(),
-            ),
-            (
-                "/eth.evm.v1.MsgConvertCoinToEvm",
-                eth::evm::MsgConvertCoinToEvm::default().into_stargate_msg(),
-            ),
-        ];
-
-        for test_case in test_cases {
-            let (tc_type_url, stargate_msg) = test_case;
-            if let cw::CosmosMsg::Stargate {
-                type_url,
-                value: _value,
-            } = stargate_msg.clone()
-            {
-                assert_eq!(tc_type_url, type_url)
-            } else {
-                panic!(
-                    "Expected CosmosMsg::Stargate from CosmosMsg: {:#?}",
-                    stargate_msg
-                )
-            }
-        }
-
-        Ok(())
-    }
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::InflationDistribution {
+    const NAME: &'static str = "InflationDistribution";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
 
-    fn parse_byte_string(s: &str) -> Vec<u8> {
-        s.trim_start_matches('[')
-            .trim_end_matches(']')
-            .split_whitespace()
-            .filter_map(|byte| byte.parse::<u8>().ok())
-            .collect()
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::MsgBurn {
+    const NAME: &'static str = "MsgBurn";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::MsgBurnResponse {
+    const NAME: &'static str = "MsgBurnResponse";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::MsgEditInflationParams {
+    const NAME: &'static str = "MsgEditInflationParams";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::MsgEditInflationParamsResponse {
+    const NAME: &'static str = "MsgEditInflationParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::MsgToggleInflation {
+    const NAME: &'static str = "MsgToggleInflation";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::MsgToggleInflationResponse {
+    const NAME: &'static str = "MsgToggleInflationResponse";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::QueryCirculatingSupplyRequest {
+    const NAME: &'static str = "QueryCirculatingSupplyRequest";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::QueryCirculatingSupplyResponse {
+    const NAME: &'static str = "QueryCirculatingSupplyResponse";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::QueryEpochMintProvisionRequest {
+    const NAME: &'static str = "QueryEpochMintProvisionRequest";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::QueryEpochMintProvisionResponse {
+    const NAME: &'static str = "QueryEpochMintProvisionResponse";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::QueryInflationRateRequest {
+    const NAME: &'static str = "QueryInflationRateRequest";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::QueryInflationRateResponse {
+    const NAME: &'static str = "QueryInflationRateResponse";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::QueryPeriodRequest {
+    const NAME: &'static str = "QueryPeriodRequest";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::QueryPeriodResponse {
+    const NAME: &'static str = "QueryPeriodResponse";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::QuerySkippedEpochsRequest {
+    const NAME: &'static str = "QuerySkippedEpochsRequest";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+#[cfg(feature = "inflation")]
+impl Name for nibiru::inflation::QuerySkippedEpochsResponse {
+    const NAME: &'static str = "QuerySkippedEpochsResponse";
+    const PACKAGE: &'static str = PACKAGE_INFLATION;
+}
+
+// nibiru.oracle.v1
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::AggregateExchangeRatePrevote {
+    const NAME: &'static str = "AggregateExchangeRatePrevote";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::AggregateExchangeRateVote {
+    const NAME: &'static str = "AggregateExchangeRateVote";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::EventAggregatePrevote {
+    const NAME: &'static str = "EventAggregatePrevote";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::EventAggregateVote {
+    const NAME: &'static str = "EventAggregateVote";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::EventDelegateFeederConsent {
+    const NAME: &'static str = "EventDelegateFeederConsent";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::EventPriceUpdate {
+    const NAME: &'static str = "EventPriceUpdate";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::EventValidatorPerformance {
+    const NAME: &'static str = "EventValidatorPerformance";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::ExchangeRateAtBlock {
+    const NAME: &'static str = "ExchangeRateAtBlock";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::ExchangeRateTuple {
+    const NAME: &'static str = "ExchangeRateTuple";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::FeederDelegation {
+    const NAME: &'static str = "FeederDelegation";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::MissCounter {
+    const NAME: &'static str = "MissCounter";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::MsgAggregateExchangeRatePrevote {
+    const NAME: &'static str = "MsgAggregateExchangeRatePrevote";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::MsgAggregateExchangeRatePrevoteResponse {
+    const NAME: &'static str = "MsgAggregateExchangeRatePrevoteResponse";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::MsgAggregateExchangeRateVote {
+    const NAME: &'static str = "MsgAggregateExchangeRateVote";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::MsgAggregateExchangeRateVoteResponse {
+    const NAME: &'static str = "MsgAggregateExchangeRateVoteResponse";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::MsgDelegateFeedConsent {
+    const NAME: &'static str = "MsgDelegateFeedConsent";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::MsgDelegateFeedConsentResponse {
+    const NAME: &'static str = "MsgDelegateFeedConsentResponse";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::MsgEditOracleParams {
+    const NAME: &'static str = "MsgEditOracleParams";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::MsgEditOracleParamsResponse {
+    const NAME: &'static str = "MsgEditOracleParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::OracleParamsMsg {
+    const NAME: &'static str = "OracleParamsMsg";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::PriceSnapshot {
+    const NAME: &'static str = "PriceSnapshot";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryActivesRequest {
+    const NAME: &'static str = "QueryActivesRequest";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryActivesResponse {
+    const NAME: &'static str = "QueryActivesResponse";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryAggregatePrevoteRequest {
+    const NAME: &'static str = "QueryAggregatePrevoteRequest";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryAggregatePrevoteResponse {
+    const NAME: &'static str = "QueryAggregatePrevoteResponse";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryAggregatePrevotesRequest {
+    const NAME: &'static str = "QueryAggregatePrevotesRequest";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryAggregatePrevotesResponse {
+    const NAME: &'static str = "QueryAggregatePrevotesResponse";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryAggregateVoteRequest {
+    const NAME: &'static str = "QueryAggregateVoteRequest";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryAggregateVoteResponse {
+    const NAME: &'static str = "QueryAggregateVoteResponse";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryAggregateVotesRequest {
+    const NAME: &'static str = "QueryAggregateVotesRequest";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryAggregateVotesResponse {
+    const NAME: &'static str = "QueryAggregateVotesResponse";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryExchangeRateRequest {
+    const NAME: &'static str = "QueryExchangeRateRequest";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryExchangeRateResponse {
+    const NAME: &'static str = "QueryExchangeRateResponse";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryExchangeRatesRequest {
+    const NAME: &'static str = "QueryExchangeRatesRequest";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryExchangeRatesResponse {
+    const NAME: &'static str = "QueryExchangeRatesResponse";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryFeederDelegationRequest {
+    const NAME: &'static str = "QueryFeederDelegationRequest";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryFeederDelegationResponse {
+    const NAME: &'static str = "QueryFeederDelegationResponse";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryMissCounterRequest {
+    const NAME: &'static str = "QueryMissCounterRequest";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryMissCounterResponse {
+    const NAME: &'static str = "QueryMissCounterResponse";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryVoteTargetsRequest {
+    const NAME: &'static str = "QueryVoteTargetsRequest";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::QueryVoteTargetsResponse {
+    const NAME: &'static str = "QueryVoteTargetsResponse";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+#[cfg(feature = "oracle")]
+impl Name for nibiru::oracle::Rewards {
+    const NAME: &'static str = "Rewards";
+    const PACKAGE: &'static str = PACKAGE_ORACLE;
+}
+
+// nibiru.perp.v2
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::AccountWithBalance {
+    const NAME: &'static str = "AccountWithBalance";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::Amm {
+    const NAME: &'static str = "Amm";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::AmmMarket {
+    const NAME: &'static str = "AmmMarket";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::AmmUpdatedEvent {
+    const NAME: &'static str = "AmmUpdatedEvent";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::DnrAllocation {
+    const NAME: &'static str = "DnrAllocation";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::EventShiftPegMultiplier {
+    const NAME: &'static str = "EventShiftPegMultiplier";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::EventShiftSwapInvariant {
+    const NAME: &'static str = "EventShiftSwapInvariant";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::FundingRateChangedEvent {
+    const NAME: &'static str = "FundingRateChangedEvent";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::GenesisMarketLastVersion {
+    const NAME: &'static str = "GenesisMarketLastVersion";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::GenesisPosition {
+    const NAME: &'static str = "GenesisPosition";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::LiquidationFailedEvent {
+    const NAME: &'static str = "LiquidationFailedEvent";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::Market {
+    const NAME: &'static str = "Market";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MarketLastVersion {
+    const NAME: &'static str = "MarketLastVersion";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MarketUpdatedEvent {
+    const NAME: &'static str = "MarketUpdatedEvent";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgAddMargin {
+    const NAME: &'static str = "MsgAddMargin";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgAddMarginResponse {
+    const NAME: &'static str = "MsgAddMarginResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgAllocateEpochRebates {
+    const NAME: &'static str = "MsgAllocateEpochRebates";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgAllocateEpochRebatesResponse {
+    const NAME: &'static str = "MsgAllocateEpochRebatesResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgChangeCollateralDenom {
+    const NAME: &'static str = "MsgChangeCollateralDenom";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgChangeCollateralDenomResponse {
+    const NAME: &'static str = "MsgChangeCollateralDenomResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgCloseMarket {
+    const NAME: &'static str = "MsgCloseMarket";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgCloseMarketResponse {
+    const NAME: &'static str = "MsgCloseMarketResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgClosePosition {
+    const NAME: &'static str = "MsgClosePosition";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgClosePositionResponse {
+    const NAME: &'static str = "MsgClosePositionResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgDonateToEcosystemFund {
+    const NAME: &'static str = "MsgDonateToEcosystemFund";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgDonateToEcosystemFundResponse {
+    const NAME: &'static str = "MsgDonateToEcosystemFundResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgMarketOrder {
+    const NAME: &'static str = "MsgMarketOrder";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgMarketOrderResponse {
+    const NAME: &'static str = "MsgMarketOrderResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgMultiLiquidate {
+    const NAME: &'static str = "MsgMultiLiquidate";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgMultiLiquidateResponse {
+    const NAME: &'static str = "MsgMultiLiquidateResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgPartialClose {
+    const NAME: &'static str = "MsgPartialClose";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgPartialCloseResponse {
+    const NAME: &'static str = "MsgPartialCloseResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgRemoveMargin {
+    const NAME: &'static str = "MsgRemoveMargin";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgRemoveMarginResponse {
+    const NAME: &'static str = "MsgRemoveMarginResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgSettlePosition {
+    const NAME: &'static str = "MsgSettlePosition";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgShiftPegMultiplier {
+    const NAME: &'static str = "MsgShiftPegMultiplier";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgShiftPegMultiplierResponse {
+    const NAME: &'static str = "MsgShiftPegMultiplierResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgShiftSwapInvariant {
+    const NAME: &'static str = "MsgShiftSwapInvariant";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgShiftSwapInvariantResponse {
+    const NAME: &'static str = "MsgShiftSwapInvariantResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgWithdrawEpochRebates {
+    const NAME: &'static str = "MsgWithdrawEpochRebates";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgWithdrawEpochRebatesResponse {
+    const NAME: &'static str = "MsgWithdrawEpochRebatesResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgWithdrawFromPerpFund {
+    const NAME: &'static str = "MsgWithdrawFromPerpFund";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::MsgWithdrawFromPerpFundResponse {
+    const NAME: &'static str = "MsgWithdrawFromPerpFundResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::Position {
+    const NAME: &'static str = "Position";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::PositionChangedEvent {
+    const NAME: &'static str = "PositionChangedEvent";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::PositionLiquidatedEvent {
+    const NAME: &'static str = "PositionLiquidatedEvent";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::PositionSettledEvent {
+    const NAME: &'static str = "PositionSettledEvent";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::QueryCollateralRequest {
+    const NAME: &'static str = "QueryCollateralRequest";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::QueryCollateralResponse {
+    const NAME: &'static str = "QueryCollateralResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::QueryMarketsRequest {
+    const NAME: &'static str = "QueryMarketsRequest";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::QueryMarketsResponse {
+    const NAME: &'static str = "QueryMarketsResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::QueryModuleAccountsRequest {
+    const NAME: &'static str = "QueryModuleAccountsRequest";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::QueryModuleAccountsResponse {
+    const NAME: &'static str = "QueryModuleAccountsResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::QueryPositionRequest {
+    const NAME: &'static str = "QueryPositionRequest";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::QueryPositionResponse {
+    const NAME: &'static str = "QueryPositionResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::QueryPositionStoreRequest {
+    const NAME: &'static str = "QueryPositionStoreRequest";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::QueryPositionStoreResponse {
+    const NAME: &'static str = "QueryPositionStoreResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::QueryPositionsRequest {
+    const NAME: &'static str = "QueryPositionsRequest";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::QueryPositionsResponse {
+    const NAME: &'static str = "QueryPositionsResponse";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+#[cfg(feature = "perp")]
+impl Name for nibiru::perp::ReserveSnapshot {
+    const NAME: &'static str = "ReserveSnapshot";
+    const PACKAGE: &'static str = PACKAGE_PERP;
+}
+
+// nibiru.spot.v1
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::EventAssetsSwapped {
+    const NAME: &'static str = "EventAssetsSwapped";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::EventPoolCreated {
+    const NAME: &'static str = "EventPoolCreated";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::EventPoolExited {
+    const NAME: &'static str = "EventPoolExited";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::EventPoolJoined {
+    const NAME: &'static str = "EventPoolJoined";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::MsgCreatePool {
+    const NAME: &'static str = "MsgCreatePool";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::MsgCreatePoolResponse {
+    const NAME: &'static str = "MsgCreatePoolResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::MsgExitPool {
+    const NAME: &'static str = "MsgExitPool";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::MsgExitPoolResponse {
+    const NAME: &'static str = "MsgExitPoolResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::MsgJoinPool {
+    const NAME: &'static str = "MsgJoinPool";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::MsgJoinPoolResponse {
+    const NAME: &'static str = "MsgJoinPoolResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::MsgSwapAssets {
+    const NAME: &'static str = "MsgSwapAssets";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::MsgSwapAssetsResponse {
+    const NAME: &'static str = "MsgSwapAssetsResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::Pool {
+    const NAME: &'static str = "Pool";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::PoolAsset {
+    const NAME: &'static str = "PoolAsset";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::PoolParams {
+    const NAME: &'static str = "PoolParams";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryExitExactAmountInRequest {
+    const NAME: &'static str = "QueryExitExactAmountInRequest";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryExitExactAmountInResponse {
+    const NAME: &'static str = "QueryExitExactAmountInResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryExitExactAmountOutRequest {
+    const NAME: &'static str = "QueryExitExactAmountOutRequest";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryExitExactAmountOutResponse {
+    const NAME: &'static str = "QueryExitExactAmountOutResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryJoinExactAmountInRequest {
+    const NAME: &'static str = "QueryJoinExactAmountInRequest";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryJoinExactAmountInResponse {
+    const NAME: &'static str = "QueryJoinExactAmountInResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryJoinExactAmountOutRequest {
+    const NAME: &'static str = "QueryJoinExactAmountOutRequest";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryJoinExactAmountOutResponse {
+    const NAME: &'static str = "QueryJoinExactAmountOutResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryNumPoolsRequest {
+    const NAME: &'static str = "QueryNumPoolsRequest";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryNumPoolsResponse {
+    const NAME: &'static str = "QueryNumPoolsResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryPoolNumberRequest {
+    const NAME: &'static str = "QueryPoolNumberRequest";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryPoolNumberResponse {
+    const NAME: &'static str = "QueryPoolNumberResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryPoolParamsRequest {
+    const NAME: &'static str = "QueryPoolParamsRequest";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryPoolParamsResponse {
+    const NAME: &'static str = "QueryPoolParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryPoolRequest {
+    const NAME: &'static str = "QueryPoolRequest";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryPoolResponse {
+    const NAME: &'static str = "QueryPoolResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryPoolsRequest {
+    const NAME: &'static str = "QueryPoolsRequest";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryPoolsResponse {
+    const NAME: &'static str = "QueryPoolsResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QuerySpotPriceRequest {
+    const NAME: &'static str = "QuerySpotPriceRequest";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QuerySpotPriceResponse {
+    const NAME: &'static str = "QuerySpotPriceResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QuerySwapExactAmountInRequest {
+    const NAME: &'static str = "QuerySwapExactAmountInRequest";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QuerySwapExactAmountInResponse {
+    const NAME: &'static str = "QuerySwapExactAmountInResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QuerySwapExactAmountOutRequest {
+    const NAME: &'static str = "QuerySwapExactAmountOutRequest";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QuerySwapExactAmountOutResponse {
+    const NAME: &'static str = "QuerySwapExactAmountOutResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryTotalLiquidityRequest {
+    const NAME: &'static str = "QueryTotalLiquidityRequest";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryTotalLiquidityResponse {
+    const NAME: &'static str = "QueryTotalLiquidityResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryTotalPoolLiquidityRequest {
+    const NAME: &'static str = "QueryTotalPoolLiquidityRequest";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryTotalPoolLiquidityResponse {
+    const NAME: &'static str = "QueryTotalPoolLiquidityResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryTotalSharesRequest {
+    const NAME: &'static str = "QueryTotalSharesRequest";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+#[cfg(feature = "spot")]
+impl Name for nibiru::spot::QueryTotalSharesResponse {
+    const NAME: &'static str = "QueryTotalSharesResponse";
+    const PACKAGE: &'static str = PACKAGE_SPOT;
+}
+
+// nibiru.sudo.v1
+
+#[cfg(feature = "sudo")]
+impl Name for nibiru::sudo::EventUpdateSudoers {
+    const NAME: &'static str = "EventUpdateSudoers";
+    const PACKAGE: &'static str = PACKAGE_SUDO;
+}
+
+#[cfg(feature = "sudo")]
+impl Name for nibiru::sudo::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_SUDO;
+}
+
+#[cfg(feature = "sudo")]
+impl Name for nibiru::sudo::MsgChangeRoot {
+    const NAME: &'static str = "MsgChangeRoot";
+    const PACKAGE: &'static str = PACKAGE_SUDO;
+}
+
+#[cfg(feature = "sudo")]
+impl Name for nibiru::sudo::MsgChangeRootResponse {
+    const NAME: &'static str = "MsgChangeRootResponse";
+    const PACKAGE: &'static str = PACKAGE_SUDO;
+}
+
+#[cfg(feature = "sudo")]
+impl Name for nibiru::sudo::MsgEditSudoers {
+    const NAME: &'static str = "MsgEditSudoers";
+    const PACKAGE: &'static str = PACKAGE_SUDO;
+}
+
+#[cfg(feature = "sudo")]
+impl Name for nibiru::sudo::MsgEditSudoersResponse {
+    const NAME: &'static str = "MsgEditSudoersResponse";
+    const PACKAGE: &'static str = PACKAGE_SUDO;
+}
+
+#[cfg(feature = "sudo")]
+impl Name for nibiru::sudo::QuerySudoersRequest {
+    const NAME: &'static str = "QuerySudoersRequest";
+    const PACKAGE: &'static str = PACKAGE_SUDO;
+}
+
+#[cfg(feature = "sudo")]
+impl Name for nibiru::sudo::QuerySudoersResponse {
+    const NAME: &'static str = "QuerySudoersResponse";
+    const PACKAGE: &'static str = PACKAGE_SUDO;
+}
+
+#[cfg(feature = "sudo")]
+impl Name for nibiru::sudo::Sudoers {
+    const NAME: &'static str = "Sudoers";
+    const PACKAGE: &'static str = PACKAGE_SUDO;
+}
+
+// nibiru.tokenfactory.v1
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::DenomAuthorityMetadata {
+    const NAME: &'static str = "DenomAuthorityMetadata";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::EventBurn {
+    const NAME: &'static str = "EventBurn";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::EventChangeAdmin {
+    const NAME: &'static str = "EventChangeAdmin";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::EventCreateDenom {
+    const NAME: &'static str = "EventCreateDenom";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::EventMint {
+    const NAME: &'static str = "EventMint";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::EventSetDenomMetadata {
+    const NAME: &'static str = "EventSetDenomMetadata";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::GenesisDenom {
+    const NAME: &'static str = "GenesisDenom";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::ModuleParams {
+    const NAME: &'static str = "ModuleParams";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::MsgBurn {
+    const NAME: &'static str = "MsgBurn";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::MsgBurnNative {
+    const NAME: &'static str = "MsgBurnNative";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::MsgBurnNativeResponse {
+    const NAME: &'static str = "MsgBurnNativeResponse";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::MsgBurnResponse {
+    const NAME: &'static str = "MsgBurnResponse";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::MsgChangeAdmin {
+    const NAME: &'static str = "MsgChangeAdmin";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::MsgChangeAdminResponse {
+    const NAME: &'static str = "MsgChangeAdminResponse";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::MsgCreateDenom {
+    const NAME: &'static str = "MsgCreateDenom";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::MsgCreateDenomResponse {
+    const NAME: &'static str = "MsgCreateDenomResponse";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::MsgMint {
+    const NAME: &'static str = "MsgMint";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::MsgMintResponse {
+    const NAME: &'static str = "MsgMintResponse";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::MsgSetDenomMetadata {
+    const NAME: &'static str = "MsgSetDenomMetadata";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::MsgSetDenomMetadataResponse {
+    const NAME: &'static str = "MsgSetDenomMetadataResponse";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::MsgSudoSetDenomMetadata {
+    const NAME: &'static str = "MsgSudoSetDenomMetadata";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::MsgSudoSetDenomMetadataResponse {
+    const NAME: &'static str = "MsgSudoSetDenomMetadataResponse";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::MsgUpdateModuleParams {
+    const NAME: &'static str = "MsgUpdateModuleParams";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::MsgUpdateModuleParamsResponse {
+    const NAME: &'static str = "MsgUpdateModuleParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::QueryDenomInfoRequest {
+    const NAME: &'static str = "QueryDenomInfoRequest";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::QueryDenomInfoResponse {
+    const NAME: &'static str = "QueryDenomInfoResponse";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::QueryDenomsRequest {
+    const NAME: &'static str = "QueryDenomsRequest";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::QueryDenomsResponse {
+    const NAME: &'static str = "QueryDenomsResponse";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Name for nibiru::tokenfactory::TfDenom {
+    const NAME: &'static str = "TfDenom";
+    const PACKAGE: &'static str = PACKAGE_TOKENFACTORY;
+}
+
+// gRPC query paths
+
+#[cfg(feature = "evm")]
+impl NibiruGrpcQuery for eth::evm::QueryBalanceRequest {
+    const GRPC_PATH: &'static str = "/eth.evm.v1.Query/Balance";
+    type Response = eth::evm::QueryBalanceResponse;
+}
+
+#[cfg(feature = "evm")]
+impl NibiruGrpcQuery for eth::evm::QueryBaseFeeRequest {
+    const GRPC_PATH: &'static str = "/eth.evm.v1.Query/BaseFee";
+    type Response = eth::evm::QueryBaseFeeResponse;
+}
+
+#[cfg(feature = "evm")]
+impl NibiruGrpcQuery for eth::evm::QueryCodeRequest {
+    const GRPC_PATH: &'static str = "/eth.evm.v1.Query/Code";
+    type Response = eth::evm::QueryCodeResponse;
+}
+
+#[cfg(feature = "evm")]
+impl NibiruGrpcQuery for eth::evm::QueryEthAccountRequest {
+    const GRPC_PATH: &'static str = "/eth.evm.v1.Query/EthAccount";
+    type Response = eth::evm::QueryEthAccountResponse;
+}
+
+#[cfg(feature = "evm")]
+impl NibiruGrpcQuery for eth::evm::QueryFunTokenMappingRequest {
+    const GRPC_PATH: &'static str = "/eth.evm.v1.Query/FunTokenMapping";
+    type Response = eth::evm::QueryFunTokenMappingResponse;
+}
+
+#[cfg(feature = "evm")]
+impl NibiruGrpcQuery for eth::evm::QueryParamsRequest {
+    const GRPC_PATH: &'static str = "/eth.evm.v1.Query/Params";
+    type Response = eth::evm::QueryParamsResponse;
+}
+
+#[cfg(feature = "evm")]
+impl NibiruGrpcQuery for eth::evm::QueryStorageRequest {
+    const GRPC_PATH: &'static str = "/eth.evm.v1.Query/Storage";
+    type Response = eth::evm::QueryStorageResponse;
+}
+
+#[cfg(feature = "evm")]
+impl NibiruGrpcQuery for eth::evm::QueryTraceBlockRequest {
+    const GRPC_PATH: &'static str = "/eth.evm.v1.Query/TraceBlock";
+    type Response = eth::evm::QueryTraceBlockResponse;
+}
+
+#[cfg(feature = "evm")]
+impl NibiruGrpcQuery for eth::evm::QueryTraceTxRequest {
+    const GRPC_PATH: &'static str = "/eth.evm.v1.Query/TraceTx";
+    type Response = eth::evm::QueryTraceTxResponse;
+}
+
+#[cfg(feature = "evm")]
+impl NibiruGrpcQuery for eth::evm::QueryValidatorAccountRequest {
+    const GRPC_PATH: &'static str = "/eth.evm.v1.Query/ValidatorAccount";
+    type Response = eth::evm::QueryValidatorAccountResponse;
+}
+
+#[cfg(feature = "devgas")]
+impl NibiruGrpcQuery for nibiru::devgas::QueryFeeShareRequest {
+    const GRPC_PATH: &'static str = "/nibiru.devgas.v1.Query/FeeShare";
+    type Response = nibiru::devgas::QueryFeeShareResponse;
+}
+
+#[cfg(feature = "devgas")]
+impl NibiruGrpcQuery for nibiru::devgas::QueryFeeSharesByWithdrawerRequest {
+    const GRPC_PATH: &'static str =
+        "/nibiru.devgas.v1.Query/FeeSharesByWithdrawer";
+    type Response = nibiru::devgas::QueryFeeSharesByWithdrawerResponse;
+}
+
+#[cfg(feature = "devgas")]
+impl NibiruGrpcQuery for nibiru::devgas::QueryFeeSharesRequest {
+    const GRPC_PATH: &'static str = "/nibiru.devgas.v1.Query/FeeShares";
+    type Response = nibiru::devgas::QueryFeeSharesResponse;
+}
+
+#[cfg(feature = "devgas")]
+impl NibiruGrpcQuery for nibiru::devgas::QueryParamsRequest {
+    const GRPC_PATH: &'static str = "/nibiru.devgas.v1.Query/Params";
+    type Response = nibiru::devgas::QueryParamsResponse;
+}
+
+#[cfg(feature = "epochs")]
+impl NibiruGrpcQuery for nibiru::epochs::QueryCurrentEpochRequest {
+    const GRPC_PATH: &'static str = "/nibiru.epochs.v1.Query/CurrentEpoch";
+    type Response = nibiru::epochs::QueryCurrentEpochResponse;
+}
+
+#[cfg(feature = "epochs")]
+impl NibiruGrpcQuery for nibiru::epochs::QueryEpochInfosRequest {
+    const GRPC_PATH: &'static str = "/nibiru.epochs.v1.Query/EpochInfos";
+    type Response = nibiru::epochs::QueryEpochInfosResponse;
+}
+
+#[cfg(feature = "inflation")]
+impl NibiruGrpcQuery for nibiru::inflation::QueryCirculatingSupplyRequest {
+    const GRPC_PATH: &'static str =
+        "/nibiru.inflation.v1.Query/CirculatingSupply";
+    type Response = nibiru::inflation::QueryCirculatingSupplyResponse;
+}
+
+#[cfg(feature = "inflation")]
+impl NibiruGrpcQuery for nibiru::inflation::QueryEpochMintProvisionRequest {
+    const GRPC_PATH: &'static str =
+        "/nibiru.inflation.v1.Query/EpochMintProvision";
+    type Response = nibiru::inflation::QueryEpochMintProvisionResponse;
+}
+
+#[cfg(feature = "inflation")]
+impl NibiruGrpcQuery for nibiru::inflation::QueryInflationRateRequest {
+    const GRPC_PATH: &'static str = "/nibiru.inflation.v1.Query/InflationRate";
+    type Response = nibiru::inflation::QueryInflationRateResponse;
+}
+
+#[cfg(feature = "inflation")]
+impl NibiruGrpcQuery for nibiru::inflation::QueryParamsRequest {
+    const GRPC_PATH: &'static str = "/nibiru.inflation.v1.Query/Params";
+    type Response = nibiru::inflation::QueryParamsResponse;
+}
+
+#[cfg(feature = "inflation")]
+impl NibiruGrpcQuery for nibiru::inflation::QueryPeriodRequest {
+    const GRPC_PATH: &'static str = "/nibiru.inflation.v1.Query/Period";
+    type Response = nibiru::inflation::QueryPeriodResponse;
+}
+
+#[cfg(feature = "inflation")]
+impl NibiruGrpcQuery for nibiru::inflation::QuerySkippedEpochsRequest {
+    const GRPC_PATH: &'static str = "/nibiru.inflation.v1.Query/SkippedEpochs";
+    type Response = nibiru::inflation::QuerySkippedEpochsResponse;
+}
+
+#[cfg(feature = "oracle")]
+impl NibiruGrpcQuery for nibiru::oracle::QueryActivesRequest {
+    const GRPC_PATH: &'static str = "/nibiru.oracle.v1.Query/Actives";
+    type Response = nibiru::oracle::QueryActivesResponse;
+}
+
+#[cfg(feature = "oracle")]
+impl NibiruGrpcQuery for nibiru::oracle::QueryAggregatePrevoteRequest {
+    const GRPC_PATH: &'static str = "/nibiru.oracle.v1.Query/AggregatePrevote";
+    type Response = nibiru::oracle::QueryAggregatePrevoteResponse;
+}
+
+#[cfg(feature = "oracle")]
+impl NibiruGrpcQuery for nibiru::oracle::QueryAggregatePrevotesRequest {
+    const GRPC_PATH: &'static str = "/nibiru.oracle.v1.Query/AggregatePrevotes";
+    type Response = nibiru::oracle::QueryAggregatePrevotesResponse;
+}
+
+#[cfg(feature = "oracle")]
+impl NibiruGrpcQuery for nibiru::oracle::QueryAggregateVoteRequest {
+    const GRPC_PATH: &'static str = "/nibiru.oracle.v1.Query/AggregateVote";
+    type Response = nibiru::oracle::QueryAggregateVoteResponse;
+}
+
+#[cfg(feature = "oracle")]
+impl NibiruGrpcQuery for nibiru::oracle::QueryAggregateVotesRequest {
+    const GRPC_PATH: &'static str = "/nibiru.oracle.v1.Query/AggregateVotes";
+    type Response = nibiru::oracle::QueryAggregateVotesResponse;
+}
+
+#[cfg(feature = "oracle")]
+impl NibiruGrpcQuery for nibiru::oracle::QueryExchangeRateRequest {
+    const GRPC_PATH: &'static str = "/nibiru.oracle.v1.Query/ExchangeRate";
+    type Response = nibiru::oracle::QueryExchangeRateResponse;
+}
+
+#[cfg(feature = "oracle")]
+impl NibiruGrpcQuery for nibiru::oracle::QueryExchangeRatesRequest {
+    const GRPC_PATH: &'static str = "/nibiru.oracle.v1.Query/ExchangeRates";
+    type Response = nibiru::oracle::QueryExchangeRatesResponse;
+}
+
+#[cfg(feature = "oracle")]
+impl NibiruGrpcQuery for nibiru::oracle::QueryFeederDelegationRequest {
+    const GRPC_PATH: &'static str = "/nibiru.oracle.v1.Query/FeederDelegation";
+    type Response = nibiru::oracle::QueryFeederDelegationResponse;
+}
+
+#[cfg(feature = "oracle")]
+impl NibiruGrpcQuery for nibiru::oracle::QueryMissCounterRequest {
+    const GRPC_PATH: &'static str = "/nibiru.oracle.v1.Query/MissCounter";
+    type Response = nibiru::oracle::QueryMissCounterResponse;
+}
+
+#[cfg(feature = "oracle")]
+impl NibiruGrpcQuery for nibiru::oracle::QueryParamsRequest {
+    const GRPC_PATH: &'static str = "/nibiru.oracle.v1.Query/Params";
+    type Response = nibiru::oracle::QueryParamsResponse;
+}
+
+#[cfg(feature = "oracle")]
+impl NibiruGrpcQuery for nibiru::oracle::QueryVoteTargetsRequest {
+    const GRPC_PATH: &'static str = "/nibiru.oracle.v1.Query/VoteTargets";
+    type Response = nibiru::oracle::QueryVoteTargetsResponse;
+}
+
+#[cfg(feature = "perp")]
+impl NibiruGrpcQuery for nibiru::perp::QueryCollateralRequest {
+    const GRPC_PATH: &'static str = "/nibiru.perp.v2.Query/QueryCollateral";
+    type Response = nibiru::perp::QueryCollateralResponse;
+}
+
+#[cfg(feature = "perp")]
+impl NibiruGrpcQuery for nibiru::perp::QueryMarketsRequest {
+    const GRPC_PATH: &'static str = "/nibiru.perp.v2.Query/QueryMarkets";
+    type Response = nibiru::perp::QueryMarketsResponse;
+}
+
+#[cfg(feature = "perp")]
+impl NibiruGrpcQuery for nibiru::perp::QueryModuleAccountsRequest {
+    const GRPC_PATH: &'static str = "/nibiru.perp.v2.Query/ModuleAccounts";
+    type Response = nibiru::perp::QueryModuleAccountsResponse;
+}
+
+#[cfg(feature = "perp")]
+impl NibiruGrpcQuery for nibiru::perp::QueryPositionRequest {
+    const GRPC_PATH: &'static str = "/nibiru.perp.v2.Query/QueryPosition";
+    type Response = nibiru::perp::QueryPositionResponse;
+}
+
+#[cfg(feature = "perp")]
+impl NibiruGrpcQuery for nibiru::perp::QueryPositionStoreRequest {
+    const GRPC_PATH: &'static str = "/nibiru.perp.v2.Query/QueryPositionStore";
+    type Response = nibiru::perp::QueryPositionStoreResponse;
+}
+
+#[cfg(feature = "perp")]
+impl NibiruGrpcQuery for nibiru::perp::QueryPositionsRequest {
+    const GRPC_PATH: &'static str = "/nibiru.perp.v2.Query/QueryPositions";
+    type Response = nibiru::perp::QueryPositionsResponse;
+}
+
+#[cfg(feature = "spot")]
+impl NibiruGrpcQuery for nibiru::spot::QueryExitExactAmountInRequest {
+    const GRPC_PATH: &'static str =
+        "/nibiru.spot.v1.Query/EstimateExitExactAmountIn";
+    type Response = nibiru::spot::QueryExitExactAmountInResponse;
+}
+
+#[cfg(feature = "spot")]
+impl NibiruGrpcQuery for nibiru::spot::QueryExitExactAmountOutRequest {
+    const GRPC_PATH: &'static str =
+        "/nibiru.spot.v1.Query/EstimateExitExactAmountOut";
+    type Response = nibiru::spot::QueryExitExactAmountOutResponse;
+}
+
+#[cfg(feature = "spot")]
+impl NibiruGrpcQuery for nibiru::spot::QueryJoinExactAmountInRequest {
+    const GRPC_PATH: &'static str =
+        "/nibiru.spot.v1.Query/EstimateJoinExactAmountIn";
+    type Response = nibiru::spot::QueryJoinExactAmountInResponse;
+}
+
+#[cfg(feature = "spot")]
+impl NibiruGrpcQuery for nibiru::spot::QueryJoinExactAmountOutRequest {
+    const GRPC_PATH: &'static str =
+        "/nibiru.spot.v1.Query/EstimateJoinExactAmountOut";
+    type Response = nibiru::spot::QueryJoinExactAmountOutResponse;
+}
+
+#[cfg(feature = "spot")]
+impl NibiruGrpcQuery for nibiru::spot::QueryNumPoolsRequest {
+    const GRPC_PATH: &'static str = "/nibiru.spot.v1.Query/NumPools";
+    type Response = nibiru::spot::QueryNumPoolsResponse;
+}
+
+#[cfg(feature = "spot")]
+impl NibiruGrpcQuery for nibiru::spot::QueryParamsRequest {
+    const GRPC_PATH: &'static str = "/nibiru.spot.v1.Query/Params";
+    type Response = nibiru::spot::QueryParamsResponse;
+}
+
+#[cfg(feature = "spot")]
+impl NibiruGrpcQuery for nibiru::spot::QueryPoolNumberRequest {
+    const GRPC_PATH: &'static str = "/nibiru.spot.v1.Query/PoolNumber";
+    type Response = nibiru::spot::QueryPoolNumberResponse;
+}
+
+#[cfg(feature = "spot")]
+impl NibiruGrpcQuery for nibiru::spot::QueryPoolParamsRequest {
+    const GRPC_PATH: &'static str = "/nibiru.spot.v1.Query/PoolParams";
+    type Response = nibiru::spot::QueryPoolParamsResponse;
+}
+
+#[cfg(feature = "spot")]
+impl NibiruGrpcQuery for nibiru::spot::QueryPoolRequest {
+    const GRPC_PATH: &'static str = "/nibiru.spot.v1.Query/Pool";
+    type Response = nibiru::spot::QueryPoolResponse;
+}
+
+#[cfg(feature = "spot")]
+impl NibiruGrpcQuery for nibiru::spot::QueryPoolsRequest {
+    const GRPC_PATH: &'static str = "/nibiru.spot.v1.Query/Pools";
+    type Response = nibiru::spot::QueryPoolsResponse;
+}
+
+#[cfg(feature = "spot")]
+impl NibiruGrpcQuery for nibiru::spot::QuerySpotPriceRequest {
+    const GRPC_PATH: &'static str = "/nibiru.spot.v1.Query/SpotPrice";
+    type Response = nibiru::spot::QuerySpotPriceResponse;
+}
+
+#[cfg(feature = "spot")]
+impl NibiruGrpcQuery for nibiru::spot::QuerySwapExactAmountInRequest {
+    const GRPC_PATH: &'static str =
+        "/nibiru.spot.v1.Query/EstimateSwapExactAmountIn";
+    type Response = nibiru::spot::QuerySwapExactAmountInResponse;
+}
+
+#[cfg(feature = "spot")]
+impl NibiruGrpcQuery for nibiru::spot::QuerySwapExactAmountOutRequest {
+    const GRPC_PATH: &'static str =
+        "/nibiru.spot.v1.Query/EstimateSwapExactAmountOut";
+    type Response = nibiru::spot::QuerySwapExactAmountOutResponse;
+}
+
+#[cfg(feature = "spot")]
+impl NibiruGrpcQuery for nibiru::spot::QueryTotalLiquidityRequest {
+    const GRPC_PATH: &'static str = "/nibiru.spot.v1.Query/TotalLiquidity";
+    type Response = nibiru::spot::QueryTotalLiquidityResponse;
+}
+
+#[cfg(feature = "spot")]
+impl NibiruGrpcQuery for nibiru::spot::QueryTotalPoolLiquidityRequest {
+    const GRPC_PATH: &'static str = "/nibiru.spot.v1.Query/TotalPoolLiquidity";
+    type Response = nibiru::spot::QueryTotalPoolLiquidityResponse;
+}
+
+#[cfg(feature = "spot")]
+impl NibiruGrpcQuery for nibiru::spot::QueryTotalSharesRequest {
+    const GRPC_PATH: &'static str = "/nibiru.spot.v1.Query/TotalShares";
+    type Response = nibiru::spot::QueryTotalSharesResponse;
+}
+
+#[cfg(feature = "sudo")]
+impl NibiruGrpcQuery for nibiru::sudo::QuerySudoersRequest {
+    const GRPC_PATH: &'static str = "/nibiru.sudo.v1.Query/QuerySudoers";
+    type Response = nibiru::sudo::QuerySudoersResponse;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl NibiruGrpcQuery for nibiru::tokenfactory::QueryDenomInfoRequest {
+    const GRPC_PATH: &'static str = "/nibiru.tokenfactory.v1.Query/DenomInfo";
+    type Response = nibiru::tokenfactory::QueryDenomInfoResponse;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl NibiruGrpcQuery for nibiru::tokenfactory::QueryDenomsRequest {
+    const GRPC_PATH: &'static str = "/nibiru.tokenfactory.v1.Query/Denoms";
+    type Response = nibiru::tokenfactory::QueryDenomsResponse;
+}
+
+#[cfg(feature = "tokenfactory")]
+impl NibiruGrpcQuery for nibiru::tokenfactory::QueryParamsRequest {
+    const GRPC_PATH: &'static str = "/nibiru.tokenfactory.v1.Query/Params";
+    type Response = nibiru::tokenfactory::QueryParamsResponse;
+}
+
+/// Returns the gRPC query path of the request with the given fully qualified
+/// proto name, or `None` if no single query method serves it.
+#[allow(clippy::match_single_binding)]
+pub(crate) fn grpc_query_path(full_name: &str) -> Option<&'static str> {
+    match full_name {
+        #[cfg(feature = "evm")]
+        "eth.evm.v1.QueryBalanceRequest" => Some(<eth::evm::QueryBalanceRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "evm")]
+        "eth.evm.v1.QueryBaseFeeRequest" => Some(<eth::evm::QueryBaseFeeRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "evm")]
+        "eth.evm.v1.QueryCodeRequest" => Some(<eth::evm::QueryCodeRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "evm")]
+        "eth.evm.v1.QueryEthAccountRequest" => Some(<eth::evm::QueryEthAccountRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "evm")]
+        "eth.evm.v1.QueryFunTokenMappingRequest" => Some(<eth::evm::QueryFunTokenMappingRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "evm")]
+        "eth.evm.v1.QueryParamsRequest" => Some(<eth::evm::QueryParamsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "evm")]
+        "eth.evm.v1.QueryStorageRequest" => Some(<eth::evm::QueryStorageRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "evm")]
+        "eth.evm.v1.QueryTraceBlockRequest" => Some(<eth::evm::QueryTraceBlockRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "evm")]
+        "eth.evm.v1.QueryTraceTxRequest" => Some(<eth::evm::QueryTraceTxRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "evm")]
+        "eth.evm.v1.QueryValidatorAccountRequest" => Some(<eth::evm::QueryValidatorAccountRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "devgas")]
+        "nibiru.devgas.v1.QueryFeeShareRequest" => Some(<nibiru::devgas::QueryFeeShareRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "devgas")]
+        "nibiru.devgas.v1.QueryFeeSharesByWithdrawerRequest" => Some(<nibiru::devgas::QueryFeeSharesByWithdrawerRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "devgas")]
+        "nibiru.devgas.v1.QueryFeeSharesRequest" => Some(<nibiru::devgas::QueryFeeSharesRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "devgas")]
+        "nibiru.devgas.v1.QueryParamsRequest" => Some(<nibiru::devgas::QueryParamsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "epochs")]
+        "nibiru.epochs.v1.QueryCurrentEpochRequest" => Some(<nibiru::epochs::QueryCurrentEpochRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "epochs")]
+        "nibiru.epochs.v1.QueryEpochInfosRequest" => Some(<nibiru::epochs::QueryEpochInfosRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "inflation")]
+        "nibiru.inflation.v1.QueryCirculatingSupplyRequest" => Some(<nibiru::inflation::QueryCirculatingSupplyRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "inflation")]
+        "nibiru.inflation.v1.QueryEpochMintProvisionRequest" => Some(<nibiru::inflation::QueryEpochMintProvisionRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "inflation")]
+        "nibiru.inflation.v1.QueryInflationRateRequest" => Some(<nibiru::inflation::QueryInflationRateRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "inflation")]
+        "nibiru.inflation.v1.QueryParamsRequest" => Some(<nibiru::inflation::QueryParamsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "inflation")]
+        "nibiru.inflation.v1.QueryPeriodRequest" => Some(<nibiru::inflation::QueryPeriodRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "inflation")]
+        "nibiru.inflation.v1.QuerySkippedEpochsRequest" => Some(<nibiru::inflation::QuerySkippedEpochsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "oracle")]
+        "nibiru.oracle.v1.QueryActivesRequest" => Some(<nibiru::oracle::QueryActivesRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "oracle")]
+        "nibiru.oracle.v1.QueryAggregatePrevoteRequest" => Some(<nibiru::oracle::QueryAggregatePrevoteRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "oracle")]
+        "nibiru.oracle.v1.QueryAggregatePrevotesRequest" => Some(<nibiru::oracle::QueryAggregatePrevotesRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "oracle")]
+        "nibiru.oracle.v1.QueryAggregateVoteRequest" => Some(<nibiru::oracle::QueryAggregateVoteRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "oracle")]
+        "nibiru.oracle.v1.QueryAggregateVotesRequest" => Some(<nibiru::oracle::QueryAggregateVotesRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "oracle")]
+        "nibiru.oracle.v1.QueryExchangeRateRequest" => Some(<nibiru::oracle::QueryExchangeRateRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "oracle")]
+        "nibiru.oracle.v1.QueryExchangeRatesRequest" => Some(<nibiru::oracle::QueryExchangeRatesRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "oracle")]
+        "nibiru.oracle.v1.QueryFeederDelegationRequest" => Some(<nibiru::oracle::QueryFeederDelegationRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "oracle")]
+        "nibiru.oracle.v1.QueryMissCounterRequest" => Some(<nibiru::oracle::QueryMissCounterRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "oracle")]
+        "nibiru.oracle.v1.QueryParamsRequest" => Some(<nibiru::oracle::QueryParamsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "oracle")]
+        "nibiru.oracle.v1.QueryVoteTargetsRequest" => Some(<nibiru::oracle::QueryVoteTargetsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "perp")]
+        "nibiru.perp.v2.QueryCollateralRequest" => Some(<nibiru::perp::QueryCollateralRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "perp")]
+        "nibiru.perp.v2.QueryMarketsRequest" => Some(<nibiru::perp::QueryMarketsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "perp")]
+        "nibiru.perp.v2.QueryModuleAccountsRequest" => Some(<nibiru::perp::QueryModuleAccountsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "perp")]
+        "nibiru.perp.v2.QueryPositionRequest" => Some(<nibiru::perp::QueryPositionRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "perp")]
+        "nibiru.perp.v2.QueryPositionStoreRequest" => Some(<nibiru::perp::QueryPositionStoreRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "perp")]
+        "nibiru.perp.v2.QueryPositionsRequest" => Some(<nibiru::perp::QueryPositionsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "spot")]
+        "nibiru.spot.v1.QueryExitExactAmountInRequest" => Some(<nibiru::spot::QueryExitExactAmountInRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "spot")]
+        "nibiru.spot.v1.QueryExitExactAmountOutRequest" => Some(<nibiru::spot::QueryExitExactAmountOutRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "spot")]
+        "nibiru.spot.v1.QueryJoinExactAmountInRequest" => Some(<nibiru::spot::QueryJoinExactAmountInRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "spot")]
+        "nibiru.spot.v1.QueryJoinExactAmountOutRequest" => Some(<nibiru::spot::QueryJoinExactAmountOutRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "spot")]
+        "nibiru.spot.v1.QueryNumPoolsRequest" => Some(<nibiru::spot::QueryNumPoolsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "spot")]
+        "nibiru.spot.v1.QueryParamsRequest" => Some(<nibiru::spot::QueryParamsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "spot")]
+        "nibiru.spot.v1.QueryPoolNumberRequest" => Some(<nibiru::spot::QueryPoolNumberRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "spot")]
+        "nibiru.spot.v1.QueryPoolParamsRequest" => Some(<nibiru::spot::QueryPoolParamsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "spot")]
+        "nibiru.spot.v1.QueryPoolRequest" => Some(<nibiru::spot::QueryPoolRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "spot")]
+        "nibiru.spot.v1.QueryPoolsRequest" => Some(<nibiru::spot::QueryPoolsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "spot")]
+        "nibiru.spot.v1.QuerySpotPriceRequest" => Some(<nibiru::spot::QuerySpotPriceRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "spot")]
+        "nibiru.spot.v1.QuerySwapExactAmountInRequest" => Some(<nibiru::spot::QuerySwapExactAmountInRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "spot")]
+        "nibiru.spot.v1.QuerySwapExactAmountOutRequest" => Some(<nibiru::spot::QuerySwapExactAmountOutRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "spot")]
+        "nibiru.spot.v1.QueryTotalLiquidityRequest" => Some(<nibiru::spot::QueryTotalLiquidityRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "spot")]
+        "nibiru.spot.v1.QueryTotalPoolLiquidityRequest" => Some(<nibiru::spot::QueryTotalPoolLiquidityRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "spot")]
+        "nibiru.spot.v1.QueryTotalSharesRequest" => Some(<nibiru::spot::QueryTotalSharesRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "sudo")]
+        "nibiru.sudo.v1.QuerySudoersRequest" => Some(<nibiru::sudo::QuerySudoersRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tokenfactory")]
+        "nibiru.tokenfactory.v1.QueryDenomInfoRequest" => Some(<nibiru::tokenfactory::QueryDenomInfoRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tokenfactory")]
+        "nibiru.tokenfactory.v1.QueryDenomsRequest" => Some(<nibiru::tokenfactory::QueryDenomsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        #[cfg(feature = "tokenfactory")]
+        "nibiru.tokenfactory.v1.QueryParamsRequest" => Some(<nibiru::tokenfactory::QueryParamsRequest as NibiruGrpcQuery>::GRPC_PATH),
+        _ => None,
+    }
+}
+
+/// (request, gRPC path, response) for every generated [NibiruGrpcQuery] impl.
+#[cfg(all(test, feature = "all_protos"))]
+#[allow(clippy::vec_init_then_push)]
+pub(crate) fn grpc_queries() -> Vec<(String, &'static str, String)> {
+    #[allow(unused_imports)]
+    use crate::proto::grpc_query_entry;
+    #[allow(unused_mut)]
+    let mut queries = vec![];
+    #[cfg(feature = "evm")]
+    queries.push(grpc_query_entry::<eth::evm::QueryBalanceRequest>());
+    #[cfg(feature = "evm")]
+    queries.push(grpc_query_entry::<eth::evm::QueryBaseFeeRequest>());
+    #[cfg(feature = "evm")]
+    queries.push(grpc_query_entry::<eth::evm::QueryCodeRequest>());
+    #[cfg(feature = "evm")]
+    queries.push(grpc_query_entry::<eth::evm::QueryEthAccountRequest>());
+    #[cfg(feature = "evm")]
+    queries.push(grpc_query_entry::<eth::evm::QueryFunTokenMappingRequest>());
+    #[cfg(feature = "evm")]
+    queries.push(grpc_query_entry::<eth::evm::QueryParamsRequest>());
+    #[cfg(feature = "evm")]
+    queries.push(grpc_query_entry::<eth::evm::QueryStorageRequest>());
+    #[cfg(feature = "evm")]
+    queries.push(grpc_query_entry::<eth::evm::QueryTraceBlockRequest>());
+    #[cfg(feature = "evm")]
+    queries.push(grpc_query_entry::<eth::evm::QueryTraceTxRequest>());
+    #[cfg(feature = "evm")]
+    queries.push(grpc_query_entry::<eth::evm::QueryValidatorAccountRequest>());
+    #[cfg(feature = "devgas")]
+    queries.push(grpc_query_entry::<nibiru::devgas::QueryFeeShareRequest>());
+    #[cfg(feature = "devgas")]
+    queries.push(grpc_query_entry::<
+        nibiru::devgas::QueryFeeSharesByWithdrawerRequest,
+    >());
+    #[cfg(feature = "devgas")]
+    queries.push(grpc_query_entry::<nibiru::devgas::QueryFeeSharesRequest>());
+    #[cfg(feature = "devgas")]
+    queries.push(grpc_query_entry::<nibiru::devgas::QueryParamsRequest>());
+    #[cfg(feature = "epochs")]
+    queries.push(grpc_query_entry::<nibiru::epochs::QueryCurrentEpochRequest>());
+    #[cfg(feature = "epochs")]
+    queries.push(grpc_query_entry::<nibiru::epochs::QueryEpochInfosRequest>());
+    #[cfg(feature = "inflation")]
+    queries.push(grpc_query_entry::<
+        nibiru::inflation::QueryCirculatingSupplyRequest,
+    >());
+    #[cfg(feature = "inflation")]
+    queries.push(grpc_query_entry::<
+        nibiru::inflation::QueryEpochMintProvisionRequest,
+    >());
+    #[cfg(feature = "inflation")]
+    queries.push(grpc_query_entry::<
+        nibiru::inflation::QueryInflationRateRequest,
+    >());
+    #[cfg(feature = "inflation")]
+    queries.push(grpc_query_entry::<nibiru::inflation::QueryParamsRequest>());
+    #[cfg(feature = "inflation")]
+    queries.push(grpc_query_entry::<nibiru::inflation::QueryPeriodRequest>());
+    #[cfg(feature = "inflation")]
+    queries.push(grpc_query_entry::<
+        nibiru::inflation::QuerySkippedEpochsRequest,
+    >());
+    #[cfg(feature = "oracle")]
+    queries.push(grpc_query_entry::<nibiru::oracle::QueryActivesRequest>());
+    #[cfg(feature = "oracle")]
+    queries.push(grpc_query_entry::<
+        nibiru::oracle::QueryAggregatePrevoteRequest,
+    >());
+    #[cfg(feature = "oracle")]
+    queries.push(grpc_query_entry::<
+        nibiru::oracle::QueryAggregatePrevotesRequest,
+    >());
+    #[cfg(feature = "oracle")]
+    queries
+        .push(grpc_query_entry::<nibiru::oracle::QueryAggregateVoteRequest>());
+    #[cfg(feature = "oracle")]
+    queries
+        .push(grpc_query_entry::<nibiru::oracle::QueryAggregateVotesRequest>());
+    #[cfg(feature = "oracle")]
+    queries.push(grpc_query_entry::<nibiru::oracle::QueryExchangeRateRequest>());
+    #[cfg(feature = "oracle")]
+    queries
+        .push(grpc_query_entry::<nibiru::oracle::QueryExchangeRatesRequest>());
+    #[cfg(feature = "oracle")]
+    queries.push(grpc_query_entry::<
+        nibiru::oracle::QueryFeederDelegationRequest,
+    >());
+    #[cfg(feature = "oracle")]
+    queries.push(grpc_query_entry::<nibiru::oracle::QueryMissCounterRequest>());
+    #[cfg(feature = "oracle")]
+    queries.push(grpc_query_entry::<nibiru::oracle::QueryParamsRequest>());
+    #[cfg(feature = "oracle")]
+    queries.push(grpc_query_entry::<nibiru::oracle::QueryVoteTargetsRequest>());
+    #[cfg(feature = "perp")]
+    queries.push(grpc_query_entry::<nibiru::perp::QueryCollateralRequest>());
+    #[cfg(feature = "perp")]
+    queries.push(grpc_query_entry::<nibiru::perp::QueryMarketsRequest>());
+    #[cfg(feature = "perp")]
+    queries.push(grpc_query_entry::<nibiru::perp::QueryModuleAccountsRequest>());
+    #[cfg(feature = "perp")]
+    queries.push(grpc_query_entry::<nibiru::perp::QueryPositionRequest>());
+    #[cfg(feature = "perp")]
+    queries.push(grpc_query_entry::<nibiru::perp::QueryPositionStoreRequest>());
+    #[cfg(feature = "perp")]
+    queries.push(grpc_query_entry::<nibiru::perp::QueryPositionsRequest>());
+    #[cfg(feature = "spot")]
+    queries.push(grpc_query_entry::<
+        nibiru::spot::QueryExitExactAmountInRequest,
+    >());
+    #[cfg(feature = "spot")]
+    queries.push(grpc_query_entry::<
+        nibiru::spot::QueryExitExactAmountOutRequest,
+    >());
+    #[cfg(feature = "spot")]
+    queries.push(grpc_query_entry::<
+        nibiru::spot::QueryJoinExactAmountInRequest,
+    >());
+    #[cfg(feature = "spot")]
+    queries.push(grpc_query_entry::<
+        nibiru::spot::QueryJoinExactAmountOutRequest,
+    >());
+    #[cfg(feature = "spot")]
+    queries.push(grpc_query_entry::<nibiru::spot::QueryNumPoolsRequest>());
+    #[cfg(feature = "spot")]
+    queries.push(grpc_query_entry::<nibiru::spot::QueryParamsRequest>());
+    #[cfg(feature = "spot")]
+    queries.push(grpc_query_entry::<nibiru::spot::QueryPoolNumberRequest>());
+    #[cfg(feature = "spot")]
+    queries.push(grpc_query_entry::<nibiru::spot::QueryPoolParamsRequest>());
+    #[cfg(feature = "spot")]
+    queries.push(grpc_query_entry::<nibiru::spot::QueryPoolRequest>());
+    #[cfg(feature = "spot")]
+    queries.push(grpc_query_entry::<nibiru::spot::QueryPoolsRequest>());
+    #[cfg(feature = "spot")]
+    queries.push(grpc_query_entry::<nibiru::spot::QuerySpotPriceRequest>());
+    #[cfg(feature = "spot")]
+    queries.push(grpc_query_entry::<
+        nibiru::spot::QuerySwapExactAmountInRequest,
+    >());
+    #[cfg(feature = "spot")]
+    queries.push(grpc_query_entry::<
+        nibiru::spot::QuerySwapExactAmountOutRequest,
+    >());
+    #[cfg(feature = "spot")]
+    queries.push(grpc_query_entry::<nibiru::spot::QueryTotalLiquidityRequest>());
+    #[cfg(feature = "spot")]
+    queries.push(grpc_query_entry::<
+        nibiru::spot::QueryTotalPoolLiquidityRequest,
+    >());
+    #[cfg(feature = "spot")]
+    queries.push(grpc_query_entry::<nibiru::spot::QueryTotalSharesRequest>());
+    #[cfg(feature = "sudo")]
+    queries.push(grpc_query_entry::<nibiru::sudo::QuerySudoersRequest>());
+    #[cfg(feature = "tokenfactory")]
+    queries.push(grpc_query_entry::<
+        nibiru::tokenfactory::QueryDenomInfoRequest,
+    >());
+    #[cfg(feature = "tokenfactory")]
+    queries.push(grpc_query_entry::<nibiru::tokenfactory::QueryDenomsRequest>());
+    #[cfg(feature = "tokenfactory")]
+    queries.push(grpc_query_entry::<nibiru::tokenfactory::QueryParamsRequest>());
+    queries
+}
+
+/// Returns the JSON codec of the message with the given type URL, which
+/// (de)serializes `Any` fields under the "serde" feature.
+#[cfg(feature = "serde")]
+#[allow(clippy::match_single_binding)]
+pub(crate) fn any_json(type_url: &str) -> Option<crate::proto::json::AnyJson> {
+    #[allow(unused_imports)]
+    use crate::proto::json::AnyJson;
+    match type_url {
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.AccessListTx" => {
+            Some(AnyJson::of::<eth::evm::AccessListTx>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.AccessTuple" => {
+            Some(AnyJson::of::<eth::evm::AccessTuple>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.DynamicFeeTx" => {
+            Some(AnyJson::of::<eth::evm::DynamicFeeTx>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.EstimateGasResponse" => {
+            Some(AnyJson::of::<eth::evm::EstimateGasResponse>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.EthCallRequest" => {
+            Some(AnyJson::of::<eth::evm::EthCallRequest>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.EventBlockBloom" => {
+            Some(AnyJson::of::<eth::evm::EventBlockBloom>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.EventContractDeployed" => {
+            Some(AnyJson::of::<eth::evm::EventContractDeployed>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.EventContractExecuted" => {
+            Some(AnyJson::of::<eth::evm::EventContractExecuted>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.EventConvertCoinToEvm" => {
+            Some(AnyJson::of::<eth::evm::EventConvertCoinToEvm>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.EventEthereumTx" => {
+            Some(AnyJson::of::<eth::evm::EventEthereumTx>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.EventFunTokenCreated" => {
+            Some(AnyJson::of::<eth::evm::EventFunTokenCreated>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.EventTransfer" => {
+            Some(AnyJson::of::<eth::evm::EventTransfer>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.EventTxLog" => Some(AnyJson::of::<eth::evm::EventTxLog>()),
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.ExtensionOptionsEthereumTx" => {
+            Some(AnyJson::of::<eth::evm::ExtensionOptionsEthereumTx>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.FunToken" => Some(AnyJson::of::<eth::evm::FunToken>()),
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.GenesisAccount" => {
+            Some(AnyJson::of::<eth::evm::GenesisAccount>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.GenesisState" => {
+            Some(AnyJson::of::<eth::evm::GenesisState>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.LegacyTx" => Some(AnyJson::of::<eth::evm::LegacyTx>()),
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.Log" => Some(AnyJson::of::<eth::evm::Log>()),
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.MsgConvertCoinToEvm" => {
+            Some(AnyJson::of::<eth::evm::MsgConvertCoinToEvm>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.MsgConvertCoinToEvmResponse" => {
+            Some(AnyJson::of::<eth::evm::MsgConvertCoinToEvmResponse>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.MsgCreateFunToken" => {
+            Some(AnyJson::of::<eth::evm::MsgCreateFunToken>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.MsgCreateFunTokenResponse" => {
+            Some(AnyJson::of::<eth::evm::MsgCreateFunTokenResponse>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.MsgEthereumTx" => {
+            Some(AnyJson::of::<eth::evm::MsgEthereumTx>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.MsgEthereumTxResponse" => {
+            Some(AnyJson::of::<eth::evm::MsgEthereumTxResponse>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.MsgUpdateParams" => {
+            Some(AnyJson::of::<eth::evm::MsgUpdateParams>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.MsgUpdateParamsResponse" => {
+            Some(AnyJson::of::<eth::evm::MsgUpdateParamsResponse>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.Params" => Some(AnyJson::of::<eth::evm::Params>()),
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryBalanceRequest" => {
+            Some(AnyJson::of::<eth::evm::QueryBalanceRequest>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryBalanceResponse" => {
+            Some(AnyJson::of::<eth::evm::QueryBalanceResponse>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryBaseFeeRequest" => {
+            Some(AnyJson::of::<eth::evm::QueryBaseFeeRequest>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryBaseFeeResponse" => {
+            Some(AnyJson::of::<eth::evm::QueryBaseFeeResponse>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryCodeRequest" => {
+            Some(AnyJson::of::<eth::evm::QueryCodeRequest>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryCodeResponse" => {
+            Some(AnyJson::of::<eth::evm::QueryCodeResponse>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryEthAccountRequest" => {
+            Some(AnyJson::of::<eth::evm::QueryEthAccountRequest>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryEthAccountResponse" => {
+            Some(AnyJson::of::<eth::evm::QueryEthAccountResponse>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryFunTokenMappingRequest" => {
+            Some(AnyJson::of::<eth::evm::QueryFunTokenMappingRequest>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryFunTokenMappingResponse" => {
+            Some(AnyJson::of::<eth::evm::QueryFunTokenMappingResponse>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryParamsRequest" => {
+            Some(AnyJson::of::<eth::evm::QueryParamsRequest>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryParamsResponse" => {
+            Some(AnyJson::of::<eth::evm::QueryParamsResponse>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryStorageRequest" => {
+            Some(AnyJson::of::<eth::evm::QueryStorageRequest>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryStorageResponse" => {
+            Some(AnyJson::of::<eth::evm::QueryStorageResponse>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryTraceBlockRequest" => {
+            Some(AnyJson::of::<eth::evm::QueryTraceBlockRequest>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryTraceBlockResponse" => {
+            Some(AnyJson::of::<eth::evm::QueryTraceBlockResponse>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryTraceTxRequest" => {
+            Some(AnyJson::of::<eth::evm::QueryTraceTxRequest>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryTraceTxResponse" => {
+            Some(AnyJson::of::<eth::evm::QueryTraceTxResponse>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryTxLogsRequest" => {
+            Some(AnyJson::of::<eth::evm::QueryTxLogsRequest>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryTxLogsResponse" => {
+            Some(AnyJson::of::<eth::evm::QueryTxLogsResponse>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryValidatorAccountRequest" => {
+            Some(AnyJson::of::<eth::evm::QueryValidatorAccountRequest>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.QueryValidatorAccountResponse" => {
+            Some(AnyJson::of::<eth::evm::QueryValidatorAccountResponse>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.State" => Some(AnyJson::of::<eth::evm::State>()),
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.TraceConfig" => {
+            Some(AnyJson::of::<eth::evm::TraceConfig>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.evm.v1.TracerConfig" => {
+            Some(AnyJson::of::<eth::evm::TracerConfig>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.types.v1.EthAccount" => {
+            Some(AnyJson::of::<eth::types::EthAccount>())
+        }
+        #[cfg(feature = "evm")]
+        "/eth.types.v1.TxResult" => Some(AnyJson::of::<eth::types::TxResult>()),
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.EventCancelDevGas" => {
+            Some(AnyJson::of::<nibiru::devgas::EventCancelDevGas>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.EventPayoutDevGas" => {
+            Some(AnyJson::of::<nibiru::devgas::EventPayoutDevGas>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.EventRegisterDevGas" => {
+            Some(AnyJson::of::<nibiru::devgas::EventRegisterDevGas>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.EventUpdateDevGas" => {
+            Some(AnyJson::of::<nibiru::devgas::EventUpdateDevGas>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.FeeShare" => {
+            Some(AnyJson::of::<nibiru::devgas::FeeShare>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.GenesisState" => {
+            Some(AnyJson::of::<nibiru::devgas::GenesisState>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.ModuleParams" => {
+            Some(AnyJson::of::<nibiru::devgas::ModuleParams>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.MsgCancelFeeShare" => {
+            Some(AnyJson::of::<nibiru::devgas::MsgCancelFeeShare>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.MsgCancelFeeShareResponse" => {
+            Some(AnyJson::of::<nibiru::devgas::MsgCancelFeeShareResponse>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.MsgRegisterFeeShare" => {
+            Some(AnyJson::of::<nibiru::devgas::MsgRegisterFeeShare>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.MsgRegisterFeeShareResponse" => {
+            Some(AnyJson::of::<nibiru::devgas::MsgRegisterFeeShareResponse>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.MsgUpdateFeeShare" => {
+            Some(AnyJson::of::<nibiru::devgas::MsgUpdateFeeShare>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.MsgUpdateFeeShareResponse" => {
+            Some(AnyJson::of::<nibiru::devgas::MsgUpdateFeeShareResponse>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.MsgUpdateParams" => {
+            Some(AnyJson::of::<nibiru::devgas::MsgUpdateParams>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.MsgUpdateParamsResponse" => {
+            Some(AnyJson::of::<nibiru::devgas::MsgUpdateParamsResponse>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.QueryFeeShareRequest" => {
+            Some(AnyJson::of::<nibiru::devgas::QueryFeeShareRequest>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.QueryFeeShareResponse" => {
+            Some(AnyJson::of::<nibiru::devgas::QueryFeeShareResponse>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.QueryFeeSharesByWithdrawerRequest" => {
+            Some(AnyJson::of::<
+                nibiru::devgas::QueryFeeSharesByWithdrawerRequest,
+            >())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.QueryFeeSharesByWithdrawerResponse" => {
+            Some(AnyJson::of::<
+                nibiru::devgas::QueryFeeSharesByWithdrawerResponse,
+            >())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.QueryFeeSharesRequest" => {
+            Some(AnyJson::of::<nibiru::devgas::QueryFeeSharesRequest>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.QueryFeeSharesResponse" => {
+            Some(AnyJson::of::<nibiru::devgas::QueryFeeSharesResponse>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.QueryParamsRequest" => {
+            Some(AnyJson::of::<nibiru::devgas::QueryParamsRequest>())
+        }
+        #[cfg(feature = "devgas")]
+        "/nibiru.devgas.v1.QueryParamsResponse" => {
+            Some(AnyJson::of::<nibiru::devgas::QueryParamsResponse>())
+        }
+        #[cfg(feature = "epochs")]
+        "/nibiru.epochs.v1.EpochInfo" => {
+            Some(AnyJson::of::<nibiru::epochs::EpochInfo>())
+        }
+        #[cfg(feature = "epochs")]
+        "/nibiru.epochs.v1.EventEpochEnd" => {
+            Some(AnyJson::of::<nibiru::epochs::EventEpochEnd>())
+        }
+        #[cfg(feature = "epochs")]
+        "/nibiru.epochs.v1.EventEpochStart" => {
+            Some(AnyJson::of::<nibiru::epochs::EventEpochStart>())
+        }
+        #[cfg(feature = "epochs")]
+        "/nibiru.epochs.v1.GenesisState" => {
+            Some(AnyJson::of::<nibiru::epochs::GenesisState>())
+        }
+        #[cfg(feature = "epochs")]
+        "/nibiru.epochs.v1.QueryCurrentEpochRequest" => {
+            Some(AnyJson::of::<nibiru::epochs::QueryCurrentEpochRequest>())
+        }
+        #[cfg(feature = "epochs")]
+        "/nibiru.epochs.v1.QueryCurrentEpochResponse" => {
+            Some(AnyJson::of::<nibiru::epochs::QueryCurrentEpochResponse>())
+        }
+        #[cfg(feature = "epochs")]
+        "/nibiru.epochs.v1.QueryEpochInfosRequest" => {
+            Some(AnyJson::of::<nibiru::epochs::QueryEpochInfosRequest>())
+        }
+        #[cfg(feature = "epochs")]
+        "/nibiru.epochs.v1.QueryEpochInfosResponse" => {
+            Some(AnyJson::of::<nibiru::epochs::QueryEpochInfosResponse>())
+        }
+        #[cfg(feature = "genmsg")]
+        "/nibiru.genmsg.v1.GenesisState" => {
+            Some(AnyJson::of::<nibiru::genmsg::GenesisState>())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.EventInflationDistribution" => {
+            Some(AnyJson::of::<nibiru::inflation::EventInflationDistribution>())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.GenesisState" => {
+            Some(AnyJson::of::<nibiru::inflation::GenesisState>())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.InflationDistribution" => {
+            Some(AnyJson::of::<nibiru::inflation::InflationDistribution>())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.MsgBurn" => {
+            Some(AnyJson::of::<nibiru::inflation::MsgBurn>())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.MsgBurnResponse" => {
+            Some(AnyJson::of::<nibiru::inflation::MsgBurnResponse>())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.MsgEditInflationParams" => {
+            Some(AnyJson::of::<nibiru::inflation::MsgEditInflationParams>())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.MsgEditInflationParamsResponse" => {
+            Some(AnyJson::of::<
+                nibiru::inflation::MsgEditInflationParamsResponse,
+            >())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.MsgToggleInflation" => {
+            Some(AnyJson::of::<nibiru::inflation::MsgToggleInflation>())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.MsgToggleInflationResponse" => {
+            Some(AnyJson::of::<nibiru::inflation::MsgToggleInflationResponse>())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.Params" => {
+            Some(AnyJson::of::<nibiru::inflation::Params>())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.QueryCirculatingSupplyRequest" => {
+            Some(AnyJson::of::<
+                nibiru::inflation::QueryCirculatingSupplyRequest,
+            >())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.QueryCirculatingSupplyResponse" => {
+            Some(AnyJson::of::<
+                nibiru::inflation::QueryCirculatingSupplyResponse,
+            >())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.QueryEpochMintProvisionRequest" => {
+            Some(AnyJson::of::<
+                nibiru::inflation::QueryEpochMintProvisionRequest,
+            >())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.QueryEpochMintProvisionResponse" => {
+            Some(AnyJson::of::<
+                nibiru::inflation::QueryEpochMintProvisionResponse,
+            >())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.QueryInflationRateRequest" => {
+            Some(AnyJson::of::<nibiru::inflation::QueryInflationRateRequest>())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.QueryInflationRateResponse" => {
+            Some(AnyJson::of::<nibiru::inflation::QueryInflationRateResponse>())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.QueryParamsRequest" => {
+            Some(AnyJson::of::<nibiru::inflation::QueryParamsRequest>())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.QueryParamsResponse" => {
+            Some(AnyJson::of::<nibiru::inflation::QueryParamsResponse>())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.QueryPeriodRequest" => {
+            Some(AnyJson::of::<nibiru::inflation::QueryPeriodRequest>())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.QueryPeriodResponse" => {
+            Some(AnyJson::of::<nibiru::inflation::QueryPeriodResponse>())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.QuerySkippedEpochsRequest" => {
+            Some(AnyJson::of::<nibiru::inflation::QuerySkippedEpochsRequest>())
+        }
+        #[cfg(feature = "inflation")]
+        "/nibiru.inflation.v1.QuerySkippedEpochsResponse" => {
+            Some(AnyJson::of::<nibiru::inflation::QuerySkippedEpochsResponse>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.AggregateExchangeRatePrevote" => {
+            Some(AnyJson::of::<nibiru::oracle::AggregateExchangeRatePrevote>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.AggregateExchangeRateVote" => {
+            Some(AnyJson::of::<nibiru::oracle::AggregateExchangeRateVote>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.EventAggregatePrevote" => {
+            Some(AnyJson::of::<nibiru::oracle::EventAggregatePrevote>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.EventAggregateVote" => {
+            Some(AnyJson::of::<nibiru::oracle::EventAggregateVote>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.EventDelegateFeederConsent" => {
+            Some(AnyJson::of::<nibiru::oracle::EventDelegateFeederConsent>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.EventPriceUpdate" => {
+            Some(AnyJson::of::<nibiru::oracle::EventPriceUpdate>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.EventValidatorPerformance" => {
+            Some(AnyJson::of::<nibiru::oracle::EventValidatorPerformance>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.ExchangeRateAtBlock" => {
+            Some(AnyJson::of::<nibiru::oracle::ExchangeRateAtBlock>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.ExchangeRateTuple" => {
+            Some(AnyJson::of::<nibiru::oracle::ExchangeRateTuple>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.FeederDelegation" => {
+            Some(AnyJson::of::<nibiru::oracle::FeederDelegation>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.GenesisState" => {
+            Some(AnyJson::of::<nibiru::oracle::GenesisState>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.MissCounter" => {
+            Some(AnyJson::of::<nibiru::oracle::MissCounter>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.MsgAggregateExchangeRatePrevote" => Some(
+            AnyJson::of::<nibiru::oracle::MsgAggregateExchangeRatePrevote>(),
+        ),
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.MsgAggregateExchangeRatePrevoteResponse" => {
+            Some(AnyJson::of::<
+                nibiru::oracle::MsgAggregateExchangeRatePrevoteResponse,
+            >())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.MsgAggregateExchangeRateVote" => {
+            Some(AnyJson::of::<nibiru::oracle::MsgAggregateExchangeRateVote>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.MsgAggregateExchangeRateVoteResponse" => {
+            Some(AnyJson::of::<
+                nibiru::oracle::MsgAggregateExchangeRateVoteResponse,
+            >())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.MsgDelegateFeedConsent" => {
+            Some(AnyJson::of::<nibiru::oracle::MsgDelegateFeedConsent>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.MsgDelegateFeedConsentResponse" => {
+            Some(AnyJson::of::<nibiru::oracle::MsgDelegateFeedConsentResponse>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.MsgEditOracleParams" => {
+            Some(AnyJson::of::<nibiru::oracle::MsgEditOracleParams>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.MsgEditOracleParamsResponse" => {
+            Some(AnyJson::of::<nibiru::oracle::MsgEditOracleParamsResponse>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.OracleParamsMsg" => {
+            Some(AnyJson::of::<nibiru::oracle::OracleParamsMsg>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.Params" => {
+            Some(AnyJson::of::<nibiru::oracle::Params>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.PriceSnapshot" => {
+            Some(AnyJson::of::<nibiru::oracle::PriceSnapshot>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryActivesRequest" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryActivesRequest>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryActivesResponse" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryActivesResponse>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryAggregatePrevoteRequest" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryAggregatePrevoteRequest>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryAggregatePrevoteResponse" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryAggregatePrevoteResponse>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryAggregatePrevotesRequest" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryAggregatePrevotesRequest>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryAggregatePrevotesResponse" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryAggregatePrevotesResponse>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryAggregateVoteRequest" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryAggregateVoteRequest>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryAggregateVoteResponse" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryAggregateVoteResponse>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryAggregateVotesRequest" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryAggregateVotesRequest>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryAggregateVotesResponse" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryAggregateVotesResponse>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryExchangeRateRequest" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryExchangeRateRequest>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryExchangeRateResponse" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryExchangeRateResponse>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryExchangeRatesRequest" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryExchangeRatesRequest>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryExchangeRatesResponse" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryExchangeRatesResponse>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryFeederDelegationRequest" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryFeederDelegationRequest>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryFeederDelegationResponse" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryFeederDelegationResponse>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryMissCounterRequest" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryMissCounterRequest>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryMissCounterResponse" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryMissCounterResponse>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryParamsRequest" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryParamsRequest>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryParamsResponse" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryParamsResponse>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryVoteTargetsRequest" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryVoteTargetsRequest>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.QueryVoteTargetsResponse" => {
+            Some(AnyJson::of::<nibiru::oracle::QueryVoteTargetsResponse>())
+        }
+        #[cfg(feature = "oracle")]
+        "/nibiru.oracle.v1.Rewards" => {
+            Some(AnyJson::of::<nibiru::oracle::Rewards>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.AccountWithBalance" => {
+            Some(AnyJson::of::<nibiru::perp::AccountWithBalance>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.Amm" => Some(AnyJson::of::<nibiru::perp::Amm>()),
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.AmmMarket" => {
+            Some(AnyJson::of::<nibiru::perp::AmmMarket>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.AmmUpdatedEvent" => {
+            Some(AnyJson::of::<nibiru::perp::AmmUpdatedEvent>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.DnrAllocation" => {
+            Some(AnyJson::of::<nibiru::perp::DnrAllocation>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.EventShiftPegMultiplier" => {
+            Some(AnyJson::of::<nibiru::perp::EventShiftPegMultiplier>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.EventShiftSwapInvariant" => {
+            Some(AnyJson::of::<nibiru::perp::EventShiftSwapInvariant>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.FundingRateChangedEvent" => {
+            Some(AnyJson::of::<nibiru::perp::FundingRateChangedEvent>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.GenesisMarketLastVersion" => {
+            Some(AnyJson::of::<nibiru::perp::GenesisMarketLastVersion>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.GenesisPosition" => {
+            Some(AnyJson::of::<nibiru::perp::GenesisPosition>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.GenesisState" => {
+            Some(AnyJson::of::<nibiru::perp::GenesisState>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.LiquidationFailedEvent" => {
+            Some(AnyJson::of::<nibiru::perp::LiquidationFailedEvent>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.Market" => Some(AnyJson::of::<nibiru::perp::Market>()),
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MarketLastVersion" => {
+            Some(AnyJson::of::<nibiru::perp::MarketLastVersion>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MarketUpdatedEvent" => {
+            Some(AnyJson::of::<nibiru::perp::MarketUpdatedEvent>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgAddMargin" => {
+            Some(AnyJson::of::<nibiru::perp::MsgAddMargin>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgAddMarginResponse" => {
+            Some(AnyJson::of::<nibiru::perp::MsgAddMarginResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgAllocateEpochRebates" => {
+            Some(AnyJson::of::<nibiru::perp::MsgAllocateEpochRebates>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgAllocateEpochRebatesResponse" => {
+            Some(AnyJson::of::<nibiru::perp::MsgAllocateEpochRebatesResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgChangeCollateralDenom" => {
+            Some(AnyJson::of::<nibiru::perp::MsgChangeCollateralDenom>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgChangeCollateralDenomResponse" => {
+            Some(AnyJson::of::<nibiru::perp::MsgChangeCollateralDenomResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgCloseMarket" => {
+            Some(AnyJson::of::<nibiru::perp::MsgCloseMarket>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgCloseMarketResponse" => {
+            Some(AnyJson::of::<nibiru::perp::MsgCloseMarketResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgClosePosition" => {
+            Some(AnyJson::of::<nibiru::perp::MsgClosePosition>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgClosePositionResponse" => {
+            Some(AnyJson::of::<nibiru::perp::MsgClosePositionResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgDonateToEcosystemFund" => {
+            Some(AnyJson::of::<nibiru::perp::MsgDonateToEcosystemFund>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgDonateToEcosystemFundResponse" => {
+            Some(AnyJson::of::<nibiru::perp::MsgDonateToEcosystemFundResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgMarketOrder" => {
+            Some(AnyJson::of::<nibiru::perp::MsgMarketOrder>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgMarketOrderResponse" => {
+            Some(AnyJson::of::<nibiru::perp::MsgMarketOrderResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgMultiLiquidate" => {
+            Some(AnyJson::of::<nibiru::perp::MsgMultiLiquidate>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgMultiLiquidateResponse" => {
+            Some(AnyJson::of::<nibiru::perp::MsgMultiLiquidateResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgPartialClose" => {
+            Some(AnyJson::of::<nibiru::perp::MsgPartialClose>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgPartialCloseResponse" => {
+            Some(AnyJson::of::<nibiru::perp::MsgPartialCloseResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgRemoveMargin" => {
+            Some(AnyJson::of::<nibiru::perp::MsgRemoveMargin>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgRemoveMarginResponse" => {
+            Some(AnyJson::of::<nibiru::perp::MsgRemoveMarginResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgSettlePosition" => {
+            Some(AnyJson::of::<nibiru::perp::MsgSettlePosition>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgShiftPegMultiplier" => {
+            Some(AnyJson::of::<nibiru::perp::MsgShiftPegMultiplier>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgShiftPegMultiplierResponse" => {
+            Some(AnyJson::of::<nibiru::perp::MsgShiftPegMultiplierResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgShiftSwapInvariant" => {
+            Some(AnyJson::of::<nibiru::perp::MsgShiftSwapInvariant>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgShiftSwapInvariantResponse" => {
+            Some(AnyJson::of::<nibiru::perp::MsgShiftSwapInvariantResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgWithdrawEpochRebates" => {
+            Some(AnyJson::of::<nibiru::perp::MsgWithdrawEpochRebates>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgWithdrawEpochRebatesResponse" => {
+            Some(AnyJson::of::<nibiru::perp::MsgWithdrawEpochRebatesResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgWithdrawFromPerpFund" => {
+            Some(AnyJson::of::<nibiru::perp::MsgWithdrawFromPerpFund>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.MsgWithdrawFromPerpFundResponse" => {
+            Some(AnyJson::of::<nibiru::perp::MsgWithdrawFromPerpFundResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.Position" => {
+            Some(AnyJson::of::<nibiru::perp::Position>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.PositionChangedEvent" => {
+            Some(AnyJson::of::<nibiru::perp::PositionChangedEvent>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.PositionLiquidatedEvent" => {
+            Some(AnyJson::of::<nibiru::perp::PositionLiquidatedEvent>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.PositionSettledEvent" => {
+            Some(AnyJson::of::<nibiru::perp::PositionSettledEvent>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.QueryCollateralRequest" => {
+            Some(AnyJson::of::<nibiru::perp::QueryCollateralRequest>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.QueryCollateralResponse" => {
+            Some(AnyJson::of::<nibiru::perp::QueryCollateralResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.QueryMarketsRequest" => {
+            Some(AnyJson::of::<nibiru::perp::QueryMarketsRequest>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.QueryMarketsResponse" => {
+            Some(AnyJson::of::<nibiru::perp::QueryMarketsResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.QueryModuleAccountsRequest" => {
+            Some(AnyJson::of::<nibiru::perp::QueryModuleAccountsRequest>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.QueryModuleAccountsResponse" => {
+            Some(AnyJson::of::<nibiru::perp::QueryModuleAccountsResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.QueryPositionRequest" => {
+            Some(AnyJson::of::<nibiru::perp::QueryPositionRequest>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.QueryPositionResponse" => {
+            Some(AnyJson::of::<nibiru::perp::QueryPositionResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.QueryPositionStoreRequest" => {
+            Some(AnyJson::of::<nibiru::perp::QueryPositionStoreRequest>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.QueryPositionStoreResponse" => {
+            Some(AnyJson::of::<nibiru::perp::QueryPositionStoreResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.QueryPositionsRequest" => {
+            Some(AnyJson::of::<nibiru::perp::QueryPositionsRequest>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.QueryPositionsResponse" => {
+            Some(AnyJson::of::<nibiru::perp::QueryPositionsResponse>())
+        }
+        #[cfg(feature = "perp")]
+        "/nibiru.perp.v2.ReserveSnapshot" => {
+            Some(AnyJson::of::<nibiru::perp::ReserveSnapshot>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.EventAssetsSwapped" => {
+            Some(AnyJson::of::<nibiru::spot::EventAssetsSwapped>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.EventPoolCreated" => {
+            Some(AnyJson::of::<nibiru::spot::EventPoolCreated>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.EventPoolExited" => {
+            Some(AnyJson::of::<nibiru::spot::EventPoolExited>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.EventPoolJoined" => {
+            Some(AnyJson::of::<nibiru::spot::EventPoolJoined>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.GenesisState" => {
+            Some(AnyJson::of::<nibiru::spot::GenesisState>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.MsgCreatePool" => {
+            Some(AnyJson::of::<nibiru::spot::MsgCreatePool>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.MsgCreatePoolResponse" => {
+            Some(AnyJson::of::<nibiru::spot::MsgCreatePoolResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.MsgExitPool" => {
+            Some(AnyJson::of::<nibiru::spot::MsgExitPool>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.MsgExitPoolResponse" => {
+            Some(AnyJson::of::<nibiru::spot::MsgExitPoolResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.MsgJoinPool" => {
+            Some(AnyJson::of::<nibiru::spot::MsgJoinPool>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.MsgJoinPoolResponse" => {
+            Some(AnyJson::of::<nibiru::spot::MsgJoinPoolResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.MsgSwapAssets" => {
+            Some(AnyJson::of::<nibiru::spot::MsgSwapAssets>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.MsgSwapAssetsResponse" => {
+            Some(AnyJson::of::<nibiru::spot::MsgSwapAssetsResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.Params" => Some(AnyJson::of::<nibiru::spot::Params>()),
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.Pool" => Some(AnyJson::of::<nibiru::spot::Pool>()),
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.PoolAsset" => {
+            Some(AnyJson::of::<nibiru::spot::PoolAsset>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.PoolParams" => {
+            Some(AnyJson::of::<nibiru::spot::PoolParams>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryExitExactAmountInRequest" => {
+            Some(AnyJson::of::<nibiru::spot::QueryExitExactAmountInRequest>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryExitExactAmountInResponse" => {
+            Some(AnyJson::of::<nibiru::spot::QueryExitExactAmountInResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryExitExactAmountOutRequest" => {
+            Some(AnyJson::of::<nibiru::spot::QueryExitExactAmountOutRequest>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryExitExactAmountOutResponse" => {
+            Some(AnyJson::of::<nibiru::spot::QueryExitExactAmountOutResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryJoinExactAmountInRequest" => {
+            Some(AnyJson::of::<nibiru::spot::QueryJoinExactAmountInRequest>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryJoinExactAmountInResponse" => {
+            Some(AnyJson::of::<nibiru::spot::QueryJoinExactAmountInResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryJoinExactAmountOutRequest" => {
+            Some(AnyJson::of::<nibiru::spot::QueryJoinExactAmountOutRequest>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryJoinExactAmountOutResponse" => {
+            Some(AnyJson::of::<nibiru::spot::QueryJoinExactAmountOutResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryNumPoolsRequest" => {
+            Some(AnyJson::of::<nibiru::spot::QueryNumPoolsRequest>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryNumPoolsResponse" => {
+            Some(AnyJson::of::<nibiru::spot::QueryNumPoolsResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryParamsRequest" => {
+            Some(AnyJson::of::<nibiru::spot::QueryParamsRequest>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryParamsResponse" => {
+            Some(AnyJson::of::<nibiru::spot::QueryParamsResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryPoolNumberRequest" => {
+            Some(AnyJson::of::<nibiru::spot::QueryPoolNumberRequest>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryPoolNumberResponse" => {
+            Some(AnyJson::of::<nibiru::spot::QueryPoolNumberResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryPoolParamsRequest" => {
+            Some(AnyJson::of::<nibiru::spot::QueryPoolParamsRequest>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryPoolParamsResponse" => {
+            Some(AnyJson::of::<nibiru::spot::QueryPoolParamsResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryPoolRequest" => {
+            Some(AnyJson::of::<nibiru::spot::QueryPoolRequest>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryPoolResponse" => {
+            Some(AnyJson::of::<nibiru::spot::QueryPoolResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryPoolsRequest" => {
+            Some(AnyJson::of::<nibiru::spot::QueryPoolsRequest>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryPoolsResponse" => {
+            Some(AnyJson::of::<nibiru::spot::QueryPoolsResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QuerySpotPriceRequest" => {
+            Some(AnyJson::of::<nibiru::spot::QuerySpotPriceRequest>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QuerySpotPriceResponse" => {
+            Some(AnyJson::of::<nibiru::spot::QuerySpotPriceResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QuerySwapExactAmountInRequest" => {
+            Some(AnyJson::of::<nibiru::spot::QuerySwapExactAmountInRequest>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QuerySwapExactAmountInResponse" => {
+            Some(AnyJson::of::<nibiru::spot::QuerySwapExactAmountInResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QuerySwapExactAmountOutRequest" => {
+            Some(AnyJson::of::<nibiru::spot::QuerySwapExactAmountOutRequest>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QuerySwapExactAmountOutResponse" => {
+            Some(AnyJson::of::<nibiru::spot::QuerySwapExactAmountOutResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryTotalLiquidityRequest" => {
+            Some(AnyJson::of::<nibiru::spot::QueryTotalLiquidityRequest>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryTotalLiquidityResponse" => {
+            Some(AnyJson::of::<nibiru::spot::QueryTotalLiquidityResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryTotalPoolLiquidityRequest" => {
+            Some(AnyJson::of::<nibiru::spot::QueryTotalPoolLiquidityRequest>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryTotalPoolLiquidityResponse" => {
+            Some(AnyJson::of::<nibiru::spot::QueryTotalPoolLiquidityResponse>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryTotalSharesRequest" => {
+            Some(AnyJson::of::<nibiru::spot::QueryTotalSharesRequest>())
+        }
+        #[cfg(feature = "spot")]
+        "/nibiru.spot.v1.QueryTotalSharesResponse" => {
+            Some(AnyJson::of::<nibiru::spot::QueryTotalSharesResponse>())
+        }
+        #[cfg(feature = "sudo")]
+        "/nibiru.sudo.v1.EventUpdateSudoers" => {
+            Some(AnyJson::of::<nibiru::sudo::EventUpdateSudoers>())
+        }
+        #[cfg(feature = "sudo")]
+        "/nibiru.sudo.v1.GenesisState" => {
+            Some(AnyJson::of::<nibiru::sudo::GenesisState>())
+        }
+        #[cfg(feature = "sudo")]
+        "/nibiru.sudo.v1.MsgChangeRoot" => {
+            Some(AnyJson::of::<nibiru::sudo::MsgChangeRoot>())
+        }
+        #[cfg(feature = "sudo")]
+        "/nibiru.sudo.v1.MsgChangeRootResponse" => {
+            Some(AnyJson::of::<nibiru::sudo::MsgChangeRootResponse>())
+        }
+        #[cfg(feature = "sudo")]
+        "/nibiru.sudo.v1.MsgEditSudoers" => {
+            Some(AnyJson::of::<nibiru::sudo::MsgEditSudoers>())
+        }
+        #[cfg(feature = "sudo")]
+        "/nibiru.sudo.v1.MsgEditSudoersResponse" => {
+            Some(AnyJson::of::<nibiru::sudo::MsgEditSudoersResponse>())
+        }
+        #[cfg(feature = "sudo")]
+        "/nibiru.sudo.v1.QuerySudoersRequest" => {
+            Some(AnyJson::of::<nibiru::sudo::QuerySudoersRequest>())
+        }
+        #[cfg(feature = "sudo")]
+        "/nibiru.sudo.v1.QuerySudoersResponse" => {
+            Some(AnyJson::of::<nibiru::sudo::QuerySudoersResponse>())
+        }
+        #[cfg(feature = "sudo")]
+        "/nibiru.sudo.v1.Sudoers" => {
+            Some(AnyJson::of::<nibiru::sudo::Sudoers>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.DenomAuthorityMetadata" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::DenomAuthorityMetadata>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.EventBurn" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::EventBurn>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.EventChangeAdmin" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::EventChangeAdmin>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.EventCreateDenom" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::EventCreateDenom>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.EventMint" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::EventMint>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.EventSetDenomMetadata" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::EventSetDenomMetadata>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.GenesisDenom" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::GenesisDenom>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.GenesisState" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::GenesisState>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.ModuleParams" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::ModuleParams>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.MsgBurn" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::MsgBurn>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.MsgBurnNative" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::MsgBurnNative>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.MsgBurnNativeResponse" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::MsgBurnNativeResponse>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.MsgBurnResponse" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::MsgBurnResponse>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.MsgChangeAdmin" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::MsgChangeAdmin>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.MsgChangeAdminResponse" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::MsgChangeAdminResponse>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.MsgCreateDenom" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::MsgCreateDenom>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.MsgCreateDenomResponse" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::MsgCreateDenomResponse>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.MsgMint" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::MsgMint>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.MsgMintResponse" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::MsgMintResponse>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.MsgSetDenomMetadata" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::MsgSetDenomMetadata>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.MsgSetDenomMetadataResponse" => {
+            Some(AnyJson::of::<
+                nibiru::tokenfactory::MsgSetDenomMetadataResponse,
+            >())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.MsgSudoSetDenomMetadata" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::MsgSudoSetDenomMetadata>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.MsgSudoSetDenomMetadataResponse" => {
+            Some(AnyJson::of::<
+                nibiru::tokenfactory::MsgSudoSetDenomMetadataResponse,
+            >())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.MsgUpdateModuleParams" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::MsgUpdateModuleParams>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.MsgUpdateModuleParamsResponse" => {
+            Some(AnyJson::of::<
+                nibiru::tokenfactory::MsgUpdateModuleParamsResponse,
+            >())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.QueryDenomInfoRequest" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::QueryDenomInfoRequest>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.QueryDenomInfoResponse" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::QueryDenomInfoResponse>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.QueryDenomsRequest" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::QueryDenomsRequest>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.QueryDenomsResponse" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::QueryDenomsResponse>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.QueryParamsRequest" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::QueryParamsRequest>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.QueryParamsResponse" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::QueryParamsResponse>())
+        }
+        #[cfg(feature = "tokenfactory")]
+        "/nibiru.tokenfactory.v1.TfDenom" => {
+            Some(AnyJson::of::<nibiru::tokenfactory::TfDenom>())
+        }
+        _ => None,
     }
 }
@@ -0,0 +1,320 @@
+//! Tests for the generated type URLs and gRPC query paths in
+//! the type_url_*.rs files.
+
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{self as cw, Empty, QueryRequest};
+use prost::Message;
+
+use crate::{
+    errors::{NibiruResult, TestResult},
+    proto::{
+        cosmos, eth, grpc_query_path, nibiru, proto_msg_parts,
+        proto_query_parts, type_url_cosmos, type_url_ibc, type_url_nibiru,
+        NibiruGrpcQuery, NibiruProstMsg, NibiruStargateMsg, NibiruStargateQuery,
+    },
+};
+
+#[test]
+fn stargate_query_conversion() -> TestResult {
+    let test_cases: Vec<(&str, NibiruResult<QueryRequest<Empty>>)> = vec![
+        (
+            "/cosmos.bank.v1beta1.Query/SupplyOf",
+            cosmos::bank::v1beta1::QuerySupplyOfRequest {
+                denom: String::from("some_denom"),
+            }
+            .into_stargate_query(),
+        ),
+        (
+            "/cosmos.bank.v1beta1.Query/Balance",
+            cosmos::bank::v1beta1::QueryBalanceRequest {
+                address: String::from("some_address"),
+                denom: String::from("some_denom"),
+            }
+            .into_stargate_query(),
+        ),
+        (
+            "/cosmos.bank.v1beta1.Query/DenomMetadata",
+            cosmos::bank::v1beta1::QueryDenomMetadataRequest {
+                denom: String::from("some_denom"),
+            }
+            .into_stargate_query(),
+        ),
+    ];
+
+    for test_case in test_cases {
+        let test_case_path = test_case.0;
+        let pb_query = test_case.1?;
+        if let Some((path, _data)) = proto_query_parts(&pb_query) {
+            assert_eq!(test_case_path, path)
+        } else {
+            panic!("failed test on case: {:#?} ", test_case_path)
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn stargate_tokenfactory_msgs() -> TestResult {
+    let test_cases: Vec<(&str, cw::CosmosMsg)> = vec![
+        (
+            "/nibiru.tokenfactory.v1.MsgMint",
+            nibiru::tokenfactory::MsgMint::default().into_stargate_msg(),
+        ),
+        (
+            "/nibiru.tokenfactory.v1.MsgBurn",
+            nibiru::tokenfactory::MsgBurn::default().into_stargate_msg(),
+        ),
+        (
+            "/nibiru.tokenfactory.v1.MsgChangeAdmin",
+            nibiru::tokenfactory::MsgChangeAdmin::default().into_stargate_msg(),
+        ),
+        (
+            "/nibiru.tokenfactory.v1.MsgSetDenomMetadata",
+            nibiru::tokenfactory::MsgSetDenomMetadata::default()
+                .into_stargate_msg(),
+        ),
+        (
+            "/nibiru.tokenfactory.v1.MsgUpdateModuleParams",
+            nibiru::tokenfactory::MsgUpdateModuleParams::default()
+                .into_stargate_msg(),
+        ),
+    ];
+
+    for test_case in test_cases {
+        let (tc_type_url, stargate_msg) = test_case;
+        if let Some((type_url, _value)) = proto_msg_parts(&stargate_msg) {
+            assert_eq!(tc_type_url, type_url)
+        } else {
+            panic!(
+                "Expected CosmosMsg::Stargate from CosmosMsg: {:#?}",
+                stargate_msg
+            )
+        }
+    }
+
+    println!(
+        "prost::Name corresponding to a CosmosMsg should error if we \
+        try converting it to QueryRequest::Stargate"
+    );
+    let pb_msg = nibiru::tokenfactory::MsgSetDenomMetadata::default();
+    pb_msg
+        .into_stargate_query()
+        .expect_err("query is not a Msg");
+
+    Ok(())
+}
+
+/// Uses values produced from the chain's protobuf marshaler to verify that
+/// our `nibiru-std` types encode the same way.
+///
+/// ```
+/// // For example, one test case came from:
+/// fmt.Printf("%v\n", encodingConfig.Marshaler.MustMarshal(&tokenfactorytypes.MsgCreateDenom{
+///     Sender:   "sender",
+///     Subdenom: "subdenom",
+/// }))
+/// // which outputs "[10 6 115 101 110 100 101 114 18 8 115 117 98 100 101 110 111 109]"
+/// ```
+#[test]
+fn stargate_encoding() -> TestResult {
+    let test_cases: Vec<(Box<dyn NibiruProstMsg>, Vec<u8>)> = vec![
+        (
+            Box::new(nibiru::tokenfactory::MsgCreateDenom {
+                        sender: String::from("sender"),
+                        subdenom: String::from("subdenom"),
+                    }),
+            parse_byte_string(
+                        "[10 6 115 101 110 100 101 114 18 8 115 117 98 100 101 110 111 109]",
+                    ),
+        ),
+        (
+            Box::new(nibiru::tokenfactory::MsgMint {
+                sender: String::from("sender"),
+                coin: Some(cosmos::base::v1beta1::Coin { denom: String::from("abcxyz"), amount: String::from("123") }),
+                mint_to: String::from("mint_to") }),
+            vec![10u8, 6, 115, 101, 110, 100, 101, 114, 18, 13, 10, 6, 97, 98, 99, 120, 121, 122, 18, 3, 49, 50, 51, 26, 7, 109, 105, 110, 116, 95, 116, 111]
+        ),
+    ];
+
+    for (pb_msg, want_bz) in &test_cases {
+        println!("pb_msg {{ value: {:?} }}", pb_msg.to_bytes(),);
+        assert_eq!(*want_bz, pb_msg.to_bytes(), "pb_msg: {pb_msg:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn stargate_evm_msgs() -> TestResult {
+    let test_cases: Vec<(&str, cw::CosmosMsg)> = vec![
+        (
+            "/eth.evm.v1.MsgEthereumTx",
+            eth::evm::MsgEthereumTx::default().into_stargate_msg(),
+        ),
+        (
+            "/eth.evm.v1.MsgUpdateParams",
+            eth::evm::MsgUpdateParams::default().into_stargate_msg(),
+        ),
+        (
+            "/eth.evm.v1.MsgCreateFunToken",
+            eth::evm::MsgCreateFunToken::default().into_stargate_msg(),
+        ),
+        (
+            "/eth.evm.v1.MsgConvertCoinToEvm",
+            eth::evm::MsgConvertCoinToEvm::default().into_stargate_msg(),
+        ),
+    ];
+
+    for test_case in test_cases {
+        let (tc_type_url, stargate_msg) = test_case;
+        if let Some((type_url, _value)) = proto_msg_parts(&stargate_msg) {
+            assert_eq!(tc_type_url, type_url)
+        } else {
+            panic!(
+                "Expected CosmosMsg::Stargate from CosmosMsg: {:#?}",
+                stargate_msg
+            )
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_byte_string(s: &str) -> Vec<u8> {
+    s.trim_start_matches('[')
+        .trim_end_matches(']')
+        .split_whitespace()
+        .filter_map(|byte| byte.parse::<u8>().ok())
+        .collect()
+}
+
+/// Checks every generated gRPC query path against the service definitions in
+/// the proto descriptor set, so that a request can't silently route to the
+/// wrong method.
+#[test]
+fn grpc_query_paths_match_descriptor() -> TestResult {
+    let descriptor = prost_types::FileDescriptorSet::decode(
+        include_bytes!("buf/proto_descriptor.bin").as_slice(),
+    )?;
+
+    // path -> (request, response, is_msg_service)
+    let mut methods: BTreeMap<String, (String, String, bool)> = BTreeMap::new();
+    let mut req_count: BTreeMap<String, usize> = BTreeMap::new();
+    for file in &descriptor.file {
+        for service in &file.service {
+            let is_msg_service = service.name() == "Msg";
+            for method in &service.method {
+                let path = format!(
+                    "/{}.{}/{}",
+                    file.package(),
+                    service.name(),
+                    method.name()
+                );
+                let req = method.input_type().trim_start_matches('.');
+                let resp = method.output_type().trim_start_matches('.');
+                if !is_msg_service {
+                    *req_count.entry(req.to_string()).or_default() += 1;
+                }
+                methods.insert(
+                    path,
+                    (req.to_string(), resp.to_string(), is_msg_service),
+                );
+            }
+        }
+    }
+
+    // Every generated impl names a real query method with matching types.
+    let generated: Vec<(String, &str, String)> = type_url_cosmos::grpc_queries()
+        .into_iter()
+        .chain(type_url_nibiru::grpc_queries())
+        .chain(type_url_ibc::grpc_queries())
+        .collect();
+    assert!(!generated.is_empty());
+    for (req, path, resp) in &generated {
+        let Some((want_req, want_resp, is_msg_service)) = methods.get(*path)
+        else {
+            panic!("{req} routes to {path}, which is not in the descriptor");
+        };
+        assert!(!is_msg_service, "{path} is a Msg service method");
+        assert_eq!((req, resp), (want_req, want_resp), "path: {path}");
+        assert_eq!(grpc_query_path(req), Some(*path), "request: {req}");
+    }
+
+    // Every lookup hit agrees with the descriptor. Requests shared by several
+    // methods or used by Msg services have no generated path.
+    for (path, (req, _, is_msg_service)) in &methods {
+        let got = grpc_query_path(req);
+        if *is_msg_service || req_count.get(req).copied().unwrap_or(0) != 1 {
+            if let Some(got) = got {
+                assert!(
+                    methods.get(got).is_some_and(|(r, _, msg)| r == req && !msg),
+                    "request {req} routes to {got}"
+                );
+            }
+            continue;
+        }
+        if let Some(got) = got {
+            assert_eq!(got, path, "request: {req}");
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn grpc_paths_for_irregular_names() -> TestResult {
+    let test_cases: Vec<(&str, String)> = vec![
+        (
+            "/nibiru.perp.v2.Query/QueryPosition",
+            nibiru::perp::QueryPositionRequest::default().path(),
+        ),
+        (
+            "/nibiru.spot.v1.Query/EstimateSwapExactAmountIn",
+            nibiru::spot::QuerySwapExactAmountInRequest::default().path(),
+        ),
+        (
+            "/nibiru.sudo.v1.Query/QuerySudoers",
+            nibiru::sudo::QuerySudoersRequest::default().path(),
+        ),
+        (
+            "/cosmos.tx.v1beta1.Service/GetTx",
+            cosmos::tx::v1beta1::GetTxRequest::default().path(),
+        ),
+        (
+            "/cosmos.base.tendermint.v1beta1.Service/GetNodeInfo",
+            cosmos::base::tendermint::v1beta1::GetNodeInfoRequest::default()
+                .path(),
+        ),
+        (
+            "/cosmos.auth.v1beta1.Query/AccountAddressByID",
+            cosmos::auth::v1beta1::QueryAccountAddressByIdRequest::default()
+                .path(),
+        ),
+    ];
+    for (want, got) in test_cases {
+        assert_eq!(want, got);
+    }
+
+    // Requests outside of "Query" services are still valid Stargate queries.
+    let query =
+        cosmos::tx::v1beta1::GetTxRequest::default().into_stargate_query()?;
+    assert_eq!(
+        proto_query_parts(&query).map(|(path, _)| path),
+        Some("/cosmos.tx.v1beta1.Service/GetTx")
+    );
+
+    // EthCallRequest serves both EthCall and EstimateGas, so it has no
+    // explicit path and falls back to the naming convention.
+    assert_eq!(grpc_query_path("eth.evm.v1.EthCallRequest"), None);
+    assert_eq!(
+        eth::evm::EthCallRequest::default().path(),
+        "/eth.evm.v1.Query/EthCall"
+    );
+
+    assert_eq!(
+        <nibiru::oracle::QueryExchangeRateRequest as NibiruGrpcQuery>::GRPC_PATH,
+        "/nibiru.oracle.v1.Query/ExchangeRate"
+    );
+    Ok(())
+}
@@ -0,0 +1,1013 @@
+//! querier.rs: [`NibiruQuerier`], a typed facade over the Nibiru and Cosmos-SDK
+//! gRPC queries that are reachable from a contract through
+//! `QueryRequest::Stargate`.
+//!
+//! Every method returns domain types (`Decimal`, `cw::Coin`,
+//! `cw::DenomMetadata`, [`AuthzGrant`], ...) rather than the raw prost
+//! responses, so callers never have to parse `LegacyDec` or `math.Int`
+//! strings by hand.
+//!
+//! Each method is gated by the cargo feature of the module it queries.
+
+#[cfg(feature = "oracle")]
+use std::collections::BTreeMap;
+
+use cosmwasm_schema::cw_serde;
+#[cfg(any(
+    feature = "oracle",
+    feature = "evm",
+    feature = "inflation",
+    feature = "sudo",
+    feature = "bank",
+    feature = "feegrant"
+))]
+use cosmwasm_std::StdError;
+#[cfg(any(
+    feature = "oracle",
+    feature = "tokenfactory",
+    feature = "evm",
+    feature = "epochs",
+    feature = "inflation",
+    feature = "sudo",
+    feature = "bank",
+    feature = "gov",
+    feature = "authz",
+    feature = "feegrant"
+))]
+use cosmwasm_std::StdResult;
+use cosmwasm_std::{
+    self as cw, CustomQuery, Decimal, Empty, QuerierWrapper, Uint256,
+};
+
+#[cfg(feature = "authz")]
+use crate::authz::AuthzGrant;
+#[cfg(any(feature = "oracle", feature = "evm", feature = "inflation"))]
+use crate::errors::{MathError, NibiruError};
+#[cfg(feature = "feegrant")]
+use crate::feegrant::FeeGrant;
+#[cfg(feature = "gov")]
+use crate::gov::ProposalInfo;
+use crate::math::DecimalExt;
+#[cfg(feature = "evm")]
+use crate::math::SdkInt;
+#[cfg(any(
+    feature = "bank",
+    feature = "gov",
+    feature = "authz",
+    feature = "feegrant"
+))]
+use crate::proto::cosmos;
+#[cfg(feature = "authz")]
+use crate::proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+#[cfg(feature = "evm")]
+use crate::proto::eth;
+#[cfg(any(
+    feature = "oracle",
+    feature = "tokenfactory",
+    feature = "epochs",
+    feature = "inflation",
+    feature = "sudo"
+))]
+use crate::proto::nibiru;
+#[cfg(any(
+    feature = "oracle",
+    feature = "tokenfactory",
+    feature = "evm",
+    feature = "epochs",
+    feature = "inflation",
+    feature = "sudo",
+    feature = "bank",
+    feature = "gov",
+    feature = "authz",
+    feature = "feegrant"
+))]
+use crate::proto::query_grpc_proto;
+
+/// NibiruQuerier: Wraps a `QuerierWrapper` with typed methods for the queries
+/// contracts on Nibiru commonly need.
+///
+/// ```rust
+/// use cosmwasm_std::{Decimal, QuerierWrapper};
+/// use nibiru_std::proto::nibiru::oracle::{
+///     QueryExchangeRateRequest, QueryExchangeRateResponse,
+/// };
+/// use nibiru_std::querier::NibiruQuerier;
+/// use nibiru_std::tutil::StargateMockQuerier;
+///
+/// let querier: StargateMockQuerier = StargateMockQuerier::new(&[]).with_handler(
+///     |_req: QueryExchangeRateRequest| {
+///         Ok(QueryExchangeRateResponse {
+///             // LegacyDec protobuf repr of 1.5
+///             exchange_rate: "1500000000000000000".to_string(),
+///             ..Default::default()
+///         })
+///     },
+/// );
+/// let wrapper = QuerierWrapper::<cosmwasm_std::Empty>::new(&querier);
+/// let price = NibiruQuerier::new(&wrapper).exchange_rate("ubtc:uusd").unwrap();
+/// assert_eq!(price, Decimal::percent(150));
+/// ```
+pub struct NibiruQuerier<'a, C: CustomQuery = Empty> {
+    // Read only by the methods of the enabled module features
+    #[cfg_attr(
+        not(any(
+            feature = "oracle",
+            feature = "tokenfactory",
+            feature = "evm",
+            feature = "epochs",
+            feature = "inflation",
+            feature = "sudo",
+            feature = "bank",
+            feature = "gov",
+            feature = "authz",
+            feature = "feegrant"
+        )),
+        allow(dead_code)
+    )]
+    querier: QuerierWrapper<'a, C>,
+}
+
+impl<'a, C: CustomQuery> NibiruQuerier<'a, C> {
+    pub fn new(querier: &QuerierWrapper<'a, C>) -> Self {
+        NibiruQuerier { querier: *querier }
+    }
+
+    // ---------------------------------------------------------------
+    // x/oracle
+    // ---------------------------------------------------------------
+
+    #[cfg(feature = "oracle")]
+    /// Current exchange rate of an oracle `pair`, e.g. "ubtc:uusd".
+    pub fn exchange_rate(&self, pair: impl Into<String>) -> StdResult<Decimal> {
+        let resp = query_grpc_proto(
+            &self.querier,
+            &nibiru::oracle::QueryExchangeRateRequest { pair: pair.into() },
+        )?;
+        price_from_sdk_dec(&resp.exchange_rate)
+    }
+
+    #[cfg(feature = "oracle")]
+    /// Current exchange rates of every active pair, keyed by pair.
+    pub fn exchange_rates(&self) -> StdResult<BTreeMap<String, Decimal>> {
+        let resp = query_grpc_proto(
+            &self.querier,
+            &nibiru::oracle::QueryExchangeRatesRequest {},
+        )?;
+        resp.exchange_rates
+            .into_iter()
+            .map(|tuple| {
+                let price = price_from_sdk_dec(&tuple.exchange_rate)?;
+                Ok((tuple.pair, price))
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "oracle")]
+    /// Pairs that currently have an active exchange rate.
+    pub fn oracle_actives(&self) -> StdResult<Vec<String>> {
+        let resp = query_grpc_proto(
+            &self.querier,
+            &nibiru::oracle::QueryActivesRequest {},
+        )?;
+        Ok(resp.actives)
+    }
+
+    // ---------------------------------------------------------------
+    // x/tokenfactory
+    // ---------------------------------------------------------------
+
+    #[cfg(feature = "tokenfactory")]
+    /// Admin and bank metadata of a tokenfactory `denom`.
+    pub fn denom_info(
+        &self,
+        denom: impl Into<String>,
+    ) -> StdResult<TfDenomInfo> {
+        let resp = query_grpc_proto(
+            &self.querier,
+            &nibiru::tokenfactory::QueryDenomInfoRequest {
+                denom: denom.into(),
+            },
+        )?;
+        Ok(TfDenomInfo {
+            admin: resp.admin,
+            metadata: resp.metadata.map(cw::DenomMetadata::from),
+        })
+    }
+
+    #[cfg(feature = "tokenfactory")]
+    /// Every tokenfactory denom created by `creator`.
+    pub fn denoms_by_creator(
+        &self,
+        creator: impl Into<String>,
+    ) -> StdResult<Vec<String>> {
+        let resp = query_grpc_proto(
+            &self.querier,
+            &nibiru::tokenfactory::QueryDenomsRequest {
+                creator: creator.into(),
+            },
+        )?;
+        Ok(resp.denoms)
+    }
+
+    // ---------------------------------------------------------------
+    // x/evm
+    // ---------------------------------------------------------------
+
+    #[cfg(feature = "evm")]
+    /// FunToken mapping for `token`, which is either an ERC20 hex address or
+    /// a bank denom.
+    pub fn funtoken_mapping(
+        &self,
+        token: impl Into<String>,
+    ) -> StdResult<FunTokenMapping> {
+        let resp = query_grpc_proto(
+            &self.querier,
+            &eth::evm::QueryFunTokenMappingRequest {
+                token: token.into(),
+            },
+        )?;
+        let fun_token = required(resp.fun_token, "fun_token")?;
+        Ok(FunTokenMapping {
+            erc20_addr: fun_token.erc20_addr,
+            bank_denom: fun_token.bank_denom,
+            is_made_from_coin: fun_token.is_made_from_coin,
+        })
+    }
+
+    #[cfg(feature = "evm")]
+    /// Account info for a Nibiru EVM account. `address` may be either a
+    /// bech32 or an Ethereum hex address.
+    pub fn eth_account(
+        &self,
+        address: impl Into<String>,
+    ) -> StdResult<EthAccount> {
+        let resp = query_grpc_proto(
+            &self.querier,
+            &eth::evm::QueryEthAccountRequest {
+                address: address.into(),
+            },
+        )?;
+        Ok(EthAccount {
+            balance: cw::Coin {
+                denom: EVM_BANK_DENOM.to_string(),
+                amount: parse_sdk_int(&resp.balance)?,
+            },
+            balance_wei: parse_sdk_int(&resp.balance_wei)?,
+            code_hash: resp.code_hash,
+            nonce: resp.nonce,
+            eth_address: resp.eth_address,
+            bech32_address: resp.bech32_address,
+        })
+    }
+
+    #[cfg(feature = "evm")]
+    /// Balance of the EVM gas token for `address`, in both unibi and wei
+    /// units.
+    pub fn evm_balance(
+        &self,
+        address: impl Into<String>,
+    ) -> StdResult<EvmBalance> {
+        let resp = query_grpc_proto(
+            &self.querier,
+            &eth::evm::QueryBalanceRequest {
+                address: address.into(),
+            },
+        )?;
+        Ok(EvmBalance {
+            balance: cw::Coin {
+                denom: EVM_BANK_DENOM.to_string(),
+                amount: parse_sdk_int(&resp.balance)?,
+            },
+            balance_wei: parse_sdk_int(&resp.balance_wei)?,
+        })
+    }
+
+    // ---------------------------------------------------------------
+    // x/epochs, x/inflation, x/sudo
+    // ---------------------------------------------------------------
+
+    #[cfg(feature = "epochs")]
+    /// Current epoch number for an epoch `identifier`, e.g. "day".
+    pub fn current_epoch(
+        &self,
+        identifier: impl Into<String>,
+    ) -> StdResult<u64> {
+        let resp = query_grpc_proto(
+            &self.querier,
+            &nibiru::epochs::QueryCurrentEpochRequest {
+                identifier: identifier.into(),
+            },
+        )?;
+        Ok(resp.current_epoch)
+    }
+
+    #[cfg(feature = "inflation")]
+    pub fn inflation_params(&self) -> StdResult<InflationParams> {
+        let resp = query_grpc_proto(
+            &self.querier,
+            &nibiru::inflation::QueryParamsRequest {},
+        )?;
+        let params = required(resp.params, "params")?;
+        let distr =
+            required(params.inflation_distribution, "inflation_distribution")?;
+        Ok(InflationParams {
+            inflation_enabled: params.inflation_enabled,
+            polynomial_factors: params
+                .polynomial_factors
+                .iter()
+                .map(|factor| sdk_dec(factor))
+                .collect::<StdResult<_>>()?,
+            inflation_distribution: InflationDistribution {
+                staking_rewards: price_from_sdk_dec(&distr.staking_rewards)?,
+                community_pool: price_from_sdk_dec(&distr.community_pool)?,
+                strategic_reserves: price_from_sdk_dec(
+                    &distr.strategic_reserves,
+                )?,
+            },
+            epochs_per_period: params.epochs_per_period,
+            periods_per_year: params.periods_per_year,
+            max_period: params.max_period,
+            has_inflation_started: params.has_inflation_started,
+        })
+    }
+
+    #[cfg(feature = "sudo")]
+    pub fn sudoers(&self) -> StdResult<Sudoers> {
+        let resp = query_grpc_proto(
+            &self.querier,
+            &nibiru::sudo::QuerySudoersRequest {},
+        )?;
+        let sudoers = required(resp.sudoers, "sudoers")?;
+        Ok(Sudoers {
+            root: sudoers.root,
+            contracts: sudoers.contracts,
+        })
+    }
+
+    // ---------------------------------------------------------------
+    // x/bank
+    // ---------------------------------------------------------------
+
+    #[cfg(feature = "bank")]
+    /// Total supply of `denom`.
+    pub fn supply_of(&self, denom: impl Into<String>) -> StdResult<cw::Coin> {
+        let resp = query_grpc_proto(
+            &self.querier,
+            &cosmos::bank::v1beta1::QuerySupplyOfRequest {
+                denom: denom.into(),
+            },
+        )?;
+        let coin = required(resp.amount, "amount")?;
+        cw::Coin::try_from(coin).map_err(StdError::from)
+    }
+
+    #[cfg(feature = "bank")]
+    pub fn denom_metadata(
+        &self,
+        denom: impl Into<String>,
+    ) -> StdResult<cw::DenomMetadata> {
+        let resp = query_grpc_proto(
+            &self.querier,
+            &cosmos::bank::v1beta1::QueryDenomMetadataRequest {
+                denom: denom.into(),
+            },
+        )?;
+        let metadata = required(resp.metadata, "metadata")?;
+        Ok(metadata.into())
+    }
+
+    // ---------------------------------------------------------------
+    // x/gov
+    // ---------------------------------------------------------------
+
+    #[cfg(feature = "gov")]
+    /// Gov proposal `proposal_id`. Errors if it does not exist.
+    pub fn gov_proposal(&self, proposal_id: u64) -> StdResult<ProposalInfo> {
+        let resp = query_grpc_proto(
+            &self.querier,
+            &cosmos::gov::v1::QueryProposalRequest { proposal_id },
+        )?;
+        Ok(ProposalInfo::try_from(resp)?)
+    }
+
+    // ---------------------------------------------------------------
+    // x/authz and x/feegrant
+    // ---------------------------------------------------------------
+
+    #[cfg(feature = "authz")]
+    /// A page of the authz grants from `granter` to `grantee`, or only the one
+    /// for `msg_type_url` if given. Pass `None` as `page_key` for the first
+    /// page, then the `next_key` of the previous page.
+    pub fn authz_grants(
+        &self,
+        granter: impl Into<String>,
+        grantee: impl Into<String>,
+        msg_type_url: Option<&str>,
+        page_key: Option<cw::Binary>,
+    ) -> StdResult<AuthzGrantsPage> {
+        let (granter, grantee) = (granter.into(), grantee.into());
+        let resp = query_grpc_proto(
+            &self.querier,
+            &cosmos::authz::v1beta1::QueryGrantsRequest {
+                granter: granter.clone(),
+                grantee: grantee.clone(),
+                msg_type_url: msg_type_url.unwrap_or_default().to_string(),
+                pagination: page_request(page_key),
+            },
+        )?;
+        let mut grants = vec![];
+        for grant in resp.grants {
+            grants.push(AuthzGrant {
+                granter: granter.clone(),
+                grantee: grantee.clone(),
+                ..AuthzGrant::try_from(grant)?
+            });
+        }
+        Ok(AuthzGrantsPage {
+            grants,
+            next_key: next_page_key(resp.pagination),
+        })
+    }
+
+    #[cfg(feature = "authz")]
+    /// A page of the authz grants held by `grantee`, across all granters.
+    /// Pass `None` as `page_key` for the first page, then the `next_key` of
+    /// the previous page.
+    pub fn authz_grantee_grants(
+        &self,
+        grantee: impl Into<String>,
+        page_key: Option<cw::Binary>,
+    ) -> StdResult<AuthzGrantsPage> {
+        let resp = query_grpc_proto(
+            &self.querier,
+            &cosmos::authz::v1beta1::QueryGranteeGrantsRequest {
+                grantee: grantee.into(),
+                pagination: page_request(page_key),
+            },
+        )?;
+        let mut grants = vec![];
+        for grant in resp.grants {
+            grants.push(AuthzGrant::try_from(grant)?);
+        }
+        Ok(AuthzGrantsPage {
+            grants,
+            next_key: next_page_key(resp.pagination),
+        })
+    }
+
+    #[cfg(feature = "feegrant")]
+    /// Fee allowance from `granter` to `grantee`. Errors if there is none.
+    pub fn fee_allowance(
+        &self,
+        granter: impl Into<String>,
+        grantee: impl Into<String>,
+    ) -> StdResult<FeeGrant> {
+        let resp = query_grpc_proto(
+            &self.querier,
+            &cosmos::feegrant::v1beta1::QueryAllowanceRequest {
+                granter: granter.into(),
+                grantee: grantee.into(),
+            },
+        )?;
+        let grant = required(resp.allowance, "allowance")?;
+        Ok(FeeGrant::try_from(grant)?)
+    }
+}
+
+/// Bank denom of the EVM gas token. Balances in wei are 10^12 times the
+/// balance in this denom.
+pub const EVM_BANK_DENOM: &str = "unibi";
+
+/// Tokenfactory denom info returned by [`NibiruQuerier::denom_info`].
+#[cw_serde]
+pub struct TfDenomInfo {
+    pub admin: String,
+    pub metadata: Option<cw::DenomMetadata>,
+}
+
+/// Mapping between a bank coin and an ERC20 token, returned by
+/// [`NibiruQuerier::funtoken_mapping`].
+#[cw_serde]
+pub struct FunTokenMapping {
+    /// Hexadecimal address of the ERC20 contract.
+    pub erc20_addr: String,
+    pub bank_denom: String,
+    /// True if the mapping was created from an existing bank coin.
+    pub is_made_from_coin: bool,
+}
+
+/// Nibiru EVM account returned by [`NibiruQuerier::eth_account`].
+#[cw_serde]
+pub struct EthAccount {
+    pub balance: cw::Coin,
+    pub balance_wei: Uint256,
+    pub code_hash: String,
+    pub nonce: u64,
+    pub eth_address: String,
+    pub bech32_address: String,
+}
+
+/// EVM gas token balance returned by [`NibiruQuerier::evm_balance`].
+#[cw_serde]
+pub struct EvmBalance {
+    pub balance: cw::Coin,
+    pub balance_wei: Uint256,
+}
+
+/// Parameters of the inflation module returned by
+/// [`NibiruQuerier::inflation_params`].
+#[cw_serde]
+pub struct InflationParams {
+    pub inflation_enabled: bool,
+    pub polynomial_factors: Vec<DecimalExt>,
+    pub inflation_distribution: InflationDistribution,
+    pub epochs_per_period: u64,
+    pub periods_per_year: u64,
+    pub max_period: u64,
+    pub has_inflation_started: bool,
+}
+
+#[cw_serde]
+pub struct InflationDistribution {
+    pub staking_rewards: Decimal,
+    pub community_pool: Decimal,
+    pub strategic_reserves: Decimal,
+}
+
+/// A page of authz grants returned by [`NibiruQuerier::authz_grants`] and
+/// [`NibiruQuerier::authz_grantee_grants`].
+#[cfg(feature = "authz")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuthzGrantsPage {
+    pub grants: Vec<AuthzGrant>,
+    /// Key of the next page, or `None` on the last page.
+    pub next_key: Option<cw::Binary>,
+}
+
+#[cw_serde]
+pub struct Sudoers {
+    pub root: String,
+    pub contracts: Vec<String>,
+}
+
+#[cfg(any(
+    feature = "evm",
+    feature = "inflation",
+    feature = "sudo",
+    feature = "bank",
+    feature = "feegrant"
+))]
+fn required<T>(field: Option<T>, name: &str) -> StdResult<T> {
+    field.ok_or_else(|| {
+        StdError::generic_err(format!("query response is missing \"{name}\""))
+    })
+}
+
+#[cfg(feature = "authz")]
+/// Request for the page starting at `key`, or the first page if there is
+/// none.
+fn page_request(key: Option<cw::Binary>) -> Option<PageRequest> {
+    key.filter(|key| !key.is_empty()).map(|key| PageRequest {
+        key: key.to_vec().into(),
+        ..Default::default()
+    })
+}
+
+#[cfg(feature = "authz")]
+fn next_page_key(page: Option<PageResponse>) -> Option<cw::Binary> {
+    page.map(|page| page.next_key)
+        .filter(|next_key| !next_key.is_empty())
+        .map(|next_key| cw::Binary::from(next_key.to_vec()))
+}
+
+#[cfg(any(feature = "oracle", feature = "evm", feature = "inflation"))]
+fn math_err(err: MathError) -> StdError {
+    NibiruError::from(err).into()
+}
+
+#[cfg(any(feature = "oracle", feature = "inflation"))]
+/// Parses the protobuf repr of a `LegacyDec`.
+fn sdk_dec(pb_repr: &str) -> StdResult<DecimalExt> {
+    DecimalExt::from_sdk_dec(pb_repr).map_err(math_err)
+}
+
+#[cfg(any(feature = "oracle", feature = "inflation"))]
+/// Parses the protobuf repr of a non-negative `LegacyDec`, like a price or a
+/// ratio.
+pub(crate) fn price_from_sdk_dec(pb_repr: &str) -> StdResult<Decimal> {
+    let dec = sdk_dec(pb_repr)?;
+    if dec.is_negative() {
+        return Err(math_err(MathError::OutOfRange {
+            value: dec.to_string(),
+            target: "cosmwasm_std::Decimal".to_string(),
+        }));
+    }
+    Ok(dec.abc_cw_dec())
+}
+
+#[cfg(feature = "evm")]
+/// Parses a `math.Int` into an unsigned integer type.
+fn parse_sdk_int<T>(int_str: &str) -> StdResult<T>
+where
+    T: TryFrom<SdkInt, Error = MathError>,
+{
+    let int: SdkInt = int_str.parse().map_err(math_err)?;
+    T::try_from(int).map_err(math_err)
+}
+
+#[cfg(all(test, feature = "all_protos"))]
+mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std::{Decimal, QuerierWrapper, Uint128, Uint256};
+
+    use super::NibiruQuerier;
+    use crate::{
+        errors::TestResult,
+        math::DecimalExt,
+        proto::{cosmos, eth, nibiru},
+        tutil::{stargate_query_err, StargateMockQuerier},
+    };
+
+    /// LegacyDec protobuf repr of a human readable decimal.
+    fn pb_dec(dec: &str) -> String {
+        DecimalExt::from_str(dec)
+            .unwrap()
+            .to_sdk_dec_pb_repr()
+            .unwrap()
+    }
+
+    #[test]
+    fn oracle_queries() -> TestResult {
+        let querier: StargateMockQuerier = StargateMockQuerier::new(&[])
+            .with_handler(|req: nibiru::oracle::QueryExchangeRateRequest| {
+                match req.pair.as_str() {
+                    "ubtc:uusd" => {
+                        Ok(nibiru::oracle::QueryExchangeRateResponse {
+                            exchange_rate: pb_dec("69420.5"),
+                            ..Default::default()
+                        })
+                    }
+                    "uneg:uusd" => {
+                        Ok(nibiru::oracle::QueryExchangeRateResponse {
+                            exchange_rate: pb_dec("-1"),
+                            ..Default::default()
+                        })
+                    }
+                    _ => stargate_query_err("pair not found"),
+                }
+            })
+            .with_handler(|_req: nibiru::oracle::QueryExchangeRatesRequest| {
+                Ok(nibiru::oracle::QueryExchangeRatesResponse {
+                    exchange_rates: vec![
+                        nibiru::oracle::ExchangeRateTuple {
+                            pair: "ubtc:uusd".to_string(),
+                            exchange_rate: pb_dec("69420.5"),
+                        },
+                        nibiru::oracle::ExchangeRateTuple {
+                            pair: "ueth:uusd".to_string(),
+                            exchange_rate: pb_dec("0.000001"),
+                        },
+                    ],
+                })
+            })
+            .with_handler(|_req: nibiru::oracle::QueryActivesRequest| {
+                Ok(nibiru::oracle::QueryActivesResponse {
+                    actives: vec!["ubtc:uusd".to_string()],
+                })
+            });
+        let wrapper = QuerierWrapper::<cosmwasm_std::Empty>::new(&querier);
+        let nibiru_querier = NibiruQuerier::new(&wrapper);
+
+        assert_eq!(
+            nibiru_querier.exchange_rate("ubtc:uusd")?,
+            Decimal::from_str("69420.5")?
+        );
+        assert!(nibiru_querier.exchange_rate("uneg:uusd").is_err());
+        let err = nibiru_querier.exchange_rate("umissing:uusd").unwrap_err();
+        assert!(err.to_string().contains("pair not found"), "{err}");
+
+        let rates = nibiru_querier.exchange_rates()?;
+        assert_eq!(rates.len(), 2);
+        assert_eq!(rates["ueth:uusd"], Decimal::from_str("0.000001")?);
+
+        assert_eq!(nibiru_querier.oracle_actives()?, vec!["ubtc:uusd"]);
+        Ok(())
+    }
+
+    #[test]
+    fn token_queries() -> TestResult {
+        let metadata = cosmos::bank::v1beta1::Metadata {
+            base: "tf/nibi1creator/foo".to_string(),
+            symbol: "FOO".to_string(),
+            ..Default::default()
+        };
+        let metadata_resp = metadata.clone();
+        let querier: StargateMockQuerier = StargateMockQuerier::new(&[])
+            .with_handler(
+                move |req: nibiru::tokenfactory::QueryDenomInfoRequest| {
+                    Ok(nibiru::tokenfactory::QueryDenomInfoResponse {
+                        admin: "nibi1admin".to_string(),
+                        metadata: Some(cosmos::bank::v1beta1::Metadata {
+                            base: req.denom,
+                            ..metadata_resp.clone()
+                        }),
+                    })
+                },
+            )
+            .with_handler(|req: nibiru::tokenfactory::QueryDenomsRequest| {
+                Ok(nibiru::tokenfactory::QueryDenomsResponse {
+                    denoms: vec![format!("tf/{}/foo", req.creator)],
+                })
+            })
+            .with_handler(|req: cosmos::bank::v1beta1::QuerySupplyOfRequest| {
+                Ok(cosmos::bank::v1beta1::QuerySupplyOfResponse {
+                    amount: Some(cosmos::base::v1beta1::Coin {
+                        denom: req.denom,
+                        amount: "1000".to_string(),
+                    }),
+                })
+            })
+            .with_handler(
+                |_req: cosmos::bank::v1beta1::QueryDenomMetadataRequest| {
+                    Ok(cosmos::bank::v1beta1::QueryDenomMetadataResponse {
+                        metadata: None,
+                    })
+                },
+            );
+        let wrapper = QuerierWrapper::<cosmwasm_std::Empty>::new(&querier);
+        let nibiru_querier = NibiruQuerier::new(&wrapper);
+
+        let info = nibiru_querier.denom_info("tf/nibi1creator/foo")?;
+        assert_eq!(info.admin, "nibi1admin");
+        assert_eq!(info.metadata, Some(metadata.into()));
+
+        assert_eq!(
+            nibiru_querier.denoms_by_creator("nibi1creator")?,
+            vec!["tf/nibi1creator/foo"]
+        );
+        assert_eq!(
+            nibiru_querier.supply_of("unibi")?,
+            cosmwasm_std::coin(1000, "unibi")
+        );
+        let err = nibiru_querier.denom_metadata("unibi").unwrap_err();
+        assert!(err.to_string().contains("metadata"), "{err}");
+        Ok(())
+    }
+
+    #[test]
+    fn evm_queries() -> TestResult {
+        let querier: StargateMockQuerier = StargateMockQuerier::new(&[])
+            .with_handler(|req: eth::evm::QueryFunTokenMappingRequest| {
+                if req.token != "unibi" {
+                    return Ok(eth::evm::QueryFunTokenMappingResponse {
+                        fun_token: None,
+                    });
+                }
+                Ok(eth::evm::QueryFunTokenMappingResponse {
+                    fun_token: Some(eth::evm::FunToken {
+                        erc20_addr: "0x7D4B7B8CA7E1a24928Bb96D59249c7a5bd1DfBe6"
+                            .to_string(),
+                        bank_denom: "unibi".to_string(),
+                        is_made_from_coin: true,
+                    }),
+                })
+            })
+            .with_handler(|req: eth::evm::QueryEthAccountRequest| {
+                Ok(eth::evm::QueryEthAccountResponse {
+                    balance: "5".to_string(),
+                    balance_wei: "5000000000000".to_string(),
+                    nonce: 7,
+                    bech32_address: req.address,
+                    ..Default::default()
+                })
+            })
+            .with_handler(|req: eth::evm::QueryBalanceRequest| {
+                Ok(eth::evm::QueryBalanceResponse {
+                    balance: if req.address == "nibi1bad" {
+                        "-1".to_string()
+                    } else {
+                        "5".to_string()
+                    },
+                    balance_wei: "5000000000000".to_string(),
+                })
+            });
+        let wrapper = QuerierWrapper::<cosmwasm_std::Empty>::new(&querier);
+        let nibiru_querier = NibiruQuerier::new(&wrapper);
+
+        let mapping = nibiru_querier.funtoken_mapping("unibi")?;
+        assert_eq!(mapping.bank_denom, "unibi");
+        assert!(mapping.is_made_from_coin);
+        assert!(nibiru_querier.funtoken_mapping("uusd").is_err());
+
+        let account = nibiru_querier.eth_account("nibi1account")?;
+        assert_eq!(account.balance, cosmwasm_std::coin(5, "unibi"));
+        assert_eq!(account.balance_wei, Uint256::from(5_000_000_000_000u128));
+        assert_eq!(account.nonce, 7);
+        assert_eq!(account.bech32_address, "nibi1account");
+
+        let balance = nibiru_querier.evm_balance("nibi1account")?;
+        assert_eq!(balance.balance.amount, Uint128::new(5));
+        assert!(nibiru_querier.evm_balance("nibi1bad").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn chain_param_queries() -> TestResult {
+        let querier: StargateMockQuerier = StargateMockQuerier::new(&[])
+            .with_handler(|req: nibiru::epochs::QueryCurrentEpochRequest| {
+                assert_eq!(req.identifier, "day");
+                Ok(nibiru::epochs::QueryCurrentEpochResponse {
+                    current_epoch: 42,
+                })
+            })
+            .with_handler(|_req: nibiru::inflation::QueryParamsRequest| {
+                Ok(nibiru::inflation::QueryParamsResponse {
+                    params: Some(nibiru::inflation::Params {
+                        inflation_enabled: true,
+                        polynomial_factors: vec![
+                            pb_dec("-0.00014"),
+                            pb_dec("0.0005"),
+                        ],
+                        inflation_distribution: Some(
+                            nibiru::inflation::InflationDistribution {
+                                staking_rewards: pb_dec("0.28"),
+                                community_pool: pb_dec("0.07"),
+                                strategic_reserves: pb_dec("0.65"),
+                            },
+                        ),
+                        epochs_per_period: 30,
+                        periods_per_year: 12,
+                        max_period: 96,
+                        has_inflation_started: true,
+                    }),
+                })
+            })
+            .with_handler(|_req: nibiru::sudo::QuerySudoersRequest| {
+                Ok(nibiru::sudo::QuerySudoersResponse {
+                    sudoers: Some(nibiru::sudo::Sudoers {
+                        root: "nibi1root".to_string(),
+                        contracts: vec!["nibi1contract".to_string()],
+                    }),
+                })
+            });
+        let wrapper = QuerierWrapper::<cosmwasm_std::Empty>::new(&querier);
+        let nibiru_querier = NibiruQuerier::new(&wrapper);
+
+        assert_eq!(nibiru_querier.current_epoch("day")?, 42);
+
+        let params = nibiru_querier.inflation_params()?;
+        assert_eq!(
+            params.polynomial_factors,
+            vec![
+                DecimalExt::from_str("-0.00014")?,
+                DecimalExt::from_str("0.0005")?
+            ]
+        );
+        assert_eq!(
+            params.inflation_distribution.strategic_reserves,
+            Decimal::percent(65)
+        );
+        assert_eq!(params.max_period, 96);
+
+        let sudoers = nibiru_querier.sudoers()?;
+        assert_eq!(sudoers.root, "nibi1root");
+        assert_eq!(sudoers.contracts, vec!["nibi1contract"]);
+        Ok(())
+    }
+
+    #[test]
+    fn authz_and_feegrant_queries() -> TestResult {
+        use cosmos::authz::v1beta1 as authz;
+        use cosmos::base::query::v1beta1::PageResponse;
+        use cosmos::feegrant::v1beta1 as feegrant;
+        use cosmwasm_std::Binary;
+
+        use crate::{
+            authz::{Authorization, AuthzGrant},
+            feegrant::FeeAllowance,
+        };
+
+        let fee_allowance = FeeAllowance::basic(vec![]).to_any()?;
+        let querier: StargateMockQuerier = StargateMockQuerier::new(&[])
+            .with_handler(|req: authz::QueryGrantsRequest| {
+                // Two pages: the first is requested without a key.
+                let key = req.pagination.map(|page| page.key.to_vec());
+                let (msg, next_key) = match key.as_deref() {
+                    None => ("/cosmos.gov.v1.MsgVote", b"page2".to_vec()),
+                    Some(b"page2") => ("/cosmos.bank.v1beta1.MsgSend", vec![]),
+                    Some(_) => return stargate_query_err("bad page key"),
+                };
+                Ok(authz::QueryGrantsResponse {
+                    grants: vec![authz::Grant {
+                        authorization: Some(
+                            Authorization::Generic {
+                                msg: msg.to_string(),
+                            }
+                            .to_any(),
+                        ),
+                        expiration: None,
+                    }],
+                    pagination: Some(PageResponse {
+                        next_key: next_key.into(),
+                        total: 0,
+                    }),
+                })
+            })
+            .with_handler(|req: authz::QueryGranteeGrantsRequest| {
+                Ok(authz::QueryGranteeGrantsResponse {
+                    grants: vec![authz::GrantAuthorization {
+                        granter: "nibi1granter".to_string(),
+                        grantee: req.grantee,
+                        authorization: Some(
+                            Authorization::send(vec![]).to_any(),
+                        ),
+                        expiration: None,
+                    }],
+                    pagination: None,
+                })
+            })
+            .with_handler(move |req: feegrant::QueryAllowanceRequest| {
+                Ok(feegrant::QueryAllowanceResponse {
+                    allowance: Some(feegrant::Grant {
+                        granter: req.granter,
+                        grantee: req.grantee,
+                        allowance: Some(fee_allowance.clone()),
+                    }),
+                })
+            });
+        let wrapper = QuerierWrapper::<cosmwasm_std::Empty>::new(&querier);
+        let nibiru_querier = NibiruQuerier::new(&wrapper);
+
+        // One page per call
+        let page = nibiru_querier.authz_grants(
+            "nibi1granter",
+            "nibi1contract",
+            None,
+            None,
+        )?;
+        let next_key = page.next_key.clone();
+        assert_eq!(next_key, Some(Binary::from(b"page2")));
+        let last_page = nibiru_querier.authz_grants(
+            "nibi1granter",
+            "nibi1contract",
+            None,
+            next_key,
+        )?;
+        assert_eq!(last_page.next_key, None);
+        let grants: Vec<AuthzGrant> = [page.grants, last_page.grants].concat();
+        let msg_type_urls: Vec<String> = grants
+            .iter()
+            .filter_map(|grant| grant.authorization.msg_type_url())
+            .collect();
+        assert_eq!(
+            msg_type_urls,
+            ["/cosmos.gov.v1.MsgVote", "/cosmos.bank.v1beta1.MsgSend"]
+        );
+        assert!(grants.iter().all(|grant| grant.granter == "nibi1granter"
+            && grant.grantee == "nibi1contract"));
+
+        let page = nibiru_querier.authz_grantee_grants("nibi1contract", None)?;
+        assert_eq!(page.next_key, None);
+        let grants = page.grants;
+        assert_eq!(grants.len(), 1);
+        assert_eq!(grants[0].granter, "nibi1granter");
+        assert_eq!(grants[0].authorization, Authorization::send(vec![]));
+
+        let fee_grant =
+            nibiru_querier.fee_allowance("nibi1granter", "nibi1contract")?;
+        assert_eq!(fee_grant.grantee, "nibi1contract");
+        assert_eq!(fee_grant.allowance, FeeAllowance::basic(vec![]));
+        Ok(())
+    }
+
+    #[test]
+    fn gov_queries() -> TestResult {
+        use cosmos::gov::v1 as gov;
+
+        use crate::gov::ProposalStatus;
+
+        let querier: StargateMockQuerier = StargateMockQuerier::new(&[])
+            .with_handler(|req: gov::QueryProposalRequest| {
+                if req.proposal_id != 1 {
+                    return stargate_query_err("proposal 2 doesn't exist");
+                }
+                Ok(gov::QueryProposalResponse {
+                    proposal: Some(gov::Proposal {
+                        id: 1,
+                        status: ProposalStatus::Passed.into(),
+                        title: "Upgrade".to_string(),
+                        ..Default::default()
+                    }),
+                })
+            });
+        let wrapper = QuerierWrapper::<cosmwasm_std::Empty>::new(&querier);
+        let nibiru_querier = NibiruQuerier::new(&wrapper);
+
+        let proposal = nibiru_querier.gov_proposal(1)?;
+        assert_eq!(proposal.status, ProposalStatus::Passed);
+        assert_eq!(proposal.title, "Upgrade");
+        assert!(nibiru_querier.gov_proposal(2).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,597 @@
+//! signing.rs: Verification of off-chain signatures made by Cosmos wallets,
+//! such as Keplr and Leap, so that contracts can accept signed intents.
+//!
+//! - [AminoSignDoc]: Legacy amino JSON `StdSignDoc`, including the ADR-036
+//!   `signArbitrary` payload ([AminoSignDoc::adr036]).
+//! - [DirectSignDoc]: Protobuf `cosmos.tx.v1beta1.SignDoc` of
+//!   `SIGN_MODE_DIRECT`.
+//! - [SignerPubKey]: A secp256k1 or ed25519 public key, with the account
+//!   address it controls and signature verification through
+//!   `cosmwasm_std::Api`.
+//!
+//! Accounts with an `eth_secp256k1` key sign keccak256 digests and derive
+//! their address from the uncompressed key, and are not supported here.
+
+use cosmwasm_std::{self as cw, Api};
+use prost::Message;
+use ripemd::Ripemd160;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    address::{bech32_decode, bech32_encode, Bech32Variant, HRP_ACCOUNT},
+    errors::{NibiruError, NibiruResult},
+    proto::{
+        cosmos::{
+            crypto::{ed25519, secp256k1},
+            tx::v1beta1::{AuthInfo, SignDoc, TxBody},
+        },
+        NibiruProstMsg, NibiruStargateMsg,
+    },
+};
+
+/// Amino type of the message wrapped by an ADR-036 sign doc.
+pub const ADR036_MSG_TYPE: &str = "sign/MsgSignData";
+
+/// SignerPubKey: Public key of a Cosmos account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignerPubKey {
+    /// Compressed secp256k1 key, `cosmos.crypto.secp256k1.PubKey`.
+    Secp256k1([u8; 33]),
+    /// `cosmos.crypto.ed25519.PubKey`.
+    Ed25519([u8; 32]),
+}
+
+impl SignerPubKey {
+    /// Parses a compressed secp256k1 key, as found in the `pub_key` of a
+    /// wallet's `signArbitrary` response.
+    pub fn secp256k1(key: &[u8]) -> NibiruResult<Self> {
+        let key: [u8; 33] = key.try_into().map_err(|_| {
+            invalid_pubkey(format!(
+                "secp256k1 keys are 33 bytes when compressed, got {}",
+                key.len()
+            ))
+        })?;
+        if key[0] != 0x02 && key[0] != 0x03 {
+            return Err(invalid_pubkey(
+                "secp256k1 key is not in compressed form".to_string(),
+            ));
+        }
+        Ok(SignerPubKey::Secp256k1(key))
+    }
+
+    pub fn ed25519(key: &[u8]) -> NibiruResult<Self> {
+        let key: [u8; 32] = key.try_into().map_err(|_| {
+            invalid_pubkey(format!(
+                "ed25519 keys are 32 bytes, got {}",
+                key.len()
+            ))
+        })?;
+        Ok(SignerPubKey::Ed25519(key))
+    }
+
+    /// Decodes a public key from its `Any` form, as found in the signer
+    /// infos of a transaction's `AuthInfo`.
+    pub fn from_any(any: &prost_types::Any) -> NibiruResult<Self> {
+        let type_url = any.type_url.as_str();
+        if type_url == <secp256k1::PubKey as prost::Name>::type_url() {
+            SignerPubKey::secp256k1(&secp256k1::PubKey::decode(&*any.value)?.key)
+        } else if type_url == <ed25519::PubKey as prost::Name>::type_url() {
+            SignerPubKey::ed25519(&ed25519::PubKey::decode(&*any.value)?.key)
+        } else {
+            Err(invalid_pubkey(format!("unsupported key type {type_url}")))
+        }
+    }
+
+    pub fn to_any(&self) -> prost_types::Any {
+        match self {
+            SignerPubKey::Secp256k1(key) => secp256k1::PubKey {
+                key: key.to_vec().into(),
+            }
+            .to_any(),
+            SignerPubKey::Ed25519(key) => ed25519::PubKey {
+                key: key.to_vec().into(),
+            }
+            .to_any(),
+        }
+    }
+
+    pub fn key_bytes(&self) -> &[u8] {
+        match self {
+            SignerPubKey::Secp256k1(key) => key,
+            SignerPubKey::Ed25519(key) => key,
+        }
+    }
+
+    /// The 20-byte account address controlled by the key:
+    /// `ripemd160(sha256(key))` for secp256k1 and `sha256(key)[..20]` for
+    /// ed25519.
+    pub fn address_bytes(&self) -> [u8; 20] {
+        let mut addr = [0u8; 20];
+        match self {
+            SignerPubKey::Secp256k1(key) => {
+                addr.copy_from_slice(&Ripemd160::digest(Sha256::digest(key)))
+            }
+            SignerPubKey::Ed25519(key) => {
+                addr.copy_from_slice(&Sha256::digest(key)[..20])
+            }
+        }
+        addr
+    }
+
+    /// Bech32 account address of the key for the human-readable part `hrp`.
+    pub fn to_bech32(&self, hrp: &str) -> NibiruResult<String> {
+        bech32_encode(hrp, &self.address_bytes(), Bech32Variant::Bech32)
+    }
+
+    /// "nibi" bech32 account address of the key.
+    ///
+    /// ```
+    /// use nibiru_std::signing::SignerPubKey;
+    ///
+    /// let pubkey = SignerPubKey::secp256k1(
+    ///     &hex::decode(
+    ///         "02d41a0aa167b21699429eab224bc03f2cd386f0af5d20cefbd0336f1544aea24f",
+    ///     )
+    ///     .unwrap(),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(
+    ///     pubkey.to_bech32("cosmos").unwrap(),
+    ///     "cosmos1h806c7khnvmjlywdrkdgk2vrayy2mmvf9rxk2r"
+    /// );
+    /// assert!(pubkey.to_nibi_bech32().starts_with("nibi1"));
+    /// ```
+    pub fn to_nibi_bech32(&self) -> String {
+        self.to_bech32(HRP_ACCOUNT)
+            .expect("20 bytes always encode with the nibi prefix")
+    }
+
+    /// Errors unless the key controls `signer`, a bech32 account address of
+    /// any prefix.
+    pub fn check_signer(&self, signer: &str) -> NibiruResult<()> {
+        let (hrp, bytes, _) = bech32_decode(signer)?;
+        if bytes != self.address_bytes() {
+            return Err(NibiruError::SignerMismatch {
+                signer: signer.to_string(),
+                pubkey_addr: self.to_bech32(&hrp)?,
+            });
+        }
+        Ok(())
+    }
+
+    /// Verifies `signature` over `sign_bytes`. Cosmos secp256k1 keys sign
+    /// the sha256 digest of the sign bytes, as a 64-byte `r || s`, while
+    /// ed25519 keys sign the bytes themselves.
+    pub fn verify(
+        &self,
+        api: &dyn Api,
+        sign_bytes: &[u8],
+        signature: &[u8],
+    ) -> NibiruResult<()> {
+        let is_valid = match self {
+            SignerPubKey::Secp256k1(key) => {
+                let digest = Sha256::digest(sign_bytes);
+                api.secp256k1_verify(&digest, signature, key)?
+            }
+            SignerPubKey::Ed25519(key) => {
+                api.ed25519_verify(sign_bytes, signature, key)?
+            }
+        };
+        if !is_valid {
+            return Err(NibiruError::InvalidSignature);
+        }
+        Ok(())
+    }
+}
+
+fn invalid_pubkey(reason: String) -> NibiruError {
+    NibiruError::InvalidPubKey(reason)
+}
+
+/// AminoSignDoc: A legacy amino JSON `StdSignDoc`, signed with
+/// `SIGN_MODE_LEGACY_AMINO_JSON`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AminoSignDoc {
+    pub chain_id: String,
+    pub account_number: u64,
+    pub sequence: u64,
+    pub fee: AminoFee,
+    pub msgs: Vec<AminoMsg>,
+    pub memo: String,
+}
+
+/// AminoFee: The `fee` of an [AminoSignDoc].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AminoFee {
+    pub amount: Vec<cw::Coin>,
+    pub gas: u64,
+    pub payer: Option<String>,
+    pub granter: Option<String>,
+}
+
+/// AminoMsg: A message of an [AminoSignDoc] in its amino JSON form, e.g.
+/// `{"type": "cosmos-sdk/MsgSend", "value": {...}}`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AminoMsg {
+    pub r#type: String,
+    pub value: Value,
+}
+
+impl AminoSignDoc {
+    /// Sign doc of an ADR-036 arbitrary message, which is what a wallet's
+    /// `signArbitrary(chain_id, signer, data)` signs. It is bound to no
+    /// chain, account number, or sequence, so contracts should put a nonce
+    /// or expiry in `data` to prevent replays.
+    ///
+    /// ```
+    /// use nibiru_std::signing::AminoSignDoc;
+    ///
+    /// let sign_doc = AminoSignDoc::adr036("nibi1signer", b"hello");
+    /// assert_eq!(
+    ///     String::from_utf8(sign_doc.sign_bytes()).unwrap(),
+    ///     r#"{"account_number":"0","chain_id":"","fee":{"amount":[],"gas":"0"},"memo":"","msgs":[{"type":"sign/MsgSignData","value":{"data":"aGVsbG8=","signer":"nibi1signer"}}],"sequence":"0"}"#
+    /// );
+    /// ```
+    pub fn adr036(signer: impl Into<String>, data: &[u8]) -> Self {
+        AminoSignDoc {
+            msgs: vec![AminoMsg {
+                r#type: ADR036_MSG_TYPE.to_string(),
+                value: json!({
+                    "data": cw::Binary::from(data).to_base64(),
+                    "signer": signer.into(),
+                }),
+            }],
+            ..Default::default()
+        }
+    }
+
+    /// Canonical sign bytes: JSON with sorted keys and no whitespace, where
+    /// "&", "<", and ">" are escaped as in Go's `encoding/json`.
+    pub fn sign_bytes(&self) -> Vec<u8> {
+        let mut fee = json!({
+            "amount": self
+                .fee
+                .amount
+                .iter()
+                .map(|coin| json!({
+                    "amount": coin.amount.to_string(),
+                    "denom": coin.denom,
+                }))
+                .collect::<Vec<_>>(),
+            "gas": self.fee.gas.to_string(),
+        });
+        for (key, value) in
+            [("payer", &self.fee.payer), ("granter", &self.fee.granter)]
+        {
+            if let Some(value) = value {
+                fee[key] = json!(value);
+            }
+        }
+        let doc = json!({
+            "account_number": self.account_number.to_string(),
+            "chain_id": self.chain_id,
+            "fee": fee,
+            "memo": self.memo,
+            "msgs": self
+                .msgs
+                .iter()
+                .map(|msg| json!({"type": msg.r#type, "value": msg.value}))
+                .collect::<Vec<_>>(),
+            "sequence": self.sequence.to_string(),
+        });
+
+        let mut out = String::new();
+        write_sorted_json(&doc, &mut out);
+        out.replace('&', "\\u0026")
+            .replace('<', "\\u003c")
+            .replace('>', "\\u003e")
+            .into_bytes()
+    }
+}
+
+/// Writes `value` as compact JSON with the keys of every object sorted,
+/// independent of whether serde_json preserves insertion order.
+fn write_sorted_json(value: &Value, out: &mut String) {
+    match value {
+        Value::Array(items) => {
+            out.push('[');
+            for (idx, item) in items.iter().enumerate() {
+                if idx > 0 {
+                    out.push(',');
+                }
+                write_sorted_json(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<(&String, &Value)> = map.iter().collect();
+            entries.sort_by_key(|&(key, _)| key);
+            out.push('{');
+            for (idx, (key, item)) in entries.into_iter().enumerate() {
+                if idx > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_sorted_json(item, out);
+            }
+            out.push('}');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+/// Verifies an ADR-036 `signArbitrary` signature of `data` by `signer`: the
+/// key must control `signer` and have signed [AminoSignDoc::adr036].
+///
+/// Wallets return the key and signature base64 encoded, under `pub_key.value`
+/// and `signature`.
+pub fn verify_adr036(
+    api: &dyn Api,
+    pubkey: &SignerPubKey,
+    signer: &str,
+    data: &[u8],
+    signature: &[u8],
+) -> NibiruResult<()> {
+    pubkey.check_signer(signer)?;
+    let sign_bytes = AminoSignDoc::adr036(signer, data).sign_bytes();
+    pubkey.verify(api, &sign_bytes, signature)
+}
+
+/// DirectSignDoc: The protobuf `SignDoc` of `SIGN_MODE_DIRECT`.
+///
+/// Keep the body and auth info as the exact bytes the wallet signed; encoding
+/// a decoded `TxBody` again is not guaranteed to give the same bytes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DirectSignDoc {
+    pub body_bytes: Vec<u8>,
+    pub auth_info_bytes: Vec<u8>,
+    pub chain_id: String,
+    pub account_number: u64,
+}
+
+impl DirectSignDoc {
+    /// Sign doc for a body and auth info built in the contract.
+    pub fn new(
+        body: &TxBody,
+        auth_info: &AuthInfo,
+        chain_id: impl Into<String>,
+        account_number: u64,
+    ) -> Self {
+        DirectSignDoc {
+            body_bytes: body.to_bytes(),
+            auth_info_bytes: auth_info.to_bytes(),
+            chain_id: chain_id.into(),
+            account_number,
+        }
+    }
+
+    pub fn sign_bytes(&self) -> Vec<u8> {
+        SignDoc {
+            body_bytes: self.body_bytes.clone().into(),
+            auth_info_bytes: self.auth_info_bytes.clone().into(),
+            chain_id: self.chain_id.clone(),
+            account_number: self.account_number,
+        }
+        .encode_to_vec()
+    }
+
+    /// Decodes the signed body, e.g. to read its messages and memo.
+    pub fn body(&self) -> NibiruResult<TxBody> {
+        Ok(TxBody::decode(self.body_bytes.as_slice())?)
+    }
+
+    /// Public keys of the signers listed in the signed auth info.
+    pub fn signer_pubkeys(&self) -> NibiruResult<Vec<SignerPubKey>> {
+        let auth_info = AuthInfo::decode(self.auth_info_bytes.as_slice())?;
+        auth_info
+            .signer_infos
+            .iter()
+            .map(|info| {
+                let any = info.public_key.as_ref().ok_or_else(|| {
+                    invalid_pubkey("signer info has no public key".to_string())
+                })?;
+                SignerPubKey::from_any(any)
+            })
+            .collect()
+    }
+
+    /// Verifies a signature of the sign doc by `pubkey`.
+    pub fn verify(
+        &self,
+        api: &dyn Api,
+        pubkey: &SignerPubKey,
+        signature: &[u8],
+    ) -> NibiruResult<()> {
+        pubkey.verify(api, &self.sign_bytes(), signature)
+    }
+}
+
+#[cfg(all(test, feature = "all_protos"))]
+mod tests {
+    use cosmwasm_std::testing::MockApi;
+    use k256::ecdsa::{signature::Signer, Signature, SigningKey};
+    use sha2::{Digest, Sha256};
+
+    use super::*;
+    use crate::{
+        errors::TestResult,
+        proto::cosmos::{
+            bank::v1beta1::MsgSend,
+            tx::v1beta1::{Fee, SignerInfo},
+        },
+    };
+
+    fn secp256k1_key() -> (SigningKey, SignerPubKey) {
+        let signing_key =
+            SigningKey::from_slice(&Sha256::digest(b"alice")).unwrap();
+        let pubkey = signing_key.verifying_key().to_encoded_point(true);
+        let pubkey = SignerPubKey::secp256k1(pubkey.as_bytes()).unwrap();
+        (signing_key, pubkey)
+    }
+
+    fn secp256k1_sign(key: &SigningKey, sign_bytes: &[u8]) -> Vec<u8> {
+        // Signer::sign hashes the message with sha256.
+        let signature: Signature = key.sign(sign_bytes);
+        signature.to_bytes().to_vec()
+    }
+
+    #[test]
+    fn pubkey_addresses() -> TestResult {
+        // Vectors from cosmjs.
+        let secp = SignerPubKey::secp256k1(&hex::decode(
+            "02d41a0aa167b21699429eab224bc03f2cd386f0af5d20cefbd0336f1544aea24f",
+        )?)?;
+        assert_eq!(
+            secp.to_bech32("cosmos")?,
+            "cosmos1h806c7khnvmjlywdrkdgk2vrayy2mmvf9rxk2r"
+        );
+        secp.check_signer("cosmos1h806c7khnvmjlywdrkdgk2vrayy2mmvf9rxk2r")?;
+        secp.check_signer(&secp.to_nibi_bech32())?;
+
+        let ed = SignerPubKey::ed25519(&hex::decode(
+            "12ee6f581fe55673a1e9e1382a0829e32075a0aa4763c968bc526e1852e78c95",
+        )?)?;
+        assert_eq!(
+            ed.to_bech32("cosmos")?,
+            "cosmos1pfq05em6sfkls66ut4m2257p7qwlk448h8mysz"
+        );
+        let err = ed.check_signer(&secp.to_nibi_bech32()).unwrap_err();
+        assert!(matches!(err, NibiruError::SignerMismatch { .. }), "{err}");
+
+        for pubkey in [secp, ed] {
+            assert_eq!(SignerPubKey::from_any(&pubkey.to_any())?, pubkey);
+        }
+
+        assert!(SignerPubKey::secp256k1(&[0x04; 33]).is_err());
+        assert!(SignerPubKey::secp256k1(&[0x02; 65]).is_err());
+        assert!(SignerPubKey::ed25519(&[0; 33]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn amino_sign_bytes_are_canonical() {
+        let sign_doc = AminoSignDoc {
+            chain_id: "cataclysm-1".to_string(),
+            account_number: 7,
+            sequence: 2,
+            fee: AminoFee {
+                amount: vec![cw::coin(500, "unibi")],
+                gas: 200_000,
+                payer: None,
+                granter: Some("nibi1granter".to_string()),
+            },
+            msgs: vec![AminoMsg {
+                r#type: "cosmos-sdk/MsgSend".to_string(),
+                value: json!({
+                    "to_address": "nibi1to",
+                    "from_address": "nibi1from",
+                    "amount": [{"denom": "unibi", "amount": "1"}],
+                }),
+            }],
+            memo: "<intent> & more".to_string(),
+        };
+        assert_eq!(
+            String::from_utf8(sign_doc.sign_bytes()).unwrap(),
+            concat!(
+                r#"{"account_number":"7","chain_id":"cataclysm-1","#,
+                r#""fee":{"amount":[{"amount":"500","denom":"unibi"}],"#,
+                r#""gas":"200000","granter":"nibi1granter"},"#,
+                r#""memo":"\u003cintent\u003e \u0026 more","#,
+                r#""msgs":[{"type":"cosmos-sdk/MsgSend","value":{"#,
+                r#""amount":[{"amount":"1","denom":"unibi"}],"#,
+                r#""from_address":"nibi1from","to_address":"nibi1to"}}],"#,
+                r#""sequence":"2"}"#,
+            )
+        );
+    }
+
+    #[test]
+    fn adr036_verification() -> TestResult {
+        let api = MockApi::default();
+        let (signing_key, pubkey) = secp256k1_key();
+        let signer = pubkey.to_nibi_bech32();
+        let data = br#"{"action":"withdraw","nonce":1}"#;
+
+        let sign_bytes = AminoSignDoc::adr036(&signer, data).sign_bytes();
+        let signature = secp256k1_sign(&signing_key, &sign_bytes);
+        verify_adr036(&api, &pubkey, &signer, data, &signature)?;
+
+        // Tampered data
+        let err = verify_adr036(&api, &pubkey, &signer, b"other", &signature)
+            .unwrap_err();
+        assert_eq!(err, NibiruError::InvalidSignature);
+
+        // Key does not control the claimed signer
+        let other = SignerPubKey::Ed25519([7; 32]).to_nibi_bech32();
+        let err =
+            verify_adr036(&api, &pubkey, &other, data, &signature).unwrap_err();
+        assert!(matches!(err, NibiruError::SignerMismatch { .. }), "{err}");
+
+        // Malformed signature
+        assert!(verify_adr036(&api, &pubkey, &signer, data, &[0; 10]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn direct_sign_doc_verification() -> TestResult {
+        let api = MockApi::default();
+        let (signing_key, pubkey) = secp256k1_key();
+        let body = TxBody {
+            messages: vec![MsgSend {
+                from_address: pubkey.to_nibi_bech32(),
+                to_address: "nibi1to".to_string(),
+                amount: vec![cw::coin(1, "unibi").into()],
+            }
+            .to_any()],
+            memo: "intent".to_string(),
+            ..Default::default()
+        };
+        let auth_info = AuthInfo {
+            signer_infos: vec![SignerInfo {
+                public_key: Some(pubkey.to_any()),
+                mode_info: None,
+                sequence: 0,
+            }],
+            fee: Some(Fee::default()),
+            ..Default::default()
+        };
+        let sign_doc = DirectSignDoc::new(&body, &auth_info, "cataclysm-1", 7);
+        let signature = secp256k1_sign(&signing_key, &sign_doc.sign_bytes());
+
+        assert_eq!(sign_doc.signer_pubkeys()?, vec![pubkey.clone()]);
+        assert_eq!(sign_doc.body()?.memo, "intent");
+        sign_doc.verify(&api, &pubkey, &signature)?;
+
+        let other_chain = DirectSignDoc {
+            chain_id: "nibiru-localnet-0".to_string(),
+            ..sign_doc
+        };
+        assert_eq!(
+            other_chain.verify(&api, &pubkey, &signature),
+            Err(NibiruError::InvalidSignature)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ed25519_verification() -> TestResult {
+        use ed25519_zebra::SigningKey as EdSigningKey;
+
+        let api = MockApi::default();
+        let signing_key = EdSigningKey::from([9u8; 32]);
+        let pubkey = SignerPubKey::ed25519(
+            ed25519_zebra::VerificationKey::from(&signing_key).as_ref(),
+        )?;
+        let sign_bytes = AminoSignDoc::adr036("nibi1x", b"data").sign_bytes();
+        let signature: [u8; 64] = signing_key.sign(&sign_bytes).into();
+
+        pubkey.verify(&api, &sign_bytes, &signature)?;
+        assert_eq!(
+            pubkey.verify(&api, b"tampered", &signature),
+            Err(NibiruError::InvalidSignature)
+        );
+        Ok(())
+    }
+}
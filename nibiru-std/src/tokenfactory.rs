@@ -0,0 +1,651 @@
+//! tokenfactory.rs: Typed helpers for the Nibiru token factory module, so
+//! that contracts catch invalid denoms and messages before broadcast instead
+//! of at `DeliverTx`.
+//!
+//! - [TfDenom]: A parsed "tf/{creator}/{subdenom}" denom, validated with the
+//!   same rules as the chain.
+//! - [MetadataBuilder]: Builds and validates a `cosmos.bank.v1beta1.Metadata`
+//!   for a [TfDenom].
+//! - [create_denom], [mint], [burn], [change_admin], and
+//!   [set_denom_metadata]: Constructors for the `nibiru.tokenfactory.v1`
+//!   messages.
+
+use std::{fmt, str::FromStr};
+
+use cosmwasm_schema::schemars::{
+    gen::SchemaGenerator, schema::Schema, JsonSchema,
+};
+use cosmwasm_std::{self as cw, Uint128};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    address::{NibiruAddress, HRP_ACCOUNT},
+    errors::{NibiruError, NibiruResult},
+    proto::{
+        cosmos::bank::v1beta1::{DenomUnit, Metadata},
+        nibiru::tokenfactory as tf,
+    },
+};
+
+/// First part of every token factory denom.
+pub const TF_DENOM_PREFIX: &str = "tf";
+
+/// Maximum length in bytes of the subdenom of a [TfDenom].
+pub const MAX_SUBDENOM_LEN: usize = 44;
+
+/// Maximum length of any bank denom, from `sdk.ValidateDenom`.
+pub const MAX_DENOM_LEN: usize = 128;
+
+/// TfDenom: A token factory denom, "tf/{creator}/{subdenom}". The creator
+/// is the bech32 "nibi" address that created the denom and is its initial
+/// admin.
+///
+/// A `TfDenom` can only be built through [TfDenom::new] or by parsing, so
+/// holding one means that the denom would be accepted by the chain.
+///
+/// ```
+/// use nibiru_std::{address::contract_address_classic, tokenfactory::TfDenom};
+///
+/// let creator = contract_address_classic(1, 1);
+/// let denom = TfDenom::new(&creator, "ustable").unwrap();
+/// assert_eq!(denom.to_string(), format!("tf/{creator}/ustable"));
+/// assert_eq!(denom.to_string().parse::<TfDenom>().unwrap(), denom);
+///
+/// assert!(TfDenom::new(&creator, "bad/subdenom").is_err());
+/// assert!(TfDenom::new("cosmos2contract", "ustable").is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TfDenom {
+    creator: String,
+    subdenom: String,
+}
+
+impl TfDenom {
+    pub fn new(
+        creator: impl Into<String>,
+        subdenom: impl Into<String>,
+    ) -> NibiruResult<Self> {
+        let denom = Self {
+            creator: creator.into(),
+            subdenom: subdenom.into(),
+        };
+        denom
+            .validate()
+            .map_err(|reason| NibiruError::InvalidTfDenom {
+                denom: denom.to_string(),
+                reason,
+            })?;
+        Ok(denom)
+    }
+
+    pub fn creator(&self) -> &str {
+        &self.creator
+    }
+
+    pub fn subdenom(&self) -> &str {
+        &self.subdenom
+    }
+
+    /// Returns a coin of `amount` of this denom.
+    pub fn coin(&self, amount: impl Into<Uint128>) -> cw::Coin {
+        cw::Coin::new(amount.into().u128(), self.to_string())
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        NibiruAddress::from_bech32_with_hrp(&self.creator, HRP_ACCOUNT)
+            .map_err(|err| format!("invalid creator: {err}"))?;
+        validate_subdenom(&self.subdenom)?;
+        validate_denom(&self.to_string())
+    }
+}
+
+fn validate_subdenom(subdenom: &str) -> Result<(), String> {
+    if subdenom.is_empty() {
+        return Err("subdenom cannot be empty".to_string());
+    }
+    if subdenom.len() > MAX_SUBDENOM_LEN {
+        return Err(format!(
+            "subdenom is {} bytes, above the max of {MAX_SUBDENOM_LEN}",
+            subdenom.len()
+        ));
+    }
+    match subdenom.chars().find(|c| !is_denom_char(*c) || *c == '/') {
+        Some(c) => Err(format!("subdenom has invalid character '{c}'")),
+        None => Ok(()),
+    }
+}
+
+fn is_denom_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '/' | ':' | '.' | '_' | '-')
+}
+
+/// Validates a bank denom with the rules of `sdk.ValidateDenom`: 3 to 128
+/// characters from `[a-zA-Z0-9/:._-]`, starting with a letter.
+fn validate_denom(denom: &str) -> Result<(), String> {
+    if !(3..=MAX_DENOM_LEN).contains(&denom.len()) {
+        return Err(format!(
+            "denom \"{denom}\" must be 3 to {MAX_DENOM_LEN} characters long"
+        ));
+    }
+    if !denom.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return Err(format!("denom \"{denom}\" must start with a letter"));
+    }
+    match denom.chars().find(|c| !is_denom_char(*c)) {
+        Some(c) => Err(format!("denom \"{denom}\" has invalid character '{c}'")),
+        None => Ok(()),
+    }
+}
+
+impl fmt::Display for TfDenom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{TF_DENOM_PREFIX}/{}/{}", self.creator, self.subdenom)
+    }
+}
+
+impl FromStr for TfDenom {
+    type Err = NibiruError;
+
+    /// Parses a "tf/{creator}/{subdenom}" denom.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| NibiruError::InvalidTfDenom {
+            denom: s.to_string(),
+            reason: reason.to_string(),
+        };
+        let parts: Vec<&str> = s.split('/').collect();
+        match parts.as_slice() {
+            [TF_DENOM_PREFIX, creator, subdenom] => {
+                TfDenom::new(*creator, *subdenom)
+            }
+            [_, _, _] => Err(invalid("denom must start with \"tf/\"")),
+            _ => Err(invalid("denom must have 3 parts separated by '/'")),
+        }
+    }
+}
+
+impl TryFrom<String> for TfDenom {
+    type Error = NibiruError;
+
+    fn try_from(denom: String) -> Result<Self, Self::Error> {
+        denom.parse()
+    }
+}
+
+impl From<TfDenom> for String {
+    fn from(denom: TfDenom) -> String {
+        denom.to_string()
+    }
+}
+
+impl TryFrom<tf::TfDenom> for TfDenom {
+    type Error = NibiruError;
+
+    fn try_from(pb: tf::TfDenom) -> Result<Self, Self::Error> {
+        TfDenom::new(pb.creator, pb.subdenom)
+    }
+}
+
+impl From<TfDenom> for tf::TfDenom {
+    fn from(denom: TfDenom) -> tf::TfDenom {
+        tf::TfDenom {
+            creator: denom.creator,
+            subdenom: denom.subdenom,
+        }
+    }
+}
+
+impl Serialize for TfDenom {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TfDenom {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let denom = String::deserialize(deserializer)?;
+        TfDenom::from_str(&denom).map_err(de::Error::custom)
+    }
+}
+
+impl JsonSchema for TfDenom {
+    fn schema_name() -> String {
+        "TfDenom".to_string()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        String::json_schema(gen)
+    }
+}
+
+/// MetadataBuilder: Builder for the bank `Metadata` of a [TfDenom]. The
+/// base unit, the [TfDenom] itself with exponent 0, is added by
+/// [MetadataBuilder::new].
+///
+/// ```
+/// use nibiru_std::{
+///     address::contract_address_classic,
+///     tokenfactory::{MetadataBuilder, TfDenom},
+/// };
+///
+/// let denom = TfDenom::new(contract_address_classic(1, 1), "ustable")?;
+/// let metadata = MetadataBuilder::new(&denom)
+///     .unit("stable", 6)
+///     .display("stable")
+///     .name("Stable")
+///     .symbol("STBL")
+///     .build()?;
+/// assert_eq!(metadata.base, denom.to_string());
+/// assert_eq!(metadata.denom_units[1].exponent, 6);
+///
+/// // The display unit must be one of the denom units.
+/// assert!(MetadataBuilder::new(&denom)
+///     .display("mstable")
+///     .name("Stable")
+///     .symbol("STBL")
+///     .build()
+///     .is_err());
+/// # Ok::<(), nibiru_std::errors::NibiruError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetadataBuilder {
+    metadata: Metadata,
+}
+
+impl MetadataBuilder {
+    pub fn new(denom: &TfDenom) -> Self {
+        let base = denom.to_string();
+        Self {
+            metadata: Metadata {
+                denom_units: vec![DenomUnit {
+                    denom: base.clone(),
+                    exponent: 0,
+                    aliases: vec![],
+                }],
+                display: base.clone(),
+                base,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Adds a denom unit worth 10^`exponent` base units.
+    pub fn unit(self, denom: impl Into<String>, exponent: u32) -> Self {
+        self.unit_with_aliases(denom, exponent, vec![])
+    }
+
+    pub fn unit_with_aliases(
+        mut self,
+        denom: impl Into<String>,
+        exponent: u32,
+        aliases: Vec<String>,
+    ) -> Self {
+        self.metadata.denom_units.push(DenomUnit {
+            denom: denom.into(),
+            exponent,
+            aliases,
+        });
+        self
+    }
+
+    /// Sets the unit that clients display amounts in. Defaults to the base.
+    pub fn display(mut self, display: impl Into<String>) -> Self {
+        self.metadata.display = display.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.metadata.name = name.into();
+        self
+    }
+
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.metadata.symbol = symbol.into();
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.metadata.description = description.into();
+        self
+    }
+
+    pub fn uri(
+        mut self,
+        uri: impl Into<String>,
+        uri_hash: impl Into<String>,
+    ) -> Self {
+        self.metadata.uri = uri.into();
+        self.metadata.uri_hash = uri_hash.into();
+        self
+    }
+
+    pub fn build(self) -> NibiruResult<Metadata> {
+        validate_metadata(&self.metadata)?;
+        Ok(self.metadata)
+    }
+}
+
+/// Validates bank `Metadata` with the rules of the bank module's
+/// `Metadata.Validate`, and checks that its base is a [TfDenom].
+pub fn validate_metadata(metadata: &Metadata) -> NibiruResult<()> {
+    check_metadata(metadata).map_err(NibiruError::InvalidDenomMetadata)
+}
+
+fn check_metadata(md: &Metadata) -> Result<(), String> {
+    if md.name.trim().is_empty() {
+        return Err("name field cannot be blank".to_string());
+    }
+    if md.symbol.trim().is_empty() {
+        return Err("symbol field cannot be blank".to_string());
+    }
+    TfDenom::from_str(&md.base).map_err(|err| err.to_string())?;
+    validate_denom(&md.display)?;
+
+    let Some(first) = md.denom_units.first() else {
+        return Err("denom units cannot be empty".to_string());
+    };
+    if first.denom != md.base || first.exponent != 0 {
+        return Err(format!(
+            "the first denom unit must be the base \"{}\" with exponent 0",
+            md.base
+        ));
+    }
+    let mut seen: Vec<&str> = vec![];
+    for (idx, unit) in md.denom_units.iter().enumerate() {
+        if idx > 0 {
+            validate_denom(&unit.denom)?;
+            let prev = md.denom_units[idx - 1].exponent;
+            if unit.exponent <= prev {
+                return Err(format!(
+                    "denom units must be sorted by increasing exponent: \
+                     \"{}\" has exponent {} after {prev}",
+                    unit.denom, unit.exponent
+                ));
+            }
+        }
+        for name in std::iter::once(&unit.denom).chain(&unit.aliases) {
+            if name.trim().is_empty() {
+                return Err(format!(
+                    "denom unit \"{}\" has a blank alias",
+                    unit.denom
+                ));
+            }
+            if seen.contains(&name.as_str()) {
+                return Err(format!("duplicate denom unit or alias \"{name}\""));
+            }
+            seen.push(name);
+        }
+    }
+    if !md.denom_units.iter().any(|unit| unit.denom == md.display) {
+        return Err(format!(
+            "display denom \"{}\" is not one of the denom units",
+            md.display
+        ));
+    }
+    Ok(())
+}
+
+/// Returns a `MsgCreateDenom` that creates `denom`. The sender is the
+/// creator of the denom.
+pub fn create_denom(denom: &TfDenom) -> tf::MsgCreateDenom {
+    tf::MsgCreateDenom {
+        sender: denom.creator.clone(),
+        subdenom: denom.subdenom.clone(),
+    }
+}
+
+/// Returns a `MsgMint` that mints `coin` to `mint_to`. The coin must be a
+/// nonzero amount of a [TfDenom]. An empty `mint_to` mints to the sender.
+pub fn mint(
+    sender: impl Into<String>,
+    coin: cw::Coin,
+    mint_to: impl Into<String>,
+) -> NibiruResult<tf::MsgMint> {
+    Ok(tf::MsgMint {
+        sender: sender.into(),
+        coin: Some(tf_coin(coin)?.into()),
+        mint_to: mint_to.into(),
+    })
+}
+
+/// Returns a `MsgBurn` that burns `coin` from `burn_from`. The coin must be
+/// a nonzero amount of a [TfDenom]. An empty `burn_from` burns from the
+/// sender.
+pub fn burn(
+    sender: impl Into<String>,
+    coin: cw::Coin,
+    burn_from: impl Into<String>,
+) -> NibiruResult<tf::MsgBurn> {
+    Ok(tf::MsgBurn {
+        sender: sender.into(),
+        coin: Some(tf_coin(coin)?.into()),
+        burn_from: burn_from.into(),
+    })
+}
+
+fn tf_coin(coin: cw::Coin) -> NibiruResult<cw::Coin> {
+    TfDenom::from_str(&coin.denom)?;
+    if coin.amount.is_zero() {
+        return Err(NibiruError::InvalidCoinAmount {
+            denom: coin.denom,
+            amount: coin.amount.to_string(),
+            reason: "amount must be positive".to_string(),
+        });
+    }
+    Ok(coin)
+}
+
+/// Returns a `MsgChangeAdmin` that makes `new_admin` the admin of `denom`.
+/// The sender must be the current admin.
+pub fn change_admin(
+    sender: impl Into<String>,
+    denom: &TfDenom,
+    new_admin: impl Into<String>,
+) -> tf::MsgChangeAdmin {
+    tf::MsgChangeAdmin {
+        sender: sender.into(),
+        denom: denom.to_string(),
+        new_admin: new_admin.into(),
+    }
+}
+
+/// Returns a `MsgSetDenomMetadata` after checking `metadata` with
+/// [validate_metadata]. The sender must be the admin of its base denom.
+pub fn set_denom_metadata(
+    sender: impl Into<String>,
+    metadata: Metadata,
+) -> NibiruResult<tf::MsgSetDenomMetadata> {
+    validate_metadata(&metadata)?;
+    Ok(tf::MsgSetDenomMetadata {
+        sender: sender.into(),
+        metadata: Some(metadata),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::coin;
+
+    use super::*;
+    use crate::{
+        address::{bech32_convert_hrp, contract_address_classic},
+        errors::TestResult,
+        proto::NibiruStargateMsg,
+    };
+
+    fn creator() -> String {
+        contract_address_classic(1, 1)
+    }
+
+    fn denom() -> TfDenom {
+        TfDenom::new(creator(), "ustable").unwrap()
+    }
+
+    #[test]
+    fn tf_denom_new() -> TestResult {
+        let creator = creator();
+        let cosmos_creator = bech32_convert_hrp(&creator, "cosmos")?;
+        let test_cases: Vec<(&str, &str, String, bool)> = vec![
+            ("valid", &creator, "ustable".into(), true),
+            ("all allowed chars", &creator, "aZ09:._-".into(), true),
+            ("max subdenom", &creator, "a".repeat(44), true),
+            ("empty subdenom", &creator, "".into(), false),
+            ("long subdenom", &creator, "a".repeat(45), false),
+            ("slash in subdenom", &creator, "a/b".into(), false),
+            ("space in subdenom", &creator, "a b".into(), false),
+            ("non-bech32 creator", "cosmos2contract", "u".into(), false),
+            ("wrong hrp", &cosmos_creator, "ustable".into(), false),
+        ];
+        for (name, creator, subdenom, ok) in test_cases {
+            let res = TfDenom::new(creator, subdenom.clone());
+            assert_eq!(res.is_ok(), ok, "{name}: {res:?}");
+            match res {
+                Ok(denom) => {
+                    assert_eq!(denom.creator(), creator);
+                    assert_eq!(denom.subdenom(), subdenom);
+                }
+                Err(err) => assert!(
+                    matches!(err, NibiruError::InvalidTfDenom { .. }),
+                    "{name}: {err}"
+                ),
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn tf_denom_parse() -> TestResult {
+        let creator = creator();
+        let test_cases: Vec<(String, bool)> = vec![
+            (format!("tf/{creator}/ustable"), true),
+            (format!("tf/{creator}/u"), true),
+            (format!("factory/{creator}/ustable"), false),
+            (format!("tf/{creator}/ustable/extra"), false),
+            (format!("tf/{creator}"), false),
+            (format!("tf/{creator}/"), false),
+            ("unibi".to_string(), false),
+            ("tf/nibi1invalid/ustable".to_string(), false),
+        ];
+        for (denom_str, ok) in test_cases {
+            let res = TfDenom::from_str(&denom_str);
+            assert_eq!(res.is_ok(), ok, "{denom_str}: {res:?}");
+            if let Ok(denom) = res {
+                assert_eq!(denom.to_string(), denom_str);
+            }
+        }
+
+        let denom = denom();
+        let pb: tf::TfDenom = denom.clone().into();
+        assert_eq!(TfDenom::try_from(pb)?, denom);
+        assert_eq!(denom.coin(5u128), coin(5, denom.to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn tf_denom_json() -> TestResult {
+        let denom = denom();
+        let json = cw::to_json_string(&denom)?;
+        assert_eq!(json, format!("\"{denom}\""));
+        assert_eq!(cw::from_json::<TfDenom>(&json)?, denom);
+        assert!(cw::from_json::<TfDenom>("\"tf/nibi1x/u\"").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn metadata_validation() -> TestResult {
+        let denom = denom();
+        let builder = || {
+            MetadataBuilder::new(&denom)
+                .unit_with_aliases("mstable", 3, vec!["millistable".into()])
+                .unit("stable", 6)
+                .display("stable")
+                .name("Stable")
+                .symbol("STBL")
+        };
+        let metadata = builder()
+            .description("A stablecoin")
+            .uri("ipfs://stable.json", "")
+            .build()?;
+        assert_eq!(metadata.base, denom.to_string());
+        assert_eq!(metadata.display, "stable");
+        assert_eq!(
+            metadata
+                .denom_units
+                .iter()
+                .map(|unit| unit.exponent)
+                .collect::<Vec<_>>(),
+            vec![0, 3, 6]
+        );
+
+        let test_cases: Vec<(&str, MetadataBuilder)> = vec![
+            ("blank name", builder().name(" ")),
+            ("blank symbol", builder().symbol("")),
+            ("unknown display", builder().display("kstable")),
+            ("exponent not increasing", builder().unit("cstable", 2)),
+            ("duplicate exponent", builder().unit("kstable", 6)),
+            ("duplicate unit", builder().unit("mstable", 9)),
+            ("duplicate alias", builder().unit("millistable", 9)),
+            ("invalid unit", builder().unit("k stable", 9)),
+            (
+                "blank alias",
+                builder().unit_with_aliases("k", 9, vec!["".into()]),
+            ),
+        ];
+        for (name, builder) in test_cases {
+            let res = builder.build();
+            assert!(
+                matches!(res, Err(NibiruError::InvalidDenomMetadata(_))),
+                "{name}: {res:?}"
+            );
+        }
+
+        let mut not_tf = builder().build()?;
+        not_tf.base = "unibi".to_string();
+        not_tf.denom_units[0].denom = "unibi".to_string();
+        assert!(validate_metadata(&not_tf).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn typed_msgs() -> TestResult {
+        let denom = denom();
+        let admin = denom.creator().to_string();
+
+        let msg = create_denom(&denom);
+        assert_eq!(
+            (msg.sender.as_str(), msg.subdenom.as_str()),
+            (admin.as_str(), "ustable")
+        );
+        assert_eq!(msg.type_url(), "/nibiru.tokenfactory.v1.MsgCreateDenom");
+
+        let msg = mint(&admin, denom.coin(100u128), "nibi1user")?;
+        let pb_coin = msg.coin.unwrap();
+        assert_eq!(pb_coin.denom, denom.to_string());
+        assert_eq!(pb_coin.amount, "100");
+        assert_eq!(msg.mint_to, "nibi1user");
+
+        let msg = burn(&admin, denom.coin(7u128), "")?;
+        assert_eq!(msg.coin.unwrap().amount, "7");
+
+        for bad_coin in [denom.coin(0u128), coin(1, "unibi")] {
+            assert!(mint(&admin, bad_coin.clone(), "").is_err());
+            assert!(burn(&admin, bad_coin, "").is_err());
+        }
+
+        let msg = change_admin(&admin, &denom, "nibi1newadmin");
+        assert_eq!(msg.denom, denom.to_string());
+        assert_eq!(msg.new_admin, "nibi1newadmin");
+
+        let metadata = MetadataBuilder::new(&denom)
+            .name("Stable")
+            .symbol("STBL")
+            .build()?;
+        let msg = set_denom_metadata(&admin, metadata.clone())?;
+        assert_eq!(msg.metadata, Some(metadata));
+        assert!(set_denom_metadata(&admin, Metadata::default()).is_err());
+        Ok(())
+    }
+}
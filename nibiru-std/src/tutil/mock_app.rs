@@ -0,0 +1,149 @@
+//! tutil/mock_app.rs: `cw_multi_test::App` with a Stargate module backed by
+//! [`StargateHandlers`]. Requires the "multitest" feature.
+
+use anyhow::{anyhow, bail};
+use cosmwasm_std::{
+    testing::{MockApi, MockStorage},
+    Addr, Api, Binary, BlockInfo, CustomMsg, CustomQuery, Empty, Querier,
+    Storage,
+};
+use cw_multi_test::{
+    App, AppBuilder, AppResponse, BankKeeper, CosmosRouter, DistributionKeeper,
+    FailingModule, GovFailingModule, IbcFailingModule, Module, StakeKeeper,
+    Stargate, StargateMsg, StargateQuery, WasmKeeper,
+};
+use serde::de::DeserializeOwned;
+
+use crate::tutil::{StargateHandlerResult, StargateHandlers};
+
+/// StargateMockModule: cw-multi-test Stargate module that answers queries
+/// with the registered [`StargateHandlers`]. Stargate messages are accepted
+/// without side effects, like `cw_multi_test::StargateAcceptingModule`.
+#[derive(Default)]
+pub struct StargateMockModule {
+    pub handlers: StargateHandlers,
+}
+
+impl StargateMockModule {
+    pub fn new(handlers: StargateHandlers) -> Self {
+        Self { handlers }
+    }
+}
+
+impl Module for StargateMockModule {
+    type ExecT = StargateMsg;
+    type QueryT = StargateQuery;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        _storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        _sender: Addr,
+        _msg: Self::ExecT,
+    ) -> anyhow::Result<AppResponse>
+    where
+        ExecC: CustomMsg + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        Ok(AppResponse::default())
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        _storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        request: Self::QueryT,
+    ) -> anyhow::Result<Binary> {
+        match self.handlers.handle(&request.path, request.data.as_slice()) {
+            Some(StargateHandlerResult::Ok(resp)) => Ok(resp),
+            Some(StargateHandlerResult::QueryErr(err))
+            | Some(StargateHandlerResult::DecodeErr(err)) => Err(anyhow!(err)),
+            None => bail!("no stargate handler registered for {}", request.path),
+        }
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        _storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        _msg: Self::SudoT,
+    ) -> anyhow::Result<AppResponse>
+    where
+        ExecC: CustomMsg + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        bail!("sudo is not supported by StargateMockModule")
+    }
+}
+
+impl Stargate for StargateMockModule {}
+
+/// StargateMockApp: `cw_multi_test::App` whose Stargate queries are answered
+/// by a [`StargateMockModule`].
+pub type StargateMockApp = App<
+    BankKeeper,
+    MockApi,
+    MockStorage,
+    FailingModule<Empty, Empty, Empty>,
+    WasmKeeper<Empty, Empty>,
+    StakeKeeper,
+    DistributionKeeper,
+    IbcFailingModule,
+    GovFailingModule,
+    StargateMockModule,
+>;
+
+/// Builds a [`StargateMockApp`] from a set of Stargate query handlers.
+pub fn mock_app(handlers: StargateHandlers) -> StargateMockApp {
+    AppBuilder::new()
+        .with_stargate(StargateMockModule::new(handlers))
+        .build(|_router, _api, _storage| {})
+}
+
+#[cfg(all(test, feature = "all_protos"))]
+mod tests {
+    use cosmwasm_std::coin;
+
+    use super::*;
+    use crate::{
+        errors::TestResult,
+        proto::{
+            nibiru::oracle::{
+                QueryExchangeRateRequest, QueryExchangeRateResponse,
+            },
+            query_stargate_proto,
+        },
+    };
+
+    #[test]
+    fn mock_app_stargate_query() -> TestResult {
+        let mut handlers = StargateHandlers::new();
+        handlers.register_query(|_req: QueryExchangeRateRequest| {
+            Ok(QueryExchangeRateResponse {
+                exchange_rate: "420.69".to_string(),
+                ..Default::default()
+            })
+        });
+        let app = mock_app(handlers);
+
+        let resp: QueryExchangeRateResponse = query_stargate_proto(
+            &app.wrap(),
+            &QueryExchangeRateRequest {
+                pair: "ubtc:uusd".to_string(),
+            },
+        )?;
+        assert_eq!(resp.exchange_rate, "420.69");
+
+        // Regular modules keep working
+        let balance = app.wrap().query_balance("alice", "unibi")?;
+        assert_eq!(balance, coin(0, "unibi"));
+        Ok(())
+    }
+}
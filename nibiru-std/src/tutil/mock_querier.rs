@@ -0,0 +1,382 @@
+//! tutil/mock_querier.rs: Mock querier that answers `QueryRequest::Stargate`
+//! (`QueryRequest::Grpc` with the "cosmwasm_2" feature) with typed protobuf
+//! handlers and passes every other query through to
+//! `cosmwasm_std::testing::MockQuerier`.
+
+use std::{collections::BTreeMap, marker::PhantomData};
+
+use cosmwasm_std::{
+    from_json,
+    testing::{MockApi, MockQuerier, MockStorage},
+    Binary, Coin, ContractResult, CustomQuery, Empty, OwnedDeps, Querier,
+    QuerierResult, QueryRequest, StdError, StdResult, SystemError, SystemResult,
+};
+use serde::de::DeserializeOwned;
+
+use crate::proto::{NibiruProstMsg, NibiruStargateQuery};
+
+/// Type-erased handler for a single gRPC query path. Takes the protobuf
+/// encoded request and returns the protobuf encoded response.
+pub type StargateHandlerFn = Box<dyn Fn(&[u8]) -> StargateHandlerResult>;
+
+/// Outcome of a [`StargateHandlerFn`]. Errors are split the same way the chain
+/// splits them: requests that fail to decode are system errors, while errors
+/// returned by the handler itself surface as query (contract) errors.
+pub enum StargateHandlerResult {
+    Ok(Binary),
+    QueryErr(String),
+    DecodeErr(String),
+}
+
+/// StargateHandlers: Registry of typed Stargate query handlers keyed by the
+/// fully qualified gRPC path, e.g. "/nibiru.oracle.v1.Query/ExchangeRate".
+#[derive(Default)]
+pub struct StargateHandlers {
+    handlers: BTreeMap<String, StargateHandlerFn>,
+}
+
+impl StargateHandlers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a handler for an explicit gRPC `path`. The request bytes are
+    /// decoded into `Req` before calling `handler`, and the returned `Resp` is
+    /// protobuf encoded as the query response.
+    pub fn register<Req, Resp, F>(&mut self, path: impl Into<String>, handler: F)
+    where
+        Req: prost::Message + Default,
+        Resp: prost::Message,
+        F: Fn(Req) -> StdResult<Resp> + 'static,
+    {
+        let handler_fn = move |data: &[u8]| match Req::decode(data) {
+            Ok(req) => match handler(req) {
+                Ok(resp) => StargateHandlerResult::Ok(resp.to_binary()),
+                Err(err) => StargateHandlerResult::QueryErr(err.to_string()),
+            },
+            Err(err) => StargateHandlerResult::DecodeErr(format!(
+                "decoding {}: {err}",
+                std::any::type_name::<Req>()
+            )),
+        };
+        self.handlers.insert(path.into(), Box::new(handler_fn));
+    }
+
+    /// Registers a handler under the gRPC path of the request type, as given
+    /// by [`NibiruStargateQuery::path`].
+    ///
+    /// ```rust
+    /// use nibiru_std::proto::nibiru::oracle::{
+    ///     QueryExchangeRateRequest, QueryExchangeRateResponse,
+    /// };
+    /// use nibiru_std::tutil::StargateHandlers;
+    ///
+    /// let mut handlers = StargateHandlers::new();
+    /// handlers.register_query(|req: QueryExchangeRateRequest| {
+    ///     assert_eq!(req.pair, "ubtc:uusd");
+    ///     Ok(QueryExchangeRateResponse {
+    ///         exchange_rate: "69420".to_string(),
+    ///         ..Default::default()
+    ///     })
+    /// });
+    /// assert!(handlers.contains("/nibiru.oracle.v1.Query/ExchangeRate"));
+    /// ```
+    pub fn register_query<Req, Resp, F>(&mut self, handler: F)
+    where
+        Req: NibiruStargateQuery + Default,
+        Resp: prost::Message,
+        F: Fn(Req) -> StdResult<Resp> + 'static,
+    {
+        let path = Req::default().path();
+        self.register(path, handler)
+    }
+
+    /// Returns true if a handler is registered for the gRPC `path`.
+    pub fn contains(&self, path: &str) -> bool {
+        self.handlers.contains_key(path)
+    }
+
+    /// Returns the raw handler result for the gRPC `path`, or `None` if no
+    /// handler is registered for it.
+    pub fn handle(
+        &self,
+        path: &str,
+        data: &[u8],
+    ) -> Option<StargateHandlerResult> {
+        self.handlers.get(path).map(|handler| handler(data))
+    }
+
+    /// Runs the handler for `path` and converts the outcome to a
+    /// `QuerierResult`, mirroring what the chain returns to a contract.
+    pub fn query(&self, path: &str, data: &[u8]) -> QuerierResult {
+        match self.handle(path, data) {
+            Some(StargateHandlerResult::Ok(resp)) => {
+                SystemResult::Ok(ContractResult::Ok(resp))
+            }
+            Some(StargateHandlerResult::QueryErr(err)) => {
+                SystemResult::Ok(ContractResult::Err(err))
+            }
+            Some(StargateHandlerResult::DecodeErr(err)) => {
+                SystemResult::Err(SystemError::InvalidRequest {
+                    error: err,
+                    request: Binary::from(data),
+                })
+            }
+            None => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: format!("no stargate handler registered for {path}"),
+            }),
+        }
+    }
+}
+
+/// StargateMockQuerier: A `Querier` for unit tests of contracts that use
+/// `query_stargate_proto`. Stargate queries are routed to the registered
+/// [`StargateHandlers`], while bank, wasm, staking and custom queries go to
+/// the wrapped `MockQuerier`.
+///
+/// ```rust
+/// use cosmwasm_std::QuerierWrapper;
+/// use nibiru_std::proto::{
+///     nibiru::oracle::{QueryExchangeRateRequest, QueryExchangeRateResponse},
+///     query_stargate_proto,
+/// };
+/// use nibiru_std::tutil::StargateMockQuerier;
+///
+/// let querier: StargateMockQuerier = StargateMockQuerier::new(&[]).with_handler(
+///     |_req: QueryExchangeRateRequest| {
+///         Ok(QueryExchangeRateResponse {
+///             exchange_rate: "1.5".to_string(),
+///             ..Default::default()
+///         })
+///     },
+/// );
+/// let wrapper = QuerierWrapper::<cosmwasm_std::Empty>::new(&querier);
+/// let resp: QueryExchangeRateResponse = query_stargate_proto(
+///     &wrapper,
+///     &QueryExchangeRateRequest {
+///         pair: "ubtc:uusd".to_string(),
+///     },
+/// )
+/// .unwrap();
+/// assert_eq!(resp.exchange_rate, "1.5");
+/// ```
+pub struct StargateMockQuerier<C: DeserializeOwned = Empty> {
+    /// Handles every non-Stargate query.
+    pub base: MockQuerier<C>,
+    pub stargate: StargateHandlers,
+}
+
+impl<C: DeserializeOwned> StargateMockQuerier<C> {
+    pub fn new(balances: &[(&str, &[Coin])]) -> Self {
+        Self {
+            base: MockQuerier::new(balances),
+            stargate: StargateHandlers::new(),
+        }
+    }
+
+    /// Builder-style variant of [`StargateHandlers::register_query`].
+    pub fn with_handler<Req, Resp, F>(mut self, handler: F) -> Self
+    where
+        Req: NibiruStargateQuery + Default,
+        Resp: prost::Message,
+        F: Fn(Req) -> StdResult<Resp> + 'static,
+    {
+        self.stargate.register_query(handler);
+        self
+    }
+
+    /// Builder-style variant of [`StargateHandlers::register`] for requests
+    /// whose gRPC path must be given explicitly.
+    pub fn with_path_handler<Req, Resp, F>(
+        mut self,
+        path: impl Into<String>,
+        handler: F,
+    ) -> Self
+    where
+        Req: prost::Message + Default,
+        Resp: prost::Message,
+        F: Fn(Req) -> StdResult<Resp> + 'static,
+    {
+        self.stargate.register(path, handler);
+        self
+    }
+}
+
+impl<C: CustomQuery + DeserializeOwned> Querier for StargateMockQuerier<C> {
+    fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+        let request: QueryRequest<C> = match from_json(bin_request) {
+            Ok(request) => request,
+            Err(err) => {
+                return SystemResult::Err(SystemError::InvalidRequest {
+                    error: format!("parsing query request: {err}"),
+                    request: bin_request.into(),
+                });
+            }
+        };
+        match request {
+            #[cfg(feature = "cosmwasm_1")]
+            QueryRequest::Stargate { path, data } => {
+                self.stargate.query(&path, data.as_slice())
+            }
+            #[cfg(not(feature = "cosmwasm_1"))]
+            QueryRequest::Grpc(cosmwasm_std::GrpcQuery { path, data }) => {
+                self.stargate.query(&path, data.as_slice())
+            }
+            request => self.base.handle_query(&request),
+        }
+    }
+}
+
+/// Creates all external requirements that can be injected for unit tests,
+/// like `cosmwasm_std::testing::mock_dependencies_with_balances`, but with a
+/// [`StargateMockQuerier`] so Stargate handlers can be registered on
+/// `deps.querier.stargate`.
+pub fn mock_dependencies_with_stargate(
+    balances: &[(&str, &[Coin])],
+) -> OwnedDeps<MockStorage, MockApi, StargateMockQuerier> {
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: StargateMockQuerier::new(balances),
+        custom_query_type: PhantomData,
+    }
+}
+
+/// Convenience for handlers that should fail the query the same way the chain
+/// does when, for example, a pair or denom is not found.
+pub fn stargate_query_err<T>(msg: impl Into<String>) -> StdResult<T> {
+    Err(StdError::generic_err(msg))
+}
+
+#[cfg(all(test, feature = "all_protos"))]
+mod tests {
+    use cosmwasm_std::{
+        coin, BalanceResponse, BankQuery, QuerierWrapper, QueryRequest,
+    };
+
+    use super::*;
+    use crate::{
+        errors::TestResult,
+        proto::{
+            cosmos::bank::v1beta1::{
+                QuerySupplyOfRequest, QuerySupplyOfResponse,
+            },
+            nibiru::oracle::{
+                QueryExchangeRateRequest, QueryExchangeRateResponse,
+            },
+            query_stargate_proto,
+        },
+    };
+
+    fn oracle_querier() -> StargateMockQuerier {
+        StargateMockQuerier::new(&[("alice", &[coin(420, "unibi")])])
+            .with_handler(|req: QueryExchangeRateRequest| {
+                match req.pair.as_str() {
+                    "ubtc:uusd" => Ok(QueryExchangeRateResponse {
+                        exchange_rate: "69420".to_string(),
+                        ..Default::default()
+                    }),
+                    pair => {
+                        stargate_query_err(format!("pair not found: {pair}"))
+                    }
+                }
+            })
+    }
+
+    #[test]
+    fn stargate_handler_decodes_and_encodes() -> TestResult {
+        let querier = oracle_querier();
+        let wrapper = QuerierWrapper::<Empty>::new(&querier);
+        let resp: QueryExchangeRateResponse = query_stargate_proto(
+            &wrapper,
+            &QueryExchangeRateRequest {
+                pair: "ubtc:uusd".to_string(),
+            },
+        )?;
+        assert_eq!(resp.exchange_rate, "69420");
+        Ok(())
+    }
+
+    #[test]
+    fn stargate_handler_errors() {
+        let querier = oracle_querier();
+        let wrapper = QuerierWrapper::<Empty>::new(&querier);
+
+        // Error returned by the handler
+        let err = query_stargate_proto::<_, _, QueryExchangeRateResponse>(
+            &wrapper,
+            &QueryExchangeRateRequest {
+                pair: "ueth:uusd".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("pair not found: ueth:uusd"),
+            "{err}"
+        );
+
+        // No handler registered for the path
+        let err = query_stargate_proto::<_, _, QuerySupplyOfResponse>(
+            &wrapper,
+            &QuerySupplyOfRequest {
+                denom: "unibi".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("/cosmos.bank.v1beta1.Query/SupplyOf"),
+            "{err}"
+        );
+
+        // Request bytes that are not a valid QueryExchangeRateRequest
+        let res = querier
+            .stargate
+            .query("/nibiru.oracle.v1.Query/ExchangeRate", &[0xff, 0xff, 0xff]);
+        assert!(
+            matches!(res, SystemResult::Err(SystemError::InvalidRequest { .. })),
+            "{res:?}"
+        );
+    }
+
+    #[test]
+    fn non_stargate_queries_pass_through() -> TestResult {
+        let mut deps =
+            mock_dependencies_with_stargate(&[("alice", &[coin(420, "unibi")])]);
+        deps.querier
+            .stargate
+            .register_query(|_req: QuerySupplyOfRequest| {
+                Ok(QuerySupplyOfResponse {
+                    amount: Some(coin(1_000, "unibi").into()),
+                })
+            });
+        // MockQuerier::update_balance moved to its bank field in CosmWasm 2
+        #[cfg(feature = "cosmwasm_1")]
+        deps.querier
+            .base
+            .update_balance("bob", vec![coin(7, "uusd")]);
+        #[cfg(not(feature = "cosmwasm_1"))]
+        deps.querier
+            .base
+            .bank
+            .update_balance("bob", vec![coin(7, "uusd")]);
+
+        let wrapper = deps.as_ref().querier;
+        let balance: BalanceResponse =
+            wrapper.query(&QueryRequest::Bank(BankQuery::Balance {
+                address: "alice".to_string(),
+                denom: "unibi".to_string(),
+            }))?;
+        assert_eq!(balance.amount, coin(420, "unibi"));
+        assert_eq!(wrapper.query_balance("bob", "uusd")?, coin(7, "uusd"));
+
+        let supply: QuerySupplyOfResponse = query_stargate_proto(
+            &wrapper,
+            &QuerySupplyOfRequest {
+                denom: "unibi".to_string(),
+            },
+        )?;
+        assert_eq!(supply.amount.unwrap().amount, "1000");
+        Ok(())
+    }
+}
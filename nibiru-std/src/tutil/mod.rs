@@ -1 +1,12 @@
 //! tutil/mod.rs: Testing utilities for Nibiru smart contracts.
+#![cfg(not(target_arch = "wasm32"))]
+
+mod mock_querier;
+
+#[cfg(feature = "multitest")]
+mod mock_app;
+
+pub use mock_querier::*;
+
+#[cfg(feature = "multitest")]
+pub use mock_app::*;
@@ -1,6 +1,14 @@
 // crate::wasm.rs
 
-use cosmwasm_std::{QueryRequest, StdResult, WasmQuery};
+// `msg_response_from_reply` needs the "abci" feature.
+#![cfg_attr(not(feature = "abci"), allow(unused_imports))]
+
+use cosmwasm_std::{QueryRequest, Reply, StdResult, WasmQuery};
+use prost::Message;
+
+use crate::errors::{NibiruError, NibiruResult};
+#[cfg(feature = "abci")]
+use crate::proto::cosmos::base::abci::v1beta1::TxMsgData;
 
 /// Generic helper for constructing WasmQuery::Smart query requests.
 pub fn wasm_query_smart<CosmosMsg>(
@@ -24,17 +32,243 @@ pub fn wasm_query_raw<CosmosMsg>(
     }))
 }
 
-#[cfg(test)]
+/// Decodes the typed response of a protobuf message sent as a `SubMsg`, like
+/// `MsgCreateDenomResponse` for a tokenfactory `MsgCreateDenom`, from the
+/// `Reply` to it. `Resp` must be the response of the message that was sent:
+/// its type URL is the message's type URL plus "Response".
+///
+/// The response is searched for in each layout a reply can carry it in:
+/// 1. `msg_responses` of the reply, with CosmWasm 2.
+/// 2. `data` holding a `TxMsgData`, in its `msg_responses` (Cosmos-SDK
+///    v0.46+) or in its `data` entry for the message type (v0.45). A
+///    `TxMsgData` with entries, but none for the message, means the response
+///    is not found.
+/// 3. `data` holding the encoded response itself, as wasmd sets it for
+///    Cosmos-SDK v0.47.
+///
+/// ```
+/// use cosmwasm_std::{Binary, Reply, SubMsgResponse, SubMsgResult};
+/// use nibiru_std::{
+///     proto::{nibiru::tokenfactory::MsgCreateDenomResponse, NibiruProstMsg},
+///     wasm::msg_response_from_reply,
+/// };
+///
+/// let resp = MsgCreateDenomResponse {
+///     new_token_denom: "tf/nibi1contract/foo".to_string(),
+/// };
+/// let reply = Reply {
+///     id: 1,
+///     result: SubMsgResult::Ok(SubMsgResponse {
+///         events: vec![],
+///         data: Some(resp.to_binary()),
+///     }),
+/// };
+/// let decoded: MsgCreateDenomResponse =
+///     msg_response_from_reply(&reply).unwrap();
+/// assert_eq!(decoded, resp);
+/// ```
+#[cfg(feature = "abci")]
+pub fn msg_response_from_reply<Resp>(reply: &Reply) -> NibiruResult<Resp>
+where
+    Resp: prost::Message + prost::Name + Default,
+{
+    let sub_msg_resp = reply
+        .result
+        .clone()
+        .into_result()
+        .map_err(NibiruError::SubMsgFailed)?;
+    let type_url = <Resp as prost::Name>::type_url();
+
+    #[cfg(not(feature = "cosmwasm_1"))]
+    if let Some(msg_resp) = sub_msg_resp
+        .msg_responses
+        .iter()
+        .find(|msg_resp| msg_resp.type_url == type_url)
+    {
+        return Ok(Resp::decode(msg_resp.value.as_slice())?);
+    }
+
+    #[allow(deprecated)] // `data` is deprecated in CosmWasm 2
+    let data =
+        sub_msg_resp
+            .data
+            .ok_or_else(|| NibiruError::MsgResponseNotFound {
+                type_url: type_url.clone(),
+            })?;
+
+    // Raw response bytes can also decode as a `TxMsgData`, so it is only
+    // used if it has entries, each with a type URL.
+    let tx_msg_data = TxMsgData::decode(data.as_slice())
+        .ok()
+        .filter(has_msg_entries);
+    if let Some(tx_msg_data) = tx_msg_data {
+        let msg_type_url = type_url.strip_suffix("Response").unwrap_or_default();
+        if let Some(any) = tx_msg_data
+            .msg_responses
+            .iter()
+            .find(|any| any.type_url == type_url)
+        {
+            return Ok(Resp::decode(any.value.as_slice())?);
+        }
+        #[allow(deprecated)] // read for Cosmos-SDK v0.45 chains
+        if let Some(msg_data) = tx_msg_data
+            .data
+            .iter()
+            .find(|msg_data| msg_data.msg_type == msg_type_url)
+        {
+            return Ok(Resp::decode(msg_data.data.clone())?);
+        }
+        return Err(NibiruError::MsgResponseNotFound { type_url });
+    }
+    Ok(Resp::decode(data.as_slice())?)
+}
+
+/// Whether a `TxMsgData` decoded from the `data` of a reply holds message
+/// responses: at least one entry, and a type URL on each.
+#[cfg(feature = "abci")]
+#[allow(deprecated)] // `TxMsgData.data` is read for Cosmos-SDK v0.45 chains
+fn has_msg_entries(tx_msg_data: &TxMsgData) -> bool {
+    let responses = tx_msg_data.msg_responses.iter().map(|any| &any.type_url);
+    let data = tx_msg_data.data.iter().map(|msg_data| &msg_data.msg_type);
+    let mut type_urls = responses.chain(data).peekable();
+    type_urls.peek().is_some() && type_urls.all(|url| url.starts_with('/'))
+}
+
+#[cfg(all(test, feature = "all_protos"))]
 mod tests {
-    use prost::Message;
+    use cosmwasm_std::{Binary, SubMsgResponse, SubMsgResult};
 
     use super::*;
-    use crate::proto::{
-        cosmos::{bank, base::v1beta1::Coin},
-        nibiru::{self, perp},
-        NibiruProstMsg,
+    use crate::{
+        errors::TestResult,
+        proto::{
+            cosmos::{
+                bank,
+                base::{abci::v1beta1::MsgData, v1beta1::Coin},
+            },
+            eth,
+            nibiru::{self, perp, tokenfactory::MsgCreateDenomResponse},
+            NibiruProstMsg, NibiruStargateMsg,
+        },
     };
 
+    #[allow(deprecated)]
+    fn reply(data: Option<Binary>, msg_responses: &[prost_types::Any]) -> Reply {
+        #[cfg(feature = "cosmwasm_1")]
+        {
+            assert!(msg_responses.is_empty(), "not in CosmWasm 1 replies");
+            Reply {
+                id: 1,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data,
+                }),
+            }
+        }
+        #[cfg(not(feature = "cosmwasm_1"))]
+        Reply {
+            id: 1,
+            payload: Binary::default(),
+            gas_used: 0,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data,
+                msg_responses: msg_responses
+                    .iter()
+                    .map(|any| cosmwasm_std::MsgResponse {
+                        type_url: any.type_url.clone(),
+                        value: any.value.clone().into(),
+                    })
+                    .collect(),
+            }),
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn msg_response_from_reply_layouts() -> TestResult {
+        let resp = MsgCreateDenomResponse {
+            new_token_denom: "tf/nibi1contract/foo".to_string(),
+        };
+        let other = eth::evm::MsgCreateFunTokenResponse::default().to_any();
+
+        let sdk_v046 = TxMsgData {
+            data: vec![],
+            msg_responses: vec![other.clone(), resp.to_any()],
+        };
+        let sdk_v045 = TxMsgData {
+            data: vec![MsgData {
+                msg_type: "/nibiru.tokenfactory.v1.MsgCreateDenom".to_string(),
+                data: resp.encode_to_vec().into(),
+            }],
+            msg_responses: vec![],
+        };
+        #[cfg_attr(feature = "cosmwasm_1", allow(unused_mut))]
+        let mut cases = vec![
+            ("raw data", reply(Some(resp.to_binary()), &[])),
+            (
+                "TxMsgData.msg_responses",
+                reply(Some(sdk_v046.to_binary()), &[]),
+            ),
+            ("TxMsgData.data", reply(Some(sdk_v045.to_binary()), &[])),
+        ];
+        #[cfg(not(feature = "cosmwasm_1"))]
+        cases.push(("msg_responses", reply(None, &[other, resp.to_any()])));
+
+        for (layout, reply) in cases {
+            let decoded: MsgCreateDenomResponse =
+                msg_response_from_reply(&reply)
+                    .map_err(|err| anyhow::anyhow!("{layout}: {err}"))?;
+            assert_eq!(decoded, resp, "{layout}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn msg_response_from_reply_errors() {
+        let err =
+            msg_response_from_reply::<MsgCreateDenomResponse>(&reply(None, &[]))
+                .unwrap_err();
+        assert_eq!(
+            err,
+            NibiruError::MsgResponseNotFound {
+                type_url: "/nibiru.tokenfactory.v1.MsgCreateDenomResponse"
+                    .to_string()
+            }
+        );
+
+        // A `TxMsgData` with the responses of other messages only
+        let tx_msg_data = TxMsgData {
+            data: vec![],
+            msg_responses: vec![
+                eth::evm::MsgCreateFunTokenResponse::default().to_any()
+            ],
+        };
+        let err = msg_response_from_reply::<MsgCreateDenomResponse>(&reply(
+            Some(tx_msg_data.to_binary()),
+            &[],
+        ))
+        .unwrap_err();
+        assert!(
+            matches!(err, NibiruError::MsgResponseNotFound { .. }),
+            "{err}"
+        );
+
+        let mut failed = reply(None, &[]);
+        failed.result = SubMsgResult::Err("denom already exists".to_string());
+        let err = msg_response_from_reply::<MsgCreateDenomResponse>(&failed)
+            .unwrap_err();
+        assert!(err.to_string().contains("denom already exists"), "{err}");
+
+        // Not a protobuf message at all
+        let garbage = reply(Some(Binary::from(vec![0xff, 0xff])), &[]);
+        assert!(matches!(
+            msg_response_from_reply::<MsgCreateDenomResponse>(&garbage),
+            Err(NibiruError::ProstDecodeError(_))
+        ));
+    }
+
     #[test]
     fn test_wasm_query_smart() -> anyhow::Result<()> {
         let proto_msg = nibiru::perp::QueryMarketsRequest { versioned: false };
@@ -4,8 +4,11 @@ use cosmwasm_std::{
 };
 
 use crate::{
-    msgs::{ExecuteMsg, InitMsg, IsMemberResponse, QueryMsg, WhitelistResponse},
-    state::{Whitelist, WHITELIST},
+    msgs::{
+        ExecuteMsg, InitMsg, IsMemberResponse, MigrateMsg, QueryMsg,
+        WhitelistResponse,
+    },
+    state::{migrate_legacy_whitelist, Whitelist, MEMBER_ROLE},
 };
 
 #[entry_point]
@@ -19,7 +22,17 @@ pub fn instantiate(
         members: vec![msg.admin.clone()].into_iter().collect(),
         admin: msg.admin,
     };
-    WHITELIST.save(deps.storage, &whitelist)?;
+    whitelist.save(deps.storage)?;
+    Ok(Response::default())
+}
+
+#[entry_point]
+pub fn migrate(
+    deps: DepsMut,
+    _env: Env,
+    _msg: MigrateMsg,
+) -> StdResult<Response> {
+    migrate_legacy_whitelist(deps.storage)?;
     Ok(Response::default())
 }
 
@@ -134,9 +147,7 @@ pub fn execute(
 
         ExecuteMsg::AddMember { address } => {
             check_admin(check)?;
-            let addr = address.as_str();
-            whitelist.members.insert(addr.to_string());
-            WHITELIST.save(deps.storage, &whitelist)?;
+            nibiru_perms::add_member(deps.storage, MEMBER_ROLE, &address)?;
 
             let res = Response::new().add_attributes(vec![
                 attr("action", "add_member"),
@@ -147,8 +158,7 @@ pub fn execute(
 
         ExecuteMsg::RemoveMember { address } => {
             check_admin(check)?;
-            whitelist.members.remove(address.as_str());
-            WHITELIST.save(deps.storage, &whitelist)?;
+            nibiru_perms::remove_member(deps.storage, MEMBER_ROLE, &address)?;
 
             let res = Response::new().add_attributes(vec![
                 attr("action", "remove_member"),
@@ -160,9 +170,11 @@ pub fn execute(
         ExecuteMsg::ChangeAdmin { address } => {
             check_admin(check)?;
             let new_admin = address.as_str();
+            // The admin changes in one step, so the ownership is set
+            // directly rather than through a transfer that has to be accepted.
             whitelist.admin = new_admin.to_string();
             whitelist.members.insert(new_admin.to_string());
-            WHITELIST.save(deps.storage, &whitelist)?;
+            whitelist.save(deps.storage)?;
 
             let res = Response::new().add_attributes(vec![
                 attr("action", "change_admin"),
@@ -181,7 +193,7 @@ struct CanExecute {
 }
 
 fn can_execute(deps: Deps, sender: &str) -> StdResult<CanExecute> {
-    let whitelist = WHITELIST.load(deps.storage).unwrap();
+    let whitelist = Whitelist::load(deps.storage)?;
     Ok(CanExecute {
         is_admin: whitelist.is_admin(sender),
         is_member: whitelist.is_member(sender),
@@ -194,7 +206,7 @@ fn can_execute(deps: Deps, sender: &str) -> StdResult<CanExecute> {
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::IsMember { address } => {
-            let whitelist = WHITELIST.load(deps.storage)?;
+            let whitelist = Whitelist::load(deps.storage)?;
             let is_member: bool = whitelist.is_member(address);
             let res = IsMemberResponse {
                 is_member,
@@ -203,7 +215,7 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             cosmwasm_std::to_json_binary(&res)
         }
         QueryMsg::Whitelist {} => {
-            let whitelist = WHITELIST.load(deps.storage)?;
+            let whitelist = Whitelist::load(deps.storage)?;
             let res = WhitelistResponse { whitelist };
             cosmwasm_std::to_json_binary(&res)
         }
@@ -215,7 +227,7 @@ mod tests {
     use super::*;
     use crate::{
         msgs::{ExecuteMsg, InitMsg},
-        state::WHITELIST,
+        state::LEGACY_WHITELIST,
     };
 
     use cosmwasm_std::{coins, testing, Addr};
@@ -251,7 +263,7 @@ mod tests {
         let msg_info = testing::mock_info(sender, &coins(2, "token"));
         instantiate(deps.as_mut(), testing::mock_env(), msg_info, msg.clone())
             .unwrap();
-        let whitelist = WHITELIST.load(&deps.storage).unwrap();
+        let whitelist = Whitelist::load(&deps.storage).unwrap();
         let has: bool = whitelist.is_admin(sender);
         assert!(!has);
 
@@ -260,7 +272,7 @@ mod tests {
         let msg_info = testing::mock_info(sender, &coins(2, "token"));
         instantiate(deps.as_mut(), testing::mock_env(), msg_info, msg.clone())
             .unwrap();
-        let whitelist = WHITELIST.load(&deps.storage).unwrap();
+        let whitelist = Whitelist::load(&deps.storage).unwrap();
         let has: bool = whitelist.is_admin(sender);
         assert!(has);
     }
@@ -303,7 +315,7 @@ mod tests {
             .unwrap();
 
         let new_member = "new_member";
-        let whitelist = WHITELIST.load(&deps.storage).unwrap();
+        let whitelist = Whitelist::load(&deps.storage).unwrap();
         let has: bool = whitelist.is_admin(new_member);
         assert!(!has);
 
@@ -338,7 +350,7 @@ mod tests {
         check_resp(result);
 
         // Check correctness of the result
-        let whitelist = WHITELIST.load(&deps.storage).unwrap();
+        let whitelist = Whitelist::load(&deps.storage).unwrap();
         let has: bool = whitelist.has(new_member);
         assert!(has);
 
@@ -371,12 +383,12 @@ mod tests {
             .iter()
             .map(|&s| s.to_string())
             .collect();
-        let mut whitelist = WHITELIST.load(&deps.storage).unwrap();
+        let mut whitelist = Whitelist::load(&deps.storage).unwrap();
         assert_eq!(whitelist.members.len(), 1); // admin remains
         for member in members_start.iter() {
             whitelist.members.insert(member.clone());
         }
-        let res = WHITELIST.save(deps.as_mut().storage, &whitelist);
+        let res = whitelist.save(deps.as_mut().storage);
         assert!(res.is_ok());
 
         // Remove a member from the whitelist
@@ -438,7 +450,7 @@ mod tests {
             .unwrap();
 
         let new_admin = "new_admin";
-        let whitelist = WHITELIST.load(&deps.storage).unwrap();
+        let whitelist = Whitelist::load(&deps.storage).unwrap();
         let has: bool = whitelist.is_admin(new_admin);
         assert!(!has);
 
@@ -473,7 +485,7 @@ mod tests {
         check_resp(result);
 
         // Check correctness of the result
-        let whitelist = WHITELIST.load(&deps.storage).unwrap();
+        let whitelist = Whitelist::load(&deps.storage).unwrap();
         let has: bool = whitelist.has(new_admin);
         assert!(has);
 
@@ -487,4 +499,45 @@ mod tests {
             cosmwasm_std::from_json(binary).unwrap();
         assert!(response.is_member);
     }
+
+    #[test]
+    fn test_migrate_legacy_whitelist() {
+        // Storage from before the whitelist moved to nibiru-perms
+        let mut deps = testing::mock_dependencies();
+        let legacy = Whitelist {
+            members: ["admin", "alice"].iter().map(|&s| s.to_string()).collect(),
+            admin: "admin".to_string(),
+        };
+        LEGACY_WHITELIST
+            .save(deps.as_mut().storage, &legacy)
+            .unwrap();
+
+        migrate(deps.as_mut(), testing::mock_env(), MigrateMsg {}).unwrap();
+        assert_eq!(LEGACY_WHITELIST.may_load(&deps.storage).unwrap(), None);
+        let binary =
+            query(deps.as_ref(), testing::mock_env(), QueryMsg::Whitelist {})
+                .unwrap();
+        let response: WhitelistResponse =
+            cosmwasm_std::from_json(binary).unwrap();
+        assert_eq!(response.whitelist, legacy);
+
+        // Permissions are unchanged
+        let add_member = ExecuteMsg::AddMember {
+            address: "bob".to_string(),
+        };
+        for (sender, ok) in [("alice", false), ("admin", true)] {
+            let info = testing::mock_info(sender, &[]);
+            let res = execute(
+                deps.as_mut(),
+                testing::mock_env(),
+                info,
+                add_member.clone(),
+            );
+            assert_eq!(res.is_ok(), ok, "sender: {sender}");
+        }
+
+        // Migrating again is a no-op
+        migrate(deps.as_mut(), testing::mock_env(), MigrateMsg {}).unwrap();
+        assert!(Whitelist::load(&deps.storage).unwrap().is_member("bob"));
+    }
 }
@@ -10,6 +10,10 @@ pub struct InitMsg {
     pub admin: String,
 }
 
+/// MigrateMsg specifies the args for the migrate entry point of the contract.
+#[cw_serde]
+pub struct MigrateMsg {}
+
 /// ExecuteMsg specifies the args for the execute entry point of the contract.
 #[cw_serde]
 pub enum ExecuteMsg {
@@ -1,10 +1,20 @@
 use std::collections::HashSet;
 
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{StdError, StdResult, Storage};
 use cw_storage_plus::Item;
+use nibiru_perms::{Actions, Role};
 
-pub const WHITELIST: Item<Whitelist> = Item::new("whitelist");
+/// The `nibiru_perms` role of the whitelist members.
+pub const MEMBER_ROLE: &str = "member";
 
+/// The whitelist from before it moved to `nibiru_ownable` and
+/// `nibiru_perms`. Only read by the `migrate` entry point.
+pub const LEGACY_WHITELIST: Item<Whitelist> = Item::new("whitelist");
+
+/// Whitelist: The admin and members of the contract. The admin is the
+/// `nibiru_ownable` owner and the members are the members of the
+/// [MEMBER_ROLE].
 #[cw_serde]
 pub struct Whitelist {
     pub members: HashSet<String>,
@@ -12,6 +22,26 @@ pub struct Whitelist {
 }
 
 impl Whitelist {
+    pub fn load(storage: &dyn Storage) -> StdResult<Self> {
+        let admin = nibiru_ownable::get_ownership(storage)?
+            .owner
+            .ok_or_else(|| StdError::generic_err("contract has no admin"))?;
+        let members = nibiru_perms::members(storage, MEMBER_ROLE)?;
+        Ok(Whitelist {
+            members: members.into_iter().collect(),
+            admin,
+        })
+    }
+
+    pub fn save(&self, storage: &mut dyn Storage) -> StdResult<()> {
+        nibiru_ownable::initialize_owner(storage, Some(&self.admin))?;
+        let role = Role {
+            members: self.members.iter().cloned().collect(),
+            actions: Actions::All,
+        };
+        nibiru_perms::save_role(storage, MEMBER_ROLE, &role)
+    }
+
     pub fn has(&self, addr: impl AsRef<str>) -> bool {
         let addr = addr.as_ref();
         self.members.contains(addr) || self.admin == addr
@@ -28,6 +58,16 @@ impl Whitelist {
     }
 }
 
+/// Moves the [LEGACY_WHITELIST] into `nibiru_ownable` and `nibiru_perms`
+/// state. Does nothing if the contract has already been migrated.
+pub fn migrate_legacy_whitelist(storage: &mut dyn Storage) -> StdResult<()> {
+    if let Some(whitelist) = LEGACY_WHITELIST.may_load(storage)? {
+        whitelist.save(storage)?;
+        LEGACY_WHITELIST.remove(storage);
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 pub mod tests {
     use cosmwasm_std::testing::MockStorage;
@@ -80,15 +120,15 @@ pub mod tests {
         let mut store = MockStorage::new();
 
         // Store should start out empty
-        assert!(WHITELIST.load(&store).is_err());
-        assert_eq!(WHITELIST.may_load(&store).unwrap(), None);
+        assert!(Whitelist::load(&store).is_err());
+        assert_eq!(nibiru_perms::get_role(&store, MEMBER_ROLE).unwrap(), None);
 
         // save to store
         let whitelist = init_mock_whitelist();
-        let res = WHITELIST.save(&mut store, &whitelist);
+        let res = whitelist.save(&mut store);
         assert!(res.is_ok());
 
         // load from store
-        assert_eq!(whitelist, WHITELIST.load(&store).unwrap());
+        assert_eq!(whitelist, Whitelist::load(&store).unwrap());
     }
 }
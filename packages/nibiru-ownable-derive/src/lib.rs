@@ -184,3 +184,126 @@ pub fn ownable_query(metadata: TokenStream, input: TokenStream) -> TokenStream {
         .into(),
     )
 }
+
+/// Append role-related execute message variant(s) to an enum.
+///
+/// For example, apply the `perms_execute` macro to the following enum:
+///
+/// ```rust
+/// extern crate cosmwasm_schema; // not to be copied
+/// extern crate nibiru_perms;    // not to be copied
+/// use cosmwasm_schema::cw_serde;
+/// use nibiru_perms::perms_execute;
+///
+/// #[perms_execute]
+/// #[cw_serde]
+/// enum ExecuteMsg {
+///     Foo {},
+///     Bar {},
+/// }
+/// ```
+///
+/// Is equivalent to:
+///
+/// ```rust
+/// extern crate cosmwasm_schema; // not to be copied
+/// extern crate nibiru_perms;    // not to be copied
+/// use cosmwasm_schema::cw_serde;
+/// use nibiru_perms::Action;
+///
+/// #[cw_serde]
+/// enum ExecuteMsg {
+///     EditRoles(Action),
+///     Foo {},
+///     Bar {},
+/// }
+///
+/// let _msg = ExecuteMsg::Foo{};
+/// ```
+///
+/// Note: `#[perms_execute]` must be applied _before_ `#[cw_serde]`.
+#[proc_macro_attribute]
+pub fn perms_execute(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    merge_variants(
+        metadata,
+        input,
+        quote! {
+            enum Right {
+                /// Edit the members and allowed actions of the contract's
+                /// roles. Only callable by the contract owner.
+                EditRoles(::nibiru_perms::Action),
+            }
+        }
+        .into(),
+    )
+}
+
+/// Append role-related query message variant(s) to an enum.
+///
+/// For example, apply the `perms_query` macro to the following enum:
+///
+/// ```rust
+/// extern crate cosmwasm_schema; // not to be copied
+/// extern crate nibiru_perms;    // not to be copied
+/// use cosmwasm_schema::{cw_serde, QueryResponses};
+/// use nibiru_perms::perms_query;
+///
+/// #[perms_query]
+/// #[cw_serde]
+/// #[derive(QueryResponses)]
+/// enum QueryMsg {
+///     #[returns(FooResponse)]
+///     Foo {},
+/// }
+///
+/// #[cw_serde]
+/// pub struct FooResponse {}
+///
+/// let _msg = QueryMsg::Foo{};
+/// ```
+///
+/// Is equivalent to:
+///
+/// ```rust
+/// extern crate cosmwasm_schema; // not to be copied
+/// extern crate nibiru_perms;    // not to be copied
+/// use cosmwasm_schema::{cw_serde, QueryResponses};
+/// use nibiru_perms::{HasRoleResponse, RolesResponse};
+///
+/// #[cw_serde]
+/// #[derive(QueryResponses)]
+/// enum QueryMsg {
+///     #[returns(RolesResponse)]
+///     Roles {},
+///     #[returns(HasRoleResponse)]
+///     HasRole { role: String, address: String },
+///     #[returns(FooResponse)]
+///     Foo {},
+/// }
+///
+/// #[cw_serde]
+/// pub struct FooResponse {}
+///
+/// let _msg = QueryMsg::Foo{};
+/// ```
+///
+/// Note: `#[perms_query]` must be applied _before_ `#[cw_serde]`.
+#[proc_macro_attribute]
+pub fn perms_query(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    merge_variants(
+        metadata,
+        input,
+        quote! {
+            enum Right {
+                /// Query the contract owner and every role with its members
+                /// and allowed actions.
+                #[returns(::nibiru_perms::RolesResponse)]
+                Roles {},
+                /// Query whether an address is a member of a role.
+                #[returns(::nibiru_perms::HasRoleResponse)]
+                HasRole { role: String, address: String },
+            }
+        }
+        .into(),
+    )
+}
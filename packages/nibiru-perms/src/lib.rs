@@ -0,0 +1,427 @@
+#![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Event, Order, StdError, StdResult, Storage};
+use cw_storage_plus::Map;
+use nibiru_ownable::OwnershipError;
+
+// re-export the proc macros
+pub use nibiru_ownable_derive::{perms_execute, perms_query};
+
+/// Storage constant for the contract's roles, keyed by role name
+const ROLES: Map<&str, Role> = Map::new("roles");
+
+/// The actions that the members of a role are allowed to take
+#[cw_serde]
+pub enum Actions {
+    /// Every action.
+    All,
+    /// Only the named actions, Ex.: "bank_send".
+    Only(BTreeSet<String>),
+}
+
+impl Actions {
+    pub fn allows(&self, action: &str) -> bool {
+        match self {
+            Actions::All => true,
+            Actions::Only(actions) => actions.contains(action),
+        }
+    }
+}
+
+/// A named set of addresses that share the same allowed actions
+#[cw_serde]
+pub struct Role {
+    pub members: BTreeSet<String>,
+    pub actions: Actions,
+}
+
+impl Role {
+    /// A role without members.
+    pub fn new(actions: Actions) -> Self {
+        Role {
+            members: BTreeSet::new(),
+            actions,
+        }
+    }
+
+    pub fn has(&self, addr: impl AsRef<str>) -> bool {
+        self.members.contains(addr.as_ref())
+    }
+}
+
+/// Actions that can be taken to alter the contract's roles
+#[cw_serde]
+pub enum Action {
+    /// Add an address to a role. A role that does not exist yet is created
+    /// with every action allowed.
+    AddMember { role: String, address: String },
+
+    /// Remove an address from a role.
+    RemoveMember { role: String, address: String },
+
+    /// Set the actions that the members of a role are allowed to take.
+    SetActions { role: String, actions: Actions },
+
+    /// Remove a role along with all of its members.
+    RemoveRole { role: String },
+}
+
+#[cw_serde]
+pub struct RolesResponse {
+    /// The contract's current owner, who is allowed every action.
+    pub owner: Option<String>,
+    pub roles: BTreeMap<String, Role>,
+}
+
+#[cw_serde]
+pub struct HasRoleResponse {
+    pub role: String,
+    pub address: String,
+    pub has_role: bool,
+}
+
+/// Errors associated with the contract's roles
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum PermsError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Ownership(#[from] OwnershipError),
+
+    #[error("Role {role:?} does not exist")]
+    RoleNotFound { role: String },
+
+    #[error("Address {address} does not have the role {role:?}")]
+    MissingRole { role: String, address: String },
+
+    #[error("Address {address} is not allowed to take the action {action:?}")]
+    ActionNotAllowed { action: String, address: String },
+}
+
+/// Save a role, replacing any role with the same name.
+pub fn save_role(
+    storage: &mut dyn Storage,
+    role: &str,
+    value: &Role,
+) -> StdResult<()> {
+    ROLES.save(storage, role, value)
+}
+
+/// Get a role, or `None` if it does not exist.
+pub fn get_role(storage: &dyn Storage, role: &str) -> StdResult<Option<Role>> {
+    ROLES.may_load(storage, role)
+}
+
+/// Return the members of a role, which is empty if the role does not exist.
+pub fn members(
+    storage: &dyn Storage,
+    role: &str,
+) -> StdResult<BTreeSet<String>> {
+    Ok(get_role(storage, role)?
+        .map(|role| role.members)
+        .unwrap_or_default())
+}
+
+/// Add an address to a role, creating the role with every action allowed if
+/// it does not exist yet.
+pub fn add_member(
+    storage: &mut dyn Storage,
+    role: &str,
+    address: &str,
+) -> StdResult<()> {
+    let mut value =
+        get_role(storage, role)?.unwrap_or_else(|| Role::new(Actions::All));
+    value.members.insert(address.to_string());
+    ROLES.save(storage, role, &value)
+}
+
+/// Remove an address from a role. Return Ok(false) if it was not a member.
+pub fn remove_member(
+    storage: &mut dyn Storage,
+    role: &str,
+    address: &str,
+) -> StdResult<bool> {
+    let Some(mut value) = get_role(storage, role)? else {
+        return Ok(false);
+    };
+    let was_member = value.members.remove(address);
+    ROLES.save(storage, role, &value)?;
+    Ok(was_member)
+}
+
+/// Return Ok(true) if the address is a member of the role. The owner is not
+/// a member of any role unless added to it.
+pub fn has_role(
+    storage: &dyn Storage,
+    role: &str,
+    address: &str,
+) -> StdResult<bool> {
+    Ok(get_role(storage, role)?.is_some_and(|role| role.has(address)))
+}
+
+/// Assert that an account is the contract's current owner or a member of the
+/// role.
+pub fn assert_role(
+    storage: &dyn Storage,
+    role: &str,
+    address: &str,
+) -> Result<(), PermsError> {
+    if nibiru_ownable::is_owner(storage, address)?
+        || has_role(storage, role, address)?
+    {
+        return Ok(());
+    }
+    Err(PermsError::MissingRole {
+        role: role.to_string(),
+        address: address.to_string(),
+    })
+}
+
+/// Return Ok(true) if the address is the contract's current owner or a member
+/// of a role that allows the action.
+pub fn is_allowed(
+    storage: &dyn Storage,
+    address: &str,
+    action: &str,
+) -> StdResult<bool> {
+    if nibiru_ownable::is_owner(storage, address)? {
+        return Ok(true);
+    }
+    for item in ROLES.range(storage, None, None, Order::Ascending) {
+        let (_, role) = item?;
+        if role.has(address) && role.actions.allows(action) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Assert that an account is allowed to take the action.
+pub fn assert_allowed(
+    storage: &dyn Storage,
+    address: &str,
+    action: &str,
+) -> Result<(), PermsError> {
+    match is_allowed(storage, address, action)? {
+        true => Ok(()),
+        false => Err(PermsError::ActionNotAllowed {
+            action: action.to_string(),
+            address: address.to_string(),
+        }),
+    }
+}
+
+/// Update the contract's roles based on the given action. Only the contract's
+/// current owner can update the roles. Return an event describing the change.
+pub fn update_roles(
+    storage: &mut dyn Storage,
+    sender: &str,
+    action: Action,
+) -> Result<Event, PermsError> {
+    nibiru_ownable::assert_owner(storage, sender)?;
+    let event = match action {
+        Action::AddMember { role, address } => {
+            add_member(storage, &role, &address)?;
+            Event::new("nibiru_perms/add_member")
+                .add_attribute("role", role)
+                .add_attribute("address", address)
+        }
+        Action::RemoveMember { role, address } => {
+            if !remove_member(storage, &role, &address)? {
+                return Err(PermsError::MissingRole { role, address });
+            }
+            Event::new("nibiru_perms/remove_member")
+                .add_attribute("role", role)
+                .add_attribute("address", address)
+        }
+        Action::SetActions { role, actions } => {
+            let mut value = get_role(storage, &role)?
+                .unwrap_or_else(|| Role::new(Actions::All));
+            let actions_json = serde_json::to_string(&actions)
+                .map_err(|err| StdError::generic_err(err.to_string()))?;
+            value.actions = actions;
+            ROLES.save(storage, &role, &value)?;
+            Event::new("nibiru_perms/set_actions")
+                .add_attribute("role", role)
+                .add_attribute("actions", actions_json)
+        }
+        Action::RemoveRole { role } => {
+            if get_role(storage, &role)?.is_none() {
+                return Err(PermsError::RoleNotFound { role });
+            }
+            ROLES.remove(storage, &role);
+            Event::new("nibiru_perms/remove_role").add_attribute("role", role)
+        }
+    };
+    Ok(event)
+}
+
+/// Get the contract owner and every role.
+pub fn query_roles(storage: &dyn Storage) -> StdResult<RolesResponse> {
+    let owner = nibiru_ownable::get_ownership(storage)?.owner;
+    let roles = ROLES
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    Ok(RolesResponse { owner, roles })
+}
+
+/// Get whether an address is a member of a role.
+pub fn query_has_role(
+    storage: &dyn Storage,
+    role: String,
+    address: String,
+) -> StdResult<HasRoleResponse> {
+    let has_role = has_role(storage, &role, &address)?;
+    Ok(HasRoleResponse {
+        role,
+        address,
+        has_role,
+    })
+}
+
+//------------------------------------------------------------------------------
+// Tests
+//------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_dependencies;
+
+    use super::*;
+
+    const OWNER: &str = "larry";
+
+    fn only(actions: &[&str]) -> Actions {
+        Actions::Only(actions.iter().map(|s| s.to_string()).collect())
+    }
+
+    #[test]
+    fn roles_and_actions() {
+        let mut deps = mock_dependencies();
+        let storage = &mut deps.storage;
+        nibiru_ownable::initialize_owner(storage, Some(OWNER)).unwrap();
+
+        add_member(storage, "operator", "jake").unwrap();
+        save_role(
+            storage,
+            "staker",
+            &Role {
+                members: ["pumpkin".to_string()].into(),
+                actions: only(&["stake", "claim_rewards"]),
+            },
+        )
+        .unwrap();
+
+        assert!(has_role(storage, "operator", "jake").unwrap());
+        assert!(!has_role(storage, "operator", OWNER).unwrap());
+        assert!(!has_role(storage, "missing", "jake").unwrap());
+        assert!(assert_role(storage, "operator", OWNER).is_ok());
+        assert_eq!(
+            assert_role(storage, "operator", "pumpkin").unwrap_err(),
+            PermsError::MissingRole {
+                role: "operator".to_string(),
+                address: "pumpkin".to_string(),
+            }
+        );
+
+        for (address, action, want) in [
+            (OWNER, "anything", true),
+            ("jake", "anything", true),
+            ("pumpkin", "stake", true),
+            ("pumpkin", "bank_send", false),
+            ("nobody", "stake", false),
+        ] {
+            assert_eq!(
+                is_allowed(storage, address, action).unwrap(),
+                want,
+                "address: {address}, action: {action}"
+            );
+        }
+        assert_eq!(
+            assert_allowed(storage, "pumpkin", "bank_send").unwrap_err(),
+            PermsError::ActionNotAllowed {
+                action: "bank_send".to_string(),
+                address: "pumpkin".to_string(),
+            }
+        );
+
+        assert!(remove_member(storage, "operator", "jake").unwrap());
+        assert!(!remove_member(storage, "operator", "jake").unwrap());
+        assert!(!remove_member(storage, "missing", "jake").unwrap());
+        assert_eq!(members(storage, "operator").unwrap(), BTreeSet::new());
+        assert_eq!(members(storage, "missing").unwrap(), BTreeSet::new());
+    }
+
+    #[test]
+    fn updating_roles() {
+        let mut deps = mock_dependencies();
+        let storage = &mut deps.storage;
+        nibiru_ownable::initialize_owner(storage, Some(OWNER)).unwrap();
+
+        let add = Action::AddMember {
+            role: "operator".to_string(),
+            address: "jake".to_string(),
+        };
+
+        // only the owner can update roles
+        let err = update_roles(storage, "jake", add.clone()).unwrap_err();
+        assert_eq!(err, PermsError::Ownership(OwnershipError::NotOwner));
+
+        let event = update_roles(storage, OWNER, add).unwrap();
+        assert_eq!(event.ty, "nibiru_perms/add_member");
+        assert!(has_role(storage, "operator", "jake").unwrap());
+
+        let event = update_roles(
+            storage,
+            OWNER,
+            Action::SetActions {
+                role: "operator".to_string(),
+                actions: only(&["bank_send"]),
+            },
+        )
+        .unwrap();
+        assert_eq!(event.ty, "nibiru_perms/set_actions");
+        assert_eq!(event.attributes[1].value, r#"{"only":["bank_send"]}"#);
+        assert!(is_allowed(storage, "jake", "bank_send").unwrap());
+        assert!(!is_allowed(storage, "jake", "withdraw").unwrap());
+
+        let res = query_roles(storage).unwrap();
+        assert_eq!(res.owner, Some(OWNER.to_string()));
+        assert_eq!(
+            res.roles,
+            BTreeMap::from([(
+                "operator".to_string(),
+                Role {
+                    members: ["jake".to_string()].into(),
+                    actions: only(&["bank_send"]),
+                }
+            )])
+        );
+
+        let remove = Action::RemoveMember {
+            role: "operator".to_string(),
+            address: "pumpkin".to_string(),
+        };
+        let err = update_roles(storage, OWNER, remove).unwrap_err();
+        assert!(matches!(err, PermsError::MissingRole { .. }));
+
+        let remove_role = Action::RemoveRole {
+            role: "operator".to_string(),
+        };
+        let event = update_roles(storage, OWNER, remove_role.clone()).unwrap();
+        assert_eq!(event.ty, "nibiru_perms/remove_role");
+        let res = query_has_role(storage, "operator".into(), "jake".into());
+        assert!(!res.unwrap().has_role);
+        let err = update_roles(storage, OWNER, remove_role).unwrap_err();
+        assert_eq!(
+            err,
+            PermsError::RoleNotFound {
+                role: "operator".to_string()
+            }
+        );
+    }
+}
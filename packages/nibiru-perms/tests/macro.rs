@@ -0,0 +1,61 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use nibiru_ownable::{ownable_execute, ownable_query};
+use nibiru_perms::{perms_execute, perms_query, Action};
+
+#[perms_execute]
+#[ownable_execute]
+#[cw_serde]
+enum ExecuteMsg {
+    Foo,
+    Bar(u64),
+}
+
+#[perms_query]
+#[ownable_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+enum QueryMsg {
+    #[returns(String)]
+    Foo,
+}
+
+#[test]
+fn derive_execute_variants() {
+    let msg = ExecuteMsg::Foo;
+
+    // If this compiles we have won.
+    match msg {
+        ExecuteMsg::EditRoles(Action::AddMember { .. })
+        | ExecuteMsg::EditRoles(Action::RemoveMember { .. })
+        | ExecuteMsg::EditRoles(Action::SetActions { .. })
+        | ExecuteMsg::EditRoles(Action::RemoveRole { .. })
+        | ExecuteMsg::UpdateOwnership(_)
+        | ExecuteMsg::Foo
+        | ExecuteMsg::Bar(_) => "yay",
+    };
+}
+
+#[test]
+fn derive_query_variants() {
+    let msg = QueryMsg::Foo;
+
+    // If this compiles we have won.
+    match msg {
+        QueryMsg::Roles {}
+        | QueryMsg::HasRole { .. }
+        | QueryMsg::Ownership {}
+        | QueryMsg::Foo => "yay",
+    };
+}
+
+#[test]
+fn query_msg_json() {
+    let msg = QueryMsg::HasRole {
+        role: "operator".to_string(),
+        address: "jake".to_string(),
+    };
+    assert_eq!(
+        cosmwasm_std::to_json_string(&msg).unwrap(),
+        r#"{"has_role":{"role":"operator","address":"jake"}}"#
+    );
+}
@@ -0,0 +1,5 @@
+//! Run with: `cargo run --bin proto_clean`. See scripts/proto_clean.rs.
+
+fn main() {
+    scripts::proto_clean::main()
+}
@@ -0,0 +1,5 @@
+//! Run with: `cargo run --bin proto_gen`. See scripts/proto_gen.rs.
+
+fn main() -> anyhow::Result<()> {
+    scripts::proto_gen::main()
+}
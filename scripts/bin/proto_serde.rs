@@ -0,0 +1,5 @@
+//! Run with: `cargo run --bin proto_serde`. See scripts/proto_serde.rs.
+
+fn main() -> anyhow::Result<()> {
+    scripts::proto_serde::main()
+}
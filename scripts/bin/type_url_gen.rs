@@ -0,0 +1,5 @@
+//! Run with: `cargo run --bin type_url_gen`. See scripts/type_url_gen.rs.
+
+fn main() -> anyhow::Result<()> {
+    scripts::type_url_gen::main()
+}
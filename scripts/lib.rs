@@ -0,0 +1,8 @@
+//! scripts/lib.rs: Code generation for the protobuf types of nibiru-std.
+//!
+//! Each module backs the binary of the same name in scripts/bin.
+
+pub mod proto_clean;
+pub mod proto_gen;
+pub mod proto_serde;
+pub mod type_url_gen;
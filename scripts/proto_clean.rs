@@ -28,6 +28,7 @@ pub fn main() {
             fname.starts_with("cosmos")
                 || fname.starts_with("nibiru")
                 || fname.starts_with("eth")
+                || fname.starts_with("ibc")
         })
     {
         // Get the path of the file we're going to clean.
@@ -46,12 +47,24 @@ pub fn main() {
 pub fn clean_file_imports(
     rust_proto_path: &str,
 ) -> Result<String, Box<dyn std::error::Error>> {
-    // Define a regular expression to match `super::` imports
-    let re = regex::Regex::new(r"super::(?:super::)+[\w:]+")?;
     let content = std::fs::read_to_string(rust_proto_path)?;
+    Ok(clean_imports(&content))
+}
+
+/// Replaces every `super::super::` import in the prost generated `content`
+/// with its absolute path under `crate::proto`.
+pub fn clean_imports(content: &str) -> String {
+    // Define a regular expression to match `super::` imports
+    let re = regex::Regex::new(r"super::(?:super::)+[\w:]+")
+        .expect("valid import regex");
     // Replace all matches in the file content using the provided function
-    let updated_content = re.replace_all(&content, |caps: &regex::Captures| {
+    let updated_content = re.replace_all(content, |caps: &regex::Captures| {
         let matched = &caps[0]; // Get the entire matched string
+                                // A bare type name, Ex.: "super::super::SignMode", is a type of the
+                                // same package seen from a nested module, so it stays relative.
+        if !matched.trim_start_matches("super::").contains("::") {
+            return matched.to_string();
+        }
         match super_import_to_clean(matched) {
             Ok(cleaned) => cleaned,
             Err(err) => {
@@ -61,7 +74,7 @@ pub fn clean_file_imports(
         }
     });
 
-    Ok(updated_content.to_string())
+    updated_content.to_string()
 }
 
 /// Runs clean_file_imports and writes new contents back to the input file.
@@ -112,6 +125,7 @@ pub fn super_import_to_clean(
     } else if proto_submodules::is_mod_tendermint(elem)
         || proto_submodules::is_mod_cosmos(elem)
         || proto_submodules::is_mod_eth(elem)
+        || proto_submodules::is_mod_ibc(elem)
     {
         prefix = "crate::proto"
     } else if proto_submodules::is_submod_cosmos_base(elem) {
@@ -122,6 +136,10 @@ pub fn super_import_to_clean(
         prefix = "crate::proto::cosmos::crypto"
     } else if proto_submodules::is_submod_eth(elem) {
         prefix = "crate::proto::eth"
+    } else if proto_submodules::is_submod_ibc(elem) {
+        prefix = "crate::proto::ibc"
+    } else if proto_submodules::is_submod_ibc_core(elem) {
+        prefix = "crate::proto::ibc::core"
     } else {
         return Err(Box::new(CustomError(format!(
             "Unrecognized import submodule: {}",
@@ -215,6 +233,26 @@ mod proto_submodules {
     pub fn is_submod_eth(s: &str) -> bool {
         ETH.contains(&s)
     }
+
+    pub fn is_mod_ibc(s: &str) -> bool {
+        matches!(s, "ibc")
+    }
+
+    /// List of all proto package names beginning with "ibc". This list only
+    /// contains immediate children.
+    pub static IBC: [&str; 2] = ["applications", "core"];
+
+    pub fn is_submod_ibc(s: &str) -> bool {
+        IBC.contains(&s)
+    }
+
+    /// IBC_CORE: Names of the proto packages: ibc.core.*
+    pub static IBC_CORE: [&str; 4] =
+        ["channel", "client", "commitment", "connection"];
+
+    pub fn is_submod_ibc_core(s: &str) -> bool {
+        IBC_CORE.contains(&s)
+    }
 }
 
 #[cfg(test)]
@@ -258,6 +296,18 @@ mod tests {
                 want_err: false,
                 want_out: Some("crate::proto::cosmos::base::v1beta1::Coin"),
             },
+            TestCase {
+                input: "super::super::super::core::client::v1::Height",
+                want_err: false,
+                want_out: Some("crate::proto::ibc::core::client::v1::Height"),
+            },
+            TestCase {
+                input: "super::super::client::v1::IdentifiedClientState",
+                want_err: false,
+                want_out: Some(
+                    "crate::proto::ibc::core::client::v1::IdentifiedClientState",
+                ),
+            },
             TestCase {
                 input: "::super::super::eth::evm::v1::FunToken",
                 want_err: false,
@@ -286,6 +336,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn clean_imports_keeps_same_package_types() {
+        let code =
+            "#[prost(enumeration=\"super::super::SignMode\", tag=\"1\")]\n\
+            pub coin: super::super::super::base::v1beta1::Coin,";
+        assert_eq!(
+            super::clean_imports(code),
+            "#[prost(enumeration=\"super::super::SignMode\", tag=\"1\")]\n\
+            pub coin: crate::proto::cosmos::base::v1beta1::Coin,"
+        );
+    }
+
     #[test]
     fn fixture_proto_clean() {
         let dirty_path = "test/fixture_proto_dirty.rs";
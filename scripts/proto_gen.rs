@@ -0,0 +1,782 @@
+//! scripts/proto_gen.rs:
+//!
+//! Run with: `cargo run --bin proto_gen`
+//!
+//! ## Usage
+//!
+//! ```text
+//! cargo run --bin proto_gen -- [--check]
+//!     [--descriptor <file> | --proto-dir <dir>]
+//! ```
+//!
+//! - `--descriptor <file>`: Protobuf `FileDescriptorSet` with the full message
+//!   definitions and source info, as written by
+//!   `buf build --as-file-descriptor-set -o <file>`. Defaults to
+//!   nibiru-std/src/proto/buf/proto_descriptor.bin.
+//! - `--proto-dir <dir>`: Vendored tree of .proto files, Ex.: the "proto"
+//!   directory of NibiruChain/nibiru and its dependencies. The script compiles
+//!   it into a descriptor set with `protoc`, or the binary at `$PROTOC`, and
+//!   writes that set to proto_descriptor.bin.
+//! - `--check`: Write nothing and fail if any committed file differs from what
+//!   the script would generate.
+//!
+//! ## Procedure
+//!
+//! 1. Decode the descriptor set, or build it from `--proto-dir`.
+//! 2. Run prost-build on every "cosmos", "nibiru", "eth", "ibc", and
+//!    "tendermint" package, with the options that protoc-gen-prost used for the committed
+//!    code: bytes fields as `Bytes` and no formatting.
+//! 3. Rename the oneofs in `ONEOF_RENAMES` whose enum clashes with a nested
+//!    message. Frame each package like protoc-gen-prost, rewrite its
+//!    `super::super::` imports (proto_clean.rs), and add the serde attributes
+//!    (proto_serde.rs).
+//! 4. Add a module to nibiru-std/src/proto/mod.rs for each new "nibiru" or
+//!    "eth" package, Ex.: `nibiru.foo.v1` becomes `nibiru::foo`, gated by the
+//!    cargo feature "foo". Packages that need a module added or removed by
+//!    hand are reported as warnings.
+//! 5. Check that each feature gating a module in mod.rs is defined in
+//!    nibiru-std/Cargo.toml and enabled by "all_protos".
+//! 6. Render the type_url_*.rs files (type_url_gen.rs).
+//! 7. Write every file to nibiru-std/src/proto, or with `--check`, list the
+//!    files that are out of date.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use prost::Message;
+use prost_build::Module;
+use prost_types::{FileDescriptorProto, FileDescriptorSet};
+
+use crate::type_url_gen::{self, DESCRIPTOR_PATH, PROTO_DIR};
+use crate::{proto_clean, proto_serde};
+
+pub static CARGO_TOML_PATH: &str = "../nibiru-std/Cargo.toml";
+
+/// Root proto packages with generated code in nibiru-std.
+pub static ROOT_PACKAGES: [&str; 5] =
+    ["cosmos", "nibiru", "eth", "ibc", "tendermint"];
+
+/// Oneofs whose enum is renamed after codegen, as (package, path of the enum,
+/// new name). prost names the enum of a oneof after the oneof, which does not
+/// compile when a nested message of the same parent has that name. The field
+/// keeps its proto name. The names follow cosmos-sdk-proto.
+pub static ONEOF_RENAMES: [(&str, &str, &str); 1] = [(
+    "cosmos.staking.v1beta1",
+    "stake_authorization::Validators",
+    "Policy",
+)];
+
+/// Root proto packages that get a module in proto/mod.rs automatically.
+static AUTO_MOD_ROOTS: [&str; 2] = ["nibiru", "eth"];
+
+static PROST_BUILD_HEADER: &str = "// This file is @generated by prost-build.\n";
+static HEADER: &str = "// @generated\n";
+static TRAILER: &str = "// @@protoc_insertion_point(module)\n";
+
+static USAGE: &str = "usage: proto_gen [--check] \
+    [--descriptor <file> | --proto-dir <dir>]";
+
+/// Where the proto definitions come from.
+#[derive(Debug, PartialEq)]
+pub enum Source {
+    /// A `FileDescriptorSet` file
+    Descriptor(PathBuf),
+    /// A directory of .proto files to compile with protoc
+    ProtoDir(PathBuf),
+}
+
+/// Command line arguments of the script.
+#[derive(Debug, PartialEq)]
+pub struct Args {
+    pub check: bool,
+    pub source: Source,
+}
+
+impl Args {
+    pub fn parse(
+        args: impl IntoIterator<Item = String>,
+    ) -> anyhow::Result<Args> {
+        let mut check = false;
+        let mut source: Option<Source> = None;
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--check" => check = true,
+                "--descriptor" | "--proto-dir" => {
+                    if source.is_some() {
+                        bail!("use only one of --descriptor and --proto-dir");
+                    }
+                    let path: PathBuf = args
+                        .next()
+                        .with_context(|| format!("missing value of {arg}"))?
+                        .into();
+                    source = Some(match arg.as_str() {
+                        "--descriptor" => Source::Descriptor(path),
+                        _ => Source::ProtoDir(path),
+                    });
+                }
+                _ => bail!("unknown argument {arg:?}\n{USAGE}"),
+            }
+        }
+        Ok(Args {
+            check,
+            source: source
+                .unwrap_or_else(|| Source::Descriptor(DESCRIPTOR_PATH.into())),
+        })
+    }
+}
+
+pub fn main() -> anyhow::Result<()> {
+    println!("Running proto_gen.rs...");
+    let args = Args::parse(std::env::args().skip(1))?;
+
+    // Generated files keyed by path
+    let mut files: BTreeMap<PathBuf, Vec<u8>> = BTreeMap::new();
+
+    let descriptor_bz = match &args.source {
+        Source::Descriptor(path) => std::fs::read(path)
+            .with_context(|| format!("reading {}", path.display()))?,
+        Source::ProtoDir(dir) => {
+            let descriptor_bz = build_descriptor(dir)?;
+            files.insert(DESCRIPTOR_PATH.into(), descriptor_bz.clone());
+            descriptor_bz
+        }
+    };
+    let descriptor = FileDescriptorSet::decode(descriptor_bz.as_slice())?;
+    let bufs = generate_bufs(&descriptor)?;
+
+    let mod_rs_path = format!("{PROTO_DIR}/mod.rs");
+    let old_mod_rs = std::fs::read_to_string(&mod_rs_path)?;
+    let (mod_rs, mut warnings) =
+        add_proto_mods(&old_mod_rs, bufs.keys().map(String::as_str));
+    let included = type_url_gen::parse_proto_mods(&mod_rs);
+    for pkg in bufs.keys() {
+        let is_new = !Path::new(&format!("{PROTO_DIR}/buf/{pkg}.rs")).exists();
+        if is_new && !included.contains_key(pkg) {
+            warnings.push(format!(
+                "{pkg} is a new package without a module in proto/mod.rs"
+            ));
+        }
+    }
+    let cargo_toml = std::fs::read_to_string(CARGO_TOML_PATH)?;
+    let feature_errs = missing_features(&mod_rs, &cargo_toml);
+
+    let type_urls =
+        type_url_gen::render_type_urls(&descriptor, &mod_rs, |pkg| {
+            bufs.get(pkg)
+                .cloned()
+                .with_context(|| format!("no generated code for {pkg}"))
+        })?;
+    for (file_name, content) in type_urls {
+        files.insert(format!("{PROTO_DIR}/{file_name}").into(), content.into());
+    }
+    files.insert(mod_rs_path.into(), mod_rs.into());
+    for (pkg, code) in bufs {
+        files.insert(format!("{PROTO_DIR}/buf/{pkg}.rs").into(), code.into());
+    }
+
+    for warning in &warnings {
+        eprintln!("warning: {warning}");
+    }
+
+    if args.check {
+        let stale: Vec<String> = files
+            .iter()
+            .filter(|(path, content)| {
+                std::fs::read(path).ok().as_ref() != Some(*content)
+            })
+            .map(|(path, _)| format!("  {}", path.display()))
+            .collect();
+        if !stale.is_empty() || !feature_errs.is_empty() {
+            let errs: Vec<String> =
+                feature_errs.iter().map(|err| format!("  {err}")).collect();
+            bail!(
+                "generated protos are out of date, run `just gen-proto`:\n{}",
+                [stale, errs].concat().join("\n")
+            );
+        }
+        println!("generated protos are up to date");
+        return Ok(());
+    }
+
+    for err in &feature_errs {
+        eprintln!("warning: {err}");
+    }
+    for (path, content) in files {
+        if std::fs::read(&path).ok() != Some(content.clone()) {
+            println!("writing {}", path.display());
+            std::fs::write(&path, content)?;
+        }
+    }
+
+    println!("ran proto_gen.rs successfully");
+    Ok(())
+}
+
+/// Compiles every .proto file under `proto_dir` into an encoded
+/// `FileDescriptorSet` with imports and source info.
+pub fn build_descriptor(proto_dir: &Path) -> anyhow::Result<Vec<u8>> {
+    let mut protos: Vec<PathBuf> = walkdir::WalkDir::new(proto_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "proto"))
+        .map(|e| e.into_path())
+        .collect();
+    protos.sort();
+    if protos.is_empty() {
+        bail!("no .proto files in {}", proto_dir.display());
+    }
+
+    let out_path = std::env::temp_dir().join("proto_gen_descriptor.bin");
+    let protoc = prost_build::protoc_from_env();
+    println!(
+        "compiling {} .proto files with {}",
+        protos.len(),
+        protoc.display()
+    );
+    let status = std::process::Command::new(&protoc)
+        .arg("--include_imports")
+        .arg("--include_source_info")
+        .arg(format!("--proto_path={}", proto_dir.display()))
+        .arg(format!("--descriptor_set_out={}", out_path.display()))
+        .args(&protos)
+        .status()
+        .with_context(|| {
+            format!("running {}, set $PROTOC to its path", protoc.display())
+        })?;
+    if !status.success() {
+        bail!("protoc failed on {}", proto_dir.display());
+    }
+    Ok(std::fs::read(&out_path)?)
+}
+
+/// Whether nibiru-std keeps the generated code of a proto package.
+pub fn is_generated(pkg: &str) -> bool {
+    let root = pkg.split('.').next().unwrap_or_default();
+    ROOT_PACKAGES.contains(&root)
+}
+
+/// Generates the code of each package in `descriptor` that nibiru-std keeps,
+/// keyed by package name, as it is committed in nibiru-std/src/proto/buf.
+pub fn generate_bufs(
+    descriptor: &FileDescriptorSet,
+) -> anyhow::Result<BTreeMap<String, String>> {
+    let files: Vec<&FileDescriptorProto> = descriptor
+        .file
+        .iter()
+        .filter(|file| is_generated(file.package()))
+        .collect();
+    if files.is_empty() {
+        bail!(
+            "the descriptor set has no {} packages",
+            ROOT_PACKAGES.join("/")
+        );
+    }
+    let has_fields = files
+        .iter()
+        .flat_map(|file| &file.message_type)
+        .any(|msg| !msg.field.is_empty());
+    if !has_fields {
+        bail!(
+            "the messages in the descriptor set have no fields, so it only \
+            holds their names. Rebuild it with `buf build \
+            --as-file-descriptor-set -o proto_descriptor.bin`, or pass \
+            --proto-dir"
+        );
+    }
+
+    // Every file goes to prost-build so that it can resolve the types of
+    // imported packages, and the packages nibiru-std drops are filtered out.
+    let mut pkgs: BTreeMap<Module, String> = BTreeMap::new();
+    let requests: Vec<(Module, FileDescriptorProto)> = descriptor
+        .file
+        .iter()
+        .map(|file| {
+            let module = Module::from_protobuf_package_name(file.package());
+            pkgs.insert(module.clone(), file.package().to_string());
+            (module, file.clone())
+        })
+        .collect();
+    let modules = prost_build::Config::new()
+        .bytes(["."])
+        .generate(requests)
+        .context("running prost-build")?;
+
+    let mut bufs: BTreeMap<String, String> = BTreeMap::new();
+    for (module, code) in modules {
+        let pkg = &pkgs[&module];
+        if is_generated(pkg) {
+            let buf = rename_oneofs(pkg, &code)
+                .and_then(|code| frame_buf(&code))
+                .with_context(|| format!("processing {pkg}"))?;
+            bufs.insert(pkg.clone(), buf);
+        }
+    }
+    Ok(bufs)
+}
+
+/// Applies the [`ONEOF_RENAMES`] of `pkg` to its prost-build output.
+pub fn rename_oneofs(pkg: &str, code: &str) -> anyhow::Result<String> {
+    let mut code = code.to_string();
+    for (_, path, new_name) in ONEOF_RENAMES.iter().filter(|(p, ..)| *p == pkg) {
+        let (parent, name) = path.rsplit_once("::").unwrap_or(("", path));
+        let new_path = format!("{parent}::{new_name}");
+        for (from, to) in [
+            (format!("oneof=\"{path}\""), format!("oneof=\"{new_path}\"")),
+            (format!("Option<{path}>"), format!("Option<{new_path}>")),
+            (
+                format!("pub enum {name} {{"),
+                format!("pub enum {new_name} {{"),
+            ),
+        ] {
+            if !code.contains(&from) {
+                bail!("oneof {path} to rename is missing {from:?}");
+            }
+            code = code.replace(&from, &to);
+        }
+    }
+    Ok(code)
+}
+
+/// Turns the prost-build output of a package into the committed form of
+/// nibiru-std/src/proto/buf: the protoc-gen-prost header and trailer, clean
+/// imports, and serde attributes.
+pub fn frame_buf(code: &str) -> anyhow::Result<String> {
+    let body = code.strip_prefix(PROST_BUILD_HEADER).unwrap_or(code);
+    let body = proto_clean::clean_imports(body);
+    proto_serde::add_serde_attrs(&format!("{HEADER}{body}{TRAILER}"))
+}
+
+/// Adds a module to `mod_rs`, the source of proto/mod.rs, for each of `pkgs`
+/// in a root of [`AUTO_MOD_ROOTS`] without one. Returns the new mod.rs and
+/// notes on the packages that need a module added or removed by hand.
+pub fn add_proto_mods<'a>(
+    mod_rs: &str,
+    pkgs: impl IntoIterator<Item = &'a str>,
+) -> (String, Vec<String>) {
+    let pkgs: BTreeSet<&str> = pkgs.into_iter().collect();
+    let included = type_url_gen::parse_proto_mods(mod_rs);
+    let mut notes: Vec<String> = included
+        .keys()
+        .filter(|pkg| !pkgs.contains(pkg.as_str()))
+        .map(|pkg| {
+            format!("proto/mod.rs includes {pkg}, which is no longer generated")
+        })
+        .collect();
+
+    let mut lines: Vec<String> = mod_rs.lines().map(String::from).collect();
+    for pkg in pkgs.iter().filter(|pkg| !included.contains_key(**pkg)) {
+        // Ex.: "nibiru.foo.v1" -> ("nibiru", "foo")
+        let (root, name) = match pkg.split('.').collect::<Vec<_>>()[..] {
+            [root, name, version]
+                if AUTO_MOD_ROOTS.contains(&root)
+                    && version.starts_with('v') =>
+            {
+                (root, name)
+            }
+            _ => continue,
+        };
+        let rust_mod = format!("{root}::{name}");
+        if let Some((other, _)) = included.iter().find(|(_, other_mod)| {
+            **other_mod == rust_mod
+                || other_mod.starts_with(&format!("{rust_mod}::"))
+        }) {
+            notes.push(format!(
+                "{pkg} has no module in proto/mod.rs, and {rust_mod} already \
+                includes {other}"
+            ));
+            continue;
+        }
+        match insert_mod(&mut lines, root, name, pkg) {
+            true => notes.push(format!(
+                "added {rust_mod} to proto/mod.rs; define the \"{name}\" \
+                feature in nibiru-std/Cargo.toml and add it to \"all_protos\""
+            )),
+            false => notes.push(format!(
+                "{pkg} has no module in proto/mod.rs, which has no \
+                `pub mod {root}`"
+            )),
+        }
+    }
+
+    let mut new_mod_rs = lines.join("\n");
+    if mod_rs.ends_with('\n') {
+        new_mod_rs.push('\n');
+    }
+    (new_mod_rs, notes)
+}
+
+/// Inserts a module `name` that includes `pkg` into the `pub mod {root}`
+/// block of `lines`, in alphabetical order. Returns false if there is no such
+/// block.
+fn insert_mod(
+    lines: &mut Vec<String>,
+    root: &str,
+    name: &str,
+    pkg: &str,
+) -> bool {
+    let Some(start) = lines
+        .iter()
+        .position(|line| *line == format!("pub mod {root} {{"))
+    else {
+        return false;
+    };
+    let Some(end) = lines[start..]
+        .iter()
+        .position(|line| line == "}")
+        .map(|idx| start + idx)
+    else {
+        return false;
+    };
+
+    // Insert before the first sibling module that sorts after `name`,
+    // including its attributes and doc comments.
+    let mut at = lines[start..end]
+        .iter()
+        .position(|line| {
+            line.strip_prefix("    pub mod ")
+                .and_then(|rest| rest.strip_suffix(" {"))
+                .is_some_and(|sibling| sibling > name)
+        })
+        .map_or(end, |idx| start + idx);
+    while at > start + 1
+        && ["    #[", "    ///"]
+            .iter()
+            .any(|prefix| lines[at - 1].starts_with(prefix))
+    {
+        at -= 1;
+    }
+
+    let new_mod = [
+        format!("    #[cfg(feature = \"{name}\")]"),
+        format!("    pub mod {name} {{"),
+        format!("        include!(\"buf/{pkg}.rs\");"),
+        "    }".to_string(),
+    ];
+    lines.splice(at..at, new_mod);
+    true
+}
+
+/// Lists the cargo features that gate a module in `mod_rs`, the source of
+/// proto/mod.rs, but are not defined in `cargo_toml` or not enabled by
+/// "all_protos".
+pub fn missing_features(mod_rs: &str, cargo_toml: &str) -> Vec<String> {
+    let features: BTreeSet<String> = type_url_gen::parse_proto_features(mod_rs)
+        .into_values()
+        .flatten()
+        .collect();
+    let all_protos = cargo_toml
+        .split_once("\nall_protos = [")
+        .and_then(|(_, rest)| rest.split_once(']'))
+        .map(|(list, _)| list)
+        .unwrap_or_default();
+
+    let mut errs: Vec<String> = vec![];
+    for feature in features {
+        if !cargo_toml.contains(&format!("\n{feature} = [")) {
+            errs.push(format!(
+                "feature \"{feature}\" is not defined in nibiru-std/Cargo.toml"
+            ));
+        } else if !all_protos.contains(&format!("\"{feature}\"")) {
+            errs.push(format!(
+                "feature \"{feature}\" is not enabled by \"all_protos\""
+            ));
+        }
+    }
+    errs
+}
+
+#[cfg(test)]
+mod tests {
+    use prost_types::{
+        field_descriptor_proto::{Label, Type},
+        DescriptorProto, FieldDescriptorProto, FileDescriptorProto,
+        FileDescriptorSet,
+    };
+
+    use super::*;
+
+    fn field(name: &str, number: i32, r#type: Type) -> FieldDescriptorProto {
+        FieldDescriptorProto {
+            name: Some(name.to_string()),
+            number: Some(number),
+            label: Some(Label::Optional.into()),
+            r#type: Some(r#type.into()),
+            json_name: Some(name.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn file(
+        pkg: &str,
+        msg: &str,
+        fields: Vec<FieldDescriptorProto>,
+    ) -> FileDescriptorProto {
+        FileDescriptorProto {
+            name: Some(format!("{}/{msg}.proto", pkg.replace('.', "/"))),
+            package: Some(pkg.to_string()),
+            message_type: vec![DescriptorProto {
+                name: Some(msg.to_string()),
+                field: fields,
+                ..Default::default()
+            }],
+            syntax: Some("proto3".to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// A `nibiru.demo.v1.MsgDemo` with a string, bytes, and imported message
+    /// field.
+    fn demo_descriptor() -> FileDescriptorSet {
+        let coin = file(
+            "cosmos.base.v1beta1",
+            "Coin",
+            vec![
+                field("denom", 1, Type::String),
+                field("amount", 2, Type::String),
+            ],
+        );
+        let mut msg_demo = file(
+            "nibiru.demo.v1",
+            "MsgDemo",
+            vec![
+                field("sender", 1, Type::String),
+                field("data", 2, Type::Bytes),
+                FieldDescriptorProto {
+                    type_name: Some(".cosmos.base.v1beta1.Coin".to_string()),
+                    ..field("coin", 3, Type::Message)
+                },
+            ],
+        );
+        msg_demo.dependency = vec![coin.name().to_string()];
+        let unused = file("google.demo", "Unused", vec![]);
+        FileDescriptorSet {
+            file: vec![coin, msg_demo, unused],
+        }
+    }
+
+    #[test]
+    fn test_args_parse() -> anyhow::Result<()> {
+        let default_source = Source::Descriptor(DESCRIPTOR_PATH.into());
+        let test_cases: Vec<(Vec<&str>, Option<Args>)> = vec![
+            (
+                vec![],
+                Some(Args {
+                    check: false,
+                    source: default_source,
+                }),
+            ),
+            (
+                vec!["--proto-dir", "proto", "--check"],
+                Some(Args {
+                    check: true,
+                    source: Source::ProtoDir("proto".into()),
+                }),
+            ),
+            (
+                vec!["--descriptor", "fds.bin"],
+                Some(Args {
+                    check: false,
+                    source: Source::Descriptor("fds.bin".into()),
+                }),
+            ),
+            (vec!["--descriptor"], None),
+            (vec!["--descriptor", "a", "--proto-dir", "b"], None),
+            (vec!["--force"], None),
+        ];
+        for (args, want) in test_cases {
+            let got = Args::parse(args.iter().map(|arg| arg.to_string()));
+            match want {
+                Some(want) => assert_eq!(got?, want, "args: {args:?}"),
+                None => assert!(got.is_err(), "args: {args:?}"),
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_bufs() -> anyhow::Result<()> {
+        let bufs = generate_bufs(&demo_descriptor())?;
+        assert_eq!(
+            bufs.keys().collect::<Vec<_>>(),
+            vec!["cosmos.base.v1beta1", "nibiru.demo.v1"]
+        );
+
+        let demo = &bufs["nibiru.demo.v1"];
+        assert!(demo.starts_with(HEADER), "{demo}");
+        assert!(demo.ends_with(TRAILER), "{demo}");
+        for want in [
+            "pub struct MsgDemo {",
+            "pub data: ::prost::bytes::Bytes,",
+            "::core::option::Option<crate::proto::cosmos::base::v1beta1::Coin>",
+            proto_serde::SERDE_ATTR,
+        ] {
+            assert!(demo.contains(want), "missing {want:?} in:\n{demo}");
+        }
+        assert!(!demo.contains("super::"), "{demo}");
+        assert!(!demo.contains("prost-build"), "{demo}");
+        assert_eq!(demo.matches(HEADER).count(), 1, "{demo}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_oneofs() -> anyhow::Result<()> {
+        let code = "pub struct StakeAuthorization {
+    #[prost(oneof=\"stake_authorization::Validators\", tags=\"2, 3\")]
+    pub validators: ::core::option::Option<stake_authorization::Validators>,
+}
+pub mod stake_authorization {
+    pub struct Validators {}
+    pub enum Validators {
+        AllowList(Validators),
+    }
+}
+";
+        let got = rename_oneofs("cosmos.staking.v1beta1", code)?;
+        for want in [
+            "oneof=\"stake_authorization::Policy\"",
+            "pub validators: ::core::option::Option<stake_authorization::Policy>",
+            "pub struct Validators {}",
+            "pub enum Policy {",
+            "AllowList(Validators)",
+        ] {
+            assert!(got.contains(want), "missing {want:?} in:\n{got}");
+        }
+        assert_eq!(rename_oneofs("cosmos.bank.v1beta1", code)?, code);
+
+        let err = rename_oneofs("cosmos.staking.v1beta1", "")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("missing"), "{err}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_bufs_names_only() {
+        let mut descriptor = demo_descriptor();
+        for file in descriptor.file.iter_mut() {
+            file.message_type
+                .iter_mut()
+                .for_each(|msg| msg.field.clear());
+        }
+        let err = generate_bufs(&descriptor).unwrap_err().to_string();
+        assert!(err.contains("buf build"), "{err}");
+
+        let err = generate_bufs(&FileDescriptorSet::default())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("no cosmos/nibiru/eth/ibc/tendermint"), "{err}");
+    }
+
+    static MOD_RS: &str = r#"pub mod cosmos {
+    pub mod bank {
+        pub mod v1beta1 {
+            include!("buf/cosmos.bank.v1beta1.rs");
+        }
+    }
+}
+
+pub mod nibiru {
+    #[cfg(feature = "devgas")]
+    pub mod devgas {
+        include!("buf/nibiru.devgas.v1.rs");
+    }
+    #[cfg(feature = "oracle")]
+    /// Oracle prices.
+    pub mod oracle {
+        include!("buf/nibiru.oracle.v1.rs");
+    }
+}
+"#;
+
+    #[test]
+    fn test_add_proto_mods() {
+        let (got, notes) = add_proto_mods(
+            MOD_RS,
+            [
+                "cosmos.bank.v1beta1",
+                "cosmos.distribution.v1beta1",
+                "nibiru.devgas.v1",
+                "nibiru.evm.module.v1",
+                "nibiru.foo.v1",
+                "nibiru.oracle.v2",
+                "nibiru.zeta.v1",
+            ],
+        );
+        let want = r#"pub mod cosmos {
+    pub mod bank {
+        pub mod v1beta1 {
+            include!("buf/cosmos.bank.v1beta1.rs");
+        }
+    }
+}
+
+pub mod nibiru {
+    #[cfg(feature = "devgas")]
+    pub mod devgas {
+        include!("buf/nibiru.devgas.v1.rs");
+    }
+    #[cfg(feature = "foo")]
+    pub mod foo {
+        include!("buf/nibiru.foo.v1.rs");
+    }
+    #[cfg(feature = "oracle")]
+    /// Oracle prices.
+    pub mod oracle {
+        include!("buf/nibiru.oracle.v1.rs");
+    }
+    #[cfg(feature = "zeta")]
+    pub mod zeta {
+        include!("buf/nibiru.zeta.v1.rs");
+    }
+}
+"#;
+        assert_eq!(got, want);
+        assert_eq!(notes.len(), 4, "{notes:#?}");
+        for (note, want) in notes.iter().zip([
+            "proto/mod.rs includes nibiru.oracle.v1, which is no longer",
+            "added nibiru::foo",
+            "nibiru.oracle.v2 has no module in proto/mod.rs, and nibiru::oracle",
+            "added nibiru::zeta",
+        ]) {
+            assert!(note.starts_with(want), "{note}");
+        }
+    }
+
+    #[test]
+    fn test_add_proto_mods_keeps_committed_mod_rs() -> anyhow::Result<()> {
+        let mod_rs = std::fs::read_to_string(format!("{PROTO_DIR}/mod.rs"))?;
+        let included = type_url_gen::parse_proto_mods(&mod_rs);
+        let (got, notes) =
+            add_proto_mods(&mod_rs, included.keys().map(String::as_str));
+        assert_eq!(got, mod_rs);
+        assert_eq!(notes, Vec::<String>::new());
+
+        let cargo_toml = std::fs::read_to_string(CARGO_TOML_PATH)?;
+        assert_eq!(missing_features(&mod_rs, &cargo_toml), Vec::<String>::new());
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_features() {
+        let cargo_toml = r#"[features]
+all_protos = [
+  "devgas",
+]
+devgas = []
+oracle = []
+"#;
+        assert_eq!(
+            missing_features(MOD_RS, cargo_toml),
+            vec!["feature \"oracle\" is not enabled by \"all_protos\""]
+        );
+        assert_eq!(
+            missing_features(MOD_RS, "[features]\n"),
+            vec![
+                "feature \"devgas\" is not defined in nibiru-std/Cargo.toml",
+                "feature \"oracle\" is not defined in nibiru-std/Cargo.toml",
+            ]
+        );
+    }
+}
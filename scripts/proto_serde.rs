@@ -0,0 +1,424 @@
+//! scripts/proto_serde.rs:
+//!
+//! Run with: `cargo run --bin proto_serde`
+//!
+//! ## Procedure
+//!
+//! 1. Walk through the prost generated files in nibiru-std/src/proto/buf and
+//!    drop any attributes a previous run added, so the script is idempotent.
+//! 2. After the `#[derive]` of each message, oneof, and enum, add a
+//!    `#[cfg_attr(feature = "serde", ...)]` that derives `Serialize`,
+//!    `Deserialize`, and `JsonSchema`. Messages use camelCase field names and
+//!    oneofs are flattened into their parent message.
+//! 3. Rename each enum variant to its proto name, Ex.: "VOTE_OPTION_YES".
+//! 4. Point fields without a native JSON form, such as 64-bit integers,
+//!    bytes, enums, and the well-known types, to the helpers in
+//!    nibiru-std/src/proto/json.rs.
+//! 5. Write the modified content back to each file.
+
+use anyhow::{bail, Context};
+
+pub static PROTO_PATH: &str = "../nibiru-std/src/proto/buf";
+
+/// Prefix shared by every attribute this script adds.
+pub static SERDE_ATTR: &str = "#[cfg_attr(feature = \"serde\", ";
+
+static SCHEMARS_CRATE: &str =
+    "schemars(crate = \"::cosmwasm_schema::schemars\")";
+static JSON_MOD: &str = "crate::proto::json";
+
+pub fn main() -> anyhow::Result<()> {
+    println!("Running proto_serde.rs...");
+
+    for entry in walkdir::WalkDir::new(PROTO_PATH)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_name().to_string_lossy().ends_with(".rs"))
+    {
+        let path = entry.path();
+        println!("adding serde attributes to {}", path.display());
+        let src = std::fs::read_to_string(path)?;
+        let out = add_serde_attrs(&src)
+            .with_context(|| format!("processing {}", path.display()))?;
+        std::fs::write(path, out)?;
+    }
+
+    println!("ran proto_serde.rs successfully");
+    Ok(())
+}
+
+/// The kind of item a prost `#[derive]` introduces.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ProstItem {
+    Message,
+    Oneof,
+    Enumeration,
+}
+
+/// Returns `src` with serde attributes added to every prost type and field.
+pub fn add_serde_attrs(src: &str) -> anyhow::Result<String> {
+    let lines: Vec<&str> = src
+        .lines()
+        .filter(|line| !line.trim_start().starts_with(SERDE_ATTR))
+        .collect();
+    let mut out: Vec<String> = Vec::with_capacity(lines.len());
+    // Proto names of the variants of the enum being written, if any
+    let mut enum_names: Option<Vec<(String, String)>> = None;
+    // Indents of the modules that declare a type named `Result`
+    let result_indents: Vec<&str> = lines
+        .iter()
+        .filter_map(|line| {
+            let trimmed = line.trim_start();
+            let is_result = trimmed.starts_with("pub struct Result ")
+                || trimmed.starts_with("pub enum Result ");
+            is_result.then(|| &line[..line.len() - trimmed.len()])
+        })
+        .collect();
+    let mut idx = 0;
+    while idx < lines.len() {
+        let line = lines[idx];
+        let indent = &line[..line.len() - line.trim_start().len()];
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("#[derive(") {
+            let end = (idx..lines.len())
+                .find(|&i| lines[i].trim_end().ends_with(")]"))
+                .context("unterminated #[derive]")?;
+            let derive = lines[idx..=end].join(" ");
+            out.extend(lines[idx..=end].iter().map(|l| l.to_string()));
+            idx = end + 1;
+            let item = if derive.contains("::prost::Message") {
+                ProstItem::Message
+            } else if derive.contains("::prost::Oneof") {
+                ProstItem::Oneof
+            } else if derive.contains("::prost::Enumeration") {
+                ProstItem::Enumeration
+            } else {
+                continue;
+            };
+            out.push(format!("{indent}{}", type_attr(item)));
+            if item == ProstItem::Enumeration {
+                enum_names = Some(enum_proto_names(&lines[idx..])?);
+            }
+            continue;
+        }
+
+        if let Some(names) = &enum_names {
+            let is_attr_or_doc =
+                trimmed.starts_with("//") || trimmed.starts_with("#[");
+            if trimmed == "}" {
+                enum_names = None;
+            } else if let Some((variant, _)) =
+                trimmed.split_once(" = ").filter(|_| !is_attr_or_doc)
+            {
+                let Some((_, proto_name)) =
+                    names.iter().find(|(v, _)| v == variant)
+                else {
+                    bail!("enum variant {variant} has no proto name");
+                };
+                out.push(format!(
+                    "{indent}{SERDE_ATTR}serde(rename = \"{proto_name}\"))]"
+                ));
+            }
+        }
+
+        if trimmed.starts_with("#[prost(") {
+            // The field or oneof variant spans every line up to its trailing
+            // comma.
+            let end = (idx + 1..lines.len())
+                .find(|&i| lines[i].trim_end().ends_with(','))
+                .context("unterminated prost field")?;
+            let decl: String = lines[idx + 1..=end]
+                .iter()
+                .map(|l| l.trim())
+                .collect::<Vec<_>>()
+                .join(" ");
+            out.push(line.to_string());
+            if let Some(mut attr) = field_attr(trimmed, &decl)? {
+                // schemars 0.8 writes the default value of a field with
+                // `serialize_with` through an impl that names a bare
+                // `Result`, which fails to compile next to a proto message
+                // called "Result". Leaving the default out of the schema
+                // avoids it.
+                let type_indent = indent.get(4..).unwrap_or_default();
+                if attr.contains("serde(with")
+                    && result_indents.contains(&type_indent)
+                {
+                    attr = attr.replace(
+                        "schemars(with",
+                        "schemars(skip_serializing, with",
+                    );
+                }
+                out.push(format!("{indent}{attr}"));
+            }
+            out.extend(lines[idx + 1..=end].iter().map(|l| l.to_string()));
+            idx = end + 1;
+            continue;
+        }
+
+        out.push(line.to_string());
+        idx += 1;
+    }
+
+    let mut out = out.join("\n");
+    if src.ends_with('\n') {
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+fn type_attr(item: ProstItem) -> String {
+    let derive = "derive(::serde::Serialize, ::serde::Deserialize, \
+                  ::cosmwasm_schema::schemars::JsonSchema)";
+    match item {
+        ProstItem::Message => format!(
+            "{SERDE_ATTR}{derive}, serde(rename_all = \"camelCase\", default), \
+             {SCHEMARS_CRATE})]"
+        ),
+        ProstItem::Oneof => format!(
+            "{SERDE_ATTR}{derive}, serde(rename_all = \"camelCase\"), \
+             {SCHEMARS_CRATE})]"
+        ),
+        ProstItem::Enumeration => {
+            format!("{SERDE_ATTR}{derive}, {SCHEMARS_CRATE})]")
+        }
+    }
+}
+
+/// Reads the (variant, proto name) pairs from the `as_str_name` function
+/// that prost generates after each enum.
+fn enum_proto_names(
+    lines_after_derive: &[&str],
+) -> anyhow::Result<Vec<(String, String)>> {
+    let start = lines_after_derive
+        .iter()
+        .position(|l| l.contains("fn as_str_name("))
+        .context("enum without as_str_name")?;
+    let mut names = vec![];
+    for line in &lines_after_derive[start + 1..] {
+        let trimmed = line.trim();
+        if trimmed == "}" {
+            break;
+        }
+        // Ex.: VoteOption::Yes => "VOTE_OPTION_YES",
+        let Some((lhs, rhs)) = trimmed.split_once(" => ") else {
+            continue;
+        };
+        let variant = lhs.rsplit("::").next().unwrap_or(lhs);
+        let proto_name = rhs.trim_end_matches(',').trim_matches('"');
+        names.push((variant.to_string(), proto_name.to_string()));
+    }
+    Ok(names)
+}
+
+/// How a field type wraps the value that needs a JSON helper.
+#[derive(Debug, PartialEq)]
+enum Wrapper {
+    Value,
+    Option,
+    Vec,
+}
+
+/// Returns the serde attribute for a prost field, given its `#[prost]`
+/// attribute and its declaration, Ex.: "pub proposal_id: u64," or
+/// "Ed25519(::prost::bytes::Bytes),".
+pub fn field_attr(
+    prost_attr: &str,
+    decl: &str,
+) -> anyhow::Result<Option<String>> {
+    if prost_attr.contains("oneof") {
+        return Ok(Some(format!("{SERDE_ATTR}serde(flatten))]")));
+    }
+
+    let ty = match decl.split_once(": ") {
+        Some((_, ty)) => ty.trim_end_matches(','),
+        None => {
+            let (_, ty) = decl.split_once('(').context("unknown field")?;
+            ty.trim_end_matches(',').trim_end_matches(')')
+        }
+    };
+    let ty: String = ty.chars().filter(|c| !c.is_whitespace()).collect();
+
+    if let Some(enum_path) = prost_attr
+        .split_once("enumeration")
+        .and_then(|(_, rest)| rest.split('"').nth(1))
+    {
+        if ty != "i32" {
+            bail!("unsupported enum field type: {ty}");
+        }
+        return Ok(Some(format!(
+            "{SERDE_ATTR}serde(serialize_with = \
+             \"{JSON_MOD}::enumeration::serialize::<{enum_path}, _>\", \
+             deserialize_with = \
+             \"{JSON_MOD}::enumeration::deserialize::<{enum_path}, _>\"), \
+             schemars(with = \"{enum_path}\"))]"
+        )));
+    }
+
+    let (wrapper, inner) = if let Some(inner) = ty
+        .strip_prefix("::core::option::Option<")
+        .and_then(|t| t.strip_suffix('>'))
+    {
+        (Wrapper::Option, inner)
+    } else if let Some(inner) = ty
+        .strip_prefix("::prost::alloc::vec::Vec<")
+        .and_then(|t| t.strip_suffix('>'))
+        .filter(|inner| *inner != "u8")
+    {
+        (Wrapper::Vec, inner)
+    } else {
+        (Wrapper::Value, ty.as_str())
+    };
+    let schema = match inner {
+        "i64"
+        | "u64"
+        | "::prost::bytes::Bytes"
+        | "::prost::alloc::vec::Vec<u8>" => "String",
+        "::prost_types::Timestamp" | "::prost_types::Duration" => "String",
+        "::prost_types::FileDescriptorProto" => "String",
+        "::prost_types::Any" => "::serde_json::Value",
+        _ if inner.starts_with("::prost_types::") => {
+            bail!("unsupported well-known type: {inner}")
+        }
+        _ => return Ok(None),
+    };
+    let (module, schema) = match wrapper {
+        Wrapper::Value => ("value", schema.to_string()),
+        Wrapper::Option => ("option", format!("Option<{schema}>")),
+        Wrapper::Vec => ("vec", format!("Vec<{schema}>")),
+    };
+    Ok(Some(format!(
+        "{SERDE_ATTR}serde(with = \"{JSON_MOD}::{module}\"), \
+         schemars(with = \"{schema}\"))]"
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BEFORE: &str = r#"/// VoteOption enumerates the valid vote options.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum VoteOption {
+    /// VOTE_OPTION_YES defines a yes vote option.
+    Yes = 1,
+}
+impl VoteOption {
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            VoteOption::Yes => "VOTE_OPTION_YES",
+        }
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgVote {
+    #[prost(uint64, tag="1")]
+    pub proposal_id: u64,
+    #[prost(enumeration="VoteOption", tag="3")]
+    pub option: i32,
+    #[prost(message, optional, tag = "4")]
+    pub pagination: ::core::option::Option<
+        super::super::query::v1beta1::PageRequest,
+    >,
+}
+"#;
+
+    const AFTER: &str = r#"/// VoteOption enumerates the valid vote options.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), schemars(crate = "::cosmwasm_schema::schemars"))]
+#[repr(i32)]
+pub enum VoteOption {
+    /// VOTE_OPTION_YES defines a yes vote option.
+    #[cfg_attr(feature = "serde", serde(rename = "VOTE_OPTION_YES"))]
+    Yes = 1,
+}
+impl VoteOption {
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            VoteOption::Yes => "VOTE_OPTION_YES",
+        }
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize, ::cosmwasm_schema::schemars::JsonSchema), serde(rename_all = "camelCase", default), schemars(crate = "::cosmwasm_schema::schemars"))]
+pub struct MsgVote {
+    #[prost(uint64, tag="1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto::json::value"), schemars(with = "String"))]
+    pub proposal_id: u64,
+    #[prost(enumeration="VoteOption", tag="3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::proto::json::enumeration::serialize::<VoteOption, _>", deserialize_with = "crate::proto::json::enumeration::deserialize::<VoteOption, _>"), schemars(with = "VoteOption"))]
+    pub option: i32,
+    #[prost(message, optional, tag = "4")]
+    pub pagination: ::core::option::Option<
+        super::super::query::v1beta1::PageRequest,
+    >,
+}
+"#;
+
+    #[test]
+    fn test_add_serde_attrs() -> anyhow::Result<()> {
+        assert_eq!(add_serde_attrs(BEFORE)?, AFTER);
+        // Running twice gives the same output.
+        assert_eq!(add_serde_attrs(AFTER)?, AFTER);
+        Ok(())
+    }
+
+    #[test]
+    fn test_field_attr() -> anyhow::Result<()> {
+        let with = |module: &str, schema: &str| {
+            Some(format!(
+                "{SERDE_ATTR}serde(with = \"crate::proto::json::{module}\"), \
+                 schemars(with = \"{schema}\"))]"
+            ))
+        };
+        let test_cases = [
+            (
+                "#[prost(string, tag=\"1\")]",
+                "pub denom: ::prost::alloc::string::String,",
+                None,
+            ),
+            (
+                "#[prost(int64, tag=\"1\")]",
+                "pub height: i64,",
+                with("value", "String"),
+            ),
+            (
+                "#[prost(uint64, repeated, tag=\"1\")]",
+                "pub ids: ::prost::alloc::vec::Vec<u64>,",
+                with("vec", "Vec<String>"),
+            ),
+            (
+                "#[prost(bytes=\"bytes\", tag=\"1\")]",
+                "pub key: ::prost::bytes::Bytes,",
+                with("value", "String"),
+            ),
+            (
+                "#[prost(bytes, tag=\"1\")]",
+                "Ed25519(::prost::bytes::Bytes),",
+                with("value", "String"),
+            ),
+            (
+                "#[prost(message, optional, tag=\"2\")]",
+                "pub time: ::core::option::Option<::prost_types::Timestamp>,",
+                with("option", "Option<String>"),
+            ),
+            (
+                "#[prost(message, repeated, tag=\"2\")]",
+                "pub msgs: ::prost::alloc::vec::Vec<::prost_types::Any>,",
+                with("vec", "Vec<::serde_json::Value>"),
+            ),
+            (
+                "#[prost(oneof=\"public_key::Sum\", tags=\"1, 2\")]",
+                "pub sum: ::core::option::Option<public_key::Sum>,",
+                Some(format!("{SERDE_ATTR}serde(flatten))]")),
+            ),
+        ];
+        for (prost_attr, decl, want) in test_cases {
+            assert_eq!(field_attr(prost_attr, decl)?, want, "{decl}");
+        }
+        Ok(())
+    }
+}
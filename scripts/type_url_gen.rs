@@ -0,0 +1,772 @@
+//! scripts/type_url_gen.rs:
+//!
+//! Run with: `cargo run --bin type_url_gen`
+//!
+//! ## Procedure
+//!
+//! 1. Decode the protobuf `FileDescriptorSet` checked in at
+//!    nibiru-std/src/proto/buf/proto_descriptor.bin.
+//! 2. Read nibiru-std/src/proto/mod.rs to find the Rust module that
+//!    `include!`s the generated code for each proto package, and the cargo
+//!    features that gate it.
+//! 3. For each top-level message of an included "cosmos", "nibiru", "eth", or
+//!    "ibc" package, emit an `impl prost::Name`. The Rust identifier of each
+//!    message is checked against the prost generated file in `proto/buf`.
+//! 4. For each gRPC query method (any method outside of a `Msg` service) whose
+//!    request type is served by exactly one method, emit an
+//!    `impl NibiruGrpcQuery` holding the explicit service path.
+//! 5. Under the "serde" feature, map the type URL of each message to its JSON
+//!    codec so that `Any` fields serialize the message they hold.
+//! 6. Gate everything generated for a package behind the same
+//!    `#[cfg(feature = ...)]` as its module in mod.rs.
+//! 7. Run rustfmt on type_url_cosmos.rs, type_url_nibiru.rs, and
+//!    type_url_ibc.rs and write them.
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Context};
+use prost::Message;
+use prost_types::FileDescriptorSet;
+
+pub static PROTO_DIR: &str = "../nibiru-std/src/proto";
+pub static DESCRIPTOR_PATH: &str =
+    "../nibiru-std/src/proto/buf/proto_descriptor.bin";
+
+/// Root rustfmt config of the repo, relative to the scripts directory.
+pub static RUSTFMT_CONFIG: &str = "../rustfmt.toml";
+
+pub fn main() -> anyhow::Result<()> {
+    println!("Running type_url_gen.rs...");
+
+    let descriptor_bz = std::fs::read(DESCRIPTOR_PATH)
+        .with_context(|| format!("reading {DESCRIPTOR_PATH}"))?;
+    let descriptor = FileDescriptorSet::decode(descriptor_bz.as_slice())?;
+    let mod_rs = std::fs::read_to_string(format!("{PROTO_DIR}/mod.rs"))?;
+
+    let out_files = render_type_urls(&descriptor, &mod_rs, |pkg| {
+        let path = format!("{PROTO_DIR}/buf/{pkg}.rs");
+        std::fs::read_to_string(&path).with_context(|| format!("reading {path}"))
+    })?;
+    for (file_name, content) in out_files {
+        let path = format!("{PROTO_DIR}/{file_name}");
+        println!("writing {path}");
+        std::fs::write(&path, content)?;
+    }
+
+    println!("ran type_url_gen.rs successfully");
+    Ok(())
+}
+
+/// The files this script generates in nibiru-std/src/proto.
+pub static OUT_FILES: [OutFile; 3] = [
+    OutFile {
+        file_name: "type_url_cosmos.rs",
+        roots: &["cosmos"],
+        about: "cosmos",
+    },
+    OutFile {
+        file_name: "type_url_nibiru.rs",
+        roots: &["nibiru", "eth"],
+        about: "Nibiru",
+    },
+    OutFile {
+        file_name: "type_url_ibc.rs",
+        roots: &["ibc"],
+        about: "IBC",
+    },
+];
+
+/// Renders and formats each of [`OUT_FILES`] as (file name, content) for the
+/// packages `include!`d in `mod_rs`. `read_buf` returns the prost generated
+/// code of a package, Ex.: "cosmos.bank.v1beta1".
+pub fn render_type_urls(
+    descriptor: &FileDescriptorSet,
+    mod_rs: &str,
+    read_buf: impl Fn(&str) -> anyhow::Result<String>,
+) -> anyhow::Result<Vec<(&'static str, String)>> {
+    let rust_mods = parse_proto_mods(mod_rs);
+    let mut protos = collect_protos(descriptor, &rust_mods, read_buf)?;
+    let features = parse_proto_features(mod_rs);
+    for (pkg_name, pkg) in protos.packages.iter_mut() {
+        pkg.features = features.get(pkg_name).cloned().unwrap_or_default();
+    }
+
+    OUT_FILES
+        .iter()
+        .map(|out| Ok((out.file_name, rustfmt(&render(out, &protos))?)))
+        .collect()
+}
+
+/// Formats Rust source code with rustfmt and the [`RUSTFMT_CONFIG`] of the
+/// repo.
+pub fn rustfmt(src: &str) -> anyhow::Result<String> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2021", "--config-path", RUSTFMT_CONFIG])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .context("running rustfmt")?;
+    child
+        .stdin
+        .take()
+        .context("opening rustfmt stdin")?
+        .write_all(src.as_bytes())?;
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        bail!("rustfmt failed");
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// A generated Rust source file and the root Rust modules whose packages it
+/// covers.
+pub struct OutFile {
+    pub file_name: &'static str,
+    pub roots: &'static [&'static str],
+    pub about: &'static str,
+}
+
+/// A proto package `include!`d by nibiru-std and its top-level messages.
+#[derive(Debug, Default)]
+pub struct ProtoPackage {
+    /// Rust module path, Ex.: "cosmos::bank::v1beta1"
+    pub rust_mod: String,
+    /// (proto name, Rust identifier) of each top-level message
+    pub messages: Vec<(String, String)>,
+    /// Cargo features that gate the package module, Ex.: ["bank"]
+    pub features: Vec<String>,
+}
+
+/// A gRPC query method whose request type maps to exactly one path.
+#[derive(Debug, PartialEq)]
+pub struct GrpcQuery {
+    /// Fully qualified proto name of the request
+    pub request: String,
+    /// Fully qualified proto name of the response
+    pub response: String,
+    /// Ex.: "/cosmos.bank.v1beta1.Query/Balance"
+    pub path: String,
+}
+
+#[derive(Debug, Default)]
+pub struct Protos {
+    /// Proto packages keyed by package name, Ex.: "cosmos.bank.v1beta1"
+    pub packages: BTreeMap<String, ProtoPackage>,
+    pub queries: Vec<GrpcQuery>,
+}
+
+impl Protos {
+    /// Rust path of a fully qualified proto message name, if the message
+    /// belongs to an included package.
+    pub fn rust_path(&self, full_name: &str) -> Option<String> {
+        let (pkg, name) = full_name.rsplit_once('.')?;
+        let package = self.packages.get(pkg)?;
+        package
+            .messages
+            .iter()
+            .find(|(proto_name, _)| proto_name == name)
+            .map(|(_, ident)| format!("{}::{}", package.rust_mod, ident))
+    }
+
+    /// Cargo features needed by every given fully qualified proto message
+    /// name, sorted and deduplicated.
+    pub fn features<'a>(
+        &self,
+        full_names: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<String> {
+        let mut features: Vec<String> = full_names
+            .into_iter()
+            .filter_map(|name| self.packages.get(name.rsplit_once('.')?.0))
+            .flat_map(|pkg| pkg.features.iter().cloned())
+            .collect();
+        features.sort();
+        features.dedup();
+        features
+    }
+}
+
+/// Renders the `#[cfg]` attribute that enables all of `features`, followed by
+/// a line break and `indent`, or an empty string if nothing is gated.
+pub fn cfg_attr(features: &[String], indent: &str) -> String {
+    let preds: Vec<String> = features
+        .iter()
+        .map(|f| format!("feature = \"{f}\""))
+        .collect();
+    match preds.as_slice() {
+        [] => String::new(),
+        [pred] => format!("#[cfg({pred})]\n{indent}"),
+        _ => format!("#[cfg(all({}))]\n{indent}", preds.join(", ")),
+    }
+}
+
+/// Maps each file `include!`d in proto/mod.rs, Ex.: "cosmos.bank.v1beta1",
+/// to the Rust module path that includes it. A file included more than once
+/// keeps its first module.
+pub fn parse_proto_mods(mod_rs: &str) -> BTreeMap<String, String> {
+    let mut mods: BTreeMap<String, String> = BTreeMap::new();
+    for include in walk_proto_mods(mod_rs) {
+        mods.entry(include.pkg).or_insert(include.rust_mod);
+    }
+    mods
+}
+
+/// Maps each file `include!`d in proto/mod.rs to the cargo features in the
+/// `#[cfg(feature = "...")]` attributes of its enclosing modules. A file
+/// included more than once keeps the features of its first module.
+pub fn parse_proto_features(mod_rs: &str) -> BTreeMap<String, Vec<String>> {
+    let mut features: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for include in walk_proto_mods(mod_rs) {
+        features.entry(include.pkg).or_insert(include.features);
+    }
+    features
+}
+
+/// An `include!` of prost generated code found in proto/mod.rs.
+struct ProtoInclude {
+    pkg: String,
+    rust_mod: String,
+    features: Vec<String>,
+}
+
+fn walk_proto_mods(mod_rs: &str) -> Vec<ProtoInclude> {
+    let mut includes: Vec<ProtoInclude> = vec![];
+    // Open modules as (name, brace depth inside the module, cfg feature)
+    let mut stack: Vec<(String, usize, Option<String>)> = vec![];
+    let mut depth: usize = 0;
+    // Feature of a `#[cfg(feature = "...")]` line that awaits its item
+    let mut pending_feature: Option<String> = None;
+    for line in mod_rs.lines().map(str::trim) {
+        if line.starts_with("//") {
+            continue;
+        }
+        if let Some(feature) = line
+            .strip_prefix("#[cfg(feature = \"")
+            .and_then(|rest| rest.strip_suffix("\")]"))
+        {
+            pending_feature = Some(feature.to_string());
+            continue;
+        }
+        if line.starts_with("#[") {
+            continue;
+        }
+        let feature = pending_feature.take();
+        let mod_decl =
+            line.strip_prefix("pub mod ").or(line.strip_prefix("mod "));
+        if let Some(name) = mod_decl.and_then(|rest| rest.strip_suffix('{')) {
+            depth += 1;
+            stack.push((name.trim().to_string(), depth, feature));
+            continue;
+        }
+        if let Some(pkg) = line
+            .strip_prefix("include!(\"buf/")
+            .and_then(|rest| rest.strip_suffix(".rs\");"))
+        {
+            let rust_mod: Vec<&str> =
+                stack.iter().map(|(name, ..)| name.as_str()).collect();
+            includes.push(ProtoInclude {
+                pkg: pkg.to_string(),
+                rust_mod: rust_mod.join("::"),
+                features: stack
+                    .iter()
+                    .filter_map(|(.., feature)| feature.clone())
+                    .collect(),
+            });
+        }
+        depth += line.matches('{').count();
+        depth = depth.saturating_sub(line.matches('}').count());
+        while stack
+            .last()
+            .is_some_and(|(_, mod_depth, _)| *mod_depth > depth)
+        {
+            stack.pop();
+        }
+    }
+    includes
+}
+
+/// Converts a proto message name to the identifier prost generates for it,
+/// Ex.: "QueryNFTsRequest" -> "QueryNfTsRequest".
+pub fn to_upper_camel(proto_name: &str) -> String {
+    let chars: Vec<char> = proto_name.chars().collect();
+    let mut words: Vec<String> = vec![];
+    let mut word = String::new();
+    for (idx, &c) in chars.iter().enumerate() {
+        if c == '_' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        if let Some(prev) = word.chars().last() {
+            let next = chars.get(idx + 1);
+            let is_boundary = (prev.is_lowercase() && c.is_uppercase())
+                || (prev.is_ascii_digit() && c.is_uppercase())
+                || (prev.is_uppercase()
+                    && c.is_uppercase()
+                    && next.is_some_and(|n| n.is_lowercase()));
+            if is_boundary {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+        .iter()
+        .map(|w| {
+            let mut cs = w.chars();
+            let first = cs.next().map(|c| c.to_ascii_uppercase());
+            first
+                .into_iter()
+                .chain(cs.flat_map(char::to_lowercase))
+                .collect::<String>()
+        })
+        .collect()
+}
+
+/// Gathers the messages and gRPC queries of every included package.
+/// `read_buf` returns the prost generated source of a package.
+pub fn collect_protos(
+    descriptor: &FileDescriptorSet,
+    rust_mods: &BTreeMap<String, String>,
+    read_buf: impl Fn(&str) -> anyhow::Result<String>,
+) -> anyhow::Result<Protos> {
+    let mut protos = Protos::default();
+    for file in &descriptor.file {
+        let pkg = file.package();
+        let is_covered = ["cosmos.", "nibiru.", "eth.", "ibc."]
+            .iter()
+            .any(|prefix| pkg.starts_with(prefix));
+        let Some(rust_mod) = rust_mods.get(pkg).filter(|_| is_covered) else {
+            continue;
+        };
+        let buf_src = read_buf(pkg)?;
+        let package =
+            protos.packages.entry(pkg.to_string()).or_insert_with(|| {
+                ProtoPackage {
+                    rust_mod: rust_mod.clone(),
+                    ..Default::default()
+                }
+            });
+        for msg in &file.message_type {
+            let ident = to_upper_camel(msg.name());
+            if !buf_src.contains(&format!("\npub struct {ident} {{")) {
+                bail!("{pkg}.{} has no prost type named {ident}", msg.name());
+            }
+            package.messages.push((msg.name().to_string(), ident));
+        }
+    }
+    for package in protos.packages.values_mut() {
+        package.messages.sort();
+    }
+
+    // Collect the methods of every non-Msg service, then keep the ones with a
+    // request type that no other method shares.
+    let mut methods: Vec<GrpcQuery> = vec![];
+    for file in &descriptor.file {
+        for service in &file.service {
+            if service.name() == "Msg" {
+                continue;
+            }
+            for method in &service.method {
+                methods.push(GrpcQuery {
+                    request: method.input_type().trim_start_matches('.').into(),
+                    response: method
+                        .output_type()
+                        .trim_start_matches('.')
+                        .into(),
+                    path: format!(
+                        "/{}.{}/{}",
+                        file.package(),
+                        service.name(),
+                        method.name()
+                    ),
+                });
+            }
+        }
+    }
+    let mut req_count: BTreeMap<String, usize> = BTreeMap::new();
+    for method in &methods {
+        *req_count.entry(method.request.clone()).or_default() += 1;
+    }
+    for method in methods {
+        let is_unique = req_count[&method.request] == 1;
+        let is_included = protos.rust_path(&method.request).is_some()
+            && protos.rust_path(&method.response).is_some();
+        if is_unique && is_included {
+            protos.queries.push(method);
+        }
+    }
+    protos.queries.sort_by(|a, b| a.request.cmp(&b.request));
+    Ok(protos)
+}
+
+/// Renders the Rust source of one generated type URL file.
+pub fn render(out: &OutFile, protos: &Protos) -> String {
+    let in_file = |pkg: &ProtoPackage| {
+        let root = pkg.rust_mod.split("::").next().unwrap_or_default();
+        out.roots.contains(&root)
+    };
+    let package_const = |pkg: &ProtoPackage| {
+        let name = pkg.rust_mod.split("::").skip(1).collect::<Vec<_>>();
+        let name = name.join("_");
+        format!("PACKAGE_{}", name.to_uppercase())
+    };
+
+    let mut src = format!(
+        "//! Implements the prost::Name trait for {} protobuf types, which defines
+//! the prost::Message.type_url function needed for CosmWasm smart contracts,
+//! and the NibiruGrpcQuery trait for requests with a known gRPC query path.
+//!
+//! Code generated from \"proto/buf/proto_descriptor.bin\" by
+//! `cargo run --bin type_url_gen` in the scripts directory. DO NOT EDIT.
+
+#[allow(unused_imports)]
+use prost::Name;
+
+#[allow(unused_imports)]
+use crate::proto::{{{}, NibiruGrpcQuery}};
+",
+        out.about,
+        out.roots.join(", ")
+    );
+
+    src.push('\n');
+    for (pkg_name, pkg) in protos.packages.iter().filter(|(_, p)| in_file(p)) {
+        src += &format!(
+            "{}const {}: &str = \"{pkg_name}\";\n",
+            cfg_attr(&pkg.features, ""),
+            package_const(pkg)
+        );
+    }
+
+    for (pkg_name, pkg) in protos.packages.iter().filter(|(_, p)| in_file(p)) {
+        src += &format!("\n// {pkg_name}\n");
+        for (proto_name, ident) in &pkg.messages {
+            src += &format!(
+                "
+{}impl Name for {}::{ident} {{
+    const NAME: &'static str = \"{proto_name}\";
+    const PACKAGE: &'static str = {};
+}}
+",
+                cfg_attr(&pkg.features, ""),
+                pkg.rust_mod,
+                package_const(pkg)
+            );
+        }
+    }
+
+    // (query, request path, response path, cargo features)
+    let queries: Vec<(&GrpcQuery, String, String, Vec<String>)> = protos
+        .queries
+        .iter()
+        .filter_map(|q| {
+            let req = protos.rust_path(&q.request)?;
+            let resp = protos.rust_path(&q.response)?;
+            let root = req.split("::").next().unwrap_or_default();
+            let features =
+                protos.features([q.request.as_str(), q.response.as_str()]);
+            out.roots
+                .contains(&root)
+                .then_some((q, req, resp, features))
+        })
+        .collect();
+
+    src += "\n// gRPC query paths\n";
+    for (query, req, resp, features) in &queries {
+        src += &format!(
+            "
+{}impl NibiruGrpcQuery for {req} {{
+    const GRPC_PATH: &'static str = \"{}\";
+    type Response = {resp};
+}}
+",
+            cfg_attr(features, ""),
+            query.path
+        );
+    }
+
+    src += "
+/// Returns the gRPC query path of the request with the given fully qualified
+/// proto name, or `None` if no single query method serves it.
+#[allow(clippy::match_single_binding)]
+pub(crate) fn grpc_query_path(full_name: &str) -> Option<&'static str> {
+    match full_name {
+";
+    for (query, req, _, features) in &queries {
+        src += &format!(
+            "        {}\"{}\" => Some(<{req} as NibiruGrpcQuery>::GRPC_PATH),\n",
+            cfg_attr(features, "        "),
+            query.request
+        );
+    }
+    src += "        _ => None,
+    }
+}
+
+/// (request, gRPC path, response) for every generated [NibiruGrpcQuery] impl.
+#[cfg(all(test, feature = \"all_protos\"))]
+#[allow(clippy::vec_init_then_push)]
+pub(crate) fn grpc_queries() -> Vec<(String, &'static str, String)> {
+    #[allow(unused_imports)]
+    use crate::proto::grpc_query_entry;
+    #[allow(unused_mut)]
+    let mut queries = vec![];
+";
+    for (_, req, _, features) in &queries {
+        src += &format!(
+            "    {}queries.push(grpc_query_entry::<{req}>());\n",
+            cfg_attr(features, "    ")
+        );
+    }
+    src += "    queries
+}
+";
+
+    src += "
+/// Returns the JSON codec of the message with the given type URL, which
+/// (de)serializes `Any` fields under the \"serde\" feature.
+#[cfg(feature = \"serde\")]
+#[allow(clippy::match_single_binding)]
+pub(crate) fn any_json(type_url: &str) -> Option<crate::proto::json::AnyJson> {
+    #[allow(unused_imports)]
+    use crate::proto::json::AnyJson;
+    match type_url {
+";
+    for (pkg_name, pkg) in protos.packages.iter().filter(|(_, p)| in_file(p)) {
+        for (proto_name, ident) in &pkg.messages {
+            src += &format!(
+                "        {}\"/{pkg_name}.{proto_name}\" => \
+                 Some(AnyJson::of::<{}::{ident}>()),\n",
+                cfg_attr(&pkg.features, "        "),
+                pkg.rust_mod
+            );
+        }
+    }
+    src += "        _ => None,
+    }
+}
+";
+    src
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_upper_camel() {
+        let test_cases = [
+            ("MsgSend", "MsgSend"),
+            ("QueryNFTsRequest", "QueryNfTsRequest"),
+            ("QueryNFTRequest", "QueryNftRequest"),
+            ("ABCIMessageLog", "AbciMessageLog"),
+            ("EthCallRequest", "EthCallRequest"),
+            ("LegacyAminoPubKey", "LegacyAminoPubKey"),
+            ("Bip44Params", "Bip44Params"),
+            ("SignDocDirectAux", "SignDocDirectAux"),
+        ];
+        for (proto_name, want) in test_cases {
+            assert_eq!(to_upper_camel(proto_name), want, "{proto_name}");
+        }
+    }
+
+    #[test]
+    fn test_parse_proto_mods() {
+        let mod_rs = r#"
+mod traits;
+
+pub mod cosmos {
+    /// Doc comment
+    pub mod bank {
+        pub mod v1beta1 {
+            include!("buf/cosmos.bank.v1beta1.rs");
+        }
+    }
+    pub mod crisis {
+        pub mod v1beta1 {
+            include!("buf/cosmos.crisis.v1beta1.rs");
+        }
+    }
+    pub mod crypto {
+        pub mod v1beta1 {
+            include!("buf/cosmos.crisis.v1beta1.rs");
+        }
+    }
+}
+
+pub mod nibiru {
+    pub mod oracle {
+        include!("buf/nibiru.oracle.v1.rs");
+    }
+}
+
+#[cfg(test)]
+mod type_url_tests;
+
+#[cfg(test)]
+mod tests {
+    fn nested() {
+        let _ = 0;
+    }
+}
+"#;
+        let got = parse_proto_mods(mod_rs);
+        let want: BTreeMap<String, String> = [
+            ("cosmos.bank.v1beta1", "cosmos::bank::v1beta1"),
+            ("cosmos.crisis.v1beta1", "cosmos::crisis::v1beta1"),
+            ("nibiru.oracle.v1", "nibiru::oracle"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn test_parse_proto_features() {
+        let mod_rs = r#"
+#[cfg(feature = "serde")]
+mod json;
+
+pub mod cosmos {
+    #[cfg(feature = "bank")]
+    pub mod bank {
+        pub mod v1beta1 {
+            include!("buf/cosmos.bank.v1beta1.rs");
+        }
+    }
+    pub mod base {
+        pub mod v1beta1 {
+            include!("buf/cosmos.base.v1beta1.rs");
+        }
+
+        /// Doc comment
+        #[cfg(feature = "tendermint")]
+        pub mod tendermint {
+            #[cfg(feature = "beta")]
+            pub mod v1beta1 {
+                include!("buf/cosmos.base.tendermint.v1beta1.rs");
+            }
+        }
+    }
+}
+"#;
+        let got = parse_proto_features(mod_rs);
+        let want: BTreeMap<String, Vec<String>> = [
+            ("cosmos.bank.v1beta1", vec!["bank"]),
+            ("cosmos.base.v1beta1", vec![]),
+            ("cosmos.base.tendermint.v1beta1", vec!["tendermint", "beta"]),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.into_iter().map(String::from).collect()))
+        .collect();
+        assert_eq!(got, want);
+        assert_eq!(
+            parse_proto_mods(mod_rs)["cosmos.base.tendermint.v1beta1"],
+            "cosmos::base::tendermint::v1beta1"
+        );
+
+        let features = |fs: &[&str]| -> Vec<String> {
+            fs.iter().map(|f| f.to_string()).collect()
+        };
+        assert_eq!(cfg_attr(&[], "    "), "");
+        assert_eq!(
+            cfg_attr(&features(&["bank"]), "    "),
+            "#[cfg(feature = \"bank\")]\n    "
+        );
+        assert_eq!(
+            cfg_attr(&features(&["abci", "bank"]), ""),
+            "#[cfg(all(feature = \"abci\", feature = \"bank\"))]\n"
+        );
+    }
+
+    #[test]
+    fn test_collect_protos() -> anyhow::Result<()> {
+        use prost_types::{
+            DescriptorProto, FileDescriptorProto, MethodDescriptorProto,
+            ServiceDescriptorProto,
+        };
+
+        let message = |name: &str| DescriptorProto {
+            name: Some(name.to_string()),
+            ..Default::default()
+        };
+        let method =
+            |name: &str, input: &str, output: &str| MethodDescriptorProto {
+                name: Some(name.to_string()),
+                input_type: Some(format!(".nibiru.evm.v1.{input}")),
+                output_type: Some(format!(".nibiru.evm.v1.{output}")),
+                ..Default::default()
+            };
+        let descriptor = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                package: Some("nibiru.evm.v1".to_string()),
+                message_type: vec![
+                    message("EthCallRequest"),
+                    message("EstimateGasResponse"),
+                    message("QueryNFTRequest"),
+                    message("QueryNFTResponse"),
+                    message("MsgEthereumTxResponse"),
+                ],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("Query".to_string()),
+                    method: vec![
+                        method("NFT", "QueryNFTRequest", "QueryNFTResponse"),
+                        method(
+                            "EthCall",
+                            "EthCallRequest",
+                            "MsgEthereumTxResponse",
+                        ),
+                        method(
+                            "EstimateGas",
+                            "EthCallRequest",
+                            "EstimateGasResponse",
+                        ),
+                    ],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        };
+        let rust_mods: BTreeMap<String, String> =
+            [("nibiru.evm.v1".to_string(), "nibiru::evm".to_string())].into();
+        let buf_src = "
+pub struct EthCallRequest {
+pub struct EstimateGasResponse {
+pub struct QueryNftRequest {
+pub struct QueryNftResponse {
+pub struct MsgEthereumTxResponse {
+";
+
+        let protos =
+            collect_protos(&descriptor, &rust_mods, |_| Ok(buf_src.into()))?;
+        assert_eq!(
+            protos.rust_path("nibiru.evm.v1.QueryNFTRequest").as_deref(),
+            Some("nibiru::evm::QueryNftRequest")
+        );
+        // EthCallRequest serves two methods, so it gets no explicit path.
+        assert_eq!(
+            protos.queries,
+            vec![GrpcQuery {
+                request: "nibiru.evm.v1.QueryNFTRequest".into(),
+                response: "nibiru.evm.v1.QueryNFTResponse".into(),
+                path: "/nibiru.evm.v1.Query/NFT".into(),
+            }]
+        );
+
+        // Descriptor names without a matching prost type are errors.
+        let err = collect_protos(&descriptor, &rust_mods, |_| {
+            Ok("pub struct EthCallRequest {".into())
+        })
+        .expect_err("missing prost types should fail");
+        assert!(err.to_string().contains("has no prost type"), "{err}");
+        Ok(())
+    }
+}